@@ -0,0 +1,38 @@
+//! Feeds random sequences of actions to a round, which must reject invalid actions with an error
+//! rather than panicking, and must stay internally consistent throughout.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use deckard::euchre::{Action, BaseRound, Round, RoundConfig};
+use libfuzzer_sys::fuzz_target;
+
+/// A step of play.
+#[derive(Debug, Arbitrary)]
+enum Step {
+    /// Takes one of the legal actions, so that play makes progress.
+    Legal(u8),
+    /// Takes any action at all, which is usually invalid.
+    Any(Action),
+}
+
+fuzz_target!(|input: (RoundConfig, Vec<Step>)| {
+    let (config, steps) = input;
+    let mut round = BaseRound::from(config);
+    for step in steps {
+        let action = match step {
+            Step::Legal(index) => {
+                let legal = round.legal_actions();
+                if legal.is_empty() {
+                    break;
+                }
+                legal[usize::from(index) % legal.len()]
+            }
+            Step::Any(action) => action,
+        };
+        let _ = round.apply_action(action);
+        while round.pop_event().is_some() {}
+        let _ = round.outcome();
+        round.validate_invariants().expect("round is consistent");
+    }
+});
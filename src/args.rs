@@ -2,11 +2,20 @@
 
 use std::path::PathBuf;
 
-use clap::{Parser, ValueEnum};
+use clap::{ArgAction, ArgGroup, Parser, Subcommand, ValueEnum};
+use deckard::euchre::{
+    DealerRotation, Difficulty, DrillKind, Preset, RobotKind, Seat, SeatMap, SheetFormat,
+    TimeControl,
+};
+use deckard::i18n::Lang;
 
 #[derive(Debug, Clone, Parser)]
 #[command(version, about, long_about = None)]
+#[command(group(ArgGroup::new("source").args(["load", "load_recent"])))]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Which game to play.
     #[arg(short, long)]
     pub game: Option<Game>,
@@ -15,9 +24,435 @@ pub struct Args {
     #[arg(short, long)]
     pub ui: Option<Ui>,
 
-    /// Log file to load.
+    /// Saved game or round log to load.
     #[arg(short, long)]
     pub load: Option<PathBuf>,
+
+    /// Loads the Nth most recently opened or saved file, counting from 1.
+    #[arg(long, value_name = "N")]
+    pub load_recent: Option<usize>,
+
+    /// Lets players fail to follow suit. Reneges are only penalized if the opponents call them,
+    /// once exposed, before the round is over. By default, the settings decide.
+    #[arg(long, conflicts_with = "source")]
+    pub renege: bool,
+
+    /// The number of points needed to win a game: 5, 7, 10 or 15. By default, it's taken from the
+    /// settings, or 10.
+    #[arg(long, conflicts_with = "source", value_parser = parse_points)]
+    pub points: Option<u8>,
+
+    /// Plays a rubber of the specified odd number of games, won by the first team to take a
+    /// majority of them. By default, it's taken from the settings, or a single game.
+    #[arg(long, conflicts_with = "source", value_parser = parse_best_of)]
+    pub best_of: Option<u8>,
+
+    /// Plays without takebacks: the history, seeking, reviewing, the odds, and robot hints are
+    /// locked until the game is over. Also enabled by the settings.
+    #[arg(long, conflicts_with = "source")]
+    pub competitive: bool,
+
+    /// How the deal passes from one round to the next: clockwise, random, or fixed, where the
+    /// same player deals every round. By default, it's taken from the settings, or clockwise.
+    #[arg(long, conflicts_with = "source", value_parser = parse_rotation)]
+    pub rotation: Option<DealerRotation>,
+
+    /// Plays against the clock, as `MINUTES+SECONDS` (e.g., `5+3`): each player has a bank of
+    /// minutes for the game, with seconds added back after each action. A player who runs out of
+    /// time forfeits the game for their team. Also applies to games hosted with `--serve`.
+    #[arg(long, value_name = "CLOCK", conflicts_with = "source", value_parser = parse_time_control)]
+    pub time_control: Option<TimeControl>,
+
+    /// Sets the bidding style of a robot, as `SEAT=PRESET` (e.g., `west=aggressive`). The presets
+    /// are conservative, standard, aggressive, and next, which plays the next and reverse-next
+    /// conventions. May be repeated.
+    #[arg(long, value_name = "SEAT=PRESET", value_parser = parse_preset)]
+    pub preset: Vec<(Seat, Preset)>,
+
+    /// Which robot plays North: heuristic (or easy), or search (or hard), which plays cards by
+    /// searching sampled deals. By default, it's the robot chosen in the settings. Also applies
+    /// to the robots in `duplicate`.
+    #[arg(long, value_name = "KIND", global = true, value_parser = parse_robot_kind)]
+    pub robot_north: Option<RobotKind>,
+
+    /// Which robot plays East, as for `--robot-north`.
+    #[arg(long, value_name = "KIND", global = true, value_parser = parse_robot_kind)]
+    pub robot_east: Option<RobotKind>,
+
+    /// Which robot plays South, as for `--robot-north`. South is yours, unless the robots play it
+    /// for you.
+    #[arg(long, value_name = "KIND", global = true, value_parser = parse_robot_kind)]
+    pub robot_south: Option<RobotKind>,
+
+    /// Which robot plays West, as for `--robot-north`.
+    #[arg(long, value_name = "KIND", global = true, value_parser = parse_robot_kind)]
+    pub robot_west: Option<RobotKind>,
+
+    /// Records each robot's reasoning in the round log, so that review mode can show what it was
+    /// thinking.
+    #[arg(long)]
+    pub trace: bool,
+
+    /// Once each round is over, adds the line the robot would have played from each of your
+    /// decisions to the round log, as a branch to explore in the history browser.
+    #[arg(long)]
+    pub robot_lines: bool,
+
+    /// Checks the consistency of the round after every step of play, and reports any violation as
+    /// an error. This is a debugging aid.
+    #[arg(long)]
+    pub paranoid: bool,
+
+    /// Opens the loaded round in review mode, to walk through it move by move.
+    #[arg(long, requires = "source")]
+    pub review: bool,
+
+    /// Plays a scripted deal, with prompts that explain bidding and following suit along the way.
+    #[arg(long, conflicts_with_all = ["source", "connect", "serve", "http"])]
+    pub tutorial: bool,
+
+    /// Joins a remote game, hosted at the specified URL (e.g., `ws://host:port` or
+    /// `tcp://host:port`).
+    #[arg(short, long, conflicts_with_all = ["source", "serve", "http"])]
+    pub connect: Option<String>,
+
+    /// Watches the remote game as a spectator, rather than claiming a seat.
+    #[arg(long, requires = "connect")]
+    pub spectate: bool,
+
+    /// The table to join on the remote host. Tables are created when first joined.
+    #[arg(long, requires = "connect")]
+    pub table: Option<String>,
+
+    /// The seat to claim at the remote table (e.g., `south` or `n`). By default, the host
+    /// assigns the first open seat.
+    #[arg(long, requires = "connect", conflicts_with = "spectate", value_parser = parse_seat)]
+    pub seat: Option<Seat>,
+
+    /// Hosts a lobby for remote players, listening on the specified address (e.g., `0.0.0.0:7777`).
+    #[arg(long, conflicts_with_all = ["source", "http"])]
+    pub serve: Option<String>,
+
+    /// Serves a REST API for creating and playing games, listening on the specified address
+    /// (e.g., `0.0.0.0:8080`).
+    #[arg(long, conflicts_with = "source")]
+    pub http: Option<String>,
+
+    /// Disables chat while a contract is being played, when hosting a game.
+    #[arg(long, requires = "serve")]
+    pub quiet_play: bool,
+
+    /// Logs more detail: robot decisions and network activity with `-v`, and more with `-vv` or
+    /// `-vvv`. Only warnings and errors are logged by default.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Logs less: only errors with `-q`, and nothing with `-qq`.
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "verbose")]
+    pub quiet: u8,
+
+    /// Writes the log to a file. Otherwise, it's written to stderr, except in the terminal UI,
+    /// which shows the most recent lines in its debug panel.
+    #[arg(long, value_name = "PATH", global = true)]
+    pub log_file: Option<PathBuf>,
+
+    /// The language of the user interface: en or fr. By default, it's chosen by `DECKARD_LANG`,
+    /// or by the system locale.
+    #[arg(long, global = true, value_parser = parse_lang)]
+    pub lang: Option<Lang>,
+}
+
+impl Args {
+    /// Returns which robot plays each seat, falling back to the specified default.
+    pub fn robot_kinds(&self, default: RobotKind) -> SeatMap<RobotKind> {
+        SeatMap::from_fn(|seat| {
+            let kind = match seat {
+                Seat::North => self.robot_north,
+                Seat::East => self.robot_east,
+                Seat::South => self.robot_south,
+                Seat::West => self.robot_west,
+            };
+            kind.unwrap_or(default)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Prints player statistics, accumulated across sessions, or gathered from an archive of
+    /// saved games and round logs.
+    Stats {
+        /// Saved games, round logs, or directories of them, to gather statistics from instead of
+        /// reading the stats file.
+        #[arg(conflicts_with = "file")]
+        paths: Vec<PathBuf>,
+        /// The stats file to read.
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        /// Prints the statistics gathered from logs as CSV.
+        #[arg(long, requires = "paths")]
+        csv: bool,
+    },
+    /// Evaluates the bids in a corpus of logs, and reports how often passing or calling was
+    /// correct, by position and hand strength.
+    Bidding {
+        /// Saved games, round logs, or directories of them.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// The number of rollouts per bid.
+        #[arg(short, long, default_value_t = 100)]
+        rollouts: usize,
+        /// Prints the report as CSV.
+        #[arg(long)]
+        csv: bool,
+        /// Writes the report to an HTML file.
+        #[arg(long, conflicts_with = "csv")]
+        html: Option<PathBuf>,
+    },
+    /// Tallies which kinds of cards were led in a corpus of logs, and how often each lead won
+    /// its trick.
+    Leads {
+        /// Saved games, round logs, or directories of them.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// Writes the report to an HTML file.
+        #[arg(long)]
+        html: Option<PathBuf>,
+    },
+    /// Plays the same deals twice, with the hands rotated between partnerships, to compare results
+    /// without the luck of the deal.
+    Duplicate {
+        /// The number of deals.
+        #[arg(short, long, default_value_t = 8)]
+        boards: usize,
+        /// A file of deals to replay. If it doesn't exist, random deals are saved there.
+        #[arg(short, long)]
+        deals: Option<PathBuf>,
+        /// Plays South yourself, and compares your results with the robots' on the same deals.
+        #[arg(long)]
+        practice: bool,
+        /// Seats the searching robot at North and South, against the heuristic robot, to compare
+        /// their play.
+        #[arg(long, conflicts_with = "practice")]
+        search: bool,
+    },
+    /// Tunes the robot's bidding thresholds by hill-climbing through seeded self-play, and writes
+    /// the best parameters found to a file, which the robots in the terminal UI then use.
+    Train {
+        /// The number of candidate parameter sets to try.
+        #[arg(short, long, default_value_t = 50)]
+        iterations: usize,
+        /// The number of deals on which each candidate is played, in duplicate.
+        #[arg(short, long, default_value_t = 200)]
+        boards: usize,
+        /// The seed for the deals and perturbations.
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// The parameter file, to start from if it exists, and to write. Defaults to
+        /// `euchre-robot.json`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Measures how many rounds per second this machine plays out, on the full and fast round
+    /// implementations, and how long the robots take to decide, and prints a comparison table.
+    Bench {
+        /// The number of seeded rounds played out on each round implementation.
+        #[arg(short, long, default_value_t = 2000)]
+        rounds: usize,
+        /// The number of those rounds in which the searching robot's decisions are timed.
+        #[arg(long, default_value_t = 20)]
+        searches: usize,
+        /// The seed for the deals.
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Reports the shape of the action trees in a corpus of logs: their size, depth, and branching,
+    /// and which seats' decisions branch most.
+    LogStats {
+        /// Saved games, round logs, or directories of them.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Values every decision in a saved game or round log by rolling out the alternatives, and
+    /// prints the evaluations with blunders flagged.
+    Analyze {
+        /// The saved game or round log, or a directory of them.
+        path: PathBuf,
+        /// The number of rollouts per decision.
+        #[arg(short, long, default_value_t = 100, value_name = "ROLLOUTS")]
+        depth: usize,
+    },
+    /// Mines a corpus of logs for puzzles: positions where exactly one card lets the player's
+    /// team win the round, with every hand face up. Each is written to a file, and graded easy,
+    /// medium, or hard.
+    Puzzles {
+        /// Saved games, round logs, or directories of them.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// The directory to write the puzzles to.
+        #[arg(short, long, default_value = "puzzles")]
+        output: PathBuf,
+        /// Keeps only puzzles of this difficulty: easy, medium, or hard.
+        #[arg(short, long, value_parser = parse_difficulty)]
+        difficulty: Option<Difficulty>,
+    },
+    /// Practices one kind of decision, drawn from random deals: order-up, call, discard, or lead.
+    /// Each choice is scored against the best action found by rollouts, and the results are kept
+    /// to track accuracy over time.
+    Drill {
+        /// The kind of decision.
+        #[arg(value_parser = parse_drill_kind)]
+        kind: DrillKind,
+        /// Only presents decisions for the player at this position, counting from the dealer's
+        /// left: 1 bids first, and 4 is the dealer.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=4))]
+        position: Option<u8>,
+        /// The number of decisions to present.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        count: usize,
+        /// The number of rollouts per choice.
+        #[arg(short, long, default_value_t = 100, value_name = "ROLLOUTS")]
+        depth: usize,
+    },
+    /// Prints the score sheet of a saved game: the dealer, maker, contract, and result of each
+    /// round, with the running totals.
+    ScoreSheet {
+        /// The saved game.
+        path: PathBuf,
+        /// The format: text, markdown, or csv. Defaults to the output file's extension, or text.
+        #[arg(short, long, value_parser = parse_sheet_format)]
+        format: Option<SheetFormat>,
+        /// Writes the score sheet to a file, instead of printing it.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Exports a seat's decision diary from a saved game or round log as JSON: the information
+    /// available to the seat at each of its decisions, and the decision it made.
+    Diary {
+        /// The saved game or round log, or a directory of them.
+        path: PathBuf,
+        /// The seat whose decisions to export (e.g., `south` or `s`).
+        #[arg(short, long, value_parser = parse_seat)]
+        seat: Seat,
+        /// Writes the diary to a file, instead of printing it.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Renders a saved game or round log to a standalone HTML file, for sharing.
+    Export {
+        /// The saved game or round log.
+        path: PathBuf,
+        /// The HTML file to write. Defaults to the input path, with an `.html` extension.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Deals a fresh round and prints its configuration as JSON, for building test fixtures and
+    /// puzzles, or sharing an interesting deal.
+    Deal {
+        /// Which game to deal. Only euchre is supported.
+        #[arg(short, long)]
+        game: Option<Game>,
+        /// The seed for the shuffle, to deal the same round every time.
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// The dealer (e.g., `north` or `n`). Chosen at random by default.
+        #[arg(short, long, value_parser = parse_seat)]
+        dealer: Option<Seat>,
+        /// Shows only this seat's hand, leaving the others out.
+        #[arg(long, value_parser = parse_seat)]
+        seat: Option<Seat>,
+    },
+    /// Prints where settings, statistics, and saved games are stored. Set `DECKARD_HOME` to keep
+    /// them all in one directory instead.
+    Paths,
+    /// Prints the action tree of a saved game or round log, laid out like the history in the
+    /// terminal UI.
+    Show {
+        /// The saved game or round log, or a directory of them.
+        path: PathBuf,
+        /// Prints without color. Color is also disabled when `NO_COLOR` is set, or when the output
+        /// isn't a terminal.
+        #[arg(long)]
+        no_color: bool,
+        /// Prints the tree in Graphviz DOT format instead, with the main line in bold (e.g., for
+        /// `deckard show --dot euchre.json | dot -Tsvg`).
+        #[arg(long)]
+        dot: bool,
+    },
+}
+
+/// Parses a target score of 5, 7, 10 or 15 points.
+fn parse_points(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(points @ (5 | 7 | 10 | 15)) => Ok(points),
+        _ => Err(format!(
+            "invalid game length: {s} (expected 5, 7, 10 or 15)"
+        )),
+    }
+}
+
+fn parse_best_of(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(games) if games % 2 == 1 => Ok(games),
+        _ => Err(format!(
+            "invalid rubber length: {s} (expected an odd number)"
+        )),
+    }
+}
+
+fn parse_rotation(s: &str) -> Result<DealerRotation, String> {
+    s.parse()
+        .map_err(|()| format!("invalid rotation: {s} (expected clockwise, random or fixed)"))
+}
+
+fn parse_time_control(s: &str) -> Result<TimeControl, String> {
+    s.parse()
+        .map_err(|()| format!("invalid time control: {s} (expected MINUTES+SECONDS, e.g., 5+3)"))
+}
+
+fn parse_preset(s: &str) -> Result<(Seat, Preset), String> {
+    let (seat, preset) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid preset: {s} (expected SEAT=PRESET)"))?;
+    let seat = parse_seat(seat)?;
+    let preset = preset
+        .parse()
+        .map_err(|()| format!("invalid preset: {preset}"))?;
+    Ok((seat, preset))
+}
+
+fn parse_robot_kind(s: &str) -> Result<RobotKind, String> {
+    s.parse()
+        .map_err(|()| format!("invalid robot: {s} (expected heuristic or search)"))
+}
+
+/// Parses a seat from its name or initial.
+fn parse_seat(s: &str) -> Result<Seat, String> {
+    s.parse().map_err(|()| format!("invalid seat: {s}"))
+}
+
+fn parse_sheet_format(s: &str) -> Result<SheetFormat, String> {
+    s.parse()
+        .map_err(|()| format!("invalid format: {s} (expected text, markdown or csv)"))
+}
+
+fn parse_drill_kind(s: &str) -> Result<DrillKind, String> {
+    s.parse()
+        .map_err(|()| format!("invalid drill: {s} (expected order-up, call, discard or lead)"))
+}
+
+fn parse_difficulty(s: &str) -> Result<Difficulty, String> {
+    s.parse().map_err(|()| format!("invalid difficulty: {s}"))
+}
+
+fn parse_lang(s: &str) -> Result<Lang, String> {
+    s.parse().map_err(|()| {
+        let codes: Vec<_> = Lang::ALL.iter().map(|l| l.code()).collect();
+        format!(
+            "unsupported language: {s} (expected {})",
+            codes.join(" or ")
+        )
+    })
 }
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -25,6 +460,27 @@ pub enum Game {
     /// The game of euchre.
     #[default]
     Euchre,
+    /// Contract bridge, played as a rubber against robots. Only the command-line UI is supported.
+    Bridge,
+    /// Partnership canasta, played to 5000 against robots. Only the command-line UI is supported.
+    Canasta,
+    /// Rummy 500, played by three to 500 points against robots. Only the command-line UI is
+    /// supported.
+    Rummy,
+    /// Skat, played over nine deals against two robots. Only the command-line UI is supported.
+    Skat,
+    /// Durak, played against three robots, in the terminal UI or from the command line.
+    Durak,
+    /// Scopa, played to 11 against a robot, in the terminal UI or from the command line.
+    Scopa,
+    /// Briscola, played against a robot, in the terminal UI or from the command line.
+    Briscola,
+    /// Ninety-Nine, played to 100 against two robots. Only the command-line UI is supported.
+    NinetyNine,
+    /// Nap, played over twelve deals against three robots. Only the command-line UI is supported.
+    Nap,
+    /// Bezique, played to 1000 against a robot. Only the command-line UI is supported.
+    Bezique,
 }
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -34,4 +490,7 @@ pub enum Ui {
     /// A full-featured terminal UI.
     #[default]
     Tui,
+    /// A desktop GUI.
+    #[cfg(feature = "gui")]
+    Gui,
 }
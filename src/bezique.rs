@@ -0,0 +1,141 @@
+//! Bezique.
+//!
+//! Two-handed bezique is played with two 32-card decks shuffled together, from the sevens up to
+//! the aces, so that every card appears twice. Cards rank ace, ten, king, queen, jack, nine,
+//! eight, seven. Each player is dealt eight cards, and the next card is turned up beneath the
+//! stock to fix the trump suit; if it's a seven, the dealer scores 10.
+//!
+//! The non-dealer leads to the first trick. While the stock lasts, there's no need to follow
+//! suit: the higher card of the suit led or a trump wins the trick, and of two identical cards
+//! the first played wins. The winner of a trick may declare one meld, laying its cards face up on
+//! the table, where they may still be played; the winner then draws from the stock, followed by
+//! the loser, and leads to the next trick. The turned-up card is drawn last.
+//!
+//! | Meld                            | Points |
+//! |---------------------------------|--------|
+//! | Seven of trumps                 | 10     |
+//! | Marriage: king and queen        | 20     |
+//! | Royal marriage, in trumps       | 40     |
+//! | Bezique: Q♤ and J♦              | 40     |
+//! | Four jacks                      | 40     |
+//! | Four queens                     | 60     |
+//! | Four kings                      | 80     |
+//! | Four aces                       | 100    |
+//! | Sequence: A T K Q J of trumps   | 250    |
+//! | Double bezique                  | 500    |
+//!
+//! Each meld must include a card from the player's hand, and a card may be used again in a meld
+//! of a different kind, but not in two of the same kind. A royal marriage may be extended to a
+//! sequence, but a marriage can't be taken out of a sequence already declared. Instead of melding, the holder of a
+//! seven of trumps may exchange it for the turned-up card, scoring 10.
+//!
+//! Once the stock is exhausted, the players take their melded cards back into hand and play the
+//! last eight tricks, following suit and winning the trick if they can, and trumping if they
+//! can't follow. Each ace and ten taken in tricks, called brisques, scores 10, as does the last
+//! trick. The game is played to 1000 points.
+
+mod deal;
+mod error;
+mod meld;
+mod robot;
+
+use itertools::Itertools;
+
+pub use self::deal::{Action, Deal, DealConfig, DealResult, DealView, Event, Phase, RawDeal};
+pub use self::error::BeziqueError;
+pub use self::meld::{Meld, Melded};
+pub use self::robot::Robot;
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The number of players.
+pub const PLAYERS: u8 = 2;
+
+/// The number of cards held by each player.
+pub const HAND_SIZE: usize = 8;
+
+/// The score that ends the game.
+pub const GAME_SCORE: u32 = 1000;
+
+/// The 64-card deck: two copies of each card from the sevens up to the aces.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new()
+        .filter_ranks(|r| r == Rank::Ace || r >= Rank::Seven)
+        .copies(2)
+        .build()
+}
+
+/// The rank of a card within its suit, from 0 for a seven up to 7 for an ace.
+pub fn rank_order(card: Card) -> u8 {
+    match card.rank() {
+        Some(Rank::Ace) => 7,
+        Some(Rank::Ten) => 6,
+        Some(Rank::King) => 5,
+        Some(Rank::Queen) => 4,
+        Some(Rank::Jack) => 3,
+        Some(Rank::Nine) => 2,
+        Some(Rank::Eight) => 1,
+        _ => 0,
+    }
+}
+
+/// Returns true if the card is an ace or a ten, which score 10 when taken in a trick.
+pub fn is_brisque(card: Card) -> bool {
+    matches!(card.rank(), Some(Rank::Ace | Rank::Ten))
+}
+
+/// Returns true if the second card played to a trick beats the first: it must be a higher card
+/// of the same suit, or a trump played against a card that isn't a trump.
+pub fn beats(trump: Suit, first: Card, second: Card) -> bool {
+    if first.suit() == second.suit() {
+        rank_order(second) > rank_order(first)
+    } else {
+        second.suit() == Some(trump)
+    }
+}
+
+/// Formats cards by suit, from highest to lowest.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| deal::sort_key(c))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Plays a game to 1000 points with a simple command-line interface, with the user as the first
+/// player and a robot as the second. The record of every deal is written to stderr when the game
+/// is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = [0; PLAYERS as usize];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let mut deals = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    while scores.iter().all(|&s| s < GAME_SCORE) {
+        let config = DealConfig::random(dealer, &mut rng);
+        let mut deal = Deal::from(config);
+        let result = console
+            .play(&mut deal, me, |deal, player, legal| {
+                robot.choose(&deal.view(player), &legal)
+            })?
+            .expect("deal is over");
+        for player in Player::all(PLAYERS) {
+            scores[player.index()] += result.points(player);
+        }
+        deals.push(RawDeal::from(&deal));
+        let totals = Player::all(PLAYERS)
+            .map(|p| format!("{p} {}", scores[p.index()]))
+            .join(", ");
+        console.println(tr!("game-score", score = totals))?;
+        dealer = dealer.next(PLAYERS);
+    }
+    serde_json::to_writer(std::io::stderr(), &deals)?;
+    Ok(())
+}
@@ -0,0 +1,653 @@
+//! A single deal: from the turn-up until the last trick.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::meld::{declare, select};
+use super::{
+    beats, deck, format_cards, is_brisque, rank_order, BeziqueError, Card, Meld, Melded, Player,
+    Rank, Suit, HAND_SIZE, PLAYERS,
+};
+use crate::deck::{Deck, Stock};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Sorts cards for display: by suit, and then from highest to lowest.
+pub(crate) fn sort_key(card: Card) -> (Option<Suit>, Reverse<u8>) {
+    (card.suit(), Reverse(rank_order(card)))
+}
+
+/// The order of the deck, and the dealer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealConfig {
+    dealer: Player,
+    deck: Vec<Card>,
+}
+
+impl DealConfig {
+    /// Shuffles the deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        Self {
+            dealer,
+            deck: deck.into_iter().collect(),
+        }
+    }
+
+    /// Creates a deal from the specified deck, which must hold two copies of each card from the
+    /// sevens up to the aces. The last card is the top of the deck: the non-dealer is dealt the
+    /// top eight cards, the dealer the next eight, and the next card is turned up.
+    pub fn new(dealer: Player, deck: Vec<Card>) -> Result<Self, BeziqueError> {
+        let config = Self { dealer, deck };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that the deck holds exactly two copies of each card.
+    pub fn validate(&self) -> Result<(), BeziqueError> {
+        if self.dealer.0 >= PLAYERS || self.deck.iter().counts() != deck().cards().iter().counts() {
+            return Err(BeziqueError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+}
+
+/// The stage of the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// A card is to be led to the next trick.
+    Lead,
+    /// A card is to be played to the card led.
+    Follow,
+    /// The winner of the trick may declare a meld, before drawing from the stock.
+    Declare,
+    /// The deal is over.
+    Over,
+}
+
+/// An action taken during the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// A card played to a trick, from the hand or the table.
+    Play(Card),
+    /// The winner of the trick declares a meld.
+    Meld(Meld),
+    /// The winner of the trick exchanges the seven of trumps for the turned-up card.
+    Exchange,
+    /// The winner of the trick draws without declaring anything.
+    Draw,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Play(card) => write!(f, "{card}"),
+            Action::Meld(meld) => f.write_str(&tr!("game-action-meld", cards = meld.to_string())),
+            Action::Exchange => f.write_str(&tr!("bezique-action-exchange")),
+            Action::Draw => f.write_str(&tr!("game-action-draw")),
+        }
+    }
+}
+
+/// The result of a deal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealResult {
+    /// The points each player scored for melds, and for the seven of trumps.
+    pub melds: Vec<u32>,
+    /// The points each player scored for the aces and tens taken in tricks.
+    pub brisques: Vec<u32>,
+    /// The player who took the last trick, scoring 10.
+    pub last_trick: Player,
+}
+
+impl DealResult {
+    /// The points scored by the player.
+    pub fn points(&self, player: Player) -> u32 {
+        let last = if player == self.last_trick { 10 } else { 0 };
+        self.melds[player.index()] + self.brisques[player.index()] + last
+    }
+}
+
+impl Display for DealResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = Player::all(PLAYERS).map(|p| {
+            let i = p.index();
+            tr!(
+                "bezique-player-points",
+                player = p.to_string(),
+                points = self.points(p),
+                melds = self.melds[i],
+                brisques = self.brisques[i],
+                last = if p == self.last_trick { "yes" } else { "no" }
+            )
+        });
+        write!(f, "{}", lines.format("\n"))
+    }
+}
+
+/// An event that occurs during the deal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// The card was turned up beneath the stock, fixing the trump suit.
+    TurnUp(Card),
+    /// A player played a card.
+    Play(Player, Card),
+    /// A player won the trick, taking the cards.
+    Trick(Player, [Card; 2]),
+    /// A player declared a meld, scoring the points.
+    Meld(Player, Meld, u32),
+    /// A player exchanged the seven of trumps for the turned-up card.
+    Exchange(Player),
+    /// The stock is exhausted, and the players have taken their melded cards back into hand.
+    Endgame,
+    /// The deal is over.
+    Over(DealResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::TurnUp(card) if card.rank() == Some(Rank::Seven) => {
+                f.write_str(&tr!("bezique-turned-up-seven", card = card.to_string()))
+            }
+            Event::TurnUp(card) => f.write_str(&tr!("bezique-turned-up", card = card.to_string())),
+            Event::Play(player, card) => {
+                let (player, card) = (player.to_string(), card.to_string());
+                f.write_str(&tr!("game-played", player = player, card = card))
+            }
+            Event::Trick(player, [a, b]) => {
+                let (player, cards) = (player.to_string(), format!("{a} {b}"));
+                f.write_str(&tr!("game-took", player = player, cards = cards))
+            }
+            Event::Meld(player, meld, points) => {
+                let (player, meld) = (player.to_string(), meld.to_string());
+                let text = tr!(
+                    "bezique-declared",
+                    player = player,
+                    meld = meld,
+                    points = *points
+                );
+                f.write_str(&text)
+            }
+            Event::Exchange(player) => {
+                f.write_str(&tr!("bezique-exchanged", player = player.to_string()))
+            }
+            Event::Endgame => f.write_str(&tr!("bezique-endgame")),
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the deal, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct DealView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The trump suit.
+    pub trump: Suit,
+    /// The card turned up beneath the stock, until it's drawn.
+    pub turn_up: Option<Card>,
+    /// The cards in the player's hand, not counting those on the table.
+    pub hand: &'a [Card],
+    /// The cards each player has melded.
+    pub tables: &'a [Vec<Melded>],
+    /// The number of cards left in the stock, not counting the turned-up card.
+    pub stock: usize,
+    /// The card led to the current trick, if any.
+    pub lead: Option<Card>,
+    /// The points each player has scored for melds so far.
+    pub melds: &'a [u32],
+    /// The stage of the deal.
+    pub phase: Phase,
+    /// The player expected to act next.
+    pub to_play: Option<Player>,
+}
+
+impl DealView<'_> {
+    /// Returns true once the stock is exhausted, and the last eight tricks are being played.
+    pub fn is_endgame(&self) -> bool {
+        self.stock == 0 && self.turn_up.is_none()
+    }
+
+    /// The cards the player has melded.
+    pub fn table(&self) -> &[Melded] {
+        &self.tables[self.player.index()]
+    }
+}
+
+impl Display for DealView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        match self.turn_up {
+            Some(card) => {
+                let text = tr!(
+                    "bezique-trump-stock",
+                    card = card.to_string(),
+                    stock = self.stock
+                );
+                writeln!(f, "{text}")?
+            }
+            None => writeln!(f, "{}", tr!("game-trump", trump = self.trump.to_string()))?,
+        }
+        writeln!(
+            f,
+            "{}",
+            tr!("bezique-melds", melds = self.melds.iter().join("-"))
+        )?;
+        for player in Player::all(PLAYERS) {
+            let table = &self.tables[player.index()];
+            if !table.is_empty() {
+                let cards: Vec<Card> = table.iter().map(|m| m.card).collect();
+                let (player, cards) = (player.to_string(), format_cards(&cards));
+                writeln!(
+                    f,
+                    "{}",
+                    tr!("bezique-table", player = player, cards = cards)
+                )?;
+            }
+        }
+        if let Some(lead) = self.lead {
+            writeln!(f, "{}", tr!("game-led", card = lead.to_string()))?;
+        }
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a deal.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    config: DealConfig,
+    hands: Vec<Vec<Card>>,
+    tables: Vec<Vec<Melded>>,
+    stock: Stock<Card>,
+    turn_up: Option<Card>,
+    trump: Suit,
+    lead: Option<(Player, Card)>,
+    declarer: Option<Player>,
+    turn: Player,
+    melds: Vec<u32>,
+    taken: Vec<Vec<Card>>,
+    last_trick: Option<Player>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<DealConfig> for Deal {
+    fn from(config: DealConfig) -> Self {
+        let mut stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let players = usize::from(PLAYERS);
+        let first = config.dealer.next(PLAYERS);
+        let mut hands = vec![vec![]; players];
+        for player in [first, config.dealer] {
+            hands[player.index()] = (0..HAND_SIZE).filter_map(|_| stock.draw()).collect();
+        }
+        let turn_up = stock.draw().expect("deck is large enough");
+        let mut melds = vec![0; players];
+        if turn_up.rank() == Some(Rank::Seven) {
+            melds[config.dealer.index()] += 10;
+        }
+        Self {
+            hands,
+            tables: vec![vec![]; players],
+            stock,
+            turn_up: Some(turn_up),
+            trump: turn_up.suit().expect("not a joker"),
+            lead: None,
+            declarer: None,
+            turn: first,
+            melds,
+            taken: vec![vec![]; players],
+            last_trick: None,
+            actions: vec![],
+            events: VecDeque::from([Event::TurnUp(turn_up)]),
+            config,
+        }
+    }
+}
+
+impl Deal {
+    /// The deal as it was dealt.
+    pub fn config(&self) -> &DealConfig {
+        &self.config
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The cards in the player's hand, not counting those on the table.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The cards the player has melded.
+    pub fn table(&self, player: Player) -> &[Melded] {
+        &self.tables[player.index()]
+    }
+
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump
+    }
+
+    /// Returns true once the stock is exhausted, and the last eight tricks are being played.
+    pub fn is_endgame(&self) -> bool {
+        self.stock.is_empty() && self.turn_up.is_none()
+    }
+
+    /// The stage of the deal.
+    pub fn phase(&self) -> Phase {
+        if self.last_trick.is_some() {
+            Phase::Over
+        } else if self.declarer.is_some() {
+            Phase::Declare
+        } else if self.lead.is_some() {
+            Phase::Follow
+        } else {
+            Phase::Lead
+        }
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        match self.phase() {
+            Phase::Over => None,
+            Phase::Declare => self.declarer,
+            Phase::Lead | Phase::Follow => Some(self.turn),
+        }
+    }
+
+    /// Returns the state of the deal, as visible to the player.
+    pub fn view(&self, player: Player) -> DealView<'_> {
+        DealView {
+            player,
+            dealer: self.config.dealer,
+            trump: self.trump,
+            turn_up: self.turn_up,
+            hand: &self.hands[player.index()],
+            tables: &self.tables,
+            stock: self.stock.len(),
+            lead: self.lead.map(|(_, card)| card),
+            melds: &self.melds,
+            phase: self.phase(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// The cards the player may play, from their hand and the table, without duplicates.
+    fn playable(&self, player: Player) -> Vec<Card> {
+        let held = self.hands[player.index()]
+            .iter()
+            .chain(self.tables[player.index()].iter().map(|m| &m.card))
+            .copied()
+            .unique()
+            .sorted_by_key(|&c| sort_key(c));
+        let Some((_, lead)) = self.lead.filter(|_| self.is_endgame()) else {
+            return held.collect();
+        };
+        let held: Vec<Card> = held.collect();
+        let following: Vec<Card> = held
+            .iter()
+            .copied()
+            .filter(|c| c.suit() == lead.suit())
+            .collect();
+        let winning: Vec<Card> = following
+            .iter()
+            .copied()
+            .filter(|&c| beats(self.trump, lead, c))
+            .collect();
+        let trumps: Vec<Card> = held
+            .iter()
+            .copied()
+            .filter(|c| c.suit() == Some(self.trump))
+            .collect();
+        vec![winning, following, trumps, held]
+            .into_iter()
+            .find(|cards| !cards.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the player may exchange the seven of trumps for the turned-up card.
+    fn can_exchange(&self, player: Player) -> bool {
+        let seven = Card::new(Rank::Seven, self.trump);
+        self.turn_up.is_some_and(|c| c != seven) && self.hands[player.index()].contains(&seven)
+    }
+
+    /// Returns the actions the next player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.phase() {
+            Phase::Lead | Phase::Follow => self
+                .playable(self.turn)
+                .into_iter()
+                .map(Action::Play)
+                .collect(),
+            Phase::Declare => {
+                let player = self.declarer.expect("trick is won");
+                let (hand, table) = (&self.hands[player.index()], &self.tables[player.index()]);
+                let melds = Meld::all()
+                    .into_iter()
+                    .filter(|&m| select(m, self.trump, hand, table).is_some())
+                    .map(Action::Meld);
+                std::iter::once(Action::Draw)
+                    .chain(self.can_exchange(player).then_some(Action::Exchange))
+                    .chain(melds)
+                    .collect()
+            }
+            Phase::Over => vec![],
+        }
+    }
+
+    /// Applies the next player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), BeziqueError> {
+        match (self.phase(), action) {
+            (Phase::Over, _) => return Err(BeziqueError::DealOver),
+            (Phase::Lead | Phase::Follow, Action::Play(card)) => self.play(card)?,
+            (Phase::Declare, Action::Meld(meld)) => {
+                self.meld(meld)?;
+                self.draw();
+            }
+            (Phase::Declare, Action::Exchange) => {
+                self.exchange()?;
+                self.draw();
+            }
+            (Phase::Declare, Action::Draw) => self.draw(),
+            _ => return Err(BeziqueError::OutOfTurn(action)),
+        }
+        self.actions.push(action);
+        if let Some(result) = self.result() {
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, card: Card) -> Result<(), BeziqueError> {
+        let player = self.turn;
+        if !self.playable(player).contains(&card) {
+            let held = self.hands[player.index()].contains(&card)
+                || self.tables[player.index()].iter().any(|m| m.card == card);
+            return Err(if held {
+                BeziqueError::IllegalPlay(player, card)
+            } else {
+                BeziqueError::CardNotHeld(player, card)
+            });
+        }
+        let hand = &mut self.hands[player.index()];
+        if let Some(pos) = hand.iter().position(|&c| c == card) {
+            hand.remove(pos);
+        } else {
+            // Of two identical cards on the table, play the one that's been melded more.
+            let table = &mut self.tables[player.index()];
+            let pos = table
+                .iter()
+                .positions(|m| m.card == card)
+                .max_by_key(|&i| table[i].melds.len())
+                .expect("card on table");
+            table.remove(pos);
+        }
+        self.events.push_back(Event::Play(player, card));
+        let Some((leader, lead)) = self.lead.take() else {
+            self.lead = Some((player, card));
+            self.turn = player.next(PLAYERS);
+            return Ok(());
+        };
+        let winner = if beats(self.trump, lead, card) {
+            player
+        } else {
+            leader
+        };
+        self.taken[winner.index()].extend([lead, card]);
+        self.events.push_back(Event::Trick(winner, [lead, card]));
+        self.turn = winner;
+        if !self.is_endgame() {
+            self.declarer = Some(winner);
+        } else if self.hands.iter().all(Vec::is_empty) {
+            self.last_trick = Some(winner);
+        }
+        Ok(())
+    }
+
+    fn meld(&mut self, meld: Meld) -> Result<(), BeziqueError> {
+        let player = self.declarer.expect("trick is won");
+        let (hand, table) = (
+            &mut self.hands[player.index()],
+            &mut self.tables[player.index()],
+        );
+        let sources =
+            select(meld, self.trump, hand, table).ok_or(BeziqueError::IllegalMeld(player, meld))?;
+        declare(meld, &sources, hand, table);
+        let points = meld.points(self.trump);
+        self.melds[player.index()] += points;
+        self.events.push_back(Event::Meld(player, meld, points));
+        Ok(())
+    }
+
+    fn exchange(&mut self) -> Result<(), BeziqueError> {
+        let player = self.declarer.expect("trick is won");
+        if !self.can_exchange(player) {
+            return Err(BeziqueError::IllegalExchange(player));
+        }
+        let seven = Card::new(Rank::Seven, self.trump);
+        let hand = &mut self.hands[player.index()];
+        let pos = hand
+            .iter()
+            .position(|&c| c == seven)
+            .expect("seven in hand");
+        hand.remove(pos);
+        hand.extend(self.turn_up.replace(seven));
+        self.melds[player.index()] += 10;
+        self.events.push_back(Event::Exchange(player));
+        Ok(())
+    }
+
+    /// Draws a card for the winner of the trick and then the loser, and once the stock is
+    /// exhausted, returns the melded cards to the players' hands.
+    fn draw(&mut self) {
+        let winner = self.declarer.take().expect("trick is won");
+        for player in [winner, winner.next(PLAYERS)] {
+            let card = self.stock.draw().or_else(|| self.turn_up.take());
+            self.hands[player.index()].extend(card);
+        }
+        if self.is_endgame() {
+            for (hand, table) in self.hands.iter_mut().zip(&mut self.tables) {
+                hand.extend(table.drain(..).map(|m| m.card));
+            }
+            self.events.push_back(Event::Endgame);
+        }
+    }
+
+    /// The result of the deal, once it's over.
+    pub fn result(&self) -> Option<DealResult> {
+        let last_trick = self.last_trick?;
+        let brisques = self
+            .taken
+            .iter()
+            .map(|cards| 10 * cards.iter().filter(|&&c| is_brisque(c)).count() as u32)
+            .collect();
+        Some(DealResult {
+            melds: self.melds.clone(),
+            brisques,
+            last_trick,
+        })
+    }
+}
+
+impl crate::engine::Engine for Deal {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = DealView<'a>;
+    type Event = Event;
+    type Outcome = DealResult;
+    type Error = BeziqueError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> DealView<'_> {
+        Deal::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Deal::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), BeziqueError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<DealResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a deal: the order of the deck, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawDeal {
+    config: DealConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Deal> for RawDeal {
+    fn from(deal: &Deal) -> Self {
+        Self {
+            config: deal.config.clone(),
+            actions: deal.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawDeal> for Deal {
+    type Error = BeziqueError;
+
+    fn try_from(raw: RawDeal) -> Result<Self, BeziqueError> {
+        raw.config.validate()?;
+        let mut deal = Deal::from(raw.config);
+        for action in raw.actions {
+            deal.apply_action(action)?;
+        }
+        deal.events.clear();
+        Ok(deal)
+    }
+}
@@ -0,0 +1,190 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+fn play(deal: &mut Deal, s: &str) {
+    deal.apply_action(Action::Play(card(s))).unwrap();
+}
+
+/// Stacks the deck so that the non-dealer, Player 0, and the dealer, Player 1, are dealt the
+/// hands, followed by the turned-up card and the top of the stock.
+fn stacked(hands: [&str; 2], turn_up: &str, stock: &str) -> DealConfig {
+    let dealt: Vec<Card> = cards(hands[0])
+        .into_iter()
+        .chain(cards(hands[1]))
+        .chain([card(turn_up)])
+        .chain(cards(stock))
+        .collect();
+    let mut deck: Vec<Card> = super::deck().into_iter().collect();
+    for card in &dealt {
+        let pos = deck.iter().position(|c| c == card).unwrap();
+        deck.remove(pos);
+    }
+    deck.extend(dealt.into_iter().rev());
+    DealConfig::new(Player(1), deck).unwrap()
+}
+
+fn config() -> DealConfig {
+    stacked(
+        ["K♡ Q♡ A♤ T♤ 7♡ 8♣ 9♦ Q♤", "J♦ A♣ T♣ 9♡ 8♡ 7♣ Q♦ K♦"],
+        "J♡",
+        "A♡ T♦ 9♤ 8♤",
+    )
+}
+
+#[test]
+fn test_invalid_deck() {
+    let mut deck: Vec<Card> = super::deck().into_iter().collect();
+    deck.pop();
+    deck.push(deck[0]);
+    let result = DealConfig::new(Player(0), deck);
+    assert!(matches!(result, Err(BeziqueError::InvalidDeck)));
+}
+
+#[test]
+fn test_deal() {
+    let mut deal = Deal::from(config());
+    assert_eq!(deal.trump(), Suit::Heart);
+    assert_eq!(deal.hand(Player(0)), cards("K♡ Q♡ A♤ T♤ 7♡ 8♣ 9♦ Q♤"));
+    assert_eq!(deal.view(Player(0)).stock, 47);
+    assert_eq!(deal.pop_event(), Some(Event::TurnUp(card("J♡"))));
+    assert_eq!(deal.phase(), Phase::Lead);
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+
+    // A seven turned up scores 10 for the dealer.
+    let deal = Deal::from(stacked(
+        ["K♡ Q♡ A♤ T♤ 7♡ 8♣ 9♦ Q♤", "J♦ A♣ T♣ 9♡ 8♡ 7♣ Q♦ K♦"],
+        "7♣",
+        "",
+    ));
+    assert_eq!(deal.view(Player(0)).melds, [0, 10]);
+}
+
+#[test]
+fn test_meld() {
+    let mut deal = Deal::from(config());
+    play(&mut deal, "8♣");
+    assert_eq!(deal.phase(), Phase::Follow);
+    play(&mut deal, "A♣");
+    assert_eq!(deal.phase(), Phase::Declare);
+    assert_eq!(deal.next_actor(), Some(Player(1)));
+    assert_eq!(
+        deal.legal_actions(),
+        [Action::Draw, Action::Meld(Meld::Marriage(Suit::Diamond))]
+    );
+    let result = deal.apply_action(Action::Meld(Meld::Bezique));
+    assert!(matches!(result, Err(BeziqueError::IllegalMeld(..))));
+    deal.apply_action(Action::Meld(Meld::Marriage(Suit::Diamond)))
+        .unwrap();
+    assert_eq!(deal.view(Player(1)).melds, [0, 20]);
+    assert_eq!(deal.hand(Player(0)), cards("K♡ Q♡ A♤ T♤ 7♡ 9♦ Q♤ T♦"));
+    assert_eq!(deal.hand(Player(1)), cards("J♦ T♣ 9♡ 8♡ 7♣ A♡"));
+    let table: Vec<Card> = deal.table(Player(1)).iter().map(|m| m.card).collect();
+    assert_eq!(table, cards("K♦ Q♦"));
+    let events: Vec<Event> = std::iter::from_fn(|| deal.pop_event()).skip(1).collect();
+    assert_eq!(
+        events,
+        [
+            Event::Play(Player(0), card("8♣")),
+            Event::Play(Player(1), card("A♣")),
+            Event::Trick(Player(1), [card("8♣"), card("A♣")]),
+            Event::Meld(Player(1), Meld::Marriage(Suit::Diamond), 20),
+        ]
+    );
+
+    // Cards on the table may be played, and the winner leads.
+    assert_eq!(deal.next_actor(), Some(Player(1)));
+    assert!(deal.legal_actions().contains(&Action::Play(card("K♦"))));
+    play(&mut deal, "K♦");
+    assert!(deal.table(Player(1)).iter().all(|m| m.card != card("K♦")));
+}
+
+#[test]
+fn test_exchange() {
+    let mut deal = Deal::from(config());
+    play(&mut deal, "8♣");
+    play(&mut deal, "7♣");
+    assert_eq!(
+        deal.legal_actions(),
+        [
+            Action::Draw,
+            Action::Exchange,
+            Action::Meld(Meld::Seven),
+            Action::Meld(Meld::Marriage(Suit::Heart))
+        ]
+    );
+    deal.apply_action(Action::Exchange).unwrap();
+    assert_eq!(deal.view(Player(0)).melds, [10, 0]);
+    assert_eq!(deal.view(Player(0)).turn_up, Some(card("7♡")));
+    assert_eq!(deal.hand(Player(0)), cards("K♡ Q♡ A♤ T♤ 9♦ Q♤ J♡ A♡"));
+
+    // The winner of the next trick can't exchange again.
+    play(&mut deal, "9♦");
+    play(&mut deal, "Q♦");
+    assert_eq!(deal.next_actor(), Some(Player(1)));
+    assert!(!deal.legal_actions().contains(&Action::Exchange));
+    let result = deal.apply_action(Action::Exchange);
+    assert!(matches!(result, Err(BeziqueError::IllegalExchange(_))));
+}
+
+#[test]
+fn test_endgame() {
+    let mut deal = Deal::from(config());
+    while deal.stock.draw().is_some() {}
+    deal.turn_up = None;
+    deal.hands = vec![cards("A♤ 9♣"), cards("T♤ K♤ 8♡ 7♦")];
+    assert!(deal.is_endgame());
+
+    // Players must follow suit, even when they can't win.
+    play(&mut deal, "A♤");
+    assert_eq!(
+        deal.legal_actions(),
+        [Action::Play(card("T♤")), Action::Play(card("K♤"))]
+    );
+    let result = deal.apply_action(Action::Play(card("8♡")));
+    assert!(matches!(result, Err(BeziqueError::IllegalPlay(..))));
+    let result = deal.apply_action(Action::Play(card("Q♣")));
+    assert!(matches!(result, Err(BeziqueError::CardNotHeld(..))));
+    play(&mut deal, "K♤");
+
+    // And trump when they can't follow, with no chance to meld.
+    assert_eq!(deal.phase(), Phase::Lead);
+    play(&mut deal, "9♣");
+    assert_eq!(deal.legal_actions(), [Action::Play(card("8♡"))]);
+}
+
+#[test]
+fn test_play_out() {
+    let mut rng = StdRng::seed_from_u64(15);
+    for i in 0..20 {
+        let mut deal = Deal::from(DealConfig::random(Player(i % 2), &mut rng));
+        let result = deal
+            .play_out(|_, _, legal| legal[0])
+            .unwrap()
+            .expect("deal is over");
+        // Every ace and ten is taken.
+        assert_eq!(result.brisques.iter().sum::<u32>(), 160);
+        assert!(deal.hands.iter().all(Vec::is_empty));
+        assert!(deal.tables.iter().all(Vec::is_empty));
+    }
+}
+
+#[test]
+fn test_raw_deal() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut deal = Deal::from(DealConfig::random(Player(0), &mut rng));
+    deal.play_out(|_, _, legal| *legal.last().unwrap()).unwrap();
+    let raw = RawDeal::from(&deal);
+    let json = serde_json::to_string(&raw).unwrap();
+    let raw: RawDeal = serde_json::from_str(&json).unwrap();
+    let mut copy = Deal::try_from(raw).unwrap();
+    assert_eq!(copy.pop_event(), None);
+    assert_eq!(copy.result(), deal.result());
+}
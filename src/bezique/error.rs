@@ -0,0 +1,29 @@
+//! Errors
+
+use super::{Action, Card, Meld, Player};
+
+/// An error that can occur during a deal.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BeziqueError {
+    /// The deck isn't two copies of the 32-card deck.
+    #[error("the deck must hold two copies of each card from the sevens up to the aces")]
+    InvalidDeck,
+    /// The action isn't expected at this point in the deal.
+    #[error("unexpected action: {0}")]
+    OutOfTurn(Action),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The player must follow suit, and win the trick if they can, once the stock is exhausted.
+    #[error("{0} cannot play {1} to this trick")]
+    IllegalPlay(Player, Card),
+    /// The player doesn't hold the cards for the meld, or they've all been melded this way.
+    #[error("{0} cannot meld {1}")]
+    IllegalMeld(Player, Meld),
+    /// The player doesn't hold the seven of trumps, or the turned-up card is gone.
+    #[error("{0} cannot exchange for the turned-up card")]
+    IllegalExchange(Player),
+    /// The deal is over, no more actions are expected.
+    #[error("deal is over")]
+    DealOver,
+}
@@ -0,0 +1,202 @@
+//! Melds, and the cards laid on the table for them.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Rank, Suit};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// A combination of cards that may be declared after winning a trick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Meld {
+    /// The seven of trumps.
+    Seven,
+    /// The king and queen of the suit.
+    Marriage(Suit),
+    /// The queen of spades and the jack of diamonds.
+    Bezique,
+    /// Four cards of the rank, which must be an ace, king, queen or jack.
+    Four(Rank),
+    /// The ace, ten, king, queen and jack of trumps.
+    Sequence,
+    /// Both queens of spades and both jacks of diamonds.
+    DoubleBezique,
+}
+
+impl Meld {
+    /// Every meld, from the least valuable to the most.
+    pub fn all() -> Vec<Meld> {
+        std::iter::once(Meld::Seven)
+            .chain(Suit::all_suits().iter().copied().map(Meld::Marriage))
+            .chain([Meld::Bezique])
+            .chain(
+                [Rank::Jack, Rank::Queen, Rank::King, Rank::Ace]
+                    .iter()
+                    .copied()
+                    .map(Meld::Four),
+            )
+            .chain([Meld::Sequence, Meld::DoubleBezique])
+            .collect()
+    }
+
+    /// The points scored for the meld.
+    pub fn points(self, trump: Suit) -> u32 {
+        match self {
+            Meld::Seven => 10,
+            Meld::Marriage(suit) if suit == trump => 40,
+            Meld::Marriage(_) => 20,
+            Meld::Bezique => 40,
+            Meld::Four(Rank::Ace) => 100,
+            Meld::Four(Rank::King) => 80,
+            Meld::Four(Rank::Queen) => 60,
+            Meld::Four(_) => 40,
+            Meld::Sequence => 250,
+            Meld::DoubleBezique => 500,
+        }
+    }
+
+    /// Returns true if a card declared in this meld can't be declared again in the other. Marriages
+    /// are all of a kind, and a marriage can't be taken out of a sequence, though a sequence may be
+    /// built on a royal marriage.
+    fn excludes(self, other: Meld) -> bool {
+        match (self, other) {
+            (Meld::Marriage(_), Meld::Marriage(_)) | (Meld::Sequence, Meld::Marriage(_)) => true,
+            _ => self == other,
+        }
+    }
+
+    /// The cards that make up the meld, or `None` for each card that may be of any suit.
+    fn slots(self, trump: Suit) -> Vec<Option<Card>> {
+        let card = |rank, suit| Some(Card::new(rank, suit));
+        let bezique = [
+            card(Rank::Queen, Suit::Spade),
+            card(Rank::Jack, Suit::Diamond),
+        ];
+        match self {
+            Meld::Seven => vec![card(Rank::Seven, trump)],
+            Meld::Marriage(suit) => vec![card(Rank::King, suit), card(Rank::Queen, suit)],
+            Meld::Bezique => bezique.to_vec(),
+            Meld::Four(_) => vec![None; 4],
+            Meld::Sequence => [Rank::Ace, Rank::Ten, Rank::King, Rank::Queen, Rank::Jack]
+                .iter()
+                .map(|&rank| card(rank, trump))
+                .collect(),
+            Meld::DoubleBezique => bezique.iter().chain(&bezique).copied().collect(),
+        }
+    }
+
+    /// Returns true if the card may fill the slot.
+    fn fits(self, slot: Option<Card>, card: Card) -> bool {
+        match (self, slot) {
+            (_, Some(slot)) => slot == card,
+            (Meld::Four(rank), None) => card.rank() == Some(rank),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Meld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Meld::Seven => f.write_str(&tr!("bezique-seven")),
+            Meld::Marriage(suit) => f.write_str(&tr!("bezique-marriage", suit = suit.to_string())),
+            Meld::Bezique => f.write_str(&tr!("bezique-bezique")),
+            Meld::Four(rank) => {
+                let rank = match rank {
+                    Rank::Ace => "ace",
+                    Rank::King => "king",
+                    Rank::Queen => "queen",
+                    _ => "jack",
+                };
+                f.write_str(&tr!("bezique-four", rank = rank))
+            }
+            Meld::Sequence => f.write_str(&tr!("bezique-sequence")),
+            Meld::DoubleBezique => f.write_str(&tr!("bezique-double-bezique")),
+        }
+    }
+}
+
+/// A card on the table, and the melds it's been declared in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Melded {
+    /// The card.
+    pub card: Card,
+    /// The melds the card has been declared in.
+    pub melds: Vec<Meld>,
+}
+
+impl Melded {
+    /// Returns true if the card may yet be declared in the meld.
+    fn can_meld(&self, meld: Meld) -> bool {
+        !self.melds.iter().any(|&m| m.excludes(meld))
+    }
+}
+
+/// Where a card for a meld comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Source {
+    /// A card from the hand.
+    Hand(Card),
+    /// The card on the table at the index.
+    Table(usize),
+}
+
+/// Selects the cards for the meld, preferring those already on the table so as to keep the
+/// hand concealed, but taking at least one from the hand. Returns `None` if the player can't
+/// declare the meld.
+pub(crate) fn select(
+    meld: Meld,
+    trump: Suit,
+    hand: &[Card],
+    table: &[Melded],
+) -> Option<Vec<Source>> {
+    let slots = meld.slots(trump);
+    let mut taken = vec![false; table.len()];
+    let mut remaining = hand.to_vec();
+    let mut sources = vec![];
+    for &slot in &slots {
+        let on_table = (0..table.len())
+            .find(|&i| !taken[i] && table[i].can_meld(meld) && meld.fits(slot, table[i].card));
+        if let Some(i) = on_table {
+            taken[i] = true;
+            sources.push(Source::Table(i));
+        } else {
+            let pos = remaining.iter().position(|&c| meld.fits(slot, c))?;
+            sources.push(Source::Hand(remaining.remove(pos)));
+        }
+    }
+    if !sources.iter().any(|s| matches!(s, Source::Hand(_))) {
+        let (k, card) = slots.iter().enumerate().find_map(|(k, &slot)| {
+            let card = remaining.iter().copied().find(|&c| meld.fits(slot, c))?;
+            Some((k, card))
+        })?;
+        sources[k] = Source::Hand(card);
+    }
+    Some(sources)
+}
+
+/// Lays the cards for the meld on the table, moving them from the hand as needed.
+pub(crate) fn declare(
+    meld: Meld,
+    sources: &[Source],
+    hand: &mut Vec<Card>,
+    table: &mut Vec<Melded>,
+) {
+    for &source in sources {
+        match source {
+            Source::Table(i) => table[i].melds.push(meld),
+            Source::Hand(card) => {
+                let pos = hand.iter().position(|&c| c == card).expect("card in hand");
+                hand.remove(pos);
+                table.push(Melded {
+                    card,
+                    melds: vec![meld],
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::*;
+use crate::french::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_points() {
+    assert_eq!(Meld::all().len(), 12);
+    assert_eq!(Meld::Marriage(Suit::Heart).points(Suit::Heart), 40);
+    assert_eq!(Meld::Marriage(Suit::Club).points(Suit::Heart), 20);
+    assert_eq!(Meld::Four(Rank::Ace).points(Suit::Heart), 100);
+    assert_eq!(Meld::Four(Rank::Jack).points(Suit::Heart), 40);
+    assert_eq!(Meld::DoubleBezique.to_string(), "Double bezique");
+    assert_eq!(Meld::Four(Rank::King).to_string(), "Four kings");
+}
+
+#[test]
+fn test_select() {
+    let hand = cards("K♡ Q♡ Q♤ 7♣ K♡");
+    assert_eq!(
+        select(Meld::Marriage(Suit::Heart), Suit::Club, &hand, &[]),
+        Some(vec![Source::Hand(card("K♡")), Source::Hand(card("Q♡"))])
+    );
+    assert_eq!(
+        select(Meld::Marriage(Suit::Spade), Suit::Club, &hand, &[]),
+        None
+    );
+    assert_eq!(select(Meld::Bezique, Suit::Club, &hand, &[]), None);
+    assert_eq!(
+        select(Meld::Seven, Suit::Club, &hand, &[]),
+        Some(vec![Source::Hand(card("7♣"))])
+    );
+    assert_eq!(select(Meld::Seven, Suit::Heart, &hand, &[]), None);
+}
+
+#[test]
+fn test_declare() {
+    let mut hand = cards("K♤ Q♤ J♦ K♦ K♣ 8♡");
+    let mut table = vec![];
+    let sources = select(Meld::Marriage(Suit::Spade), Suit::Spade, &hand, &table).unwrap();
+    declare(Meld::Marriage(Suit::Spade), &sources, &mut hand, &mut table);
+    assert_eq!(hand, cards("J♦ K♦ K♣ 8♡"));
+    assert_eq!(table.len(), 2);
+
+    // The queen of spades may go on to make a bezique, taking the jack from the hand.
+    let sources = select(Meld::Bezique, Suit::Spade, &hand, &table).unwrap();
+    assert_eq!(sources, [Source::Table(1), Source::Hand(card("J♦"))]);
+    declare(Meld::Bezique, &sources, &mut hand, &mut table);
+    assert_eq!(hand, cards("K♦ K♣ 8♡"));
+    assert_eq!(table[1].melds, [Meld::Marriage(Suit::Spade), Meld::Bezique]);
+
+    // But not into another marriage, and every meld needs a card from the hand.
+    table.push(Melded {
+        card: card("Q♦"),
+        melds: vec![Meld::Marriage(Suit::Diamond)],
+    });
+    assert_eq!(select(Meld::Bezique, Suit::Spade, &hand, &table), None);
+    assert!(select(Meld::Marriage(Suit::Diamond), Suit::Spade, &hand, &table).is_none());
+    assert_eq!(
+        select(Meld::Four(Rank::King), Suit::Spade, &hand, &table),
+        None
+    );
+    hand.push(card("K♡"));
+    assert_eq!(
+        select(Meld::Four(Rank::King), Suit::Spade, &hand, &table),
+        Some(vec![
+            Source::Table(0),
+            Source::Hand(card("K♦")),
+            Source::Hand(card("K♣")),
+            Source::Hand(card("K♡"))
+        ])
+    );
+}
+
+#[test]
+fn test_sequence() {
+    let mut hand = cards("A♡ T♡ J♡ 9♣");
+    let mut table = vec![
+        Melded {
+            card: card("K♡"),
+            melds: vec![Meld::Marriage(Suit::Heart)],
+        },
+        Melded {
+            card: card("Q♡"),
+            melds: vec![Meld::Marriage(Suit::Heart)],
+        },
+    ];
+    // A royal marriage may be extended to a sequence...
+    let sources = select(Meld::Sequence, Suit::Heart, &hand, &table).unwrap();
+    declare(Meld::Sequence, &sources, &mut hand, &mut table);
+    assert_eq!(hand, cards("9♣"));
+    assert_eq!(table.len(), 5);
+
+    // ...but a marriage can't be taken out of a sequence.
+    let mut hand = cards("A♡ T♡ K♡ Q♡ J♡ K♡");
+    let mut table = vec![];
+    let sources = select(Meld::Sequence, Suit::Heart, &hand, &table).unwrap();
+    declare(Meld::Sequence, &sources, &mut hand, &mut table);
+    assert_eq!(hand, cards("K♡"));
+    assert_eq!(
+        select(Meld::Marriage(Suit::Heart), Suit::Heart, &hand, &table),
+        None
+    );
+}
@@ -0,0 +1,80 @@
+//! A robot player.
+//!
+//! After winning a trick, the robot declares its most valuable meld, unless it can exchange the
+//! seven of trumps for something better. While the stock lasts, it keeps its trumps, brisques and
+//! court cards for melding, and only contests tricks worth taking. In the endgame, it leads its
+//! highest cards and follows as cheaply as the rules allow.
+
+use super::{beats, is_brisque, rank_order, Action, Card, DealView, Meld, Phase};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &DealView, legal: &[Action]) -> Action {
+        let choice = match view.phase {
+            Phase::Declare => Some(choose_declaration(view, legal)),
+            Phase::Lead | Phase::Follow => {
+                let cards: Vec<Card> = legal
+                    .iter()
+                    .filter_map(|a| match a {
+                        Action::Play(card) => Some(*card),
+                        _ => None,
+                    })
+                    .collect();
+                choose_card(view, &cards).map(Action::Play)
+            }
+            Phase::Over => None,
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0])
+    }
+}
+
+/// Declares the most valuable meld, or exchanges the seven of trumps if no meld is worth more.
+fn choose_declaration(view: &DealView, legal: &[Action]) -> Action {
+    let best = legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Meld(meld) => Some(*meld),
+            _ => None,
+        })
+        .max_by_key(|m| m.points(view.trump));
+    let exchange = legal.contains(&Action::Exchange);
+    match best {
+        Some(meld) if !exchange || meld.points(view.trump) > Meld::Seven.points(view.trump) => {
+            Action::Meld(meld)
+        }
+        _ if exchange => Action::Exchange,
+        _ => Action::Draw,
+    }
+}
+
+/// Chooses a card to play.
+fn choose_card(view: &DealView, legal: &[Card]) -> Option<Card> {
+    // The cost of giving up a card, while the stock lasts.
+    let cost = |&c: &Card| (c.suit() == Some(view.trump), is_brisque(c), rank_order(c));
+    let strength = |&c: &Card| (c.suit() == Some(view.trump), rank_order(c));
+    match (view.lead, view.is_endgame()) {
+        (None, false) => legal.iter().copied().min_by_key(cost),
+        (None, true) => legal.iter().copied().max_by_key(|c| rank_order(*c)),
+        (Some(_), true) => legal.iter().copied().min_by_key(strength),
+        (Some(lead), false) => {
+            let (winners, losers): (Vec<Card>, Vec<Card>) = legal
+                .iter()
+                .copied()
+                .partition(|&c| beats(view.trump, lead, c));
+            if (is_brisque(lead) || losers.is_empty()) && !winners.is_empty() {
+                winners.into_iter().min_by_key(cost)
+            } else {
+                losers.into_iter().min_by_key(cost)
+            }
+        }
+    }
+}
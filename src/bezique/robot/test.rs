@@ -0,0 +1,43 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::bezique::{Deal, DealConfig, Player, Rank, Suit};
+use crate::engine::Engine;
+
+#[test]
+fn test_choose_declaration() {
+    let deal = Deal::from(DealConfig::random(Player(0), &mut StdRng::seed_from_u64(1)));
+    let view = deal.view(Player(0));
+    let trump = view.trump;
+    let marriage = Action::Meld(Meld::Marriage(trump));
+    let legal = [Action::Draw, Action::Exchange, Action::Meld(Meld::Seven)];
+    assert_eq!(choose_declaration(&view, &legal), Action::Exchange);
+    let legal = [Action::Draw, Action::Exchange, marriage];
+    assert_eq!(choose_declaration(&view, &legal), marriage);
+    let legal = [
+        Action::Draw,
+        Action::Meld(Meld::Marriage(Suit::Club)),
+        Action::Meld(Meld::Four(Rank::Ace)),
+    ];
+    assert_eq!(choose_declaration(&view, &legal), legal[2]);
+    assert_eq!(choose_declaration(&view, &[Action::Draw]), Action::Draw);
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(8);
+    let mut melds = 0;
+    for i in 0..50 {
+        let config = DealConfig::random(Player(i % 2), &mut rng);
+        let mut deal = Deal::from(config);
+        let result = deal
+            .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+            .unwrap()
+            .expect("deal is over");
+        assert_eq!(result.brisques.iter().sum::<u32>(), 160);
+        melds += result.melds.iter().sum::<u32>();
+    }
+    // The robots find a meld or two each deal.
+    assert!(melds > 50 * 100, "{}", melds);
+}
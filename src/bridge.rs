@@ -0,0 +1,100 @@
+//! Contract bridge.
+//!
+//! Rubber bridge is played by two partnerships, North/South and East/West, with the standard
+//! 52-card deck. Each deal starts with an auction, in which the players bid for the right to name
+//! the trump suit, or no trump. The side that wins the auction must take the number of tricks it
+//! bid, with one of its players, the declarer, playing both their own hand and their partner's,
+//! which is exposed on the table as the dummy.
+
+mod auction;
+mod call;
+mod deal;
+mod error;
+mod robot;
+mod scoring;
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+pub use self::auction::Auction;
+pub use self::call::{Bid, Call, Contract, Doubling, Strain};
+pub use self::deal::{Action, Deal, DealConfig, DealResult, DealView, Event, RawDeal, Trick};
+pub use self::error::BridgeError;
+pub use self::robot::Robot;
+pub use self::scoring::{honors, Rubber, Score};
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::euchre::{Seat, SeatMap, Team, TeamMap};
+pub use crate::french::{Card, Rank, Suit};
+use crate::tr;
+
+/// Suits in the order in which hands are displayed.
+const DISPLAY_SUITS: [Suit; 4] = [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club];
+
+/// Returns the suit of a card. Bridge is played without jokers.
+fn suit_of(card: Card) -> Suit {
+    card.suit().expect("bridge is played without jokers")
+}
+
+/// Returns the rank of a card, in ace-high order.
+fn rank_of(card: Card) -> u8 {
+    card.rank()
+        .expect("bridge is played without jokers")
+        .ace_high()
+}
+
+/// Formats a hand by suit, from spades down to clubs, with each suit's cards from highest to
+/// lowest (e.g., `♤ AK3 ♡ Q72 ♦ - ♣ JT9852`).
+pub fn format_hand(cards: &[Card]) -> String {
+    let mut s = String::new();
+    for (i, &suit) in DISPLAY_SUITS.iter().enumerate() {
+        if i != 0 {
+            s.push(' ');
+        }
+        write!(s, "{suit} ").expect("write to string");
+        let ranks: String = cards
+            .iter()
+            .filter(|&&c| suit_of(c) == suit)
+            .sorted_by_key(|&&c| std::cmp::Reverse(rank_of(c)))
+            .filter_map(|c| c.rank())
+            .map(|r| r.to_string())
+            .collect();
+        if ranks.is_empty() {
+            s.push('-');
+        } else {
+            s.push_str(&ranks);
+        }
+    }
+    s
+}
+
+/// Plays a rubber with a simple command-line interface, with the user sitting South and robots
+/// everywhere else. The record of every deal is written to stderr when the rubber is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut rubber = Rubber::new();
+    let mut dealer: Seat = rand::random();
+    let mut deals = vec![];
+    let my_seat = Seat::South;
+    console.println(tr!("game-you-are-help", player = my_seat.to_string()))?;
+    while !rubber.is_over() {
+        let config = DealConfig::random(dealer, rubber.vulnerability(), &mut rng);
+        let mut deal = Deal::from(config);
+        let result = console
+            .play(&mut deal, my_seat, |deal, seat, _| {
+                robot.choose(&deal.view(seat))
+            })?
+            .expect("deal is over");
+        rubber.record(&result.score);
+        deals.push(RawDeal::from(&deal));
+        console.println(&rubber)?;
+        dealer = dealer.next();
+    }
+    let winner = rubber.winner().expect("rubber is over");
+    console.println(tr!("bridge-rubber-won", team = winner.to_string()))?;
+    serde_json::to_writer(std::io::stderr(), &deals)?;
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! The auction.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Bid, BridgeError, Call, Contract, Doubling, Seat};
+
+#[cfg(test)]
+mod test;
+
+/// The sequence of calls made during the auction, starting with the dealer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Auction {
+    dealer: Seat,
+    calls: Vec<Call>,
+}
+
+impl Auction {
+    /// Creates an empty auction.
+    pub fn new(dealer: Seat) -> Self {
+        Self {
+            dealer,
+            calls: vec![],
+        }
+    }
+
+    /// The player who made the first call.
+    pub fn dealer(&self) -> Seat {
+        self.dealer
+    }
+
+    /// The calls made so far.
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Returns an iterator over the calls made so far, with the seats that made them.
+    pub fn iter(&self) -> impl Iterator<Item = (Seat, Call)> + '_ {
+        let mut seat = self.dealer;
+        self.calls.iter().map(move |&call| {
+            let caller = seat;
+            seat = seat.next();
+            (caller, call)
+        })
+    }
+
+    /// Returns true once the auction is over: after four passes to start, or three passes in a
+    /// row after a bid.
+    pub fn is_complete(&self) -> bool {
+        let len = self.calls.len();
+        len >= 4 && self.calls[len - 3..].iter().all(|&c| c == Call::Pass)
+    }
+
+    /// Returns true if the auction ended without a bid.
+    pub fn is_passed_out(&self) -> bool {
+        self.is_complete() && self.last_bid().is_none()
+    }
+
+    /// The player expected to call next, or `None` if the auction is over.
+    pub fn next_seat(&self) -> Option<Seat> {
+        if self.is_complete() {
+            return None;
+        }
+        let mut seat = self.dealer;
+        for _ in 0..self.calls.len() % 4 {
+            seat = seat.next();
+        }
+        Some(seat)
+    }
+
+    /// The highest bid so far, and the player who made it.
+    pub fn last_bid(&self) -> Option<(Seat, Bid)> {
+        self.iter().fold(None, |last, (seat, call)| match call {
+            Call::Bid(bid) => Some((seat, bid)),
+            _ => last,
+        })
+    }
+
+    /// Whether the highest bid has been doubled or redoubled.
+    pub fn doubling(&self) -> Doubling {
+        let mut doubling = Doubling::Undoubled;
+        for call in &self.calls {
+            doubling = match call {
+                Call::Pass => doubling,
+                Call::Double => Doubling::Doubled,
+                Call::Redouble => Doubling::Redoubled,
+                Call::Bid(_) => Doubling::Undoubled,
+            };
+        }
+        doubling
+    }
+
+    /// The last call other than a pass, and the player who made it.
+    fn last_action(&self) -> Option<(Seat, Call)> {
+        self.iter().filter(|&(_, call)| call != Call::Pass).last()
+    }
+
+    /// Returns true if the next player may make the specified call.
+    pub fn is_legal(&self, call: Call) -> bool {
+        let Some(seat) = self.next_seat() else {
+            return false;
+        };
+        let last = self.last_action();
+        let by_opponent = last.is_some_and(|(s, _)| s.team() != seat.team());
+        match call {
+            Call::Pass => true,
+            Call::Double => by_opponent && matches!(last, Some((_, Call::Bid(_)))),
+            Call::Redouble => by_opponent && matches!(last, Some((_, Call::Double))),
+            Call::Bid(bid) => {
+                (1..=7).contains(&bid.level) && self.last_bid().is_none_or(|(_, b)| bid > b)
+            }
+        }
+    }
+
+    /// Returns every call the next player may make, starting with a pass, and then doubles and
+    /// bids from lowest to highest.
+    pub fn legal_calls(&self) -> Vec<Call> {
+        if self.is_complete() {
+            return vec![];
+        }
+        [Call::Pass, Call::Double, Call::Redouble]
+            .iter()
+            .copied()
+            .chain(Bid::all_bids().map(Call::Bid))
+            .filter(|&call| self.is_legal(call))
+            .collect()
+    }
+
+    /// Makes a call on behalf of the next player.
+    pub fn call(&mut self, call: Call) -> Result<(), BridgeError> {
+        if self.is_complete() {
+            return Err(BridgeError::AuctionOver);
+        }
+        if !self.is_legal(call) {
+            return Err(BridgeError::IllegalCall(call));
+        }
+        self.calls.push(call);
+        Ok(())
+    }
+
+    /// The final contract, once the auction is over. Returns `None` if the auction is still in
+    /// progress, or if it was passed out.
+    pub fn contract(&self) -> Option<Contract> {
+        if !self.is_complete() {
+            return None;
+        }
+        let (last, bid) = self.last_bid()?;
+        let declarer = self
+            .iter()
+            .find(|&(seat, call)| {
+                seat.team() == last.team() && matches!(call, Call::Bid(b) if b.strain == bid.strain)
+            })
+            .map(|(seat, _)| seat)
+            .expect("the last bid names the strain");
+        Some(Contract {
+            bid,
+            declarer,
+            doubling: self.doubling(),
+        })
+    }
+}
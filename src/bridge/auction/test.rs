@@ -0,0 +1,91 @@
+use super::*;
+use crate::bridge::Strain;
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+fn auction(dealer: Seat, calls: &[Call]) -> Auction {
+    let mut auction = Auction::new(dealer);
+    for &call in calls {
+        auction.call(call).unwrap();
+    }
+    auction
+}
+
+#[test]
+fn test_passed_out() {
+    let mut auction = auction(Seat::North, &[Call::Pass; 3]);
+    assert!(!auction.is_complete());
+    assert_eq!(auction.next_seat(), Some(Seat::West));
+    assert!(!auction.is_legal(Call::Double));
+    auction.call(Call::Pass).unwrap();
+    assert!(auction.is_passed_out());
+    assert_eq!(auction.next_seat(), None);
+    assert_eq!(auction.contract(), None);
+    assert!(matches!(
+        auction.call(Call::Pass),
+        Err(BridgeError::AuctionOver)
+    ));
+}
+
+#[test]
+fn test_legality() {
+    let auction = auction(Seat::North, &[bid(1, Strain::Heart)]);
+    assert!(auction.is_legal(Call::Double));
+    assert!(!auction.is_legal(Call::Redouble));
+    assert!(!auction.is_legal(bid(1, Strain::Diamond)));
+    assert!(auction.is_legal(bid(1, Strain::Spade)));
+    assert!(auction.is_legal(bid(1, Strain::NoTrump)));
+    assert!(!auction.is_legal(bid(8, Strain::Club)));
+    let legal = auction.legal_calls();
+    assert_eq!(
+        legal[..3],
+        [Call::Pass, Call::Double, bid(1, Strain::Spade)]
+    );
+    assert_eq!(legal.len(), 2 + 32);
+
+    // Partners can't double each other.
+    let auction = self::auction(Seat::North, &[bid(1, Strain::Heart), Call::Pass]);
+    assert!(!auction.is_legal(Call::Double));
+    let mut auction = self::auction(
+        Seat::North,
+        &[bid(1, Strain::Heart), Call::Pass, Call::Pass],
+    );
+    assert!(auction.is_legal(Call::Double));
+    auction.call(Call::Double).unwrap();
+    assert!(auction.is_legal(Call::Redouble));
+    assert!(!auction.is_legal(Call::Double));
+    auction.call(Call::Pass).unwrap();
+    assert!(!auction.is_legal(Call::Redouble));
+}
+
+#[test]
+fn test_contract() {
+    let auction = auction(
+        Seat::East,
+        &[
+            bid(1, Strain::Club),
+            bid(1, Strain::Heart),
+            bid(1, Strain::Spade),
+            Call::Pass,
+            bid(2, Strain::Spade),
+            Call::Pass,
+            bid(4, Strain::Spade),
+            Call::Double,
+            Call::Redouble,
+            Call::Pass,
+            Call::Pass,
+        ],
+    );
+    assert!(!auction.is_complete());
+    let mut auction = auction;
+    auction.call(Call::Pass).unwrap();
+    let contract = auction.contract().unwrap();
+    assert_eq!(contract.bid, Bid::new(4, Strain::Spade));
+    // West bid spades first, though East raised them.
+    assert_eq!(contract.declarer, Seat::West);
+    assert_eq!(contract.dummy(), Seat::East);
+    assert_eq!(contract.doubling, Doubling::Redoubled);
+    assert_eq!(contract.to_string(), "4♤XX by West");
+}
@@ -0,0 +1,203 @@
+//! Calls and contracts.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Seat, Suit};
+use crate::tr;
+
+/// The denomination of a bid: a trump suit, or no trump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Strain {
+    Club,
+    Diamond,
+    Heart,
+    Spade,
+    NoTrump,
+}
+
+impl Strain {
+    /// All strains, from lowest to highest.
+    pub fn all_strains() -> &'static [Strain; 5] {
+        static STRAINS: [Strain; 5] = [
+            Strain::Club,
+            Strain::Diamond,
+            Strain::Heart,
+            Strain::Spade,
+            Strain::NoTrump,
+        ];
+        &STRAINS
+    }
+
+    /// Returns the trump suit, or `None` for no trump.
+    pub fn trump(self) -> Option<Suit> {
+        match self {
+            Strain::Club => Some(Suit::Club),
+            Strain::Diamond => Some(Suit::Diamond),
+            Strain::Heart => Some(Suit::Heart),
+            Strain::Spade => Some(Suit::Spade),
+            Strain::NoTrump => None,
+        }
+    }
+
+    /// Returns true for clubs and diamonds.
+    pub fn is_minor(self) -> bool {
+        matches!(self, Strain::Club | Strain::Diamond)
+    }
+
+    /// Returns true for hearts and spades.
+    pub fn is_major(self) -> bool {
+        matches!(self, Strain::Heart | Strain::Spade)
+    }
+
+    /// The value of each trick bid and made, beyond the first.
+    pub fn trick_value(self) -> u32 {
+        if self.is_minor() {
+            20
+        } else {
+            30
+        }
+    }
+
+    /// The lowest level at which a contract in this strain scores game.
+    pub fn game_level(self) -> u8 {
+        match self {
+            Strain::Club | Strain::Diamond => 5,
+            Strain::Heart | Strain::Spade => 4,
+            Strain::NoTrump => 3,
+        }
+    }
+}
+
+impl From<Suit> for Strain {
+    fn from(suit: Suit) -> Self {
+        match suit {
+            Suit::Club => Strain::Club,
+            Suit::Diamond => Strain::Diamond,
+            Suit::Heart => Strain::Heart,
+            Suit::Spade => Strain::Spade,
+        }
+    }
+}
+
+impl Display for Strain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.trump() {
+            Some(suit) => write!(f, "{suit}"),
+            None => f.write_str(&tr!("bridge-no-trump")),
+        }
+    }
+}
+
+/// A bid to take a number of tricks in a strain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Bid {
+    /// The number of tricks over six, from 1 to 7.
+    pub level: u8,
+    /// The strain.
+    pub strain: Strain,
+}
+
+impl Bid {
+    /// Creates a new bid.
+    pub fn new(level: u8, strain: Strain) -> Self {
+        Self { level, strain }
+    }
+
+    /// Returns every bid, from lowest to highest.
+    pub fn all_bids() -> impl Iterator<Item = Bid> {
+        (1..=7).flat_map(|level| {
+            Strain::all_strains()
+                .iter()
+                .map(move |&strain| Bid::new(level, strain))
+        })
+    }
+
+    /// Returns true if this bid, if made, scores game on its own.
+    pub fn is_game(self) -> bool {
+        self.level >= self.strain.game_level()
+    }
+}
+
+impl Display for Bid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.level, self.strain)
+    }
+}
+
+/// A call made during the auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Call {
+    Pass,
+    Double,
+    Redouble,
+    Bid(Bid),
+}
+
+impl Display for Call {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Call::Pass => f.write_str(&tr!("action-pass")),
+            Call::Double => f.write_str("X"),
+            Call::Redouble => f.write_str("XX"),
+            Call::Bid(bid) => write!(f, "{bid}"),
+        }
+    }
+}
+
+/// Whether a contract has been doubled or redoubled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Doubling {
+    #[default]
+    Undoubled,
+    Doubled,
+    Redoubled,
+}
+
+impl Doubling {
+    /// The factor by which trick values are multiplied.
+    pub fn multiplier(self) -> u32 {
+        match self {
+            Doubling::Undoubled => 1,
+            Doubling::Doubled => 2,
+            Doubling::Redoubled => 4,
+        }
+    }
+}
+
+/// The final contract of an auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Contract {
+    /// The final bid.
+    pub bid: Bid,
+    /// The player who first named the strain on the declaring side.
+    pub declarer: Seat,
+    /// Whether the bid was doubled or redoubled.
+    pub doubling: Doubling,
+}
+
+impl Contract {
+    /// The seat whose hand is exposed after the opening lead.
+    pub fn dummy(&self) -> Seat {
+        self.declarer.opposite()
+    }
+
+    /// The number of tricks the declaring side must take.
+    pub fn target(&self) -> u8 {
+        self.bid.level + 6
+    }
+}
+
+impl Display for Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let doubling = match self.doubling {
+            Doubling::Undoubled => "",
+            Doubling::Doubled => "X",
+            Doubling::Redoubled => "XX",
+        };
+        let bid = format!("{}{doubling}", self.bid);
+        let declarer = self.declarer.to_string();
+        f.write_str(&tr!("bridge-contract", bid = bid, declarer = declarer))
+    }
+}
@@ -0,0 +1,645 @@
+//! A single deal: the auction, followed by the play of the contract.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::scoring::{honors, Score};
+use super::{
+    format_hand, rank_of, suit_of, Auction, BridgeError, Call, Card, Contract, Deck, Seat, SeatMap,
+    Suit, Team, TeamMap,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The cards dealt to each player, and the conditions under which they're played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealConfig {
+    dealer: Seat,
+    vulnerable: TeamMap<bool>,
+    hands: SeatMap<Vec<Card>>,
+}
+
+impl DealConfig {
+    /// Deals a shuffled deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Seat, vulnerable: TeamMap<bool>, rng: &mut R) -> Self {
+        let mut deck = Deck::standard();
+        deck.shuffle(rng);
+        let hands = SeatMap::from_fn(|_| deck.take(13));
+        Self {
+            dealer,
+            vulnerable,
+            hands,
+        }
+    }
+
+    /// Creates a deal from the specified hands, which must hold the 52 cards of the standard deck
+    /// between them, thirteen apiece.
+    pub fn from_hands(
+        dealer: Seat,
+        vulnerable: TeamMap<bool>,
+        hands: SeatMap<Vec<Card>>,
+    ) -> Result<Self, BridgeError> {
+        let config = Self {
+            dealer,
+            vulnerable,
+            hands,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that every card is dealt exactly once.
+    pub fn validate(&self) -> Result<(), BridgeError> {
+        let mut seen = vec![];
+        for (seat, hand) in self.hands.iter() {
+            if hand.len() != 13 {
+                return Err(BridgeError::InvalidHandSize(seat));
+            }
+            for &card in hand {
+                if card.is_joker() || seen.contains(&card) {
+                    return Err(BridgeError::InvalidCard(card));
+                }
+                seen.push(card);
+            }
+        }
+        Ok(())
+    }
+
+    /// The player who calls first.
+    pub fn dealer(&self) -> Seat {
+        self.dealer
+    }
+
+    /// Returns true if the team is vulnerable.
+    pub fn is_vulnerable(&self, team: Team) -> bool {
+        self.vulnerable[team]
+    }
+
+    /// The hand dealt to the player.
+    pub fn hand(&self, seat: Seat) -> &[Card] {
+        &self.hands[seat]
+    }
+}
+
+/// A call during the auction, or a card played to a trick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Call(Call),
+    Play(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Call(call) => write!(f, "{call}"),
+            Action::Play(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A trick.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trick {
+    /// The trump suit, if any.
+    pub trump: Option<Suit>,
+    /// The cards played so far, in order.
+    pub cards: Vec<(Seat, Card)>,
+}
+
+impl Trick {
+    /// Creates an empty trick.
+    pub fn new(trump: Option<Suit>) -> Self {
+        Self {
+            trump,
+            cards: vec![],
+        }
+    }
+
+    /// The suit that was led, if any.
+    pub fn lead_suit(&self) -> Option<Suit> {
+        self.cards.first().map(|&(_, card)| suit_of(card))
+    }
+
+    /// Returns true once all four players have played.
+    pub fn is_complete(&self) -> bool {
+        self.cards.len() == 4
+    }
+
+    /// The card winning the trick so far: the highest trump, or else the highest card of the
+    /// suit led.
+    pub fn winner(&self) -> Option<(Seat, Card)> {
+        let lead = self.lead_suit()?;
+        self.cards.iter().copied().max_by_key(|&(_, card)| {
+            let suit = suit_of(card);
+            let class = if Some(suit) == self.trump {
+                2
+            } else if suit == lead {
+                1
+            } else {
+                0
+            };
+            (class, rank_of(card))
+        })
+    }
+
+    /// Filters the hand down to the cards that may be played to this trick.
+    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
+        let Some(lead) = self.lead_suit() else {
+            return hand.to_vec();
+        };
+        let following: Vec<Card> = hand
+            .iter()
+            .copied()
+            .filter(|&c| suit_of(c) == lead)
+            .collect();
+        if following.is_empty() {
+            hand.to_vec()
+        } else {
+            following
+        }
+    }
+}
+
+impl Display for Trick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, (seat, card)) in self.cards.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}:{card}", seat.to_abbr())?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The result of a deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealResult {
+    /// The contract, or `None` if the deal was passed out.
+    pub contract: Option<Contract>,
+    /// The number of tricks taken by the declaring side.
+    pub tricks: u8,
+    /// The points scored.
+    pub score: Score,
+}
+
+impl Display for DealResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(contract) = self.contract else {
+            return f.write_str(&tr!("bridge-passed-out"));
+        };
+        let target = contract.target();
+        let contract = contract.to_string();
+        if self.tricks >= target {
+            let over = self.tricks - target;
+            if over > 0 {
+                f.write_str(&tr!("bridge-made-over", contract = contract, over = over))?;
+            } else {
+                f.write_str(&tr!("bridge-made", contract = contract))?;
+            }
+        } else {
+            let under = target - self.tricks;
+            f.write_str(&tr!("bridge-down", contract = contract, under = under))?;
+        }
+        for (team, _) in self.score.above.iter() {
+            let total = self.score.total(team);
+            if total > 0 {
+                write!(f, "; {} +{total}", team.to_abbr())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An event that occurs during the deal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    /// A player made a call.
+    Call(Seat, Call),
+    /// The auction is over, and the contract is set.
+    Contract(Contract),
+    /// The dummy's hand was exposed, after the opening lead.
+    Dummy(Seat, Vec<Card>),
+    /// The trick is over.
+    Trick(Trick),
+    /// The deal is over.
+    Over(DealResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Call(seat, call) => write!(f, "{seat}: {call}"),
+            Event::Contract(contract) => f.write_str(&tr!(
+                "bridge-contract-line",
+                contract = contract.to_string()
+            )),
+            Event::Dummy(seat, hand) => {
+                let (seat, hand) = (seat.to_string(), format_hand(hand));
+                f.write_str(&tr!("bridge-dummy", seat = seat, hand = hand))
+            }
+            Event::Trick(trick) => {
+                let winner = trick.winner().expect("trick is complete").0;
+                let (trick, winner) = (trick.to_string(), winner.to_string());
+                f.write_str(&tr!("game-trick-won", trick = trick, winner = winner))
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of a deal, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct DealView<'a> {
+    /// The player.
+    pub seat: Seat,
+    /// The player who called first.
+    pub dealer: Seat,
+    /// The vulnerability of each team.
+    pub vulnerable: TeamMap<bool>,
+    /// The player's remaining cards.
+    pub hand: &'a [Card],
+    /// The auction.
+    pub auction: &'a Auction,
+    /// The contract, once the auction is over.
+    pub contract: Option<Contract>,
+    /// The dummy's remaining cards, once they've been exposed.
+    pub dummy: Option<&'a [Card]>,
+    /// The tricks played so far. The last may be incomplete.
+    pub tricks: &'a [Trick],
+    /// The player whose card is to be played next, which may be the dummy.
+    pub to_play: Option<Seat>,
+}
+
+impl DealView<'_> {
+    /// The cards held by the player whose card is to be played next, if they're visible.
+    pub fn playing_hand(&self) -> Option<&[Card]> {
+        let seat = self.to_play?;
+        if seat == self.seat {
+            Some(self.hand)
+        } else if self.contract.is_some_and(|c| c.dummy() == seat) {
+            self.dummy
+        } else {
+            None
+        }
+    }
+
+    /// The trick in progress, if any cards have been played to it.
+    pub fn current_trick(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|t| !t.is_complete())
+    }
+
+    /// The cards played to the tricks so far.
+    pub fn played(&self) -> impl Iterator<Item = (Seat, Card)> + '_ {
+        self.tricks.iter().flat_map(|t| t.cards.iter().copied())
+    }
+}
+
+impl Display for DealView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let vulnerable: Vec<&str> = self
+            .vulnerable
+            .iter()
+            .filter(|&(_, &v)| v)
+            .map(|(team, _)| team.to_abbr())
+            .collect();
+        let vulnerable = match vulnerable.len() {
+            0 => tr!("bridge-vulnerable-none"),
+            2 => tr!("bridge-vulnerable-both"),
+            _ => vulnerable.join(""),
+        };
+        let (seat, dealer) = (self.seat.to_string(), self.dealer.to_string());
+        writeln!(
+            f,
+            "{}",
+            tr!(
+                "bridge-view",
+                seat = seat,
+                dealer = dealer,
+                vulnerable = vulnerable
+            )
+        )?;
+        match self.contract {
+            None if self.auction.calls().is_empty() => (),
+            None => {
+                let calls: Vec<String> = self
+                    .auction
+                    .iter()
+                    .map(|(seat, call)| format!("{}:{call}", seat.to_abbr()))
+                    .collect();
+                writeln!(f, "{}", tr!("game-auction", calls = calls.join(" ")))?;
+            }
+            Some(contract) => {
+                writeln!(
+                    f,
+                    "{}",
+                    tr!("bridge-contract-line", contract = contract.to_string())
+                )?;
+                if let (Some(dummy), false) = (self.dummy, self.seat == contract.dummy()) {
+                    let (seat, hand) = (contract.dummy().to_string(), format_hand(dummy));
+                    writeln!(f, "{}", tr!("bridge-dummy", seat = seat, hand = hand))?;
+                }
+                if let Some(trick) = self.current_trick() {
+                    writeln!(f, "{}", tr!("game-trick", trick = trick.to_string()))?;
+                }
+                if let Some(to_play) = self.to_play.filter(|&s| s != self.seat) {
+                    let seat = to_play.to_string();
+                    writeln!(f, "{}", tr!("bridge-playing-from", seat = seat))?;
+                }
+            }
+        }
+        let hand = format_hand(self.hand);
+        f.write_str(&tr!("game-hand", cards = hand))
+    }
+}
+
+/// The state of a deal.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    config: DealConfig,
+    hands: SeatMap<Vec<Card>>,
+    auction: Auction,
+    contract: Option<Contract>,
+    tricks: Vec<Trick>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<DealConfig> for Deal {
+    fn from(config: DealConfig) -> Self {
+        Self {
+            hands: config.hands.clone(),
+            auction: Auction::new(config.dealer),
+            contract: None,
+            tricks: vec![],
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        }
+    }
+}
+
+impl Deal {
+    /// The deal as it was dealt.
+    pub fn config(&self) -> &DealConfig {
+        &self.config
+    }
+
+    /// The auction.
+    pub fn auction(&self) -> &Auction {
+        &self.auction
+    }
+
+    /// The contract, once the auction is over.
+    pub fn contract(&self) -> Option<Contract> {
+        self.contract
+    }
+
+    /// The tricks played so far. The last may be incomplete.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The number of completed tricks won by the team.
+    pub fn tricks_won(&self, team: Team) -> u8 {
+        let won = self
+            .tricks
+            .iter()
+            .filter(|t| t.is_complete())
+            .filter(|t| t.winner().is_some_and(|(seat, _)| seat.team() == team))
+            .count();
+        u8::try_from(won).expect("at most 13 tricks")
+    }
+
+    /// The player whose card is to be played next, or `None` if the auction is in progress or
+    /// the play is over.
+    pub fn to_play(&self) -> Option<Seat> {
+        let contract = self.contract?;
+        match self.tricks.last() {
+            None => Some(contract.declarer.next()),
+            Some(trick) if !trick.is_complete() => {
+                Some(trick.cards.last().expect("trick is started").0.next())
+            }
+            Some(_) if self.tricks.len() == 13 => None,
+            Some(trick) => Some(trick.winner().expect("trick is complete").0),
+        }
+    }
+
+    /// The player expected to act next. The declarer plays the dummy's cards.
+    pub fn next_actor(&self) -> Option<Seat> {
+        if self.contract.is_none() {
+            return self.auction.next_seat();
+        }
+        let seat = self.to_play()?;
+        match self.contract {
+            Some(contract) if contract.dummy() == seat => Some(contract.declarer),
+            _ => Some(seat),
+        }
+    }
+
+    /// Returns the state of the deal, as visible to the player.
+    pub fn view(&self, seat: Seat) -> DealView<'_> {
+        let dummy = self
+            .contract
+            .filter(|_| !self.tricks.is_empty())
+            .map(|c| self.hands[c.dummy()].as_slice());
+        DealView {
+            seat,
+            dealer: self.config.dealer,
+            vulnerable: self.config.vulnerable,
+            hand: &self.hands[seat],
+            auction: &self.auction,
+            contract: self.contract,
+            dummy,
+            tricks: &self.tricks,
+            to_play: self.to_play(),
+        }
+    }
+
+    /// Returns the actions the next player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.contract.is_none() {
+            return self
+                .auction
+                .legal_calls()
+                .into_iter()
+                .map(Action::Call)
+                .collect();
+        }
+        let Some(seat) = self.to_play() else {
+            return vec![];
+        };
+        let hand = &self.hands[seat];
+        let mut cards = match self.tricks.last().filter(|t| !t.is_complete()) {
+            Some(trick) => trick.filter(hand),
+            None => hand.clone(),
+        };
+        cards.sort_by_key(|&c| (suit_of(c), rank_of(c)));
+        cards.into_iter().map(Action::Play).collect()
+    }
+
+    /// Applies the next player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), BridgeError> {
+        match action {
+            Action::Call(call) => self.call(call)?,
+            Action::Play(card) => self.play(card)?,
+        }
+        self.actions.push(action);
+        Ok(())
+    }
+
+    fn call(&mut self, call: Call) -> Result<(), BridgeError> {
+        let seat = self.auction.next_seat().ok_or(BridgeError::AuctionOver)?;
+        self.auction.call(call)?;
+        self.events.push_back(Event::Call(seat, call));
+        if let Some(contract) = self.auction.contract() {
+            self.contract = Some(contract);
+            self.events.push_back(Event::Contract(contract));
+        } else if self.auction.is_passed_out() {
+            self.events.push_back(Event::Over(DealResult {
+                contract: None,
+                tricks: 0,
+                score: Score::default(),
+            }));
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, card: Card) -> Result<(), BridgeError> {
+        let Some(contract) = self.contract else {
+            if self.auction.is_passed_out() {
+                return Err(BridgeError::DealOver);
+            }
+            return Err(BridgeError::AuctionInProgress);
+        };
+        let seat = self.to_play().ok_or(BridgeError::DealOver)?;
+        let hand = &self.hands[seat];
+        if !hand.contains(&card) {
+            return Err(BridgeError::CardNotHeld(seat, card));
+        }
+        match self.tricks.last().filter(|t| !t.is_complete()) {
+            Some(trick) if !trick.filter(hand).contains(&card) => {
+                let lead = trick.lead_suit().expect("trick is led");
+                return Err(BridgeError::MustFollowSuit(seat, lead));
+            }
+            Some(_) => (),
+            None => self.tricks.push(Trick::new(contract.bid.strain.trump())),
+        }
+        let trick = self.tricks.last_mut().expect("trick is started");
+        trick.cards.push((seat, card));
+        self.hands[seat].retain(|&c| c != card);
+
+        if self.tricks.len() == 1 && self.tricks[0].cards.len() == 1 {
+            let dummy = contract.dummy();
+            self.events
+                .push_back(Event::Dummy(dummy, self.hands[dummy].clone()));
+        }
+        let trick = self.tricks.last().expect("trick is started");
+        if trick.is_complete() {
+            self.events.push_back(Event::Trick(trick.clone()));
+            if let Some(result) = self.result() {
+                self.events.push_back(Event::Over(result));
+            }
+        }
+        Ok(())
+    }
+
+    /// The result of the deal, once it's over.
+    pub fn result(&self) -> Option<DealResult> {
+        if self.auction.is_passed_out() {
+            return Some(DealResult {
+                contract: None,
+                tricks: 0,
+                score: Score::default(),
+            });
+        }
+        let contract = self.contract?;
+        if self.tricks.len() < 13 || !self.tricks[12].is_complete() {
+            return None;
+        }
+        let team = contract.declarer.team();
+        let tricks = self.tricks_won(team);
+        let honors = honors(contract.bid.strain, &self.config.hands);
+        let vulnerable = self.config.is_vulnerable(team);
+        Some(DealResult {
+            contract: Some(contract),
+            tricks,
+            score: Score::new(&contract, tricks, vulnerable, honors),
+        })
+    }
+}
+
+impl crate::engine::Engine for Deal {
+    type Actor = Seat;
+    type Action = Action;
+    type View<'a> = DealView<'a>;
+    type Event = Event;
+    type Outcome = DealResult;
+    type Error = BridgeError;
+
+    fn expected_actor(&self) -> Option<Seat> {
+        self.next_actor()
+    }
+
+    fn view(&self, seat: Seat) -> DealView<'_> {
+        Deal::view(self, seat)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Deal::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), BridgeError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<DealResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a deal: the cards dealt, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawDeal {
+    config: DealConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Deal> for RawDeal {
+    fn from(deal: &Deal) -> Self {
+        Self {
+            config: deal.config.clone(),
+            actions: deal.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawDeal> for Deal {
+    type Error = BridgeError;
+
+    fn try_from(raw: RawDeal) -> Result<Self, BridgeError> {
+        raw.config.validate()?;
+        let mut deal = Deal::from(raw.config);
+        for action in raw.actions {
+            deal.apply_action(action)?;
+        }
+        deal.events.clear();
+        Ok(deal)
+    }
+}
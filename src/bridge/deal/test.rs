@@ -0,0 +1,135 @@
+use super::*;
+use crate::bridge::{Bid, Strain};
+use crate::engine::Engine;
+
+/// Deals the standard deck one card at a time, so that every hand holds every suit.
+fn round_robin(dealer: Seat) -> DealConfig {
+    let mut hands = SeatMap::<Vec<Card>>::default();
+    for (i, card) in Deck::standard().into_iter().enumerate() {
+        hands[Seat::all_seats()[i % 4]].push(card);
+    }
+    DealConfig::from_hands(dealer, TeamMap::default(), hands).unwrap()
+}
+
+fn bid(level: u8, strain: Strain) -> Action {
+    Action::Call(Call::Bid(Bid::new(level, strain)))
+}
+
+const PASS: Action = Action::Call(Call::Pass);
+
+#[test]
+fn test_invalid_config() {
+    let mut hands = round_robin(Seat::North).hands;
+    hands[Seat::North].pop();
+    let result = DealConfig::from_hands(Seat::North, TeamMap::default(), hands.clone());
+    assert!(matches!(
+        result,
+        Err(BridgeError::InvalidHandSize(Seat::North))
+    ));
+    let dup = hands[Seat::East][0];
+    hands[Seat::North].push(dup);
+    let result = DealConfig::from_hands(Seat::North, TeamMap::default(), hands);
+    assert!(matches!(result, Err(BridgeError::InvalidCard(c)) if c == dup));
+}
+
+#[test]
+fn test_passed_out() {
+    let mut deal = Deal::from(round_robin(Seat::East));
+    assert_eq!(deal.expected_actor(), Some(Seat::East));
+    for _ in 0..4 {
+        deal.apply(PASS).unwrap();
+    }
+    assert!(deal.is_over());
+    assert!(deal.legal_actions().is_empty());
+    let result = deal.outcome().unwrap();
+    assert_eq!(result.contract, None);
+    assert_eq!(result.to_string(), "Passed out");
+    let card = deal.config().hand(Seat::South)[0];
+    assert!(matches!(
+        deal.apply(Action::Play(card)),
+        Err(BridgeError::DealOver)
+    ));
+}
+
+#[test]
+fn test_play() {
+    let mut deal = Deal::from(round_robin(Seat::North));
+    for action in [
+        bid(1, Strain::NoTrump),
+        PASS,
+        bid(3, Strain::NoTrump),
+        PASS,
+        PASS,
+    ]
+    .iter()
+    {
+        deal.apply(*action).unwrap();
+    }
+    let card = deal.config().hand(Seat::West)[0];
+    assert!(matches!(
+        deal.apply(Action::Play(card)),
+        Err(BridgeError::AuctionInProgress)
+    ));
+    deal.apply(PASS).unwrap();
+    let contract = deal.contract().unwrap();
+    assert_eq!(contract.declarer, Seat::North);
+    assert_eq!(deal.expected_actor(), Some(Seat::East));
+    assert!(deal.view(Seat::East).dummy.is_none());
+    assert_eq!(deal.legal_actions().len(), 13);
+
+    // The opening lead exposes the dummy, whose cards the declarer plays.
+    let lead = deal.config().hand(Seat::East)[0];
+    deal.apply(Action::Play(lead)).unwrap();
+    assert_eq!(deal.to_play(), Some(Seat::South));
+    assert_eq!(deal.expected_actor(), Some(Seat::North));
+    let view = deal.view(Seat::North);
+    assert_eq!(view.dummy, Some(deal.config().hand(Seat::South)));
+    assert_eq!(view.playing_hand(), view.dummy);
+    assert!(deal.view(Seat::West).dummy.is_some());
+
+    let south = deal.config().hand(Seat::South).to_vec();
+    let offsuit = *south
+        .iter()
+        .find(|&&c| suit_of(c) != suit_of(lead))
+        .unwrap();
+    assert!(matches!(
+        deal.apply(Action::Play(offsuit)),
+        Err(BridgeError::MustFollowSuit(Seat::South, _))
+    ));
+    assert!(matches!(
+        deal.apply(Action::Play(lead)),
+        Err(BridgeError::CardNotHeld(Seat::South, _))
+    ));
+    let legal = deal.legal_actions();
+    assert!(legal
+        .iter()
+        .all(|a| matches!(a, Action::Play(c) if suit_of(*c) == suit_of(lead))));
+
+    let result = deal
+        .play_out(|_, _, legal| legal[0])
+        .unwrap()
+        .expect("deal is over");
+    assert_eq!(result.contract, Some(contract));
+    let ns = deal.tricks_won(Team::NorthSouth);
+    assert_eq!(ns + deal.tricks_won(Team::EastWest), 13);
+    assert_eq!(result.tricks, ns);
+
+    // The record of the deal replays to the same result.
+    let raw = serde_json::to_string(&RawDeal::from(&deal)).unwrap();
+    let replayed = Deal::try_from(serde_json::from_str::<RawDeal>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.tricks(), deal.tricks());
+}
+
+#[test]
+fn test_trick_winner() {
+    let card = |s: &str| s.parse::<Card>().unwrap();
+    let mut trick = Trick::new(Some(Suit::Heart));
+    trick.cards.push((Seat::North, card("KS")));
+    trick.cards.push((Seat::East, card("AS")));
+    assert_eq!(trick.winner(), Some((Seat::East, card("AS"))));
+    trick.cards.push((Seat::South, card("2H")));
+    trick.cards.push((Seat::West, card("AD")));
+    assert_eq!(trick.winner(), Some((Seat::South, card("2H"))));
+    assert_eq!(trick.to_string(), "[N:K♤, E:A♤, S:2♡, W:A♦]");
+}
@@ -0,0 +1,32 @@
+//! Errors
+
+use super::{Call, Card, Seat, Suit};
+
+/// An error that can occur during a deal.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BridgeError {
+    /// A player doesn't hold thirteen cards.
+    #[error("{0} must be dealt 13 cards")]
+    InvalidHandSize(Seat),
+    /// The same card was dealt twice, or a card isn't from the standard deck.
+    #[error("{0} is not dealt exactly once")]
+    InvalidCard(Card),
+    /// The call isn't legal at this point in the auction.
+    #[error("{0} is not a legal call")]
+    IllegalCall(Call),
+    /// A call was made after the auction was over.
+    #[error("the auction is over")]
+    AuctionOver,
+    /// A card was played before the auction was over.
+    #[error("the auction is still in progress")]
+    AuctionInProgress,
+    /// The player doesn't hold the card they attempted to play.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Seat, Card),
+    /// The player must follow the suit that was led.
+    #[error("{0} must follow {1}")]
+    MustFollowSuit(Seat, Suit),
+    /// The deal is over, no more actions are expected.
+    #[error("deal is over")]
+    DealOver,
+}
@@ -0,0 +1,68 @@
+//! A robot player.
+//!
+//! The robot bids a simple natural system: five-card majors, a strong 1NT, strong 2♣ and weak
+//! two-bids, with Stayman and Jacoby transfers over no trump openings. Beyond the conventional
+//! sequences, it estimates its partner's strength and shape from their calls, and places the
+//! contract on the partnership's combined values. In the play, it follows the usual rules of
+//! thumb: second hand low, third hand high, win as cheaply as possible, cash winners, and draw
+//! trumps as declarer.
+
+mod bidding;
+mod play;
+
+use super::{rank_of, suit_of, Action, Card, DealView, Suit};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses an action for the player expected to act next.
+    pub fn choose(&self, view: &DealView) -> Action {
+        if view.contract.is_none() {
+            Action::Call(bidding::choose_call(view))
+        } else {
+            Action::Play(play::choose_card(view))
+        }
+    }
+}
+
+/// Returns the index of a suit, for per-suit arrays.
+fn index(suit: Suit) -> usize {
+    suit as usize
+}
+
+/// The shape and strength of a hand.
+#[derive(Debug, Clone, Copy)]
+struct HandInfo {
+    /// High card points: four for an ace, three for a king, two for a queen, and one for a jack.
+    hcp: u8,
+    /// The number of cards in each suit.
+    lengths: [u8; 4],
+}
+
+impl HandInfo {
+    fn new(hand: &[Card]) -> Self {
+        let mut hcp = 0;
+        let mut lengths = [0; 4];
+        for &card in hand {
+            lengths[index(suit_of(card))] += 1;
+            hcp += rank_of(card).saturating_sub(8);
+        }
+        Self { hcp, lengths }
+    }
+
+    /// The number of cards in the suit.
+    fn len(&self, suit: Suit) -> u8 {
+        self.lengths[index(suit)]
+    }
+
+    /// Returns true for hands without a void or singleton, and with at most one doubleton.
+    fn is_balanced(&self) -> bool {
+        let doubletons = self.lengths.iter().filter(|&&l| l == 2).count();
+        self.lengths.iter().all(|&l| l >= 2) && doubletons <= 1
+    }
+}
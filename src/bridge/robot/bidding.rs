@@ -0,0 +1,918 @@
+//! The robot's bidding.
+
+use super::{index, HandInfo};
+use crate::bridge::{rank_of, suit_of, Auction, Bid, Call, Card, DealView, Seat, Strain, Suit};
+
+/// What a player's calls have shown about their hand.
+#[derive(Debug, Clone, Copy)]
+struct Shown {
+    /// The fewest high card points they may hold.
+    min: u8,
+    /// The most high card points they may hold.
+    max: u8,
+    /// The fewest cards they may hold in each suit.
+    lengths: [u8; 4],
+    /// Whether they've shown a balanced hand.
+    balanced: bool,
+}
+
+impl Default for Shown {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: 37,
+            lengths: [0; 4],
+            balanced: false,
+        }
+    }
+}
+
+impl Shown {
+    /// Narrows the range of high card points, ignoring any inconsistency with earlier calls.
+    fn range(&mut self, min: u8, max: u8) {
+        if min <= self.max && max >= self.min {
+            self.min = self.min.max(min);
+            self.max = self.max.min(max);
+        }
+    }
+
+    /// Records that they hold at least `len` cards in the suit.
+    fn length(&mut self, suit: Suit, len: u8) {
+        let l = &mut self.lengths[index(suit)];
+        *l = (*l).max(len);
+    }
+
+    /// Records that they hold a balanced hand.
+    fn set_balanced(&mut self) {
+        self.balanced = true;
+        for l in &mut self.lengths {
+            *l = (*l).max(2);
+        }
+    }
+
+    /// A rough estimate of their high card points. Ranges with no upper bound are assumed to
+    /// fall near their lower end.
+    fn expected(&self) -> u8 {
+        (self.min + self.max.min(self.min + 6)) / 2
+    }
+}
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+/// The lowest level at which the strain may be bid, over the last bid.
+fn cheapest_level(last: Option<Bid>, strain: Strain) -> u8 {
+    match last {
+        None => 1,
+        Some(last) if strain > last.strain => last.level,
+        Some(last) => last.level + 1,
+    }
+}
+
+/// Returns true if the player has a stopper in the suit: an ace, a guarded king, a queen with
+/// two guards, or a jack with three.
+fn has_stopper(cards: &[Card], suit: Suit) -> bool {
+    let ranks: Vec<u8> = cards
+        .iter()
+        .filter(|&&c| suit_of(c) == suit)
+        .map(|&c| rank_of(c))
+        .collect();
+    let len = ranks.len();
+    ranks.iter().any(|&r| 12 - r < len.min(4) as u8)
+}
+
+/// Works out what the player's calls have shown about their hand.
+fn describe(auction: &Auction, target: Seat) -> Shown {
+    let mut shown = Shown::default();
+    let calls: Vec<(Seat, Call)> = auction.iter().collect();
+    for (i, &(seat, call)) in calls.iter().enumerate() {
+        if seat == target {
+            interpret(&mut shown, &calls[..i], seat, call);
+        }
+    }
+    shown
+}
+
+/// Updates what the player has shown, with the meaning of a call in the context of the calls
+/// that preceded it.
+fn interpret(shown: &mut Shown, prefix: &[(Seat, Call)], seat: Seat, call: Call) {
+    let partner = seat.opposite();
+    let bids = |s: Seat| -> Vec<Bid> {
+        prefix
+            .iter()
+            .filter_map(|&(caller, call)| match call {
+                Call::Bid(bid) if caller == s => Some(bid),
+                _ => None,
+            })
+            .collect()
+    };
+    let mine = bids(seat);
+    let partners = bids(partner);
+    let contested = prefix
+        .iter()
+        .any(|&(s, c)| s.team() != seat.team() && c != Call::Pass);
+    let last = prefix.iter().rev().find_map(|&(_, call)| match call {
+        Call::Bid(bid) => Some(bid),
+        _ => None,
+    });
+    let first = prefix.iter().find_map(|&(s, call)| match call {
+        Call::Bid(bid) => Some((s, bid)),
+        _ => None,
+    });
+    let ours_doubled = prefix
+        .iter()
+        .any(|&(s, c)| s.team() == seat.team() && c == Call::Double);
+
+    let Some((opener, opening)) = first else {
+        // An opening bid.
+        let Call::Bid(b) = call else {
+            shown.range(0, 11);
+            return;
+        };
+        match (b.level, b.strain) {
+            (1, Strain::NoTrump) => {
+                shown.range(15, 17);
+                shown.set_balanced();
+            }
+            (2, Strain::NoTrump) => {
+                shown.range(20, 21);
+                shown.set_balanced();
+            }
+            (2, Strain::Club) => shown.range(22, 37),
+            (1, s) => {
+                shown.range(12, 21);
+                let suit = s.trump().expect("suit bid");
+                shown.length(suit, if s.is_major() { 5 } else { 3 });
+            }
+            (2, s) => {
+                shown.range(6, 10);
+                shown.length(s.trump().expect("suit bid"), 6);
+            }
+            (_, Strain::NoTrump) => shown.range(6, 37),
+            (_, s) => {
+                shown.range(6, 12);
+                shown.length(s.trump().expect("suit bid"), 7);
+            }
+        }
+        return;
+    };
+
+    if opener == partner && mine.is_empty() && partners.len() == 1 {
+        // A response to partner's opening.
+        respond_meaning(shown, opening, last, call, contested);
+        return;
+    }
+
+    if opener.team() != seat.team() && mine.is_empty() && partners.is_empty() && !ours_doubled {
+        // A first action over an opponent's opening.
+        match call {
+            Call::Pass => shown.range(0, 16),
+            Call::Double => {
+                shown.range(12, 37);
+                let theirs: Vec<Strain> = prefix
+                    .iter()
+                    .filter_map(|&(_, c)| match c {
+                        Call::Bid(b) => Some(b.strain),
+                        _ => None,
+                    })
+                    .collect();
+                for &suit in Suit::all_suits() {
+                    if !theirs.contains(&Strain::from(suit)) {
+                        shown.length(suit, 3);
+                    }
+                }
+            }
+            Call::Redouble => (),
+            Call::Bid(b) if b.strain == Strain::NoTrump => {
+                shown.range(15, 18);
+                shown.set_balanced();
+            }
+            Call::Bid(b) => {
+                let suit = b.strain.trump().expect("suit bid");
+                if b.level == cheapest_level(last, b.strain) {
+                    shown.range(8, 16);
+                    shown.length(suit, 5);
+                } else {
+                    shown.range(6, 10);
+                    shown.length(suit, 6);
+                }
+            }
+        }
+        return;
+    }
+
+    // Later calls are taken at face value.
+    let Call::Bid(b) = call else {
+        return;
+    };
+    let side: Vec<Bid> = prefix
+        .iter()
+        .filter_map(|&(s, c)| match c {
+            Call::Bid(b) if s.team() == seat.team() => Some(b),
+            _ => None,
+        })
+        .collect();
+    if !contested && side.len() == 2 && side[0] == Bid::new(1, Strain::NoTrump) {
+        // Answering Stayman, or completing a transfer.
+        match (side[1].strain, b.strain) {
+            (Strain::Club, Strain::Heart) => shown.length(Suit::Heart, 4),
+            (Strain::Club, Strain::Spade) => shown.length(Suit::Spade, 4),
+            _ => (),
+        }
+        return;
+    }
+    match b.strain.trump() {
+        None => shown.balanced = true,
+        Some(suit) if mine.iter().any(|m| m.strain == b.strain) => {
+            let len = shown.lengths[index(suit)];
+            shown.length(suit, (len + 1).min(7));
+        }
+        Some(suit) if partners.iter().any(|p| p.strain == b.strain) => {
+            shown.length(suit, if b.strain.is_major() { 3 } else { 4 });
+        }
+        Some(suit) => shown.length(suit, 4),
+    }
+}
+
+/// The meaning of a response to partner's opening bid.
+fn respond_meaning(
+    shown: &mut Shown,
+    opening: Bid,
+    last: Option<Bid>,
+    call: Call,
+    contested: bool,
+) {
+    let b = match call {
+        Call::Pass => {
+            match (opening.level, opening.strain) {
+                (1, Strain::NoTrump) => shown.range(0, 7),
+                (2, Strain::NoTrump) => shown.range(0, 3),
+                (1, _) if !contested => shown.range(0, 5),
+                (2, Strain::Club) => (),
+                _ => shown.range(0, 15),
+            }
+            return;
+        }
+        Call::Double => {
+            shown.range(6, 37);
+            return;
+        }
+        Call::Redouble => {
+            shown.range(10, 37);
+            return;
+        }
+        Call::Bid(b) => b,
+    };
+    let natural = |shown: &mut Shown, min: u8, len: u8| {
+        shown.range(min, 37);
+        if let Some(suit) = b.strain.trump() {
+            shown.length(suit, len);
+        }
+    };
+    match (opening.level, opening.strain) {
+        (1, Strain::NoTrump) if !contested => match (b.level, b.strain) {
+            (2, Strain::Club) => shown.range(8, 37),
+            (2, Strain::Diamond) => shown.length(Suit::Heart, 5),
+            (2, Strain::Heart) => shown.length(Suit::Spade, 5),
+            (2, Strain::NoTrump) => shown.range(8, 9),
+            (3, Strain::NoTrump) => shown.range(10, 17),
+            (_, Strain::NoTrump) => shown.range(18, 37),
+            _ => natural(shown, 0, 6),
+        },
+        (2, Strain::NoTrump) => match b.strain {
+            Strain::NoTrump if b.level == 3 => shown.range(4, 11),
+            Strain::NoTrump => shown.range(12, 37),
+            _ => natural(shown, 4, 6),
+        },
+        (2, Strain::Club) => {
+            if b != Bid::new(2, Strain::Diamond) {
+                natural(shown, 8, 5);
+            }
+        }
+        // A raise of a no trump opening after interference, or of a higher one.
+        (_, Strain::NoTrump) if b.strain == Strain::NoTrump => shown.range(8, 37),
+        (_, strain) if b.strain == strain => {
+            let suit = strain.trump().expect("suit opening");
+            if opening.level > 1 {
+                shown.length(suit, 3);
+                return;
+            }
+            match b.level {
+                2 => shown.range(6, 9),
+                3 => shown.range(10, 12),
+                _ => shown.range(13, 37),
+            }
+            shown.length(suit, if strain.is_major() { 3 } else { 5 });
+        }
+        (1, _) => match (b.level, b.strain) {
+            (1, Strain::NoTrump) => shown.range(6, 10),
+            (2, Strain::NoTrump) if !contested => {
+                shown.range(11, 12);
+                shown.set_balanced();
+            }
+            (3, Strain::NoTrump) => {
+                shown.range(13, 15);
+                shown.set_balanced();
+            }
+            (_, Strain::NoTrump) => shown.range(10, 37),
+            (level, strain) if level == cheapest_level(last, strain) => {
+                natural(shown, if level == 1 { 6 } else { 10 }, 4);
+            }
+            _ => natural(shown, 6, 6),
+        },
+        _ => match b.strain {
+            Strain::NoTrump => shown.range(16, 37),
+            _ => natural(shown, 14, 5),
+        },
+    }
+}
+
+/// Chooses a call for the player.
+pub(super) fn choose_call(view: &DealView) -> Call {
+    let hand = HandInfo::new(view.hand);
+    let call = decide(&hand, view.hand, view.auction, view.seat);
+    if view.auction.is_legal(call) {
+        call
+    } else {
+        Call::Pass
+    }
+}
+
+fn decide(hand: &HandInfo, cards: &[Card], auction: &Auction, seat: Seat) -> Call {
+    let calls: Vec<(Seat, Call)> = auction.iter().collect();
+    let team = seat.team();
+    let ours: Vec<(Seat, Call)> = calls
+        .iter()
+        .copied()
+        .filter(|&(s, c)| s.team() == team && c != Call::Pass)
+        .collect();
+    let contested = calls
+        .iter()
+        .any(|&(s, c)| s.team() != team && c != Call::Pass);
+    let Some((opener, _)) = calls
+        .iter()
+        .copied()
+        .find(|(_, c)| matches!(c, Call::Bid(_)))
+    else {
+        return opening(hand).map_or(Call::Pass, Call::Bid);
+    };
+    if opener.team() != team && ours.is_empty() {
+        return overcall(hand, cards, auction);
+    }
+    if !contested {
+        let seq: Vec<(Seat, Bid)> = ours
+            .iter()
+            .filter_map(|&(s, c)| match c {
+                Call::Bid(b) => Some((s, b)),
+                _ => None,
+            })
+            .collect();
+        if let Some(call) = uncontested(hand, &seq, seat) {
+            return call;
+        }
+    }
+    if let [(partner, Call::Double)] = ours[..] {
+        if calls.last().is_some_and(|&(_, c)| c == Call::Pass) && partner == seat.opposite() {
+            return advance_double(hand, cards, auction);
+        }
+    }
+    placement(hand, auction, seat)
+}
+
+/// Chooses an opening bid, if the hand is worth one.
+fn opening(hand: &HandInfo) -> Option<Bid> {
+    let h = hand.hcp;
+    let len = |suit| hand.len(suit);
+    let (spades, hearts) = (len(Suit::Spade), len(Suit::Heart));
+    let (diamonds, clubs) = (len(Suit::Diamond), len(Suit::Club));
+    let mut by_length = hand.lengths;
+    by_length.sort_unstable();
+    let rule_of_20 = h + by_length[3] + by_length[2] >= 20;
+    if h >= 22 {
+        return Some(Bid::new(2, Strain::Club));
+    }
+    if hand.is_balanced() && (20..=21).contains(&h) {
+        return Some(Bid::new(2, Strain::NoTrump));
+    }
+    if hand.is_balanced() && (15..=17).contains(&h) {
+        return Some(Bid::new(1, Strain::NoTrump));
+    }
+    if h >= 12 || (h >= 10 && rule_of_20) {
+        let strain = if spades >= 5 && spades >= hearts {
+            Strain::Spade
+        } else if hearts >= 5 {
+            Strain::Heart
+        } else if diamonds > clubs || (diamonds == clubs && diamonds >= 4) {
+            Strain::Diamond
+        } else {
+            Strain::Club
+        };
+        return Some(Bid::new(1, strain));
+    }
+    if (6..=10).contains(&h) {
+        let longest = [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club]
+            .iter()
+            .copied()
+            .max_by_key(|&s| len(s))
+            .expect("four suits");
+        match len(longest) {
+            7.. => return Some(Bid::new(3, Strain::from(longest))),
+            6 if longest != Suit::Club => return Some(Bid::new(2, Strain::from(longest))),
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Bids over an opponent's opening: a 1NT overcall, a suit overcall, or a takeout double.
+fn overcall(hand: &HandInfo, cards: &[Card], auction: &Auction) -> Call {
+    let h = hand.hcp;
+    let Some((_, last)) = auction.last_bid() else {
+        return Call::Pass;
+    };
+    let theirs = last.strain.trump();
+    let stopped = theirs.is_some_and(|suit| has_stopper(cards, suit));
+    if hand.is_balanced()
+        && (15..=18).contains(&h)
+        && stopped
+        && cheapest_level(Some(last), Strain::NoTrump) == 1
+    {
+        return bid(1, Strain::NoTrump);
+    }
+    let takeout = auction.is_legal(Call::Double)
+        && last.level <= 3
+        && (h >= 17
+            || (h >= 12
+                && theirs.is_some_and(|t| {
+                    hand.len(t) <= 2
+                        && Suit::all_suits()
+                            .iter()
+                            .all(|&s| s == t || hand.len(s) >= 3)
+                })));
+    if h >= 17 && takeout {
+        return Call::Double;
+    }
+    let longest = Suit::all_suits()
+        .iter()
+        .copied()
+        .filter(|&s| Some(s) != theirs)
+        .max_by_key(|&s| (hand.len(s), Strain::from(s)))
+        .expect("three suits");
+    let len = hand.len(longest);
+    let strain = Strain::from(longest);
+    let level = cheapest_level(Some(last), strain);
+    let sound = match level {
+        1 => len >= 5 && (8..=16).contains(&h),
+        2 => len >= 5 && (11..=16).contains(&h),
+        3 => len >= 6 && (13..=16).contains(&h),
+        _ => false,
+    };
+    if sound {
+        return bid(level, strain);
+    }
+    if takeout {
+        return Call::Double;
+    }
+    Call::Pass
+}
+
+/// Answers partner's takeout double, with the longest unbid suit, preferring majors.
+fn advance_double(hand: &HandInfo, cards: &[Card], auction: &Auction) -> Call {
+    let Some((_, last)) = auction.last_bid() else {
+        return Call::Pass;
+    };
+    let calls: Vec<(Seat, Call)> = auction.iter().collect();
+    let theirs: Vec<Strain> = calls
+        .iter()
+        .filter_map(|&(_, c)| match c {
+            Call::Bid(b) => Some(b.strain),
+            _ => None,
+        })
+        .collect();
+    let unbid: Vec<Suit> = Suit::all_suits()
+        .iter()
+        .copied()
+        .filter(|&s| !theirs.contains(&Strain::from(s)))
+        .collect();
+    let major = unbid
+        .iter()
+        .copied()
+        .filter(|&s| Strain::from(s).is_major() && hand.len(s) >= 4)
+        .max_by_key(|&s| (hand.len(s), Strain::from(s)));
+    let stopped = last.strain.trump().is_some_and(|t| has_stopper(cards, t));
+    let strain = match major {
+        Some(suit) => Strain::from(suit),
+        None if stopped && (6..=10).contains(&hand.hcp) => Strain::NoTrump,
+        None => unbid
+            .iter()
+            .copied()
+            .max_by_key(|&s| (hand.len(s), Strain::from(s)))
+            .map_or(Strain::NoTrump, Strain::from),
+    };
+    let mut level = cheapest_level(Some(last), strain);
+    if hand.hcp >= 10 && strain != Strain::NoTrump && level < 3 {
+        level += 1;
+    }
+    bid(level, strain)
+}
+
+/// Handles the conventional sequences of an uncontested auction. Returns `None` when the
+/// sequence is left to general judgment.
+fn uncontested(hand: &HandInfo, seq: &[(Seat, Bid)], seat: Seat) -> Option<Call> {
+    use Strain::{Club, Diamond, Heart, NoTrump, Spade};
+    let (opener, _) = *seq.first()?;
+    let i_opened = opener == seat;
+    let bids: Vec<(u8, Strain)> = seq.iter().map(|(_, b)| (b.level, b.strain)).collect();
+    let h = hand.hcp;
+    let call = match (i_opened, &bids[..]) {
+        (false, [(1, NoTrump)]) => respond_1nt(hand),
+        (true, [(1, NoTrump), (2, Club)]) => {
+            if hand.len(Suit::Heart) >= 4 {
+                bid(2, Heart)
+            } else if hand.len(Suit::Spade) >= 4 {
+                bid(2, Spade)
+            } else {
+                bid(2, Diamond)
+            }
+        }
+        (true, [(1, NoTrump), (2, Diamond)]) => bid(2, Heart),
+        (true, [(1, NoTrump), (2, Heart)]) => bid(2, Spade),
+        (false, [(1, NoTrump), (2, Club), (2, answer)]) => {
+            let fit = answer
+                .trump()
+                .filter(|&s| Strain::from(s).is_major() && hand.len(s) >= 4);
+            match (fit, h) {
+                (Some(suit), 8..=9) => bid(3, Strain::from(suit)),
+                (Some(suit), _) => bid(4, Strain::from(suit)),
+                (None, 8..=9) => bid(2, NoTrump),
+                (None, _) => bid(3, NoTrump),
+            }
+        }
+        (false, [(1, NoTrump), (2, Diamond | Heart), (2, major)]) => {
+            let len = hand.len(major.trump()?);
+            match h {
+                0..=7 => Call::Pass,
+                8..=9 if len >= 6 => bid(3, *major),
+                8..=9 => bid(2, NoTrump),
+                _ if len >= 6 => bid(4, *major),
+                _ => bid(3, NoTrump),
+            }
+        }
+        (true, [(1, NoTrump), (2, Club), (2, answer), next]) => {
+            let spades = *answer == Heart && hand.len(Suit::Spade) >= 4;
+            match next {
+                (2, NoTrump) if h >= 16 && spades => bid(4, Spade),
+                (2, NoTrump) if h >= 16 => bid(3, NoTrump),
+                (3, NoTrump) if spades => bid(4, Spade),
+                (3, major) if major.is_major() && h >= 16 => bid(4, *major),
+                _ => Call::Pass,
+            }
+        }
+        (true, [(1, NoTrump), (2, Diamond | Heart), (2, major), next]) => {
+            let support = hand.len(major.trump()?) >= 3;
+            match next {
+                (2, NoTrump) if h >= 16 && support => bid(4, *major),
+                (2, NoTrump) if h >= 16 => bid(3, NoTrump),
+                (2, NoTrump) if support => bid(3, *major),
+                (3, NoTrump) if support => bid(4, *major),
+                (3, m) if m == major && h >= 16 => bid(4, *major),
+                _ => Call::Pass,
+            }
+        }
+        (false, [(2, NoTrump)]) => {
+            let major = [Suit::Spade, Suit::Heart]
+                .iter()
+                .copied()
+                .find(|&s| hand.len(s) >= 6);
+            match (h, major) {
+                (0..=3, _) => Call::Pass,
+                (4..=11, Some(suit)) => bid(4, Strain::from(suit)),
+                (4..=11, None) => bid(3, NoTrump),
+                _ => bid(6, NoTrump),
+            }
+        }
+        (false, [(2, Club)]) => {
+            let longest = longest_suit(hand);
+            match (h, hand.len(longest)) {
+                (8.., 5..) if Strain::from(longest).is_major() => bid(2, Strain::from(longest)),
+                (8.., 5..) => bid(3, Strain::from(longest)),
+                _ => bid(2, Diamond),
+            }
+        }
+        (true, [(2, Club), (2, Diamond)]) => {
+            let longest = longest_suit(hand);
+            if hand.is_balanced() || hand.len(longest) < 5 {
+                bid(if h <= 24 { 2 } else { 3 }, NoTrump)
+            } else if Strain::from(longest).is_major() {
+                bid(2, Strain::from(longest))
+            } else {
+                bid(3, Strain::from(longest))
+            }
+        }
+        (false, [(level, strain)])
+            if *strain != NoTrump && (*level >= 3 || *strain != Club) && *level >= 2 =>
+        {
+            respond_preempt(hand, Bid::new(*level, *strain))
+        }
+        (false, [(1, strain)]) => respond_one(hand, *strain),
+        (true, [(1, strain), (level, response)]) if *strain != NoTrump => {
+            opener_rebid(hand, *strain, Bid::new(*level, *response))
+        }
+        _ => return None,
+    };
+    Some(call)
+}
+
+/// The longest suit, preferring higher-ranking suits among equals.
+fn longest_suit(hand: &HandInfo) -> Suit {
+    Suit::all_suits()
+        .iter()
+        .copied()
+        .max_by_key(|&s| (hand.len(s), Strain::from(s)))
+        .expect("four suits")
+}
+
+/// Responds to partner's 1NT opening, with Stayman, a transfer, or a raise.
+fn respond_1nt(hand: &HandInfo) -> Call {
+    let (spades, hearts) = (hand.len(Suit::Spade), hand.len(Suit::Heart));
+    let h = hand.hcp;
+    if hearts >= 5 && hearts >= spades {
+        return bid(2, Strain::Diamond);
+    }
+    if spades >= 5 {
+        return bid(2, Strain::Heart);
+    }
+    if h >= 8 && (hearts == 4 || spades == 4) {
+        return bid(2, Strain::Club);
+    }
+    match h {
+        0..=7 => Call::Pass,
+        8..=9 => bid(2, Strain::NoTrump),
+        10..=17 => bid(3, Strain::NoTrump),
+        18..=21 => bid(6, Strain::NoTrump),
+        _ => bid(7, Strain::NoTrump),
+    }
+}
+
+/// Responds to partner's weak two-bid or preempt.
+fn respond_preempt(hand: &HandInfo, opening: Bid) -> Call {
+    let Some(suit) = opening.strain.trump() else {
+        return Call::Pass;
+    };
+    let support = hand.len(suit);
+    let major = opening.strain.is_major();
+    match hand.hcp {
+        16.. if major && support >= 2 => bid(4, opening.strain),
+        16.. if hand.is_balanced() => bid(3, Strain::NoTrump),
+        10.. if major && support >= 4 => bid(4, opening.strain),
+        _ if support >= 3 && opening.level == 2 => bid(3, opening.strain),
+        _ => Call::Pass,
+    }
+}
+
+/// Responds to partner's opening bid of one of a suit.
+fn respond_one(hand: &HandInfo, opening: Strain) -> Call {
+    let h = hand.hcp;
+    if h < 6 {
+        return Call::Pass;
+    }
+    let Some(suit) = opening.trump() else {
+        return Call::Pass;
+    };
+    if opening.is_major() && hand.len(suit) >= 3 {
+        return match h {
+            6..=9 => bid(2, opening),
+            10..=12 => bid(3, opening),
+            _ => bid(4, opening),
+        };
+    }
+
+    // A new suit at the one level, preferring majors over a minor opening.
+    let higher: Vec<Suit> = Suit::all_suits()
+        .iter()
+        .copied()
+        .filter(|&s| Strain::from(s) > opening && hand.len(s) >= 4)
+        .collect();
+    let pick = |suits: &[Suit]| {
+        suits.iter().copied().max_by_key(|&s| {
+            let len = hand.len(s);
+            // Bid four-card suits up the line, and the higher of five-card suits.
+            let order = Strain::from(s) as i8;
+            (len, if len >= 5 { order } else { -order })
+        })
+    };
+    let majors: Vec<Suit> = higher
+        .iter()
+        .copied()
+        .filter(|&s| Strain::from(s).is_major())
+        .collect();
+    let choice = if !majors.is_empty() && (hand.len(Suit::Diamond) < 5 || h < 11) {
+        pick(&majors)
+    } else {
+        pick(&higher)
+    };
+    if let Some(suit) = choice {
+        return bid(1, Strain::from(suit));
+    }
+
+    // A new suit at the two level.
+    if h >= 10 {
+        let lower: Vec<Suit> = Suit::all_suits()
+            .iter()
+            .copied()
+            .filter(|&s| Strain::from(s) < opening && hand.len(s) >= 4)
+            .filter(|&s| !(s == Suit::Heart && hand.len(s) < 5))
+            .collect();
+        if let Some(suit) = pick(&lower) {
+            return bid(2, Strain::from(suit));
+        }
+    }
+
+    if opening.is_minor() && hand.len(suit) >= 5 {
+        match h {
+            6..=9 => return bid(2, opening),
+            10..=12 => return bid(3, opening),
+            _ => (),
+        }
+    }
+    match h {
+        6..=10 => bid(1, Strain::NoTrump),
+        11..=12 => bid(2, Strain::NoTrump),
+        13..=15 => bid(3, Strain::NoTrump),
+        _ => {
+            let longest = longest_suit(hand);
+            let strain = Strain::from(longest);
+            bid(if strain > opening { 1 } else { 2 }, strain)
+        }
+    }
+}
+
+/// Chooses the opener's rebid, after partner's response.
+fn opener_rebid(hand: &HandInfo, opening: Strain, response: Bid) -> Call {
+    let h = hand.hcp;
+    let Some(suit) = opening.trump() else {
+        return Call::Pass;
+    };
+    let own = hand.len(suit);
+    let last = Some(response);
+    let game = |strain: Strain| {
+        if strain.is_minor() && hand.is_balanced() {
+            bid(3, Strain::NoTrump)
+        } else {
+            bid(strain.game_level(), strain)
+        }
+    };
+    // A second suit, other than the ones bid so far.
+    let second = Suit::all_suits()
+        .iter()
+        .copied()
+        .filter(|&s| s != suit && Strain::from(s) != response.strain && hand.len(s) >= 4)
+        .max_by_key(|&s| (hand.len(s), Strain::from(s)));
+
+    if response.strain == opening {
+        return match (response.level, h) {
+            (2, 19..) | (3, 14..) => game(opening),
+            (2, 16..) => bid(3, opening),
+            _ => Call::Pass,
+        };
+    }
+
+    if response.strain == Strain::NoTrump {
+        return match response.level {
+            1 => {
+                if hand.is_balanced() && h >= 19 {
+                    bid(3, Strain::NoTrump)
+                } else if hand.is_balanced() && h >= 18 {
+                    bid(2, Strain::NoTrump)
+                } else if own >= 6 {
+                    bid(if h >= 16 { 3 } else { 2 }, opening)
+                } else if let Some(s) = second.filter(|&s| Strain::from(s) < opening || h >= 17) {
+                    bid(2, Strain::from(s))
+                } else {
+                    Call::Pass
+                }
+            }
+            2 if h >= 14 && own >= 6 && opening.is_major() => bid(4, opening),
+            2 if h >= 14 => bid(3, Strain::NoTrump),
+            _ => Call::Pass,
+        };
+    }
+
+    // Partner bid a new suit, which is forcing.
+    let Some(theirs) = response.strain.trump() else {
+        return Call::Pass;
+    };
+    let cheapest = |strain| cheapest_level(last, strain);
+    if hand.len(theirs) >= 4 {
+        let level = cheapest(response.strain);
+        return match h {
+            0..=15 => bid(level, response.strain),
+            16..=18 => bid(level + 1, response.strain),
+            _ => game(response.strain),
+        };
+    }
+    if hand.is_balanced() {
+        let level = cheapest(Strain::NoTrump);
+        return bid(if h >= 18 { level + 1 } else { level }, Strain::NoTrump);
+    }
+    if own >= 6 {
+        let level = cheapest(opening);
+        return bid(if h >= 16 { level + 1 } else { level }, opening);
+    }
+    if let Some(s) = second {
+        let strain = Strain::from(s);
+        let level = cheapest(strain);
+        if level == 1 || strain < opening || h >= 17 {
+            return bid(level, strain);
+        }
+    }
+    if h < 16 {
+        bid(cheapest(opening), opening)
+    } else {
+        bid(cheapest(Strain::NoTrump), Strain::NoTrump)
+    }
+}
+
+/// Places the contract based on the partnership's combined values, once the conventional
+/// sequences are over.
+fn placement(hand: &HandInfo, auction: &Auction, seat: Seat) -> Call {
+    let Some((last_seat, last)) = auction.last_bid() else {
+        return Call::Pass;
+    };
+    let partner = describe(auction, seat.opposite());
+    let team = seat.team();
+    let ours_last = last_seat.team() == team;
+
+    // The best trump fit, if any: a major where possible.
+    let fit = Suit::all_suits()
+        .iter()
+        .copied()
+        .map(|s| (s, hand.len(s) + partner.lengths[index(s)]))
+        .filter(|&(_, len)| len >= 8)
+        .max_by_key(|&(s, len)| (Strain::from(s).is_major(), len));
+    let strain = match fit {
+        Some((s, _)) if Strain::from(s).is_major() => Strain::from(s),
+        Some((s, _)) if !hand.is_balanced() && !partner.balanced => Strain::from(s),
+        _ => Strain::NoTrump,
+    };
+
+    // A strong 2♣ opening forces the partnership to game.
+    let first = auction.iter().find_map(|(s, c)| match c {
+        Call::Bid(b) => Some((s, b)),
+        _ => None,
+    });
+    let opened_2c = first.is_some_and(|(s, b)| s.team() == team && b == Bid::new(2, Strain::Club));
+    let min = hand.hcp + partner.min;
+    let mut expected = hand.hcp + partner.expected();
+    if opened_2c {
+        expected = expected.max(25);
+    }
+
+    let slam = match min {
+        37.. => 7,
+        33.. => 6,
+        _ => 0,
+    };
+    if slam > last.level {
+        return bid(slam, strain);
+    }
+    if ours_last && last.is_game() {
+        return Call::Pass;
+    }
+    if expected >= 25 {
+        let game = Bid::new(strain.game_level(), strain);
+        if game > last {
+            return Call::Bid(game);
+        }
+        if !ours_last && auction.is_legal(Call::Double) && last.level >= 3 {
+            return Call::Double;
+        }
+        return Call::Pass;
+    }
+    if expected >= 23 && ours_last {
+        let invite = if strain.is_major() {
+            Bid::new(3, strain)
+        } else {
+            Bid::new(2, Strain::NoTrump)
+        };
+        if invite > last {
+            return Call::Bid(invite);
+        }
+        return Call::Pass;
+    }
+    if !ours_last {
+        if let Some((suit, len)) = fit {
+            let strain = Strain::from(suit);
+            let level = cheapest_level(Some(last), strain);
+            if level + 6 <= len && expected >= 17 {
+                return bid(level, strain);
+            }
+        }
+        let defends = last.strain.trump().is_none_or(|t| hand.len(t) >= 4);
+        if auction.is_legal(Call::Double) && last.level >= 2 && defends && expected >= 23 {
+            return Call::Double;
+        }
+    }
+    Call::Pass
+}
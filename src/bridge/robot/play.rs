@@ -0,0 +1,294 @@
+//! The robot's card play.
+
+use crate::bridge::{rank_of, suit_of, Call, Card, DealView, Rank, Seat, Suit, Trick};
+
+/// Chooses a card for the player whose turn it is to play, which may be the dummy.
+pub(super) fn choose_card(view: &DealView) -> Card {
+    let play = Play::new(view);
+    match view.current_trick() {
+        None if view.tricks.is_empty() => play.opening_lead(view),
+        None => play.lead(view),
+        Some(trick) => play.follow(trick),
+    }
+}
+
+/// What the player knows when choosing a card.
+struct Play<'a> {
+    /// The player whose card is to be played.
+    seat: Seat,
+    /// Their cards.
+    hand: &'a [Card],
+    /// Their partner's cards, if they're visible: the declarer and the dummy see each other's.
+    partner_hand: Option<&'a [Card]>,
+    /// The trump suit, if any.
+    trump: Option<Suit>,
+    /// Whether the player is on the declaring side.
+    declaring: bool,
+    /// The cards that have been played.
+    played: Vec<Card>,
+}
+
+impl<'a> Play<'a> {
+    fn new(view: &'a DealView<'_>) -> Self {
+        let seat = view.to_play.expect("a card is to be played");
+        let contract = view.contract.expect("the auction is over");
+        let declaring = seat.team() == contract.declarer.team();
+        let partner_hand = if !declaring {
+            None
+        } else if seat == contract.declarer {
+            view.dummy
+        } else {
+            Some(view.hand)
+        };
+        Self {
+            seat,
+            hand: view
+                .playing_hand()
+                .expect("the cards to be played are visible"),
+            partner_hand,
+            trump: contract.bid.strain.trump(),
+            declaring,
+            played: view.played().map(|(_, card)| card).collect(),
+        }
+    }
+
+    /// The player's cards in the suit, from lowest to highest.
+    fn suit_cards(cards: &[Card], suit: Suit) -> Vec<Card> {
+        let mut cards: Vec<Card> = cards
+            .iter()
+            .copied()
+            .filter(|&c| suit_of(c) == suit)
+            .collect();
+        cards.sort_by_key(|&c| rank_of(c));
+        cards
+    }
+
+    /// Returns true if the card is known to be held by the player or their partner.
+    fn is_ours(&self, card: Card) -> bool {
+        self.hand.contains(&card) || self.partner_hand.is_some_and(|h| h.contains(&card))
+    }
+
+    /// Returns true if no opponent can hold a higher card of the same suit.
+    fn is_master(&self, card: Card) -> bool {
+        let suit = suit_of(card);
+        Rank::all_ranks()
+            .iter()
+            .filter(|r| r.ace_high() > rank_of(card))
+            .map(|&r| Card::new(r, suit))
+            .all(|c| self.played.contains(&c) || self.is_ours(c))
+    }
+
+    /// Returns the lowest card that is equivalent to this one, in that no card between them is
+    /// outstanding.
+    fn lowest_equivalent(&self, card: Card) -> Card {
+        let suit = suit_of(card);
+        let mut lowest = card;
+        for &rank in Rank::all_ranks().iter().rev() {
+            if rank.ace_high() >= rank_of(card) {
+                continue;
+            }
+            let c = Card::new(rank, suit);
+            if self.hand.contains(&c) {
+                lowest = c;
+            } else if !self.played.contains(&c) {
+                break;
+            }
+        }
+        lowest
+    }
+
+    /// The number of trumps that the opponents may still hold.
+    fn outstanding_trumps(&self) -> usize {
+        let Some(trump) = self.trump else {
+            return 0;
+        };
+        Rank::all_ranks()
+            .iter()
+            .map(|&r| Card::new(r, trump))
+            .filter(|&c| !self.played.contains(&c) && !self.is_ours(c))
+            .count()
+    }
+
+    /// The suit partner bid first, other than trumps.
+    fn partner_suit(&self, view: &DealView) -> Option<Suit> {
+        view.auction.iter().find_map(|(seat, call)| match call {
+            Call::Bid(bid) if seat == self.seat.opposite() => {
+                bid.strain.trump().filter(|&s| Some(s) != self.trump)
+            }
+            _ => None,
+        })
+    }
+
+    /// Leads a card from the suit: the top of a doubleton or of touching honors, or a low card.
+    fn lead_from(&self, suit: Suit) -> Card {
+        let cards = Self::suit_cards(self.hand, suit);
+        let len = cards.len();
+        let top = cards[len - 1];
+        let touching = len >= 2 && rank_of(top) >= 9 && rank_of(cards[len - 2]) + 1 == rank_of(top);
+        if len <= 2 || touching {
+            top
+        } else if len >= 4 {
+            cards[len - 4]
+        } else {
+            cards[0]
+        }
+    }
+
+    fn opening_lead(&self, view: &DealView) -> Card {
+        let suits: Vec<Suit> = Suit::all_suits()
+            .iter()
+            .copied()
+            .filter(|&s| Some(s) != self.trump && self.hand.iter().any(|&c| suit_of(c) == s))
+            .collect();
+        if let Some(suit) = self.partner_suit(view).filter(|s| suits.contains(s)) {
+            return self.lead_from(suit);
+        }
+        if suits.is_empty() {
+            return self.lowest(self.hand);
+        }
+        let ace = |s| self.hand.contains(&Card::new(Rank::Ace, s));
+        let king = |s| self.hand.contains(&Card::new(Rank::King, s));
+        if self.trump.is_some() {
+            if let Some(&suit) = suits.iter().find(|&&s| ace(s) && king(s)) {
+                return Card::new(Rank::Ace, suit);
+            }
+            let has_trumps = self.hand.iter().any(|&c| Some(suit_of(c)) == self.trump);
+            let singleton = suits
+                .iter()
+                .copied()
+                .find(|&s| Self::suit_cards(self.hand, s).len() == 1);
+            if let (Some(suit), true) = (singleton, has_trumps) {
+                return self.lead_from(suit);
+            }
+        }
+        // The longest suit, avoiding leading away from a lone ace against a suit contract.
+        let suit = suits
+            .iter()
+            .copied()
+            .max_by_key(|&s| {
+                let underlead = self.trump.is_some() && ace(s) && !king(s);
+                (!underlead, Self::suit_cards(self.hand, s).len())
+            })
+            .expect("a side suit");
+        self.lead_from(suit)
+    }
+
+    fn lead(&self, view: &DealView) -> Card {
+        if self.declaring {
+            // Draw trumps, while the opponents have them.
+            if let Some(trump) = self.trump {
+                let trumps = Self::suit_cards(self.hand, trump);
+                if self.outstanding_trumps() > 0 && !trumps.is_empty() {
+                    let top = trumps[trumps.len() - 1];
+                    return if self.is_master(top) { top } else { trumps[0] };
+                }
+            }
+        } else {
+            // Return partner's lead.
+            let partner = self.seat.opposite();
+            let led = view
+                .tricks
+                .iter()
+                .filter(|t| t.cards.first().is_some_and(|&(s, _)| s == partner))
+                .filter_map(Trick::lead_suit)
+                .find(|&s| Some(s) != self.trump && self.hand.iter().any(|&c| suit_of(c) == s));
+            if let Some(suit) = led {
+                return self.lead_from(suit);
+            }
+        }
+
+        // Cash winners.
+        if let Some(&card) = self
+            .hand
+            .iter()
+            .filter(|&&c| Some(suit_of(c)) != self.trump)
+            .find(|&&c| self.is_master(c))
+        {
+            return card;
+        }
+
+        // Lead low from the longest side suit.
+        let length = |s: Suit| {
+            let partner = self
+                .partner_hand
+                .map_or(0, |h| Self::suit_cards(h, s).len());
+            Self::suit_cards(self.hand, s).len() + partner
+        };
+        let suit = Suit::all_suits()
+            .iter()
+            .copied()
+            .filter(|&s| Some(s) != self.trump && self.hand.iter().any(|&c| suit_of(c) == s))
+            .max_by_key(|&s| length(s));
+        match suit {
+            Some(suit) => Self::suit_cards(self.hand, suit)[0],
+            None => self.lowest(self.hand),
+        }
+    }
+
+    /// Returns true if the card would win the trick, if played now.
+    fn beats(trick: &Trick, card: Card, seat: Seat) -> bool {
+        let mut trick = trick.clone();
+        trick.cards.push((seat, card));
+        trick.winner() == Some((seat, card))
+    }
+
+    /// The lowest card, preferring to keep trumps.
+    fn lowest(&self, cards: &[Card]) -> Card {
+        *cards
+            .iter()
+            .min_by_key(|&&c| (Some(suit_of(c)) == self.trump, rank_of(c)))
+            .expect("a card to play")
+    }
+
+    /// The least valuable card to throw away: a low card that isn't a winner, keeping trumps.
+    fn discard(&self, cards: &[Card]) -> Card {
+        *cards
+            .iter()
+            .min_by_key(|&&c| {
+                (
+                    Some(suit_of(c)) == self.trump,
+                    self.is_master(c),
+                    rank_of(c),
+                )
+            })
+            .expect("a card to discard")
+    }
+
+    fn follow(&self, trick: &Trick) -> Card {
+        let legal = trick.filter(self.hand);
+        let (winner, winning) = trick.winner().expect("trick is led");
+        let partner_winning = winner == self.seat.opposite();
+        let last_to_play = trick.cards.len() == 3;
+        let mut beaters: Vec<Card> = legal
+            .iter()
+            .copied()
+            .filter(|&c| Self::beats(trick, c, self.seat))
+            .collect();
+        beaters.sort_by_key(|&c| (Some(suit_of(c)) == self.trump, rank_of(c)));
+
+        let following = trick.lead_suit() == Some(suit_of(legal[0]));
+        if following {
+            if partner_winning || beaters.is_empty() {
+                return self.lowest(&legal);
+            }
+            return match trick.cards.len() {
+                // Second hand low.
+                1 => self.lowest(&legal),
+                // Third hand high, but no higher than necessary.
+                2 => match beaters.iter().find(|&&c| self.is_master(c)) {
+                    Some(&card) => card,
+                    None => self.lowest_equivalent(beaters[beaters.len() - 1]),
+                },
+                // Fourth hand wins as cheaply as possible.
+                _ => beaters[0],
+            };
+        }
+
+        // Ruff, unless partner has the trick.
+        let safe = partner_winning && (last_to_play || self.is_master(winning));
+        match beaters.first() {
+            Some(&card) if !safe && Some(suit_of(card)) == self.trump => card,
+            _ => self.discard(&legal),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::bridge::{Bid, Call, Deal, DealConfig, Seat, SeatMap, Strain, TeamMap};
+use crate::deck::Deck;
+use crate::engine::Engine;
+use crate::french::cards;
+
+fn bid(level: u8, strain: Strain) -> Call {
+    Call::Bid(Bid::new(level, strain))
+}
+
+/// Sets up a deal in which the seat holds the specified hand, with the remaining cards dealt to
+/// the other seats, and makes the calls starting from the dealer.
+fn deal_with(seat: Seat, hand: &str, dealer: Seat, calls: &[Call]) -> Deal {
+    let hand = cards(hand);
+    let mut rest = Deck::standard().into_iter().filter(|c| !hand.contains(c));
+    let hands = SeatMap::from_fn(|s| {
+        if s == seat {
+            hand.clone()
+        } else {
+            rest.by_ref().take(13).collect()
+        }
+    });
+    let config = DealConfig::from_hands(dealer, TeamMap::default(), hands).unwrap();
+    let mut deal = Deal::from(config);
+    for &call in calls {
+        deal.apply(Action::Call(call)).unwrap();
+    }
+    deal
+}
+
+fn choose(deal: &Deal) -> Action {
+    let seat = deal.expected_actor().unwrap();
+    Robot.choose(&deal.view(seat))
+}
+
+#[test]
+fn test_hand_info() {
+    let info = HandInfo::new(&cards("AS KS 4S 3S KH 9H 5H AD 7D 6D QC 8C 2C"));
+    assert_eq!(info.hcp, 16);
+    assert_eq!(info.len(Suit::Spade), 4);
+    assert!(info.is_balanced());
+    let info = HandInfo::new(&cards("KS QS JS 9S 8S 2S 5H 4H 6D 3D 8C 7C 2C"));
+    assert_eq!(info.hcp, 6);
+    assert!(!info.is_balanced());
+}
+
+#[test]
+fn test_opening() {
+    let cases = [
+        (
+            "AS KS 4S 3S KH 9H 5H AD 7D 6D QC 8C 2C",
+            bid(1, Strain::NoTrump),
+        ),
+        (
+            "AS KS 9S 8S 7S QH 4H 3H KD 5D 2D 6C 3C",
+            bid(1, Strain::Spade),
+        ),
+        (
+            "KS QS JS 9S 8S 2S 5H 4H 6D 3D 8C 7C 2C",
+            bid(2, Strain::Spade),
+        ),
+        (
+            "AS KS QS 4S AH KH QH AD KD 5D AC 3C 2C",
+            bid(2, Strain::Club),
+        ),
+        ("QS 8S 4S 3S JH 9H 5H 8D 7D 6D KC 8C 2C", Call::Pass),
+    ];
+    for &(hand, call) in cases.iter() {
+        let deal = deal_with(Seat::North, hand, Seat::North, &[]);
+        assert_eq!(choose(&deal), Action::Call(call), "{hand}");
+    }
+}
+
+#[test]
+fn test_no_trump_responses() {
+    let opened = [bid(1, Strain::NoTrump), Call::Pass];
+    // A transfer to hearts, completed by the opener.
+    let hand = "2S 3S KH QH 8H 7H 6H 4D 5D 2D 3C 4C 5C";
+    let deal = deal_with(Seat::South, hand, Seat::North, &opened);
+    assert_eq!(choose(&deal), Action::Call(bid(2, Strain::Diamond)));
+    let calls = [opened[0], opened[1], bid(2, Strain::Diamond), Call::Pass];
+    let deal = deal_with(Seat::South, hand, Seat::North, &calls);
+    assert_eq!(choose(&deal), Action::Call(bid(2, Strain::Heart)));
+
+    // Stayman, with a four-card major and invitational values.
+    let hand = "AS QS 8S 7S KH 5H 3H QD 4D 2D 6C 4C 3C";
+    let deal = deal_with(Seat::South, hand, Seat::North, &opened);
+    assert_eq!(choose(&deal), Action::Call(bid(2, Strain::Club)));
+}
+
+#[test]
+fn test_support_and_overcall() {
+    let hand = "8S 4S 2S KH 9H 5H QD 7D 6D 3D KC 8C 2C";
+    let deal = deal_with(
+        Seat::South,
+        hand,
+        Seat::North,
+        &[bid(1, Strain::Heart), Call::Pass],
+    );
+    assert_eq!(choose(&deal), Action::Call(bid(2, Strain::Heart)));
+
+    let hand = "AS QS 9S 8S 7S KH 4H 3H 5D 2D 6C 4C 3C";
+    let deal = deal_with(Seat::South, hand, Seat::East, &[bid(1, Strain::Club)]);
+    assert_eq!(choose(&deal), Action::Call(bid(1, Strain::Spade)));
+}
+
+#[test]
+fn test_opening_lead() {
+    let calls = [bid(4, Strain::Spade), Call::Pass, Call::Pass, Call::Pass];
+    let hand = "AH KH 7H 4H 9D 8D 5D 3C 2C 6S 7S 8S 9S";
+    let deal = deal_with(Seat::North, hand, Seat::West, &calls);
+    assert_eq!(choose(&deal), Action::Play("AH".parse().unwrap()));
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(0x6272_6964);
+    for i in 0..100 {
+        let dealer = Seat::all_seats()[i % 4];
+        let mut deal = Deal::from(DealConfig::random(dealer, TeamMap::default(), &mut rng));
+        let result = deal
+            .play_out(|deal, seat, legal| {
+                let action = Robot.choose(&deal.view(seat));
+                assert!(legal.contains(&action));
+                action
+            })
+            .unwrap()
+            .expect("deal is over");
+        if let Some(contract) = result.contract {
+            assert!(contract.bid.level <= 7);
+            assert_eq!(deal.tricks().len(), 13);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Rubber scoring.
+//!
+//! Points for contracts bid and made are scored "below the line", and count towards game. Every
+//! other score, such as overtricks, bonuses, and penalties, is scored "above the line". The first
+//! side to 100 points below the line wins a game, and becomes vulnerable; the first side to win
+//! two games wins the rubber.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Contract, Doubling, Rank, Seat, SeatMap, Strain, Team, TeamMap};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The points scored on a single deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Score {
+    /// Points towards game.
+    pub below: TeamMap<u32>,
+    /// Every other point.
+    pub above: TeamMap<u32>,
+}
+
+impl Score {
+    /// The total points scored by the team.
+    pub fn total(&self, team: Team) -> u32 {
+        self.below[team] + self.above[team]
+    }
+
+    /// Scores a played contract, given the number of tricks taken by the declaring side, whether
+    /// they were vulnerable, and any honors held.
+    pub fn new(
+        contract: &Contract,
+        tricks: u8,
+        vulnerable: bool,
+        honors: Option<(Seat, u32)>,
+    ) -> Self {
+        let mut score = Self::default();
+        let declarers = contract.declarer.team();
+        let multiplier = contract.doubling.multiplier();
+        let target = contract.target();
+        if tricks >= target {
+            let level = u32::from(contract.bid.level);
+            let strain = contract.bid.strain;
+            let mut value = level * strain.trick_value();
+            if strain == Strain::NoTrump {
+                value += 10;
+            }
+            score.below[declarers] = value * multiplier;
+
+            let over = u32::from(tricks - target);
+            let per_overtrick = match (contract.doubling, vulnerable) {
+                (Doubling::Undoubled, _) => strain.trick_value(),
+                (Doubling::Doubled, false) => 100,
+                (Doubling::Doubled, true) => 200,
+                (Doubling::Redoubled, false) => 200,
+                (Doubling::Redoubled, true) => 400,
+            };
+            let insult = match contract.doubling {
+                Doubling::Undoubled => 0,
+                Doubling::Doubled => 50,
+                Doubling::Redoubled => 100,
+            };
+            let slam = match (contract.bid.level, vulnerable) {
+                (6, false) => 500,
+                (6, true) => 750,
+                (7, false) => 1000,
+                (7, true) => 1500,
+                _ => 0,
+            };
+            score.above[declarers] = over * per_overtrick + insult + slam;
+        } else {
+            score.above[declarers.other()] =
+                undertricks(target - tricks, contract.doubling, vulnerable);
+        }
+        if let Some((seat, points)) = honors {
+            score.above[seat.team()] += points;
+        }
+        score
+    }
+}
+
+/// The penalty for going down the specified number of tricks.
+fn undertricks(down: u8, doubling: Doubling, vulnerable: bool) -> u32 {
+    let down = u32::from(down);
+    let doubled = match (doubling, vulnerable) {
+        (Doubling::Undoubled, false) => return down * 50,
+        (Doubling::Undoubled, true) => return down * 100,
+        (_, false) => match down {
+            1 => 100,
+            2 => 300,
+            3 => 500,
+            _ => 500 + (down - 3) * 300,
+        },
+        (_, true) => 200 + (down - 1) * 300,
+    };
+    if doubling == Doubling::Redoubled {
+        doubled * 2
+    } else {
+        doubled
+    }
+}
+
+/// Finds a hand holding honors in the strain: 150 for all five trump honors, or all four aces at
+/// no trump, and 100 for four of the five trump honors.
+pub fn honors(strain: Strain, hands: &SeatMap<Vec<Card>>) -> Option<(Seat, u32)> {
+    hands.iter().find_map(|(seat, hand)| {
+        let points = match strain.trump() {
+            Some(trump) => {
+                let count = hand
+                    .iter()
+                    .filter(|c| c.suit() == Some(trump) && c.rank().is_some_and(is_honor))
+                    .count();
+                match count {
+                    5 => 150,
+                    4 => 100,
+                    _ => 0,
+                }
+            }
+            None => {
+                let aces = hand.iter().filter(|c| c.rank() == Some(Rank::Ace)).count();
+                if aces == 4 {
+                    150
+                } else {
+                    0
+                }
+            }
+        };
+        (points > 0).then_some((seat, points))
+    })
+}
+
+/// Returns true for the five highest ranks.
+fn is_honor(rank: Rank) -> bool {
+    rank.ace_high() >= Rank::Ten.ace_high()
+}
+
+/// The score of a rubber in progress.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Rubber {
+    /// Points below the line, towards the current game.
+    below: TeamMap<u32>,
+    /// All points scored so far, apart from the rubber bonus.
+    points: TeamMap<u32>,
+    /// Games won.
+    games: TeamMap<u8>,
+    /// The number of deals played, including those that were passed out.
+    deals: usize,
+}
+
+impl Rubber {
+    /// Creates a new rubber.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the team has won a game, and so is vulnerable.
+    pub fn is_vulnerable(&self, team: Team) -> bool {
+        self.games[team] > 0
+    }
+
+    /// The vulnerability of each team.
+    pub fn vulnerability(&self) -> TeamMap<bool> {
+        let mut vulnerable = TeamMap::default();
+        for team in [Team::NorthSouth, Team::EastWest].iter().copied() {
+            vulnerable[team] = self.is_vulnerable(team);
+        }
+        vulnerable
+    }
+
+    /// The number of games won by the team.
+    pub fn games(&self, team: Team) -> u8 {
+        self.games[team]
+    }
+
+    /// The team's points below the line, towards the current game.
+    pub fn below(&self, team: Team) -> u32 {
+        self.below[team]
+    }
+
+    /// The number of deals played so far.
+    pub fn deals(&self) -> usize {
+        self.deals
+    }
+
+    /// Records the score of a deal. Passed-out deals score nothing.
+    pub fn record(&mut self, score: &Score) {
+        self.deals += 1;
+        for team in [Team::NorthSouth, Team::EastWest].iter().copied() {
+            self.below[team] += score.below[team];
+            self.points[team] += score.total(team);
+        }
+        if let Some(team) = [Team::NorthSouth, Team::EastWest]
+            .iter()
+            .copied()
+            .find(|&t| self.below[t] >= 100)
+        {
+            self.games[team] += 1;
+            self.below = TeamMap::default();
+        }
+    }
+
+    /// The team that won the rubber, if it's over.
+    pub fn winner(&self) -> Option<Team> {
+        [Team::NorthSouth, Team::EastWest]
+            .iter()
+            .copied()
+            .find(|&t| self.games[t] == 2)
+    }
+
+    /// Returns true once a team has won two games.
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// The team's total points, including the rubber bonus once the rubber is over: 700 for
+    /// winning two games to none, and 500 for two games to one.
+    pub fn total(&self, team: Team) -> u32 {
+        let bonus = match self.winner() {
+            Some(winner) if winner == team => {
+                if self.games[team.other()] == 0 {
+                    700
+                } else {
+                    500
+                }
+            }
+            _ => 0,
+        };
+        self.points[team] + bonus
+    }
+}
+
+impl Display for Rubber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (ns, ew) = (Team::NorthSouth, Team::EastWest);
+        writeln!(f, "{:<8} {:>6} {:>6}", "", ns.to_abbr(), ew.to_abbr())?;
+        writeln!(
+            f,
+            "{:<8} {:>6} {:>6}",
+            tr!("bridge-games"),
+            self.games[ns],
+            self.games[ew]
+        )?;
+        writeln!(
+            f,
+            "{:<8} {:>6} {:>6}",
+            tr!("bridge-partial"),
+            self.below[ns],
+            self.below[ew]
+        )?;
+        write!(
+            f,
+            "{:<8} {:>6} {:>6}",
+            tr!("bridge-total"),
+            self.total(ns),
+            self.total(ew)
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::bridge::Bid;
+
+fn contract(level: u8, strain: Strain, doubling: Doubling) -> Contract {
+    Contract {
+        bid: Bid::new(level, strain),
+        declarer: Seat::South,
+        doubling,
+    }
+}
+
+#[test]
+fn test_made() {
+    let ns = Team::NorthSouth;
+    let score = Score::new(
+        &contract(3, Strain::NoTrump, Doubling::Undoubled),
+        10,
+        false,
+        None,
+    );
+    assert_eq!(score.below[ns], 100);
+    assert_eq!(score.above[ns], 30);
+
+    let score = Score::new(&contract(2, Strain::Club, Doubling::Doubled), 9, true, None);
+    assert_eq!(score.below[ns], 80);
+    assert_eq!(score.above[ns], 200 + 50);
+
+    let score = Score::new(
+        &contract(6, Strain::Spade, Doubling::Undoubled),
+        12,
+        true,
+        None,
+    );
+    assert_eq!(score.below[ns], 180);
+    assert_eq!(score.above[ns], 750);
+
+    let score = Score::new(
+        &contract(1, Strain::Heart, Doubling::Redoubled),
+        7,
+        false,
+        Some((Seat::West, 100)),
+    );
+    assert_eq!(score.below[ns], 120);
+    assert_eq!(score.above[ns], 100);
+    assert_eq!(score.above[Team::EastWest], 100);
+}
+
+#[test]
+fn test_undertricks() {
+    let cases = [
+        (Doubling::Undoubled, false, 2, 100),
+        (Doubling::Undoubled, true, 2, 200),
+        (Doubling::Doubled, false, 1, 100),
+        (Doubling::Doubled, false, 3, 500),
+        (Doubling::Doubled, false, 5, 1100),
+        (Doubling::Doubled, true, 1, 200),
+        (Doubling::Doubled, true, 3, 800),
+        (Doubling::Redoubled, true, 2, 1000),
+    ];
+    for (doubling, vulnerable, down, expect) in cases.iter().copied() {
+        let score = Score::new(
+            &contract(4, Strain::Heart, doubling),
+            10 - down,
+            vulnerable,
+            None,
+        );
+        assert_eq!(
+            score.above[Team::EastWest],
+            expect,
+            "{doubling:?} {vulnerable} {down}"
+        );
+        assert_eq!(score.total(Team::NorthSouth), 0);
+    }
+}
+
+#[test]
+fn test_honors() {
+    let hand = |cards: &str| -> Vec<Card> {
+        cards
+            .split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect()
+    };
+    let mut hands = SeatMap::<Vec<Card>>::default();
+    hands[Seat::East] = hand("AS KS QS TS 2H");
+    hands[Seat::West] = hand("AH AD AC 3H");
+    assert_eq!(honors(Strain::Spade, &hands), Some((Seat::East, 100)));
+    assert_eq!(honors(Strain::Heart, &hands), None);
+    assert_eq!(honors(Strain::NoTrump, &hands), None);
+    hands[Seat::West].push("AS".parse().unwrap());
+    assert_eq!(honors(Strain::NoTrump, &hands), Some((Seat::West, 150)));
+}
+
+#[test]
+fn test_rubber() {
+    let (ns, ew) = (Team::NorthSouth, Team::EastWest);
+    let part = Score::new(
+        &contract(2, Strain::Heart, Doubling::Undoubled),
+        8,
+        false,
+        None,
+    );
+    let game = Score::new(
+        &contract(3, Strain::NoTrump, Doubling::Undoubled),
+        9,
+        false,
+        None,
+    );
+    let mut rubber = Rubber::new();
+    rubber.record(&part);
+    assert_eq!(rubber.below(ns), 60);
+    assert!(!rubber.is_vulnerable(ns));
+    rubber.record(&part);
+    assert_eq!(rubber.games(ns), 1);
+    assert_eq!(rubber.below(ns), 0);
+    assert!(rubber.vulnerability()[ns]);
+    assert!(!rubber.vulnerability()[ew]);
+    assert!(!rubber.is_over());
+    rubber.record(&game);
+    assert_eq!(rubber.winner(), Some(ns));
+    assert_eq!(rubber.total(ns), 60 + 60 + 100 + 700);
+    assert_eq!(rubber.total(ew), 0);
+    assert_eq!(rubber.deals(), 3);
+}
@@ -0,0 +1,109 @@
+//! Briscola.
+//!
+//! Two players are each dealt three cards from the 40-card Italian deck, and the next card is
+//! turned face up beneath the stock; its suit, the briscola, is trump. The dealer's opponent
+//! leads to the first trick. There's no need to follow suit: the highest trump wins the trick,
+//! or else the highest card of the suit led. Cards rank ace, three, king, queen, jack, and then
+//! seven down to two. The winner of each trick draws from the stock first, the turned-up card
+//! being drawn last, and leads to the next.
+//!
+//! Aces count 11 card points, threes 10, kings 4, queens 3 and jacks 2, for 120 in all. Once
+//! every card is played, the player with more than 60 points wins.
+
+mod error;
+mod robot;
+mod round;
+
+use rand::Rng;
+
+pub use self::error::BriscolaError;
+pub use self::robot::Robot;
+pub use self::round::{Event, RawRound, Round, RoundConfig, RoundResult, RoundView};
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::italian::{deck, format_cards, Card, Rank, Suit};
+use crate::tr;
+
+/// The number of players.
+pub const PLAYERS: u8 = 2;
+
+/// The card points of a card: 11 for an ace, 10 for a three, 4 for a king, 3 for a queen, 2 for
+/// a jack, and nothing otherwise.
+pub fn points(card: Card) -> u32 {
+    match card.rank() {
+        Some(Rank::Ace) => 11,
+        Some(Rank::Three) => 10,
+        Some(Rank::King) => 4,
+        Some(Rank::Queen) => 3,
+        Some(Rank::Jack) => 2,
+        _ => 0,
+    }
+}
+
+/// The rank of a card within its suit, from 0 for a two up to 9 for an ace.
+pub fn rank_order(card: Card) -> u8 {
+    match card.rank() {
+        Some(Rank::Ace) => 9,
+        Some(Rank::Three) => 8,
+        Some(Rank::King) => 7,
+        Some(Rank::Queen) => 6,
+        Some(Rank::Jack) => 5,
+        Some(Rank::Two) | None => 0,
+        Some(rank) => rank as u8 - 2,
+    }
+}
+
+/// Returns true if the second card played to a trick beats the first: it must be a higher card
+/// of the same suit, or a trump played against a card that isn't a trump.
+pub fn beats(briscola: Suit, first: Card, second: Card) -> bool {
+    if first.suit() == second.suit() {
+        rank_order(second) > rank_order(first)
+    } else {
+        second.suit() == Some(briscola)
+    }
+}
+
+/// Plays a game with a simple command-line interface, with the user as the first player and a
+/// robot as the second. The record of the game is written to stderr when it's over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let me = Player(0);
+    let dealer = Player(rand::thread_rng().gen_range(0..PLAYERS));
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    let mut round = Round::from(RoundConfig::random(dealer, &mut rand::thread_rng()));
+    console.play(&mut round, me, |round, player, legal| {
+        robot.choose(&round.view(player), &legal)
+    })?;
+    serde_json::to_writer(std::io::stderr(), &RawRound::from(&round))?;
+    Ok(())
+}
+
+/// Plays games in a rich terminal UI, with the user as the first player and a robot as the
+/// second. The deal alternates between games.
+#[cfg(feature = "tui")]
+pub fn tui_main() -> anyhow::Result<()> {
+    let mut tui = crate::engine::tui::Tui::new("Briscola")?;
+    let robot = Robot;
+    let me = Player(0);
+    let mut dealer = Player(rand::thread_rng().gen_range(0..PLAYERS));
+    let mut wins = [0; PLAYERS as usize];
+    tui.println(tr!("game-you-are", player = me.to_string()));
+    loop {
+        let mut round = Round::from(RoundConfig::random(dealer, &mut rand::thread_rng()));
+        let Some(result) = tui.play(&mut round, me, |round, player, legal| {
+            robot.choose(&round.view(player), &legal)
+        })?
+        else {
+            return Ok(());
+        };
+        if let Some(winner) = result.winner() {
+            wins[winner.index()] += 1;
+        }
+        tui.println(tr!("briscola-games-won", first = wins[0], second = wins[1]));
+        if !tui.pause(&tr!("engine-next-game"))? {
+            return Ok(());
+        }
+        dealer = dealer.next(PLAYERS);
+    }
+}
@@ -0,0 +1,17 @@
+//! Errors
+
+use super::{Card, Player};
+
+/// An error that can occur during a game.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BriscolaError {
+    /// The deck isn't the 40-card Italian deck.
+    #[error("the deck must hold the 40 cards of the Italian deck")]
+    InvalidDeck,
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The game is over, no more cards are expected.
+    #[error("game is over")]
+    GameOver,
+}
@@ -0,0 +1,45 @@
+//! A robot player.
+//!
+//! Leading, the robot plays its cheapest card, holding back trumps and counters. Following, it
+//! takes the trick with a card of the suit led if it can, and otherwise trumps a lead worth
+//! having; failing both, it throws its cheapest card.
+
+use super::{beats, points, rank_order, Card, RoundView};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal cards for the player whose turn it is.
+    pub fn choose(&self, view: &RoundView, legal: &[Card]) -> Card {
+        let trump = view.trump();
+        let cost = |&c: &Card| (c.suit() == Some(trump), points(c), rank_order(c));
+        let cheapest = || legal.iter().copied().min_by_key(cost).unwrap_or(legal[0]);
+        let Some(lead) = view.lead else {
+            return cheapest();
+        };
+        let same_suit = legal
+            .iter()
+            .copied()
+            .filter(|&c| c.suit() == lead.suit() && beats(trump, lead, c))
+            .max_by_key(|&c| (points(c), rank_order(c)));
+        if let Some(card) = same_suit {
+            return card;
+        }
+        if points(lead) >= 4 {
+            let trump_card = legal
+                .iter()
+                .copied()
+                .filter(|&c| beats(trump, lead, c))
+                .min_by_key(cost);
+            if let Some(card) = trump_card {
+                return card;
+            }
+        }
+        cheapest()
+    }
+}
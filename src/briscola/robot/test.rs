@@ -0,0 +1,60 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::briscola::{Player, Round, RoundConfig};
+use crate::engine::Engine;
+use crate::italian::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+fn view_of(hand: &[Card], lead: Option<Card>) -> RoundView<'_> {
+    RoundView {
+        player: Player(0),
+        dealer: Player(1),
+        briscola: card("7♦"),
+        hand,
+        opponent_hand: 3,
+        lead,
+        points: &[0, 0],
+        stock: 20,
+        to_play: Some(Player(0)),
+    }
+}
+
+#[test]
+fn test_choose() {
+    // Leading, hold back the trump and the counter.
+    let hand = cards("A♣ 2♦ 4♤");
+    assert_eq!(Robot.choose(&view_of(&hand, None), &hand), card("4♤"));
+    // Take the trick with the best card of the suit led.
+    let hand = cards("3♣ 6♣ 2♦");
+    let view = view_of(&hand, Some(card("K♣")));
+    assert_eq!(Robot.choose(&view, &hand), card("3♣"));
+    // Trump a king, but not a worthless card.
+    let hand = cards("Q♦ 2♦ 6♤");
+    let view = view_of(&hand, Some(card("K♡")));
+    assert_eq!(Robot.choose(&view, &hand), card("2♦"));
+    let view = view_of(&hand, Some(card("5♡")));
+    assert_eq!(Robot.choose(&view, &hand), card("6♤"));
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(13);
+    let mut wins = [0; 2];
+    for i in 0..50 {
+        let mut round = Round::from(RoundConfig::random(Player(i % 2), &mut rng));
+        let result = round
+            .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+            .unwrap()
+            .expect("round is over");
+        assert_eq!(result.points.iter().sum::<u32>(), 120);
+        if let Some(winner) = result.winner() {
+            wins[winner.index()] += 1;
+        }
+    }
+    assert!(wins.iter().all(|&w| w > 0), "{:?}", wins);
+}
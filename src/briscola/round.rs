@@ -0,0 +1,389 @@
+//! A single round: from the deal until every card is played.
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beats, deck, format_cards, points, rank_order, BriscolaError, Card, Player, Suit, PLAYERS,
+};
+use crate::deck::{Deck, Stock};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of cards in each player's hand.
+const HAND_SIZE: usize = 3;
+
+/// The order of the deck, and the dealer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundConfig {
+    dealer: Player,
+    deck: Vec<Card>,
+}
+
+impl RoundConfig {
+    /// Shuffles the deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        Self {
+            dealer,
+            deck: deck.into_iter().collect(),
+        }
+    }
+
+    /// Creates a round from the specified deck, which must hold the cards of the Italian deck.
+    /// The last card is the top of the deck, and the first is turned up to fix the briscola.
+    pub fn new(dealer: Player, deck: Vec<Card>) -> Result<Self, BriscolaError> {
+        let config = Self { dealer, deck };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that the deck holds exactly the cards of the Italian deck.
+    pub fn validate(&self) -> Result<(), BriscolaError> {
+        let expected: HashSet<Card> = deck().into_iter().collect();
+        let actual: HashSet<Card> = self.deck.iter().copied().collect();
+        if self.dealer.0 >= PLAYERS || self.deck.len() != expected.len() || actual != expected {
+            return Err(BriscolaError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer. Their opponent leads to the first trick.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The card turned up beneath the stock, whose suit is trump.
+    pub fn briscola_card(&self) -> Card {
+        self.deck[0]
+    }
+}
+
+/// The result of a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// The card points each player took.
+    pub points: Vec<u32>,
+}
+
+impl RoundResult {
+    /// The player with more than half of the points, or `None` if they split them evenly.
+    pub fn winner(&self) -> Option<Player> {
+        Player::all(PLAYERS).find(|p| self.points[p.index()] > 60)
+    }
+}
+
+impl Display for RoundResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points = self.points.iter().join("-");
+        match self.winner() {
+            Some(winner) => f.write_str(&tr!(
+                "briscola-wins",
+                player = winner.to_string(),
+                points = points
+            )),
+            None => f.write_str(&tr!("briscola-drawn", points = points)),
+        }
+    }
+}
+
+/// An event that occurs during a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// The card was turned up beneath the stock, fixing the briscola.
+    Briscola(Card),
+    /// A player played a card.
+    Play(Player, Card),
+    /// A player won a trick, worth the specified number of points.
+    Trick(Player, u32),
+    /// The round is over.
+    Over(RoundResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Briscola(card) => f.write_str(&tr!("briscola-is", card = card.to_string())),
+            Event::Play(player, card) => {
+                let (player, card) = (player.to_string(), card.to_string());
+                f.write_str(&tr!("game-played", player = player, card = card))
+            }
+            Event::Trick(player, points) => {
+                let text = tr!(
+                    "briscola-trick",
+                    player = player.to_string(),
+                    points = *points
+                );
+                f.write_str(&text)
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the round, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct RoundView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The card turned up beneath the stock.
+    pub briscola: Card,
+    /// The player's cards.
+    pub hand: &'a [Card],
+    /// The number of cards held by their opponent.
+    pub opponent_hand: usize,
+    /// The card led to the current trick, if any.
+    pub lead: Option<Card>,
+    /// The points each player has taken.
+    pub points: &'a [u32],
+    /// The number of cards left in the stock, including the briscola.
+    pub stock: usize,
+    /// The player whose turn it is, or `None` if the round is over.
+    pub to_play: Option<Player>,
+}
+
+impl RoundView<'_> {
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.briscola.suit().expect("not a joker")
+    }
+}
+
+impl Display for RoundView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        let (card, stock) = (self.briscola.to_string(), self.stock);
+        writeln!(f, "{}", tr!("briscola-status", card = card, stock = stock))?;
+        writeln!(
+            f,
+            "{}",
+            tr!("briscola-points", points = self.points.iter().join("-"))
+        )?;
+        if let Some(lead) = self.lead {
+            writeln!(f, "{}", tr!("game-led", card = lead.to_string()))?;
+        }
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a round.
+#[derive(Debug, Clone)]
+pub struct Round {
+    config: RoundConfig,
+    hands: Vec<Vec<Card>>,
+    stock: Stock<Card>,
+    lead: Option<(Player, Card)>,
+    points: Vec<u32>,
+    turn: Player,
+    over: bool,
+    actions: Vec<Card>,
+    events: VecDeque<Event>,
+}
+
+impl From<RoundConfig> for Round {
+    fn from(config: RoundConfig) -> Self {
+        let stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let players = usize::from(PLAYERS);
+        let first = config.dealer.next(PLAYERS);
+        let mut round = Self {
+            hands: vec![vec![]; players],
+            stock,
+            lead: None,
+            points: vec![0; players],
+            turn: first,
+            over: false,
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        };
+        for _ in 0..HAND_SIZE {
+            round.draw(first);
+        }
+        round
+            .events
+            .push_back(Event::Briscola(round.config.briscola_card()));
+        round
+    }
+}
+
+impl Round {
+    /// Draws a card for each player from the stock, if any are left, starting with the player.
+    fn draw(&mut self, first: Player) {
+        let mut player = first;
+        for _ in 0..PLAYERS {
+            if let Some(card) = self.stock.draw() {
+                self.hands[player.index()].push(card);
+            }
+            player = player.next(PLAYERS);
+        }
+    }
+
+    /// The round as it was dealt.
+    pub fn config(&self) -> &RoundConfig {
+        &self.config
+    }
+
+    /// The cards played so far.
+    pub fn actions(&self) -> &[Card] {
+        &self.actions
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.config.briscola_card().suit().expect("not a joker")
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        (!self.over).then_some(self.turn)
+    }
+
+    /// Returns the state of the round, as visible to the player.
+    pub fn view(&self, player: Player) -> RoundView<'_> {
+        RoundView {
+            player,
+            dealer: self.config.dealer,
+            briscola: self.config.briscola_card(),
+            hand: &self.hands[player.index()],
+            opponent_hand: self.hands[player.next(PLAYERS).index()].len(),
+            lead: self.lead.map(|(_, card)| card),
+            points: &self.points,
+            stock: self.stock.len(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// Returns the cards the current player may play, which is any card in their hand.
+    pub fn legal_actions(&self) -> Vec<Card> {
+        if self.over {
+            return vec![];
+        }
+        self.hand(self.turn)
+            .iter()
+            .copied()
+            .sorted_by_key(|&c| (c.suit(), rank_order(c)))
+            .collect()
+    }
+
+    /// Plays a card for the current player.
+    pub fn apply_action(&mut self, card: Card) -> Result<(), BriscolaError> {
+        if self.over {
+            return Err(BriscolaError::GameOver);
+        }
+        let player = self.turn;
+        let hand = &mut self.hands[player.index()];
+        let Some(pos) = hand.iter().position(|&c| c == card) else {
+            return Err(BriscolaError::CardNotHeld(player, card));
+        };
+        hand.remove(pos);
+        self.actions.push(card);
+        self.events.push_back(Event::Play(player, card));
+        let Some((leader, lead)) = self.lead.take() else {
+            self.lead = Some((player, card));
+            self.turn = player.next(PLAYERS);
+            return Ok(());
+        };
+        let winner = if beats(self.trump(), lead, card) {
+            player
+        } else {
+            leader
+        };
+        let won = points(lead) + points(card);
+        self.points[winner.index()] += won;
+        self.events.push_back(Event::Trick(winner, won));
+        self.draw(winner);
+        self.turn = winner;
+        if self.hands.iter().all(Vec::is_empty) {
+            self.over = true;
+            let result = self.result().expect("round is over");
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    /// The result of the round, once it's over.
+    pub fn result(&self) -> Option<RoundResult> {
+        self.over.then(|| RoundResult {
+            points: self.points.clone(),
+        })
+    }
+}
+
+impl crate::engine::Engine for Round {
+    type Actor = Player;
+    type Action = Card;
+    type View<'a> = RoundView<'a>;
+    type Event = Event;
+    type Outcome = RoundResult;
+    type Error = BriscolaError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> RoundView<'_> {
+        Round::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Card> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, card: Card) -> Result<(), BriscolaError> {
+        self.apply_action(card)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<RoundResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a round: the order of the deck, and the cards played.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRound {
+    config: RoundConfig,
+    actions: Vec<Card>,
+}
+
+impl From<&Round> for RawRound {
+    fn from(round: &Round) -> Self {
+        Self {
+            config: round.config.clone(),
+            actions: round.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawRound> for Round {
+    type Error = BriscolaError;
+
+    fn try_from(raw: RawRound) -> Result<Self, BriscolaError> {
+        raw.config.validate()?;
+        let mut round = Round::from(raw.config);
+        for card in raw.actions {
+            round.apply_action(card)?;
+        }
+        round.events.clear();
+        Ok(round)
+    }
+}
@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::briscola::Robot;
+use crate::engine::Engine;
+use crate::italian::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// Stacks the deck so that the dealer's opponent and then the dealer are dealt the hands, with
+/// the stock drawn in order after them, and the briscola turned up at the bottom. The second
+/// player deals.
+fn stacked(hands: [&str; 2], stock: &str, briscola: &str) -> RoundConfig {
+    let hands = [cards(hands[0]), cards(hands[1])];
+    let dealt: Vec<Card> = hands[0]
+        .iter()
+        .interleave(&hands[1])
+        .chain(&cards(stock))
+        .copied()
+        .collect();
+    let briscola = card(briscola);
+    let mut deck = vec![briscola];
+    deck.extend(
+        super::deck()
+            .into_iter()
+            .filter(|c| *c != briscola && !dealt.contains(c)),
+    );
+    deck.extend(dealt.into_iter().rev());
+    RoundConfig::new(Player(1), deck).unwrap()
+}
+
+#[test]
+fn test_invalid_deck() {
+    let mut deck: Vec<Card> = super::deck().into_iter().collect();
+    deck.pop();
+    let result = RoundConfig::new(Player(0), deck);
+    assert!(matches!(result, Err(BriscolaError::InvalidDeck)));
+}
+
+#[test]
+fn test_tricks() {
+    let config = stacked(["A♣ 2♦ 3♤", "K♣ 4♦ 5♡"], "J♣ Q♣", "7♦");
+    let mut round = Round::from(config);
+    assert_eq!(round.pop_event(), Some(Event::Briscola(card("7♦"))));
+    assert_eq!(round.trump(), Suit::Diamond);
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    assert_eq!(round.hand(Player(1)), &cards("K♣ 4♦ 5♡")[..]);
+    assert!(matches!(
+        round.apply_action(card("K♣")),
+        Err(BriscolaError::CardNotHeld(Player(0), _))
+    ));
+
+    // The ace takes the king, and its player draws first.
+    round.apply_action(card("A♣")).unwrap();
+    assert_eq!(round.view(Player(1)).lead, Some(card("A♣")));
+    round.apply_action(card("K♣")).unwrap();
+    let events: Vec<Event> = std::iter::from_fn(|| round.pop_event()).collect();
+    assert_eq!(
+        events,
+        [
+            Event::Play(Player(0), card("A♣")),
+            Event::Play(Player(1), card("K♣")),
+            Event::Trick(Player(0), 15),
+        ]
+    );
+    assert_eq!(round.hand(Player(0)), &cards("2♦ 3♤ J♣")[..]);
+    assert_eq!(round.hand(Player(1)), &cards("4♦ 5♡ Q♣")[..]);
+
+    // A lowly trump takes the three.
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    round.apply_action(card("3♤")).unwrap();
+    round.apply_action(card("4♦")).unwrap();
+    assert_eq!(round.view(Player(0)).points, [15, 10]);
+    assert_eq!(round.next_actor(), Some(Player(1)));
+
+    // Off-suit cards lose to the lead, whatever their rank.
+    round.apply_action(card("5♡")).unwrap();
+    round.apply_action(card("J♣")).unwrap();
+    assert_eq!(round.view(Player(0)).points, [15, 12]);
+    assert_eq!(round.next_actor(), Some(Player(1)));
+}
+
+#[test]
+fn test_play_out() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let mut round = Round::from(RoundConfig::random(Player(0), &mut rng));
+    let briscola = round.config().briscola_card();
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("round is over");
+    assert_eq!(result.points.iter().sum::<u32>(), 120);
+    assert_eq!(round.actions().len(), 40);
+    assert!(matches!(
+        round.apply_action(briscola),
+        Err(BriscolaError::GameOver)
+    ));
+}
+
+#[test]
+fn test_raw_round() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut round = Round::from(RoundConfig::random(Player(1), &mut rng));
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("round is over");
+    let raw = serde_json::to_string(&RawRound::from(&round)).unwrap();
+    let replayed = Round::try_from(serde_json::from_str::<RawRound>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), round.actions());
+}
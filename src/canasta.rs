@@ -0,0 +1,112 @@
+//! Canasta.
+//!
+//! Partnership canasta is played by two teams, North/South and East/West, with two standard decks
+//! and four jokers. On their turn, a player draws from the stock or takes the discard pile, melds
+//! cards of the same rank, and discards. Jokers and deuces are wild. A meld of seven or more cards
+//! is a canasta, and a team must have one before either partner may go out. The first team to
+//! 5000 points wins.
+//!
+//! The discard pile is frozen by a wild card discarded into it, and it's frozen for a team until
+//! they've made their initial meld, which must count a minimum that rises with their score. A
+//! frozen pile may only be taken with a natural pair matching its top card.
+
+mod error;
+mod meld;
+mod robot;
+mod round;
+
+use itertools::Itertools;
+
+pub use self::error::CanastaError;
+pub use self::meld::{card_value, is_wild, Meld, MeldSpec};
+pub use self::robot::Robot;
+pub use self::round::{
+    Action, Event, Phase, RawRound, Round, RoundConfig, RoundResult, RoundView, TeamScore,
+};
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::euchre::{Seat, SeatMap, Team, TeamMap};
+pub use crate::french::{Card, Color, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The score a team must reach to win the game.
+pub const GAME_SCORE: i32 = 5000;
+
+/// The two standard decks and four jokers with which canasta is played.
+pub fn shoe() -> Deck<Card> {
+    DeckBuilder::new().copies(2).with_jokers().build()
+}
+
+/// The minimum count of a team's initial meld, which depends on their score: 15 with a negative
+/// score, 50 below 1500, 90 below 3000, and 120 from then on.
+pub fn minimum_meld(score: i32) -> i32 {
+    match score {
+        i32::MIN..=-1 => 15,
+        0..=1499 => 50,
+        1500..=2999 => 90,
+        _ => 120,
+    }
+}
+
+/// Formats cards in order, from jokers and deuces down to threes.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| meld::sort_key(c))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Returns the winning team, once a team has reached the game score. If both have, the higher
+/// score wins.
+pub fn winner(scores: &TeamMap<i32>) -> Option<Team> {
+    let (ns, ew) = (scores[Team::NorthSouth], scores[Team::EastWest]);
+    if ns.max(ew) < GAME_SCORE || ns == ew {
+        None
+    } else if ns > ew {
+        Some(Team::NorthSouth)
+    } else {
+        Some(Team::EastWest)
+    }
+}
+
+/// Plays a game with a simple command-line interface, with the user sitting South and robots
+/// everywhere else. The record of every round is written to stderr when the game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = TeamMap::<i32>::default();
+    let mut dealer: Seat = rand::random();
+    let mut rounds = vec![];
+    let my_seat = Seat::South;
+    console.println(tr!("game-you-are-help", player = my_seat.to_string()))?;
+    loop {
+        let config = RoundConfig::random(dealer, scores, &mut rng);
+        let mut round = Round::from(config);
+        let result = console
+            .play(&mut round, my_seat, |round, seat, legal| {
+                robot.choose(&round.view(seat), &legal)
+            })?
+            .expect("round is over");
+        for (team, score) in result.scores.iter() {
+            scores[team] += score.total();
+        }
+        rounds.push(RawRound::from(&round));
+        let score = format!(
+            "{} {}, {} {}",
+            Team::NorthSouth.to_abbr(),
+            scores[Team::NorthSouth],
+            Team::EastWest.to_abbr(),
+            scores[Team::EastWest]
+        );
+        console.println(tr!("game-score", score = score))?;
+        if let Some(team) = winner(&scores) {
+            console.println(tr!("game-team-wins", team = team.to_string()))?;
+            break;
+        }
+        dealer = dealer.next();
+    }
+    serde_json::to_writer(std::io::stderr(), &rounds)?;
+    Ok(())
+}
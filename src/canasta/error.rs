@@ -0,0 +1,41 @@
+//! Errors
+
+use super::{Card, MeldSpec, Seat};
+
+/// An error that can occur during a round.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CanastaError {
+    /// The deck isn't made up of two standard decks and four jokers.
+    #[error("the deck must hold two standard decks and four jokers")]
+    InvalidDeck,
+    /// The player must draw or take the pile before melding or discarding.
+    #[error("must draw or take the pile first")]
+    MustDraw,
+    /// The player has already drawn this turn.
+    #[error("already drew this turn")]
+    AlreadyDrew,
+    /// The stock is exhausted.
+    #[error("the stock is empty")]
+    StockEmpty,
+    /// The pile can't be taken with the specified cards.
+    #[error("cannot take the pile")]
+    IllegalTake(MeldSpec),
+    /// The cards don't make a legal meld.
+    #[error("cannot meld {0}")]
+    IllegalMeld(MeldSpec),
+    /// The player doesn't hold the card they attempted to discard.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Seat, Card),
+    /// The team's first melds don't count enough.
+    #[error("the initial meld must count at least {0}")]
+    MinimumNotMet(i32),
+    /// The player can't go out, or be left without a card to discard, without a canasta.
+    #[error("cannot go out without a canasta")]
+    NoCanasta,
+    /// There are no melds to take back.
+    #[error("nothing to withdraw")]
+    NothingToWithdraw,
+    /// The round is over, no more actions are expected.
+    #[error("round is over")]
+    RoundOver,
+}
@@ -0,0 +1,251 @@
+//! Melds, and the values of the cards in them.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Color, Rank};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Returns true for the wild cards: jokers and deuces.
+pub fn is_wild(card: Card) -> bool {
+    card.is_joker() || card.rank() == Some(Rank::Two)
+}
+
+/// Returns true for a red three, which is laid aside as a bonus as soon as it's drawn.
+pub fn is_red_three(card: Card) -> bool {
+    card.rank() == Some(Rank::Three) && card.color() == Color::Red
+}
+
+/// Returns true for a black three, which blocks the discard pile, and may only be melded when
+/// going out.
+pub fn is_black_three(card: Card) -> bool {
+    card.rank() == Some(Rank::Three) && card.color() == Color::Black
+}
+
+/// The count of a card: 50 for a joker, 20 for a deuce or an ace, 10 for eight through king, and
+/// 5 for four through seven and black threes. Red threes score as bonuses, and count nothing.
+pub fn card_value(card: Card) -> i32 {
+    match card.rank() {
+        None => 50,
+        Some(Rank::Two | Rank::Ace) => 20,
+        Some(Rank::Three) if is_red_three(card) => 0,
+        Some(rank) if rank >= Rank::Eight => 10,
+        Some(_) => 5,
+    }
+}
+
+/// The order in which cards are displayed: jokers, deuces, aces down to fours, then threes.
+pub fn sort_key(card: Card) -> (u8, u8, u8) {
+    match (card, card.rank(), card.suit()) {
+        (Card::BigJoker, ..) => (0, 0, 0),
+        (Card::Joker, ..) => (0, 1, 0),
+        (_, Some(Rank::Two), Some(suit)) => (1, 0, suit as u8),
+        (_, Some(Rank::Three), Some(suit)) => (3, 0, suit as u8),
+        (_, Some(rank), Some(suit)) => (2, 12 - rank.ace_high(), suit as u8),
+        _ => unreachable!("suited cards have a rank and suit"),
+    }
+}
+
+/// Three or more cards of the same rank, including up to three wild cards.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Meld {
+    rank: Rank,
+    cards: Vec<Card>,
+}
+
+impl Meld {
+    /// Creates a meld of the rank from the specified cards, which aren't checked.
+    pub fn new(rank: Rank, cards: Vec<Card>) -> Self {
+        let mut meld = Self {
+            rank,
+            cards: vec![],
+        };
+        meld.extend(cards);
+        meld
+    }
+
+    /// The rank of the meld's natural cards.
+    pub fn rank(&self) -> Rank {
+        self.rank
+    }
+
+    /// The cards in the meld, with natural cards first.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Adds cards to the meld.
+    pub fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        self.cards.extend(cards);
+        self.cards.sort_by_key(|&c| (is_wild(c), sort_key(c)));
+    }
+
+    /// The number of cards in the meld.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the meld has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// The number of natural cards in the meld.
+    pub fn naturals(&self) -> usize {
+        self.cards.iter().filter(|&&c| !is_wild(c)).count()
+    }
+
+    /// The number of wild cards in the meld.
+    pub fn wilds(&self) -> usize {
+        self.cards.iter().filter(|&&c| is_wild(c)).count()
+    }
+
+    /// Returns true if the meld has at least two natural cards and at most three wild ones, and
+    /// at least three cards in all.
+    pub fn is_valid(&self) -> bool {
+        self.naturals() >= 2 && self.wilds() <= 3 && self.len() >= 3
+    }
+
+    /// Returns true if the meld has seven or more cards.
+    pub fn is_canasta(&self) -> bool {
+        self.len() >= 7
+    }
+
+    /// Returns true if the meld has no wild cards.
+    pub fn is_natural(&self) -> bool {
+        self.wilds() == 0
+    }
+
+    /// The total count of the cards in the meld.
+    pub fn value(&self) -> i32 {
+        self.cards.iter().copied().map(card_value).sum()
+    }
+
+    /// The bonus for a canasta: 500 for a natural one, and 300 for a mixed one.
+    pub fn bonus(&self) -> i32 {
+        match (self.is_canasta(), self.is_natural()) {
+            (false, _) => 0,
+            (true, true) => 500,
+            (true, false) => 300,
+        }
+    }
+}
+
+impl Display for Meld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.rank)?;
+        for card in &self.cards {
+            write!(f, " {card}")?;
+        }
+        match (self.is_canasta(), self.is_natural()) {
+            (false, _) => Ok(()),
+            (true, true) => write!(f, " ({})", tr!("canasta-natural")),
+            (true, false) => write!(f, " ({})", tr!("canasta-mixed")),
+        }
+    }
+}
+
+/// Cards from a player's hand to be melded, identified by rank and kind. The suits of the cards
+/// make no difference, apart from the color of threes, so they're left unspecified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MeldSpec {
+    /// The rank of the natural cards.
+    pub rank: Rank,
+    /// The number of natural cards of the rank. For threes, these are black threes.
+    pub naturals: u8,
+    /// The number of deuces.
+    pub deuces: u8,
+    /// The number of jokers.
+    pub jokers: u8,
+}
+
+impl MeldSpec {
+    /// Creates a new spec.
+    pub fn new(rank: Rank, naturals: u8, deuces: u8, jokers: u8) -> Self {
+        Self {
+            rank,
+            naturals,
+            deuces,
+            jokers,
+        }
+    }
+
+    /// The number of cards specified.
+    pub fn len(&self) -> usize {
+        usize::from(self.naturals + self.wilds())
+    }
+
+    /// Returns true if no cards are specified.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of wild cards specified.
+    pub fn wilds(&self) -> u8 {
+        self.deuces + self.jokers
+    }
+
+    /// Returns true if the card is one of the spec's natural cards.
+    fn is_natural(&self, card: Card) -> bool {
+        !is_wild(card) && card.rank() == Some(self.rank) && !is_red_three(card)
+    }
+
+    /// The number of natural cards of the rank in the hand.
+    pub fn available(rank: Rank, hand: &[Card]) -> u8 {
+        let spec = Self::new(rank, 0, 0, 0);
+        count(hand.iter().filter(|&&c| spec.is_natural(c)))
+    }
+
+    /// The number of deuces and jokers in the hand.
+    pub fn available_wilds(hand: &[Card]) -> (u8, u8) {
+        let deuces = count(hand.iter().filter(|c| c.rank() == Some(Rank::Two)));
+        let jokers = count(hand.iter().filter(|c| c.is_joker()));
+        (deuces, jokers)
+    }
+
+    /// Selects the specified cards from the hand, or returns `None` if the hand doesn't hold
+    /// them.
+    pub fn select(&self, hand: &[Card]) -> Option<Vec<Card>> {
+        let mut cards = vec![];
+        let mut take = |n: u8, f: &dyn Fn(Card) -> bool| {
+            let found: Vec<Card> = hand
+                .iter()
+                .copied()
+                .filter(|&c| f(c))
+                .take(usize::from(n))
+                .collect();
+            let ok = found.len() == usize::from(n);
+            cards.extend(found);
+            ok
+        };
+        let ok = take(self.naturals, &|c| self.is_natural(c))
+            && take(self.deuces, &|c| c.rank() == Some(Rank::Two))
+            && take(self.jokers, &|c| c.is_joker());
+        ok.then_some(cards)
+    }
+}
+
+/// Counts the items of an iterator over cards in a hand.
+fn count<I: Iterator>(iter: I) -> u8 {
+    u8::try_from(iter.count()).expect("fewer than 256 cards")
+}
+
+impl Display for MeldSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for _ in 0..self.naturals {
+            write!(f, "{}", self.rank)?;
+        }
+        for _ in 0..self.deuces {
+            f.write_str("2")?;
+        }
+        for _ in 0..self.jokers {
+            f.write_str("*")?;
+        }
+        Ok(())
+    }
+}
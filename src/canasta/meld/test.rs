@@ -0,0 +1,51 @@
+use super::*;
+use crate::french::cards;
+
+#[test]
+fn test_card_value() {
+    let values: Vec<i32> = cards("jk JK 2♣ A♡ K♤ 8♦ 7♣ 4♡ 3♣ 3♦")
+        .into_iter()
+        .map(card_value)
+        .collect();
+    assert_eq!(values, [50, 50, 20, 20, 10, 10, 5, 5, 5, 0]);
+    assert!(is_red_three(cards("3♡")[0]));
+    assert!(is_black_three(cards("3♤")[0]));
+    assert!(is_wild(Card::Joker) && is_wild(cards("2♦")[0]));
+}
+
+#[test]
+fn test_meld() {
+    let meld = Meld::new(Rank::King, cards("2♣ K♤ K♡"));
+    assert!(meld.is_valid());
+    assert_eq!(meld.cards(), cards("K♤ K♡ 2♣").as_slice());
+    assert_eq!(meld.value(), 40);
+    assert_eq!(meld.bonus(), 0);
+    assert!(!Meld::new(Rank::King, cards("2♣ jk K♡")).is_valid());
+    assert!(!Meld::new(Rank::King, cards("2♣ jk 2♡ JK K♡ K♣")).is_valid());
+
+    let mut meld = Meld::new(Rank::Seven, cards("7♣ 7♦ 7♡ 7♤ 7♣ 7♦"));
+    assert!(!meld.is_canasta());
+    meld.extend(cards("7♡"));
+    assert!(meld.is_canasta() && meld.is_natural());
+    assert_eq!(meld.bonus(), 500);
+    meld.extend(cards("jk"));
+    assert_eq!(meld.bonus(), 300);
+    assert_eq!(
+        meld.to_string(),
+        "7: 7♣ 7♣ 7♦ 7♦ 7♤ 7♡ 7♡ jk (mixed canasta)"
+    );
+}
+
+#[test]
+fn test_meld_spec() {
+    let hand = cards("K♤ K♡ K♣ 2♣ jk 3♡ 3♣ 3♤");
+    assert_eq!(MeldSpec::available(Rank::King, &hand), 3);
+    assert_eq!(MeldSpec::available(Rank::Three, &hand), 2);
+    assert_eq!(MeldSpec::available_wilds(&hand), (1, 1));
+
+    let spec = MeldSpec::new(Rank::King, 2, 1, 1);
+    assert_eq!(spec.to_string(), "KK2*");
+    assert_eq!(spec.select(&hand), Some(cards("K♤ K♡ 2♣ jk")));
+    assert_eq!(MeldSpec::new(Rank::King, 2, 2, 0).select(&hand), None);
+    assert_eq!(MeldSpec::new(Rank::Three, 3, 0, 0).select(&hand), None);
+}
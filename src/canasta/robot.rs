@@ -0,0 +1,180 @@
+//! A robot player.
+//!
+//! The robot takes the discard pile whenever it can, and melds natural cards as soon as it's
+//! able, keeping its wild cards to complete canastas. It makes its initial meld only once it can
+//! count the minimum, and goes out as soon as the rules allow. It discards black threes first,
+//! then low cards it holds singly, avoiding ranks that the opponents have melded.
+
+use std::collections::HashMap;
+
+use super::meld::is_black_three;
+use super::{
+    card_value, is_wild, Action, Card, Meld, MeldSpec, Phase, Rank, RoundView, Seat, Team,
+};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &RoundView, legal: &[Action]) -> Action {
+        let seat = view.to_play.expect("it's someone's turn");
+        let choice = match view.phase {
+            Phase::Draw => take(legal),
+            Phase::Play => {
+                let team = seat.team();
+                if view.opened[team] {
+                    meld(view, team, legal)
+                } else {
+                    initial_meld(view, team, legal)
+                }
+                .or_else(|| discard(view, seat, legal))
+            }
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0])
+    }
+}
+
+/// Takes the pile using as few cards from hand as possible, and wild cards only as a last resort.
+fn take(legal: &[Action]) -> Option<Action> {
+    legal
+        .iter()
+        .filter_map(|&a| match a {
+            Action::Take(spec) => Some(spec),
+            _ => None,
+        })
+        .min_by_key(|spec| (spec.wilds(), spec.naturals))
+        .map(Action::Take)
+        .or(Some(Action::Draw))
+}
+
+/// Returns the legal meld of the rank with the most natural cards and no more wild cards than
+/// specified.
+fn best_meld(legal: &[Action], rank: Rank, max_wilds: u8) -> Option<MeldSpec> {
+    legal
+        .iter()
+        .filter_map(|&a| match a {
+            Action::Meld(spec) if spec.rank == rank && spec.wilds() <= max_wilds => Some(spec),
+            _ => None,
+        })
+        .max_by_key(|spec| (spec.naturals, std::cmp::Reverse(spec.wilds())))
+}
+
+/// Melds cards once the team has opened: natural cards first, and then wild cards where they
+/// complete a canasta.
+fn meld(view: &RoundView, team: Team, legal: &[Action]) -> Option<Action> {
+    let melds: &[Meld] = &view.melds[team];
+    for &rank in Rank::all_ranks() {
+        if let Some(spec) = best_meld(legal, rank, 0) {
+            let existing = melds.iter().any(|m| m.rank() == rank);
+            if existing || spec.naturals >= 3 {
+                return Some(Action::Meld(spec));
+            }
+        }
+    }
+    for meld in melds.iter().filter(|m| !m.is_canasta()) {
+        let wilds_needed = 7 - meld.len();
+        if let Some(spec) = best_meld(legal, meld.rank(), 3) {
+            if spec.naturals == 0 && usize::from(spec.wilds()) == wilds_needed {
+                return Some(Action::Meld(spec));
+            }
+        }
+    }
+    None
+}
+
+/// Plans the initial meld from the player's hand: every rank of which they hold three natural
+/// cards, and then pairs with a wild card, for as long as wild cards last, always keeping two
+/// cards back.
+fn plan(hand: &[Card]) -> Vec<MeldSpec> {
+    let mut specs = vec![];
+    let mut left = hand.len();
+    let mut add = |spec: MeldSpec| {
+        if left >= spec.len() + 2 {
+            left -= spec.len();
+            specs.push(spec);
+            true
+        } else {
+            false
+        }
+    };
+    let (mut deuces, mut jokers) = MeldSpec::available_wilds(hand);
+    let ranks = Rank::all_ranks()
+        .iter()
+        .copied()
+        .filter(|&r| r != Rank::Two && r != Rank::Three);
+    for rank in ranks.clone() {
+        let naturals = MeldSpec::available(rank, hand);
+        if naturals >= 3 {
+            add(MeldSpec::new(rank, naturals, 0, 0));
+        }
+    }
+    for rank in ranks {
+        if MeldSpec::available(rank, hand) != 2 {
+            continue;
+        }
+        if deuces > 0 && add(MeldSpec::new(rank, 2, 1, 0)) {
+            deuces -= 1;
+        } else if jokers > 0 && add(MeldSpec::new(rank, 2, 0, 1)) {
+            jokers -= 1;
+        }
+    }
+    specs
+}
+
+/// Makes the initial meld, if the plan counts the minimum, or takes back melds that don't.
+fn initial_meld(view: &RoundView, team: Team, legal: &[Action]) -> Option<Action> {
+    let laid: i32 = view.melds[team].iter().map(Meld::value).sum();
+    let specs = plan(view.hand);
+    let planned: i32 = specs
+        .iter()
+        .filter_map(|spec| spec.select(view.hand))
+        .flatten()
+        .map(card_value)
+        .sum();
+    if laid + planned < view.minimum[team] {
+        return legal
+            .contains(&Action::Withdraw)
+            .then_some(Action::Withdraw);
+    }
+    let next = specs
+        .into_iter()
+        .map(Action::Meld)
+        .find(|a| legal.contains(a));
+    next.or_else(|| {
+        let withdraw = laid > 0 && laid < view.minimum[team];
+        withdraw.then_some(Action::Withdraw)
+    })
+}
+
+/// Discards the least useful card.
+fn discard(view: &RoundView, seat: Seat, legal: &[Action]) -> Option<Action> {
+    let mut held: HashMap<Option<Rank>, usize> = HashMap::new();
+    for card in view.hand {
+        *held.entry(card.rank()).or_default() += 1;
+    }
+    let theirs = &view.melds[seat.team().other()];
+    legal
+        .iter()
+        .filter_map(|&a| match a {
+            Action::Discard(card) => Some(card),
+            _ => None,
+        })
+        .min_by_key(|&card| {
+            let feeds = theirs.iter().any(|m| Some(m.rank()) == card.rank());
+            (
+                !is_black_three(card),
+                is_wild(card),
+                feeds,
+                held[&card.rank()],
+                card_value(card),
+            )
+        })
+        .map(Action::Discard)
+}
@@ -0,0 +1,46 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::canasta::{Round, RoundConfig, TeamMap};
+use crate::engine::Engine;
+use crate::french::cards;
+
+#[test]
+fn test_plan() {
+    let hand = cards("K♤ K♡ K♣ Q♤ Q♡ 2♣ 7♤ 7♡ jk 5♣ 4♦");
+    assert_eq!(
+        plan(&hand),
+        [
+            MeldSpec::new(Rank::King, 3, 0, 0),
+            MeldSpec::new(Rank::Seven, 2, 1, 0),
+            MeldSpec::new(Rank::Queen, 2, 0, 1),
+        ]
+    );
+    // Two cards are always kept back.
+    let hand = cards("K♤ K♡ K♣ Q♤ Q♡ 2♣");
+    assert_eq!(plan(&hand), [MeldSpec::new(Rank::King, 3, 0, 0)]);
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(0x6361_6e61);
+    let mut went_out = 0;
+    for i in 0..40 {
+        let dealer = Seat::all_seats()[i % 4];
+        let mut round = Round::from(RoundConfig::random(dealer, TeamMap::default(), &mut rng));
+        let mut turns = 0;
+        let result = round
+            .play_out(|round, seat, legal| {
+                turns += 1;
+                assert!(turns < 10_000, "round never ends");
+                Robot.choose(&round.view(seat), &legal)
+            })
+            .unwrap()
+            .expect("round is over");
+        if result.went_out.is_some() {
+            went_out += 1;
+        }
+    }
+    assert!(went_out > 20);
+}
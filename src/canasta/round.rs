@@ -0,0 +1,926 @@
+//! A single round: from the deal until a player goes out, or the stock runs out.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::meld::{is_red_three, sort_key};
+use super::{
+    card_value, format_cards, is_wild, minimum_meld, shoe, CanastaError, Card, Meld, MeldSpec,
+    Rank, Seat, SeatMap, Team, TeamMap,
+};
+use crate::deck::{Deck, Pile, Stock, Waste};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of cards dealt to each player.
+const HAND_SIZE: usize = 11;
+
+/// Both teams, for iterating over per-team state.
+const TEAMS: [Team; 2] = [Team::NorthSouth, Team::EastWest];
+
+/// The order of the shoe, and the state of the game in which the round is played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundConfig {
+    dealer: Seat,
+    scores: TeamMap<i32>,
+    deck: Vec<Card>,
+}
+
+impl RoundConfig {
+    /// Shuffles the shoe.
+    pub fn random<R: Rng + ?Sized>(dealer: Seat, scores: TeamMap<i32>, rng: &mut R) -> Self {
+        let mut deck = shoe();
+        deck.shuffle(rng);
+        Self {
+            dealer,
+            scores,
+            deck: deck.into_iter().collect(),
+        }
+    }
+
+    /// Creates a round from the specified deck, which must hold the cards of the shoe. The last
+    /// card is the top of the deck.
+    pub fn new(dealer: Seat, scores: TeamMap<i32>, deck: Vec<Card>) -> Result<Self, CanastaError> {
+        let config = Self {
+            dealer,
+            scores,
+            deck,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that the deck holds exactly the cards of the shoe.
+    pub fn validate(&self) -> Result<(), CanastaError> {
+        let mut counts: HashMap<Card, usize> = HashMap::new();
+        for card in shoe() {
+            *counts.entry(card).or_default() += 1;
+        }
+        for card in &self.deck {
+            match counts.get_mut(card) {
+                Some(n) if *n > 0 => *n -= 1,
+                _ => return Err(CanastaError::InvalidDeck),
+            }
+        }
+        if counts.values().any(|&n| n > 0) {
+            return Err(CanastaError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer. The player to their left plays first.
+    pub fn dealer(&self) -> Seat {
+        self.dealer
+    }
+
+    /// The team's score before the round.
+    pub fn score(&self, team: Team) -> i32 {
+        self.scores[team]
+    }
+
+    /// The minimum count of the team's initial meld.
+    pub fn minimum(&self, team: Team) -> i32 {
+        minimum_meld(self.scores[team])
+    }
+}
+
+/// The stage of a player's turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// The player must draw from the stock, or take the discard pile.
+    Draw,
+    /// The player may meld, and must then discard, unless they go out.
+    Play,
+}
+
+/// An action taken during a player's turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Draws the top card of the stock.
+    Draw,
+    /// Takes the discard pile, melding its top card with the specified cards from hand.
+    Take(MeldSpec),
+    /// Melds the specified cards, adding them to the team's meld of the same rank, if any.
+    Meld(MeldSpec),
+    /// Takes back the team's melds, which don't yet count the minimum for an initial meld.
+    Withdraw,
+    /// Discards a card, ending the turn.
+    Discard(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Draw => f.write_str(&tr!("game-action-draw")),
+            Action::Take(spec) if spec.is_empty() => f.write_str(&tr!("canasta-action-take")),
+            Action::Take(spec) => f.write_str(&tr!("game-action-take", cards = spec.to_string())),
+            Action::Meld(spec) => f.write_str(&tr!("game-action-meld", cards = spec.to_string())),
+            Action::Withdraw => f.write_str(&tr!("canasta-action-withdraw")),
+            Action::Discard(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A team's score for a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TeamScore {
+    /// The count of the cards melded.
+    pub melds: i32,
+    /// Bonuses for canastas.
+    pub canastas: i32,
+    /// Bonuses for red threes, which are penalties if the team never melded.
+    pub red_threes: i32,
+    /// The bonus for going out.
+    pub going_out: i32,
+    /// The count of the cards left in the partners' hands, which is negative.
+    pub hands: i32,
+}
+
+impl TeamScore {
+    /// The team's total for the round.
+    pub fn total(&self) -> i32 {
+        self.melds + self.canastas + self.red_threes + self.going_out + self.hands
+    }
+}
+
+impl Display for TeamScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = tr!(
+            "canasta-team-score",
+            total = self.total(),
+            melds = self.melds,
+            canastas = self.canastas,
+            red_threes = self.red_threes,
+            going_out = self.going_out,
+            hands = self.hands
+        );
+        f.write_str(&text)
+    }
+}
+
+/// The result of a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// The player who went out, or `None` if the stock ran out.
+    pub went_out: Option<Seat>,
+    /// The scores for the round.
+    pub scores: TeamMap<TeamScore>,
+}
+
+impl Display for RoundResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.went_out {
+            Some(seat) => f.write_str(&tr!("game-went-out", player = seat.to_string()))?,
+            None => f.write_str(&tr!("game-stock-ran-out"))?,
+        }
+        for team in TEAMS.iter().copied() {
+            write!(f, "\n{}: {}", team.to_abbr(), self.scores[team])?;
+        }
+        Ok(())
+    }
+}
+
+/// An event that occurs during a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// A player laid aside a red three.
+    RedThree(Seat, Card),
+    /// The card turned up to start the discard pile.
+    Upcard(Card),
+    /// A player drew from the stock.
+    Draw(Seat),
+    /// A player took the discard pile, with the card on top, and the number of cards in it.
+    Take(Seat, Card, usize),
+    /// A player melded, resulting in the meld.
+    Meld(Seat, Meld),
+    /// A player took back their team's melds.
+    Withdraw(Seat),
+    /// A player discarded.
+    Discard(Seat, Card),
+    /// A player went out.
+    Out(Seat),
+    /// The round is over.
+    Over(RoundResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::RedThree(seat, card) => {
+                let (seat, card) = (seat.to_string(), card.to_string());
+                f.write_str(&tr!("canasta-red-three", player = seat, card = card))
+            }
+            Event::Upcard(card) => f.write_str(&tr!("game-upcard", card = card.to_string())),
+            Event::Draw(seat) => f.write_str(&tr!("game-drew", player = seat.to_string())),
+            Event::Take(seat, card, n) => {
+                let (seat, card) = (seat.to_string(), card.to_string());
+                f.write_str(&tr!(
+                    "canasta-took-pile",
+                    player = seat,
+                    count = *n,
+                    card = card
+                ))
+            }
+            Event::Meld(seat, meld) => {
+                let (seat, meld) = (seat.to_string(), meld.to_string());
+                f.write_str(&tr!("game-melded", player = seat, meld = meld))
+            }
+            Event::Withdraw(seat) => {
+                f.write_str(&tr!("canasta-withdrew", player = seat.to_string()))
+            }
+            Event::Discard(seat, card) => {
+                let (seat, card) = (seat.to_string(), card.to_string());
+                f.write_str(&tr!("game-discarded", player = seat, card = card))
+            }
+            Event::Out(seat) => f.write_str(&tr!("game-went-out", player = seat.to_string())),
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the round, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct RoundView<'a> {
+    /// The player.
+    pub seat: Seat,
+    /// The dealer.
+    pub dealer: Seat,
+    /// Each team's score before the round.
+    pub scores: TeamMap<i32>,
+    /// The minimum count of each team's initial meld.
+    pub minimum: TeamMap<i32>,
+    /// The player's cards.
+    pub hand: &'a [Card],
+    /// The number of cards held by each player.
+    pub hand_sizes: SeatMap<usize>,
+    /// Each team's melds.
+    pub melds: &'a TeamMap<Vec<Meld>>,
+    /// Each team's red threes.
+    pub red_threes: &'a TeamMap<Vec<Card>>,
+    /// Whether each team has made its initial meld.
+    pub opened: TeamMap<bool>,
+    /// The number of cards in the stock.
+    pub stock: usize,
+    /// The number of cards in the discard pile.
+    pub pile: usize,
+    /// The top card of the discard pile.
+    pub top: Option<Card>,
+    /// Whether the discard pile is frozen for everyone, by a wild card or red three in it.
+    pub frozen: bool,
+    /// The player whose turn it is, or `None` if the round is over.
+    pub to_play: Option<Seat>,
+    /// The stage of their turn.
+    pub phase: Phase,
+}
+
+impl Display for RoundView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (seat, dealer) = (self.seat.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = seat, dealer = dealer))?;
+        for team in TEAMS.iter().copied() {
+            write!(f, "{} ({}):", team.to_abbr(), self.scores[team])?;
+            if self.melds[team].is_empty() {
+                let minimum = self.minimum[team];
+                write!(f, " {}", tr!("canasta-no-melds", minimum = minimum))?;
+            }
+            for (i, meld) in self.melds[team].iter().enumerate() {
+                let sep = if i == 0 { " " } else { " | " };
+                write!(f, "{sep}{meld}")?;
+            }
+            if !self.red_threes[team].is_empty() {
+                write!(f, "; {}", format_cards(&self.red_threes[team]))?;
+            }
+            writeln!(f)?;
+        }
+        let (stock, pile) = (self.stock, self.pile);
+        f.write_str(&tr!("game-stock-pile", stock = stock, pile = pile))?;
+        if let Some(top) = self.top {
+            write!(f, " {}", tr!("canasta-on-top", card = top.to_string()))?;
+        }
+        if self.frozen {
+            write!(f, " ({})", tr!("canasta-frozen"))?;
+        }
+        writeln!(f)?;
+        let sizes: Vec<String> = self
+            .hand_sizes
+            .iter()
+            .filter(|&(seat, _)| seat != self.seat)
+            .map(|(seat, n)| format!("{} {n}", seat.to_abbr()))
+            .collect();
+        writeln!(f, "{}", tr!("game-cards-held", sizes = sizes.join(", ")))?;
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a round.
+#[derive(Debug, Clone)]
+pub struct Round {
+    config: RoundConfig,
+    hands: SeatMap<Vec<Card>>,
+    stock: Stock<Card>,
+    pile: Waste<Card>,
+    frozen: bool,
+    melds: TeamMap<Vec<Meld>>,
+    red_threes: TeamMap<Vec<Card>>,
+    opened: TeamMap<bool>,
+    turn: Seat,
+    phase: Phase,
+    /// Whether the current player's team had melded before their turn, for the concealed bonus.
+    opened_before_turn: bool,
+    went_out: Option<Seat>,
+    exhausted: bool,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<RoundConfig> for Round {
+    fn from(config: RoundConfig) -> Self {
+        let stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let turn = config.dealer.next();
+        let mut round = Self {
+            config,
+            hands: SeatMap::default(),
+            stock,
+            pile: Waste::new(),
+            frozen: false,
+            melds: TeamMap::default(),
+            red_threes: TeamMap::default(),
+            opened: TeamMap::default(),
+            turn,
+            phase: Phase::Draw,
+            opened_before_turn: false,
+            went_out: None,
+            exhausted: false,
+            actions: vec![],
+            events: VecDeque::new(),
+        };
+        round.deal();
+        round
+    }
+}
+
+impl Round {
+    /// Deals the hands, lays aside red threes, and turns up the first card of the pile. A wild
+    /// card or red three turned up freezes the pile, and another card is turned up on top of it.
+    fn deal(&mut self) {
+        let order = self.config.dealer.next_n(4);
+        for &seat in &order {
+            let hand = (0..HAND_SIZE).filter_map(|_| self.stock.draw()).collect();
+            self.hands[seat] = hand;
+        }
+        for &seat in &order {
+            while let Some(i) = self.hands[seat].iter().position(|&c| is_red_three(c)) {
+                let card = self.hands[seat].remove(i);
+                self.lay_red_three(seat, card);
+                if let Some(card) = self.stock.draw() {
+                    self.hands[seat].push(card);
+                }
+            }
+        }
+        while let Some(card) = self.stock.draw() {
+            self.pile.push(card);
+            self.events.push_back(Event::Upcard(card));
+            if !is_wild(card) && !is_red_three(card) {
+                break;
+            }
+            self.frozen = true;
+        }
+    }
+
+    /// The round as it was dealt.
+    pub fn config(&self) -> &RoundConfig {
+        &self.config
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, seat: Seat) -> &[Card] {
+        &self.hands[seat]
+    }
+
+    /// The team's melds.
+    pub fn melds(&self, team: Team) -> &[Meld] {
+        &self.melds[team]
+    }
+
+    /// The stage of the current player's turn.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The team of the player whose turn it is.
+    fn team(&self) -> Team {
+        self.turn.team()
+    }
+
+    /// Returns true if the team has at least one canasta.
+    fn has_canasta(&self, team: Team) -> bool {
+        self.melds[team].iter().any(Meld::is_canasta)
+    }
+
+    /// Returns true once a player has gone out, or the stock has run out.
+    fn is_over(&self) -> bool {
+        self.went_out.is_some()
+            || self.exhausted
+            || (self.phase == Phase::Draw
+                && self.stock.is_empty()
+                && self.take_options().is_empty())
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Seat> {
+        (!self.is_over()).then_some(self.turn)
+    }
+
+    /// Returns the state of the round, as visible to the player.
+    pub fn view(&self, seat: Seat) -> RoundView<'_> {
+        let mut minimum = TeamMap::default();
+        for team in TEAMS.iter().copied() {
+            minimum[team] = self.config.minimum(team);
+        }
+        RoundView {
+            seat,
+            dealer: self.config.dealer,
+            scores: self.config.scores,
+            minimum,
+            hand: &self.hands[seat],
+            hand_sizes: SeatMap::from_fn(|s| self.hands[s].len()),
+            melds: &self.melds,
+            red_threes: &self.red_threes,
+            opened: self.opened,
+            stock: self.stock.len(),
+            pile: self.pile.len(),
+            top: self.pile.top().copied(),
+            frozen: self.frozen,
+            to_play: self.next_actor(),
+            phase: self.phase,
+        }
+    }
+
+    /// Every combination of natural cards of the rank and wild cards from the hand.
+    fn candidates(hand: &[Card], rank: Rank) -> Vec<MeldSpec> {
+        let naturals = MeldSpec::available(rank, hand);
+        let (deuces, jokers) = MeldSpec::available_wilds(hand);
+        let (deuces, jokers) = (deuces.min(3), jokers.min(3));
+        let mut specs = vec![];
+        for n in 0..=naturals {
+            for d in 0..=deuces {
+                for j in 0..=jokers.min(3 - d) {
+                    specs.push(MeldSpec::new(rank, n, d, j));
+                }
+            }
+        }
+        specs
+    }
+
+    /// The ways in which the pile may be taken.
+    fn take_options(&self) -> Vec<MeldSpec> {
+        let Some(rank) = self.pile.top().and_then(|c| c.rank()) else {
+            return vec![];
+        };
+        Self::candidates(&self.hands[self.turn], rank)
+            .into_iter()
+            .filter(|&spec| self.check_take(spec).is_ok())
+            .collect()
+    }
+
+    /// The team's meld of the rank, with the cards added to it.
+    fn extended_meld(&self, rank: Rank, cards: Vec<Card>) -> Meld {
+        let mut meld = self.melds[self.team()]
+            .iter()
+            .find(|m| m.rank() == rank)
+            .cloned()
+            .unwrap_or_else(|| Meld::new(rank, vec![]));
+        meld.extend(cards);
+        meld
+    }
+
+    /// The count of the melds made by a team that hasn't yet met its minimum.
+    fn pending_value(&self) -> i32 {
+        let team = self.team();
+        if self.opened[team] {
+            0
+        } else {
+            self.melds[team].iter().map(Meld::value).sum()
+        }
+    }
+
+    fn check_draw(&self) -> Result<(), CanastaError> {
+        if self.phase != Phase::Draw {
+            return Err(CanastaError::AlreadyDrew);
+        }
+        if self.stock.is_empty() {
+            return Err(CanastaError::StockEmpty);
+        }
+        Ok(())
+    }
+
+    /// Checks that the player may take the pile, melding its top card with the specified cards.
+    ///
+    /// The pile can't be taken when a wild card or a three is on top. When it's frozen, either
+    /// for everyone or for a team that hasn't yet melded, the top card must be melded with a
+    /// natural pair. A team making its initial meld by taking the pile must count the minimum
+    /// with that meld alone.
+    fn check_take(&self, spec: MeldSpec) -> Result<(), CanastaError> {
+        if self.phase != Phase::Draw {
+            return Err(CanastaError::AlreadyDrew);
+        }
+        let illegal = CanastaError::IllegalTake(spec);
+        let Some(&top) = self.pile.top() else {
+            return Err(illegal);
+        };
+        if is_wild(top) || top.rank() != Some(spec.rank) || spec.rank == Rank::Three {
+            return Err(illegal);
+        }
+        let team = self.team();
+        if (self.frozen || !self.opened[team]) && spec.naturals < 2 {
+            return Err(illegal);
+        }
+        let hand = &self.hands[self.turn];
+        let Some(mut cards) = spec.select(hand) else {
+            return Err(illegal);
+        };
+        cards.push(top);
+        let meld = self.extended_meld(spec.rank, cards);
+        if !meld.is_valid() {
+            return Err(illegal);
+        }
+        let minimum = self.config.minimum(team);
+        if !self.opened[team] && meld.value() < minimum {
+            return Err(CanastaError::MinimumNotMet(minimum));
+        }
+        let taken = self.pile.iter().filter(|&&c| !is_red_three(c)).count() - 1;
+        if hand.len() - spec.len() + taken < 2 && !self.has_canasta(team) && !meld.is_canasta() {
+            return Err(CanastaError::NoCanasta);
+        }
+        Ok(())
+    }
+
+    /// Checks that the player may meld the specified cards.
+    ///
+    /// Black threes may only be melded, without wild cards, when going out. A player must keep
+    /// at least one card to discard, unless their team has a canasta and they're going out.
+    fn check_meld(&self, spec: MeldSpec) -> Result<(), CanastaError> {
+        if self.phase != Phase::Play {
+            return Err(CanastaError::MustDraw);
+        }
+        let illegal = CanastaError::IllegalMeld(spec);
+        if spec.is_empty() || spec.rank == Rank::Two {
+            return Err(illegal);
+        }
+        let hand = &self.hands[self.turn];
+        let Some(cards) = spec.select(hand) else {
+            return Err(illegal);
+        };
+        let value: i32 = cards.iter().copied().map(card_value).sum();
+        let meld = self.extended_meld(spec.rank, cards);
+        if !meld.is_valid() {
+            return Err(illegal);
+        }
+        let team = self.team();
+        let left = hand.len() - spec.len();
+        let canasta = self.has_canasta(team) || meld.is_canasta();
+        if spec.rank == Rank::Three && (spec.wilds() > 0 || left > 1 || !canasta) {
+            return Err(illegal);
+        }
+        if left < 2 && !canasta {
+            return Err(CanastaError::NoCanasta);
+        }
+        let minimum = self.config.minimum(team);
+        if left == 0 && !self.opened[team] && self.pending_value() + value < minimum {
+            return Err(CanastaError::MinimumNotMet(minimum));
+        }
+        Ok(())
+    }
+
+    fn check_withdraw(&self) -> Result<(), CanastaError> {
+        if self.phase != Phase::Play {
+            return Err(CanastaError::MustDraw);
+        }
+        let team = self.team();
+        if self.opened[team] || self.melds[team].is_empty() {
+            return Err(CanastaError::NothingToWithdraw);
+        }
+        Ok(())
+    }
+
+    fn check_discard(&self, card: Card) -> Result<(), CanastaError> {
+        if self.phase != Phase::Play {
+            return Err(CanastaError::MustDraw);
+        }
+        let hand = &self.hands[self.turn];
+        if !hand.contains(&card) {
+            return Err(CanastaError::CardNotHeld(self.turn, card));
+        }
+        let team = self.team();
+        let minimum = self.config.minimum(team);
+        if !self.opened[team] && !self.melds[team].is_empty() && self.pending_value() < minimum {
+            return Err(CanastaError::MinimumNotMet(minimum));
+        }
+        if hand.len() == 1 && !self.has_canasta(team) {
+            return Err(CanastaError::NoCanasta);
+        }
+        Ok(())
+    }
+
+    /// Returns the actions the current player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.is_over() {
+            return vec![];
+        }
+        let mut actions = vec![];
+        match self.phase {
+            Phase::Draw => {
+                if self.check_draw().is_ok() {
+                    actions.push(Action::Draw);
+                }
+                actions.extend(self.take_options().into_iter().map(Action::Take));
+            }
+            Phase::Play => {
+                let hand = &self.hands[self.turn];
+                for &rank in Rank::all_ranks() {
+                    actions.extend(
+                        Self::candidates(hand, rank)
+                            .into_iter()
+                            .filter(|&spec| self.check_meld(spec).is_ok())
+                            .map(Action::Meld),
+                    );
+                }
+                if self.check_withdraw().is_ok() {
+                    actions.push(Action::Withdraw);
+                }
+                let mut cards = hand.clone();
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.dedup();
+                actions.extend(
+                    cards
+                        .into_iter()
+                        .filter(|&c| self.check_discard(c).is_ok())
+                        .map(Action::Discard),
+                );
+            }
+        }
+        actions
+    }
+
+    /// Applies the current player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), CanastaError> {
+        if self.is_over() {
+            return Err(CanastaError::RoundOver);
+        }
+        match action {
+            Action::Draw => self.draw()?,
+            Action::Take(spec) => self.take(spec)?,
+            Action::Meld(spec) => self.meld(spec)?,
+            Action::Withdraw => self.withdraw()?,
+            Action::Discard(card) => self.discard(card)?,
+        }
+        self.actions.push(action);
+        if let Some(result) = self.result() {
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    fn lay_red_three(&mut self, seat: Seat, card: Card) {
+        self.red_threes[seat.team()].push(card);
+        self.events.push_back(Event::RedThree(seat, card));
+    }
+
+    /// Removes one copy of each of the cards from the player's hand.
+    fn remove_cards(&mut self, seat: Seat, cards: &[Card]) {
+        let hand = &mut self.hands[seat];
+        for card in cards {
+            let i = hand.iter().position(|c| c == card).expect("card is held");
+            hand.remove(i);
+        }
+    }
+
+    /// Adds the cards to the team's meld of the rank, or starts a new one.
+    fn add_to_meld(&mut self, seat: Seat, rank: Rank, cards: Vec<Card>) {
+        let melds = &mut self.melds[seat.team()];
+        let i = match melds.iter().position(|m| m.rank() == rank) {
+            Some(i) => i,
+            None => {
+                melds.push(Meld::new(rank, vec![]));
+                melds.len() - 1
+            }
+        };
+        melds[i].extend(cards);
+        self.events.push_back(Event::Meld(seat, melds[i].clone()));
+    }
+
+    /// Ends the round if the player has no cards left.
+    fn check_out(&mut self) {
+        let seat = self.turn;
+        if self.hands[seat].is_empty() {
+            self.opened[seat.team()] = true;
+            self.went_out = Some(seat);
+            self.events.push_back(Event::Out(seat));
+        }
+    }
+
+    /// Draws a card, laying aside any red threes drawn, and drawing again to replace them. The
+    /// round ends if the stock runs out.
+    fn draw(&mut self) -> Result<(), CanastaError> {
+        self.check_draw()?;
+        let seat = self.turn;
+        self.events.push_back(Event::Draw(seat));
+        loop {
+            let Some(card) = self.stock.draw() else {
+                self.exhausted = true;
+                break;
+            };
+            if is_red_three(card) {
+                self.lay_red_three(seat, card);
+                continue;
+            }
+            self.hands[seat].push(card);
+            break;
+        }
+        self.phase = Phase::Play;
+        Ok(())
+    }
+
+    fn take(&mut self, spec: MeldSpec) -> Result<(), CanastaError> {
+        self.check_take(spec)?;
+        let seat = self.turn;
+        let mut cards = spec.select(&self.hands[seat]).expect("cards are held");
+        self.remove_cards(seat, &cards);
+        let len = self.pile.len();
+        let mut taken = Pile::new();
+        self.pile
+            .move_to(&mut taken, len)
+            .expect("the pile is face up");
+        let mut taken = taken.take(len).expect("the pile is face up");
+        let top = taken.pop().expect("the pile is not empty");
+        self.events.push_back(Event::Take(seat, top, len));
+        for card in taken {
+            if is_red_three(card) {
+                self.lay_red_three(seat, card);
+            } else {
+                self.hands[seat].push(card);
+            }
+        }
+        cards.push(top);
+        self.add_to_meld(seat, spec.rank, cards);
+        self.frozen = false;
+        self.opened[seat.team()] = true;
+        self.phase = Phase::Play;
+        self.check_out();
+        Ok(())
+    }
+
+    fn meld(&mut self, spec: MeldSpec) -> Result<(), CanastaError> {
+        self.check_meld(spec)?;
+        let seat = self.turn;
+        let cards = spec.select(&self.hands[seat]).expect("cards are held");
+        self.remove_cards(seat, &cards);
+        self.add_to_meld(seat, spec.rank, cards);
+        self.check_out();
+        Ok(())
+    }
+
+    fn withdraw(&mut self) -> Result<(), CanastaError> {
+        self.check_withdraw()?;
+        let seat = self.turn;
+        let melds = std::mem::take(&mut self.melds[seat.team()]);
+        for meld in melds {
+            self.hands[seat].extend_from_slice(meld.cards());
+        }
+        self.events.push_back(Event::Withdraw(seat));
+        Ok(())
+    }
+
+    fn discard(&mut self, card: Card) -> Result<(), CanastaError> {
+        self.check_discard(card)?;
+        let seat = self.turn;
+        let team = seat.team();
+        self.remove_cards(seat, &[card]);
+        self.pile.push(card);
+        if is_wild(card) {
+            self.frozen = true;
+        }
+        if !self.melds[team].is_empty() {
+            self.opened[team] = true;
+        }
+        self.events.push_back(Event::Discard(seat, card));
+        self.check_out();
+        if self.went_out.is_none() {
+            self.turn = seat.next();
+            self.phase = Phase::Draw;
+            self.opened_before_turn = self.opened[self.turn.team()];
+        }
+        Ok(())
+    }
+
+    /// The result of the round, once it's over.
+    pub fn result(&self) -> Option<RoundResult> {
+        if !self.is_over() {
+            return None;
+        }
+        let mut scores = TeamMap::<TeamScore>::default();
+        for team in TEAMS.iter().copied() {
+            let melds = &self.melds[team];
+            let score = &mut scores[team];
+            score.melds = melds.iter().map(Meld::value).sum();
+            score.canastas = melds.iter().map(Meld::bonus).sum();
+            let red_threes = match self.red_threes[team].len() {
+                4 => 800,
+                n => 100 * i32::try_from(n).expect("at most four red threes"),
+            };
+            score.red_threes = if melds.is_empty() {
+                -red_threes
+            } else {
+                red_threes
+            };
+            score.hands = -Seat::all_seats()
+                .iter()
+                .filter(|s| s.team() == team)
+                .flat_map(|&s| self.hands[s].iter().copied())
+                .map(card_value)
+                .sum::<i32>();
+        }
+        if let Some(seat) = self.went_out {
+            // A player going out without their team having melded before goes out concealed.
+            scores[seat.team()].going_out = if self.opened_before_turn { 100 } else { 200 };
+        }
+        Some(RoundResult {
+            went_out: self.went_out,
+            scores,
+        })
+    }
+}
+
+impl crate::engine::Engine for Round {
+    type Actor = Seat;
+    type Action = Action;
+    type View<'a> = RoundView<'a>;
+    type Event = Event;
+    type Outcome = RoundResult;
+    type Error = CanastaError;
+
+    fn expected_actor(&self) -> Option<Seat> {
+        self.next_actor()
+    }
+
+    fn view(&self, seat: Seat) -> RoundView<'_> {
+        Round::view(self, seat)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), CanastaError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<RoundResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a round: the order of the shoe, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRound {
+    config: RoundConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Round> for RawRound {
+    fn from(round: &Round) -> Self {
+        Self {
+            config: round.config.clone(),
+            actions: round.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawRound> for Round {
+    type Error = CanastaError;
+
+    fn try_from(raw: RawRound) -> Result<Self, CanastaError> {
+        raw.config.validate()?;
+        let mut round = Round::from(raw.config);
+        for action in raw.actions {
+            round.apply_action(action)?;
+        }
+        round.events.clear();
+        Ok(round)
+    }
+}
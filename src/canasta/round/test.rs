@@ -0,0 +1,220 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::canasta::Robot;
+use crate::engine::Engine;
+use crate::french::cards;
+
+/// Removes one copy of each of the cards from the deck.
+fn remove(deck: &mut Vec<Card>, cards: &[Card]) {
+    for card in cards {
+        let i = deck.iter().position(|c| c == card).expect("card in deck");
+        deck.remove(i);
+    }
+}
+
+/// Stacks the shoe so that the players from the dealer's left are dealt the hands, topped up
+/// with natural cards other than threes, followed by the upcard, and then the stock in order.
+fn stacked(scores: TeamMap<i32>, hands: [&str; 4], upcard: &str, stock: &str) -> RoundConfig {
+    let mut rest: Vec<Card> = shoe().into_iter().collect();
+    let mut hands: Vec<Vec<Card>> = hands.iter().map(|h| cards(h)).collect();
+    let upcard = cards(upcard);
+    let stock = cards(stock);
+    for cards in hands.iter().chain([&upcard, &stock].iter().copied()) {
+        remove(&mut rest, cards);
+    }
+    for hand in &mut hands {
+        while hand.len() < HAND_SIZE {
+            let i = rest
+                .iter()
+                .position(|&c| !is_wild(c) && c.rank() != Some(Rank::Three))
+                .unwrap();
+            hand.push(rest.remove(i));
+        }
+    }
+    let mut deck = rest;
+    deck.extend(stock.into_iter().rev());
+    deck.extend(upcard);
+    for hand in hands.into_iter().rev() {
+        deck.extend(hand);
+    }
+    RoundConfig::new(Seat::North, scores, deck).unwrap()
+}
+
+fn spec(rank: Rank, naturals: u8, deuces: u8, jokers: u8) -> MeldSpec {
+    MeldSpec::new(rank, naturals, deuces, jokers)
+}
+
+#[test]
+fn test_invalid_deck() {
+    let mut deck: Vec<Card> = shoe().into_iter().collect();
+    deck.pop();
+    let result = RoundConfig::new(Seat::North, TeamMap::default(), deck.clone());
+    assert!(matches!(result, Err(CanastaError::InvalidDeck)));
+    deck.push(deck[0]);
+    let result = RoundConfig::new(Seat::North, TeamMap::default(), deck);
+    assert!(matches!(result, Err(CanastaError::InvalidDeck)));
+}
+
+#[test]
+fn test_deal() {
+    // East's red three is replaced, and the wild upcard freezes the pile.
+    let config = stacked(TeamMap::default(), ["3♡", "", "", ""], "K♣", "2♣ 7♦");
+    let mut round = Round::from(config);
+    assert_eq!(round.hand(Seat::East).len(), HAND_SIZE);
+    assert!(round.hand(Seat::East).contains(&cards("K♣")[0]));
+    let view = round.view(Seat::East);
+    assert_eq!(view.red_threes[Team::EastWest], cards("3♡"));
+    assert_eq!(view.top, Some(cards("7♦")[0]));
+    assert_eq!((view.pile, view.frozen), (2, true));
+    assert_eq!(round.next_actor(), Some(Seat::East));
+    let events: Vec<Event> = std::iter::from_fn(|| round.pop_event()).collect();
+    assert_eq!(
+        events,
+        [
+            Event::RedThree(Seat::East, cards("3♡")[0]),
+            Event::Upcard(cards("2♣")[0]),
+            Event::Upcard(cards("7♦")[0]),
+        ]
+    );
+}
+
+#[test]
+fn test_take() {
+    let mut scores = TeamMap::default();
+    scores[Team::EastWest] = -100;
+    let hand = "K♤ K♡ Q♤ 2♦";
+    let config = stacked(scores, [hand, "", "", ""], "K♣", "");
+    let mut round = Round::from(config.clone());
+    // The pile is frozen until the team has melded, so it takes a natural pair.
+    assert!(matches!(
+        round.apply_action(Action::Take(spec(Rank::King, 1, 1, 0))),
+        Err(CanastaError::IllegalTake(_))
+    ));
+    let legal = round.legal_actions();
+    assert!(legal.contains(&Action::Draw));
+    assert!(legal.contains(&Action::Take(spec(Rank::King, 2, 0, 0))));
+    assert!(legal.contains(&Action::Take(spec(Rank::King, 2, 1, 0))));
+    assert!(!legal.contains(&Action::Take(spec(Rank::King, 1, 1, 0))));
+    round
+        .apply_action(Action::Take(spec(Rank::King, 2, 0, 0)))
+        .unwrap();
+    assert_eq!(round.melds(Team::EastWest).len(), 1);
+    assert_eq!(round.hand(Seat::East).len(), HAND_SIZE - 2);
+    assert_eq!(round.phase(), Phase::Play);
+    assert!(matches!(
+        round.apply_action(Action::Draw),
+        Err(CanastaError::AlreadyDrew)
+    ));
+    // Discarding a wild card freezes the pile.
+    round.apply_action(Action::Discard(cards("2♦")[0])).unwrap();
+    assert_eq!(round.next_actor(), Some(Seat::South));
+    assert!(round.view(Seat::South).frozen);
+
+    // At the default minimum, three kings don't count enough without the deuce.
+    let mut config = config;
+    config.scores = TeamMap::default();
+    let mut round = Round::from(config);
+    assert!(matches!(
+        round.apply_action(Action::Take(spec(Rank::King, 2, 0, 0))),
+        Err(CanastaError::MinimumNotMet(50))
+    ));
+    assert_eq!(
+        round.legal_actions(),
+        [Action::Draw, Action::Take(spec(Rank::King, 2, 1, 0))]
+    );
+}
+
+#[test]
+fn test_black_three() {
+    let config = stacked(TeamMap::default(), ["3♣ 3♤", "", "", ""], "3♣", "");
+    let round = Round::from(config);
+    assert_eq!(round.legal_actions(), [Action::Draw]);
+}
+
+#[test]
+fn test_initial_meld() {
+    let hand = "A♤ A♡ A♣ K♤ K♡ K♣ 7♣ 8♦ 9♦ T♣ J♦";
+    let config = stacked(TeamMap::default(), [hand, "", "", ""], "5♣", "4♦");
+    let mut round = Round::from(config);
+    assert!(matches!(
+        round.apply_action(Action::Meld(spec(Rank::King, 3, 0, 0))),
+        Err(CanastaError::MustDraw)
+    ));
+    round.apply_action(Action::Draw).unwrap();
+    round
+        .apply_action(Action::Meld(spec(Rank::King, 3, 0, 0)))
+        .unwrap();
+    let legal = round.legal_actions();
+    assert!(legal.contains(&Action::Withdraw));
+    assert!(!legal.iter().any(|a| matches!(a, Action::Discard(_))));
+    assert!(matches!(
+        round.apply_action(Action::Discard(cards("4♦")[0])),
+        Err(CanastaError::MinimumNotMet(50))
+    ));
+    round.apply_action(Action::Withdraw).unwrap();
+    assert_eq!(round.hand(Seat::East).len(), 12);
+    assert!(round.melds(Team::EastWest).is_empty());
+    round
+        .apply_action(Action::Meld(spec(Rank::Ace, 3, 0, 0)))
+        .unwrap();
+    round.apply_action(Action::Discard(cards("4♦")[0])).unwrap();
+    assert!(round.view(Seat::South).opened[Team::EastWest]);
+}
+
+#[test]
+fn test_going_out() {
+    let hand = "K♤ K♡ K♣ K♦ K♤ K♡ K♣ A♤ A♡ A♣ A♦";
+    let config = stacked(TeamMap::default(), [hand, "", "", ""], "5♣", "4♦");
+    let mut round = Round::from(config);
+    round.apply_action(Action::Draw).unwrap();
+    round
+        .apply_action(Action::Meld(spec(Rank::King, 7, 0, 0)))
+        .unwrap();
+    round
+        .apply_action(Action::Meld(spec(Rank::Ace, 4, 0, 0)))
+        .unwrap();
+    round.apply_action(Action::Discard(cards("4♦")[0])).unwrap();
+    assert_eq!(round.next_actor(), None);
+    assert!(matches!(
+        round.apply_action(Action::Draw),
+        Err(CanastaError::RoundOver)
+    ));
+
+    let count = |seat: Seat| -> i32 { round.hand(seat).iter().copied().map(card_value).sum() };
+    let result = round.result().unwrap();
+    assert_eq!(result.went_out, Some(Seat::East));
+    let ew = result.scores[Team::EastWest];
+    assert_eq!(
+        ew,
+        TeamScore {
+            melds: 150,
+            canastas: 500,
+            red_threes: 0,
+            going_out: 200,
+            hands: -count(Seat::West),
+        }
+    );
+    let ns = result.scores[Team::NorthSouth];
+    assert_eq!(ns.hands, -count(Seat::North) - count(Seat::South));
+    assert_eq!(ns.melds + ns.canastas + ns.going_out, 0);
+}
+
+#[test]
+fn test_raw_round() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut round = Round::from(RoundConfig::random(
+        Seat::West,
+        TeamMap::default(),
+        &mut rng,
+    ));
+    let result = round
+        .play_out(|round, seat, legal| Robot.choose(&round.view(seat), &legal))
+        .unwrap()
+        .expect("round is over");
+    let raw = serde_json::to_string(&RawRound::from(&round)).unwrap();
+    let replayed = Round::try_from(serde_json::from_str::<RawRound>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), round.actions());
+}
@@ -4,6 +4,7 @@ use std::iter::FromIterator;
 
 use rand::distributions::{Distribution, Standard};
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 /// A deck of cards.
 #[derive(Debug, Clone)]
@@ -15,9 +16,9 @@ impl<C> Distribution<Deck<C>> for Standard
 where
     Deck<C>: Default,
 {
-    fn sample<R: rand::prelude::Rng + ?Sized>(&self, rng: &mut R) -> Deck<C> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Deck<C> {
         let mut deck = Deck::default();
-        deck.cards.shuffle(rng);
+        deck.shuffle(rng);
         deck
     }
 }
@@ -29,15 +30,309 @@ impl<C> FromIterator<C> for Deck<C> {
     }
 }
 
+impl<C> IntoIterator for Deck<C> {
+    type Item = C;
+    type IntoIter = std::vec::IntoIter<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
 impl<C> Deck<C> {
+    /// Creates a new [`Deck`] from the specified cards. The last card is the top of the deck.
+    pub fn with_cards(cards: Vec<C>) -> Self {
+        Self { cards }
+    }
+
+    /// Returns the cards remaining in the deck, from bottom to top.
+    pub fn cards(&self) -> &[C] {
+        &self.cards
+    }
+
     /// The number of cards remaining in the deck.
     pub fn len(&self) -> usize {
         self.cards.len()
     }
 
+    /// Returns true if there are no cards remaining in the deck.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
     /// Removes a card from the deck.
     pub fn take(&mut self, n: usize) -> Vec<C> {
         let idx = self.cards.len().saturating_sub(n);
         self.cards.split_off(idx)
     }
+
+    /// Shuffles the remaining cards in the deck.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+}
+
+/// The orientation of a card in a [`Pile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Facing {
+    Up,
+    Down,
+}
+
+/// An error that can occur when moving cards between piles.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PileError {
+    /// The pile holds fewer cards than requested.
+    #[error("requested {requested} cards, but only {available} available")]
+    NotEnoughCards { requested: usize, available: usize },
+    /// Face-down cards cannot be moved.
+    #[error("cannot move face-down cards")]
+    FaceDown,
+    /// The stock cannot be replenished until it is empty.
+    #[error("the stock is not empty")]
+    NotEmpty,
+}
+
+/// An ordered pile of cards, each of which is either face up or face down. The last card is the
+/// top of the pile.
+#[derive(Debug, Clone)]
+pub struct Pile<C> {
+    cards: Vec<(C, Facing)>,
+}
+
+impl<C> Default for Pile<C> {
+    fn default() -> Self {
+        Self { cards: vec![] }
+    }
+}
+
+impl<C> Pile<C> {
+    /// Creates a new, empty [`Pile`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of cards in the pile.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the pile is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the top card of the pile, and its facing.
+    pub fn top(&self) -> Option<(&C, Facing)> {
+        self.cards.last().map(|(c, f)| (c, *f))
+    }
+
+    /// Returns an iterator over the cards in the pile, from bottom to top.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, Facing)> {
+        self.cards.iter().map(|(c, f)| (c, *f))
+    }
+
+    /// The number of consecutive face-up cards at the top of the pile.
+    pub fn face_up_len(&self) -> usize {
+        self.cards
+            .iter()
+            .rev()
+            .take_while(|(_, f)| *f == Facing::Up)
+            .count()
+    }
+
+    /// Places a card on top of the pile.
+    pub fn push(&mut self, card: C, facing: Facing) {
+        self.cards.push((card, facing));
+    }
+
+    /// Removes the top card from the pile, regardless of its facing.
+    pub fn pop(&mut self) -> Option<(C, Facing)> {
+        self.cards.pop()
+    }
+
+    /// Turns the top card face up. Returns true if the card was previously face down.
+    pub fn flip_top(&mut self) -> bool {
+        match self.cards.last_mut() {
+            Some((_, facing @ Facing::Down)) => {
+                *facing = Facing::Up;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the top `n` face-up cards from the pile, in bottom-to-top order.
+    pub fn take(&mut self, n: usize) -> Result<Vec<C>, PileError> {
+        if n > self.cards.len() {
+            return Err(PileError::NotEnoughCards {
+                requested: n,
+                available: self.cards.len(),
+            });
+        }
+        if n > self.face_up_len() {
+            return Err(PileError::FaceDown);
+        }
+        let idx = self.cards.len() - n;
+        Ok(self
+            .cards
+            .split_off(idx)
+            .into_iter()
+            .map(|(c, _)| c)
+            .collect())
+    }
+
+    /// Moves the top `n` face-up cards onto another pile, preserving their order.
+    pub fn move_to(&mut self, other: &mut Pile<C>, n: usize) -> Result<(), PileError> {
+        for card in self.take(n)? {
+            other.push(card, Facing::Up);
+        }
+        Ok(())
+    }
+}
+
+/// A face-down pile from which cards are drawn.
+#[derive(Debug, Clone)]
+pub struct Stock<C>(Pile<C>);
+
+impl<C> From<Deck<C>> for Stock<C> {
+    fn from(deck: Deck<C>) -> Self {
+        let mut pile = Pile::new();
+        for card in deck {
+            pile.push(card, Facing::Down);
+        }
+        Self(pile)
+    }
+}
+
+impl<C> Stock<C> {
+    /// The number of cards remaining in the stock.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the stock is exhausted.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Draws the top card from the stock.
+    pub fn draw(&mut self) -> Option<C> {
+        self.0.pop().map(|(c, _)| c)
+    }
+
+    /// Draws a card from the stock and turns it face up on the waste.
+    pub fn turn(&mut self, waste: &mut Waste<C>) -> bool {
+        match self.draw() {
+            Some(card) => {
+                waste.push(card);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Turns the waste over to form a new stock. Fails if the stock is not yet exhausted.
+    pub fn recycle(&mut self, waste: &mut Waste<C>) -> Result<(), PileError> {
+        if !self.is_empty() {
+            return Err(PileError::NotEmpty);
+        }
+        while let Some((card, _)) = waste.0.pop() {
+            self.0.push(card, Facing::Down);
+        }
+        Ok(())
+    }
+}
+
+/// A face-up pile onto which cards are discarded.
+#[derive(Debug, Clone)]
+pub struct Waste<C>(Pile<C>);
+
+impl<C> Default for Waste<C> {
+    fn default() -> Self {
+        Self(Pile::new())
+    }
+}
+
+impl<C> Waste<C> {
+    /// Creates a new, empty [`Waste`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of cards in the waste.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the waste is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the top card of the waste.
+    pub fn top(&self) -> Option<&C> {
+        self.0.top().map(|(c, _)| c)
+    }
+
+    /// Returns an iterator over the cards in the waste, from bottom to top.
+    pub fn iter(&self) -> impl Iterator<Item = &C> {
+        self.0.iter().map(|(c, _)| c)
+    }
+
+    /// Discards a card onto the waste.
+    pub fn push(&mut self, card: C) {
+        self.0.push(card, Facing::Up);
+    }
+
+    /// Removes the top card from the waste.
+    pub fn pop(&mut self) -> Option<C> {
+        self.0.pop().map(|(c, _)| c)
+    }
+
+    /// Moves the top `n` cards onto another pile.
+    pub fn move_to(&mut self, other: &mut Pile<C>, n: usize) -> Result<(), PileError> {
+        self.0.move_to(other, n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pile_moves() {
+        let mut pile = Pile::new();
+        pile.push(1, Facing::Down);
+        pile.push(2, Facing::Down);
+        pile.push(3, Facing::Up);
+        assert_eq!(pile.face_up_len(), 1);
+
+        let mut other = Pile::new();
+        assert_eq!(pile.move_to(&mut other, 2), Err(PileError::FaceDown));
+        assert_eq!(
+            pile.take(4),
+            Err(PileError::NotEnoughCards {
+                requested: 4,
+                available: 3
+            })
+        );
+        pile.move_to(&mut other, 1).unwrap();
+        assert!(pile.flip_top());
+        assert!(!pile.flip_top());
+        pile.move_to(&mut other, 1).unwrap();
+        assert_eq!(other.iter().map(|(&c, _)| c).collect::<Vec<_>>(), [3, 2]);
+    }
+
+    #[test]
+    fn test_stock_and_waste() {
+        let mut stock = Stock::from(Deck::with_cards(vec![1, 2, 3]));
+        let mut waste = Waste::new();
+        assert_eq!(stock.recycle(&mut waste), Err(PileError::NotEmpty));
+        while stock.turn(&mut waste) {}
+        assert_eq!(waste.top(), Some(&1));
+        stock.recycle(&mut waste).unwrap();
+        assert!(waste.is_empty());
+        assert_eq!(stock.draw(), Some(3));
+    }
 }
@@ -0,0 +1,106 @@
+//! Durak.
+//!
+//! Two to six players are each dealt six cards from a 36-card deck, sixes through aces. The bottom
+//! card of the stock is turned face up beneath it, and its suit is trump. The player holding the
+//! lowest trump attacks first, against the player to their left.
+//!
+//! The attacker leads one or more cards of a rank, and the defender must beat each attacking card,
+//! with a higher card of its suit, or with a trump if it isn't a trump itself. Once every attack
+//! is beaten, any player but the defender may add cards of ranks already on the table, so long as
+//! the defender holds enough cards to answer them, and no more than six are played. The defender
+//! may instead take the cards on the table into their hand, after the others have added what they
+//! like; otherwise, once nobody adds anything more, the attack is beaten off and the cards are
+//! discarded.
+//!
+//! Players then refill their hands to six from the stock, the attacker first and the defender
+//! last. A defender who beats off an attack leads the next; one who takes is skipped. Once the
+//! stock is exhausted, players who run out of cards are out of the game, and the last player left
+//! holding cards is the durak, or fool.
+
+mod error;
+mod robot;
+mod round;
+#[cfg(feature = "tui")]
+mod tui;
+
+use itertools::Itertools;
+
+pub use self::error::DurakError;
+pub use self::robot::Robot;
+pub use self::round::{Action, Event, RawRound, Round, RoundConfig, RoundResult, RoundView};
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The number of cards to which players refill their hands.
+pub const HAND_SIZE: usize = 6;
+
+/// The most cards that may be played against the defender in a single attack.
+pub const MAX_ATTACKS: usize = 6;
+
+/// The 36-card deck with which durak is played, sixes through aces.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new()
+        .filter_ranks(|r| r == Rank::Ace || r >= Rank::Six)
+        .build()
+}
+
+/// The rank of a card, from 0 for a two up to 12 for an ace.
+fn rank_order(card: Card) -> u8 {
+    card.rank().map_or(0, Rank::ace_high)
+}
+
+/// Returns true if the defending card beats the attacking card: it must be a higher card of the
+/// same suit, or a trump played against a card that isn't a trump.
+pub fn beats(trump: Suit, attack: Card, defense: Card) -> bool {
+    if attack.suit() == defense.suit() {
+        rank_order(defense) > rank_order(attack)
+    } else {
+        defense.suit() == Some(trump)
+    }
+}
+
+/// The strength of a card, for ordering: trumps above every other suit, and by rank within each.
+pub fn strength(trump: Suit, card: Card) -> u8 {
+    let bonus = if card.suit() == Some(trump) { 13 } else { 0 };
+    rank_order(card) + bonus
+}
+
+/// Formats cards by suit, and in rank order within each suit.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|c| (c.suit(), rank_order(**c)))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Plays a game with a simple command-line interface, with the user as the first of four players
+/// and robots as the others. The record of the game is written to stderr when it's over.
+pub fn cli_main() -> anyhow::Result<()> {
+    const PLAYERS: u8 = 4;
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let dealer = Player(rand::random::<u8>() % PLAYERS);
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    let config = RoundConfig::random(dealer, PLAYERS, &mut rand::thread_rng())?;
+    let mut round = Round::from(config);
+    console.play(&mut round, me, |round, player, legal| {
+        robot.choose(&round.view(player), &legal)
+    })?;
+    serde_json::to_writer(std::io::stderr(), &RawRound::from(&round))?;
+    Ok(())
+}
+
+/// Plays games in a rich terminal UI, with the user as the first of four players and robots as
+/// the others. The durak deals the next game.
+#[cfg(feature = "tui")]
+pub fn tui_main() -> anyhow::Result<()> {
+    let mut terminal = crate::engine::tui::init()?;
+    let result = tui::Tui::new(4).run(&mut terminal);
+    crate::engine::tui::restore()?;
+    result
+}
@@ -0,0 +1,38 @@
+//! Errors
+
+use super::{Action, Card, Player};
+
+/// An error that can occur during a game.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DurakError {
+    /// Durak is played by two to six players.
+    #[error("durak is played by two to six players, not {0}")]
+    InvalidPlayers(u8),
+    /// The deck isn't a 36-card deck.
+    #[error("the deck must hold the 36 cards from sixes through aces")]
+    InvalidDeck,
+    /// The action isn't expected of the player whose turn it is.
+    #[error("unexpected action: {0}")]
+    OutOfTurn(Action),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// Attacks are made with one or more cards of a single rank.
+    #[error("must attack with one or more cards of a single rank")]
+    InvalidAttack,
+    /// Cards added to an attack must match the rank of a card on the table.
+    #[error("cannot add {0}, as no card of its rank is on the table")]
+    CannotAdd(Card),
+    /// The attack would hold more cards than the defender can answer, or more than six.
+    #[error("too many cards for the defender to answer")]
+    TooManyCards,
+    /// The card isn't an unbeaten attacking card.
+    #[error("{0} is not an unbeaten attacking card")]
+    NotAttacking(Card),
+    /// The defending card doesn't beat the attacking card.
+    #[error("{1} does not beat {0}")]
+    CannotBeat(Card, Card),
+    /// The game is over, no more actions are expected.
+    #[error("game is over")]
+    GameOver,
+}
@@ -0,0 +1,90 @@
+//! A robot player.
+//!
+//! The robot leads its weakest cards, with every card of the rank it can spare, and adds low
+//! cards to attacks while the stock lasts, or anything but trumps once it's gone. It defends
+//! with the cheapest cards that will beat every attack on the table, and takes the cards when no
+//! such defense exists.
+
+use std::cmp::Reverse;
+
+use super::{beats, strength, Action, Card, Rank, RoundView, Suit};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &RoundView, legal: &[Action]) -> Action {
+        let choice = if view.table.is_empty() {
+            attack(view.trump(), legal)
+        } else if view.to_play == Some(view.defender) && !view.taking {
+            defend(view)
+        } else {
+            add(view, legal)
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0].clone())
+    }
+}
+
+/// Chooses the attack whose strongest card is weakest, with as many cards as possible.
+fn attack(trump: Suit, legal: &[Action]) -> Option<Action> {
+    legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Attack(cards) => Some(cards),
+            _ => None,
+        })
+        .min_by_key(|cards| {
+            let strongest = cards.iter().map(|&c| strength(trump, c)).max();
+            (strongest, Reverse(cards.len()))
+        })
+        .map(|cards| Action::Attack(cards.clone()))
+}
+
+/// Adds cards to the attack: those below a jack while the stock lasts, and anything but trumps
+/// once it's gone.
+fn add(view: &RoundView, legal: &[Action]) -> Option<Action> {
+    let trump = view.trump();
+    let spare = |card: &Card| {
+        card.suit() != Some(trump)
+            && (view.stock == 0 || card.rank().map(Rank::ace_high) < Some(Rank::Jack.ace_high()))
+    };
+    let spares: Vec<Action> = legal
+        .iter()
+        .filter(|a| matches!(a, Action::Attack(cards) if cards.iter().all(spare)))
+        .cloned()
+        .collect();
+    attack(trump, &spares).or(Some(Action::Pass))
+}
+
+/// Beats the attacking cards with the cheapest defense that answers them all: each card, from
+/// the strongest down, with the weakest card that beats it. Returns `None` if some card can't be
+/// beaten.
+fn plan_defense(trump: Suit, hand: &[Card], attacks: &[Card]) -> Option<Vec<(Card, Card)>> {
+    let mut hand = hand.to_vec();
+    hand.sort_by_key(|&c| strength(trump, c));
+    let mut attacks = attacks.to_vec();
+    attacks.sort_by_key(|&c| Reverse(strength(trump, c)));
+    attacks
+        .into_iter()
+        .map(|attack| {
+            let i = hand.iter().position(|&c| beats(trump, attack, c))?;
+            Some((attack, hand.remove(i)))
+        })
+        .collect()
+}
+
+/// Follows the planned defense, or takes if there's none.
+fn defend(view: &RoundView) -> Option<Action> {
+    let attacks: Vec<Card> = view.unbeaten().collect();
+    match plan_defense(view.trump(), view.hand, &attacks) {
+        Some(plan) => plan.first().map(|&(a, d)| Action::Defend(a, d)),
+        None => Some(Action::Take),
+    }
+}
@@ -0,0 +1,52 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::durak::{Player, Round, RoundConfig};
+use crate::engine::Engine;
+use crate::french::cards;
+
+#[test]
+fn test_plan_defense() {
+    let hand = cards("7♣ 9♣ 6♡ Q♤");
+    // Only the trump beats the king, and the nine is the cheapest card that beats the eight.
+    let plan = plan_defense(Suit::Heart, &hand, &cards("K♤ 8♣")).unwrap();
+    assert_eq!(
+        plan,
+        [
+            (cards("K♤")[0], cards("6♡")[0]),
+            (cards("8♣")[0], cards("9♣")[0])
+        ]
+    );
+    let plan = plan_defense(Suit::Heart, &hand, &cards("T♤ 6♣")).unwrap();
+    assert_eq!(
+        plan,
+        [
+            (cards("T♤")[0], cards("Q♤")[0]),
+            (cards("6♣")[0], cards("7♣")[0])
+        ]
+    );
+    // Nothing beats a diamond but the only trump, which can't beat both.
+    assert_eq!(plan_defense(Suit::Heart, &hand, &cards("T♦ J♦")), None);
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(41);
+    for players in 2..=6 {
+        for _ in 0..20 {
+            let dealer = Player(0);
+            let mut round = Round::from(RoundConfig::random(dealer, players, &mut rng).unwrap());
+            let result = round
+                .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+                .unwrap()
+                .expect("game is over");
+            let durak = usize::from(result.durak.is_some());
+            assert_eq!(result.out.len() + durak, usize::from(players));
+            if let Some(player) = result.durak {
+                assert!(!round.hand(player).is_empty());
+                assert!(!result.out.contains(&player));
+            }
+        }
+    }
+}
@@ -0,0 +1,745 @@
+//! A single game: from the deal until every player but the durak is out.
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    beats, deck, format_cards, strength, Card, DurakError, Player, Rank, Suit, HAND_SIZE,
+    MAX_ATTACKS,
+};
+use crate::deck::{Deck, Stock};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The order of the deck, and the players at the table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundConfig {
+    dealer: Player,
+    players: u8,
+    deck: Vec<Card>,
+}
+
+impl RoundConfig {
+    /// Shuffles the deck.
+    pub fn random<R: Rng + ?Sized>(
+        dealer: Player,
+        players: u8,
+        rng: &mut R,
+    ) -> Result<Self, DurakError> {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        Self::new(dealer, players, deck.into_iter().collect())
+    }
+
+    /// Creates a game from the specified deck, which must hold the 36 cards from sixes through
+    /// aces. The last card is the top of the deck, and the first is the trump card.
+    pub fn new(dealer: Player, players: u8, deck: Vec<Card>) -> Result<Self, DurakError> {
+        let config = Self {
+            dealer,
+            players,
+            deck,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks the number of players, and that the deck holds exactly the cards from sixes
+    /// through aces.
+    pub fn validate(&self) -> Result<(), DurakError> {
+        if !(2..=6).contains(&self.players) || self.dealer.0 >= self.players {
+            return Err(DurakError::InvalidPlayers(self.players));
+        }
+        let expected: HashSet<Card> = deck().into_iter().collect();
+        let actual: HashSet<Card> = self.deck.iter().copied().collect();
+        if self.deck.len() != expected.len() || actual != expected {
+            return Err(DurakError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The number of players.
+    pub fn players(&self) -> u8 {
+        self.players
+    }
+
+    /// The trump card, at the bottom of the deck.
+    pub fn trump_card(&self) -> Card {
+        self.deck[0]
+    }
+}
+
+/// An action taken during a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Plays one or more cards of a rank against the defender: any rank to lead an attack, or a
+    /// rank already on the table to add to it.
+    Attack(Vec<Card>),
+    /// Beats the attacking card with the defending card.
+    Defend(Card, Card),
+    /// Takes the cards on the table, once the others have added to them.
+    Take,
+    /// Declines to add to the attack.
+    Pass,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Attack(cards) => {
+                f.write_str(&tr!("durak-action-attack", cards = format_cards(cards)))
+            }
+            Action::Defend(attack, defense) => {
+                let (attack, defense) = (attack.to_string(), defense.to_string());
+                f.write_str(&tr!(
+                    "durak-action-beat",
+                    attack = attack,
+                    defense = defense
+                ))
+            }
+            Action::Take => f.write_str(&tr!("durak-action-take")),
+            Action::Pass => f.write_str(&tr!("action-pass")),
+        }
+    }
+}
+
+/// The result of a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// The last player left holding cards, or `None` if the last players ran out together.
+    pub durak: Option<Player>,
+    /// The players who ran out of cards, in the order that they did so.
+    pub out: Vec<Player>,
+}
+
+impl Display for RoundResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.durak {
+            Some(player) => f.write_str(&tr!("durak-is-durak", player = player.to_string())),
+            None => f.write_str(&tr!("durak-nobody")),
+        }
+    }
+}
+
+/// An event that occurs during a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// The trump card was turned up beneath the stock.
+    Trump(Card),
+    /// A player attacked, or added to an attack.
+    Attack(Player, Vec<Card>),
+    /// The defender beat an attacking card.
+    Defend(Player, Card, Card),
+    /// The defender chose to take the cards on the table.
+    Take(Player),
+    /// A player declined to add to the attack.
+    Pass(Player),
+    /// The defender took the cards into their hand.
+    PickUp(Player, Vec<Card>),
+    /// The defender beat off the attack, and the cards were discarded.
+    BeatOff(Player),
+    /// A player drew cards from the stock.
+    Draw(Player, usize),
+    /// A player ran out of cards, and is out of the game.
+    Out(Player),
+    /// The game is over.
+    Over(RoundResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Trump(card) => f.write_str(&tr!("game-trump", trump = card.to_string())),
+            Event::Attack(player, cards) => {
+                let (player, cards) = (player.to_string(), cards.iter().join(" "));
+                f.write_str(&tr!("durak-attacked", player = player, cards = cards))
+            }
+            Event::Defend(player, attack, defense) => {
+                let player = player.to_string();
+                let (attack, defense) = (attack.to_string(), defense.to_string());
+                let text = tr!(
+                    "durak-beat",
+                    player = player,
+                    attack = attack,
+                    defense = defense
+                );
+                f.write_str(&text)
+            }
+            Event::Take(player) => {
+                f.write_str(&tr!("durak-will-take", player = player.to_string()))
+            }
+            Event::Pass(player) => f.write_str(&tr!("game-passed", player = player.to_string())),
+            Event::PickUp(player, cards) => {
+                let (player, cards) = (player.to_string(), cards.iter().join(" "));
+                f.write_str(&tr!("game-took", player = player, cards = cards))
+            }
+            Event::BeatOff(player) => {
+                f.write_str(&tr!("durak-beat-off", player = player.to_string()))
+            }
+            Event::Draw(player, n) => {
+                f.write_str(&tr!("durak-drew", player = player.to_string(), count = *n))
+            }
+            Event::Out(player) => f.write_str(&tr!("durak-out", player = player.to_string())),
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the game, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct RoundView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The trump card, at the bottom of the stock.
+    pub trump_card: Card,
+    /// The player's cards.
+    pub hand: &'a [Card],
+    /// The number of cards held by each player.
+    pub hand_sizes: Vec<usize>,
+    /// The number of cards in the stock.
+    pub stock: usize,
+    /// The number of cards discarded from attacks that were beaten off.
+    pub discards: usize,
+    /// The attacking cards on the table, each with the card that beat it, if any.
+    pub table: &'a [(Card, Option<Card>)],
+    /// The player leading the attack.
+    pub attacker: Player,
+    /// The player defending against it.
+    pub defender: Player,
+    /// True if the defender has chosen to take the cards on the table.
+    pub taking: bool,
+    /// The players who have run out of cards, in the order that they did so.
+    pub out: &'a [Player],
+    /// The player whose turn it is, or `None` if the game is over.
+    pub to_play: Option<Player>,
+}
+
+impl RoundView<'_> {
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump_card.suit().expect("not a joker")
+    }
+
+    /// The attacking cards that haven't been beaten.
+    pub fn unbeaten(&self) -> impl Iterator<Item = Card> + '_ {
+        self.table
+            .iter()
+            .filter(|(_, defense)| defense.is_none())
+            .map(|&(attack, _)| attack)
+    }
+}
+
+impl Display for RoundView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, attacker) = (self.player.to_string(), self.attacker.to_string());
+        let defender = self.defender.to_string();
+        let text = tr!(
+            "durak-view",
+            player = player,
+            attacker = attacker,
+            defender = defender
+        );
+        writeln!(f, "{text}")?;
+        let (card, stock, discards) = (self.trump_card.to_string(), self.stock, self.discards);
+        let text = tr!(
+            "durak-status",
+            card = card,
+            stock = stock,
+            discards = discards
+        );
+        writeln!(f, "{text}")?;
+        let table = self
+            .table
+            .iter()
+            .map(|(attack, defense)| match defense {
+                Some(defense) => format!("{attack}/{defense}"),
+                None => attack.to_string(),
+            })
+            .join(" ");
+        writeln!(f, "{}", tr!("game-table", cards = table))?;
+        let sizes = self
+            .hand_sizes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != self.player.index())
+            .map(|(i, n)| format!("{} {n}", tr!("engine-player-abbr", n = i + 1)))
+            .join(", ");
+        writeln!(f, "{}", tr!("game-cards-held", sizes = sizes))?;
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a game.
+#[derive(Debug, Clone)]
+pub struct Round {
+    config: RoundConfig,
+    trump: Suit,
+    hands: Vec<Vec<Card>>,
+    stock: Stock<Card>,
+    discards: usize,
+    table: Vec<(Card, Option<Card>)>,
+    attacker: Player,
+    defender: Player,
+    taking: bool,
+    /// The players who have declined to add to the attack since cards were last added.
+    passed: Vec<Player>,
+    out: Vec<Player>,
+    over: bool,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<RoundConfig> for Round {
+    fn from(config: RoundConfig) -> Self {
+        let trump_card = config.trump_card();
+        let stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let players = config.players;
+        let mut round = Self {
+            trump: trump_card.suit().expect("not a joker"),
+            hands: vec![vec![]; usize::from(players)],
+            stock,
+            discards: 0,
+            table: vec![],
+            attacker: config.dealer,
+            defender: config.dealer,
+            taking: false,
+            passed: vec![],
+            out: vec![],
+            over: false,
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        };
+        round.deal();
+        round.events.push_back(Event::Trump(trump_card));
+        round.attacker = round.first_attacker();
+        round.defender = round.attacker.next(players);
+        round
+    }
+}
+
+impl Round {
+    /// Deals the hands, starting to the dealer's left.
+    fn deal(&mut self) {
+        let players = self.config.players;
+        let mut player = self.config.dealer.next(players);
+        for _ in 0..players {
+            let hand = (0..HAND_SIZE).filter_map(|_| self.stock.draw()).collect();
+            self.hands[player.index()] = hand;
+            player = player.next(players);
+        }
+    }
+
+    /// The player holding the lowest trump, or the player to the dealer's left if nobody holds a
+    /// trump.
+    fn first_attacker(&self) -> Player {
+        let trump = self.trump;
+        Player::all(self.config.players)
+            .filter_map(|p| {
+                let lowest = self
+                    .hand(p)
+                    .iter()
+                    .filter(|c| c.suit() == Some(trump))
+                    .map(|&c| strength(trump, c))
+                    .min()?;
+                Some((lowest, p))
+            })
+            .min()
+            .map_or_else(|| self.config.dealer.next(self.config.players), |(_, p)| p)
+    }
+
+    /// The game as it was dealt.
+    pub fn config(&self) -> &RoundConfig {
+        &self.config
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The attacking cards on the table, each with the card that beat it, if any.
+    pub fn table(&self) -> &[(Card, Option<Card>)] {
+        &self.table
+    }
+
+    /// The player leading the attack.
+    pub fn attacker(&self) -> Player {
+        self.attacker
+    }
+
+    /// The player defending against it.
+    pub fn defender(&self) -> Player {
+        self.defender
+    }
+
+    /// The number of attacking cards that haven't been beaten.
+    fn unbeaten(&self) -> usize {
+        self.table.iter().filter(|(_, d)| d.is_none()).count()
+    }
+
+    /// The number of cards that may yet be added to the attack.
+    fn room(&self) -> usize {
+        let answerable = self
+            .hand(self.defender)
+            .len()
+            .saturating_sub(self.unbeaten());
+        answerable.min(MAX_ATTACKS - self.table.len())
+    }
+
+    /// Returns true if the rank of the card is already on the table.
+    fn on_table(&self, rank: Option<Rank>) -> bool {
+        self.table
+            .iter()
+            .flat_map(|&(a, d)| std::iter::once(a).chain(d))
+            .any(|c| c.rank() == rank)
+    }
+
+    /// The players who may add to the attack, in turn: the attacker first, and then the others
+    /// around the table, but never the defender.
+    fn attackers(&self) -> impl Iterator<Item = Player> + '_ {
+        let players = self.config.players;
+        std::iter::successors(Some(self.attacker), move |p| Some(p.next(players)))
+            .take(usize::from(players))
+            .filter(move |&p| p != self.defender)
+    }
+
+    /// The next player who may add to the attack, and hasn't yet declined to.
+    fn next_adder(&self) -> Option<Player> {
+        if self.room() == 0 {
+            return None;
+        }
+        self.attackers().find(|p| {
+            !self.passed.contains(p) && self.hand(*p).iter().any(|c| self.on_table(c.rank()))
+        })
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        if self.over {
+            None
+        } else if self.table.is_empty() {
+            Some(self.attacker)
+        } else if self.unbeaten() > 0 && !self.taking {
+            Some(self.defender)
+        } else {
+            self.next_adder()
+        }
+    }
+
+    /// Returns the state of the game, as visible to the player.
+    pub fn view(&self, player: Player) -> RoundView<'_> {
+        RoundView {
+            player,
+            dealer: self.config.dealer,
+            trump_card: self.config.trump_card(),
+            hand: &self.hands[player.index()],
+            hand_sizes: self.hands.iter().map(Vec::len).collect(),
+            stock: self.stock.len(),
+            discards: self.discards,
+            table: &self.table,
+            attacker: self.attacker,
+            defender: self.defender,
+            taking: self.taking,
+            out: &self.out,
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// Checks that the player holds every card once.
+    fn check_held(&self, player: Player, cards: &[Card]) -> Result<(), DurakError> {
+        let hand = self.hand(player);
+        let mut seen = HashSet::new();
+        for &card in cards {
+            if !hand.contains(&card) || !seen.insert(card) {
+                return Err(DurakError::CardNotHeld(player, card));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_attack(&self, player: Player, cards: &[Card]) -> Result<(), DurakError> {
+        self.check_held(player, cards)?;
+        let Some(&first) = cards.first() else {
+            return Err(DurakError::InvalidAttack);
+        };
+        if cards.iter().any(|c| c.rank() != first.rank()) {
+            return Err(DurakError::InvalidAttack);
+        }
+        if !self.table.is_empty() && !self.on_table(first.rank()) {
+            return Err(DurakError::CannotAdd(first));
+        }
+        let room = if self.table.is_empty() {
+            self.hand(self.defender).len().min(MAX_ATTACKS)
+        } else {
+            self.room()
+        };
+        if cards.len() > room {
+            return Err(DurakError::TooManyCards);
+        }
+        Ok(())
+    }
+
+    fn check_defend(&self, attack: Card, defense: Card) -> Result<(), DurakError> {
+        self.check_held(self.defender, &[defense])?;
+        if !self.table.contains(&(attack, None)) {
+            return Err(DurakError::NotAttacking(attack));
+        }
+        if !beats(self.trump, attack, defense) {
+            return Err(DurakError::CannotBeat(attack, defense));
+        }
+        Ok(())
+    }
+
+    /// Returns the actions the current player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        let Some(player) = self.next_actor() else {
+            return vec![];
+        };
+        let mut actions = vec![];
+        if player == self.defender && !self.taking {
+            let mut hand = self.hand(player).to_vec();
+            hand.sort_by_key(|&c| strength(self.trump, c));
+            for &(attack, _) in self.table.iter().filter(|(_, d)| d.is_none()) {
+                actions.extend(
+                    hand.iter()
+                        .filter(|&&c| beats(self.trump, attack, c))
+                        .map(|&c| Action::Defend(attack, c)),
+                );
+            }
+            actions.push(Action::Take);
+            return actions;
+        }
+        let hand = self
+            .hand(player)
+            .iter()
+            .copied()
+            .sorted_by_key(|&c| (c.rank().map(Rank::ace_high), strength(self.trump, c)));
+        for (_, group) in &hand.chunk_by(|c| c.rank()) {
+            let group: Vec<Card> = group.collect();
+            actions.extend(
+                group
+                    .into_iter()
+                    .powerset()
+                    .filter(|cards| self.check_attack(player, cards).is_ok())
+                    .map(Action::Attack),
+            );
+        }
+        if !self.table.is_empty() {
+            actions.push(Action::Pass);
+        }
+        actions
+    }
+
+    /// Applies the current player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), DurakError> {
+        let Some(player) = self.next_actor() else {
+            return Err(DurakError::GameOver);
+        };
+        let defending = player == self.defender && !self.taking;
+        match &action {
+            Action::Attack(cards) if !defending => self.attack(player, cards)?,
+            Action::Defend(attack, defense) if defending => self.defend(*attack, *defense)?,
+            Action::Take if defending => {
+                self.taking = true;
+                self.events.push_back(Event::Take(player));
+            }
+            Action::Pass if !defending && !self.table.is_empty() => {
+                self.passed.push(player);
+                self.events.push_back(Event::Pass(player));
+            }
+            _ => return Err(DurakError::OutOfTurn(action)),
+        }
+        self.actions.push(action);
+        if self.next_actor().is_none() {
+            self.end_attack();
+        }
+        Ok(())
+    }
+
+    /// Removes the cards from the player's hand.
+    fn remove_cards(&mut self, player: Player, cards: &[Card]) {
+        self.hands[player.index()].retain(|c| !cards.contains(c));
+    }
+
+    fn attack(&mut self, player: Player, cards: &[Card]) -> Result<(), DurakError> {
+        self.check_attack(player, cards)?;
+        self.remove_cards(player, cards);
+        self.table.extend(cards.iter().map(|&c| (c, None)));
+        self.passed.clear();
+        self.events.push_back(Event::Attack(player, cards.to_vec()));
+        Ok(())
+    }
+
+    fn defend(&mut self, attack: Card, defense: Card) -> Result<(), DurakError> {
+        self.check_defend(attack, defense)?;
+        self.remove_cards(self.defender, &[defense]);
+        let pair = self
+            .table
+            .iter_mut()
+            .find(|pair| **pair == (attack, None))
+            .expect("attacking card is on the table");
+        pair.1 = Some(defense);
+        self.events
+            .push_back(Event::Defend(self.defender, attack, defense));
+        Ok(())
+    }
+
+    /// Ends the attack, once nobody will add to it: the defender either picks up the cards on
+    /// the table, or beats them off. Hands are refilled, and the next attack begins, unless the
+    /// game is over.
+    fn end_attack(&mut self) {
+        let players = self.config.players;
+        let defender = self.defender;
+        let cards: Vec<Card> = self
+            .table
+            .drain(..)
+            .flat_map(|(a, d)| std::iter::once(a).chain(d))
+            .collect();
+        if self.taking {
+            self.hands[defender.index()].extend_from_slice(&cards);
+            self.events.push_back(Event::PickUp(defender, cards));
+        } else {
+            self.discards += cards.len();
+            self.events.push_back(Event::BeatOff(defender));
+        }
+        let order: Vec<Player> = self.attackers().chain([defender]).collect();
+        for player in order {
+            let hand = &mut self.hands[player.index()];
+            let n = HAND_SIZE.saturating_sub(hand.len()).min(self.stock.len());
+            if n > 0 {
+                let stock = &mut self.stock;
+                hand.extend((0..n).filter_map(|_| stock.draw()));
+                self.events.push_back(Event::Draw(player, n));
+            }
+        }
+        if self.stock.is_empty() {
+            for player in Player::all(players) {
+                if self.hand(player).is_empty() && !self.out.contains(&player) {
+                    self.out.push(player);
+                    self.events.push_back(Event::Out(player));
+                }
+            }
+        }
+        let holding = |p: &Player| !self.hands[p.index()].is_empty();
+        if Player::all(players).filter(holding).count() < 2 {
+            self.over = true;
+            let result = self.result().expect("game is over");
+            self.events.push_back(Event::Over(result));
+            return;
+        }
+        let mut next = defender;
+        if self.taking || !holding(&next) {
+            next = next.next(players);
+            while !holding(&next) {
+                next = next.next(players);
+            }
+        }
+        let mut defender = next.next(players);
+        while !holding(&defender) {
+            defender = defender.next(players);
+        }
+        self.attacker = next;
+        self.defender = defender;
+        self.taking = false;
+        self.passed.clear();
+    }
+
+    /// The result of the game, once it's over.
+    pub fn result(&self) -> Option<RoundResult> {
+        if !self.over {
+            return None;
+        }
+        let durak = Player::all(self.config.players).find(|p| !self.hand(*p).is_empty());
+        Some(RoundResult {
+            durak,
+            out: self.out.clone(),
+        })
+    }
+}
+
+impl crate::engine::Engine for Round {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = RoundView<'a>;
+    type Event = Event;
+    type Outcome = RoundResult;
+    type Error = DurakError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> RoundView<'_> {
+        Round::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), DurakError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<RoundResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a game: the order of the deck, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRound {
+    config: RoundConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Round> for RawRound {
+    fn from(round: &Round) -> Self {
+        Self {
+            config: round.config.clone(),
+            actions: round.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawRound> for Round {
+    type Error = DurakError;
+
+    fn try_from(raw: RawRound) -> Result<Self, DurakError> {
+        raw.config.validate()?;
+        let mut round = Round::from(raw.config);
+        for action in raw.actions {
+            round.apply_action(action)?;
+        }
+        round.events.clear();
+        Ok(round)
+    }
+}
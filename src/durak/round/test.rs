@@ -0,0 +1,216 @@
+use std::convert::TryFrom;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::durak::Robot;
+use crate::engine::Engine;
+use crate::french::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// Stacks the deck so that the players from the dealer's left are dealt the hands, followed by
+/// the stock in order, with the trump card at the bottom. The last player deals.
+fn stacked(hands: &[&str], stock: &str, trump: &str) -> RoundConfig {
+    let hands: Vec<Vec<Card>> = hands.iter().map(|h| cards(h)).collect();
+    let stock = cards(stock);
+    let trump = card(trump);
+    let mut deck = vec![trump];
+    deck.extend(
+        super::deck().into_iter().filter(|c| {
+            *c != trump && !stock.contains(c) && !hands.iter().flatten().any(|h| h == c)
+        }),
+    );
+    deck.extend(stock.into_iter().rev());
+    for hand in hands.iter().rev() {
+        deck.extend(hand.iter().rev());
+    }
+    let players = u8::try_from(hands.len()).unwrap();
+    RoundConfig::new(Player(players - 1), players, deck).unwrap()
+}
+
+#[test]
+fn test_invalid_config() {
+    let deck: Vec<Card> = super::deck().into_iter().collect();
+    for players in [1, 7] {
+        let result = RoundConfig::new(Player(0), players, deck.clone());
+        assert!(matches!(result, Err(DurakError::InvalidPlayers(_))));
+    }
+    let result = RoundConfig::new(Player(2), 2, deck.clone());
+    assert!(matches!(result, Err(DurakError::InvalidPlayers(2))));
+    let mut short = deck;
+    short.pop();
+    let result = RoundConfig::new(Player(0), 2, short);
+    assert!(matches!(result, Err(DurakError::InvalidDeck)));
+}
+
+#[test]
+fn test_deal() {
+    let hands = ["6♣ 7♣ 8♣ 9♣ T♣ J♣", "6♦ 7♡ 8♦ 9♦ T♦ J♦"];
+    let mut round = Round::from(stacked(&hands, "", "A♡"));
+    assert_eq!(round.trump(), Suit::Heart);
+    assert_eq!(round.hand(Player(0)), &cards(hands[0])[..]);
+    assert_eq!(round.hand(Player(1)), &cards(hands[1])[..]);
+    // The player holding the lowest trump attacks first.
+    assert_eq!(round.attacker(), Player(1));
+    assert_eq!(round.defender(), Player(0));
+    assert_eq!(round.next_actor(), Some(Player(1)));
+    let view = round.view(Player(0));
+    assert_eq!(view.stock, 24);
+    assert_eq!(view.trump_card, card("A♡"));
+    assert_eq!(view.hand_sizes, [6, 6]);
+    assert_eq!(round.pop_event(), Some(Event::Trump(card("A♡"))));
+    assert_eq!(round.pop_event(), None);
+}
+
+#[test]
+fn test_beat_off() {
+    let hands = [
+        "6♡ 6♣ 6♦ 7♣ 9♤ K♤",
+        "7♦ 8♣ 8♦ T♤ Q♤ 7♡",
+        "6♤ 9♣ 9♦ J♣ J♦ K♣",
+    ];
+    let mut round = Round::from(stacked(&hands, "", "A♡"));
+    assert_eq!(round.attacker(), Player(0));
+    assert_eq!(round.defender(), Player(1));
+    assert!(round
+        .legal_actions()
+        .contains(&Action::Attack(cards("6♣ 6♦ 6♡"))));
+    assert!(!round.legal_actions().contains(&Action::Pass));
+    round.apply_action(Action::Attack(cards("6♣ 6♦"))).unwrap();
+
+    assert_eq!(round.next_actor(), Some(Player(1)));
+    assert_eq!(
+        round.legal_actions(),
+        [
+            Action::Defend(card("6♣"), card("8♣")),
+            Action::Defend(card("6♣"), card("7♡")),
+            Action::Defend(card("6♦"), card("7♦")),
+            Action::Defend(card("6♦"), card("8♦")),
+            Action::Defend(card("6♦"), card("7♡")),
+            Action::Take,
+        ]
+    );
+    assert!(matches!(
+        round.apply_action(Action::Defend(card("6♣"), card("7♦"))),
+        Err(DurakError::CannotBeat(_, _))
+    ));
+    round
+        .apply_action(Action::Defend(card("6♣"), card("8♣")))
+        .unwrap();
+    round
+        .apply_action(Action::Defend(card("6♦"), card("7♦")))
+        .unwrap();
+
+    // Once every attack is beaten, the attacker may add cards of ranks on the table.
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    assert!(matches!(
+        round.apply_action(Action::Attack(cards("9♤"))),
+        Err(DurakError::CannotAdd(_))
+    ));
+    assert!(matches!(
+        round.apply_action(Action::Take),
+        Err(DurakError::OutOfTurn(Action::Take))
+    ));
+    round.apply_action(Action::Attack(cards("7♣"))).unwrap();
+    round
+        .apply_action(Action::Defend(card("7♣"), card("7♡")))
+        .unwrap();
+    round.apply_action(Action::Pass).unwrap();
+
+    // The others may add cards once the attacker passes.
+    assert_eq!(round.next_actor(), Some(Player(2)));
+    round.apply_action(Action::Attack(cards("6♤"))).unwrap();
+    round
+        .apply_action(Action::Defend(card("6♤"), card("T♤")))
+        .unwrap();
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    while round.pop_event().is_some() {}
+
+    // Player 3 has nothing to add, so the attack is beaten off once the attacker passes.
+    round.apply_action(Action::Pass).unwrap();
+    let events: Vec<Event> = std::iter::from_fn(|| round.pop_event()).collect();
+    assert_eq!(
+        events,
+        [
+            Event::Pass(Player(0)),
+            Event::BeatOff(Player(1)),
+            Event::Draw(Player(0), 3),
+            Event::Draw(Player(2), 1),
+            Event::Draw(Player(1), 4),
+        ]
+    );
+    let view = round.view(Player(0));
+    assert_eq!((view.stock, view.discards), (10, 8));
+    assert!(view.table.is_empty());
+    assert_eq!(round.attacker(), Player(1));
+    assert_eq!(round.defender(), Player(2));
+}
+
+#[test]
+fn test_take() {
+    let hands = ["6♡ 6♣ 6♦ 6♤ 7♣ 7♦", "7♤ 8♣ 9♣ T♣ J♣ Q♣"];
+    let mut round = Round::from(stacked(&hands, "", "A♡"));
+    assert_eq!(round.attacker(), Player(0));
+    round
+        .apply_action(Action::Attack(cards("6♣ 6♦ 6♤")))
+        .unwrap();
+    round
+        .apply_action(Action::Defend(card("6♤"), card("7♤")))
+        .unwrap();
+    round.apply_action(Action::Take).unwrap();
+
+    // The attacker may still add cards of ranks on the table, up to six in all.
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    assert_eq!(
+        round.legal_actions(),
+        [
+            Action::Attack(cards("6♡")),
+            Action::Attack(cards("7♣")),
+            Action::Attack(cards("7♦")),
+            Action::Attack(cards("7♣ 7♦")),
+            Action::Pass,
+        ]
+    );
+    assert!(matches!(
+        round.apply_action(Action::Attack(cards("6♡ 7♣"))),
+        Err(DurakError::InvalidAttack)
+    ));
+    round.apply_action(Action::Attack(cards("7♣ 7♦"))).unwrap();
+    assert_eq!(
+        round.legal_actions(),
+        [Action::Attack(cards("6♡")), Action::Pass]
+    );
+    while round.pop_event().is_some() {}
+    round.apply_action(Action::Pass).unwrap();
+    let events: Vec<Event> = std::iter::from_fn(|| round.pop_event()).collect();
+    assert_eq!(
+        events,
+        [
+            Event::Pass(Player(0)),
+            Event::PickUp(Player(1), cards("6♣ 6♦ 6♤ 7♤ 7♣ 7♦")),
+            Event::Draw(Player(0), 5),
+        ]
+    );
+    assert_eq!(round.hand(Player(1)).len(), 11);
+    // The defender who took is skipped.
+    assert_eq!(round.attacker(), Player(0));
+    assert_eq!(round.defender(), Player(1));
+}
+
+#[test]
+fn test_raw_round() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut round = Round::from(RoundConfig::random(Player(2), 4, &mut rng).unwrap());
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("game is over");
+    let raw = serde_json::to_string(&RawRound::from(&round)).unwrap();
+    let replayed = Round::try_from(serde_json::from_str::<RawRound>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), round.actions());
+}
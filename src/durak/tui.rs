@@ -0,0 +1,270 @@
+//! Rich terminal UI.
+
+use std::io;
+use std::time::Duration;
+
+use itertools::Itertools;
+use ratatui::crossterm::event::{self, KeyCode, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+
+use crate::engine::tui::{line_with_span, Term};
+use crate::engine::Engine;
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+use super::{Action, Card, Event, Player, Robot, Round, RoundConfig, RoundView};
+
+/// How long to pause after each of the robots' actions.
+const ROBOT_DELAY: Duration = Duration::from_millis(700);
+
+/// The number of events kept in the log.
+const LOG_SIZE: usize = 100;
+
+/// A game of durak against robots, in the terminal.
+pub struct Tui {
+    players: u8,
+    /// The user's player.
+    player: Player,
+    robot: Robot,
+    round: Round,
+    /// The legal actions, when it's the user's turn.
+    actions: Vec<Action>,
+    selected: ListState,
+    /// Messages describing what has happened, oldest first.
+    log: Vec<String>,
+    /// The number of games each player has lost.
+    losses: Vec<u32>,
+    exit: bool,
+}
+
+impl Tui {
+    /// Creates a game for the specified number of players, with the user as the first.
+    pub fn new(players: u8) -> Self {
+        let dealer = Player(rand::random::<u8>() % players);
+        Self {
+            players,
+            player: Player(0),
+            robot: Robot,
+            round: Self::deal(dealer, players),
+            actions: vec![],
+            selected: ListState::default(),
+            log: vec![],
+            losses: vec![0; usize::from(players)],
+            exit: false,
+        }
+    }
+
+    fn deal(dealer: Player, players: u8) -> Round {
+        let config = RoundConfig::random(dealer, players, &mut rand::thread_rng())
+            .expect("valid number of players");
+        Round::from(config)
+    }
+
+    /// Runs the terminal UI until the user exits.
+    pub fn run(mut self, terminal: &mut Term) -> anyhow::Result<()> {
+        self.update();
+        while !self.exit {
+            terminal.draw(|frame| self.render_frame(frame))?;
+            self.handle_events()?;
+        }
+        Ok(())
+    }
+
+    /// Logs new events, and lists the user's options if it's their turn.
+    fn update(&mut self) {
+        while let Some(event) = self.round.pop_event() {
+            if let Event::Over(result) = &event {
+                if let Some(durak) = result.durak {
+                    self.losses[durak.index()] += 1;
+                }
+            }
+            self.log.push(event.to_string());
+        }
+        if self.log.len() > LOG_SIZE {
+            self.log.drain(..self.log.len() - LOG_SIZE);
+        }
+        self.actions = if self.round.next_actor() == Some(self.player) {
+            self.round.legal_actions()
+        } else {
+            vec![]
+        };
+        if self.actions.is_empty() {
+            self.selected.select(None);
+        } else {
+            let index = self.selected.selected().unwrap_or(0);
+            self.selected
+                .select(Some(index.min(self.actions.len() - 1)));
+        }
+    }
+
+    /// Applies the action, and logs any error.
+    fn apply(&mut self, action: Action) {
+        if let Err(err) = self.round.apply_action(action) {
+            self.log.push(tr!("console-error", error = err.to_string()));
+        }
+        self.update();
+    }
+
+    /// Top-level event handler. Robots act after a delay, unless a key is pressed.
+    fn handle_events(&mut self) -> io::Result<()> {
+        if let Some(player) = self.round.next_actor() {
+            if player != self.player && !event::poll(ROBOT_DELAY)? {
+                let legal = self.round.legal_actions();
+                let action = self.robot.choose(&self.round.view(player), &legal);
+                self.apply(action);
+                return Ok(());
+            }
+        }
+        let event::Event::Key(key) = event::read()? else {
+            return Ok(());
+        };
+        if key.kind != KeyEventKind::Press {
+            return Ok(());
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let action = self
+                    .selected
+                    .selected()
+                    .and_then(|i| self.actions.get(i).cloned());
+                if let Some(action) = action {
+                    self.apply(action);
+                }
+            }
+            KeyCode::Char('n') => {
+                if let Some(result) = self.round.result() {
+                    // The durak deals the next game.
+                    let dealer = result.durak.unwrap_or(self.round.config().dealer());
+                    self.round = Self::deal(dealer, self.players);
+                    self.log.push(String::new());
+                    self.update();
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn render_frame(&mut self, frame: &mut Frame) {
+        let [game, log] =
+            Layout::horizontal([Constraint::Length(48), Constraint::Min(20)]).areas(frame.area());
+        let [status, table, hand, actions, hint] = Layout::vertical([
+            Constraint::Length(u16::from(self.players) + 3),
+            Constraint::Length(8),
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(game);
+        let view = self.round.view(self.player);
+        frame.render_widget(self.status(&view), status);
+        frame.render_widget(table_widget(&view), table);
+        frame.render_widget(hand_line(&view), hand);
+        let list = List::new(self.actions.iter().map(ToString::to_string))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().reversed());
+        frame.render_stateful_widget(list, actions, &mut self.selected);
+        let help = if self.round.result().is_some() {
+            tr!("durak-hint-over")
+        } else {
+            tr!("engine-hint-play")
+        };
+        frame.render_widget(Line::from(help).dim(), hint);
+        frame.render_widget(self.log_widget(log.height), log);
+    }
+
+    /// The trump card, the stock, and the players' hands and losses.
+    fn status(&self, view: &RoundView) -> Paragraph<'static> {
+        let text = tr!(
+            "durak-tui-status",
+            card = PLACEHOLDER,
+            stock = view.stock,
+            discards = view.discards
+        );
+        let mut lines = vec![line_with_span(&text, view.trump_card.to_span())];
+        for player in Player::all(self.players) {
+            let role = if view.out.contains(&player) {
+                Some(tr!("durak-role-out"))
+            } else if view.to_play.is_none() {
+                None
+            } else if player == view.attacker {
+                Some(tr!("durak-role-attacking"))
+            } else if player == view.defender {
+                Some(tr!("durak-role-defending"))
+            } else {
+                None
+            };
+            let mut name = player.to_string();
+            if player == self.player {
+                name = tr!("game-player-you", player = name);
+            }
+            let mut text = tr!(
+                "durak-player-status",
+                player = name,
+                cards = view.hand_sizes[player.index()],
+                losses = self.losses[player.index()]
+            );
+            if let Some(role) = role {
+                text = format!("{text}, {role}");
+            }
+            let mut line = Line::from(text);
+            if view.to_play == Some(player) {
+                line = line.bold();
+            }
+            lines.push(line);
+        }
+        Paragraph::new(lines).block(Block::bordered().title(format!(" {} ", tr!("durak-title"))))
+    }
+
+    /// The most recent messages that fit in the specified height.
+    fn log_widget(&self, height: u16) -> Paragraph<'_> {
+        let rows = usize::from(height.saturating_sub(2));
+        let start = self.log.len().saturating_sub(rows);
+        let lines: Vec<Line> = self.log[start..]
+            .iter()
+            .map(|s| Line::from(s.as_str()))
+            .collect();
+        Paragraph::new(lines).block(Block::bordered().title(format!(" {} ", tr!("engine-log"))))
+    }
+}
+
+/// The attacking cards on the table, each above the card that beat it.
+fn table_widget(view: &RoundView) -> Paragraph<'static> {
+    let mut attacks = vec![];
+    let mut defenses = vec![];
+    for &(attack, defense) in view.table {
+        attacks.push(attack.to_span());
+        attacks.push("  ".into());
+        defenses.push(defense.map_or_else(|| "  ".into(), Card::to_span));
+        defenses.push("  ".into());
+    }
+    let title = if view.taking {
+        tr!("durak-table-taking", player = view.defender.to_string())
+    } else {
+        tr!("durak-table-title")
+    };
+    Paragraph::new(vec![Line::from(attacks), Line::from(defenses)])
+        .block(Block::bordered().title(format!(" {title} ")))
+}
+
+/// The user's hand, weakest cards first.
+fn hand_line(view: &RoundView) -> Line<'static> {
+    let trump = view.trump();
+    let text = tr!("game-hand", cards = PLACEHOLDER);
+    let (before, after) = text.split_once(PLACEHOLDER).unwrap_or((&text, ""));
+    let mut spans = vec![Span::from(before.to_string())];
+    for card in view
+        .hand
+        .iter()
+        .sorted_by_key(|&&c| (super::strength(trump, c), c.suit()))
+    {
+        spans.push(card.to_span());
+        spans.push(" ".into());
+    }
+    spans.push(Span::from(after.to_string()));
+    Line::from(spans)
+}
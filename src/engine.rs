@@ -0,0 +1,107 @@
+//! Turn-based game engines.
+
+pub mod console;
+#[cfg(feature = "tui")]
+pub mod tui;
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::tr;
+
+/// A turn-based game engine.
+///
+/// An engine is a deterministic state machine. At any point, it expects a particular actor to
+/// take one of a set of legal actions, or else the game is over and it has an outcome. Applying
+/// an action advances the state, and may enqueue events for frontends to consume.
+///
+/// Games that implement this trait can be driven by generic infrastructure, such as simulations
+/// and player harnesses, without game-specific glue.
+pub trait Engine {
+    /// Identifies a participant, such as a seat at the table.
+    type Actor: Copy + Eq;
+
+    /// An action taken by a participant.
+    type Action: Clone;
+
+    /// The state of the game, as visible to a particular participant.
+    type View<'a>
+    where
+        Self: 'a;
+
+    /// An event emitted when the state of the game advances.
+    type Event;
+
+    /// The outcome of a completed game.
+    type Outcome;
+
+    /// An error returned when an action cannot be applied.
+    type Error: std::error::Error;
+
+    /// Returns the participant expected to act next, or `None` if the game is over.
+    fn expected_actor(&self) -> Option<Self::Actor>;
+
+    /// Returns the state visible to the specified participant.
+    fn view(&self, actor: Self::Actor) -> Self::View<'_>;
+
+    /// Returns the set of actions that the expected actor may legally take.
+    fn legal_actions(&self) -> Vec<Self::Action>;
+
+    /// Applies the specified action.
+    fn apply(&mut self, action: Self::Action) -> Result<(), Self::Error>;
+
+    /// Pops the oldest event from the queue of events.
+    fn pop_event(&mut self) -> Option<Self::Event>;
+
+    /// Returns the outcome of the game, if it is over.
+    fn outcome(&self) -> Option<Self::Outcome>;
+
+    /// Returns true if the game is over.
+    fn is_over(&self) -> bool {
+        self.expected_actor().is_none()
+    }
+
+    /// Plays the game to completion, using `choose` to select each action from the set of legal
+    /// actions. Events are discarded.
+    fn play_out<F>(&mut self, mut choose: F) -> Result<Option<Self::Outcome>, Self::Error>
+    where
+        F: FnMut(&Self, Self::Actor, Vec<Self::Action>) -> Self::Action,
+    {
+        while let Some(actor) = self.expected_actor() {
+            let actions = self.legal_actions();
+            let action = choose(self, actor, actions);
+            self.apply(action)?;
+            while self.pop_event().is_some() {}
+        }
+        Ok(self.outcome())
+    }
+}
+
+/// A participant identified by their position at the table, counting from zero, for games played
+/// by a varying number of players. Play passes to the next position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Player(pub u8);
+
+impl Player {
+    /// The player's position, for indexing per-player state.
+    pub fn index(self) -> usize {
+        usize::from(self.0)
+    }
+
+    /// The player to this one's left, at a table of the specified size.
+    pub fn next(self, players: u8) -> Player {
+        Player((self.0 + 1) % players)
+    }
+
+    /// All players at a table of the specified size.
+    pub fn all(players: u8) -> impl Iterator<Item = Player> {
+        (0..players).map(Player)
+    }
+}
+
+impl Display for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&tr!("engine-player", n = self.0 + 1))
+    }
+}
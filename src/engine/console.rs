@@ -0,0 +1,141 @@
+//! A generic command-line harness for engines.
+//!
+//! The harness plays a game between a human at the console and robots. Events are printed as they
+//! happen, and the human is shown their view of the game whenever it's their turn. Actions are
+//! entered as text, and matched against the displayed forms of the legal actions, so that games
+//! need not implement a parser of their own.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+
+use anyhow::bail;
+
+use super::Engine;
+use crate::french::Suit;
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Normalizes text for matching: case and whitespace are ignored, and suit symbols are replaced
+/// by their initials, so that `2♤` may be entered as `2s`.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match Suit::try_from(c) {
+            Ok(suit) if !c.is_ascii() => match suit {
+                Suit::Club => 'c',
+                Suit::Diamond => 'd',
+                Suit::Heart => 'h',
+                Suit::Spade => 's',
+            },
+            _ => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Finds the legal action that matches the input: either exactly, or as the only action that
+/// starts with it.
+fn parse_action<A: Display + Clone>(input: &str, legal: &[A]) -> Option<A> {
+    let input = normalize(input);
+    let texts: Vec<String> = legal.iter().map(|a| normalize(&a.to_string())).collect();
+    if let Some(index) = texts.iter().position(|t| *t == input) {
+        return Some(legal[index].clone());
+    }
+    let mut matches = texts
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.starts_with(&input));
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(legal[index].clone()),
+        _ => None,
+    }
+}
+
+/// A console, over which a human plays against robots.
+pub struct Console<R, W> {
+    input: R,
+    output: W,
+}
+
+impl Console<std::io::StdinLock<'static>, std::io::Stdout> {
+    /// Creates a console on standard input and output.
+    pub fn stdio() -> Self {
+        Self::new(std::io::stdin().lock(), std::io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> Console<R, W> {
+    /// Creates a console on the specified input and output.
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    /// Writes a line of text to the console.
+    pub fn println<D: Display>(&mut self, text: D) -> anyhow::Result<()> {
+        writeln!(self.output, "{text}")?;
+        Ok(())
+    }
+
+    /// Plays the game to completion, with the human acting for `human` and `robot` choosing
+    /// every other actor's action from the set of legal actions. Enter `?` to list the legal
+    /// actions. Returns an error if the input is closed before the game is over.
+    pub fn play<E, F>(
+        &mut self,
+        engine: &mut E,
+        human: E::Actor,
+        mut robot: F,
+    ) -> anyhow::Result<Option<E::Outcome>>
+    where
+        E: Engine,
+        E::Actor: Display,
+        E::Action: Display,
+        E::Event: Display,
+        for<'a> E::View<'a>: Display,
+        E::Error: Send + Sync + 'static,
+        F: FnMut(&E, E::Actor, Vec<E::Action>) -> E::Action,
+    {
+        loop {
+            while let Some(event) = engine.pop_event() {
+                self.println(event)?;
+            }
+            let Some(actor) = engine.expected_actor() else {
+                return Ok(engine.outcome());
+            };
+            let legal = engine.legal_actions();
+            let action = if actor == human {
+                self.println(engine.view(actor))?;
+                self.prompt(&legal)?
+            } else {
+                robot(engine, actor, legal)
+            };
+            engine.apply(action)?;
+        }
+    }
+
+    /// Prompts the human for one of the legal actions.
+    fn prompt<A: Display + Clone>(&mut self, legal: &[A]) -> anyhow::Result<A> {
+        loop {
+            write!(self.output, "> ")?;
+            self.output.flush()?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                bail!(tr!("engine-input-closed"));
+            }
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line == "?" {
+                let texts: Vec<String> = legal.iter().map(ToString::to_string).collect();
+                self.println(texts.join(" "))?;
+                continue;
+            }
+            match parse_action(line, legal) {
+                Some(action) => return Ok(action),
+                None => self.println(tr!("engine-cant-do", input = line))?,
+            }
+        }
+    }
+}
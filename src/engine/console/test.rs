@@ -0,0 +1,109 @@
+use std::fmt;
+
+use super::*;
+
+/// A game of Nim: players take turns removing one or two stones, and whoever takes the last one
+/// wins.
+struct Nim {
+    stones: u8,
+    turn: u8,
+    events: Vec<String>,
+}
+
+struct NimView(u8);
+
+impl Display for NimView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} stones left", self.0)
+    }
+}
+
+#[derive(Debug)]
+struct NimError;
+
+impl Display for NimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("illegal move")
+    }
+}
+
+impl std::error::Error for NimError {}
+
+impl Engine for Nim {
+    type Actor = u8;
+    type Action = u8;
+    type View<'a> = NimView;
+    type Event = String;
+    type Outcome = u8;
+    type Error = NimError;
+
+    fn expected_actor(&self) -> Option<u8> {
+        (self.stones > 0).then_some(self.turn)
+    }
+
+    fn view(&self, _: u8) -> NimView {
+        NimView(self.stones)
+    }
+
+    fn legal_actions(&self) -> Vec<u8> {
+        (1..=self.stones.min(2)).collect()
+    }
+
+    fn apply(&mut self, action: u8) -> Result<(), NimError> {
+        if !self.legal_actions().contains(&action) {
+            return Err(NimError);
+        }
+        self.stones -= action;
+        self.events.push(format!("{} took {action}", self.turn));
+        self.turn = 1 - self.turn;
+        Ok(())
+    }
+
+    fn pop_event(&mut self) -> Option<String> {
+        (!self.events.is_empty()).then(|| self.events.remove(0))
+    }
+
+    fn outcome(&self) -> Option<u8> {
+        (self.stones == 0).then_some(1 - self.turn)
+    }
+}
+
+#[test]
+fn test_parse_action() {
+    let legal = ["Pass", "2♤", "2NT", "X"];
+    assert_eq!(parse_action("2s", &legal), Some("2♤"));
+    assert_eq!(parse_action("2 nt", &legal), Some("2NT"));
+    assert_eq!(parse_action("p", &legal), Some("Pass"));
+    assert_eq!(parse_action("x", &legal), Some("X"));
+    assert_eq!(parse_action("2", &legal), None);
+    assert_eq!(parse_action("3s", &legal), None);
+}
+
+#[test]
+fn test_play() {
+    let mut nim = Nim {
+        stones: 4,
+        turn: 0,
+        events: vec![],
+    };
+    let input = "3\n?\n1\n2\n".as_bytes();
+    let mut output = vec![];
+    let outcome = Console::new(input, &mut output)
+        .play(&mut nim, 0, |_, _, legal| legal[0])
+        .unwrap();
+    assert_eq!(outcome, Some(0));
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(
+        output,
+        "4 stones left\n> Can't do \"3\" now; enter ? for options\n> 1 2\n> 0 took 1\n\
+         1 took 1\n2 stones left\n> 0 took 2\n"
+    );
+
+    let mut nim = Nim {
+        stones: 4,
+        turn: 0,
+        events: vec![],
+    };
+    let mut console = Console::new("".as_bytes(), vec![]);
+    assert!(console.play(&mut nim, 0, |_, _, legal| legal[0]).is_err());
+}
@@ -0,0 +1,211 @@
+//! A generic terminal UI for engines.
+//!
+//! Like the [console](super::console) harness, this plays a game between a human and robots,
+//! without game-specific glue: the human's view of the game is shown as text, beside a log of
+//! events, and the human picks their actions from a list of the legal actions.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::io::{self, stdout, Stdout};
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, KeyCode, KeyEventKind};
+use ratatui::crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::crossterm::ExecutableCommand;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, List, ListState, Paragraph, Wrap};
+
+use super::Engine;
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+/// How long to pause after each of the robots' actions.
+const ROBOT_DELAY: Duration = Duration::from_millis(700);
+
+/// The number of messages kept in the log.
+const LOG_SIZE: usize = 200;
+
+pub(crate) type Term = Terminal<CrosstermBackend<Stdout>>;
+
+/// Initializes the terminal for a TUI.
+pub(crate) fn init() -> io::Result<Term> {
+    stdout().execute(EnterAlternateScreen)?;
+    enable_raw_mode()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    terminal.clear()?;
+    Ok(terminal)
+}
+
+/// Lays out a localized message, with the span in place of its [`PLACEHOLDER`] argument.
+pub(crate) fn line_with_span(text: &str, span: Span<'static>) -> Line<'static> {
+    match text.split_once(PLACEHOLDER) {
+        Some((before, after)) => Line::from(vec![
+            before.to_string().into(),
+            span,
+            after.to_string().into(),
+        ]),
+        None => Line::from(text.to_string()),
+    }
+}
+
+/// Restores the original terminal mode.
+pub(crate) fn restore() -> io::Result<()> {
+    stdout().execute(LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    Ok(())
+}
+
+/// A terminal UI, over which a human plays against robots. The terminal is restored when it's
+/// dropped.
+pub struct Tui {
+    terminal: Term,
+    title: String,
+    /// The human's view of the game, as last shown.
+    view: String,
+    /// Messages describing what has happened, oldest first.
+    log: Vec<String>,
+}
+
+impl Tui {
+    /// Takes over the terminal, for a game with the specified title.
+    pub fn new(title: &str) -> io::Result<Self> {
+        Ok(Self {
+            terminal: init()?,
+            title: format!(" {title} "),
+            view: String::new(),
+            log: vec![],
+        })
+    }
+
+    /// Adds a message to the log.
+    pub fn println<D: Display>(&mut self, text: D) {
+        self.log.extend(text.to_string().lines().map(String::from));
+        if self.log.len() > LOG_SIZE {
+            self.log.drain(..self.log.len() - LOG_SIZE);
+        }
+    }
+
+    /// Plays the game to completion, with the human acting for `human` and `robot` choosing
+    /// every other actor's action from the set of legal actions. Returns `None` if the human
+    /// quits before the game is over.
+    pub fn play<E, F>(
+        &mut self,
+        engine: &mut E,
+        human: E::Actor,
+        mut robot: F,
+    ) -> anyhow::Result<Option<E::Outcome>>
+    where
+        E: Engine,
+        E::Action: Display,
+        E::Event: Display,
+        for<'a> E::View<'a>: Display,
+        E::Error: Send + Sync + 'static,
+        F: FnMut(&E, E::Actor, Vec<E::Action>) -> E::Action,
+    {
+        let mut selected = ListState::default();
+        loop {
+            while let Some(event) = engine.pop_event() {
+                self.println(event);
+            }
+            let Some(actor) = engine.expected_actor() else {
+                return Ok(engine.outcome());
+            };
+            let legal = engine.legal_actions();
+            self.view = engine.view(human).to_string();
+            if actor != human {
+                self.draw(&[], &mut selected, &tr!("engine-hint-wait"))?;
+                if !event::poll(ROBOT_DELAY)? {
+                    let action = robot(engine, actor, legal);
+                    engine.apply(action)?;
+                } else if read_key()? == Some(KeyCode::Char('q')) {
+                    return Ok(None);
+                }
+                continue;
+            }
+            let options: Vec<String> = legal.iter().map(ToString::to_string).collect();
+            if selected.selected().is_none_or(|i| i >= options.len()) {
+                selected.select(Some(0));
+            }
+            self.draw(&options, &mut selected, &tr!("engine-hint-play"))?;
+            match read_key()? {
+                Some(KeyCode::Char('q')) => return Ok(None),
+                Some(KeyCode::Up | KeyCode::Char('k')) => selected.select_previous(),
+                Some(KeyCode::Down | KeyCode::Char('j')) => selected.select_next(),
+                Some(KeyCode::Enter | KeyCode::Char(' ')) => {
+                    let index = selected.selected().unwrap_or(0).min(legal.len() - 1);
+                    engine.apply(legal[index].clone())?;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Shows the message, and waits for a key. Returns false if the key was `q`.
+    pub fn pause(&mut self, message: &str) -> anyhow::Result<bool> {
+        let hint = tr!("engine-hint-pause", message = message);
+        self.draw(&[], &mut ListState::default(), &hint)?;
+        loop {
+            match read_key()? {
+                Some(KeyCode::Char('q')) => return Ok(false),
+                Some(_) => return Ok(true),
+                None => (),
+            }
+        }
+    }
+
+    /// Draws the view, the list of options, the log, and a hint at the bottom.
+    fn draw(&mut self, options: &[String], selected: &mut ListState, hint: &str) -> io::Result<()> {
+        let title = self.title.as_str();
+        let view = self.view.as_str();
+        let log = &self.log;
+        self.terminal.draw(|frame| {
+            let [main, hint_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+            let [game, log_area] =
+                Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(main);
+            let view_height = u16::try_from(view.lines().count()).unwrap_or(u16::MAX);
+            let [view_area, options_area] = Layout::vertical([
+                Constraint::Length(view_height.saturating_add(2)),
+                Constraint::Min(0),
+            ])
+            .areas(game);
+            let view = Paragraph::new(view)
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title(title));
+            frame.render_widget(view, view_area);
+            let list = List::new(options.iter().map(String::as_str))
+                .highlight_symbol("> ")
+                .highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(list, options_area, selected);
+            let rows = usize::from(log_area.height.saturating_sub(2));
+            let lines: Vec<Line> = log[log.len().saturating_sub(rows)..]
+                .iter()
+                .map(|s| Line::from(s.as_str()))
+                .collect();
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .block(Block::bordered().title(format!(" {} ", tr!("engine-log")))),
+                log_area,
+            );
+            frame.render_widget(Line::from(hint).dim(), hint_area);
+        })?;
+        Ok(())
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        let _ = restore();
+    }
+}
+
+/// Reads a key press, ignoring other terminal events.
+fn read_key() -> io::Result<Option<KeyCode>> {
+    match event::read()? {
+        event::Event::Key(key) if key.kind == KeyEventKind::Press => Ok(Some(key.code)),
+        _ => Ok(None),
+    }
+}
@@ -1,86 +1,761 @@
 //! The game of euchre.
 
+mod achievements;
 mod action;
+mod analysis;
+mod archive;
+mod bench;
 mod card;
+mod clock;
+mod drill;
+mod duplicate;
 mod error;
+#[cfg(feature = "cdylib")]
+pub mod ffi;
 mod game;
+#[cfg(feature = "gui")]
+mod gui;
+mod html;
+mod metadata;
+#[cfg(feature = "net")]
+pub mod net;
+mod paths;
 mod player;
+mod recent;
 mod round;
+mod scoresheet;
 mod seat;
+mod settings;
+mod stats;
+mod train;
 mod trick;
+#[cfg(feature = "tui")]
 mod tui;
-use std::path::Path;
-
-use self::action::{Action, ActionData, ActionType, ExpectAction};
-use self::card::{Card, Deck, Rank, Suit};
-use self::error::{PlayerError, RoundError};
-use self::game::Game;
-use self::player::{Console, Player, Robot};
-use self::round::{
-    BaseRound, Contract, Log, LogId, LoggingRound, PlayerState, RawLog, Round, RoundConfig,
-    RoundOutcome, Tricks,
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Context;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use serde::{Deserialize, Serialize};
+
+pub use self::achievements::{Achievement, Achievements};
+pub use self::action::{Action, ActionData, ActionType, ExpectAction};
+pub use self::analysis::{
+    action_values, add_robot_lines, expected_points, load_corpus, playout, trick_odds,
+    win_probability, AccuracyRow, BidDecision, BiddingAnalyzer, BiddingReport, Decision,
+    DecisionReport, Diary, DiaryEntry, DiaryRound, Difficulty, Inference, Lead, LeadCategory,
+    LeadReport, LeadRole, LogStats, Puzzle, Tally, BLUNDER_POINTS,
+};
+pub use self::archive::{Archive, ArchivedGame};
+pub use self::bench::{Bench, BenchReport, Measurement};
+pub use self::card::{Card, CardSet, Deck, Rank, Suit};
+pub use self::clock::{Clock, TimeControl};
+pub use self::drill::{Drill, DrillKind, DrillRecord, DrillSession, DrillTally, Grade};
+pub use self::duplicate::{BoardResult, Duplicate, DuplicateResult, Lineup};
+pub use self::error::{PlayerError, RoundError};
+pub use self::game::{
+    Cut, DealerRotation, Game, GameOutcome, GameView, RawGame, RoundRecord, Rubber, Rules,
+};
+pub use self::html::HtmlReport;
+pub use self::metadata::Metadata;
+#[cfg(feature = "net")]
+use self::net::{ChatPolicy, Games, Server};
+pub use self::paths::Paths;
+pub use self::player::{
+    solve, Console, Player, Preset, Robot, RobotKind, RobotParams, SearchRobot,
 };
-use self::seat::{Seat, Team};
-use self::trick::Trick;
-use self::tui::{tui_init, tui_restore, Tui};
+pub use self::recent::RecentFiles;
+pub use self::round::{
+    BaseRound, Contract, DealPattern, FastRound, Log, LogId, LoggingRound, PlayerState, RawLog,
+    RenegePolicy, Revoke, Round, RoundConfig, RoundOutcome, RoundView, Timing, Trace, Tricks,
+};
+pub use self::scoresheet::{ScoreSheet, SheetFormat};
+pub use self::seat::{Seat, SeatMap, Team, TeamMap};
+pub use self::settings::{CardBack, Settings, Theme};
+pub use self::stats::{CorpusStats, SeatStats, Stats};
+pub use self::train::{Trainer, TrainingStep};
+pub use self::trick::Trick;
+#[cfg(feature = "tui")]
+use self::tui::{format_log, tui_init, tui_restore, RemoteTui, Tui};
+use crate::tr;
 
 /// An event that occurs during the game.
-#[derive(Debug, Clone)]
-enum Event {
+///
+/// Events are cheap to clone for each observer: every variant but the cut is [`Copy`], and the
+/// cut, which happens once a game, is shared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    /// The players cut for the first deal.
+    Cut(Arc<Cut>),
+    /// The dealer dealt a packet of cards to a player.
+    Packet(Seat, usize),
     /// The dealer dealt and revealed the top card.
     Deal(Seat, Card),
+    /// The round was thrown in without being scored, and the specified dealer deals a fresh one.
+    Redeal(Seat),
     /// A player declared a contract.
     Call(Contract),
     /// The trick is over.
     Trick(Trick),
+    /// A renege was called, and the round is forfeited by the offending team.
+    Renege(Revoke),
     /// The round is over.
     Round(RoundOutcome),
     /// The game is over.
-    Game(Team),
+    Game(GameOutcome),
 }
 
-/// Runs the game with a simple command-line interface.
-pub fn cli_main() {
+/// Where the command-line interface saves the round log, if the round ends in a fatal error.
+const CLI_PARTIAL_LOG: &str = "euchre-partial.json";
+
+/// Runs the game with a simple command-line interface. The round log is written to stderr when
+/// the round is over. If the round can't be finished, the partial log is saved to a file instead,
+/// so that it can be loaded and resumed.
+pub fn cli_main() -> anyhow::Result<()> {
     let console = Console::default().into_player();
     let robot = Robot::default().into_player();
 
+    let my_seat = Seat::South;
     let mut round = LoggingRound::random();
-    for my_seat in [Seat::South] {
-        round.restart();
-        println!("You are {my_seat}");
-        loop {
-            while let Some(event) = round.pop_event() {
-                console.notify(round.player_state(my_seat), &event);
+    println!("{}", tr!("game-you-are", player = my_seat.to_string()));
+    if let Err(err) = play_cli_round(&mut round, my_seat, &console, &robot) {
+        let path = Path::new(CLI_PARTIAL_LOG);
+        let file = File::create(path)
+            .with_context(|| format!("failed to save the partial log to {}", path.display()))?;
+        serde_json::to_writer(file, &RawLog::from(&round))?;
+        return Err(anyhow::Error::from(err).context(format!(
+            "round abandoned; partial log saved to {}",
+            path.display()
+        )));
+    }
+    serde_json::to_writer(std::io::stderr(), &RawLog::from(round))?;
+    Ok(())
+}
+
+/// Plays a round to completion, with the user at the specified seat and robots everywhere else.
+/// Invalid actions taken by the user are reported, and they're asked again. Any other error is
+/// fatal.
+fn play_cli_round(
+    round: &mut LoggingRound,
+    my_seat: Seat,
+    console: &Arc<dyn Player>,
+    robot: &Arc<dyn Player>,
+) -> Result<(), RoundError> {
+    loop {
+        while let Some(event) = round.pop_event() {
+            console.notify(round.player_state(my_seat), &event);
+        }
+        let Some(expect) = round.next_action() else {
+            return Ok(());
+        };
+        let player = if expect.seat == my_seat {
+            console
+        } else {
+            robot
+        };
+        let data = player.take_action(round.player_state(expect.seat), expect.action);
+        match round.apply_action(expect.with_data(data)) {
+            Ok(()) => (),
+            Err(RoundError::Player(err)) if player.handle_error(err.clone()) => (),
+            Err(err @ (RoundError::InvalidActionData | RoundError::ExpectActioned { .. }))
+                if expect.seat == my_seat =>
+            {
+                println!("{}", tr!("console-error", error = err.to_string()));
             }
-            let Some(expect) = round.next_action() else {
-                break;
-            };
-            let player = if expect.seat == my_seat {
-                &console
-            } else {
-                &robot
-            };
-            let player_state = round.player_state(expect.seat);
-            let data = player.take_action(player_state, expect.action);
-            let action = expect.with_data(data);
-            match round.apply_action(action) {
-                Err(RoundError::Player(err)) if player.handle_error(err.clone()) => continue,
-                Err(err) => panic!("Fatal: {}", err),
-                _ => (),
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Prints the accumulated player statistics, and the achievements earned so far.
+pub fn stats_main(path: Option<&Path>) -> anyhow::Result<()> {
+    let paths = Paths::new();
+    let path = path.map_or_else(|| paths.stats(), Path::to_path_buf);
+    let stats = Stats::load(&path).with_context(|| format!("failed to load {}", path.display()))?;
+    print!("{stats}");
+    let path = paths.achievements();
+    let achievements =
+        Achievements::load(&path).with_context(|| format!("failed to load {}", path.display()))?;
+    print!("\nAchievements:\n{achievements}");
+    Ok(())
+}
+
+/// Gathers statistics from the logs in a corpus, and prints a summary, or the per-seat statistics
+/// as CSV.
+pub fn corpus_stats_main(paths: &[PathBuf], csv: bool) -> anyhow::Result<()> {
+    let logs = load_corpus(paths)?;
+    let stats = CorpusStats::analyze(&logs)?;
+    if csv {
+        print!("{}", stats.to_csv());
+    } else {
+        print!("{stats}");
+    }
+    Ok(())
+}
+
+/// Evaluates the bids in a corpus of logs, and prints a summary of their accuracy, or writes it
+/// to an HTML file.
+pub fn bidding_main(
+    paths: &[PathBuf],
+    rollouts: usize,
+    csv: bool,
+    html: Option<&Path>,
+) -> anyhow::Result<()> {
+    let logs = load_corpus(paths).with_context(|| {
+        format!(
+            "failed to load {}",
+            paths.iter().map(|p| p.display()).join(", ")
+        )
+    })?;
+    let report = BiddingAnalyzer::default()
+        .with_rollouts(rollouts)
+        .analyze(&logs)
+        .context("failed to analyze the bids")?;
+    if let Some(path) = html {
+        HtmlReport::new(&tr!("report-bidding-analysis"))
+            .paragraph(&tr!(
+                "report-bids-in-logs",
+                bids = report.decisions().len(),
+                logs = logs.len()
+            ))
+            .bidding(&report)
+            .write(path)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    } else if csv {
+        print!("{}", report.to_csv());
+    } else {
+        print!("{report}");
+    }
+    Ok(())
+}
+
+/// Tallies the leads in a corpus of logs, and prints how often each kind of lead won its trick,
+/// or writes it to an HTML file.
+pub fn leads_main(paths: &[PathBuf], html: Option<&Path>) -> anyhow::Result<()> {
+    let logs = load_corpus(paths).with_context(|| {
+        format!(
+            "failed to load {}",
+            paths.iter().map(|p| p.display()).join(", ")
+        )
+    })?;
+    let report = LeadReport::analyze(&logs).context("failed to analyze the leads")?;
+    if let Some(path) = html {
+        HtmlReport::new(&tr!("report-lead-analysis"))
+            .paragraph(&tr!(
+                "report-leads-in-logs",
+                leads = report.leads().len(),
+                logs = logs.len()
+            ))
+            .leads(&report)
+            .write(path)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    } else {
+        print!("{report}");
+    }
+    Ok(())
+}
+
+/// Prints structural statistics for the logs in a corpus: how large the action trees are, how
+/// they branch, and whose decisions branch most.
+pub fn log_stats_main(paths: &[PathBuf]) -> anyhow::Result<()> {
+    let logs = load_corpus(paths)?;
+    let stats = LogStats::analyze(&logs)?;
+    print!("{stats}");
+    Ok(())
+}
+
+/// Values every decision on the main line of a saved game or round log, or of each log in a
+/// directory, by rolling out each alternative over the specified number of random deals, and
+/// prints the evaluations with blunders flagged.
+pub fn analyze_main(path: &Path, rollouts: usize) -> anyhow::Result<()> {
+    let logs = load_corpus(&[path.to_path_buf()])
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    for (i, log) in logs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!(
+            "{}",
+            DecisionReport::analyze(log, &robot, rollouts, &mut rng)?
+        );
+    }
+    Ok(())
+}
+
+/// Exports a decision diary for the specified seat from a saved game, a round log, or a directory
+/// of them: what the seat knew at each of its decisions, and what it decided, as JSON. The diary
+/// is written to a file, or printed.
+pub fn diary_main(path: &Path, seat: Seat, output: Option<&Path>) -> anyhow::Result<()> {
+    let logs = load_corpus(&[path.to_path_buf()])
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    let diary = Diary::new(&logs, seat)?;
+    match output {
+        Some(output) => {
+            let file = File::create(output)
+                .with_context(|| format!("failed to write {}", output.display()))?;
+            serde_json::to_writer_pretty(file, &diary)?;
+            println!(
+                "{}",
+                tr!("report-wrote", path = output.display().to_string())
+            );
+        }
+        None => println!("{}", serde_json::to_string_pretty(&diary)?),
+    }
+    Ok(())
+}
+
+/// Runs practice drills on the command line. Each of `count` decisions of the specified kind is
+/// drawn from a random deal, for the player at the specified position if any, and the user's
+/// choice is scored against the best action, as valued by the specified number of rollouts. The
+/// session's results are added to the drills file, and compared with earlier sessions.
+pub fn drill_main(
+    kind: DrillKind,
+    position: Option<u8>,
+    count: usize,
+    rollouts: usize,
+) -> anyhow::Result<()> {
+    let paths = Paths::new();
+    let path = paths.drills();
+    let mut record = DrillRecord::load(&path)?;
+    let robot = Robot::default();
+    let console = Console::default();
+    let mut rng = rand::thread_rng();
+    let mut tally = DrillTally::default();
+    for i in 1..=count {
+        let drill = Drill::generate(kind, position, &robot, &mut rng)
+            .with_context(|| format!("no {kind} decision came up"))?;
+        let state = drill.state();
+        println!(
+            "\n{}",
+            tr!(
+                "drill-header",
+                n = i,
+                count = count,
+                kind = kind.to_string()
+            )
+        );
+        println!(
+            "{}",
+            tr!(
+                "drill-position",
+                seat = state.seat.to_string(),
+                position = drill.position(),
+                dealer = state.dealer.to_string(),
+                card = state.top.to_string()
+            )
+        );
+        if let Some(contract) = state.contract {
+            let seat = contract.maker.to_string();
+            let suit = contract.suit.to_string();
+            println!(
+                "{}",
+                if contract.alone {
+                    tr!("narration-called-alone", seat = seat, suit = suit)
+                } else {
+                    tr!("narration-called", seat = seat, suit = suit)
+                }
+            );
+        }
+        if kind == DrillKind::CallTrump {
+            let hand: Vec<String> = state.sorted_hand().iter().map(Card::to_string).collect();
+            println!("{}", tr!("console-hand", cards = hand.join(", ")));
+        }
+        let choice = loop {
+            let choice = console.take_action(drill.state(), kind.action());
+            if drill.is_legal(choice) {
+                break choice;
             }
+            println!("{}", tr!("drill-illegal", action = choice.to_string()));
+        };
+        if let Some(grade) = drill.grade(choice, &robot, rollouts, &mut rng) {
+            print!("{grade}");
+            tally.record(&grade);
         }
     }
-    let log = RawLog::from(round);
-    serde_json::to_writer(std::io::stderr(), &log).unwrap();
+    record.record_session(kind, tally);
+    record
+        .save(&path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    println!("\n{}", tr!("drill-session", tally = tally.to_string()));
+    println!(
+        "{}",
+        tr!("drill-all-time", tally = record.total(kind).to_string())
+    );
+    let recent: Vec<String> = record
+        .sessions(kind)
+        .filter_map(|session| session.tally.accuracy())
+        .map(|accuracy| format!("{:.0}%", accuracy * 100.0))
+        .collect();
+    let recent = &recent[recent.len().saturating_sub(10)..];
+    println!("{}", tr!("drill-recent", sessions = recent.join(" ")));
+    Ok(())
 }
 
-/// Runs the game in a rich terminal UI.
-pub fn tui_main(log_path: Option<&Path>) {
-    let tui = match log_path {
-        Some(p) => Tui::from_round_file(p).unwrap(),
-        None => Tui::default(),
+/// Mines a corpus of logs for puzzles, positions where exactly one card wins the round for the
+/// player's team, and writes each to a numbered file in the output directory, named for its
+/// difficulty. With `difficulty`, only puzzles of that difficulty are kept.
+pub fn puzzles_main(
+    paths: &[PathBuf],
+    output: &Path,
+    difficulty: Option<Difficulty>,
+) -> anyhow::Result<()> {
+    let logs = load_corpus(paths)?;
+    let robot = Robot::default();
+    let mut counts = [0; Difficulty::ALL.len()];
+    for log in &logs {
+        for puzzle in Puzzle::mine(log, &robot)? {
+            if difficulty.is_some_and(|d| d != puzzle.difficulty) {
+                continue;
+            }
+            let count = &mut counts[puzzle.difficulty as usize];
+            *count += 1;
+            let path = output.join(format!("{}-{:04}.json", puzzle.difficulty, count));
+            puzzle
+                .save(&path)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            println!("{}: {puzzle}", path.display());
+        }
+    }
+    let total: usize = counts.iter().sum();
+    let tally: Vec<String> = Difficulty::ALL
+        .iter()
+        .map(|&d| format!("{} {d}", counts[d as usize]))
+        .collect();
+    let (logs, tally) = (logs.len(), tally.join(", "));
+    let text = tr!("report-puzzles", total = total, logs = logs, tally = tally);
+    println!("{text}");
+    Ok(())
+}
+
+/// Prints the score sheet of a saved game, round by round, or writes it to a file. Unless given,
+/// the format is chosen by the output file's extension, or is text when printing. A finished round
+/// that hasn't been scored yet, as in an archived game, is scored first.
+pub fn score_sheet_main(
+    path: &Path,
+    format: Option<SheetFormat>,
+    output: Option<&Path>,
+) -> anyhow::Result<()> {
+    let mut game = RawGame::from_json_file(path)
+        .and_then(|raw| Ok(raw.into_game()?))
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    if game.round().outcome().is_some() {
+        game.score_round();
+    }
+    let sheet = ScoreSheet::new(&game);
+    match output {
+        Some(output) => {
+            let format = format.unwrap_or_else(|| SheetFormat::from_path(output));
+            std::fs::write(output, sheet.format(format))
+                .with_context(|| format!("failed to write {}", output.display()))?;
+            println!(
+                "{}",
+                tr!("report-wrote", path = output.display().to_string())
+            );
+        }
+        None => print!("{}", sheet.format(format.unwrap_or_default())),
+    }
+    Ok(())
+}
+
+/// Renders a saved game or round log to a standalone HTML file. By default, the file is written
+/// alongside the input, with an `.html` extension.
+pub fn export_main(path: &Path, output: Option<&Path>) -> anyhow::Result<()> {
+    let default = path.with_extension("html");
+    let output = output.unwrap_or(&default);
+    let report = match RawGame::from_json_file(path) {
+        Ok(game) => {
+            let game = game
+                .into_game()
+                .with_context(|| format!("failed to load {}", path.display()))?;
+            HtmlReport::new(&tr!("start-title"))
+                .score(&game)
+                .round(game.round())
+        }
+        Err(_) => {
+            let log = RawLog::from_json_file(path)
+                .with_context(|| format!("failed to load {}", path.display()))?;
+            let log = log.into_log();
+            let end = log.first_leaf(None);
+            let mut round = LoggingRound::from(log);
+            round
+                .seek(end)
+                .with_context(|| format!("failed to replay {}", path.display()))?;
+            HtmlReport::new(&tr!("report-euchre-round")).round(&round)
+        }
+    };
+    report
+        .write(output)
+        .with_context(|| format!("failed to write {}", output.display()))?;
+    println!(
+        "{}",
+        tr!("report-wrote", path = output.display().to_string())
+    );
+    Ok(())
+}
+
+/// Deals a round and prints its configuration as JSON, for building test fixtures and puzzles, or
+/// sharing an interesting deal. With a seed, the same deal is produced every time; the dealer is
+/// chosen at random unless specified. With `seat`, every other player's hand is left out.
+pub fn deal_main(
+    seed: Option<u64>,
+    dealer: Option<Seat>,
+    seat: Option<Seat>,
+) -> anyhow::Result<()> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let dealer = dealer.unwrap_or_else(|| rng.gen());
+    let config = RoundConfig::new(dealer, rng.gen(), DealPattern::default())?;
+    let config = match seat {
+        Some(seat) => config.redacted(seat),
+        None => config,
+    };
+    println!("{}", serde_json::to_string_pretty(&config)?);
+    Ok(())
+}
+
+/// Prints where settings and data are stored.
+pub fn paths_main() -> anyhow::Result<()> {
+    print!("{}", Paths::new());
+    Ok(())
+}
+
+/// Prints the action tree of a saved game or round log, laid out like the history in the terminal
+/// UI. Red suits are colored unless `color` is false. With `dot`, the tree is printed in Graphviz
+/// DOT format instead.
+#[cfg(feature = "tui")]
+pub fn show_main(path: &Path, color: bool, dot: bool) -> anyhow::Result<()> {
+    let logs = load_corpus(&[path.to_path_buf()])
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    for (i, log) in logs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        if dot {
+            print!("{}", log.to_dot());
+        } else {
+            print!("{}", format_log(log, color));
+        }
+    }
+    Ok(())
+}
+
+/// Plays a set of deals in duplicate, and prints the results. The deals are loaded from the
+/// specified file if it exists, or dealt at random and saved there otherwise. When practicing,
+/// the user plays South, and their results are compared against robots on the same deals. The
+/// robots are of the specified kinds, unless with `search`, the searching robot plays North-South
+/// against the heuristic robot.
+pub fn duplicate_main(
+    boards: usize,
+    deals: Option<&Path>,
+    practice: bool,
+    search: bool,
+    kinds: &SeatMap<RobotKind>,
+) -> anyhow::Result<()> {
+    let dup = match deals {
+        Some(path) if path.exists() => Duplicate::from_json_file(path)
+            .with_context(|| format!("failed to load {}", path.display()))?,
+        _ => {
+            let dup = Duplicate::random(boards);
+            if let Some(path) = deals {
+                dup.save(path)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+            }
+            dup
+        }
+    };
+    let robot = Robot::default().into_player();
+    let robots: Lineup = SeatMap::from_fn(|seat| kinds[seat].player(RobotParams::default()));
+    if search {
+        let searcher = SearchRobot::default().into_player();
+        let lineup = SeatMap::from_fn(|seat| match seat.team() {
+            Team::NorthSouth => searcher.clone(),
+            Team::EastWest => robot.clone(),
+        });
+        print!("{}", dup.play(&lineup)?);
+        println!("{}", tr!("report-searched"));
+    } else if practice {
+        let mut lineup = robots.clone();
+        lineup[Seat::South] = Console::default().into_player();
+        let yours = dup.play(&lineup)?;
+        let theirs = dup.play(&robots)?;
+        println!("{}\n{yours}", tr!("report-your-table"));
+        println!("{}\n{theirs}", tr!("report-robot-table"));
+        let diff = yours.net(Team::NorthSouth) - theirs.net(Team::NorthSouth);
+        println!("{}", tr!("report-practice", diff = format!("{diff:+}")));
+    } else {
+        print!("{}", dup.play(&robots)?);
+        if kinds.values().any(|&kind| kind != RobotKind::default()) {
+            let seats: Vec<String> = kinds
+                .iter()
+                .map(|(seat, kind)| format!("{seat} {kind}"))
+                .collect();
+            println!("{}", tr!("report-robots", robots = seats.join(", ")));
+        }
+    }
+    Ok(())
+}
+
+/// Trains the robot's parameters through seeded self-play, starting from those in the output file
+/// if it exists, and writes the best parameters found back to it.
+pub fn train_main(
+    iterations: usize,
+    boards: usize,
+    seed: u64,
+    output: Option<&Path>,
+) -> anyhow::Result<()> {
+    let path = output.map_or_else(|| Paths::new().robot_params(), Path::to_path_buf);
+    let start =
+        RobotParams::load(&path).with_context(|| format!("failed to load {}", path.display()))?;
+    // Write the starting parameters back, so that an unwritable output fails before training.
+    start
+        .save(&path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    let best = Trainer::default()
+        .with_seed(seed)
+        .with_iterations(iterations)
+        .with_boards(boards)
+        .train(start, |step| println!("{step}"))?;
+    best.save(&path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    println!(
+        "Best: call {}, loner {}, margin {}. Wrote {}",
+        best.call,
+        best.loner,
+        best.dealer_margin,
+        path.display()
+    );
+    Ok(())
+}
+
+/// Measures round throughput and robot decision latency on this machine, over seeded deals, and
+/// prints a comparison table.
+pub fn bench_main(rounds: usize, searches: usize, seed: u64) -> anyhow::Result<()> {
+    let report = Bench::default()
+        .with_seed(seed)
+        .with_rounds(rounds)
+        .with_searches(searches)
+        .run()?;
+    print!("{report}");
+    Ok(())
+}
+
+/// Runs the game in a rich terminal UI, optionally reviewing the loaded round. New games are
+/// played with the specified rules, by robots of the specified kinds, which bid in the specified
+/// styles, and their reasoning is recorded in the log if `trace` is set. With `robot_lines` set,
+/// the line the robot prefers at each of the user's decisions is added to the log once a round is
+/// over. With `paranoid` set, the round's invariants are checked after every step of play. With
+/// `tutorial` set, a new game is the scripted tutorial deal.
+#[cfg(feature = "tui")]
+#[allow(clippy::too_many_arguments)]
+pub fn tui_main(
+    log_path: Option<&Path>,
+    review: bool,
+    rules: Rules,
+    presets: &[(Seat, Preset)],
+    kinds: &SeatMap<RobotKind>,
+    trace: bool,
+    robot_lines: bool,
+    paranoid: bool,
+    tutorial: bool,
+) -> anyhow::Result<()> {
+    let mut tui = match log_path {
+        Some(p) => Tui::from_file(p).with_context(|| format!("failed to load {}", p.display()))?,
+        None if tutorial => Tui::tutorial(),
+        None => {
+            let mut tui = Tui::new_game(rules);
+            tui.open_start_menu();
+            tui
+        }
     };
-    let terminal = tui_init().unwrap();
-    tui.run(terminal).unwrap();
-    tui_restore().unwrap();
+    for &(seat, preset) in presets {
+        tui.set_preset(seat, preset);
+    }
+    for (seat, &kind) in kinds.iter() {
+        tui.set_robot_kind(seat, kind);
+    }
+    tui.set_trace(trace);
+    tui.set_robot_lines(robot_lines);
+    tui.set_paranoid(paranoid);
+    if review {
+        tui.enter_review_mode();
+    }
+    let terminal = tui_init()?;
+    let result = tui.run(terminal);
+    tui_restore()?;
+    result
+}
+
+/// Runs the game in a desktop GUI.
+#[cfg(feature = "gui")]
+pub fn gui_main(log_path: Option<&Path>) -> anyhow::Result<()> {
+    let gui = match log_path {
+        Some(p) => {
+            gui::Gui::from_file(p).with_context(|| format!("failed to load {}", p.display()))?
+        }
+        None => gui::Gui::default(),
+    };
+    gui::run(gui)
+}
+
+/// Connects to a remote game, and plays (or watches) it in a rich terminal UI.
+#[cfg(feature = "tui")]
+pub fn remote_tui_main(
+    url: &str,
+    table: Option<&str>,
+    seat: Option<Seat>,
+    spectate: bool,
+) -> anyhow::Result<()> {
+    let tui = if spectate {
+        RemoteTui::spectate(url, table)
+    } else {
+        RemoteTui::connect(url, table, seat)
+    }
+    .with_context(|| format!("failed to join {url}"))?;
+    let terminal = tui_init()?;
+    let result = tui.run(terminal);
+    tui_restore()?;
+    result
+}
+
+/// Hosts a lobby for remote players, until the process is killed.
+#[cfg(feature = "net")]
+pub fn serve_main(
+    addr: &str,
+    chat: ChatPolicy,
+    time_control: Option<TimeControl>,
+) -> anyhow::Result<()> {
+    let server = Server::bind(addr)
+        .with_context(|| format!("failed to listen on {addr}"))?
+        .with_chat_policy(chat)
+        .with_time_control(time_control);
+    println!("Listening on ws://{}", server.local_addr()?);
+    server.run();
+    Ok(())
+}
+
+/// Serves a REST API for creating and playing games.
+#[cfg(feature = "net")]
+pub fn http_main(addr: &str) -> anyhow::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("failed to listen on {addr}"))?;
+        println!("Listening on http://{}", listener.local_addr()?);
+        let app = net::router(std::sync::Arc::new(Games::default()));
+        axum::serve(listener, app).await?;
+        Ok(())
+    })
 }
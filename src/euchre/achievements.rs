@@ -0,0 +1,171 @@
+//! Persistent achievements.
+//!
+//! Achievements are earned by the human player's seat, and accumulated across sessions in a
+//! local profile, alongside the stats file.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::paths::create_file;
+use super::{Game, Round, Seat, Team};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// A notable accomplishment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Achievement {
+    /// Took all five tricks on a lone hand.
+    LonerMarch,
+    /// Euchred an opponent's lone hand.
+    LonerEuchre,
+    /// Won a game after trailing 0-8.
+    Comeback,
+    /// Won a game without the opponents scoring.
+    Shutout,
+}
+
+impl Display for Achievement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Achievement::LonerMarch => tr!("achievement-loner-march"),
+            Achievement::LonerEuchre => tr!("achievement-loner-euchre"),
+            Achievement::Comeback => tr!("achievement-comeback"),
+            Achievement::Shutout => tr!("achievement-shutout"),
+        })
+    }
+}
+
+impl Achievement {
+    /// All achievements.
+    pub fn all() -> &'static [Achievement; 4] {
+        static ACHIEVEMENTS: [Achievement; 4] = [
+            Achievement::LonerMarch,
+            Achievement::LonerEuchre,
+            Achievement::Comeback,
+            Achievement::Shutout,
+        ];
+        &ACHIEVEMENTS
+    }
+
+    /// Describes how the achievement is earned.
+    pub fn description(self) -> String {
+        match self {
+            Achievement::LonerMarch => tr!("achievement-loner-march-description"),
+            Achievement::LonerEuchre => tr!("achievement-loner-euchre-description"),
+            Achievement::Comeback => tr!("achievement-comeback-description"),
+            Achievement::Shutout => tr!("achievement-shutout-description"),
+        }
+    }
+}
+
+/// The achievements earned so far, with the number of times each has been earned.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Achievements {
+    earned: BTreeMap<Achievement, u32>,
+    /// Whether the player's team has trailed 0-8 in the current game. This isn't persisted, so a
+    /// comeback in a resumed game only counts if the deficit happened in this session.
+    #[serde(skip)]
+    trailed: bool,
+}
+
+impl Achievements {
+    /// The name of the profile, in the data directory.
+    pub const FILE_NAME: &'static str = "euchre-achievements.json";
+
+    /// Loads achievements from a file. A missing file is treated as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes achievements to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the number of times the achievement has been earned.
+    pub fn count(&self, achievement: Achievement) -> u32 {
+        self.earned.get(&achievement).copied().unwrap_or_default()
+    }
+
+    /// Checks the current round of a game for achievements earned by the specified seat, before
+    /// the round is scored. Returns the achievements that were earned for the first time. Rounds
+    /// that are not over are ignored.
+    pub fn record_round<R: Round>(&mut self, game: &Game<R>, seat: Seat) -> Vec<Achievement> {
+        let round = game.round();
+        let (Some(contract), Some(outcome)) = (round.contract(), round.outcome()) else {
+            return vec![];
+        };
+        let team = seat.team();
+        let mut earned = vec![];
+        if contract.alone {
+            let makers = contract.maker.team();
+            if contract.maker == seat && round.tricks().win_count(makers) == 5 {
+                earned.push(Achievement::LonerMarch);
+            } else if makers != team && outcome.team == team {
+                earned.push(Achievement::LonerEuchre);
+            }
+        }
+        let points = |t: Team| {
+            let won = if outcome.team == t { outcome.points } else { 0 };
+            game.score(t) + won
+        };
+        earned.extend(self.check_score(points(team), points(team.other()), game.target_score()));
+        earned.into_iter().filter(|&a| self.earn(a)).collect()
+    }
+
+    /// Checks the score after a round for game-level achievements.
+    fn check_score(&mut self, ours: u8, theirs: u8, target: u8) -> Vec<Achievement> {
+        if ours == 0 && theirs >= 8 {
+            self.trailed = true;
+        }
+        let mut earned = vec![];
+        if ours >= target {
+            if self.trailed {
+                earned.push(Achievement::Comeback);
+            }
+            if theirs == 0 {
+                earned.push(Achievement::Shutout);
+            }
+        }
+        if ours >= target || theirs >= target {
+            self.trailed = false;
+        }
+        earned
+    }
+
+    /// Records an achievement, and returns true if it was earned for the first time.
+    fn earn(&mut self, achievement: Achievement) -> bool {
+        let count = self.earned.entry(achievement).or_default();
+        *count += 1;
+        *count == 1
+    }
+}
+
+impl Display for Achievements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for &achievement in Achievement::all() {
+            let count = self.count(achievement);
+            let mark = if count > 0 { "*" } else { " " };
+            writeln!(
+                f,
+                "{mark} {:<20} {:<42} {count:>3}",
+                achievement.to_string(),
+                achievement.description()
+            )?;
+        }
+        Ok(())
+    }
+}
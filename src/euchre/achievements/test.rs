@@ -0,0 +1,78 @@
+use crate::euchre::{
+    ActionData, ActionType, BaseRound, Card, DealPattern, Player, Robot, RoundConfig, SeatMap, Suit,
+};
+
+use super::*;
+
+/// Plays a round in which South holds the five highest trumps and orders up alone.
+fn loner_game() -> Game<BaseRound> {
+    let south: Vec<Card> = ["JH", "JD", "AH", "KH", "QH"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let top: Card = "9H".parse().unwrap();
+    let mut rest = (0..24)
+        .map(Card::from_index)
+        .filter(|c| !south.contains(c) && *c != top);
+    let hands = SeatMap::from_fn(|seat| {
+        if seat == Seat::South {
+            south.clone()
+        } else {
+            rest.by_ref().take(5).collect()
+        }
+    });
+    let config = RoundConfig::from_hands(Seat::East, hands, top, DealPattern::default()).unwrap();
+    let mut game = Game::from(BaseRound::from(config));
+    let robot = Robot::default();
+    let round = game.round_mut();
+    while let Some(expect) = round.next_action() {
+        let data = if expect.seat == Seat::South && expect.action == ActionType::BidTop {
+            ActionData::Call {
+                suit: Suit::Heart,
+                alone: true,
+            }
+        } else {
+            robot.take_action(round.player_state(expect.seat), expect.action)
+        };
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+    game
+}
+
+#[test]
+fn test_loner() {
+    let game = loner_game();
+    let mut achievements = Achievements::default();
+    assert_eq!(
+        achievements.record_round(&game, Seat::South),
+        vec![Achievement::LonerMarch]
+    );
+    assert!(achievements.record_round(&game, Seat::South).is_empty());
+    assert_eq!(achievements.count(Achievement::LonerMarch), 2);
+
+    assert!(achievements.record_round(&game, Seat::North).is_empty());
+    assert!(achievements.record_round(&game, Seat::East).is_empty());
+    assert_eq!(achievements.count(Achievement::LonerEuchre), 0);
+
+    let ser = serde_json::to_string(&achievements).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Achievements>(&ser).unwrap(),
+        achievements
+    );
+}
+
+#[test]
+fn test_score() {
+    let mut achievements = Achievements::default();
+    assert!(achievements.check_score(0, 8, 10).is_empty());
+    assert!(achievements.check_score(6, 8, 10).is_empty());
+    assert_eq!(
+        achievements.check_score(10, 8, 10),
+        vec![Achievement::Comeback]
+    );
+    assert!(achievements.check_score(10, 8, 10).is_empty());
+    assert_eq!(
+        achievements.check_score(10, 0, 10),
+        vec![Achievement::Shutout]
+    );
+}
@@ -5,9 +5,11 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 use super::{Card, Seat, Suit};
+use crate::tr;
 
 /// Types of actions that a player can take.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ActionType {
     /// Bid the top card.
     BidTop,
@@ -19,21 +21,26 @@ pub enum ActionType {
     Lead,
     /// Follow a pending trick.
     Follow,
+    /// Call a renege on a card played by an opponent. Unlike other actions, this may be taken
+    /// out of turn, by any player, while tricks are being played.
+    CallRenege,
 }
 impl Display for ActionType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
-            ActionType::BidTop => "bid top",
-            ActionType::BidOther => "bid other",
-            ActionType::DealerDiscard => "discard",
-            ActionType::Lead => "lead",
-            ActionType::Follow => "follow",
+        f.write_str(&match self {
+            ActionType::BidTop => tr!("action-type-bid-top"),
+            ActionType::BidOther => tr!("action-type-bid-other"),
+            ActionType::DealerDiscard => tr!("action-type-discard"),
+            ActionType::Lead => tr!("action-type-lead"),
+            ActionType::Follow => tr!("action-type-follow"),
+            ActionType::CallRenege => tr!("action-type-call-renege"),
         })
     }
 }
 
 /// The payload for actions that a player can take during the game.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ActionData {
     /// Pass on an opportunity to declare trump.
     Pass,
@@ -49,12 +56,27 @@ pub enum ActionData {
         alone: bool,
     },
 
-    /// Play or discard a card.
+    /// Play or discard a card, or identify the card that was reneged.
     Card { card: Card },
 }
 
+impl Display for ActionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionData::Pass => f.write_str(&tr!("action-pass")),
+            ActionData::Call { suit, alone: false } => {
+                f.write_str(&tr!("action-call", suit = suit.to_string()))
+            }
+            ActionData::Call { suit, alone: true } => {
+                f.write_str(&tr!("action-call-alone", suit = suit.to_string()))
+            }
+            ActionData::Card { card } => write!(f, "{card}"),
+        }
+    }
+}
+
 /// The action that the game's state machine expects to happen next.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExpectAction {
     /// The player expected to take the action.
     pub seat: Seat,
@@ -76,6 +98,7 @@ impl ExpectAction {
 
 /// An action taken by a player during a round.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Action {
     /// The player taking the action.
     pub seat: Seat,
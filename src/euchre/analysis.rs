@@ -0,0 +1,119 @@
+//! Analysis of played rounds.
+//!
+//! Analyzers read a corpus of round logs, and evaluate the decisions recorded in them by rolling
+//! out alternative lines of play with robots.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::{
+    Action, Card, CardSet, FastRound, Log, RawGame, RawLog, Robot, Round, RoundConfig, RoundError,
+    Seat, SeatMap, Team,
+};
+
+mod bidding;
+mod diary;
+mod estimator;
+mod inference;
+mod leads;
+mod odds;
+mod puzzle;
+mod review;
+mod structure;
+mod suggest;
+pub use bidding::{AccuracyRow, BidDecision, BiddingAnalyzer, BiddingReport};
+pub use diary::{Diary, DiaryEntry, DiaryRound};
+pub use estimator::win_probability;
+pub use inference::Inference;
+pub use leads::{Lead, LeadCategory, LeadReport, LeadRole, Tally};
+pub use odds::trick_odds;
+pub use puzzle::{Difficulty, Puzzle};
+pub use review::{add_robot_lines, Decision, DecisionReport, BLUNDER_POINTS};
+pub use structure::LogStats;
+pub use suggest::{action_values, expected_points};
+
+#[cfg(test)]
+mod test;
+
+/// Loads the round logs in a corpus. Each path may be a saved game, a round log, or a directory,
+/// which is searched recursively for `.json` files.
+pub fn load_corpus(paths: &[PathBuf]) -> anyhow::Result<Vec<Log>> {
+    let mut logs = vec![];
+    for path in paths {
+        if path.is_dir() {
+            let mut entries: Vec<_> = fs::read_dir(path)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<_, _>>()?;
+            entries.sort();
+            entries.retain(|p| p.is_dir() || p.extension().is_some_and(|ext| ext == "json"));
+            logs.extend(load_corpus(&entries)?);
+        } else {
+            logs.push(load_log(path)?);
+        }
+    }
+    Ok(logs)
+}
+
+/// Loads a round log, or the current round from a saved game.
+fn load_log(path: &Path) -> anyhow::Result<Log> {
+    match RawGame::from_json_file(path) {
+        Ok(game) => Ok(game.into_game()?.round().log().clone()),
+        Err(_) => Ok(RawLog::from_json_file(path)?.into_log()),
+    }
+}
+
+/// Deals a round that is consistent with what the specified player has seen, by dealing the
+/// cards they haven't seen at random to the other players.
+fn determinize<R: Rng + ?Sized>(
+    config: &RoundConfig,
+    seat: Seat,
+    seen: CardSet,
+    rng: &mut R,
+) -> RoundConfig {
+    let hands = determinize_hands(seat, config.hand(seat), seen, rng);
+    RoundConfig::from_hands(config.dealer(), hands, config.top(), config.pattern())
+        .expect("deal is valid")
+}
+
+/// Deals five cards to every player other than the specified one, from the cards they haven't
+/// seen.
+fn determinize_hands<R: Rng + ?Sized>(
+    seat: Seat,
+    hand: &[Card],
+    seen: CardSet,
+    rng: &mut R,
+) -> SeatMap<Vec<Card>> {
+    let mut unseen: Vec<Card> = (!seen).iter().collect();
+    unseen.shuffle(rng);
+    SeatMap::from_fn(|s| {
+        if s == seat {
+            hand.to_vec()
+        } else {
+            unseen.split_off(unseen.len() - 5)
+        }
+    })
+}
+
+/// Replays a line of play on a fresh round, and plays it out with the robot. Returns the number of
+/// points won by the specified team, or a negative number if their opponents won.
+pub fn playout(
+    config: RoundConfig,
+    actions: &[Action],
+    robot: &Robot,
+    team: Team,
+) -> Result<i32, RoundError> {
+    let mut round = FastRound::from(config);
+    for &action in actions {
+        round.apply_action(action)?;
+    }
+    let outcome = round.play_out(robot)?;
+    let points = i32::from(outcome.points);
+    Ok(if outcome.team == team {
+        points
+    } else {
+        -points
+    })
+}
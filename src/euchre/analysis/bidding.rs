@@ -0,0 +1,316 @@
+//! Bidding-accuracy analysis.
+//!
+//! Each bid is evaluated by dealing the cards the bidder couldn't see at random, and playing out
+//! the round with robots, once after passing and once after calling. A bid is correct if it scored
+//! at least as well, on average, as the alternative.
+
+use std::fmt::{Display, Write};
+
+use super::{determinize, playout};
+use crate::euchre::{
+    Action, ActionData, ActionType, FastRound, Log, Robot, Round, RoundError, Seat, Suit,
+};
+use crate::tr;
+
+/// Labels for positions in the bidding order.
+const POSITIONS: [&str; 4] = ["1st", "2nd", "3rd", "Dealer"];
+
+/// Labels for hand-strength buckets.
+const BUCKETS: [&str; 5] = ["0-3", "4-5", "6-7", "8-9", "10+"];
+
+/// Returns the hand-strength bucket for the specified strength.
+fn bucket(strength: u8) -> usize {
+    match strength {
+        0..=3 => 0,
+        4..=5 => 1,
+        6..=7 => 2,
+        8..=9 => 3,
+        _ => 4,
+    }
+}
+
+/// An evaluated bid.
+#[derive(Debug, Clone, Copy)]
+pub struct BidDecision {
+    /// The player who bid.
+    pub seat: Seat,
+    /// The player's position in the bidding order, from 0 (left of the dealer) to 3 (the dealer).
+    pub position: usize,
+    /// The type of bid.
+    pub action: ActionType,
+    /// The strength of the player's hand, for the suit they called or could have called.
+    pub strength: u8,
+    /// Whether the player called trump.
+    pub called: bool,
+    /// The average points won by the player's team after passing.
+    pub pass_value: f64,
+    /// The average points won by the player's team after calling.
+    pub call_value: f64,
+}
+
+impl BidDecision {
+    /// Returns true if the bid scored at least as well as the alternative.
+    pub fn is_correct(&self) -> bool {
+        if self.called {
+            self.call_value >= self.pass_value
+        } else {
+            self.pass_value >= self.call_value
+        }
+    }
+}
+
+/// Evaluates bids via rollouts.
+#[derive(Debug)]
+pub struct BiddingAnalyzer {
+    robot: Robot,
+    rollouts: usize,
+}
+
+impl Default for BiddingAnalyzer {
+    fn default() -> Self {
+        Self {
+            robot: Robot::default(),
+            rollouts: 100,
+        }
+    }
+}
+
+impl BiddingAnalyzer {
+    /// Sets the number of rollouts per bid.
+    pub fn with_rollouts(mut self, rollouts: usize) -> Self {
+        self.rollouts = rollouts.max(1);
+        self
+    }
+
+    /// Evaluates every bid in a corpus of logs.
+    pub fn analyze(&self, logs: &[Log]) -> Result<BiddingReport, RoundError> {
+        let mut decisions = vec![];
+        for log in logs {
+            decisions.extend(self.analyze_log(log)?);
+        }
+        Ok(BiddingReport { decisions })
+    }
+
+    /// Evaluates every bid in a log, including those on alternative branches. Bids where the
+    /// player had no choice are skipped.
+    pub fn analyze_log(&self, log: &Log) -> Result<Vec<BidDecision>, RoundError> {
+        let mut nodes: Vec<_> = log
+            .action_nodes()
+            .filter(|n| matches!(n.action.action, ActionType::BidTop | ActionType::BidOther))
+            .collect();
+        nodes.sort_unstable_by_key(|n| n.id);
+
+        let mut decisions = vec![];
+        for node in nodes {
+            let mut prefix: Vec<Action> = match node.parent {
+                Some(id) => log.backtrace(id)?.into_iter().map(|(_, a)| a).collect(),
+                None => vec![],
+            };
+            if let Some(decision) = self.evaluate(log, &mut prefix, node.action)? {
+                decisions.push(decision);
+            }
+        }
+        Ok(decisions)
+    }
+
+    /// Evaluates a bid, which follows the specified actions.
+    fn evaluate(
+        &self,
+        log: &Log,
+        prefix: &mut Vec<Action>,
+        action: Action,
+    ) -> Result<Option<BidDecision>, RoundError> {
+        let config = log.config();
+        let mut round = FastRound::from(config.clone());
+        for &action in prefix.iter() {
+            round.apply_action(action)?;
+        }
+        let seat = action.seat;
+        let dealer = config.dealer();
+        let top = config.top().suit;
+        if action.action == ActionType::BidOther && seat == dealer {
+            return Ok(None);
+        }
+
+        let hand = config.hand(seat);
+        let call = match action.data {
+            ActionData::Call { .. } => action.data,
+            _ if action.action == ActionType::BidTop => ActionData::Call {
+                suit: top,
+                alone: false,
+            },
+            _ => {
+                let suit = Suit::all_suits()
+                    .iter()
+                    .copied()
+                    .filter(|&s| s != top)
+                    .max_by_key(|&s| Robot::hand_strength(hand, s))
+                    .expect("non-empty");
+                ActionData::Call { suit, alone: false }
+            }
+        };
+        let ActionData::Call { suit, .. } = call else {
+            unreachable!("call is a call");
+        };
+        let strength = Robot::hand_strength(hand, suit);
+
+        let seen = round.player_state(seat).seen;
+        let mut rng = rand::thread_rng();
+        let (mut pass_total, mut call_total) = (0, 0);
+        for _ in 0..self.rollouts {
+            let config = determinize(config, seat, seen, &mut rng);
+            for (data, total) in [(ActionData::Pass, &mut pass_total), (call, &mut call_total)] {
+                prefix.push(Action::new(seat, action.action, data));
+                *total += playout(config.clone(), prefix, &self.robot, seat.team())?;
+                prefix.pop();
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.rollouts as f64;
+        Ok(Some(BidDecision {
+            seat,
+            position: dealer
+                .next_n(4)
+                .iter()
+                .position(|&s| s == seat)
+                .expect("seat is at the table"),
+            action: action.action,
+            strength,
+            called: matches!(action.data, ActionData::Call { .. }),
+            pass_value: f64::from(pass_total) / n,
+            call_value: f64::from(call_total) / n,
+        }))
+    }
+}
+
+/// Accuracy for bids in a particular position and hand-strength bucket.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AccuracyRow {
+    /// The position in the bidding order.
+    pub position: usize,
+    /// The hand-strength bucket.
+    pub bucket: usize,
+    /// The number of passes.
+    pub passes: u32,
+    /// The number of correct passes.
+    pub passes_correct: u32,
+    /// The number of calls.
+    pub calls: u32,
+    /// The number of correct calls.
+    pub calls_correct: u32,
+}
+
+impl AccuracyRow {
+    /// Returns a label for the position in the bidding order.
+    pub fn position_label(&self) -> &'static str {
+        POSITIONS[self.position]
+    }
+
+    /// Returns a label for the hand-strength bucket.
+    pub fn strength_label(&self) -> &'static str {
+        BUCKETS[self.bucket]
+    }
+
+    /// Returns a localized name for the position, for display.
+    pub fn position_name(&self) -> String {
+        tr!("report-position-name", position = self.position)
+    }
+}
+
+/// The results of a bidding analysis.
+#[derive(Debug, Clone, Default)]
+pub struct BiddingReport {
+    decisions: Vec<BidDecision>,
+}
+
+impl BiddingReport {
+    /// Returns the evaluated bids.
+    pub fn decisions(&self) -> &[BidDecision] {
+        &self.decisions
+    }
+
+    /// Summarizes accuracy by position and hand-strength bucket, omitting empty rows.
+    pub fn rows(&self) -> Vec<AccuracyRow> {
+        let mut rows = [[AccuracyRow::default(); BUCKETS.len()]; POSITIONS.len()];
+        for d in &self.decisions {
+            let row = &mut rows[d.position][bucket(d.strength)];
+            let correct = u32::from(d.is_correct());
+            if d.called {
+                row.calls += 1;
+                row.calls_correct += correct;
+            } else {
+                row.passes += 1;
+                row.passes_correct += correct;
+            }
+        }
+        rows.iter()
+            .enumerate()
+            .flat_map(|(position, buckets)| {
+                buckets
+                    .iter()
+                    .enumerate()
+                    .map(move |(bucket, row)| AccuracyRow {
+                        position,
+                        bucket,
+                        ..*row
+                    })
+            })
+            .filter(|row| row.passes + row.calls > 0)
+            .collect()
+    }
+
+    /// Formats the summary as CSV.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("position,strength,passes,passes_correct,calls,calls_correct\n");
+        for row in self.rows() {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                row.position_label(),
+                row.strength_label(),
+                row.passes,
+                row.passes_correct,
+                row.calls,
+                row.calls_correct
+            )
+            .expect("infallible");
+        }
+        csv
+    }
+}
+
+impl Display for BiddingReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pct = |n: u32, d: u32| {
+            if d == 0 {
+                "-".to_string()
+            } else {
+                format!("{:.0}%", f64::from(n) * 100.0 / f64::from(d))
+            }
+        };
+        writeln!(
+            f,
+            "{:<8} {:>8} {:>7} {:>7} {:>7} {:>7}",
+            tr!("report-position"),
+            tr!("report-strength"),
+            tr!("report-passes"),
+            tr!("report-pass-ok"),
+            tr!("report-calls"),
+            tr!("report-call-ok")
+        )?;
+        for row in self.rows() {
+            writeln!(
+                f,
+                "{:<8} {:>8} {:>7} {:>7} {:>7} {:>7}",
+                row.position_name(),
+                row.strength_label(),
+                row.passes,
+                pct(row.passes_correct, row.passes),
+                row.calls,
+                pct(row.calls_correct, row.calls),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Decision diaries.
+//!
+//! Walks the main line of a round log from one seat's point of view, and records what the seat
+//! knew at each of its decisions, and what it decided. Diaries are written as JSON, for analysis
+//! with other tools.
+
+use std::convert::TryFrom;
+
+use serde::Serialize;
+
+use crate::euchre::{
+    Action, ActionData, ActionType, Card, Contract, FastRound, Log, Round, RoundError, Seat,
+};
+
+/// What a seat knew at one of its decisions, and what it decided.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiaryEntry {
+    /// The position of the decision on the round's main line, counting every seat's actions
+    /// from 1.
+    pub step: usize,
+    /// The kind of action that was expected.
+    pub action: ActionType,
+    /// The contract, if one had been declared.
+    pub contract: Option<Contract>,
+    /// The seat's hand.
+    pub hand: Vec<Card>,
+    /// The actions taken so far this round, as the seat saw them. Other seats' discards are
+    /// left out, since the cards aren't shown.
+    pub history: Vec<Action>,
+    /// The actions the seat could have taken.
+    pub legal: Vec<ActionData>,
+    /// The action the seat took.
+    pub decision: ActionData,
+    /// How long the seat thought before deciding, in milliseconds, if it was recorded.
+    pub think_ms: Option<u64>,
+}
+
+/// One round of a diary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiaryRound {
+    /// The dealer.
+    pub dealer: Seat,
+    /// The card turned up.
+    pub top: Card,
+    /// The seat's decisions, in the order they were taken.
+    pub entries: Vec<DiaryEntry>,
+}
+
+impl DiaryRound {
+    /// Records the specified seat's decisions on the main line of the log, which follows the
+    /// first recorded continuation from the deal.
+    pub fn new(log: &Log, seat: Seat) -> Result<Self, RoundError> {
+        let config = log.config();
+        let mut round = FastRound::from(config.clone());
+        let line = match log.first_leaf(None) {
+            Some(leaf) => log.backtrace(leaf)?,
+            None => vec![],
+        };
+        let mut history = vec![];
+        let mut entries = vec![];
+        for (step, (id, action)) in line.into_iter().enumerate() {
+            if action.seat == seat {
+                let state = round.player_state(seat);
+                let think_ms = log.node(id).and_then(|node| node.timing).map(|timing| {
+                    u64::try_from(timing.think_time().as_millis()).unwrap_or(u64::MAX)
+                });
+                entries.push(DiaryEntry {
+                    step: step + 1,
+                    action: action.action,
+                    contract: state.contract,
+                    hand: state.hand.clone(),
+                    history: history.clone(),
+                    legal: round.legal_actions().into_iter().map(|a| a.data).collect(),
+                    decision: action.data,
+                    think_ms,
+                });
+            }
+            if action.seat == seat || action.action != ActionType::DealerDiscard {
+                history.push(action);
+            }
+            round.apply_action(action)?;
+        }
+        Ok(Self {
+            dealer: config.dealer(),
+            top: config.top(),
+            entries,
+        })
+    }
+}
+
+/// A seat's decisions over a set of rounds, with what it knew when it made each one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diary {
+    /// The seat whose decisions are recorded.
+    pub seat: Seat,
+    /// The rounds, in the order they were given.
+    pub rounds: Vec<DiaryRound>,
+}
+
+impl Diary {
+    /// Records the specified seat's decisions in each log.
+    pub fn new(logs: &[Log], seat: Seat) -> Result<Self, RoundError> {
+        let rounds = logs
+            .iter()
+            .map(|log| DiaryRound::new(log, seat))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { seat, rounds })
+    }
+}
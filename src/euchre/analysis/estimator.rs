@@ -0,0 +1,73 @@
+//! Win probability.
+//!
+//! A team's chances of winning the game are estimated by finishing the round in progress on deals
+//! that are consistent with what one of its players has seen, as when valuing actions, and then
+//! playing further rounds on random deals until one team reaches the target score. Robots take
+//! every action.
+
+use std::convert::TryFrom;
+
+use rand::Rng;
+
+use super::playout;
+use super::suggest::reconstruct;
+use crate::euchre::{FastRound, Game, PlayerState, Robot, Round, TeamMap};
+
+/// Estimates the probability that the specified player's team wins the game, over the specified
+/// number of simulated finishes. Returns `None` if no deal consistent with the player's view
+/// could be reconstructed.
+pub fn win_probability<D: Round, R: Rng + ?Sized>(
+    game: &Game<D>,
+    state: &PlayerState,
+    robot: &Robot,
+    samples: usize,
+    rng: &mut R,
+) -> Option<f64> {
+    let team = state.seat.team();
+    if let Some(winner) = game.winner() {
+        return Some(if winner == team { 1.0 } else { 0.0 });
+    }
+    let mut wins = 0_u32;
+    let mut count = 0_u32;
+    for _ in 0..samples {
+        if let Some(won) = simulate(game, state, robot, rng) {
+            wins += u32::from(won);
+            count += 1;
+        }
+    }
+    (count > 0).then(|| f64::from(wins) / f64::from(count))
+}
+
+/// Plays out the rest of the game once, and returns true if the player's team wins.
+fn simulate<D: Round, R: Rng + ?Sized>(
+    game: &Game<D>,
+    state: &PlayerState,
+    robot: &Robot,
+    rng: &mut R,
+) -> Option<bool> {
+    let team = state.seat.team();
+    let round = game.round();
+    let (winner, points) = match (round.outcome(), round.next_action()) {
+        (Some(outcome), _) => (outcome.team, outcome.points),
+        (None, Some(expect)) => {
+            let (config, prefix) = reconstruct(state, expect, rng)?;
+            let points = playout(config, &prefix, robot, team).ok()?;
+            let winner = if points > 0 { team } else { team.other() };
+            (winner, u8::try_from(points.unsigned_abs()).ok()?)
+        }
+        (None, None) => (team, 0),
+    };
+
+    // The round in progress isn't scored until the next one is dealt.
+    let mut score = TeamMap::default();
+    for t in [team, team.other()] {
+        score[t] = game.score(t);
+    }
+    score[winner] = score[winner].saturating_add(points);
+    let target = game.target_score();
+    while score.iter().all(|(_, &s)| s < target) {
+        let outcome = FastRound::random().play_out(robot).ok()?;
+        score[outcome.team] = score[outcome.team].saturating_add(outcome.points);
+    }
+    Some(score[team] >= target)
+}
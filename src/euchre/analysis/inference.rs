@@ -0,0 +1,114 @@
+//! Inference about hidden hands.
+//!
+//! A player knows their own hand, and can infer a few things about the others from the round so
+//! far: a player who failed to follow suit holds no more cards of that suit, and a dealer who
+//! picked up the top card still holds it, unless it has been played. Everything else is dealt at
+//! random from the cards the player hasn't seen.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::euchre::{Card, CardSet, PlayerState, Seat, SeatMap};
+
+/// What a player knows about every hand at the table, once a contract has been declared.
+#[derive(Debug, Clone)]
+pub struct Inference {
+    /// The number of cards held at each seat.
+    counts: SeatMap<usize>,
+    /// The cards known to be held at each seat.
+    known: SeatMap<CardSet>,
+    /// The cards that each seat can't hold.
+    excluded: SeatMap<CardSet>,
+    /// The cards whose whereabouts are unknown.
+    unseen: CardSet,
+}
+
+impl Inference {
+    /// Draws inferences from the specified player's view of the round. Returns `None` if no
+    /// contract has been declared yet.
+    pub fn new(state: &PlayerState) -> Option<Self> {
+        let contract = state.contract?;
+        let sitting_out = contract.alone.then(|| contract.maker.opposite());
+        let dealer = state.dealer;
+
+        // The dealer picks up the top card when it's ordered up, unless they're sitting out. They
+        // only discard if their partner called it, or if nobody is going alone.
+        let picked_up = contract.suit == state.top.suit && sitting_out != Some(dealer);
+        let discarded = !contract.alone || contract.maker == dealer;
+
+        let mut counts = SeatMap::default();
+        let mut known = SeatMap::<CardSet>::default();
+        let mut excluded = SeatMap::default();
+        for &seat in Seat::all_seats() {
+            if sitting_out == Some(seat) {
+                continue;
+            }
+            if seat == state.seat {
+                counts[seat] = state.hand.len();
+                known[seat] = state.hand.iter().collect();
+                continue;
+            }
+            let played: Vec<Card> = state.tricks.cards_played_by(seat).collect();
+            counts[seat] = 5 - played.len();
+            if seat == dealer && picked_up {
+                if !discarded {
+                    counts[seat] += 1;
+                }
+                if !played.contains(&state.top) {
+                    known[seat].insert(state.top);
+                }
+            }
+            for trick in state.tricks {
+                if let Some(card) = trick.get_card(seat) {
+                    if !trick.following().contains(card) {
+                        excluded[seat] = excluded[seat] | trick.following();
+                    }
+                }
+            }
+        }
+        Some(Self {
+            counts,
+            known,
+            excluded,
+            unseen: !state.seen,
+        })
+    }
+
+    /// Returns the number of cards held at the specified seat.
+    pub fn count(&self, seat: Seat) -> usize {
+        self.counts[seat]
+    }
+
+    /// Deals a hand to every seat that is consistent with the inferences. Seats that are sitting
+    /// out are dealt nothing.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SeatMap<Vec<Card>> {
+        let mut pool: Vec<Card> = self.unseen.iter().collect();
+        pool.shuffle(rng);
+        // Deal to the most constrained seats first, so they get their pick of the pool.
+        let mut seats = *Seat::all_seats();
+        seats.sort_by_key(|&seat| std::cmp::Reverse(self.excluded[seat].len()));
+        let mut hands = SeatMap::<Vec<Card>>::default();
+        for seat in seats {
+            let hand = &mut hands[seat];
+            hand.extend(self.known[seat]);
+            let count = self.counts[seat];
+            let excluded = self.excluded[seat];
+            pool.retain(|&card| {
+                let take = hand.len() < count && !excluded.contains(card);
+                if take {
+                    hand.push(card);
+                }
+                !take
+            });
+            // If the pool runs dry, an inference was wrong (e.g., the player reneged), so ignore
+            // it.
+            while hand.len() < count {
+                match pool.pop() {
+                    Some(card) => hand.push(card),
+                    None => break,
+                }
+            }
+        }
+        hands
+    }
+}
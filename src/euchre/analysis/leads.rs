@@ -0,0 +1,272 @@
+//! Lead-choice analysis.
+//!
+//! Every completed trick in a corpus of logs is classified by the kind of card that was led, and
+//! the situation of the player who led it. Tricks shared by several branches of a log are only
+//! counted once.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::euchre::{
+    ActionData, ActionType, Card, Contract, FastRound, Log, LogId, Rank, Round, RoundError, Seat,
+    Suit, Team,
+};
+use crate::tr;
+
+/// The kind of card that was led.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LeadCategory {
+    /// The jack of trump.
+    RightBower,
+    /// The jack of the same color as trump.
+    LeftBower,
+    /// The ace or king of trump.
+    HighTrump,
+    /// The queen, ten or nine of trump.
+    LowTrump,
+    /// The ace of a side suit.
+    SideAce,
+    /// The king of a side suit.
+    SideKing,
+    /// Any lower card of a side suit.
+    LowSide,
+}
+
+impl Display for LeadCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            LeadCategory::RightBower => tr!("lead-right-bower"),
+            LeadCategory::LeftBower => tr!("lead-left-bower"),
+            LeadCategory::HighTrump => tr!("lead-high-trump"),
+            LeadCategory::LowTrump => tr!("lead-low-trump"),
+            LeadCategory::SideAce => tr!("lead-side-ace"),
+            LeadCategory::SideKing => tr!("lead-side-king"),
+            LeadCategory::LowSide => tr!("lead-low-side"),
+        })
+    }
+}
+
+impl LeadCategory {
+    /// All categories, from strongest to weakest.
+    pub fn all() -> &'static [LeadCategory; 7] {
+        static CATEGORIES: [LeadCategory; 7] = [
+            LeadCategory::RightBower,
+            LeadCategory::LeftBower,
+            LeadCategory::HighTrump,
+            LeadCategory::LowTrump,
+            LeadCategory::SideAce,
+            LeadCategory::SideKing,
+            LeadCategory::LowSide,
+        ];
+        &CATEGORIES
+    }
+
+    /// Classifies a card, given the trump suit.
+    pub fn of(card: Card, trump: Suit) -> Self {
+        match (card.is_trump(trump), card.rank) {
+            (true, Rank::Jack) if card.suit == trump => LeadCategory::RightBower,
+            (true, Rank::Jack) => LeadCategory::LeftBower,
+            (true, Rank::Ace | Rank::King) => LeadCategory::HighTrump,
+            (true, _) => LeadCategory::LowTrump,
+            (false, Rank::Ace) => LeadCategory::SideAce,
+            (false, Rank::King) => LeadCategory::SideKing,
+            (false, _) => LeadCategory::LowSide,
+        }
+    }
+}
+
+/// The leader's role with respect to the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeadRole {
+    /// The player who declared the contract.
+    Maker,
+    /// The maker's partner.
+    Partner,
+    /// Either of the defenders.
+    Defender,
+}
+
+impl Display for LeadRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            LeadRole::Maker => tr!("lead-maker"),
+            LeadRole::Partner => tr!("lead-partner"),
+            LeadRole::Defender => tr!("lead-defender"),
+        })
+    }
+}
+
+impl LeadRole {
+    /// All roles.
+    pub fn all() -> &'static [LeadRole; 3] {
+        static ROLES: [LeadRole; 3] = [LeadRole::Maker, LeadRole::Partner, LeadRole::Defender];
+        &ROLES
+    }
+
+    /// Returns the role of the specified seat.
+    pub fn of(seat: Seat, contract: Contract) -> Self {
+        if seat == contract.maker {
+            LeadRole::Maker
+        } else if Team::from(seat) == Team::from(contract.maker) {
+            LeadRole::Partner
+        } else {
+            LeadRole::Defender
+        }
+    }
+}
+
+/// A lead, and whether the leader's team won the trick.
+#[derive(Debug, Clone, Copy)]
+pub struct Lead {
+    /// The kind of card led.
+    pub category: LeadCategory,
+    /// The leader's role.
+    pub role: LeadRole,
+    /// The trick number, starting from 1.
+    pub trick: usize,
+    /// Whether the leader's team won the trick.
+    pub won: bool,
+}
+
+/// Counts of leads, and of the tricks they won.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tally {
+    /// The number of leads.
+    pub led: u32,
+    /// The number of tricks won by the leader's team.
+    pub won: u32,
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.led == 0 {
+            f.pad("-")
+        } else {
+            let pct = f64::from(self.won) * 100.0 / f64::from(self.led);
+            f.pad(&format!("{} ({:.0}%)", self.led, pct))
+        }
+    }
+}
+
+/// The results of a lead-choice analysis.
+#[derive(Debug, Clone, Default)]
+pub struct LeadReport {
+    leads: Vec<Lead>,
+}
+
+impl LeadReport {
+    /// Classifies every completed trick in a corpus of logs.
+    pub fn analyze(logs: &[Log]) -> Result<Self, RoundError> {
+        let mut leads = vec![];
+        for log in logs {
+            leads.extend(analyze_log(log)?);
+        }
+        Ok(Self { leads })
+    }
+
+    /// Returns the classified leads.
+    pub fn leads(&self) -> &[Lead] {
+        &self.leads
+    }
+
+    /// Tallies leads by category and by the leader's role. Rows follow [`LeadCategory::all`], and
+    /// columns follow [`LeadRole::all`].
+    pub fn by_role(&self) -> Vec<Vec<Tally>> {
+        self.tally(LeadRole::all(), |l| l.role)
+    }
+
+    /// Tallies leads by category and by trick number. Rows follow [`LeadCategory::all`], and
+    /// there is a column for each of the five tricks.
+    pub fn by_trick(&self) -> Vec<Vec<Tally>> {
+        let numbers: Vec<usize> = (1..=5).collect();
+        self.tally(&numbers, |l| l.trick)
+    }
+
+    /// Tallies leads by category, and by the specified situation.
+    fn tally<K: PartialEq + Copy>(&self, keys: &[K], key: impl Fn(&Lead) -> K) -> Vec<Vec<Tally>> {
+        let mut table = vec![vec![Tally::default(); keys.len()]; LeadCategory::all().len()];
+        for lead in &self.leads {
+            let col = keys
+                .iter()
+                .position(|&k| k == key(lead))
+                .expect("known key");
+            let cell = &mut table[lead.category as usize][col];
+            cell.led += 1;
+            cell.won += u32::from(lead.won);
+        }
+        table
+    }
+}
+
+/// Writes a table of tallies, with a row for each lead category.
+fn write_table(
+    f: &mut std::fmt::Formatter<'_>,
+    headers: &[String],
+    table: &[Vec<Tally>],
+) -> std::fmt::Result {
+    write!(f, "{:<12}", tr!("report-lead"))?;
+    for header in headers {
+        write!(f, " {header:>10}")?;
+    }
+    writeln!(f)?;
+    for (category, row) in LeadCategory::all().iter().zip(table) {
+        write!(f, "{:<12}", category.to_string())?;
+        for tally in row {
+            write!(f, " {tally:>10}")?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+impl Display for LeadReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let roles: Vec<_> = LeadRole::all().iter().map(ToString::to_string).collect();
+        write_table(f, &roles, &self.by_role())?;
+        writeln!(f)?;
+        let tricks: Vec<_> = (1..=5).map(|n| tr!("report-trick-number", n = n)).collect();
+        write_table(f, &tricks, &self.by_trick())
+    }
+}
+
+/// Classifies the completed tricks in a log, following every branch to its end.
+fn analyze_log(log: &Log) -> Result<Vec<Lead>, RoundError> {
+    let parents: HashSet<LogId> = log.action_nodes().filter_map(|n| n.parent).collect();
+    let mut leaves: Vec<LogId> = log
+        .action_nodes()
+        .map(|n| n.id)
+        .filter(|id| !parents.contains(id))
+        .collect();
+    leaves.sort_unstable();
+
+    let mut counted = HashSet::new();
+    let mut leads = vec![];
+    for leaf in leaves {
+        let trace = log.backtrace(leaf)?;
+        let mut round = FastRound::from(log.config().clone());
+        let mut lead_ids = vec![];
+        for &(id, action) in &trace {
+            if let (ActionType::Lead, ActionData::Card { .. }) = (action.action, action.data) {
+                lead_ids.push(id);
+            }
+            round.apply_action(action)?;
+        }
+        let Some(contract) = round.contract() else {
+            continue;
+        };
+        let tricks = round.tricks();
+        for (number, (trick, id)) in tricks.completed().zip(lead_ids).enumerate() {
+            if !counted.insert(id) {
+                continue;
+            }
+            let (leader, card) = trick.lead();
+            leads.push(Lead {
+                category: LeadCategory::of(card, trick.trump),
+                role: LeadRole::of(leader, contract),
+                trick: number + 1,
+                won: Team::from(trick.best().0) == Team::from(leader),
+            });
+        }
+    }
+    Ok(leads)
+}
@@ -0,0 +1,90 @@
+//! Trick-winning odds.
+//!
+//! The odds of taking the trick in progress with each playable card are estimated by dealing
+//! hands to the players still to play that are consistent with what the player has inferred, and
+//! letting robots finish the trick.
+
+use rand::Rng;
+
+use super::Inference;
+use crate::euchre::{ActionData, ActionType, Card, Player, PlayerState, Robot, Trick};
+
+/// Estimates the probability that the player's team takes the trick in progress, for each card
+/// they may play, over the specified number of random deals. Returns `None` if the expected action
+/// isn't a lead or a follow.
+pub fn trick_odds<R: Rng + ?Sized>(
+    state: &PlayerState,
+    action: ActionType,
+    robot: &Robot,
+    samples: usize,
+    rng: &mut R,
+) -> Option<Vec<(Card, f64)>> {
+    if !matches!(action, ActionType::Lead | ActionType::Follow) {
+        return None;
+    }
+    let contract = state.contract?;
+    let inference = Inference::new(state)?;
+    let in_progress = state.tricks.in_progress();
+    let legal = match in_progress {
+        Some(trick) => trick.filter(state.hand),
+        None => state.hand.clone(),
+    };
+
+    // The players still to play into the trick, after this one.
+    let sitting_out = contract.alone.then(|| contract.maker.opposite());
+    let mut remaining = state.tricks.trick_size() - in_progress.map_or(0, Trick::len) - 1;
+    let mut order = vec![];
+    let mut seat = state.seat;
+    while remaining > 0 {
+        seat = seat.next();
+        if Some(seat) != sitting_out {
+            order.push(seat);
+            remaining -= 1;
+        }
+    }
+
+    let mut wins = vec![0_u32; legal.len()];
+    for _ in 0..samples {
+        let hands = inference.sample(rng);
+        for (&card, wins) in legal.iter().zip(&mut wins) {
+            let mut tricks = state.tricks.clone();
+            match tricks.in_progress() {
+                Some(_) => tricks
+                    .last_mut()
+                    .expect("in progress")
+                    .play(state.seat, card),
+                None => tricks.push(Trick::new(contract.suit, state.seat, card)),
+            }
+            for &seat in &order {
+                let hand = &hands[seat];
+                let view = PlayerState::new(
+                    seat,
+                    state.dealer,
+                    state.top,
+                    state.contract,
+                    hand,
+                    hand.iter().collect(),
+                    &tricks,
+                );
+                let ActionData::Card { card } = robot.take_action(view, ActionType::Follow) else {
+                    unreachable!("robot follows with a card");
+                };
+                tricks.last_mut().expect("in progress").play(seat, card);
+            }
+            let trick = tricks.last().expect("trick was played");
+            if trick.best().0.team() == state.seat.team() {
+                *wins += 1;
+            }
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let n = samples.max(1) as f64;
+    Some(
+        legal
+            .into_iter()
+            .zip(wins)
+            .map(|(card, wins)| (card, f64::from(wins) / n))
+            .collect(),
+    )
+}
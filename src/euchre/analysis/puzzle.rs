@@ -0,0 +1,178 @@
+//! Puzzle generation.
+//!
+//! Walks the main line of a round log, and solves every card play with all hands face up. A
+//! position where exactly one of the legal cards still lets the player's team take three tricks
+//! makes a puzzle: find the card that wins. Puzzles are graded by whether the heuristic robot
+//! finds the card, and by how many tricks remain to be played.
+
+use std::fmt::Display;
+use std::fs::File;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::paths::create_file;
+use crate::euchre::{
+    solve, Action, ActionData, Card, FastRound, Log, Player, Robot, Round, RoundConfig, RoundError,
+    Seat,
+};
+
+/// The number of tricks a team must take to win the round, as makers or as defenders.
+const WINNING_TRICKS: u8 = 3;
+
+/// How hard a puzzle is to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// The heuristic robot finds the winning card.
+    Easy,
+    /// The heuristic robot misses the winning card, with three or fewer cards left to play.
+    Medium,
+    /// The heuristic robot misses the winning card, with four or more cards left to play.
+    Hard,
+}
+
+impl Difficulty {
+    /// All difficulties, from easiest to hardest.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    /// Grades a position by whether the robot found the winning card, and by the number of cards
+    /// left in the player's hand.
+    fn grade(robot_solved: bool, hand_size: usize) -> Self {
+        match (robot_solved, hand_size) {
+            (true, _) => Difficulty::Easy,
+            (false, 0..=3) => Difficulty::Medium,
+            (false, _) => Difficulty::Hard,
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        })
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|d| d.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+/// A position in which exactly one card wins the round for the player's team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Puzzle {
+    /// How hard the puzzle is.
+    pub difficulty: Difficulty,
+    /// The deal.
+    pub config: RoundConfig,
+    /// The actions leading to the position, from the deal.
+    pub line: Vec<Action>,
+    /// The player to move.
+    pub seat: Seat,
+    /// The only card that lets the player's team take three tricks.
+    pub solution: Card,
+}
+
+impl Puzzle {
+    /// Finds the puzzles on the main line of the log, which follows the first recorded
+    /// continuation from the deal. Each is graded against the robot.
+    pub fn mine(log: &Log, robot: &Robot) -> Result<Vec<Self>, RoundError> {
+        let mut round = FastRound::from(log.config().clone());
+        let line = match log.first_leaf(None) {
+            Some(leaf) => log.backtrace(leaf)?,
+            None => vec![],
+        };
+        let mut played = vec![];
+        let mut puzzles = vec![];
+        for (_, action) in line {
+            if let Some(solution) = unique_win(&round) {
+                let state = round.player_state(action.seat);
+                let hand_size = state.hand.len();
+                let robot_solved = matches!(
+                    robot.take_action(state, action.action),
+                    ActionData::Card { card } if card == solution
+                );
+                puzzles.push(Self {
+                    difficulty: Difficulty::grade(robot_solved, hand_size),
+                    config: log.config().clone(),
+                    line: played.clone(),
+                    seat: action.seat,
+                    solution,
+                });
+            }
+            round.apply_action(action)?;
+            played.push(action);
+        }
+        Ok(puzzles)
+    }
+
+    /// Sets up the position, by replaying the line from the deal.
+    pub fn round(&self) -> Result<FastRound, RoundError> {
+        let mut round = FastRound::from(self.config.clone());
+        for &action in &self.line {
+            round.apply_action(action)?;
+        }
+        Ok(round)
+    }
+
+    /// Loads a puzzle from a file, and checks that its solution is still the only winning card.
+    pub fn from_json_file(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path)?;
+        let puzzle: Self = serde_json::from_reader(file)?;
+        puzzle.config.validate()?;
+        let round = puzzle.round()?;
+        if unique_win(&round) != Some(puzzle.solution) {
+            anyhow::bail!("{} is not the only winning card", puzzle.solution);
+        }
+        Ok(puzzle)
+    }
+
+    /// Writes the puzzle to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} to play, after {} actions; {} wins",
+            self.difficulty,
+            self.seat,
+            self.line.len(),
+            self.solution
+        )
+    }
+}
+
+/// Returns the only card that lets the next player's team take three tricks, if there's a choice
+/// of cards, and exactly one of them wins.
+fn unique_win<R: Round>(round: &R) -> Option<Card> {
+    let expect = round.next_action()?;
+    let won = round.tricks().win_count(expect.seat.team());
+    let values = solve(round);
+    if values.len() < 2 {
+        return None;
+    }
+    let mut winners = values
+        .iter()
+        .filter(|&&(_, tricks)| won + tricks >= WINNING_TRICKS);
+    match (winners.next(), winners.next()) {
+        (Some(&(card, _)), None) => Some(card),
+        _ => None,
+    }
+}
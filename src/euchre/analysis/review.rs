@@ -0,0 +1,222 @@
+//! Decision review.
+//!
+//! Walks the main line of a round log, and values every decision that had an alternative with
+//! [`action_values`], flagging those that gave up the most points as blunders. The line the robot
+//! would have preferred can also be added to the log, as a branch beside each decision.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::time::Duration;
+
+use rand::Rng;
+
+use super::action_values;
+use crate::euchre::{Action, ActionData, FastRound, Log, Player, Robot, Round, RoundError, Seat};
+use crate::tr;
+
+/// The expected points a decision must give up, relative to the best alternative, to count as a
+/// blunder.
+pub const BLUNDER_POINTS: f64 = 0.5;
+
+/// A decision on the main line of a round, and how it compares with the alternatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    /// The action taken.
+    pub action: Action,
+    /// The average points won by the player's team after the action, if it had alternatives and
+    /// could be valued.
+    pub value: Option<f64>,
+    /// The best alternative to the action, and its value.
+    pub best: Option<(ActionData, f64)>,
+    /// How long the player thought before taking the action, if it was recorded.
+    pub think_time: Option<Duration>,
+}
+
+impl Decision {
+    /// The expected points given up by not taking the best alternative, if any.
+    pub fn loss(&self) -> f64 {
+        match (self.value, self.best) {
+            (Some(value), Some((_, best))) => (best - value).max(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns true if the decision gave up at least [`BLUNDER_POINTS`].
+    pub fn is_blunder(&self) -> bool {
+        self.loss() >= BLUNDER_POINTS
+    }
+}
+
+/// The decisions on the main line of a round, in the order they were taken.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecisionReport {
+    /// The decisions.
+    pub decisions: Vec<Decision>,
+}
+
+impl DecisionReport {
+    /// Values each decision on the main line of the log, which follows the first recorded
+    /// continuation from the deal, over the specified number of random deals.
+    pub fn analyze<R: Rng + ?Sized>(
+        log: &Log,
+        robot: &Robot,
+        rollouts: usize,
+        rng: &mut R,
+    ) -> Result<Self, RoundError> {
+        let mut round = FastRound::from(log.config().clone());
+        let line = match log.first_leaf(None) {
+            Some(leaf) => log.backtrace(leaf)?,
+            None => vec![],
+        };
+        let mut decisions = vec![];
+        for (id, action) in line {
+            let values = if round.legal_actions().len() > 1 {
+                action_values(&round, robot, rollouts, rng)
+            } else {
+                vec![]
+            };
+            let value = values
+                .iter()
+                .find(|&&(d, _)| d == action.data)
+                .map(|&(_, v)| v);
+            let best = values
+                .iter()
+                .filter(|&&(d, _)| d != action.data)
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .copied();
+            let think_time = log
+                .node(id)
+                .and_then(|node| node.timing)
+                .map(|timing| timing.think_time());
+            decisions.push(Decision {
+                action,
+                value,
+                best,
+                think_time,
+            });
+            round.apply_action(action)?;
+        }
+        Ok(Self { decisions })
+    }
+
+    /// Returns the decisions that count as blunders.
+    pub fn blunders(&self) -> impl Iterator<Item = &Decision> {
+        self.decisions.iter().filter(|d| d.is_blunder())
+    }
+
+    /// Returns the average time taken over the decisions that had alternatives, among those
+    /// whose think times were recorded, if any were.
+    pub fn mean_think_time(&self) -> Option<Duration> {
+        let times: Vec<Duration> = self
+            .decisions
+            .iter()
+            .filter(|d| d.value.is_some())
+            .filter_map(|d| d.think_time)
+            .collect();
+        let count = u32::try_from(times.len()).ok().filter(|&n| n > 0)?;
+        Some(times.iter().sum::<Duration>() / count)
+    }
+}
+
+/// Adds the line that the robot prefers at each of the player's decisions on the main line of the
+/// log, as a branch beside the action that was taken, so that the history browser shows what it
+/// would have done. The preferred action is the one with the best value over the specified number
+/// of random deals, and the robot plays out the rest of the round from there. Returns the number
+/// of decisions at which the robot preferred another action.
+pub fn add_robot_lines<R: Rng + ?Sized>(
+    log: &mut Log,
+    seat: Seat,
+    robot: &Robot,
+    rollouts: usize,
+    rng: &mut R,
+) -> Result<usize, RoundError> {
+    let mut round = FastRound::from(log.config().clone());
+    let line = match log.first_leaf(None) {
+        Some(leaf) => log.backtrace(leaf)?,
+        None => vec![],
+    };
+    let mut parent = None;
+    let mut added = 0;
+    for (id, action) in line {
+        let preferred = if action.seat == seat && round.legal_actions().len() > 1 {
+            action_values(&round, robot, rollouts, rng)
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(data, _)| data)
+                .filter(|&data| data != action.data)
+        } else {
+            None
+        };
+        if let Some(data) = preferred {
+            let mut branch = round.clone();
+            let mut next = Some(Action::new(seat, action.action, data));
+            let mut node = parent;
+            while let Some(action) = next {
+                branch.apply_action(action)?;
+                node = Some(log.insert(node, action));
+                next = branch.next_action().map(|expect| {
+                    let state = branch.player_state(expect.seat);
+                    expect.with_data(robot.take_action(state, expect.action))
+                });
+            }
+            added += 1;
+        }
+        round.apply_action(action)?;
+        parent = Some(id);
+    }
+    Ok(added)
+}
+
+impl Display for DecisionReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>3} {:<6} {:<12} {:<14} {:>7} {:<14} {:>7} {:>6}",
+            "#",
+            tr!("report-seat"),
+            tr!("report-type"),
+            tr!("report-action"),
+            tr!("report-value"),
+            tr!("report-best-other"),
+            tr!("report-value"),
+            tr!("report-think")
+        )?;
+        for (i, decision) in self.decisions.iter().enumerate() {
+            let Action { seat, action, data } = decision.action;
+            let value = decision
+                .value
+                .map_or_else(|| "-".into(), |v| format!("{v:+.2}"));
+            let (best, best_value) = decision
+                .best
+                .map_or((String::new(), String::new()), |(d, v)| {
+                    (d.to_string(), format!("{v:+.2}"))
+                });
+            let think = decision
+                .think_time
+                .map_or_else(String::new, |t| format!("{:.1}s", t.as_secs_f64()));
+            let flag = if decision.is_blunder() { " ??" } else { "" };
+            let line = format!(
+                "{:>3} {:<6} {:<12} {:<14} {value:>7} {best:<14} {best_value:>7} {think:>6}{flag}",
+                i + 1,
+                seat.to_string(),
+                action.to_string(),
+                data.to_string(),
+            );
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        let blunders = self.blunders().count();
+        let text = tr!(
+            "report-blunders",
+            blunders = blunders,
+            points = BLUNDER_POINTS
+        );
+        f.write_str(&text)?;
+        match self.mean_think_time() {
+            Some(mean) => {
+                let seconds = format!("{:.1}", mean.as_secs_f64());
+                writeln!(f, "; {}", tr!("report-think-time", seconds = seconds))
+            }
+            None => writeln!(f),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Structural statistics for round logs.
+//!
+//! Logs that are used for analysis can grow large, as alternative lines are explored from many
+//! positions. These statistics describe the shape of the tree, rather than the play.
+
+use std::fmt::Display;
+
+use crate::euchre::{FastRound, Log, LogId, Round, RoundError, Seat, SeatMap};
+
+/// The shape of the action trees in one or more logs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogStats {
+    /// The number of logs.
+    pub logs: usize,
+    /// The number of actions recorded.
+    pub nodes: usize,
+    /// The number of positions from which more than one action was explored.
+    pub branch_points: usize,
+    /// The number of actions in the longest line.
+    pub max_depth: usize,
+    /// The number of distinct lines, from the deal to a leaf.
+    pub lines: usize,
+    /// The number of lines that play the round to completion.
+    pub complete_lines: usize,
+    /// The number of branch points at each seat's decisions.
+    pub branches: SeatMap<usize>,
+}
+
+impl LogStats {
+    /// Measures the specified logs.
+    pub fn analyze(logs: &[Log]) -> Result<Self, RoundError> {
+        let mut stats = Self::default();
+        for log in logs {
+            stats.add(log)?;
+        }
+        Ok(stats)
+    }
+
+    /// Adds a log to the statistics.
+    fn add(&mut self, log: &Log) -> Result<(), RoundError> {
+        self.logs += 1;
+        let mut stack: Vec<(Option<LogId>, usize)> = vec![(None, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            self.max_depth = self.max_depth.max(depth);
+            let children = log.children(id);
+            self.nodes += children.len();
+            match (id, children) {
+                (Some(leaf), []) => {
+                    self.lines += 1;
+                    if is_complete(log, leaf)? {
+                        self.complete_lines += 1;
+                    }
+                }
+                (_, [first, _, ..]) => {
+                    let node = log.node(*first).ok_or(RoundError::InvalidLogId(*first))?;
+                    self.branch_points += 1;
+                    self.branches[node.action.seat] += 1;
+                }
+                _ => (),
+            }
+            stack.extend(children.iter().map(|&child| (Some(child), depth + 1)));
+        }
+        Ok(())
+    }
+
+    /// Returns the seats, ordered by the number of branch points at their decisions, from most to
+    /// fewest.
+    pub fn seats_by_branches(&self) -> Vec<(Seat, usize)> {
+        let mut seats: Vec<_> = self.branches.iter().map(|(s, &n)| (s, n)).collect();
+        seats.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+        seats
+    }
+}
+
+/// Returns true if the line ending at the specified leaf plays the round to completion.
+fn is_complete(log: &Log, leaf: LogId) -> Result<bool, RoundError> {
+    let mut round = FastRound::from(log.config().clone());
+    for (_, action) in log.backtrace(leaf)? {
+        round.apply_action(action)?;
+    }
+    Ok(round.outcome().is_some())
+}
+
+impl Display for LogStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<16} {:>8}", "Logs", self.logs)?;
+        writeln!(f, "{:<16} {:>8}", "Nodes", self.nodes)?;
+        writeln!(f, "{:<16} {:>8}", "Branch points", self.branch_points)?;
+        writeln!(f, "{:<16} {:>8}", "Maximum depth", self.max_depth)?;
+        writeln!(f, "{:<16} {:>8}", "Lines", self.lines)?;
+        writeln!(f, "{:<16} {:>8}", "Complete lines", self.complete_lines)?;
+        writeln!(f)?;
+        writeln!(f, "{:<16} {:>8}", "Seat", "Branches")?;
+        for (seat, count) in self.seats_by_branches() {
+            writeln!(f, "{:<16} {count:>8}", seat.to_string())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+//! Action values.
+//!
+//! Each legal action is valued by dealing the cards the player hasn't seen in a way that is
+//! consistent with what they have inferred, replaying the round so far on that deal, and playing
+//! out the rest of the round with robots, once for each action.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::{determinize_hands, playout, Inference};
+use crate::euchre::{
+    Action, ActionData, ActionType, Card, CardSet, DealPattern, ExpectAction, PlayerState, Robot,
+    Round, RoundConfig, Seat, Team,
+};
+
+/// Estimates the average points won by the next player's team after each legal action, over the
+/// specified number of random deals. Actions are listed in the order given by
+/// [`Round::legal_actions`]. Returns an empty vector if the round is over, or if no deal could be
+/// reconstructed.
+pub fn action_values<D: Round, R: Rng + ?Sized>(
+    round: &D,
+    robot: &Robot,
+    rollouts: usize,
+    rng: &mut R,
+) -> Vec<(ActionData, f64)> {
+    let legal = round.legal_actions();
+    let Some(expect) = round.next_action() else {
+        return vec![];
+    };
+    let state = round.player_state(expect.seat);
+    let team = state.seat.team();
+    let mut totals = vec![0; legal.len()];
+    let mut count = 0_u32;
+    for _ in 0..rollouts {
+        let Some((config, mut prefix)) = reconstruct(&state, expect, rng) else {
+            continue;
+        };
+        let values: Option<Vec<i32>> = legal
+            .iter()
+            .map(|&action| {
+                prefix.push(action);
+                let value = playout(config.clone(), &prefix, robot, team).ok();
+                prefix.pop();
+                value
+            })
+            .collect();
+        if let Some(values) = values {
+            totals.iter_mut().zip(values).for_each(|(t, v)| *t += v);
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return vec![];
+    }
+    legal
+        .iter()
+        .zip(totals)
+        .map(|(action, total)| (action.data, f64::from(total) / f64::from(count)))
+        .collect()
+}
+
+/// Estimates the average points won by the specified team, if the round is played out from the
+/// current position by robots, over the specified number of random deals that are consistent with
+/// what the next player has seen. Returns the outcome if the round is over, or `None` if no deal
+/// could be reconstructed.
+pub fn expected_points<D: Round, R: Rng + ?Sized>(
+    round: &D,
+    team: Team,
+    robot: &Robot,
+    rollouts: usize,
+    rng: &mut R,
+) -> Option<f64> {
+    let Some(expect) = round.next_action() else {
+        let outcome = round.outcome()?;
+        let points = f64::from(outcome.points);
+        return Some(if outcome.team == team {
+            points
+        } else {
+            -points
+        });
+    };
+    let state = round.player_state(expect.seat);
+    let mut total = 0;
+    let mut count = 0_u32;
+    for _ in 0..rollouts {
+        let Some((config, prefix)) = reconstruct(&state, expect, rng) else {
+            continue;
+        };
+        if let Ok(value) = playout(config, &prefix, robot, team) {
+            total += value;
+            count += 1;
+        }
+    }
+    (count > 0).then(|| f64::from(total) / f64::from(count))
+}
+
+/// Deals a round that is consistent with the player's view, and returns it along with the actions
+/// that lead to the current position, where the expected action is next.
+pub(super) fn reconstruct<R: Rng + ?Sized>(
+    state: &PlayerState,
+    expect: ExpectAction,
+    rng: &mut R,
+) -> Option<(RoundConfig, Vec<Action>)> {
+    let next = expect.action;
+    let dealer = state.dealer;
+    let pass = |seat, action| Action::new(seat, action, ActionData::Pass);
+    let Some(contract) = state.contract else {
+        // Everyone before the player to act has passed.
+        let mut prefix = vec![];
+        if next == ActionType::BidOther {
+            prefix.extend(
+                dealer
+                    .next_n(4)
+                    .iter()
+                    .map(|&s| pass(s, ActionType::BidTop)),
+            );
+        }
+        let mut seat = dealer.next();
+        while seat != expect.seat {
+            prefix.push(pass(seat, next));
+            seat = seat.next();
+        }
+        let hands = determinize_hands(state.seat, state.hand, state.seen, rng);
+        let config = RoundConfig::from_hands(dealer, hands, state.top, DealPattern::default());
+        return Some((config.ok()?, prefix));
+    };
+
+    // The bidding, up to the maker's call. The top card's suit can only be called on the first
+    // round.
+    let mut prefix = vec![];
+    let bid = if contract.suit == state.top.suit {
+        ActionType::BidTop
+    } else {
+        prefix.extend(
+            dealer
+                .next_n(4)
+                .iter()
+                .map(|&s| pass(s, ActionType::BidTop)),
+        );
+        ActionType::BidOther
+    };
+    let mut seat = dealer.next();
+    while seat != contract.maker {
+        prefix.push(pass(seat, bid));
+        seat = seat.next();
+    }
+    let call = ActionData::Call {
+        suit: contract.suit,
+        alone: contract.alone,
+    };
+    prefix.push(Action::new(contract.maker, bid, call));
+
+    // Put the cards that have been played back into the hands they came from.
+    let mut hands = Inference::new(state)?.sample(rng);
+    for &seat in Seat::all_seats() {
+        hands[seat].extend(state.tricks.cards_played_by(seat));
+    }
+    let mut discard = None;
+    let picked_up = bid == ActionType::BidTop && hands[dealer].contains(&state.top);
+    if picked_up && hands[dealer].len() == 5 {
+        // The dealer's discard is the only card they've seen that isn't accounted for. Anyone
+        // else assumes it was one of the cards they haven't seen. If the dealer has yet to
+        // discard, it's still in their hand.
+        let dealt: CardSet = hands.values().flatten().copied().collect();
+        let candidates = if state.seat == dealer {
+            state.seen - dealt
+        } else {
+            !(state.seen | dealt)
+        };
+        let candidates: Vec<Card> = candidates.iter().collect();
+        let card = *candidates.choose(rng)?;
+        hands[dealer].push(card);
+        if next != ActionType::DealerDiscard {
+            discard = Some(card);
+        }
+    }
+    if picked_up {
+        hands[dealer].retain(|&c| c != state.top);
+    }
+    if let Some(card) = discard {
+        prefix.push(Action::new(
+            dealer,
+            ActionType::DealerDiscard,
+            ActionData::Card { card },
+        ));
+    }
+
+    // A seat sitting out is dealt whatever is left over; its cards are never played.
+    let dealt: CardSet = hands.values().flatten().chain([&state.top]).collect();
+    let mut rest = (!dealt).iter();
+    for hand in hands.values_mut().filter(|h| h.is_empty()) {
+        hand.extend(rest.by_ref().take(5));
+    }
+
+    for trick in state.tricks {
+        for (i, &(seat, card)) in trick.cards().iter().enumerate() {
+            let action = if i == 0 {
+                ActionType::Lead
+            } else {
+                ActionType::Follow
+            };
+            prefix.push(Action::new(seat, action, ActionData::Card { card }));
+        }
+    }
+    let config = RoundConfig::from_hands(dealer, hands, state.top, DealPattern::default());
+    Some((config.ok()?, prefix))
+}
@@ -0,0 +1,379 @@
+use std::time::Duration;
+
+use crate::euchre::{solve, ActionData, ActionType, Game, LoggingRound, Player, Suit};
+
+use super::*;
+
+#[test]
+fn test_analyze_bidding() {
+    let robot = Robot::default();
+    let mut round = LoggingRound::random();
+    let mut bids = 0;
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        let forced = expect.action == ActionType::BidOther && expect.seat == round.dealer();
+        if matches!(expect.action, ActionType::BidTop | ActionType::BidOther) && !forced {
+            bids += 1;
+        }
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+
+    let logs = [round.log().clone()];
+    let report = BiddingAnalyzer::default()
+        .with_rollouts(4)
+        .analyze(&logs)
+        .unwrap();
+    assert_eq!(report.decisions().len(), bids);
+    let rows = report.rows();
+    let total: u32 = rows.iter().map(|r| r.passes + r.calls).sum();
+    assert_eq!(total as usize, bids);
+    assert_eq!(report.to_csv().lines().count(), rows.len() + 1);
+}
+
+#[test]
+fn test_determinize() {
+    let config = RoundConfig::random();
+    let seat = Seat::East;
+    let seen: CardSet = config.hand(seat).iter().chain([&config.top()]).collect();
+    let dealt = determinize(&config, seat, seen, &mut rand::thread_rng());
+    assert_eq!(dealt.hand(seat), config.hand(seat));
+    assert_eq!(dealt.top(), config.top());
+    assert_eq!(dealt.dealer(), config.dealer());
+}
+
+#[test]
+fn test_lead_category() {
+    use crate::euchre::Rank;
+    let trump = Suit::Heart;
+    let cases = [
+        (Card::new(Rank::Jack, Suit::Heart), LeadCategory::RightBower),
+        (
+            Card::new(Rank::Jack, Suit::Diamond),
+            LeadCategory::LeftBower,
+        ),
+        (Card::new(Rank::King, Suit::Heart), LeadCategory::HighTrump),
+        (Card::new(Rank::Nine, Suit::Heart), LeadCategory::LowTrump),
+        (Card::new(Rank::Ace, Suit::Spade), LeadCategory::SideAce),
+        (Card::new(Rank::King, Suit::Club), LeadCategory::SideKing),
+        (Card::new(Rank::Jack, Suit::Club), LeadCategory::LowSide),
+    ];
+    for (card, category) in cases {
+        assert_eq!(LeadCategory::of(card, trump), category, "{card}");
+    }
+}
+
+#[test]
+fn test_lead_report() {
+    let robot = Robot::default();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+    let tricks = round.tricks().completed().count();
+
+    let report = LeadReport::analyze(&[round.log().clone()]).unwrap();
+    assert_eq!(report.leads().len(), tricks);
+    for (i, lead) in report.leads().iter().enumerate() {
+        assert_eq!(lead.trick, i + 1);
+    }
+    assert!(report.to_string().contains("Right bower"));
+}
+
+#[test]
+fn test_trick_odds() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        let state = round.player_state(expect.seat);
+        if matches!(expect.action, ActionType::Lead | ActionType::Follow) {
+            let odds = trick_odds(&state, expect.action, &robot, 8, &mut rng).unwrap();
+            let legal = match state.tricks.in_progress() {
+                Some(trick) => trick.filter(state.hand),
+                None => state.hand.clone(),
+            };
+            assert_eq!(odds.iter().map(|&(c, _)| c).collect::<Vec<_>>(), legal);
+            assert!(odds.iter().all(|&(_, p)| (0.0..=1.0).contains(&p)));
+
+            // The last card to be played determines the outcome.
+            let last = state
+                .tricks
+                .in_progress()
+                .is_some_and(|t| t.len() + 1 == state.tricks.trick_size());
+            if last {
+                assert!(odds.iter().all(|&(_, p)| p == 0.0 || p == 1.0));
+            }
+        } else {
+            assert!(trick_odds(&state, expect.action, &robot, 8, &mut rng).is_none());
+        }
+        let data = robot.take_action(state, expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+}
+
+#[test]
+fn test_action_values() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+        let mut round = LoggingRound::random();
+        while let Some(expect) = round.next_action() {
+            let legal = round.legal_actions();
+            let values = action_values(&round, &robot, 4, &mut rng);
+            assert_eq!(
+                values.iter().map(|&(d, _)| d).collect::<Vec<_>>(),
+                legal.iter().map(|a| a.data).collect::<Vec<_>>(),
+                "{expect:?}"
+            );
+            assert!(values.iter().all(|&(_, v)| (-4.0..=4.0).contains(&v)));
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        assert!(action_values(&round, &robot, 4, &mut rng).is_empty());
+    }
+}
+
+#[test]
+fn test_expected_points() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        for team in [Team::NorthSouth, Team::EastWest] {
+            let value = expected_points(&round, team, &robot, 4, &mut rng);
+            let value = value.unwrap_or_else(|| panic!("{:?}", expect));
+            assert!((-4.0..=4.0).contains(&value), "{:?}", expect);
+        }
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+
+    // Once the round is over, the value is the outcome.
+    let outcome = round.outcome().unwrap();
+    let points = f64::from(outcome.points);
+    let mut value = |team| expected_points(&round, team, &robot, 4, &mut rng);
+    assert_eq!(value(outcome.team), Some(points));
+    assert_eq!(value(outcome.team.other()), Some(-points));
+}
+
+#[test]
+fn test_win_probability() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    let mut game = Game::from(LoggingRound::random()).with_target_score(1);
+    while let Some(expect) = game.round().next_action() {
+        // Every player can estimate their chances, whether or not it's their turn.
+        for &seat in Seat::all_seats() {
+            let state = game.round().player_state(seat);
+            let p = win_probability(&game, &state, &robot, 4, &mut rng);
+            let p = p.unwrap_or_else(|| panic!("{:?} {:?}", seat, expect));
+            assert!((0.0..=1.0).contains(&p), "{:?}", expect);
+        }
+        let data = robot.take_action(game.round().player_state(expect.seat), expect.action);
+        game.round_mut()
+            .apply_action(expect.with_data(data))
+            .unwrap();
+    }
+
+    // Once the round is over, the first to a single point has won.
+    let winner = game.round().outcome().unwrap().team;
+    for &seat in Seat::all_seats() {
+        let state = game.round().player_state(seat);
+        let p = win_probability(&game, &state, &robot, 4, &mut rng).unwrap();
+        assert_eq!(p, if seat.team() == winner { 1.0 } else { 0.0 });
+    }
+}
+
+#[test]
+fn test_log_stats() {
+    let robot = Robot::default();
+    let mut round = LoggingRound::random();
+    let mut depth = 0;
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+        depth += 1;
+    }
+
+    // Branch from the first bid, with a pass or a call, whichever wasn't played.
+    let first = round.dealer().next();
+    round.seek(None).unwrap();
+    let alternatives = [
+        ActionData::Pass,
+        ActionData::Call {
+            suit: round.top_card().suit,
+            alone: false,
+        },
+    ];
+    let played = round.log().node(0).unwrap().action.data;
+    let data = alternatives.iter().find(|&&d| d != played).unwrap();
+    round
+        .apply_action(Action::new(first, ActionType::BidTop, *data))
+        .unwrap();
+
+    let stats = LogStats::analyze(&[round.log().clone()]).unwrap();
+    assert_eq!(stats.logs, 1);
+    assert_eq!(stats.nodes, depth + 1);
+    assert_eq!(stats.branch_points, 1);
+    assert_eq!(stats.max_depth, depth);
+    assert_eq!(stats.lines, 2);
+    assert_eq!(stats.complete_lines, 1);
+    assert_eq!(stats.seats_by_branches()[0], (first, 1));
+}
+
+#[test]
+fn test_decision_report() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    let mut round = LoggingRound::random();
+    let mut forced = 0;
+    while let Some(expect) = round.next_action() {
+        if round.legal_actions().len() == 1 {
+            forced += 1;
+        }
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+        round.record_think_time(Duration::from_millis(1500));
+    }
+
+    let report = DecisionReport::analyze(round.log(), &robot, 4, &mut rng).unwrap();
+    let actions: Vec<Action> = report.decisions.iter().map(|d| d.action).collect();
+    let line = round.log().backtrace(round.cursor().unwrap()).unwrap();
+    assert_eq!(actions, line.iter().map(|&(_, a)| a).collect::<Vec<_>>());
+    let unvalued = report.decisions.iter().filter(|d| d.value.is_none());
+    assert!(unvalued.count() >= forced);
+    for decision in &report.decisions {
+        assert!(decision.loss() >= 0.0);
+        assert_eq!(decision.is_blunder(), decision.loss() >= BLUNDER_POINTS);
+        assert_eq!(decision.think_time, Some(Duration::from_millis(1500)));
+    }
+    if report.decisions.iter().any(|d| d.value.is_some()) {
+        assert_eq!(report.mean_think_time(), Some(Duration::from_millis(1500)));
+    }
+    let text = report.to_string();
+    assert_eq!(text.lines().count(), report.decisions.len() + 2);
+}
+
+#[test]
+fn test_diary() {
+    let robot = Robot::default();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+        round.record_think_time(Duration::from_millis(1500));
+    }
+
+    let diary = Diary::new(&[round.log().clone()], Seat::South).unwrap();
+    assert_eq!(diary.rounds.len(), 1);
+    let line = round.log().backtrace(round.cursor().unwrap()).unwrap();
+    let entries = &diary.rounds[0].entries;
+    let decisions: Vec<ActionData> = entries.iter().map(|e| e.decision).collect();
+    let expected: Vec<ActionData> = line
+        .iter()
+        .filter(|(_, a)| a.seat == Seat::South)
+        .map(|(_, a)| a.data)
+        .collect();
+    assert_eq!(decisions, expected);
+    for entry in entries {
+        assert!(entry.legal.contains(&entry.decision));
+        assert_eq!(entry.think_ms, Some(1500));
+        // The history holds every earlier action but other seats' discards.
+        let earlier = &line[..entry.step - 1];
+        let visible = earlier
+            .iter()
+            .filter(|(_, a)| a.seat == Seat::South || a.action != ActionType::DealerDiscard);
+        assert_eq!(entry.history.len(), visible.count());
+    }
+    let json = serde_json::to_value(&diary).unwrap();
+    assert_eq!(
+        json["rounds"][0]["entries"].as_array().unwrap().len(),
+        entries.len()
+    );
+}
+
+#[test]
+fn test_add_robot_lines() {
+    let robot = Robot::default();
+    let mut rng = rand::thread_rng();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        // South plays the first legal action, which the robot often wouldn't.
+        let action = if expect.seat == Seat::South {
+            round.legal_actions()[0]
+        } else {
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            expect.with_data(data)
+        };
+        round.apply_action(action).unwrap();
+    }
+    let cursor = round.cursor();
+    let mut log = round.log().clone();
+    let added = add_robot_lines(&mut log, Seat::South, &robot, 4, &mut rng).unwrap();
+
+    // The main line is undisturbed, and each branch point has a single alternative, taken by
+    // South, which is played out to the end of the round.
+    assert_eq!(log.first_leaf(None), cursor);
+    let mut branches = 0;
+    for node in log.action_nodes() {
+        if node.id > cursor.unwrap() && log.children(node.parent)[0] != node.id {
+            assert_eq!(node.action.seat, Seat::South);
+            assert_eq!(log.children(node.parent).len(), 2);
+            branches += 1;
+            let leaf = log.first_leaf(Some(node.id)).unwrap();
+            let mut branch = FastRound::from(log.config().clone());
+            for (_, action) in log.backtrace(leaf).unwrap() {
+                branch.apply_action(action).unwrap();
+            }
+            assert!(branch.outcome().is_some());
+        }
+    }
+    assert_eq!(branches, added);
+}
+
+#[test]
+fn test_difficulty() {
+    for difficulty in Difficulty::ALL {
+        assert_eq!(difficulty.to_string().parse(), Ok(difficulty));
+    }
+    assert_eq!("HARD".parse(), Ok(Difficulty::Hard));
+    assert_eq!("expert".parse::<Difficulty>(), Err(()));
+}
+
+#[test]
+fn test_mine_puzzles() {
+    let robot = Robot::default();
+    let mut puzzles = vec![];
+    for _ in 0..100 {
+        let mut round = LoggingRound::random();
+        while let Some(expect) = round.next_action() {
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        puzzles.extend(Puzzle::mine(round.log(), &robot).unwrap());
+        if puzzles.len() >= 3 {
+            break;
+        }
+    }
+    assert!(!puzzles.is_empty());
+
+    let dir = std::env::temp_dir().join(format!("deckard-puzzles-{}", std::process::id()));
+    for (i, puzzle) in puzzles.iter().enumerate() {
+        let round = puzzle.round().unwrap();
+        let expect = round.next_action().unwrap();
+        assert_eq!(expect.seat, puzzle.seat);
+        assert!(matches!(
+            expect.action,
+            ActionType::Lead | ActionType::Follow
+        ));
+        let values = solve(&round);
+        assert!(values.len() >= 2);
+        assert!(values.iter().any(|&(card, _)| card == puzzle.solution));
+
+        let path = dir.join(format!("{i}.json"));
+        puzzle.save(&path).unwrap();
+        assert_eq!(&Puzzle::from_json_file(&path).unwrap(), puzzle);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
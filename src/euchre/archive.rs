@@ -0,0 +1,100 @@
+//! An archive of finished games.
+//!
+//! Each game is archived as a saved game, in the state it was in when its final round ended, so
+//! that the round can be reviewed when the game is opened. Files are named by the date and a hash
+//! of their contents, so archiving the same game twice keeps a single copy.
+
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsStr;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use super::paths::create_file;
+use super::{GameOutcome, RawGame, Round, Rules};
+
+#[cfg(test)]
+mod test;
+
+/// A summary of an archived game.
+#[derive(Debug, Clone)]
+pub struct ArchivedGame {
+    /// The archive file.
+    pub path: PathBuf,
+    /// The date on which the game was archived, as `YYYY-MM-DD`, if known.
+    pub date: String,
+    /// The outcome of the game.
+    pub outcome: GameOutcome,
+    /// The rules the game was played by.
+    pub rules: Rules,
+}
+
+impl ArchivedGame {
+    /// Reads an archived game, returning `None` if the file isn't a finished game.
+    fn read(path: PathBuf) -> Option<Self> {
+        let raw = RawGame::from_json_file(&path).ok()?;
+        let date = raw.metadata().map(|m| m.date.clone()).unwrap_or_default();
+        let mut game = raw.into_game().ok()?;
+        if game.round().outcome().is_some() {
+            game.score_round();
+        }
+        Some(Self {
+            path,
+            date,
+            outcome: game.outcome()?,
+            rules: game.rules(),
+        })
+    }
+}
+
+/// A directory of finished games.
+#[derive(Debug, Clone)]
+pub struct Archive {
+    dir: PathBuf,
+}
+
+impl Archive {
+    /// Opens the archive in the specified directory, which is created when the first game is
+    /// stored.
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// Stores a finished game, and returns the path of the archive file.
+    pub fn store(&self, game: &RawGame) -> anyhow::Result<PathBuf> {
+        let json = serde_json::to_string(game)?;
+        let mut hasher = DefaultHasher::new();
+        json.hash(&mut hasher);
+        let date = game.metadata().map_or("", |m| m.date.as_str());
+        let name = if date.is_empty() {
+            format!("{:016x}.json", hasher.finish())
+        } else {
+            format!("{date}-{:016x}.json", hasher.finish())
+        };
+        let path = self.dir.join(name);
+        create_file(&path)?.write_all(json.as_bytes())?;
+        Ok(path)
+    }
+
+    /// Lists the archived games, most recent first. Files that aren't finished games are
+    /// skipped, and a missing directory is treated as empty.
+    pub fn list(&self) -> anyhow::Result<Vec<ArchivedGame>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+        let mut paths = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension() == Some(OsStr::new("json")) {
+                paths.push(path);
+            }
+        }
+        paths.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(paths.into_iter().filter_map(ArchivedGame::read).collect())
+    }
+}
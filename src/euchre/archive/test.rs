@@ -0,0 +1,57 @@
+use super::*;
+use crate::euchre::{Game, LoggingRound, Metadata, Player, Robot};
+
+/// Plays a game to the end with robots, stopping once its final round is over, before it's
+/// scored.
+fn finished_game() -> Game<LoggingRound> {
+    let robot = Robot::default();
+    let mut game = Game::from(LoggingRound::random()).with_target_score(5);
+    loop {
+        while let Some(expect) = game.round().next_action() {
+            let state = game.round().player_state(expect.seat);
+            let data = robot.take_action(state, expect.action);
+            game.round_mut()
+                .apply_action(expect.with_data(data))
+                .unwrap();
+        }
+        let outcome = game.round().outcome().unwrap();
+        if game.score(outcome.team) + outcome.points >= game.target_score() {
+            return game;
+        }
+        game.next_round();
+    }
+}
+
+#[test]
+fn test_archive() {
+    let dir = std::env::temp_dir().join(format!("deckard-archive-{}", std::process::id()));
+    let archive = Archive::new(&dir);
+    assert!(archive.list().unwrap().is_empty());
+
+    let game = finished_game();
+    let metadata = Metadata {
+        date: "2024-01-02".into(),
+        ..Metadata::default()
+    };
+    let raw = RawGame::from(&game).with_metadata(metadata);
+    let path = archive.store(&raw).unwrap();
+    assert_eq!(archive.store(&raw).unwrap(), path);
+
+    let games = archive.list().unwrap();
+    assert_eq!(games.len(), 1);
+    let archived = &games[0];
+    assert_eq!(archived.path, path);
+    assert_eq!(archived.date, "2024-01-02");
+    assert_eq!(archived.rules, game.rules());
+    assert_eq!(archived.outcome.rounds, game.rounds() + 1);
+    let winner = archived.outcome.winner;
+    assert!(archived.outcome.score[winner] >= 5);
+    assert!(archived.outcome.score[winner.other()] < 5);
+
+    // Games in progress aren't listed.
+    let mut unfinished = Game::from(LoggingRound::random());
+    unfinished.round_mut().pop_event();
+    archive.store(&RawGame::from(&unfinished)).unwrap();
+    assert_eq!(archive.list().unwrap().len(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,222 @@
+//! Throughput benchmarks.
+//!
+//! Measures how quickly this machine plays out rounds, on the full round used by the UIs and on
+//! the fast round used for simulation, and how long the robots take to decide. Every benchmark
+//! plays the same seeded deals, so runs are comparable across builds.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::{BaseRound, FastRound, Player, Robot, Round, RoundConfig, RoundError, SearchRobot};
+
+#[cfg(test)]
+mod test;
+
+/// The timing of one benchmark.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    /// What was measured.
+    pub name: &'static str,
+    /// What was counted, e.g. rounds or decisions.
+    pub unit: &'static str,
+    /// The number of times it was done.
+    pub count: u32,
+    /// The total time taken.
+    pub elapsed: Duration,
+}
+
+impl Measurement {
+    /// The number done per second.
+    pub fn rate(&self) -> f64 {
+        f64::from(self.count) / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// The average time taken for each.
+    pub fn mean(&self) -> Duration {
+        self.elapsed / self.count.max(1)
+    }
+}
+
+/// The results of a benchmark run.
+#[derive(Debug, Clone, Default)]
+pub struct BenchReport {
+    /// The measurements, in the order they were taken.
+    pub measurements: Vec<Measurement>,
+}
+
+impl BenchReport {
+    /// Returns the measurement with the specified name.
+    pub fn get(&self, name: &str) -> Option<&Measurement> {
+        self.measurements.iter().find(|m| m.name == name)
+    }
+
+    /// The ratio of the fast round's throughput to the base round's.
+    pub fn speedup(&self) -> Option<f64> {
+        let base = self.get(Bench::BASE_ROUND)?;
+        let fast = self.get(Bench::FAST_ROUND)?;
+        Some(fast.rate() / base.rate())
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:>8} {:<10} {:>10} {:>12} {:>10}",
+            "Benchmark", "Count", "", "Total", "Per second", "Mean"
+        )?;
+        for m in &self.measurements {
+            writeln!(
+                f,
+                "{:<24} {:>8} {:<10} {:>10} {:>12.0} {:>10}",
+                m.name,
+                m.count,
+                m.unit,
+                format!("{:.2?}", m.elapsed),
+                m.rate(),
+                format!("{:.2?}", m.mean()),
+            )?;
+        }
+        if let Some(speedup) = self.speedup() {
+            writeln!(
+                f,
+                "The fast round is {speedup:.1}x faster than the base round."
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Benchmarks round throughput and robot latency on seeded deals.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    seed: u64,
+    rounds: usize,
+    searches: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            rounds: 2000,
+            searches: 20,
+        }
+    }
+}
+
+impl Bench {
+    /// The name of the base round benchmark.
+    pub const BASE_ROUND: &'static str = "Base round";
+    /// The name of the fast round benchmark.
+    pub const FAST_ROUND: &'static str = "Fast round";
+    /// The name of the robot decision benchmark.
+    pub const ROBOT: &'static str = "Robot decision";
+    /// The name of the searching robot decision benchmark.
+    pub const SEARCH_ROBOT: &'static str = "Search robot decision";
+
+    /// Sets the seed for the deals.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the number of rounds played out on each round implementation.
+    pub fn with_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds.max(1);
+        self
+    }
+
+    /// Sets the number of rounds in which the searching robot's decisions are timed. It's much
+    /// slower than the others.
+    pub fn with_searches(mut self, searches: usize) -> Self {
+        self.searches = searches;
+        self
+    }
+
+    /// Runs the benchmarks.
+    pub fn run(&self) -> Result<BenchReport, RoundError> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let configs: Vec<RoundConfig> = (0..self.rounds).map(|_| rng.gen()).collect();
+        let robot = Robot::default();
+        let mut report = BenchReport::default();
+
+        let start = Instant::now();
+        for config in &configs {
+            play_out(BaseRound::from(config.clone()), &robot)?;
+        }
+        report
+            .measurements
+            .push(rounds(Self::BASE_ROUND, &configs, start));
+
+        let start = Instant::now();
+        for config in &configs {
+            FastRound::from(config.clone()).play_out(&robot)?;
+        }
+        report
+            .measurements
+            .push(rounds(Self::FAST_ROUND, &configs, start));
+
+        report
+            .measurements
+            .push(decisions(Self::ROBOT, &configs, &robot)?);
+        let searches = &configs[..self.searches.min(configs.len())];
+        if !searches.is_empty() {
+            let search = SearchRobot::default();
+            report
+                .measurements
+                .push(decisions(Self::SEARCH_ROBOT, searches, &search)?);
+        }
+        Ok(report)
+    }
+}
+
+/// Plays the round to completion, with the robot taking every action.
+fn play_out<R: Round>(mut round: R, robot: &Robot) -> Result<(), RoundError> {
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data))?;
+    }
+    Ok(())
+}
+
+/// Measures the rounds played since the start.
+fn rounds(name: &'static str, configs: &[RoundConfig], start: Instant) -> Measurement {
+    Measurement {
+        name,
+        unit: "rounds",
+        count: u32::try_from(configs.len()).unwrap_or(u32::MAX),
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Plays out the rounds with the player, timing each of its decisions.
+fn decisions<P: Player>(
+    name: &'static str,
+    configs: &[RoundConfig],
+    player: &P,
+) -> Result<Measurement, RoundError> {
+    let mut count = 0;
+    let mut elapsed = Duration::ZERO;
+    for config in configs {
+        let mut round = FastRound::from(config.clone());
+        while let Some(expect) = round.next_action() {
+            let state = round.player_state(expect.seat);
+            let start = Instant::now();
+            let data = player.take_action(state, expect.action);
+            elapsed += start.elapsed();
+            count += 1;
+            round.apply_action(expect.with_data(data))?;
+        }
+    }
+    Ok(Measurement {
+        name,
+        unit: "decisions",
+        count,
+        elapsed,
+    })
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn test_bench() {
+    let report = Bench::default()
+        .with_seed(5)
+        .with_rounds(10)
+        .with_searches(1)
+        .run()
+        .unwrap();
+    let names: Vec<&str> = report.measurements.iter().map(|m| m.name).collect();
+    assert_eq!(
+        names,
+        [
+            Bench::BASE_ROUND,
+            Bench::FAST_ROUND,
+            Bench::ROBOT,
+            Bench::SEARCH_ROBOT
+        ]
+    );
+    assert_eq!(report.get(Bench::FAST_ROUND).unwrap().count, 10);
+    // Every seat bids at least once in each round.
+    let robot = report.get(Bench::ROBOT).unwrap();
+    assert!(robot.count >= 10 * 4, "{}", robot.count);
+    assert!(report.speedup().is_some());
+
+    let table = report.to_string();
+    assert!(table.contains("Search robot decision"), "{}", table);
+    assert!(table.contains("faster than the base round"), "{}", table);
+}
+
+#[test]
+fn test_measurement() {
+    let m = Measurement {
+        name: "x",
+        unit: "rounds",
+        count: 4,
+        elapsed: Duration::from_millis(2),
+    };
+    assert_eq!(m.mean(), Duration::from_micros(500));
+    assert!((m.rate() - 2000.0).abs() < 1e-6, "{}", m.rate());
+}
@@ -1,9 +1,12 @@
 //! Euchre deck.
 
 use std::convert::{TryFrom, TryInto};
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Not, Sub};
 use std::{fmt::Display, str::FromStr};
 
 use ansi_term::ANSIString;
+#[cfg(feature = "tui")]
 use ratatui::text::Span;
 use serde::{Deserialize, Serialize};
 
@@ -85,20 +88,20 @@ pub struct Card {
 }
 impl From<Card> for french::Card {
     fn from(card: Card) -> Self {
-        french::Card {
-            rank: card.rank.into(),
-            suit: card.suit,
-        }
+        french::Card::new(card.rank.into(), card.suit)
     }
 }
 impl TryFrom<french::Card> for Card {
     type Error = ();
 
     fn try_from(card: french::Card) -> Result<Self, Self::Error> {
-        Ok(Card {
-            rank: card.rank.try_into()?,
-            suit: card.suit,
-        })
+        match card {
+            french::Card::Suited { rank, suit } => Ok(Card {
+                rank: rank.try_into()?,
+                suit,
+            }),
+            french::Card::Joker | french::Card::BigJoker => Err(()),
+        }
     }
 }
 impl Display for Card {
@@ -113,6 +116,16 @@ impl FromStr for Card {
         french::Card::from_str(s)?.try_into()
     }
 }
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Card {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_index(u.int_in_range(0..=23)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
 impl Serialize for Card {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -132,12 +145,27 @@ impl<'de> Deserialize<'de> for Card {
     }
 }
 
+/// Suits in the order used for [`Card::index`].
+const INDEX_SUITS: [Suit; 4] = [Suit::Club, Suit::Diamond, Suit::Spade, Suit::Heart];
+
 impl Card {
     /// Creates a new [`Card`].
     pub fn new(rank: Rank, suit: Suit) -> Self {
         Self { rank, suit }
     }
 
+    /// Returns a unique index for this card, in the range `0..24`.
+    pub fn index(self) -> u8 {
+        self.suit as u8 * 6 + self.rank as u8
+    }
+
+    /// Returns the card for an index produced by [`Card::index`].
+    pub fn from_index(index: u8) -> Self {
+        let suit = INDEX_SUITS[usize::from(index / 6)];
+        let rank = Rank::all_ranks()[usize::from(index % 6)];
+        Self { rank, suit }
+    }
+
     /// Returns a string representation of the card, decorated with ANSI color codes.
     pub fn to_ansi_string(self) -> ANSIString<'static> {
         use ansi_term::Colour::Red;
@@ -148,6 +176,7 @@ impl Card {
     }
 
     /// Returns a [`ratatui::text::Span`] for the card.
+    #[cfg(feature = "tui")]
     pub fn to_span(self) -> Span<'static> {
         use ratatui::style::Color;
         match self.suit {
@@ -159,16 +188,12 @@ impl Card {
     /// Returns true if the card is consindered to be trump, given the suit declared in the
     /// contract.
     pub fn is_trump(self, trump: Suit) -> bool {
-        self.suit == trump || matches!(self.rank, Rank::Jack) && self.suit.color() == trump.color()
+        self.effective_suit(trump) == trump
     }
 
     /// Returns the effective suit for this card, given the suit declared in the contract.
     pub fn effective_suit(self, trump: Suit) -> Suit {
-        if self.is_trump(trump) {
-            trump
-        } else {
-            self.suit
-        }
+        EFFECTIVE_SUITS[trump as usize][usize::from(self.index())]
     }
 
     /// Returns true if the played card is the same effective suit as the card that was lead.
@@ -178,42 +203,331 @@ impl Card {
 
     /// Returns the value of the card, for determining the winner of a trick.
     pub fn value(self, trump: Suit, lead: Card) -> u8 {
-        if self.is_trump(trump) {
-            match self.rank {
-                Rank::Nine => 7,
-                Rank::Ten => 8,
-                Rank::Queen => 9,
-                Rank::King => 10,
-                Rank::Ace => 11,
-                Rank::Jack => {
-                    if self.suit == trump {
+        let lead = lead.effective_suit(trump);
+        VALUES[trump as usize][lead as usize][usize::from(self.index())]
+    }
+}
+
+/// Returns true if the suits, given by discriminant, are the same color. Clubs and spades, and
+/// diamonds and hearts, are two apart.
+const fn same_color(a: usize, b: usize) -> bool {
+    a % 2 == b % 2
+}
+
+/// The effective suit of each card, indexed by trump suit and [`Card::index`].
+const EFFECTIVE_SUITS: [[Suit; 24]; 4] = {
+    let mut table = [[Suit::Club; 24]; 4];
+    let mut trump = 0;
+    while trump < 4 {
+        let mut index = 0;
+        while index < 24 {
+            let (suit, rank) = (index / 6, index % 6);
+            let is_left = rank == Rank::Jack as usize && same_color(suit, trump);
+            table[trump][index] = INDEX_SUITS[if is_left { trump } else { suit }];
+            index += 1;
+        }
+        trump += 1;
+    }
+    table
+};
+
+/// The value of each card for determining the winner of a trick, indexed by trump suit, the
+/// effective suit of the card that was lead, and [`Card::index`]. The right bower is worth 13 and
+/// the left 12, other trumps 7 to 11, cards following the lead 1 to 6, and the rest nothing.
+const VALUES: [[[u8; 24]; 4]; 4] = {
+    /// Trump values by rank; the jacks are handled separately.
+    const TRUMP_VALUES: [u8; 6] = [7, 8, 0, 9, 10, 11];
+    let mut table = [[[0; 24]; 4]; 4];
+    let mut trump = 0;
+    while trump < 4 {
+        let mut lead = 0;
+        while lead < 4 {
+            let mut index = 0;
+            while index < 24 {
+                let (suit, rank) = (index / 6, index % 6);
+                let effective = EFFECTIVE_SUITS[trump][index] as usize;
+                table[trump][lead][index] = if effective == trump && rank == Rank::Jack as usize {
+                    if suit == trump {
                         13
                     } else {
                         12
                     }
-                }
+                } else if effective == trump {
+                    TRUMP_VALUES[rank]
+                } else if effective == lead {
+                    rank as u8 + 1
+                } else {
+                    0
+                };
+                index += 1;
             }
-        } else if self.suit == lead.suit && !lead.is_trump(trump) {
-            match self.rank {
-                Rank::Nine => 1,
-                Rank::Ten => 2,
-                Rank::Jack => 3,
-                Rank::Queen => 4,
-                Rank::King => 5,
-                Rank::Ace => 6,
-            }
-        } else {
-            0
+            lead += 1;
         }
+        trump += 1;
     }
-}
+    table
+};
 
 /// A euchre deck.
 pub type Deck = deck::Deck<Card>;
 impl Default for Deck {
     fn default() -> Self {
-        itertools::iproduct!(Rank::all_ranks(), Suit::all_suits())
-            .map(|(&rank, &suit)| Card { rank, suit })
+        french::DeckBuilder::new()
+            .ranks(Rank::all_ranks().iter().map(|&r| r.into()))
+            .build()
+            .into_iter()
+            .map(|c| Card::try_from(c).expect("euchre rank"))
             .collect()
     }
 }
+
+/// A set of euchre cards, represented as a bitset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u32);
+
+impl CardSet {
+    /// The set of all 24 euchre cards.
+    pub const ALL: CardSet = CardSet((1 << 24) - 1);
+
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the set of cards of the specified suit, ignoring trump.
+    pub fn suit(suit: Suit) -> Self {
+        Self(0x3f << (suit as u32 * 6))
+    }
+
+    /// Returns the set of cards whose effective suit is `suit`, given the trump suit.
+    pub fn effective_suit(suit: Suit, trump: Suit) -> Self {
+        let left = CardSet::from(Card::new(Rank::Jack, trump.to_matching_color()));
+        if suit == trump {
+            Self::suit(suit) | left
+        } else if suit == trump.to_matching_color() {
+            Self::suit(suit) - left
+        } else {
+            Self::suit(suit)
+        }
+    }
+
+    /// The number of cards in the set.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the set contains the card.
+    pub fn contains(self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    /// Adds a card to the set, returning true if it was not already present.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 |= 1 << card.index();
+        !present
+    }
+
+    /// Removes a card from the set, returning true if it was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !(1 << card.index());
+        present
+    }
+
+    /// Returns an iterator over the cards in the set, in index order.
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> Self {
+        Self(1 << card.index())
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = &'a Card>>(iter: T) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl Not for CardSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+/// An iterator over the cards in a [`CardSet`].
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u32);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            None
+        } else {
+            let index = self.0.trailing_zeros();
+            self.0 &= self.0 - 1;
+            Some(Card::from_index(u8::try_from(index).expect("less than 32")))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl Serialize for CardSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+impl<'de> Deserialize<'de> for CardSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+        Ok(cards.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_card_index() {
+        let mut deck = Deck::default();
+        let set: CardSet = deck.take(24).into_iter().collect();
+        assert_eq!(set, CardSet::ALL);
+        for card in set {
+            assert_eq!(card, Card::from_index(card.index()));
+        }
+    }
+
+    #[test]
+    fn test_card_set_effective_suit() {
+        for &trump in Suit::all_suits() {
+            for &suit in Suit::all_suits() {
+                let expect: CardSet = CardSet::ALL
+                    .iter()
+                    .filter(|c| c.effective_suit(trump) == suit)
+                    .collect();
+                assert_eq!(expect, CardSet::effective_suit(suit, trump));
+            }
+        }
+    }
+
+    #[test]
+    fn test_effective_suit() {
+        for &trump in Suit::all_suits() {
+            for card in CardSet::ALL {
+                let is_left = card.rank == Rank::Jack && card.suit == trump.to_matching_color();
+                let expected = if is_left { trump } else { card.suit };
+                assert_eq!(card.effective_suit(trump), expected, "{} {}", card, trump);
+                assert_eq!(
+                    card.is_trump(trump),
+                    expected == trump,
+                    "{} {}",
+                    card,
+                    trump
+                );
+                assert!(CardSet::effective_suit(expected, trump).contains(card));
+            }
+        }
+    }
+
+    #[test]
+    fn test_value() {
+        let card = |s: &str| s.parse::<Card>().unwrap();
+        let trump = Suit::Heart;
+        let lead = card("9♤");
+        let order = [
+            "J♡", "J♢", "A♡", "K♡", "Q♡", "T♡", "9♡", "A♤", "K♤", "Q♤", "J♤", "T♤",
+        ];
+        let values: Vec<u8> = order.iter().map(|s| card(s).value(trump, lead)).collect();
+        assert_eq!(values, [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2]);
+        assert_eq!(card("A♣").value(trump, lead), 0);
+        assert_eq!(card("A♢").value(trump, lead), 0);
+
+        // When the left bower is lead, only trump follows.
+        let lead = card("J♢");
+        assert_eq!(card("A♢").value(trump, lead), 0);
+        assert_eq!(card("9♡").value(trump, lead), 7);
+
+        // Every trump beats every card that follows the lead.
+        for &trump in Suit::all_suits() {
+            for lead in CardSet::ALL {
+                for card in CardSet::ALL {
+                    let value = card.value(trump, lead);
+                    assert_eq!(value > 6, card.is_trump(trump));
+                    assert_eq!(
+                        (1..=6).contains(&value),
+                        !card.is_trump(trump) && card.is_following(trump, lead)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Time controls.
+//!
+//! Like a chess clock, each player has a bank of time for the whole game. The time a player
+//! spends choosing an action is drawn from their bank, and a fixed increment is added back once
+//! the action is made. A player whose bank runs dry forfeits the game for their team.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Seat, SeatMap};
+
+#[cfg(test)]
+mod test;
+
+/// The time allowed to each player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeControl {
+    /// Each player's bank of time, at the start of a game.
+    pub bank: Duration,
+    /// The time added to a player's bank after each of their actions.
+    pub increment: Duration,
+}
+
+impl TimeControl {
+    /// Creates a time control with the specified bank and increment.
+    pub fn new(bank: Duration, increment: Duration) -> Self {
+        Self { bank, increment }
+    }
+}
+
+impl Display for TimeControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}+{}",
+            self.bank.as_secs() / 60,
+            self.increment.as_secs()
+        )
+    }
+}
+
+impl FromStr for TimeControl {
+    type Err = ();
+
+    /// Parses a time control written as `MINUTES+SECONDS`, as for a chess clock (e.g., `5+3` for
+    /// five minutes, with three seconds added after each action). The increment may be omitted,
+    /// but the bank must be at least a minute.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (bank, increment) = s.split_once('+').unwrap_or((s, "0"));
+        let bank: u64 = bank.trim().parse().map_err(|_| ())?;
+        let increment: u64 = increment.trim().parse().map_err(|_| ())?;
+        if bank == 0 {
+            return Err(());
+        }
+        Ok(Self::new(
+            Duration::from_secs(bank * 60),
+            Duration::from_secs(increment),
+        ))
+    }
+}
+
+/// The time remaining to each player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Clock {
+    /// The time control.
+    control: TimeControl,
+    /// The time remaining in each player's bank.
+    remaining: SeatMap<Duration>,
+}
+
+impl From<TimeControl> for Clock {
+    fn from(control: TimeControl) -> Self {
+        Self {
+            control,
+            remaining: SeatMap::from_fn(|_| control.bank),
+        }
+    }
+}
+
+impl Clock {
+    /// Returns the time control.
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns the time remaining in the player's bank.
+    pub fn remaining(&self, seat: Seat) -> Duration {
+        self.remaining[seat]
+    }
+
+    /// Returns the time that would remain in the player's bank, after thinking for the specified
+    /// time.
+    pub fn remaining_after(&self, seat: Seat, elapsed: Duration) -> Duration {
+        self.remaining[seat].saturating_sub(elapsed)
+    }
+
+    /// Draws the time a player spent on an action from their bank. Returns false if the bank ran
+    /// dry; otherwise, adds the increment, and returns true.
+    pub fn charge(&mut self, seat: Seat, elapsed: Duration) -> bool {
+        let remaining = &mut self.remaining[seat];
+        if elapsed >= *remaining {
+            *remaining = Duration::ZERO;
+            return false;
+        }
+        *remaining = *remaining - elapsed + self.control.increment;
+        true
+    }
+
+    /// Returns the first player whose bank has run dry, if any.
+    pub fn flagged(&self) -> Option<Seat> {
+        self.remaining
+            .iter()
+            .find(|(_, remaining)| remaining.is_zero())
+            .map(|(seat, _)| seat)
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_time_control() {
+    let control: TimeControl = "5+3".parse().unwrap();
+    assert_eq!(control.bank, Duration::from_secs(300));
+    assert_eq!(control.increment, Duration::from_secs(3));
+    assert_eq!(control.to_string(), "5+3");
+    assert_eq!(
+        "10".parse(),
+        Ok(TimeControl::new(Duration::from_secs(600), Duration::ZERO))
+    );
+    assert_eq!("0+5".parse::<TimeControl>(), Err(()));
+    assert_eq!("5+".parse::<TimeControl>(), Err(()));
+    assert_eq!("five".parse::<TimeControl>(), Err(()));
+}
+
+#[test]
+fn test_clock() {
+    let control = TimeControl::new(Duration::from_secs(60), Duration::from_secs(2));
+    let mut clock = Clock::from(control);
+    assert_eq!(clock.remaining(Seat::North), Duration::from_secs(60));
+    assert_eq!(
+        clock.remaining_after(Seat::North, Duration::from_secs(90)),
+        Duration::ZERO
+    );
+
+    assert!(clock.charge(Seat::North, Duration::from_secs(10)));
+    assert_eq!(clock.remaining(Seat::North), Duration::from_secs(52));
+    assert_eq!(clock.remaining(Seat::East), Duration::from_secs(60));
+    assert_eq!(clock.flagged(), None);
+
+    assert!(!clock.charge(Seat::East, Duration::from_secs(60)));
+    assert_eq!(clock.remaining(Seat::East), Duration::ZERO);
+    assert_eq!(clock.flagged(), Some(Seat::East));
+}
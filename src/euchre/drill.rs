@@ -0,0 +1,355 @@
+//! Practice drills.
+//!
+//! A drill deals random rounds, and lets robots play each one until a particular kind of decision
+//! comes up, such as whether to order up from the first seat. The player's choice is scored against
+//! the best action found by rolling out every alternative. Results are accumulated across sessions
+//! in a local drills file, so that accuracy can be tracked over time.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::paths::create_file;
+use super::{
+    action_values, ActionData, ActionType, FastRound, Player, PlayerState, Robot, Round,
+    RoundConfig, Seat,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of deals to try before giving up on finding a decision.
+const MAX_DEALS: usize = 10_000;
+
+/// Choices within this many expected points of the best count as correct, since the rollouts that
+/// value them are noisy.
+pub const CLOSE_CALL: f64 = 0.1;
+
+/// The number of past sessions kept in the drills file.
+const MAX_SESSIONS: usize = 100;
+
+/// The kind of decision practiced in a drill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DrillKind {
+    /// Whether to order up the top card.
+    OrderUp,
+    /// Whether to name another suit as trump, once the top card is turned down.
+    CallTrump,
+    /// Which card to discard, as the dealer who picked up the top card.
+    Discard,
+    /// Which card to lead to the first trick.
+    Lead,
+}
+
+impl DrillKind {
+    /// All kinds of drill.
+    pub const ALL: [DrillKind; 4] = [
+        DrillKind::OrderUp,
+        DrillKind::CallTrump,
+        DrillKind::Discard,
+        DrillKind::Lead,
+    ];
+
+    /// The type of action the decision calls for.
+    pub fn action(self) -> ActionType {
+        match self {
+            DrillKind::OrderUp => ActionType::BidTop,
+            DrillKind::CallTrump => ActionType::BidOther,
+            DrillKind::Discard => ActionType::DealerDiscard,
+            DrillKind::Lead => ActionType::Lead,
+        }
+    }
+}
+
+impl Display for DrillKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DrillKind::OrderUp => "order-up",
+            DrillKind::CallTrump => "call",
+            DrillKind::Discard => "discard",
+            DrillKind::Lead => "lead",
+        })
+    }
+}
+
+impl FromStr for DrillKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|k| k.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+/// Returns the position of the seat at the table, counting clockwise from the dealer's left, so
+/// that the first to bid is at position 1, and the dealer at position 4.
+pub fn position(dealer: Seat, seat: Seat) -> u8 {
+    let mut position = 1;
+    let mut next = dealer.next();
+    while next != seat {
+        next = next.next();
+        position += 1;
+    }
+    position
+}
+
+/// A decision to practice.
+#[derive(Debug, Clone)]
+pub struct Drill {
+    /// The kind of decision.
+    pub kind: DrillKind,
+    /// The round, with the decision next.
+    pub round: FastRound,
+}
+
+impl Drill {
+    /// Deals random rounds, playing each with robots, until a decision of the specified kind comes
+    /// up with more than one legal choice. If a position is specified, the decision must be for
+    /// the player at that position. Returns `None` if no such decision comes up in a reasonable
+    /// number of deals.
+    pub fn generate<R: Rng + ?Sized>(
+        kind: DrillKind,
+        at: Option<u8>,
+        robot: &Robot,
+        rng: &mut R,
+    ) -> Option<Self> {
+        for _ in 0..MAX_DEALS {
+            let mut round = FastRound::from(rng.gen::<RoundConfig>());
+            while let Some(expect) = round.next_action() {
+                if expect.action == kind.action()
+                    && at.is_none_or(|p| position(round.dealer(), expect.seat) == p)
+                    && round.legal_actions().len() > 1
+                {
+                    return Some(Self { kind, round });
+                }
+                let data = robot.take_action(round.player_state(expect.seat), expect.action);
+                round
+                    .apply_action(expect.with_data(data))
+                    .expect("robots play legally");
+            }
+        }
+        None
+    }
+
+    /// Returns the seat of the player to decide.
+    pub fn seat(&self) -> Seat {
+        self.round.next_action().expect("decision pending").seat
+    }
+
+    /// Returns the position of the player to decide, counting from the dealer's left.
+    pub fn position(&self) -> u8 {
+        position(self.round.dealer(), self.seat())
+    }
+
+    /// Returns what the player to decide can see.
+    pub fn state(&self) -> PlayerState<'_> {
+        self.round.player_state(self.seat())
+    }
+
+    /// Returns true if the choice is one of the legal actions.
+    pub fn is_legal(&self, choice: ActionData) -> bool {
+        self.round
+            .legal_actions()
+            .iter()
+            .any(|action| action.data == choice)
+    }
+
+    /// Scores the choice against the alternatives, each valued by the average points won by the
+    /// player's team over the specified number of random deals. Returns `None` if the choice
+    /// isn't legal, or the alternatives couldn't be valued.
+    pub fn grade<R: Rng + ?Sized>(
+        &self,
+        choice: ActionData,
+        robot: &Robot,
+        rollouts: usize,
+        rng: &mut R,
+    ) -> Option<Grade> {
+        let values = action_values(&self.round, robot, rollouts, rng);
+        let value = values.iter().find(|&&(d, _)| d == choice)?.1;
+        let best = values.iter().copied().max_by(|a, b| a.1.total_cmp(&b.1))?;
+        Some(Grade {
+            choice,
+            value,
+            best,
+            values,
+        })
+    }
+}
+
+/// How a choice compares with the alternatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grade {
+    /// The player's choice.
+    pub choice: ActionData,
+    /// The average points won by the player's team after the choice.
+    pub value: f64,
+    /// The best alternative, and its value.
+    pub best: (ActionData, f64),
+    /// The value of every legal action.
+    pub values: Vec<(ActionData, f64)>,
+}
+
+impl Grade {
+    /// The expected points given up by not taking the best alternative.
+    pub fn loss(&self) -> f64 {
+        (self.best.1 - self.value).max(0.0)
+    }
+
+    /// Returns true if the choice is within [`CLOSE_CALL`] of the best.
+    pub fn is_correct(&self) -> bool {
+        self.loss() < CLOSE_CALL
+    }
+}
+
+impl Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_correct() {
+            writeln!(f, "{}", tr!("drill-correct"))?;
+        } else {
+            let action = self.best.0.to_string();
+            let loss = format!("{:.2}", self.loss());
+            writeln!(f, "{}", tr!("drill-best", action = action, loss = loss))?;
+        }
+        for &(data, value) in &self.values {
+            let marker = if data == self.choice { "*" } else { " " };
+            writeln!(f, "{marker} {:<16} {value:+.2}", data.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// The results of a drill, or of many.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct DrillTally {
+    /// The number of decisions made.
+    pub attempts: u32,
+    /// The number of decisions that were correct.
+    pub correct: u32,
+    /// The total expected points given up.
+    pub loss: f64,
+}
+
+impl DrillTally {
+    /// Adds a graded decision.
+    pub fn record(&mut self, grade: &Grade) {
+        self.attempts += 1;
+        self.correct += u32::from(grade.is_correct());
+        self.loss += grade.loss();
+    }
+
+    /// The fraction of decisions that were correct.
+    pub fn accuracy(&self) -> Option<f64> {
+        (self.attempts > 0).then(|| f64::from(self.correct) / f64::from(self.attempts))
+    }
+}
+
+impl Display for DrillTally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (correct, attempts) = (self.correct, self.attempts);
+        match self.accuracy() {
+            Some(accuracy) => {
+                let accuracy = format!("{:.0}", accuracy * 100.0);
+                let loss = format!("{:.2}", self.loss / f64::from(self.attempts));
+                write!(
+                    f,
+                    "{}",
+                    tr!(
+                        "drill-tally-accuracy",
+                        correct = correct,
+                        attempts = attempts,
+                        accuracy = accuracy,
+                        loss = loss
+                    )
+                )
+            }
+            None => write!(
+                f,
+                "{}",
+                tr!("drill-tally", correct = correct, attempts = attempts)
+            ),
+        }
+    }
+}
+
+/// A session of drills of one kind.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DrillSession {
+    /// When the session ended, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The kind of decision practiced.
+    pub kind: DrillKind,
+    /// The results.
+    pub tally: DrillTally,
+}
+
+/// Drill results, accumulated across sessions.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DrillRecord {
+    /// The results for each kind of drill, over all sessions.
+    totals: BTreeMap<DrillKind, DrillTally>,
+    /// The most recent sessions, oldest first.
+    sessions: Vec<DrillSession>,
+}
+
+impl DrillRecord {
+    /// The name of the drills file, in the data directory.
+    pub const FILE_NAME: &'static str = "euchre-drills.json";
+
+    /// Loads drill results from a file. A missing file is treated as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes drill results to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the results for the specified kind of drill, over all sessions.
+    pub fn total(&self, kind: DrillKind) -> DrillTally {
+        self.totals.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the most recent sessions of the specified kind of drill, oldest first.
+    pub fn sessions(&self, kind: DrillKind) -> impl Iterator<Item = &DrillSession> {
+        self.sessions.iter().filter(move |s| s.kind == kind)
+    }
+
+    /// Adds the results of a session. Sessions without any decisions are ignored.
+    pub fn record_session(&mut self, kind: DrillKind, tally: DrillTally) {
+        if tally.attempts == 0 {
+            return;
+        }
+        let total = self.totals.entry(kind).or_default();
+        total.attempts += tally.attempts;
+        total.correct += tally.correct;
+        total.loss += tally.loss;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.sessions.push(DrillSession {
+            timestamp,
+            kind,
+            tally,
+        });
+        let excess = self.sessions.len().saturating_sub(MAX_SESSIONS);
+        self.sessions.drain(..excess);
+    }
+}
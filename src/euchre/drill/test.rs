@@ -0,0 +1,101 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::euchre::{Card, Rank, Suit};
+
+#[test]
+fn test_drill_kind() {
+    for kind in DrillKind::ALL {
+        assert_eq!(kind.to_string().parse(), Ok(kind));
+    }
+    assert_eq!("Order-Up".parse(), Ok(DrillKind::OrderUp));
+    assert_eq!("follow".parse::<DrillKind>(), Err(()));
+}
+
+#[test]
+fn test_position() {
+    assert_eq!(position(Seat::North, Seat::East), 1);
+    assert_eq!(position(Seat::North, Seat::West), 3);
+    assert_eq!(position(Seat::North, Seat::North), 4);
+}
+
+#[test]
+fn test_generate() {
+    let robot = Robot::default();
+    let mut rng = StdRng::seed_from_u64(3471);
+    for kind in DrillKind::ALL {
+        let drill = Drill::generate(kind, None, &robot, &mut rng).unwrap();
+        let expect = drill.round.next_action().unwrap();
+        assert_eq!(expect.action, kind.action());
+        assert!(drill.round.legal_actions().len() > 1);
+    }
+    for at in 1..=4 {
+        let drill = Drill::generate(DrillKind::OrderUp, Some(at), &robot, &mut rng).unwrap();
+        assert_eq!(drill.position(), at);
+    }
+    let drill = Drill::generate(DrillKind::Discard, None, &robot, &mut rng).unwrap();
+    assert_eq!(drill.position(), 4);
+}
+
+#[test]
+fn test_grade() {
+    let robot = Robot::default();
+    let mut rng = StdRng::seed_from_u64(3471);
+    let drill = Drill::generate(DrillKind::Lead, None, &robot, &mut rng).unwrap();
+    let hand = drill.state().sorted_hand();
+    let choice = ActionData::Card { card: hand[0] };
+    assert!(drill.is_legal(choice));
+    let grade = drill
+        .grade(choice, &robot, 8, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    assert_eq!(grade.values.len(), hand.len());
+    assert!(grade.best.1 >= grade.value);
+
+    // Over the same deals, the best choice is correct.
+    let best = drill
+        .grade(grade.best.0, &robot, 8, &mut StdRng::seed_from_u64(1))
+        .unwrap();
+    assert_eq!(best.value, best.best.1);
+    assert!(best.is_correct());
+
+    // Cards that aren't in the hand can't be graded.
+    let missing = Card::new(Rank::Nine, Suit::Club);
+    let missing = (!hand.contains(&missing)).then_some(ActionData::Card { card: missing });
+    if let Some(choice) = missing {
+        assert!(!drill.is_legal(choice));
+        assert_eq!(drill.grade(choice, &robot, 8, &mut rng), None);
+    }
+}
+
+#[test]
+fn test_drill_record() {
+    let grade = |value| Grade {
+        choice: ActionData::Pass,
+        value,
+        best: (ActionData::Pass, 1.0),
+        values: vec![(ActionData::Pass, value)],
+    };
+    let mut tally = DrillTally::default();
+    assert_eq!(tally.accuracy(), None);
+    tally.record(&grade(1.0));
+    tally.record(&grade(0.5));
+    assert_eq!(tally.attempts, 2);
+    assert_eq!(tally.correct, 1);
+    assert_eq!(tally.accuracy(), Some(0.5));
+    assert_eq!(tally.loss, 0.5);
+
+    let mut record = DrillRecord::default();
+    record.record_session(DrillKind::OrderUp, DrillTally::default());
+    assert_eq!(record.sessions(DrillKind::OrderUp).count(), 0);
+    record.record_session(DrillKind::OrderUp, tally);
+    record.record_session(DrillKind::OrderUp, tally);
+    record.record_session(DrillKind::Lead, tally);
+    assert_eq!(record.sessions(DrillKind::OrderUp).count(), 2);
+    assert_eq!(record.total(DrillKind::OrderUp).attempts, 4);
+    assert_eq!(record.total(DrillKind::CallTrump), DrillTally::default());
+
+    let ser = serde_json::to_string(&record).unwrap();
+    let de: DrillRecord = serde_json::from_str(&ser).unwrap();
+    assert_eq!(de, record);
+}
@@ -0,0 +1,178 @@
+//! Duplicate euchre.
+//!
+//! In duplicate, every deal is played twice: once as dealt, and once with the hands and the deal
+//! passed one seat to the left, so that each partnership holds the cards that the other held the
+//! first time. Adding up both results cancels out the luck of the deal, leaving the difference in
+//! play.
+
+use std::fmt::Display;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::{BaseRound, Player, Round, RoundConfig, RoundError, RoundOutcome, SeatMap, Team};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The players at each seat.
+pub type Lineup = SeatMap<Arc<dyn Player>>;
+
+/// Returns the deal with every hand, and the deal itself, passed one seat to the left.
+pub fn rotate(config: &RoundConfig) -> RoundConfig {
+    let hands = SeatMap::from_fn(|seat| config.hand(seat.opposite().next()).to_vec());
+    RoundConfig::from_hands(
+        config.dealer().next(),
+        hands,
+        config.top(),
+        config.pattern(),
+    )
+    .expect("rotated deal is valid")
+}
+
+/// Plays a round to completion, with the specified players.
+pub fn play_round(config: RoundConfig, lineup: &Lineup) -> Result<RoundOutcome, RoundError> {
+    let mut round = BaseRound::from(config);
+    loop {
+        while let Some(event) = round.pop_event() {
+            for (seat, player) in lineup.iter() {
+                player.notify(round.player_state(seat), &event);
+            }
+        }
+        let Some(expect) = round.next_action() else {
+            break;
+        };
+        let player = &lineup[expect.seat];
+        let data = player.take_action(round.player_state(expect.seat), expect.action);
+        match round.apply_action(expect.with_data(data)) {
+            Err(RoundError::Player(err)) if player.handle_error(err.clone()) => continue,
+            Err(err) => return Err(err),
+            Ok(()) => (),
+        }
+    }
+    Ok(round.outcome().expect("round is over"))
+}
+
+/// Returns the points won by the specified team, or the negated points won by its opponents.
+fn net(outcome: &RoundOutcome, team: Team) -> i32 {
+    if outcome.team == team {
+        i32::from(outcome.points)
+    } else {
+        -i32::from(outcome.points)
+    }
+}
+
+/// A set of deals, to be played in duplicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Duplicate {
+    boards: Vec<RoundConfig>,
+}
+
+impl Duplicate {
+    /// Creates a duplicate match from a set of deals.
+    pub fn new(boards: Vec<RoundConfig>) -> Self {
+        Self { boards }
+    }
+
+    /// Creates a duplicate match with the specified number of random deals.
+    pub fn random(boards: usize) -> Self {
+        Self::new((0..boards).map(|_| RoundConfig::random()).collect())
+    }
+
+    /// Loads a set of deals from a file.
+    pub fn from_json_file(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path)?;
+        let dup: Self = serde_json::from_reader(file)?;
+        for board in &dup.boards {
+            board.validate()?;
+        }
+        Ok(dup)
+    }
+
+    /// Writes the set of deals to a file, so that they may be replayed later.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the deals.
+    pub fn boards(&self) -> &[RoundConfig] {
+        &self.boards
+    }
+
+    /// Plays every deal twice with the specified lineup: once as dealt, and once rotated.
+    pub fn play(&self, lineup: &Lineup) -> Result<DuplicateResult, RoundError> {
+        let mut results = vec![];
+        for config in &self.boards {
+            let dealt = play_round(config.clone(), lineup)?;
+            let rotated = play_round(rotate(config), lineup)?;
+            results.push(BoardResult { dealt, rotated });
+        }
+        Ok(DuplicateResult { boards: results })
+    }
+}
+
+/// The outcomes of a single deal, played both ways.
+#[derive(Debug, Clone)]
+pub struct BoardResult {
+    /// The outcome of the deal as dealt.
+    pub dealt: RoundOutcome,
+    /// The outcome of the deal with the hands rotated.
+    pub rotated: RoundOutcome,
+}
+
+impl BoardResult {
+    /// Returns the net points won by the specified team, over both plays of the deal. Since each
+    /// partnership holds both sets of cards, this is zero when the teams play equally well.
+    pub fn net(&self, team: Team) -> i32 {
+        net(&self.dealt, team) + net(&self.rotated, team)
+    }
+}
+
+/// The results of a duplicate match.
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateResult {
+    boards: Vec<BoardResult>,
+}
+
+impl DuplicateResult {
+    /// Returns the results for each deal.
+    pub fn boards(&self) -> &[BoardResult] {
+        &self.boards
+    }
+
+    /// Returns the net points won by the specified team, over every deal.
+    pub fn net(&self, team: Team) -> i32 {
+        self.boards.iter().map(|b| b.net(team)).sum()
+    }
+}
+
+impl Display for DuplicateResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ns = Team::NorthSouth;
+        writeln!(
+            f,
+            "{:<6} {:>7} {:>7} {:>7}",
+            tr!("report-board"),
+            tr!("report-board-dealt"),
+            tr!("report-board-rotated"),
+            tr!("report-net")
+        )?;
+        for (i, board) in self.boards.iter().enumerate() {
+            writeln!(
+                f,
+                "{:<6} {:>7} {:>7} {:>7}",
+                i + 1,
+                format!("{:+}", net(&board.dealt, ns)),
+                format!("{:+}", net(&board.rotated, ns)),
+                format!("{:+}", board.net(ns)),
+            )?;
+        }
+        let total = format!("{:+}", self.net(ns));
+        writeln!(f, "{:<6} {total:>23}", tr!("report-total"))
+    }
+}
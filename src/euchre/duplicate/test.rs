@@ -0,0 +1,29 @@
+use crate::euchre::{Robot, Seat};
+
+use super::*;
+
+#[test]
+fn test_rotate() {
+    let config = RoundConfig::random();
+    let rotated = rotate(&config);
+    assert_eq!(rotated.dealer(), config.dealer().next());
+    assert_eq!(rotated.top(), config.top());
+    for &seat in Seat::all_seats() {
+        assert_eq!(config.hand(seat), rotated.hand(seat.next()));
+    }
+    let full_circle = (0..4).fold(config.clone(), |c, _| rotate(&c));
+    assert_eq!(full_circle, config);
+}
+
+#[test]
+fn test_play() {
+    let robot = Robot::default().into_player();
+    let lineup: Lineup = SeatMap::from_fn(|_| robot.clone());
+    let dup = Duplicate::random(5);
+    let result = dup.play(&lineup).unwrap();
+    assert_eq!(result.boards().len(), 5);
+    assert_eq!(result.net(Team::NorthSouth), -result.net(Team::EastWest));
+
+    let ser = serde_json::to_string(&dup).unwrap();
+    assert_eq!(serde_json::from_str::<Duplicate>(&ser).unwrap(), dup);
+}
@@ -1,56 +1,100 @@
 //! Errors
 
+use std::fmt::Display;
+
 use super::{ActionType, Card, LogId, Seat, Suit};
+use crate::tr;
 
 /// An invalid action taken by a player.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum PlayerError {
     /// The dealer is required to choose a suit after all players have passed.
-    #[error("the dealer must bid")]
     DealerMustBidOther,
 
     /// Must call the same suit as the top card.
-    #[error("must call {0}")]
     MustCallTopSuit(Suit),
 
     /// Cannot call the same suit as the top card.
-    #[error("cannot call {0}")]
     CannotCallTopSuit(Suit),
 
     /// The player doesn't actually hold the card they attempted to play.
-    #[error("{0} does not hold {1}")]
     CardNotHeld(Seat, Card),
 
     /// The player must follow the lead card for this trick.
-    #[error("{0} must follow {1}")]
     MustFollowLead(Seat, Card),
+
+    /// The called card was not a renege by an opponent.
+    NoRenege(Card),
 }
 
 /// An error that can occur during the round.
 #[derive(Debug, thiserror::Error)]
 pub enum RoundError {
     /// Not playing with a full deck.
-    #[error("deck is missing cards")]
     IncompleteDeck,
     /// The deck has duplicate cards.
-    #[error("deck contains duplicate card")]
     DuplicateCard,
     /// A player has too many or too few cards.
-    #[error("a player has the incorrect number of cards")]
     InvalidHandSize,
     /// The provided [`ActionData`](super::ActionData) is not appropriate for the [`ActionType`].
-    #[error("action contains invalid data")]
     InvalidActionData,
     /// The provided [`Action`](super::Action) doesn't match the expected [`ExpectAction`](super::ExpectAction).
-    #[error("expected {seat} to {action}")]
     ExpectActioned { seat: Seat, action: ActionType },
     /// The game is over, no more actions are expected.
-    #[error("round is over")]
     RoundOver,
+    /// The state of the round is inconsistent, which indicates a bug.
+    Invariant(String),
     /// Invalid reference to a log record.
-    #[error("invalid log id {0}")]
     InvalidLogId(LogId),
     /// A player attempted to play an invalid action.
-    #[error(transparent)]
-    Player(#[from] PlayerError),
+    Player(PlayerError),
+}
+
+impl From<PlayerError> for RoundError {
+    fn from(err: PlayerError) -> Self {
+        Self::Player(err)
+    }
+}
+
+impl Display for PlayerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Self::DealerMustBidOther => tr!("error-dealer-must-bid"),
+            Self::MustCallTopSuit(suit) => tr!("error-must-call-top-suit", suit = suit.to_string()),
+            Self::CannotCallTopSuit(suit) => {
+                tr!("error-cannot-call-top-suit", suit = suit.to_string())
+            }
+            Self::CardNotHeld(seat, card) => tr!(
+                "error-card-not-held",
+                seat = seat.to_string(),
+                card = card.to_string()
+            ),
+            Self::MustFollowLead(seat, card) => tr!(
+                "error-must-follow-lead",
+                seat = seat.to_string(),
+                card = card.to_string()
+            ),
+            Self::NoRenege(card) => tr!("error-no-renege", card = card.to_string()),
+        })
+    }
+}
+
+impl Display for RoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Self::IncompleteDeck => tr!("error-incomplete-deck"),
+            Self::DuplicateCard => tr!("error-duplicate-card"),
+            Self::InvalidHandSize => tr!("error-invalid-hand-size"),
+            Self::InvalidActionData => tr!("error-invalid-action-data"),
+            Self::ExpectActioned { seat, action } => tr!(
+                "error-expected-action",
+                seat = seat.to_string(),
+                action = action.to_string()
+            ),
+            Self::RoundOver => tr!("error-round-over"),
+            Self::Invariant(reason) => tr!("error-invariant", reason = reason.as_str()),
+            Self::InvalidLogId(id) => tr!("error-invalid-log-id", id = *id),
+            Self::Player(err) => err.to_string(),
+        })
+    }
 }
@@ -0,0 +1,291 @@
+//! C bindings, for embedding the engine in non-Rust applications.
+//!
+//! Rounds are exposed as opaque [`DeckardRound`] handles. Values cross the boundary as
+//! NUL-terminated JSON strings, in the same shape as their serialization elsewhere. Strings
+//! returned by this module are owned by the caller, and must be released with
+//! [`deckard_string_free`].
+//!
+//! Functions that can fail return a null pointer (or a negative status), and record a message
+//! that can be retrieved with [`deckard_last_error`].
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+use serde::Serialize;
+
+use super::{Action, Event, LoggingRound, Player, RawLog, Robot, Round, RoundView, Seat};
+
+#[cfg(test)]
+mod test;
+
+thread_local! {
+    /// The message for the most recent error on this thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records an error message for [`deckard_last_error`].
+fn set_error(msg: impl ToString) {
+    let msg = CString::new(msg.to_string()).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+/// Serializes a value to a caller-owned JSON string, or returns null on failure.
+fn to_json<T: Serialize>(value: &T) -> *mut c_char {
+    match serde_json::to_string(value).map(CString::new) {
+        Ok(Ok(s)) => s.into_raw(),
+        Ok(Err(err)) => {
+            set_error(err);
+            ptr::null_mut()
+        }
+        Err(err) => {
+            set_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Borrows a NUL-terminated string from the caller.
+///
+/// # Safety
+///
+/// The pointer must be null, or point to a valid NUL-terminated string.
+unsafe fn from_c_str<'a>(s: *const c_char) -> Option<Result<&'a str, std::str::Utf8Error>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_str())
+    }
+}
+
+/// A round of euchre, with a robot that can suggest actions.
+pub struct DeckardRound {
+    round: LoggingRound,
+    robot: Robot,
+}
+
+/// Deals a new round, with a random dealer.
+///
+/// The handle must be released with [`deckard_round_free`].
+#[no_mangle]
+pub extern "C" fn deckard_round_new() -> *mut DeckardRound {
+    Box::into_raw(Box::new(DeckardRound {
+        round: LoggingRound::random(),
+        robot: Robot::default(),
+    }))
+}
+
+/// Restores a round from a JSON round log, as produced by [`deckard_round_log`]. Returns null if
+/// the log is invalid.
+///
+/// # Safety
+///
+/// `json` must point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_load(json: *const c_char) -> *mut DeckardRound {
+    let Some(Ok(json)) = from_c_str(json) else {
+        set_error("invalid string");
+        return ptr::null_mut();
+    };
+    let log = match serde_json::from_str::<RawLog>(json)
+        .map_err(anyhow::Error::from)
+        .and_then(|log| Ok(log.validated()?))
+    {
+        Ok(log) => log,
+        Err(err) => {
+            set_error(err);
+            return ptr::null_mut();
+        }
+    };
+    Box::into_raw(Box::new(DeckardRound {
+        round: LoggingRound::from(log.into_log()),
+        robot: Robot::default(),
+    }))
+}
+
+/// Releases a round.
+///
+/// # Safety
+///
+/// `round` must be null, or a handle returned by this module that has not yet been released.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_free(round: *mut DeckardRound) {
+    if !round.is_null() {
+        drop(Box::from_raw(round));
+    }
+}
+
+/// Returns the legal actions for the player who is expected to act next, as a JSON array. The
+/// array is empty if the round is over.
+///
+/// # Safety
+///
+/// `round` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_legal_actions(round: *const DeckardRound) -> *mut c_char {
+    let round = &*round;
+    to_json(&round.round.legal_actions())
+}
+
+/// Returns the reneges that the specified seat (e.g., `"south"` or `"S"`) may call out of turn,
+/// as a JSON array, or null if the seat is invalid.
+///
+/// # Safety
+///
+/// `round` must be a valid handle, and `seat` must point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_legal_calls(
+    round: *const DeckardRound,
+    seat: *const c_char,
+) -> *mut c_char {
+    let round = &*round;
+    let seat = match from_c_str(seat) {
+        Some(Ok(s)) => match s.parse::<Seat>() {
+            Ok(seat) => seat,
+            Err(()) => {
+                set_error(format!("invalid seat: {s}"));
+                return ptr::null_mut();
+            }
+        },
+        Some(Err(err)) => {
+            set_error(err);
+            return ptr::null_mut();
+        }
+        None => {
+            set_error("invalid string");
+            return ptr::null_mut();
+        }
+    };
+    to_json(&round.round.legal_calls(seat))
+}
+
+/// Applies an action, given as a JSON object. Returns zero on success, or a negative value if the
+/// action is malformed or illegal.
+///
+/// # Safety
+///
+/// `round` must be a valid handle, and `action` must point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_apply_action(
+    round: *mut DeckardRound,
+    action: *const c_char,
+) -> c_int {
+    let round = &mut *round;
+    let Some(Ok(action)) = from_c_str(action) else {
+        set_error("invalid string");
+        return -1;
+    };
+    let action: Action = match serde_json::from_str(action) {
+        Ok(action) => action,
+        Err(err) => {
+            set_error(err);
+            return -1;
+        }
+    };
+    match round.round.apply_action(action) {
+        Ok(()) => 0,
+        Err(err) => {
+            set_error(err);
+            -2
+        }
+    }
+}
+
+/// Returns the action that the robot would take next, as a JSON object, or `null` if the round is
+/// over.
+///
+/// # Safety
+///
+/// `round` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_robot_suggestion(round: *const DeckardRound) -> *mut c_char {
+    let round = &*round;
+    let action = round.round.next_action().map(|expect| {
+        let state = round.round.player_state(expect.seat);
+        expect.with_data(round.robot.take_action(state, expect.action))
+    });
+    to_json(&action)
+}
+
+/// Returns the state of the round as a JSON object, as visible from the specified seat (e.g.,
+/// `"south"` or `"S"`), or as visible to a spectator if the seat is null.
+///
+/// # Safety
+///
+/// `round` must be a valid handle, and `seat` must be null or point to a valid NUL-terminated
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_state(
+    round: *const DeckardRound,
+    seat: *const c_char,
+) -> *mut c_char {
+    let round = &*round;
+    let view = match from_c_str(seat) {
+        None => RoundView::public(&round.round),
+        Some(Ok(s)) => match s.parse::<Seat>() {
+            Ok(seat) => RoundView::new(&round.round, seat),
+            Err(()) => {
+                set_error(format!("invalid seat: {s}"));
+                return ptr::null_mut();
+            }
+        },
+        Some(Err(err)) => {
+            set_error(err);
+            return ptr::null_mut();
+        }
+    };
+    to_json(&view)
+}
+
+/// Returns the events that have occurred since the last call, as a JSON array.
+///
+/// # Safety
+///
+/// `round` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_events(round: *mut DeckardRound) -> *mut c_char {
+    let round = &mut *round;
+    let events: Vec<Event> = std::iter::from_fn(|| round.round.pop_event()).collect();
+    to_json(&events)
+}
+
+/// Returns the outcome of the round as a JSON object, or `null` if the round is not over.
+///
+/// # Safety
+///
+/// `round` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_outcome(round: *const DeckardRound) -> *mut c_char {
+    let round = &*round;
+    to_json(&round.round.outcome())
+}
+
+/// Serializes the round's log, which can be restored with [`deckard_round_load`].
+///
+/// # Safety
+///
+/// `round` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_round_log(round: *const DeckardRound) -> *mut c_char {
+    let round = &*round;
+    to_json(&RawLog::from(&round.round))
+}
+
+/// Returns the message for the most recent error on this thread, or null if there was none. The
+/// string is borrowed, and remains valid until the next failing call on this thread.
+#[no_mangle]
+pub extern "C" fn deckard_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |s| s.as_ptr()))
+}
+
+/// Releases a string returned by this module.
+///
+/// # Safety
+///
+/// `s` must be null, or a string returned by this module that has not yet been released.
+#[no_mangle]
+pub unsafe extern "C" fn deckard_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
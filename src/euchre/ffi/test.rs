@@ -0,0 +1,63 @@
+use std::ffi::CStr;
+
+use super::*;
+
+/// Takes ownership of a string returned by the bindings.
+unsafe fn take_string(s: *mut c_char) -> String {
+    assert!(!s.is_null());
+    let owned = CStr::from_ptr(s).to_str().unwrap().to_string();
+    deckard_string_free(s);
+    owned
+}
+
+#[test]
+fn test_ffi_robot_playout() {
+    unsafe {
+        let round = deckard_round_new();
+        loop {
+            let action = take_string(deckard_round_robot_suggestion(round));
+            if action == "null" {
+                break;
+            }
+            let legal: Vec<Action> =
+                serde_json::from_str(&take_string(deckard_round_legal_actions(round))).unwrap();
+            assert!(legal.contains(&serde_json::from_str(&action).unwrap()));
+            let action = CString::new(action).unwrap();
+            assert_eq!(deckard_round_apply_action(round, action.as_ptr()), 0);
+        }
+        assert_ne!(take_string(deckard_round_outcome(round)), "null");
+        assert_eq!(take_string(deckard_round_legal_actions(round)), "[]");
+
+        let log = CString::new(take_string(deckard_round_log(round))).unwrap();
+        let restored = deckard_round_load(log.as_ptr());
+        assert!(!restored.is_null());
+        deckard_round_free(restored);
+        deckard_round_free(round);
+    }
+}
+
+#[test]
+fn test_ffi_errors() {
+    unsafe {
+        let round = deckard_round_new();
+        let garbage = CString::new("{}").unwrap();
+        assert_eq!(deckard_round_apply_action(round, garbage.as_ptr()), -1);
+        assert!(!deckard_last_error().is_null());
+
+        let seat = CString::new("nowhere").unwrap();
+        assert!(deckard_round_state(round, seat.as_ptr()).is_null());
+        let err = CStr::from_ptr(deckard_last_error()).to_str().unwrap();
+        assert_eq!(err, "invalid seat: nowhere");
+
+        assert!(deckard_round_legal_calls(round, seat.as_ptr()).is_null());
+
+        let seat = CString::new("south").unwrap();
+        take_string(deckard_round_state(round, seat.as_ptr()));
+        assert_eq!(
+            take_string(deckard_round_legal_calls(round, seat.as_ptr())),
+            "[]"
+        );
+        take_string(deckard_round_state(round, ptr::null()));
+        deckard_round_free(round);
+    }
+}
@@ -1,20 +1,307 @@
 //! Game management.
 //!
 //! A game consists of a sequence of [`Round`]s, by which [`Team`]s score points. A team wins the
-//! game by scoring ten or more points.
+//! game by scoring ten or more points, or some other agreed target. Games may be grouped into a
+//! best-of-N [`Rubber`]. A game may be played against the [`Clock`], in which case a player who
+//! runs out of time forfeits it for their team.
 
-use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
 
-use super::{Round, RoundConfig, Team};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Card, Clock, Contract, DealPattern, Deck, LogId, LoggingRound, Metadata, RawLog, RenegePolicy,
+    Round, RoundConfig, RoundError, RoundOutcome, RoundView, Seat, Team, TeamMap, TimeControl,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
 
 /// A game of euchre.
 pub struct Game<R> {
     /// The current round.
     round: R,
     /// The current scores.
-    score: HashMap<Team, u8>,
+    score: TeamMap<u8>,
     /// The target score.
     target_score: u8,
+    /// The number of rounds completed.
+    rounds: u16,
+    /// The number of lone hands played by each team.
+    loners: TeamMap<u8>,
+    /// How the deal passes from one round to the next.
+    rotation: DealerRotation,
+    /// The pattern in which cards are dealt.
+    pattern: DealPattern,
+    /// How failures to follow suit are handled.
+    renege: RenegePolicy,
+    /// Games won so far in the rubber.
+    rubber: Rubber,
+    /// The rounds completed in the current game, in order.
+    history: Vec<RoundRecord>,
+    /// Whether looking back and asking for help are locked until the game is over.
+    competitive: bool,
+    /// The time remaining to each player, if the game is played against the clock.
+    clock: Option<Clock>,
+}
+
+/// The result of a completed round, as recorded on the score sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundRecord {
+    /// The dealer.
+    pub dealer: Seat,
+    /// The contract that was played.
+    pub contract: Contract,
+    /// The number of tricks taken by the makers.
+    pub tricks: u8,
+    /// The player whose renege was called, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renege: Option<Seat>,
+    /// The points scored.
+    pub outcome: RoundOutcome,
+    /// The scores after the round.
+    pub score: TeamMap<u8>,
+}
+
+/// The rules chosen when setting up a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rules {
+    /// The score required to win a game.
+    pub target_score: u8,
+    /// The number of games in the rubber.
+    pub best_of: u8,
+    /// How failures to follow suit are handled.
+    pub renege: RenegePolicy,
+    /// Locks the history, seeking, and robot hints until the game is over, so that the score is
+    /// earned without takebacks.
+    #[serde(default)]
+    pub competitive: bool,
+    /// The time allowed to each player, if the game is played against the clock.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_control: Option<TimeControl>,
+    /// How the deal passes from one round to the next.
+    #[serde(default)]
+    pub rotation: DealerRotation,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            target_score: 10,
+            best_of: 1,
+            renege: RenegePolicy::default(),
+            competitive: false,
+            time_control: None,
+            rotation: DealerRotation::default(),
+        }
+    }
+}
+
+/// A match of several games, won by the first team to win a majority of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rubber {
+    /// The number of games in the rubber.
+    best_of: u8,
+    /// The number of games won by each team.
+    games: TeamMap<u8>,
+}
+
+impl Default for Rubber {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Rubber {
+    /// Creates a rubber of the specified number of games, which should be odd.
+    pub fn new(best_of: u8) -> Self {
+        Self {
+            best_of: best_of.max(1),
+            games: TeamMap::default(),
+        }
+    }
+
+    /// Returns the number of games in the rubber.
+    pub fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    /// Returns the number of games won by the specified team.
+    pub fn games(&self, team: Team) -> u8 {
+        self.games[team]
+    }
+
+    /// Returns the number of games played so far.
+    pub fn played(&self) -> u8 {
+        self.games.iter().map(|(_, n)| n).sum()
+    }
+
+    /// Returns the team that won the rubber, if it is over.
+    pub fn winner(&self) -> Option<Team> {
+        self.games
+            .iter()
+            .find(|(_, &n)| n > self.best_of / 2)
+            .map(|(team, _)| team)
+    }
+
+    /// Records a game won by the specified team.
+    fn record(&mut self, winner: Team) {
+        self.games[winner] += 1;
+    }
+}
+
+/// The policy for choosing the dealer of the next round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DealerRotation {
+    /// The deal passes to the left.
+    #[default]
+    Clockwise,
+    /// The dealer is chosen at random for each round.
+    Random,
+    /// The same player deals every round.
+    Fixed,
+}
+
+impl DealerRotation {
+    /// All dealer rotation policies.
+    pub const ALL: [DealerRotation; 3] = [
+        DealerRotation::Clockwise,
+        DealerRotation::Random,
+        DealerRotation::Fixed,
+    ];
+}
+
+impl Display for DealerRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DealerRotation::Clockwise => "clockwise",
+            DealerRotation::Random => "random",
+            DealerRotation::Fixed => "fixed",
+        })
+    }
+}
+
+impl FromStr for DealerRotation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|r| r.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+/// The result of cutting the deck to choose the first dealer.
+///
+/// Each player draws a card, and the lowest card deals. Players who tie for lowest draw again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cut {
+    /// Cards drawn, in order.
+    pub cards: Vec<(Seat, Card)>,
+    /// The player who won the cut, and deals first.
+    pub dealer: Seat,
+}
+
+impl Distribution<Cut> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cut {
+        let mut deck: Deck = rng.gen();
+        let mut cards = vec![];
+        let mut contenders = Seat::all_seats().to_vec();
+        loop {
+            let draws: Option<Vec<_>> = contenders
+                .iter()
+                .map(|&seat| Some((seat, deck.take(1).first().copied()?)))
+                .collect();
+            let Some(draws) = draws else {
+                // The deck ran out with players still tied, so one of them deals at random.
+                let dealer = contenders[rng.gen_range(0..contenders.len())];
+                return Cut { cards, dealer };
+            };
+            cards.extend(&draws);
+            let low = draws.iter().map(|(_, c)| c.rank).min().expect("non-empty");
+            contenders = draws
+                .into_iter()
+                .filter_map(|(seat, c)| if c.rank == low { Some(seat) } else { None })
+                .collect();
+            if let [dealer] = contenders[..] {
+                return Cut { cards, dealer };
+            }
+        }
+    }
+}
+
+impl Cut {
+    /// Returns the last card drawn by the specified player.
+    pub fn card(&self, seat: Seat) -> Option<Card> {
+        self.cards
+            .iter()
+            .rev()
+            .find_map(|(s, c)| if *s == seat { Some(*c) } else { None })
+    }
+}
+
+/// The outcome of a game.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GameOutcome {
+    /// The winning team.
+    pub winner: Team,
+    /// The final scores.
+    pub score: TeamMap<u8>,
+    /// The number of rounds played.
+    pub rounds: u16,
+    /// The number of lone hands played by each team.
+    pub loners: TeamMap<u8>,
+    /// The state of the rubber, including this game.
+    #[serde(default)]
+    pub rubber: Rubber,
+    /// The player who ran out of time, if the game was forfeited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forfeit: Option<Seat>,
+}
+
+impl Display for GameOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let loser = self.winner.other();
+        let team = self.winner.to_string();
+        let score = format!("{}-{}", self.score[self.winner], self.score[loser]);
+        f.write_str(&match self.forfeit {
+            Some(seat) => tr!(
+                "outcome-game-on-time",
+                team = team,
+                score = score,
+                rounds = self.rounds,
+                seat = seat.to_string()
+            ),
+            None => tr!(
+                "outcome-game",
+                team = team,
+                score = score,
+                rounds = self.rounds
+            ),
+        })
+    }
+}
+
+impl GameOutcome {
+    /// The number of points by which the winning team won.
+    pub fn margin(&self) -> u8 {
+        self.score[self.winner].saturating_sub(self.score[self.winner.other()])
+    }
+
+    /// The total number of lone hands played.
+    pub fn loner_count(&self) -> u8 {
+        self.loners.iter().map(|(_, n)| n).sum()
+    }
 }
 
 impl<R> Default for Game<R>
@@ -31,11 +318,17 @@ impl<R> From<R> for Game<R> {
     fn from(round: R) -> Self {
         Self {
             round,
-            score: [(Team::NorthSouth, 0), (Team::EastWest, 0)]
-                .iter()
-                .copied()
-                .collect(),
+            score: TeamMap::default(),
             target_score: 10,
+            rounds: 0,
+            loners: TeamMap::default(),
+            rotation: DealerRotation::default(),
+            pattern: DealPattern::default(),
+            renege: RenegePolicy::default(),
+            rubber: Rubber::default(),
+            history: vec![],
+            competitive: false,
+            clock: None,
         }
     }
 }
@@ -44,12 +337,64 @@ impl<R> Game<R>
 where
     R: Round,
 {
+    /// Sets the dealer rotation policy.
+    pub fn with_dealer_rotation(mut self, rotation: DealerRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the pattern in which cards are dealt, from the next round onwards.
+    pub fn with_deal_pattern(mut self, pattern: DealPattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Sets the policy for failures to follow suit, from the next round onwards.
+    pub fn with_renege_policy(mut self, renege: RenegePolicy) -> Self {
+        self.renege = renege;
+        self
+    }
+
     /// Sets the target score.
     pub fn with_target_score(mut self, score: u8) -> Self {
         self.target_score = score;
         self
     }
 
+    /// Plays a rubber of the specified number of games.
+    pub fn with_best_of(mut self, best_of: u8) -> Self {
+        self.rubber = Rubber::new(best_of);
+        self
+    }
+
+    /// Applies the specified rules. The renege policy applies from the next round onwards.
+    pub fn with_rules(self, rules: Rules) -> Self {
+        self.with_target_score(rules.target_score)
+            .with_best_of(rules.best_of)
+            .with_renege_policy(rules.renege)
+            .with_competitive(rules.competitive)
+            .with_time_control(rules.time_control)
+            .with_dealer_rotation(rules.rotation)
+    }
+
+    /// Plays the game against the clock, with the specified time control, or without a clock.
+    pub fn with_time_control(mut self, control: Option<TimeControl>) -> Self {
+        self.clock = control.map(Clock::from);
+        self
+    }
+
+    /// Sets whether the game is competitive, locking the history, seeking, and robot hints until
+    /// it's over.
+    pub fn with_competitive(mut self, competitive: bool) -> Self {
+        self.competitive = competitive;
+        self
+    }
+
+    /// Returns the state of the rubber, not including the current game.
+    pub fn rubber(&self) -> Rubber {
+        self.rubber
+    }
+
     /// Returns an immutable reference to the current round.
     pub fn round(&self) -> &R {
         &self.round
@@ -62,7 +407,10 @@ where
 
     /// Returns the winning team, if the game is over.
     pub fn winner(&self) -> Option<Team> {
-        for (&team, &points) in &self.score {
+        if let Some(seat) = self.forfeit() {
+            return Some(seat.team().other());
+        }
+        for (team, &points) in self.score.iter() {
             if points >= self.target_score {
                 return Some(team);
             }
@@ -71,8 +419,130 @@ where
     }
 
     /// Returns the outcome of the game, if it is over.
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        self.winner().map(|winner| {
+            let mut rubber = self.rubber;
+            rubber.record(winner);
+            GameOutcome {
+                winner,
+                score: self.score,
+                rounds: self.rounds,
+                loners: self.loners,
+                rubber,
+                forfeit: self.forfeit(),
+            }
+        })
+    }
+
+    /// Returns the score required to win the game.
+    pub fn target_score(&self) -> u8 {
+        self.target_score
+    }
+
+    /// Returns the rules of the game.
+    pub fn rules(&self) -> Rules {
+        Rules {
+            target_score: self.target_score,
+            best_of: self.rubber.best_of(),
+            renege: self.renege,
+            competitive: self.competitive,
+            time_control: self.clock.map(|clock| clock.control()),
+            rotation: self.rotation,
+        }
+    }
+
+    /// Returns the time remaining to each player, if the game is played against the clock.
+    pub fn clock(&self) -> Option<&Clock> {
+        self.clock.as_ref()
+    }
+
+    /// Charges a player for the time spent on an action, if the game is played against the
+    /// clock. Returns false if the player ran out of time, forfeiting the game.
+    pub fn charge_time(&mut self, seat: Seat, elapsed: Duration) -> bool {
+        self.clock
+            .as_mut()
+            .is_none_or(|clock| clock.charge(seat, elapsed))
+    }
+
+    /// Replaces the time remaining to each player, such as with an update from the host.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = Some(clock);
+    }
+
+    /// Returns the player who ran out of time, if the game was forfeited.
+    pub fn forfeit(&self) -> Option<Seat> {
+        self.clock.as_ref().and_then(Clock::flagged)
+    }
+
+    /// Returns the number of rounds completed.
+    pub fn rounds(&self) -> u16 {
+        self.rounds
+    }
+
+    /// Returns the current score for the specified team.
     pub fn score(&self, team: Team) -> u8 {
-        self.score.get(&team).copied().unwrap_or_default()
+        self.score[team]
+    }
+
+    /// Returns the rounds completed in the current game, in order.
+    pub fn history(&self) -> &[RoundRecord] {
+        &self.history
+    }
+
+    /// Returns true if the game is competitive, and not yet over, so that looking back through
+    /// the history, seeking, and asking the robots for hints are locked.
+    pub fn assists_locked(&self) -> bool {
+        self.competitive && self.outcome().is_none()
+    }
+
+    /// Updates the score from the outcome of the current round. It is the caller's
+    /// responsibility to ensure that the current round is finished, and that it is only scored
+    /// once.
+    pub fn score_round(&mut self) {
+        let outcome = self.round.outcome().expect("round must be over");
+        let contract = self.round.contract().expect("round must have a contract");
+        self.score[outcome.team] += outcome.points;
+        self.rounds += 1;
+        if contract.alone {
+            self.loners[contract.maker.team()] += 1;
+        }
+        let tricks = self.round.tricks();
+        self.history.push(RoundRecord {
+            dealer: self.round.dealer(),
+            contract,
+            tricks: tricks.win_count(contract.maker.team()),
+            renege: tricks.called_renege().map(|revoke| revoke.seat),
+            outcome,
+            score: self.score,
+        });
+    }
+
+    /// Returns a snapshot of the game, as visible to the specified seat.
+    pub fn view(&self, seat: Seat) -> GameView {
+        GameView {
+            score: self.score,
+            target_score: self.target_score,
+            rounds: self.rounds,
+            loners: self.loners,
+            rubber: self.rubber,
+            history: self.history.clone(),
+            clock: self.clock.map(Box::new),
+            round: RoundView::new(&self.round, seat),
+        }
+    }
+
+    /// Returns a public snapshot of the game, which reveals no player's hand.
+    pub fn public_view(&self) -> GameView {
+        GameView {
+            score: self.score,
+            target_score: self.target_score,
+            rounds: self.rounds,
+            loners: self.loners,
+            rubber: self.rubber,
+            history: self.history.clone(),
+            clock: self.clock.map(Box::new),
+            round: RoundView::public(&self.round),
+        }
     }
 }
 
@@ -80,13 +550,228 @@ impl<R> Game<R>
 where
     R: Round + From<RoundConfig>,
 {
+    /// Creates a new game, dealt by the winner of the cut.
+    pub fn from_cut(cut: &Cut) -> Self {
+        let round = R::from(RoundConfig::random_with_dealer(
+            cut.dealer,
+            DealPattern::default(),
+        ));
+        Self::from(round)
+    }
+
+    /// Throws in the current round without scoring it, and deals a fresh one with the specified
+    /// dealer. The new round's events begin with an
+    /// [`Event::Redeal`](crate::euchre::Event::Redeal), ahead of the deal.
+    pub fn redeal(&mut self, dealer: Seat) {
+        self.round = RoundConfig::random_with_dealer(dealer, self.pattern)
+            .with_renege_policy(self.renege)
+            .with_redeal()
+            .into();
+    }
+
+    /// Throws in the current round by agreement, without playing it, and deals the next round,
+    /// passing the deal on as if the round had been played. Neither team scores, and the round
+    /// isn't counted or recorded on the score sheet. Returns the outcome of the round thrown in,
+    /// with no points for the dealer's team, or `None` if a contract has been made, and the round
+    /// must be played.
+    pub fn throw_in(&mut self) -> Option<RoundOutcome> {
+        if self.round.contract().is_some() {
+            return None;
+        }
+        let outcome = RoundOutcome::new(self.round.dealer().team(), 0);
+        self.redeal(self.next_dealer());
+        Some(outcome)
+    }
+
     /// Updates the score from the outcome of the current round, and begins a new round. It is the
     /// caller's responsibility to ensure that the current round is finished.
     pub fn next_round(&mut self) {
-        let outcome = self.round.outcome().expect("round must be over");
-        let score = self.score.entry(outcome.team).or_default();
-        *score += outcome.points;
-        let dealer = self.round.dealer().next();
-        self.round = RoundConfig::random_with_dealer(dealer).into();
+        self.score_round();
+        self.round = self.deal(self.next_dealer());
+    }
+
+    /// Records the outcome of the current game in the rubber, and begins the next game with the
+    /// round that has already been dealt. It is the caller's responsibility to ensure that the
+    /// game is over, and the rubber is not. If the game was forfeited mid-round, a fresh round is
+    /// dealt instead.
+    pub fn next_game(&mut self) {
+        let winner = self.winner().expect("game must be over");
+        self.rubber.record(winner);
+        if self.forfeit().is_some() {
+            self.round = self.deal(self.next_dealer());
+        }
+        if let Some(clock) = self.clock {
+            self.clock = Some(Clock::from(clock.control()));
+        }
+        self.score = TeamMap::default();
+        self.rounds = 0;
+        self.loners = TeamMap::default();
+        self.history.clear();
+    }
+
+    /// Chooses the dealer of the next round.
+    fn next_dealer(&self) -> Seat {
+        match self.rotation {
+            DealerRotation::Clockwise => self.round.dealer().next(),
+            DealerRotation::Random => rand::random(),
+            DealerRotation::Fixed => self.round.dealer(),
+        }
+    }
+
+    /// Deals a new round.
+    fn deal(&self, dealer: Seat) -> R {
+        RoundConfig::random_with_dealer(dealer, self.pattern)
+            .with_renege_policy(self.renege)
+            .into()
+    }
+}
+
+/// A snapshot of a game in progress, as visible to a particular seat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameView {
+    /// The current scores.
+    score: TeamMap<u8>,
+    /// The target score.
+    target_score: u8,
+    /// The number of rounds completed.
+    rounds: u16,
+    /// The number of lone hands played by each team.
+    loners: TeamMap<u8>,
+    /// Games won so far in the rubber.
+    #[serde(default)]
+    rubber: Rubber,
+    /// The rounds completed in the current game.
+    #[serde(default)]
+    history: Vec<RoundRecord>,
+    /// The time remaining to each player, if the game is played against the clock. Boxed, so that
+    /// untimed views stay small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clock: Option<Box<Clock>>,
+    /// The current round.
+    round: RoundView,
+}
+impl From<GameView> for Game<RoundView> {
+    fn from(view: GameView) -> Self {
+        Game {
+            round: view.round,
+            score: view.score,
+            target_score: view.target_score,
+            rounds: view.rounds,
+            loners: view.loners,
+            rotation: DealerRotation::default(),
+            pattern: DealPattern::default(),
+            renege: RenegePolicy::default(),
+            rubber: view.rubber,
+            history: view.history,
+            competitive: false,
+            clock: view.clock.map(|clock| *clock),
+        }
+    }
+}
+
+/// A serializable snapshot of a game in progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawGame {
+    /// The current scores.
+    score: TeamMap<u8>,
+    /// The target score.
+    target_score: u8,
+    /// The number of rounds completed.
+    #[serde(default)]
+    rounds: u16,
+    /// The number of lone hands played by each team.
+    #[serde(default)]
+    loners: TeamMap<u8>,
+    /// The dealer rotation policy.
+    #[serde(default)]
+    rotation: DealerRotation,
+    /// The pattern in which cards are dealt.
+    #[serde(default)]
+    pattern: DealPattern,
+    /// How failures to follow suit are handled.
+    #[serde(default)]
+    renege: RenegePolicy,
+    /// Games won so far in the rubber.
+    #[serde(default)]
+    rubber: Rubber,
+    /// The rounds completed in the current game.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    history: Vec<RoundRecord>,
+    /// Whether the game is competitive.
+    #[serde(default)]
+    competitive: bool,
+    /// The time remaining to each player, if the game is played against the clock.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clock: Option<Clock>,
+    /// The log for the current round.
+    round: RawLog,
+    /// The position of the current round within its log.
+    #[serde(default)]
+    cursor: Option<LogId>,
+    /// Descriptive details about the game, if any were given when it was saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>,
+}
+impl<'a> From<&'a Game<LoggingRound>> for RawGame {
+    fn from(game: &'a Game<LoggingRound>) -> Self {
+        RawGame {
+            score: game.score,
+            target_score: game.target_score,
+            rounds: game.rounds,
+            loners: game.loners,
+            rotation: game.rotation,
+            pattern: game.pattern,
+            renege: game.renege,
+            rubber: game.rubber,
+            history: game.history.clone(),
+            competitive: game.competitive,
+            clock: game.clock,
+            round: RawLog::from(&game.round),
+            cursor: game.round.cursor(),
+            metadata: None,
+        }
+    }
+}
+impl RawGame {
+    /// Attaches descriptive details about the game.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Returns the descriptive details about the game, if any.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    pub fn from_json_reader<R: Read>(r: R) -> anyhow::Result<Self> {
+        let mut game: RawGame = serde_json::from_reader(r)?;
+        game.round = game.round.validated()?;
+        Ok(game)
+    }
+
+    pub fn from_json_file(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path)?;
+        RawGame::from_json_reader(file)
+    }
+
+    /// Restores the game, replaying the current round up to the saved cursor.
+    pub fn into_game(self) -> Result<Game<LoggingRound>, RoundError> {
+        let mut round = LoggingRound::from(self.round.into_log());
+        round.seek(self.cursor)?;
+        Ok(Game {
+            round,
+            score: self.score,
+            target_score: self.target_score,
+            rounds: self.rounds,
+            loners: self.loners,
+            rotation: self.rotation,
+            pattern: self.pattern,
+            renege: self.renege,
+            rubber: self.rubber,
+            history: self.history,
+            competitive: self.competitive,
+            clock: self.clock,
+        })
     }
 }
@@ -0,0 +1,274 @@
+use crate::euchre::{Action, ActionData, ActionType, Event, Player, Robot};
+
+use super::*;
+
+#[test]
+fn test_raw_game_resume() {
+    let robot = Robot::default();
+    let mut game: Game<LoggingRound> = Game::default().with_target_score(7);
+    game.score[Team::EastWest] = 3;
+    for _ in 0..6 {
+        let round = game.round_mut();
+        let expect = round.next_action().expect("round in progress");
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+
+    let raw = RawGame::from(&game);
+    let ser = serde_json::to_string(&raw).unwrap();
+    let de = RawGame::from_json_reader(ser.as_bytes()).unwrap();
+    assert_eq!(raw, de);
+
+    let resumed = de.into_game().unwrap();
+    assert_eq!(resumed.target_score, 7);
+    assert_eq!(resumed.score(Team::EastWest), 3);
+    assert_eq!(resumed.score(Team::NorthSouth), 0);
+    assert_eq!(resumed.round().cursor(), game.round().cursor());
+    assert_eq!(
+        resumed.round().next_action().map(|e| (e.seat, e.action)),
+        game.round().next_action().map(|e| (e.seat, e.action))
+    );
+}
+
+#[test]
+fn test_cut_for_deal() {
+    for _ in 0..100 {
+        let cut: Cut = rand::random();
+
+        // Split the cards into draws; a new draw starts whenever a seat repeats.
+        let mut draws: Vec<Vec<(Seat, Card)>> = vec![vec![]];
+        for &(seat, card) in &cut.cards {
+            if draws.last().unwrap().iter().any(|(s, _)| *s == seat) {
+                draws.push(vec![]);
+            }
+            draws.last_mut().unwrap().push((seat, card));
+        }
+        assert_eq!(draws[0].len(), 4);
+
+        let last = draws.last().unwrap();
+        let dealer_card = cut.card(cut.dealer).unwrap();
+        assert!(last
+            .iter()
+            .all(|(s, c)| *s == cut.dealer || c.rank > dealer_card.rank));
+    }
+}
+
+#[test]
+fn test_dealer_rotation() {
+    let robot = Robot::default();
+    for (rotation, expect) in [
+        (DealerRotation::Clockwise, Seat::East),
+        (DealerRotation::Fixed, Seat::North),
+    ] {
+        let round = LoggingRound::from(RoundConfig::random_with_dealer(
+            Seat::North,
+            DealPattern::TwoThree,
+        ));
+        let rules = Rules {
+            rotation,
+            ..Rules::default()
+        };
+        let mut game = Game::from(round).with_rules(rules);
+        assert_eq!(game.rules(), rules);
+        while let Some(expect) = game.round().next_action() {
+            let round = game.round_mut();
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        game.next_round();
+        assert_eq!(game.round().dealer(), expect);
+    }
+    for rotation in DealerRotation::ALL {
+        assert_eq!(rotation.to_string().parse(), Ok(rotation));
+    }
+    assert_eq!("Fixed".parse(), Ok(DealerRotation::Fixed));
+    assert_eq!("widdershins".parse::<DealerRotation>(), Err(()));
+}
+
+#[test]
+fn test_redeal() {
+    let round = LoggingRound::from(RoundConfig::random_with_dealer(
+        Seat::West,
+        DealPattern::TwoThree,
+    ));
+    let mut game = Game::from(round);
+    let first = game.round().log().config().clone();
+    game.redeal(Seat::West);
+    assert_eq!(game.round().dealer(), Seat::West);
+    assert_ne!(game.round().log().config(), &first);
+    assert_eq!(game.rounds(), 0);
+    assert_eq!(game.score(Team::NorthSouth), 0);
+    assert_eq!(game.score(Team::EastWest), 0);
+    assert!(matches!(
+        game.round_mut().pop_event(),
+        Some(Event::Redeal(Seat::West))
+    ));
+    assert!(matches!(
+        game.round_mut().pop_event(),
+        Some(Event::Packet(Seat::North, _))
+    ));
+}
+
+#[test]
+fn test_throw_in() {
+    let round = LoggingRound::from(RoundConfig::random_with_dealer(
+        Seat::West,
+        DealPattern::TwoThree,
+    ));
+    let mut game = Game::from(round);
+    let outcome = game.throw_in().expect("no contract yet");
+    assert!(outcome.is_thrown_in());
+    assert_eq!(outcome.to_string(), "Thrown in");
+    assert_eq!(game.round().dealer(), Seat::North);
+    assert!(matches!(
+        game.round_mut().pop_event(),
+        Some(Event::Redeal(Seat::North))
+    ));
+    assert_eq!(game.rounds(), 0);
+    assert!(game.history().is_empty());
+    assert_eq!(game.score(Team::NorthSouth), 0);
+    assert_eq!(game.score(Team::EastWest), 0);
+
+    // Once trump is called, the round must be played.
+    let suit = game.round().top_card().suit;
+    let call = Action::new(
+        Seat::East,
+        ActionType::BidTop,
+        ActionData::Call { suit, alone: true },
+    );
+    game.round_mut().apply_action(call).unwrap();
+    assert_eq!(game.throw_in(), None);
+    assert_eq!(game.round().dealer(), Seat::North);
+}
+
+#[test]
+fn test_rubber() {
+    let mut game: Game<LoggingRound> = Game::default().with_rules(Rules {
+        target_score: 5,
+        best_of: 3,
+        renege: RenegePolicy::Reject,
+        competitive: false,
+        time_control: None,
+        rotation: DealerRotation::Clockwise,
+    });
+    game.score[Team::NorthSouth] = 5;
+    let outcome = game.outcome().expect("game over");
+    assert_eq!(outcome.rubber.games(Team::NorthSouth), 1);
+    assert_eq!(outcome.rubber.winner(), None);
+
+    game.next_game();
+    assert_eq!(game.score(Team::NorthSouth), 0);
+    assert_eq!(game.rubber().played(), 1);
+    assert!(game.outcome().is_none());
+
+    game.score[Team::EastWest] = 6;
+    game.next_game();
+    let raw = RawGame::from(&game);
+    let ser = serde_json::to_string(&raw).unwrap();
+    let mut game = RawGame::from_json_reader(ser.as_bytes())
+        .unwrap()
+        .into_game()
+        .unwrap();
+    assert_eq!(game.rubber().games(Team::EastWest), 1);
+
+    game.score[Team::NorthSouth] = 5;
+    let outcome = game.outcome().expect("game over");
+    assert_eq!(outcome.rubber.played(), 3);
+    assert_eq!(outcome.rubber.winner(), Some(Team::NorthSouth));
+}
+
+#[test]
+fn test_raw_game_metadata() {
+    let game: Game<LoggingRound> = Game::default();
+    let raw = RawGame::from(&game);
+    let ser = serde_json::to_string(&raw).unwrap();
+    assert!(!ser.contains("metadata"));
+
+    let mut metadata = Metadata {
+        notes: "practice".into(),
+        rules: Some(game.rules()),
+        ..Metadata::default()
+    };
+    metadata.players[Seat::East] = "Cy".into();
+    let raw = raw.with_metadata(metadata.clone());
+    let ser = serde_json::to_string(&raw).unwrap();
+    let de = RawGame::from_json_reader(ser.as_bytes()).unwrap();
+    assert_eq!(de.metadata(), Some(&metadata));
+}
+
+#[test]
+fn test_competitive() {
+    let rules = Rules {
+        competitive: true,
+        ..Rules::default()
+    };
+    let game: Game<LoggingRound> = Game::default().with_rules(rules);
+    assert_eq!(game.rules(), rules);
+    assert!(game.assists_locked());
+
+    // Saving and loading the game doesn't unlock it.
+    let raw = RawGame::from(&game);
+    let ser = serde_json::to_string(&raw).unwrap();
+    let mut game = RawGame::from_json_reader(ser.as_bytes())
+        .unwrap()
+        .into_game()
+        .unwrap();
+    assert!(game.assists_locked());
+
+    game.score[Team::EastWest] = 10;
+    assert!(!game.assists_locked());
+    assert!(!Game::<LoggingRound>::default().assists_locked());
+}
+
+#[test]
+fn test_forfeit_on_time() {
+    let control: TimeControl = "1+2".parse().unwrap();
+    let mut game: Game<LoggingRound> = Game::default()
+        .with_best_of(3)
+        .with_time_control(Some(control));
+    assert_eq!(game.rules().time_control, Some(control));
+    assert!(game.charge_time(Seat::North, Duration::from_secs(30)));
+    assert_eq!(
+        game.clock().unwrap().remaining(Seat::North),
+        Duration::from_secs(32)
+    );
+    assert!(game.winner().is_none());
+
+    // The clock survives saving and loading.
+    let raw = RawGame::from(&game);
+    let ser = serde_json::to_string(&raw).unwrap();
+    let mut game = RawGame::from_json_reader(ser.as_bytes())
+        .unwrap()
+        .into_game()
+        .unwrap();
+    assert_eq!(
+        game.clock().unwrap().remaining(Seat::North),
+        Duration::from_secs(32)
+    );
+
+    assert!(!game.charge_time(Seat::West, Duration::from_secs(61)));
+    assert_eq!(game.forfeit(), Some(Seat::West));
+    let outcome = game.outcome().expect("game over");
+    assert_eq!(outcome.winner, Team::NorthSouth);
+    assert_eq!(outcome.forfeit, Some(Seat::West));
+    assert!(outcome.to_string().ends_with("when West ran out of time"));
+
+    // The next game starts with a fresh deal, and fresh clocks.
+    let dealer = game.round().dealer();
+    game.next_game();
+    assert!(game.outcome().is_none());
+    assert_eq!(game.round().dealer(), dealer.next());
+    assert_eq!(game.rubber().games(Team::NorthSouth), 1);
+    assert_eq!(
+        game.clock().unwrap().remaining(Seat::West),
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn test_untimed() {
+    let mut game: Game<LoggingRound> = Game::default();
+    assert!(game.clock().is_none());
+    assert!(game.charge_time(Seat::North, Duration::from_secs(3600)));
+    assert_eq!(game.forfeit(), None);
+}
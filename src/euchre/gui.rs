@@ -0,0 +1,456 @@
+//! Desktop GUI.
+
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use eframe::egui::{self, Align2, Color32, RichText};
+
+mod card;
+mod history;
+mod table;
+use self::card::CARD_SIZE;
+
+use super::{
+    Action, ActionData, ActionType, Cut, Event, ExpectAction, Game, LogId, LoggingRound, Player,
+    RawGame, RawLog, Robot, Round, Seat, Team,
+};
+use crate::{i18n, tr};
+
+/// How long each packet of the deal is displayed.
+const PACKET_DELAY: Duration = Duration::from_millis(150);
+
+/// The human player's seat at the table.
+const HUMAN_SEAT: Seat = Seat::South;
+
+/// Runs the GUI until the user closes the window.
+pub fn run(gui: Gui) -> anyhow::Result<()> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_title("Deckard")
+            .with_inner_size([960.0, 720.0]),
+        ..Default::default()
+    };
+    eframe::run_native("deckard", options, Box::new(|_cc| Ok(Box::new(gui))))
+        .map_err(|e| anyhow::anyhow!("{e}"))
+}
+
+/// GUI state.
+pub struct Gui {
+    /// The game being played.
+    game: Game<LoggingRound>,
+    /// The event being displayed, if any.
+    event: Option<Event>,
+    /// When the current event was first displayed.
+    event_at: Instant,
+    /// The robot implementation.
+    robot: Robot,
+    /// Whether to auto-play as robots.
+    robot_autoplay: bool,
+    /// Whether to show the history tree.
+    show_history: bool,
+    /// An error message to display to the user.
+    error: Option<String>,
+    /// A debug message to display to the user.
+    debug: Option<String>,
+}
+
+impl Default for Gui {
+    fn default() -> Self {
+        let cut: Cut = rand::random();
+        let game = Game::from_cut(&cut);
+        Self::new(game, Event::Cut(cut.into()))
+    }
+}
+impl From<Game<LoggingRound>> for Gui {
+    fn from(mut game: Game<LoggingRound>) -> Self {
+        let event = game.round_mut().pop_event().expect("deal");
+        Self::new(game, event)
+    }
+}
+
+impl Gui {
+    /// Creates a new [`Gui`], displaying the specified event.
+    fn new(game: Game<LoggingRound>, event: Event) -> Self {
+        Self {
+            game,
+            event: Some(event),
+            event_at: Instant::now(),
+            robot: Robot::default(),
+            robot_autoplay: true,
+            show_history: false,
+            error: None,
+            debug: None,
+        }
+    }
+
+    /// Loads a saved game or round from a file.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        match RawGame::from_json_file(path) {
+            Ok(game) => {
+                let mut game = game.into_game()?;
+                // Drop events that were replayed while restoring the round.
+                while game.round_mut().pop_event().is_some() {}
+                let round = game.round();
+                let deal = Event::Deal(round.dealer(), round.top_card());
+                Ok(Self::new(game, deal))
+            }
+            Err(_) => {
+                let log = RawLog::from_json_file(path)?;
+                let round = LoggingRound::from(log.into_log());
+                Ok(Game::from(round).with_target_score(1).into())
+            }
+        }
+    }
+
+    /// Returns the action that the user is expected to take, if any.
+    fn user_action(&self) -> Option<ExpectAction> {
+        if self.event.is_some() {
+            return None;
+        }
+        self.game
+            .round()
+            .next_action()
+            .filter(|expect| expect.seat == HUMAN_SEAT || !self.robot_autoplay)
+    }
+
+    /// Returns the seat whose hand is displayed.
+    fn hand_seat(&self) -> Seat {
+        self.user_action().map_or(HUMAN_SEAT, |expect| expect.seat)
+    }
+
+    /// Displays an event.
+    fn show_event(&mut self, event: Event) {
+        self.event = Some(event);
+        self.event_at = Instant::now();
+    }
+
+    /// Acknowledges the event being displayed.
+    fn acknowledge(&mut self) {
+        match self.event.take() {
+            Some(Event::Round(_)) => self.next_round(),
+            Some(event @ Event::Game(_)) => self.event = Some(event),
+            _ => self.game_step(),
+        }
+    }
+
+    /// Starts the next round of the game, and checks to see if the game is over.
+    fn next_round(&mut self) {
+        self.game.next_round();
+        if let Some(outcome) = self.game.outcome() {
+            self.show_event(Event::Game(outcome));
+        } else {
+            self.game_step();
+        }
+    }
+
+    /// Advances the state of the game until an event occurs, or the game is blocked waiting
+    /// on the user's action.
+    fn game_step(&mut self) {
+        self.event = None;
+        loop {
+            if let Some(event) = self.game.round_mut().pop_event() {
+                self.show_event(event);
+                break;
+            }
+
+            // We may have missed the end-of-round event, because we dropped events while
+            // seeking through history. Generate a synthetic event.
+            if let Some(outcome) = self.game.round().outcome() {
+                self.show_event(Event::Round(outcome));
+                break;
+            }
+
+            match self.game.round().next_action() {
+                Some(expect) if expect.seat == HUMAN_SEAT || !self.robot_autoplay => break,
+                Some(expect) => self.play_as_robot(expect),
+                None => break,
+            }
+        }
+    }
+
+    /// Applies the specified action to the game.
+    fn apply_action(&mut self, action: Action) {
+        if let Err(err) = self.game.round_mut().apply_action(action) {
+            self.error = Some(err.to_string());
+        } else {
+            self.game_step();
+        }
+    }
+
+    /// Uses the robot to resolve the next action.
+    fn play_as_robot(&mut self, expect: ExpectAction) {
+        let round = self.game.round_mut();
+        let state = round.player_state(expect.seat);
+        let data = self.robot.take_action(state, expect.action);
+        round
+            .apply_action(expect.with_data(data))
+            .expect("robots don't err");
+    }
+
+    /// Asks what the robot would do, displaying the result as a debug message.
+    fn ask_robot(&mut self) {
+        let round = self.game.round();
+        self.debug = round.next_action().map(|expect| {
+            let data = self
+                .robot
+                .take_action(round.player_state(expect.seat), expect.action);
+            tr!("ui-robot-suggests", action = action_label(data))
+        });
+    }
+
+    /// Seeks to a particular point in round history.
+    fn seek_round_history(&mut self, id: Option<LogId>) {
+        if let Err(e) = self.game.round_mut().seek(id) {
+            self.error = Some(e.to_string());
+        } else {
+            // Drop events.
+            while self.game.round_mut().pop_event().is_some() {}
+            self.game_step();
+        }
+    }
+
+    /// Saves the game to a file.
+    fn save_game(&mut self) {
+        let result = File::create("euchre.json")
+            .map_err(anyhow::Error::from)
+            .and_then(|file| Ok(serde_json::to_writer(file, &RawGame::from(&self.game))?));
+        match result {
+            Ok(()) => self.debug = Some(tr!("ui-wrote", path = "euchre.json")),
+            Err(e) => {
+                self.error = Some(tr!(
+                    "ui-write-failed",
+                    path = "euchre.json",
+                    error = e.to_string()
+                ));
+            }
+        }
+    }
+
+    /// Renders the menu bar.
+    fn menu_bar(&mut self, ui: &mut egui::Ui) {
+        egui::menu::bar(ui, |ui| {
+            if ui.button(tr!("ui-new-game")).clicked() {
+                *self = Self::default();
+            }
+            if ui.button(tr!("ui-save")).clicked() {
+                self.save_game();
+            }
+            ui.separator();
+            // In competitive mode, the history and hints unlock once the game is over.
+            let locked = self.game.assists_locked();
+            ui.add_enabled_ui(!locked, |ui| {
+                ui.toggle_value(&mut self.show_history, tr!("ui-history"))
+            });
+            let autoplay = ui.checkbox(&mut self.robot_autoplay, tr!("ui-robot-autoplay"));
+            if autoplay.changed() && self.event.is_none() {
+                self.game_step();
+            }
+            if ui
+                .add_enabled(
+                    self.user_action().is_some() && !locked,
+                    egui::Button::new(tr!("ui-hint")),
+                )
+                .clicked()
+            {
+                self.ask_robot();
+            }
+        });
+    }
+
+    /// Renders the scoreboard and the status of the round.
+    fn status(&self, ui: &mut egui::Ui) {
+        let tricks = self.game.round().tricks();
+        egui::Grid::new("score").striped(true).show(ui, |ui| {
+            ui.label("");
+            ui.strong("N/S");
+            ui.strong("E/W");
+            ui.end_row();
+            ui.label(tr!("ui-score"));
+            ui.label(self.game.score(Team::NorthSouth).to_string());
+            ui.label(self.game.score(Team::EastWest).to_string());
+            ui.end_row();
+            ui.label(tr!("ui-tricks"));
+            ui.label(tricks.win_count(Team::NorthSouth).to_string());
+            ui.label(tricks.win_count(Team::EastWest).to_string());
+            ui.end_row();
+        });
+        ui.separator();
+        for line in table::status_lines(self.event.as_ref(), self.game.round()) {
+            ui.label(line);
+        }
+        if let Some(error) = &self.error {
+            ui.label(RichText::new(error).color(Color32::RED).strong());
+        }
+        if let Some(debug) = &self.debug {
+            ui.label(RichText::new(debug).color(Color32::LIGHT_BLUE).strong());
+        }
+    }
+
+    /// Renders the hand, and whatever controls the user needs to act.
+    fn controls(&mut self, ui: &mut egui::Ui) {
+        let expect = self.user_action();
+        let seat = self.hand_seat();
+        let round = self.game.round();
+        let legal = if expect.is_some() {
+            round.legal_actions()
+        } else {
+            vec![]
+        };
+        let choosing_card = expect.is_some_and(|expect| {
+            matches!(
+                expect.action,
+                ActionType::DealerDiscard | ActionType::Lead | ActionType::Follow
+            )
+        });
+
+        let mut chosen = None;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(seat.to_string()).strong());
+            for card in round.player_state(seat).sorted_hand() {
+                let playable = legal
+                    .iter()
+                    .find(|action| action.data == ActionData::Card { card });
+                let response = card::hand_card(
+                    ui,
+                    card,
+                    playable.is_some(),
+                    choosing_card && playable.is_none(),
+                );
+                if response.clicked() {
+                    chosen = playable.copied();
+                }
+            }
+        });
+        ui.add_space(4.0);
+        ui.horizontal(|ui| match expect.map(|expect| expect.action) {
+            Some(ActionType::BidTop | ActionType::BidOther) => {
+                for action in &legal {
+                    if ui.button(action_label(action.data)).clicked() {
+                        chosen = Some(*action);
+                    }
+                }
+            }
+            Some(ActionType::DealerDiscard) => {
+                ui.label(tr!("ui-click-to-discard"));
+            }
+            Some(ActionType::Lead | ActionType::Follow) => {
+                ui.label(tr!("ui-click-to-play"));
+            }
+            Some(ActionType::CallRenege) => (),
+            None => {
+                let pending = !matches!(self.event, None | Some(Event::Game(_)));
+                if pending && ui.button(tr!("ui-continue")).clicked() {
+                    self.error = None;
+                    self.debug = None;
+                    self.acknowledge();
+                }
+            }
+        });
+
+        if let Some(action) = chosen {
+            self.error = None;
+            self.debug = None;
+            self.apply_action(action);
+        }
+    }
+
+    /// Renders the end-of-game summary.
+    fn summary(&mut self, ctx: &egui::Context) {
+        let Some(Event::Game(outcome)) = &self.event else {
+            return;
+        };
+        let outcome = *outcome;
+        let mut new_game = false;
+        egui::Window::new(tr!("ui-game-over"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(tr!(
+                    "ui-wins-by",
+                    team = outcome.winner.to_abbr(),
+                    margin = outcome.margin()
+                ));
+                ui.label(tr!("ui-rounds-played", rounds = outcome.rounds));
+                egui::Grid::new("summary").show(ui, |ui| {
+                    ui.label("");
+                    ui.strong("N/S");
+                    ui.strong("E/W");
+                    ui.end_row();
+                    for (label, counts) in
+                        [("ui-score", outcome.score), ("ui-loners", outcome.loners)]
+                    {
+                        ui.label(i18n::tr(label, None));
+                        ui.label(counts[Team::NorthSouth].to_string());
+                        ui.label(counts[Team::EastWest].to_string());
+                        ui.end_row();
+                    }
+                });
+                new_game = ui.button(tr!("ui-new-game")).clicked();
+            });
+        if new_game {
+            *self = Self::default();
+        }
+    }
+}
+
+impl eframe::App for Gui {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // The deal is animated, advancing automatically.
+        if let Some(Event::Packet(_, _)) = self.event {
+            if self.event_at.elapsed() >= PACKET_DELAY {
+                self.game_step();
+            }
+            ctx.request_repaint_after(PACKET_DELAY);
+        }
+
+        egui::TopBottomPanel::top("menu").show(ctx, |ui| self.menu_bar(ui));
+        egui::SidePanel::left("status")
+            .resizable(false)
+            .exact_width(200.0)
+            .show(ctx, |ui| self.status(ui));
+        if self.show_history && !self.game.assists_locked() {
+            let mut seek = None;
+            egui::SidePanel::right("history")
+                .default_width(240.0)
+                .show(ctx, |ui| {
+                    ui.heading(tr!("ui-history"));
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        seek = history::show(ui, self.game.round());
+                    });
+                });
+            if let Some(id) = seek {
+                self.seek_round_history(id);
+            }
+        }
+        egui::TopBottomPanel::bottom("hand")
+            .min_height(CARD_SIZE.y + 48.0)
+            .show(ctx, |ui| self.controls(ui));
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let acting = self.game.round().next_action().map(|e| e.seat);
+            let clicked = table::show(ui, self.event.as_ref(), self.game.round(), acting);
+            if clicked && self.event.is_some() {
+                self.acknowledge();
+            }
+        });
+        self.summary(ctx);
+    }
+}
+
+/// Describes an action, for use as a button label.
+fn action_label(data: ActionData) -> String {
+    match data {
+        ActionData::Pass => tr!("action-pass"),
+        ActionData::Call { suit, alone: false } => {
+            tr!("action-call", suit = card::suit_symbol(suit).to_string())
+        }
+        ActionData::Call { suit, alone: true } => {
+            tr!(
+                "action-call-alone",
+                suit = card::suit_symbol(suit).to_string()
+            )
+        }
+        ActionData::Card { card } => card::label(card),
+    }
+}
@@ -0,0 +1,103 @@
+//! Card graphics.
+
+use eframe::egui::{self, Align2, Color32, FontId, Painter, Rect, Response, Sense, Stroke, Vec2};
+
+use crate::euchre::{Card, Rank, Suit};
+use crate::french::Color;
+
+/// The size of a card.
+pub const CARD_SIZE: Vec2 = Vec2::new(60.0, 84.0);
+
+/// The radius of a card's corners.
+const ROUNDING: f32 = 6.0;
+
+/// The color of the card backs.
+const BACK: Color32 = Color32::from_rgb(40, 70, 140);
+
+/// How far a playable card rises when the pointer hovers over it.
+const HOVER_LIFT: f32 = 8.0;
+
+/// Returns the symbol for the suit. Unlike the suit's [`Display`](std::fmt::Display)
+/// representation, all suits are drawn solid.
+pub fn suit_symbol(suit: Suit) -> char {
+    match suit {
+        Suit::Club => '♣',
+        Suit::Diamond => '♦',
+        Suit::Heart => '♥',
+        Suit::Spade => '♠',
+    }
+}
+
+/// Returns a short label for the card, like `J♥`.
+pub fn label(card: Card) -> String {
+    let rank = match card.rank {
+        Rank::Ten => String::from("10"),
+        rank => rank.to_string(),
+    };
+    format!("{rank}{}", suit_symbol(card.suit))
+}
+
+/// Returns the ink color for the suit.
+fn ink(suit: Suit) -> Color32 {
+    match suit.color() {
+        Color::Red => Color32::from_rgb(200, 30, 30),
+        Color::Black => Color32::BLACK,
+    }
+}
+
+/// Paints a face-up card.
+pub fn paint_face(painter: &Painter, rect: Rect, card: Card, dimmed: bool) {
+    let fill = if dimmed {
+        Color32::from_gray(190)
+    } else {
+        Color32::WHITE
+    };
+    painter.rect(rect, ROUNDING, fill, Stroke::new(1.0, Color32::DARK_GRAY));
+    let ink = ink(card.suit);
+    let corner = rect.left_top() + Vec2::new(5.0, 3.0);
+    painter.text(
+        corner,
+        Align2::LEFT_TOP,
+        label(card),
+        FontId::proportional(15.0),
+        ink,
+    );
+    painter.text(
+        rect.center() + Vec2::new(0.0, 8.0),
+        Align2::CENTER_CENTER,
+        suit_symbol(card.suit),
+        FontId::proportional(30.0),
+        ink,
+    );
+}
+
+/// Paints a face-down card.
+pub fn paint_back(painter: &Painter, rect: Rect) {
+    painter.rect(rect, ROUNDING, BACK, Stroke::new(1.0, Color32::DARK_GRAY));
+    painter.rect_stroke(
+        rect.shrink(5.0),
+        ROUNDING / 2.0,
+        Stroke::new(1.0, Color32::from_rgb(150, 170, 220)),
+    );
+}
+
+/// Adds a card from the player's hand, which can be clicked if it's playable. Cards that the
+/// player may not play are dimmed.
+pub fn hand_card(ui: &mut egui::Ui, card: Card, playable: bool, dimmed: bool) -> Response {
+    let sense = if playable {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let (rect, response) = ui.allocate_exact_size(CARD_SIZE + Vec2::new(0.0, HOVER_LIFT), sense);
+    let mut card_rect = Rect::from_min_size(rect.min + Vec2::new(0.0, HOVER_LIFT), CARD_SIZE);
+    if playable && response.hovered() {
+        card_rect = card_rect.translate(Vec2::new(0.0, -HOVER_LIFT));
+    }
+    paint_face(ui.painter(), card_rect, card, dimmed);
+    if playable {
+        response.on_hover_cursor(egui::CursorIcon::PointingHand)
+    } else {
+        response
+    }
+}
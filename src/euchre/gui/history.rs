@@ -0,0 +1,102 @@
+//! The history tree.
+
+use std::collections::BTreeMap;
+
+use eframe::egui::{self, RichText};
+
+use crate::euchre::{Action, ActionData, ActionType, LogId, LoggingRound};
+
+use super::card;
+
+/// Describes an action, as a history entry.
+fn describe(action: Action) -> String {
+    let seat = action.seat;
+    match (action.action, action.data) {
+        (_, ActionData::Pass) => format!("{seat} passed"),
+        (_, ActionData::Call { suit, alone }) => format!(
+            "{seat} called {}{}",
+            card::suit_symbol(suit),
+            if alone { " alone" } else { "" }
+        ),
+        (ActionType::DealerDiscard, ActionData::Card { card }) => {
+            format!("{seat} discarded {}", card::label(card))
+        }
+        (ActionType::Lead, ActionData::Card { card }) => {
+            format!("{seat} led {}", card::label(card))
+        }
+        (_, ActionData::Card { card }) => format!("{seat} followed {}", card::label(card)),
+    }
+}
+
+/// The actions in a round's log, indexed by parent.
+struct Branches(BTreeMap<Option<LogId>, Vec<(LogId, Action)>>);
+
+impl Branches {
+    fn new(round: &LoggingRound) -> Self {
+        let mut branches: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for node in round.log().action_nodes() {
+            branches
+                .entry(node.parent)
+                .or_default()
+                .push((node.id, node.action));
+        }
+        for children in branches.values_mut() {
+            children.sort_unstable_by_key(|(id, _)| *id);
+        }
+        Self(branches)
+    }
+
+    /// Renders the line of play that follows `parent`. A line continues at the same
+    /// indentation until it forks, and each fork is indented beneath it.
+    #[allow(clippy::option_option)]
+    fn show(
+        &self,
+        ui: &mut egui::Ui,
+        mut parent: Option<LogId>,
+        cursor: Option<LogId>,
+    ) -> Option<Option<LogId>> {
+        let mut seek = None;
+        loop {
+            match self.0.get(&parent).map(Vec::as_slice) {
+                Some(&[(id, action)]) => {
+                    if ui
+                        .selectable_label(cursor == Some(id), describe(action))
+                        .clicked()
+                    {
+                        seek = Some(Some(id));
+                    }
+                    parent = Some(id);
+                }
+                Some(children) => {
+                    for &(id, action) in children {
+                        ui.indent(id, |ui| {
+                            if ui
+                                .selectable_label(cursor == Some(id), describe(action))
+                                .clicked()
+                            {
+                                seek = Some(Some(id));
+                            }
+                            seek = self.show(ui, Some(id), cursor).or(seek);
+                        });
+                    }
+                    return seek;
+                }
+                None => return seek,
+            }
+        }
+    }
+}
+
+/// Renders the history of the round as a tree. Returns the point in history that the user
+/// selected, if any; the deal is represented by `Some(None)`.
+#[allow(clippy::option_option)]
+pub fn show(ui: &mut egui::Ui, round: &LoggingRound) -> Option<Option<LogId>> {
+    let cursor = round.cursor();
+    let dealer = round.log().config().dealer();
+    let mut seek = None;
+    let deal = RichText::new(format!("{dealer} dealt")).strong();
+    if ui.selectable_label(cursor.is_none(), deal).clicked() {
+        seek = Some(None);
+    }
+    Branches::new(round).show(ui, None, cursor).or(seek)
+}
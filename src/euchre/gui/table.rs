@@ -0,0 +1,157 @@
+//! The play area.
+
+use eframe::egui::{self, Align2, Color32, FontId, Rect, Sense, Vec2};
+
+use crate::euchre::{ActionType, Card, Event, Round, RoundOutcome, Seat, SeatMap};
+use crate::tr;
+
+use super::card::{self, CARD_SIZE};
+
+/// The cards on the table.
+struct Arena {
+    top: Option<Card>,
+    cards: SeatMap<Option<Card>>,
+    packet: Option<Seat>,
+}
+
+impl Arena {
+    fn new(event: Option<&Event>, round: &impl Round) -> Self {
+        let action = round.next_action().map(|expect| expect.action);
+        let top = match (event, action) {
+            (Some(Event::Game(_) | Event::Cut(_)), _) => None,
+            (Some(Event::Deal(_, _)), _) | (_, Some(ActionType::BidTop)) => Some(round.top_card()),
+            _ => None,
+        };
+        let trick = match (event, action) {
+            (Some(Event::Trick(trick)), _) => Some(trick),
+            (_, Some(ActionType::Follow)) => round.tricks().in_progress(),
+            _ => None,
+        };
+        let cards = match (event, trick) {
+            (Some(Event::Cut(cut)), _) => SeatMap::from_fn(|seat| cut.card(seat)),
+            (_, Some(trick)) => SeatMap::from_fn(|seat| trick.get_card(seat)),
+            _ => SeatMap::default(),
+        };
+        let packet = match event {
+            Some(Event::Packet(seat, _)) => Some(*seat),
+            _ => None,
+        };
+        Self { top, cards, packet }
+    }
+}
+
+/// Returns the direction from the center of the table towards the seat.
+fn direction(seat: Seat) -> Vec2 {
+    match seat {
+        Seat::North => Vec2::new(0.0, -1.0),
+        Seat::East => Vec2::new(1.0, 0.0),
+        Seat::South => Vec2::new(0.0, 1.0),
+        Seat::West => Vec2::new(-1.0, 0.0),
+    }
+}
+
+/// Renders the table, highlighting the seat that is expected to act. Returns true if the
+/// table was clicked.
+pub fn show(
+    ui: &mut egui::Ui,
+    event: Option<&Event>,
+    round: &impl Round,
+    acting: Option<Seat>,
+) -> bool {
+    let (rect, response) = ui.allocate_exact_size(ui.available_size(), Sense::click());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 12.0, Color32::from_rgb(30, 100, 50));
+
+    let arena = Arena::new(event, round);
+    let center = rect.center();
+    let card_offset = Vec2::new(CARD_SIZE.x * 1.2, CARD_SIZE.y * 0.8);
+    let label_offset = rect.size() / 2.0 - Vec2::splat(24.0);
+    for &seat in Seat::all_seats() {
+        let dir = direction(seat);
+        let color = if Some(seat) == acting && event.is_none() {
+            Color32::YELLOW
+        } else {
+            Color32::WHITE
+        };
+        painter.text(
+            center + dir * label_offset,
+            Align2::CENTER_CENTER,
+            seat.to_string(),
+            FontId::proportional(18.0),
+            color,
+        );
+        let card_rect = Rect::from_center_size(center + dir * card_offset, CARD_SIZE);
+        if arena.packet == Some(seat) {
+            card::paint_back(&painter, card_rect);
+        } else if let Some(card) = arena.cards[seat] {
+            card::paint_face(&painter, card_rect, card, false);
+        }
+    }
+    if let Some(top) = arena.top {
+        card::paint_face(
+            &painter,
+            Rect::from_center_size(center, CARD_SIZE),
+            top,
+            false,
+        );
+    }
+    response.clicked()
+}
+
+/// Describes the state of the round, or the event being displayed.
+pub fn status_lines(event: Option<&Event>, round: &impl Round) -> Vec<String> {
+    let first = match (event, round.contract()) {
+        (Some(Event::Game(_)), _) => None,
+        (Some(Event::Cut(_)), _) => Some(tr!("narration-low-card-deals")),
+        (_, Some(contract)) => {
+            let seat = contract.maker.to_string();
+            let suit = card::suit_symbol(contract.suit).to_string();
+            Some(if contract.alone {
+                tr!("narration-called-alone", seat = seat, suit = suit)
+            } else {
+                tr!("narration-called", seat = seat, suit = suit)
+            })
+        }
+        (_, None) => Some(tr!("narration-dealt", seat = round.dealer().to_string())),
+    };
+    let second = match (event, round.next_action()) {
+        (Some(Event::Cut(cut)), _) => {
+            Some(tr!("narration-deals-first", seat = cut.dealer.to_string()))
+        }
+        (Some(Event::Packet(seat, count)), _) => Some(tr!(
+            "narration-packet",
+            count = *count,
+            seat = seat.to_string()
+        )),
+        (Some(Event::Deal(_, card)), _) => {
+            Some(tr!("narration-turned-up", card = card::label(*card)))
+        }
+        (Some(Event::Redeal(dealer)), _) => {
+            Some(tr!("narration-redeal", seat = dealer.to_string()))
+        }
+        (Some(Event::Call(_)), _) => None,
+        (Some(Event::Renege(revoke)), _) => Some(tr!(
+            "narration-renege",
+            seat = revoke.seat.to_string(),
+            card = card::label(revoke.card)
+        )),
+        (Some(Event::Trick(trick)), _) => {
+            Some(tr!("narration-trick", seat = trick.best().0.to_string()))
+        }
+        (Some(Event::Round(RoundOutcome { team, points })), _) => Some(tr!(
+            "narration-round",
+            team = team.to_abbr(),
+            points = *points
+        )),
+        (Some(Event::Game(outcome)), _) => {
+            Some(tr!("narration-game", team = outcome.winner.to_abbr()))
+        }
+        (None, Some(expect)) => Some(tr!(
+            "narration-expect",
+            seat = expect.seat.to_string(),
+            action = expect.action.to_string()
+        )),
+        (None, None) => None,
+    };
+    first.into_iter().chain(second).collect()
+}
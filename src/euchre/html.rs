@@ -0,0 +1,299 @@
+//! HTML report export.
+//!
+//! Reports are standalone HTML documents, with inline styles and no external resources, so that
+//! they can be shared as a single file.
+
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::french::Color;
+use crate::tr;
+
+use super::{
+    BiddingReport, Card, Game, LeadCategory, LeadReport, LeadRole, LoggingRound, Round, Seat,
+    Tally, Team, Trick,
+};
+
+#[cfg(test)]
+mod test;
+
+const STYLE: &str = "
+body { font-family: sans-serif; max-width: 48em; margin: 2em auto; color: #222; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { padding: 0.2em 0.8em; text-align: right; }
+th:first-child, td:first-child { text-align: left; }
+thead th { border-bottom: 1px solid #888; }
+.card { font-family: monospace; font-size: 1.1em; white-space: nowrap; }
+.red { color: #c00; }
+.tricks { display: flex; flex-wrap: wrap; gap: 1em; }
+.trick { display: grid; grid-template: repeat(3, 2em) / repeat(3, 3em); border: 1px solid #ccc;
+  border-radius: 0.4em; padding: 0.3em; place-items: center; }
+.trick .lead { text-decoration: underline; }
+.trick .best { font-weight: bold; background: #ffd; border-radius: 0.2em; }
+.trick .label { grid-area: 2 / 2; color: #888; font-size: 0.8em; }
+";
+
+/// Escapes text for inclusion in HTML.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a card, colored by suit.
+fn card_html(card: Card) -> String {
+    match card.suit.color() {
+        Color::Red => format!("<span class=\"card red\">{card}</span>"),
+        Color::Black => format!("<span class=\"card\">{card}</span>"),
+    }
+}
+
+/// Renders a list of cards.
+fn cards_html(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|&c| card_html(c))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the grid area for a seat in a trick diagram, with north at the top.
+fn grid_area(seat: Seat) -> &'static str {
+    match seat {
+        Seat::North => "1 / 2",
+        Seat::West => "2 / 1",
+        Seat::East => "2 / 3",
+        Seat::South => "3 / 2",
+    }
+}
+
+/// A standalone HTML document, built up section by section.
+#[derive(Debug, Clone)]
+pub struct HtmlReport {
+    title: String,
+    body: String,
+}
+
+impl HtmlReport {
+    /// Creates an empty report with the specified title.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            body: format!("<h1>{}</h1>\n", escape(title)),
+        }
+    }
+
+    /// Adds a section heading.
+    pub fn heading(mut self, text: &str) -> Self {
+        writeln!(self.body, "<h2>{}</h2>", escape(text)).expect("infallible");
+        self
+    }
+
+    /// Adds a paragraph of text.
+    pub fn paragraph(mut self, text: &str) -> Self {
+        writeln!(self.body, "<p>{}</p>", escape(text)).expect("infallible");
+        self
+    }
+
+    /// Adds a table. Cells are escaped.
+    pub fn table(mut self, headers: &[&str], rows: &[Vec<String>]) -> Self {
+        self.body.push_str("<table>\n<thead><tr>");
+        for header in headers {
+            write!(self.body, "<th>{}</th>", escape(header)).expect("infallible");
+        }
+        self.body.push_str("</tr></thead>\n<tbody>\n");
+        for row in rows {
+            self.body.push_str("<tr>");
+            for cell in row {
+                write!(self.body, "<td>{}</td>", escape(cell)).expect("infallible");
+            }
+            self.body.push_str("</tr>\n");
+        }
+        self.body.push_str("</tbody>\n</table>\n");
+        self
+    }
+
+    /// Adds the score table for a game.
+    pub fn score<R: Round>(self, game: &Game<R>) -> Self {
+        let mut rows = vec![vec![
+            tr!("ui-score"),
+            game.score(Team::NorthSouth).to_string(),
+            game.score(Team::EastWest).to_string(),
+        ]];
+        if let Some(outcome) = game.outcome() {
+            rows.push(vec![
+                tr!("ui-loners"),
+                outcome.loners[Team::NorthSouth].to_string(),
+                outcome.loners[Team::EastWest].to_string(),
+            ]);
+        }
+        let summary = match game.outcome() {
+            Some(outcome) => format!("{outcome}."),
+            None => tr!("report-in-progress", rounds = game.rounds()),
+        };
+        self.heading(&tr!("ui-score"))
+            .paragraph(&summary)
+            .table(&["", "N/S", "E/W"], &rows)
+    }
+
+    /// Adds the deal, contract, trick diagrams and outcome for a round, as played up to its
+    /// current position.
+    pub fn round(mut self, round: &LoggingRound) -> Self {
+        let config = round.log().config();
+        self = self.heading(&tr!("report-deal"));
+        let dealer = config.dealer().to_string();
+        let text = tr!(
+            "report-dealt",
+            dealer = dealer,
+            card = card_html(config.top())
+        );
+        writeln!(self.body, "<p>{text}</p>").expect("infallible");
+        self.body.push_str("<table>\n<tbody>\n");
+        for &seat in Seat::all_seats() {
+            writeln!(
+                self.body,
+                "<tr><td>{seat}</td><td>{}</td></tr>",
+                cards_html(config.hand(seat))
+            )
+            .expect("infallible");
+        }
+        self.body.push_str("</tbody>\n</table>\n");
+
+        if let Some(contract) = round.contract() {
+            let suit = match contract.suit.color() {
+                Color::Red => format!("<span class=\"red\">{}</span>", contract.suit),
+                Color::Black => contract.suit.to_string(),
+            };
+            let maker = contract.maker.to_string();
+            let text = if contract.alone {
+                tr!("report-called-alone", seat = maker, suit = suit)
+            } else {
+                tr!("report-called", seat = maker, suit = suit)
+            };
+            writeln!(self.body, "<p>{text}</p>").expect("infallible");
+        }
+
+        let tricks = round.tricks();
+        if !tricks.is_empty() {
+            self = self.heading(&tr!("report-tricks"));
+            self.body.push_str("<div class=\"tricks\">\n");
+            for (i, trick) in tricks.iter().enumerate() {
+                self.trick(i + 1, trick);
+            }
+            self.body.push_str("</div>\n");
+        }
+
+        if let Some(outcome) = round.outcome() {
+            self = self.paragraph(&format!("{outcome}."));
+        }
+        self
+    }
+
+    /// Adds a trick diagram, with the lead underlined and the winning card highlighted.
+    fn trick(&mut self, number: usize, trick: &Trick) {
+        self.body.push_str("<div class=\"trick\">");
+        write!(self.body, "<span class=\"label\">#{number}</span>").expect("infallible");
+        let (leader, _) = trick.lead();
+        let (best, _) = trick.best();
+        for &(seat, card) in trick.cards() {
+            let mut class = vec![];
+            if seat == leader {
+                class.push("lead");
+            }
+            if seat == best {
+                class.push("best");
+            }
+            write!(
+                self.body,
+                "<span class=\"{}\" style=\"grid-area: {}\" title=\"{seat}\">{}</span>",
+                class.join(" "),
+                grid_area(seat),
+                card_html(card)
+            )
+            .expect("infallible");
+        }
+        self.body.push_str("</div>\n");
+    }
+
+    /// Adds the results of a bidding analysis.
+    pub fn bidding(self, report: &BiddingReport) -> Self {
+        let pct = |n: u32, d: u32| {
+            if d == 0 {
+                "-".to_string()
+            } else {
+                format!("{:.0}%", f64::from(n) * 100.0 / f64::from(d))
+            }
+        };
+        let rows: Vec<_> = report
+            .rows()
+            .iter()
+            .map(|row| {
+                vec![
+                    row.position_name(),
+                    row.strength_label().to_string(),
+                    row.passes.to_string(),
+                    pct(row.passes_correct, row.passes),
+                    row.calls.to_string(),
+                    pct(row.calls_correct, row.calls),
+                ]
+            })
+            .collect();
+        let headers = [
+            tr!("report-position"),
+            tr!("report-strength"),
+            tr!("report-passes"),
+            tr!("report-pass-ok"),
+            tr!("report-calls"),
+            tr!("report-call-ok"),
+        ];
+        let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+        self.heading(&tr!("report-bidding-accuracy"))
+            .table(&headers, &rows)
+    }
+
+    /// Adds the results of a lead-choice analysis.
+    pub fn leads(self, report: &LeadReport) -> Self {
+        let rows = |table: Vec<Vec<Tally>>| -> Vec<Vec<String>> {
+            LeadCategory::all()
+                .iter()
+                .zip(table)
+                .map(|(category, row)| {
+                    std::iter::once(category.to_string())
+                        .chain(row.into_iter().map(|t| t.to_string()))
+                        .collect()
+                })
+                .collect()
+        };
+        let roles: Vec<String> = LeadRole::all().iter().map(ToString::to_string).collect();
+        let lead = tr!("report-lead");
+        let role_headers: Vec<&str> = std::iter::once(lead.as_str())
+            .chain(roles.iter().map(String::as_str))
+            .collect();
+        let tricks: Vec<String> = (1..=5).map(|n| tr!("report-trick-number", n = n)).collect();
+        let trick_headers: Vec<&str> = std::iter::once(lead.as_str())
+            .chain(tricks.iter().map(String::as_str))
+            .collect();
+        self.heading(&tr!("report-leads-by-role"))
+            .table(&role_headers, &rows(report.by_role()))
+            .heading(&tr!("report-leads-by-trick"))
+            .table(&trick_headers, &rows(report.by_trick()))
+    }
+
+    /// Renders the document.
+    pub fn render(&self) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             <style>{STYLE}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+            escape(&self.title),
+            self.body
+        )
+    }
+
+    /// Writes the document to a file.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.render())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::euchre::{Player, Robot};
+
+use super::*;
+
+#[test]
+fn test_escape() {
+    let html = HtmlReport::new("<N&S>").paragraph("\"hi\"").render();
+    assert!(html.contains("<title>&lt;N&amp;S&gt;</title>"));
+    assert!(html.contains("<p>&quot;hi&quot;</p>"));
+}
+
+#[test]
+fn test_round() {
+    let robot = Robot::default();
+    let round = loop {
+        let mut round = LoggingRound::random();
+        while let Some(expect) = round.next_action() {
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        // Skip deals that everyone passed.
+        if round.contract().is_some() {
+            break round;
+        }
+    };
+    let html = HtmlReport::new("Round").round(&round).render();
+    let tricks = html.matches("<div class=\"trick\">").count();
+    assert_eq!(tricks, round.tricks().iter().count());
+    assert!(tricks >= 3);
+    assert!(html.contains(&format!("{}.", round.outcome().unwrap())));
+}
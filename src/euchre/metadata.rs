@@ -0,0 +1,84 @@
+//! Descriptive metadata for saved games.
+
+use std::convert::TryFrom;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Rules, Seat, SeatMap};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Optional details about a saved game, for display when choosing a file to load.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The names of the players at each seat. Empty names are unknown.
+    #[serde(default)]
+    pub players: SeatMap<String>,
+    /// The date on which the game was saved, as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub date: String,
+    /// The rules of the game.
+    #[serde(default)]
+    pub rules: Option<Rules>,
+    /// Free-form notes.
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Metadata {
+    /// Returns a one-line summary of the metadata, omitting anything that's empty.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![];
+        if !self.date.is_empty() {
+            parts.push(self.date.clone());
+        }
+        if let Some(rules) = self.rules {
+            let mut rules_parts = vec![tr!("metadata-target", points = rules.target_score)];
+            if rules.best_of > 1 {
+                rules_parts.push(tr!("metadata-best-of", games = rules.best_of));
+            }
+            if let Some(control) = rules.time_control {
+                rules_parts.push(tr!("metadata-clock", control = control.to_string()));
+            }
+            parts.push(rules_parts.join(", "));
+        }
+        let players: Vec<String> = Seat::all_seats()
+            .iter()
+            .filter(|&&seat| !self.players[seat].is_empty())
+            .map(|&seat| format!("{}: {}", seat.to_abbr(), self.players[seat]))
+            .collect();
+        if !players.is_empty() {
+            parts.push(players.join(", "));
+        }
+        if !self.notes.is_empty() {
+            parts.push(self.notes.clone());
+        }
+        parts.join(" · ")
+    }
+
+    /// Returns today's date in UTC, as `YYYY-MM-DD`.
+    pub fn today() -> String {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        format_date(i64::try_from(secs / 86_400).unwrap_or_default())
+    }
+}
+
+/// Formats a number of days since the Unix epoch as a date in the proleptic Gregorian calendar.
+fn format_date(days: i64) -> String {
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
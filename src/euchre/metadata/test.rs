@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_format_date() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(11_016), "2000-02-29");
+    assert_eq!(format_date(20_742), "2026-10-16");
+    assert_eq!(format_date(-1), "1969-12-31");
+}
+
+#[test]
+fn test_summary() {
+    assert_eq!(Metadata::default().summary(), "");
+    let mut meta = Metadata {
+        date: "2024-01-02".into(),
+        rules: Some(Rules {
+            best_of: 3,
+            ..Rules::default()
+        }),
+        notes: "club night".into(),
+        ..Metadata::default()
+    };
+    meta.players[Seat::South] = "Ann".into();
+    meta.players[Seat::North] = "Bo".into();
+    assert_eq!(
+        meta.summary(),
+        "2024-01-02 · to 10, best of 3 · N: Bo, S: Ann · club night"
+    );
+}
@@ -0,0 +1,61 @@
+//! Network play.
+//!
+//! A host runs a lobby of named tables, and clients connect to it to claim seats at them. Each
+//! table begins its game once all of its players are ready, and is closed when the game ends.
+//! Clients may use either a WebSocket, or a plain TCP stream carrying length-prefixed JSON
+//! messages, which is convenient on a LAN. Seats without a connected client are played by
+//! robots. The host sends each client a [`GameView`](super::GameView) whenever the state of the
+//! game changes, followed by any events that occurred. Clients respond with actions when it's
+//! their turn. On the host, each client is represented by a [`RemotePlayer`], so that the game
+//! loop drives humans and robots alike through the [`Player`](super::Player) trait.
+//!
+//! Clients may also join as spectators, who receive public views of the game that reveal no
+//! player's hand.
+//!
+//! On joining, each client is issued a token, which it can present to reclaim its seat after
+//! losing its connection.
+
+mod client;
+mod http;
+mod lobby;
+mod protocol;
+mod remote;
+mod server;
+mod transport;
+pub use client::Client;
+pub use http::{router, ApiError, Claim, Events, Games, NewGame, TableInfo};
+pub use protocol::{ClientMessage, SeatStatus, ServerMessage, TableSummary, DEFAULT_TABLE};
+pub use remote::RemotePlayer;
+pub use server::{ChatPolicy, Server};
+pub use transport::Transport;
+
+#[cfg(test)]
+mod test;
+
+use super::Seat;
+
+/// The order in which seats are assigned to joining players. The first two players are partners.
+const SEAT_ORDER: [Seat; 4] = [Seat::South, Seat::North, Seat::East, Seat::West];
+
+/// An error that can occur during network play.
+#[derive(Debug, thiserror::Error)]
+pub enum NetError {
+    /// The peer disconnected.
+    #[error("disconnected")]
+    Disconnected,
+    /// The peer sent an unexpected message.
+    #[error("protocol error: {0}")]
+    Protocol(String),
+    /// An I/O error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// A WebSocket error.
+    #[error(transparent)]
+    WebSocket(Box<tungstenite::Error>),
+}
+
+impl From<tungstenite::Error> for NetError {
+    fn from(err: tungstenite::Error) -> Self {
+        NetError::WebSocket(Box::new(err))
+    }
+}
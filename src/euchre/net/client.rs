@@ -0,0 +1,176 @@
+//! Game client.
+
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::time::Duration;
+
+use tungstenite::stream::MaybeTlsStream;
+
+use crate::euchre::Seat;
+
+use super::transport::{self, Framed, POLL_INTERVAL};
+use super::{ClientMessage, NetError, ServerMessage, TableSummary};
+
+/// How long to wait for the host to assign a seat.
+const WELCOME_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A connection to a remote host.
+pub struct Client {
+    url: String,
+    /// The assigned seat, or `None` for a spectator.
+    seat: Option<Seat>,
+    /// The token used to rejoin the seat, or `None` for a spectator.
+    token: Option<String>,
+    /// The table watched by a spectator, or `None` for the default table.
+    table: Option<String>,
+    tx: Sender<ClientMessage>,
+    rx: Inbox,
+}
+
+/// Messages received from the host, terminated by `None` when the connection is lost.
+type Inbox = Receiver<Option<ServerMessage>>;
+
+impl Client {
+    /// Connects to the host at the specified URL, and waits to be assigned a seat at the
+    /// default table.
+    ///
+    /// The URL scheme selects the transport: `ws://host:port` for a WebSocket, or
+    /// `tcp://host:port` for length-prefixed JSON over a plain TCP stream.
+    pub fn connect(url: &str) -> Result<Self, NetError> {
+        Self::join(url, None, None)
+    }
+
+    /// Connects to the host at the specified URL, and claims a seat at the named table. If no
+    /// seat is specified, the host assigns the first open seat.
+    pub fn join(url: &str, table: Option<&str>, seat: Option<Seat>) -> Result<Self, NetError> {
+        let hello = ClientMessage::Join {
+            token: None,
+            table: table.map(str::to_string),
+            seat,
+        };
+        Self::open(url, hello)
+    }
+
+    /// Connects to the host at the specified URL as a spectator of the named table.
+    pub fn spectate(url: &str, table: Option<&str>) -> Result<Self, NetError> {
+        let table = table.map(str::to_string);
+        let mut client = Self::open(
+            url,
+            ClientMessage::Spectate {
+                table: table.clone(),
+            },
+        )?;
+        client.table = table;
+        Ok(client)
+    }
+
+    /// Lists the tables hosted at the specified URL.
+    pub fn list_tables(url: &str) -> Result<Vec<TableSummary>, NetError> {
+        let (tx, rx) = Self::dial(url)?;
+        tx.send(ClientMessage::ListTables)
+            .map_err(|_| NetError::Disconnected)?;
+        match rx.recv_timeout(WELCOME_TIMEOUT) {
+            Ok(Some(ServerMessage::Tables { tables })) => Ok(tables),
+            Ok(Some(msg)) => Err(NetError::Protocol(format!("unexpected message: {msg:?}"))),
+            Ok(None) | Err(_) => Err(NetError::Disconnected),
+        }
+    }
+
+    /// Closes the current connection, if it's still open, and rejoins the same seat.
+    ///
+    /// The host only releases the seat once it notices that the previous connection was lost,
+    /// so callers may need to retry.
+    pub fn reconnect(&mut self) -> Result<(), NetError> {
+        // Dropping the sender closes the connection.
+        self.tx = mpsc::channel().0;
+        let hello = match &self.token {
+            Some(token) => ClientMessage::Join {
+                token: Some(token.clone()),
+                table: None,
+                seat: None,
+            },
+            None => ClientMessage::Spectate {
+                table: self.table.clone(),
+            },
+        };
+        let table = self.table.take();
+        *self = Self::open(&self.url, hello)?;
+        self.table = table;
+        Ok(())
+    }
+
+    /// Signals whether the player is ready for the game to begin.
+    pub fn set_ready(&self, ready: bool) -> Result<(), NetError> {
+        self.send(ClientMessage::Ready { ready })
+    }
+
+    /// Opens a connection to the host.
+    fn dial(url: &str) -> Result<(Sender<ClientMessage>, Inbox), NetError> {
+        let (in_tx, rx) = mpsc::channel();
+        let on_recv = move |msg| {
+            let _ = in_tx.send(msg);
+        };
+        let tx = if let Some(addr) = url.strip_prefix("tcp://") {
+            let stream = TcpStream::connect(addr)?;
+            stream.set_read_timeout(Some(POLL_INTERVAL))?;
+            transport::spawn(Framed::new(stream), on_recv)
+        } else {
+            let (mut ws, _) = tungstenite::connect(url)?;
+            if let MaybeTlsStream::Plain(stream) = ws.get_mut() {
+                stream.set_read_timeout(Some(POLL_INTERVAL))?;
+            }
+            transport::spawn(ws, on_recv)
+        };
+        Ok((tx, rx))
+    }
+
+    /// Connects to the host, and introduces the client with the specified message.
+    fn open(url: &str, hello: ClientMessage) -> Result<Self, NetError> {
+        let (tx, rx) = Self::dial(url)?;
+        tx.send(hello).map_err(|_| NetError::Disconnected)?;
+        let (seat, token) = match rx.recv_timeout(WELCOME_TIMEOUT) {
+            Ok(Some(ServerMessage::Welcome { seat, token })) => (Some(seat), Some(token)),
+            Ok(Some(ServerMessage::Spectating)) => (None, None),
+            Ok(Some(ServerMessage::Error { message })) => return Err(NetError::Protocol(message)),
+            Ok(Some(msg)) => {
+                return Err(NetError::Protocol(format!("unexpected message: {msg:?}")));
+            }
+            Ok(None) | Err(_) => return Err(NetError::Disconnected),
+        };
+        Ok(Self {
+            url: url.to_string(),
+            seat,
+            token,
+            table: None,
+            tx,
+            rx,
+        })
+    }
+
+    /// Returns the seat assigned by the host, or `None` for a spectator.
+    pub fn seat(&self) -> Option<Seat> {
+        self.seat
+    }
+
+    /// Sends a message to the host.
+    pub fn send(&self, msg: ClientMessage) -> Result<(), NetError> {
+        self.tx.send(msg).map_err(|_| NetError::Disconnected)
+    }
+
+    /// Receives a message from the host, if one is available.
+    pub fn try_recv(&self) -> Result<Option<ServerMessage>, NetError> {
+        match self.rx.try_recv() {
+            Ok(Some(msg)) => Ok(Some(msg)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Ok(None) | Err(TryRecvError::Disconnected) => Err(NetError::Disconnected),
+        }
+    }
+
+    /// Receives a message from the host, blocking until one is available.
+    pub fn recv(&self) -> Result<ServerMessage, NetError> {
+        match self.rx.recv() {
+            Ok(Some(msg)) => Ok(msg),
+            Ok(None) | Err(_) => Err(NetError::Disconnected),
+        }
+    }
+}
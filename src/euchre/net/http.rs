@@ -0,0 +1,363 @@
+//! REST API for hosting games.
+//!
+//! | Method | Path                       | Description                                    |
+//! |--------|----------------------------|------------------------------------------------|
+//! | GET    | `/games`                   | Lists games.                                   |
+//! | POST   | `/games`                   | Creates a game from a [`NewGame`].             |
+//! | GET    | `/games/:id`               | Describes a game.                              |
+//! | POST   | `/games/:id/seats/:seat`   | Claims an open seat, returning a [`Claim`].    |
+//! | GET    | `/games/:id/state`         | Fetches the [`GameView`] for the caller's seat. |
+//! | POST   | `/games/:id/actions`       | Submits an [`Action`] for the caller's seat.   |
+//! | GET    | `/games/:id/events?since=` | Fetches events, starting at the given index.   |
+//!
+//! Endpoints that act on behalf of a seat require the token from the seat's [`Claim`], in an
+//! `Authorization: Bearer <token>` header. Seats that are not open to humans are played by
+//! robots.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::{
+    Action, Cut, Event, Game, GameView, LoggingRound, Player, Robot, Round, RoundError, Seat,
+    SeatMap,
+};
+
+use super::SEAT_ORDER;
+
+/// An error returned by the REST API.
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    /// The game does not exist.
+    #[error("no such game")]
+    NoSuchGame,
+    /// The seat is already claimed, or is played by a robot.
+    #[error("seat is not available")]
+    SeatUnavailable,
+    /// The request did not include a valid token for the game.
+    #[error("missing or invalid token")]
+    Unauthorized,
+    /// The game is over.
+    #[error("game is over")]
+    GameOver,
+    /// The action was rejected.
+    #[error(transparent)]
+    Round(#[from] RoundError),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            ApiError::NoSuchGame => StatusCode::NOT_FOUND,
+            ApiError::SeatUnavailable | ApiError::GameOver => StatusCode::CONFLICT,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ApiError::Round(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        let body = Json(serde_json::json!({ "error": self.to_string() }));
+        (status, body).into_response()
+    }
+}
+
+/// A request to create a game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewGame {
+    /// The number of seats open to humans. Seats are opened in the order South, North, East,
+    /// West.
+    pub humans: usize,
+}
+
+/// A summary of a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableInfo {
+    /// Identifies the game.
+    pub id: u64,
+    /// Seats that are open to humans, and not yet claimed.
+    pub open_seats: Vec<Seat>,
+    /// Whether the game is over.
+    pub over: bool,
+}
+
+/// A claim on a seat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claim {
+    /// The claimed seat.
+    pub seat: Seat,
+    /// The secret used to act on behalf of the seat.
+    pub token: String,
+}
+
+/// A page of events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Events {
+    /// Events, in the order that they occurred.
+    pub events: Vec<Event>,
+    /// The index of the next event, for use in a subsequent request.
+    pub next: usize,
+}
+
+/// Query parameters for the events endpoint.
+#[derive(Debug, Deserialize)]
+struct EventsQuery {
+    #[serde(default)]
+    since: usize,
+}
+
+/// The occupant of a seat.
+enum Occupant {
+    /// The seat is open to a human, but has not been claimed.
+    Open,
+    /// The seat has been claimed by a human, who holds the token.
+    Human(String),
+    /// The seat is played by a robot.
+    Robot,
+}
+
+/// A game in progress.
+struct Table {
+    game: Game<LoggingRound>,
+    robot: Robot,
+    seats: SeatMap<Occupant>,
+    events: Vec<Event>,
+}
+
+impl Table {
+    /// Creates a new table, with the specified number of seats open to humans.
+    fn new(humans: usize) -> Self {
+        let cut: Cut = rand::random();
+        let seats = SeatMap::from_fn(|seat| {
+            if SEAT_ORDER[..humans].contains(&seat) {
+                Occupant::Open
+            } else {
+                Occupant::Robot
+            }
+        });
+        let mut table = Self {
+            game: Game::from_cut(&cut),
+            robot: Robot::default(),
+            seats,
+            events: vec![Event::Cut(cut.into())],
+        };
+        table.advance();
+        table
+    }
+
+    /// Returns true if the game is over.
+    fn is_over(&self) -> bool {
+        self.game.outcome().is_some()
+    }
+
+    /// Summarizes the table.
+    fn info(&self, id: u64) -> TableInfo {
+        let open_seats = SEAT_ORDER
+            .iter()
+            .copied()
+            .filter(|&seat| matches!(self.seats[seat], Occupant::Open))
+            .collect();
+        TableInfo {
+            id,
+            open_seats,
+            over: self.is_over(),
+        }
+    }
+
+    /// Returns the seat held by the specified token.
+    fn authorize(&self, token: Option<&str>) -> Result<Seat, ApiError> {
+        let token = token.ok_or(ApiError::Unauthorized)?;
+        self.seats
+            .iter()
+            .find_map(|(seat, occupant)| match occupant {
+                Occupant::Human(t) if t == token => Some(seat),
+                _ => None,
+            })
+            .ok_or(ApiError::Unauthorized)
+    }
+
+    /// Advances the game until a human is expected to act, or the game is over.
+    fn advance(&mut self) {
+        loop {
+            while let Some(event) = self.game.round_mut().pop_event() {
+                self.events.push(event);
+            }
+            if self.game.round().outcome().is_some() {
+                self.game.next_round();
+                if let Some(outcome) = self.game.outcome() {
+                    self.events.push(Event::Game(outcome));
+                    return;
+                }
+                continue;
+            }
+            let expect = self.game.round().next_action().expect("round in progress");
+            if !matches!(self.seats[expect.seat], Occupant::Robot) {
+                return;
+            }
+            let state = self.game.round().player_state(expect.seat);
+            let action = expect.with_data(self.robot.take_action(state, expect.action));
+            self.game
+                .round_mut()
+                .apply_action(action)
+                .expect("robot actions are valid");
+        }
+    }
+}
+
+/// The set of games hosted by the REST API.
+#[derive(Default)]
+pub struct Games {
+    tables: Mutex<BTreeMap<u64, Table>>,
+}
+
+impl Games {
+    /// Invokes the callback on the specified table.
+    fn with_table<T, F>(&self, id: u64, f: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(&mut Table) -> Result<T, ApiError>,
+    {
+        let mut tables = self.tables.lock().expect("not poisoned");
+        tables.get_mut(&id).ok_or(ApiError::NoSuchGame).and_then(f)
+    }
+
+    /// Lists all games.
+    pub fn list(&self) -> Vec<TableInfo> {
+        let tables = self.tables.lock().expect("not poisoned");
+        tables.iter().map(|(&id, table)| table.info(id)).collect()
+    }
+
+    /// Creates a new game.
+    pub fn create(&self, new: &NewGame) -> TableInfo {
+        let mut tables = self.tables.lock().expect("not poisoned");
+        let id = tables.keys().next_back().map_or(1, |id| id + 1);
+        let table = Table::new(new.humans.min(SEAT_ORDER.len()));
+        let info = table.info(id);
+        tables.insert(id, table);
+        info
+    }
+
+    /// Describes the specified game.
+    pub fn info(&self, id: u64) -> Result<TableInfo, ApiError> {
+        self.with_table(id, |table| Ok(table.info(id)))
+    }
+
+    /// Claims an open seat.
+    pub fn claim(&self, id: u64, seat: Seat) -> Result<Claim, ApiError> {
+        self.with_table(id, |table| {
+            if !matches!(table.seats[seat], Occupant::Open) {
+                return Err(ApiError::SeatUnavailable);
+            }
+            let token = format!("{:016x}", rand::random::<u64>());
+            table.seats[seat] = Occupant::Human(token.clone());
+            Ok(Claim { seat, token })
+        })
+    }
+
+    /// Returns the state of the game, as visible from the token holder's seat.
+    pub fn state(&self, id: u64, token: Option<&str>) -> Result<GameView, ApiError> {
+        self.with_table(id, |table| {
+            let seat = table.authorize(token)?;
+            Ok(table.game.view(seat))
+        })
+    }
+
+    /// Applies an action on behalf of the token holder, and returns the resulting state.
+    pub fn act(&self, id: u64, token: Option<&str>, action: Action) -> Result<GameView, ApiError> {
+        self.with_table(id, |table| {
+            let seat = table.authorize(token)?;
+            if table.is_over() {
+                return Err(ApiError::GameOver);
+            }
+            if action.seat != seat {
+                let expect = table.game.round().next_action().expect("round in progress");
+                return Err(RoundError::ExpectActioned {
+                    seat: expect.seat,
+                    action: expect.action,
+                }
+                .into());
+            }
+            table.game.round_mut().apply_action(action)?;
+            table.advance();
+            Ok(table.game.view(seat))
+        })
+    }
+
+    /// Returns events that have occurred, starting from the specified index.
+    pub fn events(&self, id: u64, since: usize) -> Result<Events, ApiError> {
+        self.with_table(id, |table| {
+            let events = table.events.get(since..).unwrap_or_default().to_vec();
+            let next = table.events.len().max(since);
+            Ok(Events { events, next })
+        })
+    }
+}
+
+/// Extracts the bearer token from the request headers.
+fn bearer(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+}
+
+/// Builds a router for the REST API.
+pub fn router(games: Arc<Games>) -> Router {
+    Router::new()
+        .route(
+            "/games",
+            get(|State(games): State<Arc<Games>>| async move { Json(games.list()) }).post(
+                |State(games): State<Arc<Games>>, Json(new): Json<NewGame>| async move {
+                    (StatusCode::CREATED, Json(games.create(&new)))
+                },
+            ),
+        )
+        .route(
+            "/games/:id",
+            get(
+                |State(games): State<Arc<Games>>, Path(id): Path<u64>| async move {
+                    games.info(id).map(Json)
+                },
+            ),
+        )
+        .route(
+            "/games/:id/seats/:seat",
+            post(
+                |State(games): State<Arc<Games>>, Path((id, seat)): Path<(u64, Seat)>| async move {
+                    games.claim(id, seat).map(Json)
+                },
+            ),
+        )
+        .route(
+            "/games/:id/state",
+            get(
+                |State(games): State<Arc<Games>>, Path(id): Path<u64>, headers: HeaderMap| async move {
+                    games.state(id, bearer(&headers)).map(Json)
+                },
+            ),
+        )
+        .route(
+            "/games/:id/actions",
+            post(
+                |State(games): State<Arc<Games>>,
+                 Path(id): Path<u64>,
+                 headers: HeaderMap,
+                 Json(action): Json<Action>| async move {
+                    games.act(id, bearer(&headers), action).map(Json)
+                },
+            ),
+        )
+        .route(
+            "/games/:id/events",
+            get(
+                |State(games): State<Arc<Games>>,
+                 Path(id): Path<u64>,
+                 Query(query): Query<EventsQuery>| async move {
+                    games.events(id, query.since).map(Json)
+                },
+            ),
+        )
+        .with_state(games)
+}
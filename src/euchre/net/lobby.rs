@@ -0,0 +1,490 @@
+//! Tables, seating, and game start coordination.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use crate::euchre::{
+    Cut, Event, Game, GameOutcome, LoggingRound, Player, Robot, Round, RoundError, Seat, SeatMap,
+    TimeControl,
+};
+
+use super::remote::{ConnId, Peer, RemotePlayer};
+use super::server::{ChatPolicy, Inbound};
+use super::{ClientMessage, SeatStatus, ServerMessage, TableSummary, DEFAULT_TABLE, SEAT_ORDER};
+
+/// The longest chat message that the host will relay, in characters.
+const MAX_CHAT_LEN: usize = 200;
+
+/// The longest table name, in characters.
+const MAX_TABLE_NAME_LEN: usize = 32;
+
+/// Seats players at tables as they join, relays their messages, and starts games once
+/// everyone is ready.
+pub(super) struct Lobby {
+    /// When players may chat.
+    chat: ChatPolicy,
+    /// The time allowed to each player, if games are played against the clock.
+    time_control: Option<TimeControl>,
+    /// The lobby's own inbox, for notifications from games in progress.
+    inbox: Sender<Inbound>,
+    /// Connections that haven't yet joined a table.
+    pending: HashMap<ConnId, Sender<ServerMessage>>,
+    /// Tables, by name.
+    tables: BTreeMap<String, Table>,
+}
+
+/// A table, and the players seated at it.
+#[derive(Default)]
+struct Table {
+    /// Human players. Tokens remain valid while a player is disconnected, so that they can
+    /// rejoin their seat.
+    seats: SeatMap<Option<Arc<Peer>>>,
+    /// Whether each player is ready to begin.
+    ready: SeatMap<bool>,
+    /// Spectators.
+    gallery: Arc<Gallery>,
+    /// Whether a contract is being played.
+    in_play: Arc<AtomicBool>,
+    /// Whether the game has begun.
+    started: bool,
+}
+
+impl Table {
+    /// Summarizes the table.
+    fn summary(&self, name: &str) -> TableSummary {
+        let seats = SeatMap::from_fn(|seat| match &self.seats[seat] {
+            Some(peer) => SeatStatus::Claimed {
+                ready: self.ready[seat],
+                connected: peer.is_connected(),
+            },
+            None if self.started => SeatStatus::Robot,
+            None => SeatStatus::Open,
+        });
+        TableSummary {
+            name: name.to_string(),
+            seats,
+            started: self.started,
+        }
+    }
+
+    /// Sends a message to everyone at the table.
+    fn broadcast(&self, msg: &ServerMessage) {
+        for peer in self.seats.values().flatten() {
+            peer.send(msg.clone());
+        }
+        self.gallery.broadcast(msg.clone());
+    }
+
+    /// Returns true if the game is ready to begin: there's at least one player, and everyone
+    /// is ready.
+    fn is_ready(&self) -> bool {
+        !self.started
+            && self.seats.values().any(Option::is_some)
+            && self
+                .seats
+                .iter()
+                .all(|(seat, peer)| peer.is_none() || self.ready[seat])
+    }
+
+    /// Returns the first open seat, if any.
+    fn open_seat(&self) -> Option<Seat> {
+        SEAT_ORDER
+            .iter()
+            .copied()
+            .find(|&seat| self.seats[seat].is_none())
+    }
+}
+
+impl Lobby {
+    /// Creates a new lobby.
+    pub(super) fn new(
+        chat: ChatPolicy,
+        time_control: Option<TimeControl>,
+        inbox: Sender<Inbound>,
+    ) -> Self {
+        Self {
+            chat,
+            time_control,
+            inbox,
+            pending: HashMap::new(),
+            tables: BTreeMap::new(),
+        }
+    }
+
+    /// Handles notifications until the server goes away.
+    pub(super) fn run(mut self, inbox: &Receiver<Inbound>) {
+        while let Ok(inbound) = inbox.recv() {
+            self.handle(inbound);
+        }
+    }
+
+    /// Handles a notification.
+    fn handle(&mut self, inbound: Inbound) {
+        match inbound {
+            Inbound::Connected(id, tx) => {
+                self.pending.insert(id, tx);
+            }
+            Inbound::Message(id, ClientMessage::ListTables) => {
+                let tables = self
+                    .tables
+                    .iter()
+                    .map(|(name, table)| table.summary(name))
+                    .collect();
+                self.reply(id, ServerMessage::Tables { tables });
+            }
+            Inbound::Message(id, ClientMessage::Join { token, table, seat }) => {
+                if let Some(tx) = self.pending.remove(&id) {
+                    if let Err(message) = self.join(id, &tx, token, table, seat) {
+                        tracing::warn!("connection {id} failed to join: {message}");
+                        let _ = tx.send(ServerMessage::Error { message });
+                        self.pending.insert(id, tx);
+                    }
+                }
+            }
+            Inbound::Message(id, ClientMessage::Spectate { table }) => {
+                if let Some(tx) = self.pending.remove(&id) {
+                    if let Err(message) = self.spectate(id, &tx, table) {
+                        let _ = tx.send(ServerMessage::Error { message });
+                        self.pending.insert(id, tx);
+                    }
+                }
+            }
+            Inbound::Message(id, ClientMessage::Ready { ready }) => {
+                if let Some((name, seat, _)) = self.peer_on(id) {
+                    let name = name.to_string();
+                    self.set_ready(&name, seat, ready);
+                }
+            }
+            Inbound::Message(id, ClientMessage::Action { action }) => {
+                if let Some((_, _, peer)) = self.peer_on(id) {
+                    peer.deliver(action);
+                }
+            }
+            Inbound::Message(id, ClientMessage::Chat { text }) => {
+                if let Some((name, seat, peer)) = self.peer_on(id) {
+                    if let Err(message) = self.chat(name, seat, text) {
+                        peer.send(ServerMessage::Error { message });
+                    }
+                }
+            }
+            Inbound::Disconnected(id) => {
+                tracing::info!("connection {id} closed");
+                self.pending.remove(&id);
+                for table in self.tables.values() {
+                    table.gallery.dismiss(id);
+                }
+                if let Some((name, _, peer)) = self.peer_on(id) {
+                    peer.detach();
+                    let name = name.to_string();
+                    self.update(&name);
+                }
+            }
+            Inbound::Finished(name) => {
+                self.tables.remove(&name);
+            }
+        }
+    }
+
+    /// Sends a message to the client on the specified connection, if it's pending or seated.
+    fn reply(&self, id: ConnId, msg: ServerMessage) {
+        if let Some(tx) = self.pending.get(&id) {
+            let _ = tx.send(msg);
+        } else if let Some((_, _, peer)) = self.peer_on(id) {
+            peer.send(msg);
+        }
+    }
+
+    /// Seats a player: either in the seat matching their token, or at the requested table.
+    fn join(
+        &mut self,
+        id: ConnId,
+        tx: &Sender<ServerMessage>,
+        token: Option<String>,
+        table: Option<String>,
+        seat: Option<Seat>,
+    ) -> Result<(), String> {
+        let (name, seat) = match token {
+            Some(token) => self.find_token(&token)?,
+            None => self.claim(table, seat)?,
+        };
+        let peer = self.tables[&name].seats[seat]
+            .as_ref()
+            .expect("claimed")
+            .clone();
+        let token = peer.token().to_string();
+        tracing::info!("connection {id} took {seat} at table {name}");
+        let _ = tx.send(ServerMessage::Welcome { seat, token });
+        peer.attach(id, tx.clone());
+        self.update(&name);
+        Ok(())
+    }
+
+    /// Finds the disconnected seat that holds the specified token.
+    fn find_token(&self, token: &str) -> Result<(String, Seat), String> {
+        for (name, table) in &self.tables {
+            for (seat, peer) in table.seats.iter() {
+                match peer {
+                    Some(peer) if peer.token() == token && peer.is_connected() => {
+                        return Err("seat is already connected".into());
+                    }
+                    Some(peer) if peer.token() == token => return Ok((name.clone(), seat)),
+                    _ => (),
+                }
+            }
+        }
+        Err("unknown token".into())
+    }
+
+    /// Claims a seat at the named table, creating the table if necessary.
+    fn claim(
+        &mut self,
+        table: Option<String>,
+        seat: Option<Seat>,
+    ) -> Result<(String, Seat), String> {
+        let name = table_name(table)?;
+        let table = self.tables.entry(name.clone()).or_default();
+        if table.started {
+            return Err("game has already begun".into());
+        }
+        let seat = match seat {
+            Some(seat) if table.seats[seat].is_some() => return Err(format!("{seat} is taken")),
+            Some(seat) => seat,
+            None => table.open_seat().ok_or("table is full")?,
+        };
+        table.seats[seat] = Some(Peer::new());
+        table.ready[seat] = false;
+        Ok((name, seat))
+    }
+
+    /// Admits a spectator to the named table.
+    fn spectate(
+        &mut self,
+        id: ConnId,
+        tx: &Sender<ServerMessage>,
+        table: Option<String>,
+    ) -> Result<(), String> {
+        let name = table_name(table)?;
+        let table = self.tables.get(&name).ok_or("no such table")?;
+        table.gallery.admit(id, tx.clone());
+        if !table.started {
+            let _ = tx.send(ServerMessage::Table {
+                table: table.summary(&name),
+            });
+        }
+        Ok(())
+    }
+
+    /// Updates a player's readiness.
+    fn set_ready(&mut self, name: &str, seat: Seat, ready: bool) {
+        let table = self.tables.get_mut(name).expect("table exists");
+        if !table.started {
+            table.ready[seat] = ready;
+            self.update(name);
+        }
+    }
+
+    /// Notifies everyone at the named table of a change in its status, and starts the game if
+    /// everyone is ready.
+    fn update(&mut self, name: &str) {
+        let table = self.tables.get_mut(name).expect("table exists");
+        let start = table.is_ready();
+        table.started |= start;
+        if start || !table.started {
+            table.broadcast(&ServerMessage::Table {
+                table: table.summary(name),
+            });
+        }
+        if start {
+            self.start(name);
+        }
+    }
+
+    /// Starts the game at the named table, with robots in the empty seats.
+    fn start(&self, name: &str) {
+        let table = &self.tables[name];
+        let peers = table.seats.clone();
+        let gallery = Arc::clone(&table.gallery);
+        let in_play = Arc::clone(&table.in_play);
+        let inbox = self.inbox.clone();
+        let name = name.to_string();
+        let time_control = self.time_control;
+        thread::spawn(move || {
+            let cut: Cut = rand::random();
+            let mut game = Game::from_cut(&cut).with_time_control(time_control);
+            let players = SeatMap::from_fn(|seat| -> Arc<dyn Player> {
+                match &peers[seat] {
+                    Some(peer) => {
+                        Arc::new(RemotePlayer::new(seat, Arc::clone(peer), game.view(seat)))
+                    }
+                    None => Robot::default().into_player(),
+                }
+            });
+            play(&mut game, &players, &gallery, &in_play, cut);
+            let _ = inbox.send(Inbound::Finished(name));
+        });
+    }
+
+    /// Relays a chat message from the player in the specified seat to everyone at the table.
+    fn chat(&self, name: &str, seat: Seat, text: String) -> Result<(), String> {
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return Ok(());
+        }
+        if text.chars().count() > MAX_CHAT_LEN {
+            return Err(format!(
+                "chat messages are limited to {MAX_CHAT_LEN} characters"
+            ));
+        }
+        let table = &self.tables[name];
+        if self.chat == ChatPolicy::QuietDuringPlay && table.in_play.load(Ordering::Relaxed) {
+            return Err("chat is disabled while a contract is being played".into());
+        }
+        table.broadcast(&ServerMessage::Chat { seat, text });
+        Ok(())
+    }
+
+    /// Returns the table, seat and peer on the specified connection.
+    fn peer_on(&self, id: ConnId) -> Option<(&str, Seat, &Arc<Peer>)> {
+        self.tables.iter().find_map(|(name, table)| {
+            table.seats.iter().find_map(|(seat, peer)| {
+                peer.as_ref()
+                    .filter(|p| p.is_on(id))
+                    .map(|p| (name.as_str(), seat, p))
+            })
+        })
+    }
+}
+
+/// Validates a table name, substituting the default if none is specified.
+fn table_name(name: Option<String>) -> Result<String, String> {
+    let name = name.map_or(DEFAULT_TABLE.to_string(), |n| n.trim().to_string());
+    if name.is_empty() || name.chars().count() > MAX_TABLE_NAME_LEN {
+        return Err(format!(
+            "table names must be between 1 and {MAX_TABLE_NAME_LEN} characters"
+        ));
+    }
+    Ok(name)
+}
+
+/// Plays the game to completion. Players and spectators are notified of every change in state,
+/// and of every event. The `in_play` flag is raised while a contract is being played. If the game
+/// is played against the clock, each player is charged for the time they take to act, and a
+/// player who runs out of time forfeits the game. Each action is logged with the time the player
+/// took to choose it.
+fn play(
+    game: &mut Game<LoggingRound>,
+    players: &SeatMap<Arc<dyn Player>>,
+    gallery: &Gallery,
+    in_play: &AtomicBool,
+    cut: Cut,
+) -> GameOutcome {
+    let notify = |game: &Game<LoggingRound>, event: &Event| {
+        for (seat, player) in players.iter() {
+            player.notify(game.round().player_state(seat), event);
+        }
+        gallery.broadcast(ServerMessage::Event {
+            event: event.clone(),
+        });
+    };
+    let observe = |game: &Game<LoggingRound>| {
+        let round = game.round();
+        in_play.store(
+            round.contract().is_some() && round.outcome().is_none(),
+            Ordering::Relaxed,
+        );
+        let next_action = round.next_action();
+        for (seat, player) in players.iter() {
+            if let Some(clock) = game.clock() {
+                player.observe_clock(clock);
+            }
+            player.observe(game.round().player_state(seat), next_action);
+        }
+        gallery.broadcast(ServerMessage::State {
+            game: game.public_view(),
+        });
+    };
+
+    observe(game);
+    notify(game, &Event::Cut(cut.into()));
+    loop {
+        while let Some(event) = game.round_mut().pop_event() {
+            notify(game, &event);
+        }
+        if game.round().outcome().is_some() {
+            game.next_round();
+            if let Some(outcome) = game.outcome() {
+                notify(game, &Event::Game(outcome));
+                return outcome;
+            }
+            observe(game);
+            continue;
+        }
+
+        let expect = game.round().next_action().expect("round in progress");
+        let player = &players[expect.seat];
+        let state = game.round().player_state(expect.seat);
+        let started = Instant::now();
+        let action = expect.with_data(player.take_action(state, expect.action));
+        let elapsed = started.elapsed();
+        if !game.charge_time(expect.seat, elapsed) {
+            let outcome = game.outcome().expect("forfeited");
+            notify(game, &Event::Game(outcome));
+            return outcome;
+        }
+        match game.round_mut().apply_action(action) {
+            Ok(()) => {
+                game.round_mut().record_think_time(elapsed);
+                observe(game);
+            }
+            Err(RoundError::Player(err)) if player.handle_error(err.clone()) => (),
+            Err(err) => panic!("Fatal: {}", err),
+        }
+    }
+}
+
+/// Spectators of a game.
+#[derive(Default)]
+struct Gallery {
+    inner: Mutex<GalleryInner>,
+}
+
+#[derive(Default)]
+struct GalleryInner {
+    /// Connected spectators.
+    spectators: Vec<(ConnId, Sender<ServerMessage>)>,
+    /// The most recent public state, for newly admitted spectators.
+    state: Option<ServerMessage>,
+}
+
+impl Gallery {
+    /// Admits a spectator, and brings it up to date.
+    fn admit(&self, id: ConnId, tx: Sender<ServerMessage>) {
+        let mut inner = self.inner.lock().expect("not poisoned");
+        let _ = tx.send(ServerMessage::Spectating);
+        if let Some(state) = &inner.state {
+            let _ = tx.send(state.clone());
+        }
+        inner.spectators.push((id, tx));
+    }
+
+    /// Dismisses the spectator on the specified connection, if there is one.
+    fn dismiss(&self, id: ConnId) {
+        let mut inner = self.inner.lock().expect("not poisoned");
+        inner.spectators.retain(|(conn, _)| *conn != id);
+    }
+
+    /// Sends a message to all spectators.
+    fn broadcast(&self, msg: ServerMessage) {
+        let mut inner = self.inner.lock().expect("not poisoned");
+        inner
+            .spectators
+            .retain(|(_, tx)| tx.send(msg.clone()).is_ok());
+        if let ServerMessage::State { .. } = msg {
+            inner.state = Some(msg);
+        }
+    }
+}
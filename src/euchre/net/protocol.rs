@@ -0,0 +1,89 @@
+//! Messages exchanged between a host and its clients.
+
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::{Action, Event, GameView, Seat, SeatMap};
+
+/// The name of the table that clients join, if they don't specify one.
+pub const DEFAULT_TABLE: &str = "main";
+
+/// A message sent from the host to a client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Assigns the client a seat at the table. The token may be used to rejoin the seat after
+    /// losing the connection.
+    Welcome { seat: Seat, token: String },
+    /// Admits the client as a spectator. Spectators receive public views of the game, which
+    /// reveal no player's hand.
+    Spectating,
+    /// The tables hosted by the server.
+    Tables { tables: Vec<TableSummary> },
+    /// The state of the client's table, before the game begins.
+    Table { table: TableSummary },
+    /// The state of the game, as visible from the client's seat.
+    State { game: GameView },
+    /// An event occurred.
+    Event { event: Event },
+    /// A player said something.
+    Chat { seat: Seat, text: String },
+    /// The client's last message was rejected.
+    Error { message: String },
+}
+
+/// A message sent from a client to the host.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Lists the tables hosted by the server.
+    ListTables,
+    /// Claims a seat at a table, which is created if it doesn't exist. If no seat is
+    /// specified, the first open seat is claimed, in the order South, North, East, West.
+    ///
+    /// To rejoin a seat after losing the connection, provide the token from the original
+    /// [`ServerMessage::Welcome`]; the table and seat are then ignored.
+    Join {
+        #[serde(default)]
+        token: Option<String>,
+        #[serde(default)]
+        table: Option<String>,
+        #[serde(default)]
+        seat: Option<Seat>,
+    },
+    /// Watches a table as a spectator.
+    Spectate {
+        #[serde(default)]
+        table: Option<String>,
+    },
+    /// Signals whether the player is ready to begin. The game begins once every seated player
+    /// is ready, with robots in the empty seats.
+    Ready { ready: bool },
+    /// Takes an action in the current round.
+    Action { action: Action },
+    /// Says something to the table.
+    Chat { text: String },
+}
+
+/// The status of a seat at a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SeatStatus {
+    /// The seat is open. Open seats are played by robots once the game begins.
+    #[default]
+    Open,
+    /// The seat is claimed by a player.
+    Claimed { ready: bool, connected: bool },
+    /// The seat is played by a robot.
+    Robot,
+}
+
+/// A summary of a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSummary {
+    /// The name of the table.
+    pub name: String,
+    /// The status of each seat.
+    pub seats: SeatMap<SeatStatus>,
+    /// Whether the game has begun.
+    pub started: bool,
+}
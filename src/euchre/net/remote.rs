@@ -0,0 +1,238 @@
+//! A player connected over the network.
+
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::euchre::{
+    Action, ActionData, ActionType, Clock, Event, ExpectAction, Game, GameView, Player,
+    PlayerError, PlayerState, Robot, Round, RoundView, Seat,
+};
+
+use super::transport::POLL_INTERVAL;
+use super::ServerMessage;
+
+/// How long to wait for a disconnected player to rejoin, before a robot takes over their seat.
+const REJOIN_GRACE: Duration = Duration::from_secs(30);
+
+/// Identifies a connection to the host.
+pub(super) type ConnId = u64;
+
+/// The link between a seat and the client playing it.
+///
+/// The client's connection may be replaced when it rejoins the seat after losing its
+/// connection.
+pub(super) struct Peer {
+    /// The secret used to rejoin the seat.
+    token: String,
+    /// The client's current connection.
+    conn: Mutex<Connection>,
+    /// Actions received from the client.
+    actions: Mutex<Receiver<Action>>,
+    /// The sender half of `actions`.
+    actions_tx: Sender<Action>,
+}
+
+/// The state of a peer's connection.
+#[derive(Default)]
+struct Connection {
+    /// The connection and its sender, if the client is connected.
+    tx: Option<(ConnId, Sender<ServerMessage>)>,
+    /// When the client lost its connection, if it's disconnected.
+    disconnected_at: Option<Instant>,
+    /// The last state sent to the client, to be resent when it rejoins.
+    state: Option<ServerMessage>,
+}
+
+impl Peer {
+    /// Creates a new peer, with a random token.
+    pub(super) fn new() -> Arc<Self> {
+        let (actions_tx, actions) = mpsc::channel();
+        Arc::new(Self {
+            token: format!("{:016x}", rand::random::<u64>()),
+            conn: Mutex::default(),
+            actions: Mutex::new(actions),
+            actions_tx,
+        })
+    }
+
+    /// Returns the token used to rejoin the seat.
+    pub(super) fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns true if the client is connected.
+    pub(super) fn is_connected(&self) -> bool {
+        self.conn.lock().expect("not poisoned").tx.is_some()
+    }
+
+    /// Returns true if the client is connected on the specified connection.
+    pub(super) fn is_on(&self, id: ConnId) -> bool {
+        let conn = self.conn.lock().expect("not poisoned");
+        matches!(conn.tx, Some((conn_id, _)) if conn_id == id)
+    }
+
+    /// Attaches the client's connection, and brings it up to date.
+    pub(super) fn attach(&self, id: ConnId, tx: Sender<ServerMessage>) {
+        let mut conn = self.conn.lock().expect("not poisoned");
+        if let Some(state) = &conn.state {
+            let _ = tx.send(state.clone());
+        }
+        conn.tx = Some((id, tx));
+        conn.disconnected_at = None;
+    }
+
+    /// Detaches the client's connection.
+    pub(super) fn detach(&self) {
+        let mut conn = self.conn.lock().expect("not poisoned");
+        conn.tx = None;
+        conn.disconnected_at = Some(Instant::now());
+    }
+
+    /// Delivers an action received from the client.
+    pub(super) fn deliver(&self, action: Action) {
+        let _ = self.actions_tx.send(action);
+    }
+
+    /// Sends a message to the client, if it's connected.
+    pub(super) fn send(&self, msg: ServerMessage) {
+        let mut conn = self.conn.lock().expect("not poisoned");
+        if matches!(msg, ServerMessage::State { .. }) {
+            conn.state = Some(msg.clone());
+        }
+        if let Some((_, tx)) = &conn.tx {
+            let _ = tx.send(msg);
+        }
+    }
+
+    /// Returns true if the client has been disconnected for too long.
+    fn is_abandoned(&self) -> bool {
+        let conn = self.conn.lock().expect("not poisoned");
+        conn.disconnected_at
+            .is_some_and(|at| at.elapsed() >= REJOIN_GRACE)
+    }
+
+    /// Waits for the client to send an action. Returns `None` if the client has abandoned the
+    /// seat, or the deadline has passed.
+    fn recv(&self, deadline: Option<Instant>) -> Option<Action> {
+        let actions = self.actions.lock().expect("not poisoned");
+        loop {
+            if self.is_abandoned() || deadline.is_some_and(|at| Instant::now() >= at) {
+                return None;
+            }
+            match actions.recv_timeout(POLL_INTERVAL) {
+                Ok(action) => return Some(action),
+                Err(RecvTimeoutError::Timeout) => (),
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+        }
+    }
+}
+
+/// A [`Player`] whose decisions are made by a client connected over the network.
+///
+/// The player forwards state and events to the client, and waits for the client to respond
+/// with actions. If the client abandons the seat, a robot plays in its stead until the client
+/// rejoins. If the client runs out of time, the robot's action stands in for theirs, so that the
+/// game loop can declare the forfeit.
+pub struct RemotePlayer {
+    seat: Seat,
+    peer: Arc<Peer>,
+    robot: Robot,
+    /// The game as seen by the client, used to track the score.
+    game: Mutex<Game<RoundView>>,
+}
+
+impl RemotePlayer {
+    /// Creates a new [`RemotePlayer`] for the client linked by the peer, starting from the
+    /// specified view of the game.
+    pub(super) fn new(seat: Seat, peer: Arc<Peer>, game: GameView) -> Self {
+        Self {
+            seat,
+            peer,
+            robot: Robot::default(),
+            game: Mutex::new(Game::from(game)),
+        }
+    }
+
+    /// Returns the seat played by the client.
+    pub fn seat(&self) -> Seat {
+        self.seat
+    }
+
+    /// Sends the client a fresh snapshot of the game.
+    fn send_state(&self, state: &PlayerState, next_action: Option<ExpectAction>) {
+        let mut game = self.game.lock().expect("not poisoned");
+        let renege = game.round().renege_policy();
+        *game.round_mut() = RoundView::from_state(state, next_action).with_renege_policy(renege);
+        let game = game.view(state.seat);
+        self.peer.send(ServerMessage::State { game });
+    }
+
+    /// Rejects an action from the client, and prompts it to try again.
+    fn reject(&self, message: String) {
+        self.peer.send(ServerMessage::Error { message });
+        let state = self.peer.conn.lock().expect("not poisoned").state.clone();
+        if let Some(state) = state {
+            self.peer.send(state);
+        }
+    }
+}
+
+impl Player for RemotePlayer {
+    fn take_action(&self, state: PlayerState, action: ActionType) -> ActionData {
+        let expect = ExpectAction {
+            seat: state.seat,
+            action,
+        };
+        // The client has usually been prompted already, by a call to `observe`.
+        let prompted = self
+            .game
+            .lock()
+            .expect("not poisoned")
+            .round()
+            .next_action();
+        if prompted != Some(expect) {
+            self.send_state(&state, Some(expect));
+        }
+        let (deadline, renege) = {
+            let game = self.game.lock().expect("not poisoned");
+            let deadline = game
+                .clock()
+                .map(|clock| Instant::now() + clock.remaining(self.seat));
+            (deadline, game.round().renege_policy())
+        };
+        loop {
+            let Some(received) = self.peer.recv(deadline) else {
+                return self.robot.take_action(state, action);
+            };
+            let mut view = RoundView::from_state(&state, Some(expect)).with_renege_policy(renege);
+            match view.apply_action(received) {
+                Ok(()) => return received.data,
+                Err(err) => self.reject(err.to_string()),
+            }
+        }
+    }
+
+    fn handle_error(&self, err: PlayerError) -> bool {
+        self.reject(err.to_string());
+        true
+    }
+
+    fn notify(&self, _state: PlayerState, event: &Event) {
+        if let Event::Round(_) = event {
+            self.game.lock().expect("not poisoned").score_round();
+        }
+        self.peer.send(ServerMessage::Event {
+            event: event.clone(),
+        });
+    }
+
+    fn observe(&self, state: PlayerState, next_action: Option<ExpectAction>) {
+        self.send_state(&state, next_action);
+    }
+
+    fn observe_clock(&self, clock: &Clock) {
+        self.game.lock().expect("not poisoned").set_clock(*clock);
+    }
+}
@@ -0,0 +1,135 @@
+//! Game host.
+
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+use crate::euchre::TimeControl;
+
+use super::lobby::Lobby;
+use super::remote::ConnId;
+use super::transport::{self, Framed, POLL_INTERVAL};
+use super::{ClientMessage, NetError, ServerMessage};
+
+/// When players may chat with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatPolicy {
+    /// Players may chat at any time.
+    #[default]
+    Open,
+    /// Players may not chat while a contract is being played.
+    QuietDuringPlay,
+}
+
+/// Notifications to the lobby.
+pub(super) enum Inbound {
+    /// A new connection was established.
+    Connected(ConnId, Sender<ServerMessage>),
+    /// A message arrived on a connection.
+    Message(ConnId, ClientMessage),
+    /// A connection was lost.
+    Disconnected(ConnId),
+    /// The game at the named table is over.
+    Finished(String),
+}
+
+/// Hosts games for remote players.
+///
+/// Clients may connect with either a WebSocket, or a plain TCP stream carrying length-prefixed
+/// JSON messages. The protocol is detected automatically.
+///
+/// Players gather at named tables, which are created as players join them. Each table's game
+/// begins once every seated player is ready, with robots in the empty seats.
+pub struct Server {
+    listener: TcpListener,
+    chat: ChatPolicy,
+    time_control: Option<TimeControl>,
+}
+
+impl Server {
+    /// Binds a new [`Server`] to the specified address.
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, NetError> {
+        let listener = TcpListener::bind(addr)?;
+        Ok(Self {
+            listener,
+            chat: ChatPolicy::default(),
+            time_control: None,
+        })
+    }
+
+    /// Sets the chat policy.
+    pub fn with_chat_policy(mut self, chat: ChatPolicy) -> Self {
+        self.chat = chat;
+        self
+    }
+
+    /// Plays each game against the clock, with the specified time control.
+    pub fn with_time_control(mut self, time_control: Option<TimeControl>) -> Self {
+        self.time_control = time_control;
+        self
+    }
+
+    /// Returns the address that the server is listening on.
+    pub fn local_addr(&self) -> Result<SocketAddr, NetError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Hosts games indefinitely.
+    pub fn run(self) {
+        let (inbox_tx, inbox) = mpsc::channel();
+        let lobby = Lobby::new(self.chat, self.time_control, inbox_tx.clone());
+        thread::spawn(move || lobby.run(&inbox));
+        accept_loop(&self.listener, &inbox_tx);
+    }
+}
+
+/// Accepts connections until the lobby goes away.
+fn accept_loop(listener: &TcpListener, inbox: &Sender<Inbound>) {
+    for (id, stream) in (0..).zip(listener.incoming()) {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::warn!("failed to accept a connection: {err}");
+                continue;
+            }
+        };
+        let inbox = inbox.clone();
+        // Handshakes are performed off the accept thread, so a slow client can't block others.
+        thread::spawn(move || {
+            let peer = stream
+                .peer_addr()
+                .map_or_else(|_| "?".into(), |a| a.to_string());
+            match handshake(id, stream, inbox.clone()) {
+                Ok(tx) => {
+                    tracing::info!("connection {id} from {peer}");
+                    let _ = inbox.send(Inbound::Connected(id, tx));
+                }
+                Err(err) => tracing::warn!("handshake with {peer} failed: {err}"),
+            }
+        });
+    }
+}
+
+/// Detects the client's protocol, and spawns a thread to service the connection.
+fn handshake(
+    id: ConnId,
+    stream: TcpStream,
+    inbox: Sender<Inbound>,
+) -> Result<Sender<ServerMessage>, NetError> {
+    let on_recv = move |msg| {
+        let _ = inbox.send(match msg {
+            Some(msg) => Inbound::Message(id, msg),
+            None => Inbound::Disconnected(id),
+        });
+    };
+    let mut prefix = [0; 4];
+    let n = stream.peek(&mut prefix)?;
+    if &prefix[..n] == b"GET " {
+        let ws = tungstenite::accept(stream).map_err(|e| NetError::Protocol(e.to_string()))?;
+        ws.get_ref().set_read_timeout(Some(POLL_INTERVAL))?;
+        Ok(transport::spawn(ws, on_recv))
+    } else {
+        stream.set_read_timeout(Some(POLL_INTERVAL))?;
+        Ok(transport::spawn(Framed::new(stream), on_recv))
+    }
+}
@@ -0,0 +1,234 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::euchre::{Event, Game, GameOutcome, Round, RoundView, Seat, Team, TimeControl};
+
+use super::*;
+
+/// Plays the client's seat with the first legal action, until the game is over. Before the
+/// client's `reconnect_at`-th action, it drops its connection and rejoins.
+fn play(mut client: Client, mut reconnect_at: Option<usize>) -> GameOutcome {
+    let mut actions = 0;
+    loop {
+        match client.recv().unwrap() {
+            ServerMessage::State { game } => {
+                let mut game = Game::<RoundView>::from(game);
+                let round = game.round_mut();
+                let Some(expect) = round
+                    .next_action()
+                    .filter(|e| Some(e.seat) == client.seat())
+                else {
+                    continue;
+                };
+                if reconnect_at == Some(actions) {
+                    reconnect_at = None;
+                    while client.reconnect().is_err() {
+                        thread::sleep(Duration::from_millis(10));
+                    }
+                    continue;
+                }
+                let action = round.legal_actions()[0];
+                round.apply_action(action).unwrap();
+                assert_eq!(round.take_pending(), [action]);
+                assert_eq!(expect.seat, action.seat);
+                client.send(ClientMessage::Action { action }).unwrap();
+                actions += 1;
+            }
+            ServerMessage::Event {
+                event: Event::Game(outcome),
+            } => return outcome,
+            ServerMessage::Error { message } => panic!("{}", message),
+            _ => (),
+        }
+    }
+}
+
+/// Starts a server on an ephemeral port, and returns its address.
+fn serve() -> std::net::SocketAddr {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    addr
+}
+
+#[test]
+fn test_remote_game() {
+    let url = format!("ws://{}", serve());
+    let client = Client::connect(&url).unwrap();
+    assert_eq!(client.seat(), Some(Seat::South));
+    client.set_ready(true).unwrap();
+    let outcome = play(client, None);
+    assert!(outcome.winner == Team::NorthSouth || outcome.winner == Team::EastWest);
+}
+
+#[test]
+fn test_forfeit_on_time() {
+    let control = TimeControl::new(Duration::from_millis(200), Duration::ZERO);
+    let server = Server::bind("127.0.0.1:0")
+        .unwrap()
+        .with_time_control(Some(control));
+    let url = format!("ws://{}", server.local_addr().unwrap());
+    thread::spawn(move || server.run());
+    let client = Client::connect(&url).unwrap();
+    client.set_ready(true).unwrap();
+
+    // The client never acts, so it runs out of time on its first turn.
+    let outcome = loop {
+        if let ServerMessage::Event {
+            event: Event::Game(outcome),
+        } = client.recv().unwrap()
+        {
+            break outcome;
+        }
+    };
+    assert_eq!(outcome.forfeit, Some(Seat::South));
+    assert_eq!(outcome.winner, Team::EastWest);
+}
+
+#[test]
+fn test_spectator() {
+    let url = format!("ws://{}", serve());
+    assert!(Client::spectate(&url, None).is_err());
+    let player = Client::connect(&url).unwrap();
+    let spectator = Client::spectate(&url, None).unwrap();
+    assert_eq!(spectator.seat(), None);
+    player.set_ready(true).unwrap();
+    let player = thread::spawn(move || play(player, None));
+
+    let mut states = 0;
+    let outcome = loop {
+        match spectator.recv().unwrap() {
+            ServerMessage::State { game } => {
+                let game = Game::<RoundView>::from(game);
+                for &seat in Seat::all_seats() {
+                    assert!(game.round().player_state(seat).hand.is_empty());
+                }
+                states += 1;
+            }
+            ServerMessage::Event {
+                event: Event::Game(outcome),
+            } => break outcome,
+            _ => (),
+        }
+    };
+    assert!(states > 0);
+    assert_eq!(outcome.winner, player.join().unwrap().winner);
+}
+
+#[test]
+fn test_chat() {
+    let url = format!("tcp://{}", serve());
+    let player = Client::connect(&url).unwrap();
+    let spectator = Client::spectate(&url, None).unwrap();
+    let text = String::from("good luck");
+    player
+        .send(ClientMessage::Chat { text: text.clone() })
+        .unwrap();
+    for client in [&player, &spectator] {
+        loop {
+            if let ServerMessage::Chat { seat, text: t } = client.recv().unwrap() {
+                assert_eq!(seat, Seat::South);
+                assert_eq!(t, text);
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lan_game_with_reconnect() {
+    let addr = serve();
+    let south = Client::connect(&format!("tcp://{addr}")).unwrap();
+    let north = Client::connect(&format!("ws://{addr}")).unwrap();
+    assert_eq!(south.seat(), Some(Seat::South));
+    assert_eq!(north.seat(), Some(Seat::North));
+    assert!(Client::join(&format!("tcp://{addr}"), None, Some(Seat::North)).is_err());
+
+    south.set_ready(true).unwrap();
+    north.set_ready(true).unwrap();
+    let south = thread::spawn(move || play(south, Some(3)));
+    let north = play(north, None);
+    let south = south.join().unwrap();
+    assert_eq!(north.winner, south.winner);
+}
+
+#[test]
+fn test_lobby() {
+    let url = format!("tcp://{}", serve());
+    let east = Client::join(&url, Some("den"), Some(Seat::East)).unwrap();
+    assert_eq!(east.seat(), Some(Seat::East));
+    let south = Client::join(&url, Some("den"), None).unwrap();
+    assert_eq!(south.seat(), Some(Seat::South));
+    let main = Client::connect(&url).unwrap();
+    assert_eq!(main.seat(), Some(Seat::South));
+
+    let tables = Client::list_tables(&url).unwrap();
+    let names: Vec<_> = tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["den", DEFAULT_TABLE]);
+    assert_eq!(tables[0].seats[Seat::West], SeatStatus::Open);
+    assert_eq!(
+        tables[0].seats[Seat::East],
+        SeatStatus::Claimed {
+            ready: false,
+            connected: true
+        }
+    );
+
+    // The game begins only once both players at the table are ready.
+    east.set_ready(true).unwrap();
+    south.set_ready(true).unwrap();
+    let started = loop {
+        if let ServerMessage::Table { table } = east.recv().unwrap() {
+            if table.started {
+                break table;
+            }
+        }
+    };
+    assert_eq!(started.seats[Seat::North], SeatStatus::Robot);
+    assert!(Client::join(&url, Some("den"), None).is_err());
+    assert!(!Client::list_tables(&url).unwrap()[1].started);
+}
+
+#[test]
+fn test_http_game() {
+    let games = Games::default();
+    let info = games.create(&NewGame { humans: 2 });
+    assert_eq!(info.open_seats, [Seat::South, Seat::North]);
+    assert_eq!(games.list(), vec![info.clone()]);
+
+    let south = games.claim(info.id, Seat::South).unwrap();
+    assert!(matches!(
+        games.claim(info.id, Seat::South),
+        Err(ApiError::SeatUnavailable)
+    ));
+    assert!(matches!(
+        games.claim(info.id, Seat::East),
+        Err(ApiError::SeatUnavailable)
+    ));
+    assert!(matches!(
+        games.state(info.id, Some("bogus")),
+        Err(ApiError::Unauthorized)
+    ));
+    let north = games.claim(info.id, Seat::North).unwrap();
+    assert!(games.info(info.id).unwrap().open_seats.is_empty());
+
+    let outcome = loop {
+        let events = games.events(info.id, 0).unwrap();
+        if let Some(Event::Game(outcome)) = events.events.last() {
+            break *outcome;
+        }
+        for claim in [&south, &north] {
+            if games.info(info.id).unwrap().over {
+                break;
+            }
+            let game = Game::<RoundView>::from(games.state(info.id, Some(&claim.token)).unwrap());
+            let round = game.round();
+            if round.next_action().map(|e| e.seat) == Some(claim.seat) {
+                let action = round.legal_actions()[0];
+                games.act(info.id, Some(&claim.token), action).unwrap();
+            }
+        }
+    };
+    assert!(games.info(info.id).unwrap().over);
+    assert!(outcome.winner == Team::NorthSouth || outcome.winner == Team::EastWest);
+}
@@ -0,0 +1,171 @@
+//! Message transports.
+
+use std::convert::{TryFrom, TryInto};
+use std::io::{ErrorKind, Read, Write};
+use std::sync::mpsc::{self, Sender, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tungstenite::{Message, WebSocket};
+
+use super::NetError;
+
+/// How long a transport should block while waiting for an incoming message.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A bidirectional, message-oriented connection to a peer.
+pub trait Transport: Send + 'static {
+    /// Sends a message to the peer.
+    fn send(&mut self, text: String) -> Result<(), NetError>;
+
+    /// Receives a message from the peer, or returns `None` if no message arrived within the
+    /// transport's read timeout.
+    fn recv(&mut self) -> Result<Option<String>, NetError>;
+
+    /// Closes the connection.
+    fn close(&mut self);
+}
+
+impl<S: Read + Write + Send + 'static> Transport for WebSocket<S> {
+    fn send(&mut self, text: String) -> Result<(), NetError> {
+        Ok(WebSocket::send(self, Message::Text(text))?)
+    }
+
+    fn recv(&mut self) -> Result<Option<String>, NetError> {
+        match self.read() {
+            Ok(Message::Text(text)) => Ok(Some(text)),
+            Ok(Message::Close(_)) => Err(NetError::Disconnected),
+            Ok(_) => Ok(None),
+            Err(tungstenite::Error::Io(e))
+                if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+            {
+                Ok(None)
+            }
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                Err(NetError::Disconnected)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn close(&mut self) {
+        let _ = WebSocket::close(self, None);
+        let _ = self.flush();
+    }
+}
+
+/// The largest frame accepted by [`Framed`].
+const MAX_FRAME_LEN: usize = 1 << 20;
+
+/// A transport that frames each message with a four-byte, big-endian length prefix.
+pub struct Framed<S> {
+    stream: S,
+    buf: Vec<u8>,
+}
+
+impl<S> Framed<S> {
+    /// Creates a new [`Framed`] transport over the stream.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buf: vec![],
+        }
+    }
+
+    /// Removes the next complete frame from the buffer, if there is one.
+    fn next_frame(&mut self) -> Result<Option<String>, NetError> {
+        let Some(prefix) = self.buf.get(..4) else {
+            return Ok(None);
+        };
+        let len = u32::from_be_bytes(prefix.try_into().expect("four bytes")) as usize;
+        if len > MAX_FRAME_LEN {
+            return Err(NetError::Protocol(format!("frame too long: {len} bytes")));
+        }
+        if self.buf.len() < 4 + len {
+            return Ok(None);
+        }
+        let frame: Vec<u8> = self.buf.drain(..4 + len).skip(4).collect();
+        String::from_utf8(frame)
+            .map(Some)
+            .map_err(|_| NetError::Protocol("invalid utf-8".into()))
+    }
+}
+
+impl<S: Read + Write + Send + 'static> Transport for Framed<S> {
+    fn send(&mut self, text: String) -> Result<(), NetError> {
+        if text.len() > MAX_FRAME_LEN {
+            return Err(NetError::Protocol("message too long".into()));
+        }
+        let len = u32::try_from(text.len()).expect("less than 2^32");
+        self.stream.write_all(&len.to_be_bytes())?;
+        self.stream.write_all(text.as_bytes())?;
+        Ok(self.stream.flush()?)
+    }
+
+    fn recv(&mut self) -> Result<Option<String>, NetError> {
+        if let Some(text) = self.next_frame()? {
+            return Ok(Some(text));
+        }
+        let mut chunk = [0; 4096];
+        match self.stream.read(&mut chunk) {
+            Ok(0) => Err(NetError::Disconnected),
+            Ok(n) => {
+                self.buf.extend_from_slice(&chunk[..n]);
+                self.next_frame()
+            }
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn close(&mut self) {
+        let _ = self.stream.flush();
+    }
+}
+
+/// Spawns a thread to service the transport.
+///
+/// Incoming messages are decoded and passed to `on_recv`; messages that fail to decode are
+/// dropped. When the connection is lost, `on_recv` is called one final time with `None`. Outgoing
+/// messages are written to the returned channel, and dropping it closes the connection.
+pub fn spawn<T, In, Out, F>(mut transport: T, mut on_recv: F) -> Sender<Out>
+where
+    T: Transport,
+    In: DeserializeOwned,
+    Out: Serialize + Send + 'static,
+    F: FnMut(Option<In>) + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel::<Out>();
+    thread::spawn(move || {
+        'service: loop {
+            match transport.recv() {
+                Ok(Some(text)) => {
+                    if let Ok(msg) = serde_json::from_str(&text) {
+                        on_recv(Some(msg));
+                    }
+                }
+                Ok(None) => (),
+                Err(_) => break,
+            }
+            loop {
+                match rx.try_recv() {
+                    Ok(msg) => {
+                        let text = serde_json::to_string(&msg).expect("serializable");
+                        if transport.send(text).is_err() {
+                            break 'service;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        transport.close();
+                        break 'service;
+                    }
+                }
+            }
+        }
+        on_recv(None);
+    });
+    tx
+}
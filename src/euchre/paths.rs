@@ -0,0 +1,149 @@
+//! Where settings and data are stored.
+//!
+//! Files are kept in the platform's usual locations (e.g., `~/.config/deckard` and
+//! `~/.local/share/deckard` on Linux). Setting `DECKARD_HOME` keeps everything under a single
+//! directory instead, which is handy for testing, or for a portable install.
+
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+
+use super::{Achievements, DrillRecord, RecentFiles, RobotParams, Settings, Stats};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The locations of the settings and data files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paths {
+    config_dir: PathBuf,
+    data_dir: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Paths {
+    /// The environment variable that overrides the platform's locations.
+    pub const HOME_VAR: &'static str = "DECKARD_HOME";
+
+    /// Finds the locations for this platform. If they can't be determined, the working directory
+    /// is used.
+    pub fn new() -> Self {
+        if let Some(root) = std::env::var_os(Self::HOME_VAR) {
+            return Self::with_root(Path::new(&root));
+        }
+        match ProjectDirs::from("", "", "deckard") {
+            Some(dirs) => Self {
+                config_dir: dirs.config_dir().to_path_buf(),
+                data_dir: dirs.data_dir().to_path_buf(),
+            },
+            None => Self::with_root(Path::new(".")),
+        }
+    }
+
+    /// Keeps everything under the specified directory.
+    pub fn with_root(root: &Path) -> Self {
+        Self {
+            config_dir: root.to_path_buf(),
+            data_dir: root.to_path_buf(),
+        }
+    }
+
+    /// The directory for settings.
+    pub fn config_dir(&self) -> &Path {
+        &self.config_dir
+    }
+
+    /// The directory for data accumulated across sessions.
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// The directory for games saved automatically.
+    pub fn autosave_dir(&self) -> PathBuf {
+        self.data_dir.join("autosave")
+    }
+
+    /// The directory for finished games.
+    pub fn archive_dir(&self) -> PathBuf {
+        self.data_dir.join("archive")
+    }
+
+    /// The robot's parameters, as written by training.
+    pub fn robot_params(&self) -> PathBuf {
+        self.config_dir.join(RobotParams::FILE_NAME)
+    }
+
+    /// The user's settings for the terminal UI.
+    pub fn settings(&self) -> PathBuf {
+        self.config_dir.join(Settings::FILE_NAME)
+    }
+
+    /// The player statistics.
+    pub fn stats(&self) -> PathBuf {
+        self.data_dir.join(Stats::FILE_NAME)
+    }
+
+    /// The achievements earned.
+    pub fn achievements(&self) -> PathBuf {
+        self.data_dir.join(Achievements::FILE_NAME)
+    }
+
+    /// The results of practice drills.
+    pub fn drills(&self) -> PathBuf {
+        self.data_dir.join(DrillRecord::FILE_NAME)
+    }
+
+    /// The recently opened and saved files.
+    pub fn recent_files(&self) -> PathBuf {
+        self.data_dir.join(RecentFiles::FILE_NAME)
+    }
+
+    /// The game in progress, saved automatically.
+    pub fn autosave(&self) -> PathBuf {
+        self.autosave_dir().join("euchre.json")
+    }
+}
+
+impl Display for Paths {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = [
+            (tr!("paths-config"), self.config_dir.clone()),
+            (tr!("paths-data"), self.data_dir.clone()),
+            (tr!("paths-autosaves"), self.autosave_dir()),
+            (tr!("paths-archive"), self.archive_dir()),
+            (tr!("paths-robot"), self.robot_params()),
+            (tr!("paths-settings"), self.settings()),
+            (tr!("paths-stats"), self.stats()),
+            (tr!("paths-achievements"), self.achievements()),
+            (tr!("paths-drills"), self.drills()),
+            (tr!("paths-recent-files"), self.recent_files()),
+        ];
+        let width = rows
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 2;
+        for (name, path) in rows {
+            writeln!(f, "{name:<width$} {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates a file for writing, along with any missing parent directories.
+pub(crate) fn create_file(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    File::create(path)
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_with_root() {
+    let paths = Paths::with_root(Path::new("/tmp/deckard"));
+    assert_eq!(paths.config_dir(), Path::new("/tmp/deckard"));
+    assert_eq!(paths.data_dir(), Path::new("/tmp/deckard"));
+    assert_eq!(paths.autosave_dir(), Path::new("/tmp/deckard/autosave"));
+    assert_eq!(
+        paths.stats(),
+        Path::new("/tmp/deckard").join(Stats::FILE_NAME)
+    );
+    assert_eq!(paths.to_string().lines().count(), 10);
+}
+
+#[test]
+fn test_create_file() {
+    let root = std::env::temp_dir().join(format!("deckard-paths-{}", std::process::id()));
+    let path = root.join("a").join("b.json");
+    create_file(&path).unwrap();
+    assert!(path.exists());
+    fs::remove_dir_all(&root).unwrap();
+}
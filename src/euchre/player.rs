@@ -1,11 +1,15 @@
 //! Player interfacing
 
-use super::{ActionData, ActionType, Card, Event, PlayerError, PlayerState, Suit, Trick};
+use super::{
+    ActionData, ActionType, Card, Clock, Event, ExpectAction, PlayerError, PlayerState, Suit, Trick,
+};
 
 mod console;
 mod robot;
+mod search;
 pub use console::Console;
-pub use robot::Robot;
+pub use robot::{Preset, Robot, RobotParams};
+pub use search::{solve, RobotKind, SearchRobot};
 
 /// A trait that implements a euchre player.
 pub trait Player {
@@ -21,7 +25,24 @@ pub trait Player {
         false
     }
 
+    /// Asks whether the player agrees to throw in the round without playing it, before a contract
+    /// is made. By default, players insist on playing.
+    #[allow(unused_variables)]
+    fn agree_to_throw_in(&self, state: PlayerState) -> bool {
+        false
+    }
+
     /// Notifies the player of a public event.
     #[allow(unused_variables)]
     fn notify(&self, state: PlayerState, event: &Event) {}
+
+    /// Notifies the player that the state of the round has changed, and which action is
+    /// expected next.
+    #[allow(unused_variables)]
+    fn observe(&self, state: PlayerState, next_action: Option<ExpectAction>) {}
+
+    /// Notifies the player of the time remaining to each player, when the game is played against
+    /// the clock. Called before each call to `observe`.
+    #[allow(unused_variables)]
+    fn observe_clock(&self, clock: &Clock) {}
 }
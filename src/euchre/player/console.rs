@@ -6,6 +6,7 @@ use ansi_term::{ANSIString, ANSIStrings};
 use itertools::Itertools;
 
 use super::{ActionData, ActionType, Card, Event, Player, PlayerError, PlayerState, Suit, Trick};
+use crate::tr;
 
 pub struct Console {
     color: bool,
@@ -21,7 +22,7 @@ fn prompt<T: FromStr, S: Display>(prompt: S) -> T {
     let mut stdout = std::io::stdout();
     loop {
         let mut buffer = String::new();
-        print!("{prompt}");
+        print!("{prompt} ");
         stdout.flush().expect("flush");
         stdin.read_line(&mut buffer).expect("read");
         let trimmed = buffer.trim();
@@ -29,7 +30,7 @@ fn prompt<T: FromStr, S: Display>(prompt: S) -> T {
             if let Ok(obj) = T::from_str(trimmed) {
                 return obj;
             }
-            println!("Invalid input, try again");
+            println!("{}", tr!("console-invalid-input"));
         }
     }
 }
@@ -74,13 +75,18 @@ impl Console {
         self.format(&ANSIStrings(&parts))
     }
 
+    fn print_hand(&self, state: &PlayerState) {
+        let cards = self.format_cards(state.hand);
+        println!("{}", tr!("console-hand", cards = cards));
+    }
+
     fn format_trick(&self, trick: &Trick) -> String {
         let mut parts: Vec<ANSIString> = vec!["[".into()];
-        for (i, (seat, card)) in trick.cards.iter().enumerate() {
+        for (i, (seat, card)) in trick.cards().iter().enumerate() {
             if i != 0 {
                 parts.push(", ".into());
             }
-            parts.push(format!("{seat:?}:").into());
+            parts.push(format!("{seat}:").into());
             parts.push(card.to_ansi_string());
         }
         parts.push("]".into());
@@ -88,9 +94,9 @@ impl Console {
     }
 
     fn bid_top(&self, state: &PlayerState) -> ActionData {
-        println!("Hand: {}", self.format_cards(state.hand));
-        if prompt::<bool, _>("Bid top? ") {
-            let alone = prompt::<bool, _>("Alone? ");
+        self.print_hand(state);
+        if prompt::<bool, _>(tr!("console-bid-top")) {
+            let alone = prompt::<bool, _>(tr!("console-alone"));
             ActionData::Call {
                 suit: state.top.suit,
                 alone,
@@ -102,9 +108,9 @@ impl Console {
 
     #[allow(clippy::unused_self)]
     fn bid_other(&self, _: &PlayerState) -> ActionData {
-        if prompt::<bool, _>("Bid other? ") {
-            let suit = prompt::<Suit, _>("Suit? ");
-            let alone = prompt::<bool, _>("Alone? ");
+        if prompt::<bool, _>(tr!("console-bid-other")) {
+            let suit = prompt::<Suit, _>(tr!("console-suit"));
+            let alone = prompt::<bool, _>(tr!("console-alone"));
             ActionData::Call { suit, alone }
         } else {
             ActionData::Pass
@@ -112,22 +118,33 @@ impl Console {
     }
 
     fn dealer_discard(&self, state: &PlayerState) -> ActionData {
-        println!("Hand: {}", self.format_cards(state.hand));
-        let card = prompt("Discard? ");
+        self.print_hand(state);
+        let card = prompt(tr!("console-discard"));
         ActionData::Card { card }
     }
 
     fn lead(&self, state: &PlayerState) -> ActionData {
-        println!("Hand: {}", self.format_cards(state.hand));
-        let card = prompt("Lead? ");
+        self.print_hand(state);
+        let card = prompt(tr!("console-lead"));
         ActionData::Card { card }
     }
 
+    #[allow(clippy::unused_self)]
+    fn call_renege(&self, _: &PlayerState) -> ActionData {
+        if prompt::<bool, _>(tr!("console-call-renege")) {
+            let card = prompt(tr!("console-card"));
+            ActionData::Card { card }
+        } else {
+            ActionData::Pass
+        }
+    }
+
     fn follow(&self, state: &PlayerState) -> ActionData {
-        let trick = state.tricks.last().unwrap();
-        println!("Trick: {}", self.format_trick(trick));
-        println!("Hand: {}", self.format_cards(state.hand));
-        let card = prompt("Follow? ");
+        let trick = state.tricks.in_progress().unwrap();
+        let trick = self.format_trick(trick);
+        println!("{}", tr!("console-trick", trick = trick));
+        self.print_hand(state);
+        let card = prompt(tr!("console-follow"));
         ActionData::Card { card }
     }
 }
@@ -140,39 +157,65 @@ impl Player for Console {
             ActionType::DealerDiscard => self.dealer_discard(&state),
             ActionType::Lead => self.lead(&state),
             ActionType::Follow => self.follow(&state),
+            ActionType::CallRenege => self.call_renege(&state),
         }
     }
 
     fn notify(&self, _: PlayerState, event: &Event) {
         match event {
+            Event::Cut(cut) => {
+                for (seat, card) in &cut.cards {
+                    let card = self.format_card(*card);
+                    println!(
+                        "{}",
+                        tr!("console-cuts", seat = seat.to_string(), card = card)
+                    );
+                }
+                let dealer = cut.dealer.to_string();
+                println!("{}", tr!("narration-deals-first", seat = dealer));
+            }
+            Event::Packet(_, _) => (),
             Event::Deal(dealer, top) => {
-                println!("Dealer: {dealer}");
-                println!("Top card: {}", self.format_card(*top));
+                println!("{}", tr!("console-dealer", seat = dealer.to_string()));
+                let top = self.format_card(*top);
+                println!("{}", tr!("console-top-card", card = top));
+            }
+            Event::Redeal(dealer) => {
+                println!("{}", tr!("narration-redeal", seat = dealer.to_string()));
             }
             Event::Call(contract) => {
+                let seat = contract.maker.to_string();
+                let suit = self.format_suit(contract.suit);
                 println!(
-                    "{:?}: Called {}{}",
-                    contract.maker,
-                    self.format_suit(contract.suit),
-                    if contract.alone { " alone" } else { "" }
+                    "{}",
+                    if contract.alone {
+                        tr!("narration-called-alone", seat = seat, suit = suit)
+                    } else {
+                        tr!("narration-called", seat = seat, suit = suit)
+                    }
                 );
             }
+            Event::Renege(revoke) => {
+                let seat = revoke.seat.to_string();
+                let card = self.format_card(revoke.card);
+                println!("{}", tr!("narration-renege", seat = seat, card = card));
+            }
             Event::Trick(trick) => {
-                println!(
-                    "Trick: {} -> {:?}",
-                    self.format_trick(trick),
-                    trick.best().0
-                );
+                let seat = trick.best().0.to_string();
+                let trick = self.format_trick(trick);
+                println!("{}", tr!("console-trick-won", trick = trick, seat = seat));
             }
             Event::Round(outcome) => {
-                println!("{:}: {} points", outcome.team, outcome.points);
+                let team = outcome.team.to_string();
+                let points = outcome.points;
+                println!("{}", tr!("narration-round", team = team, points = points));
             }
-            Event::Game(team) => println!("{team} wins!"),
+            Event::Game(outcome) => println!("{outcome}"),
         }
     }
 
     fn handle_error(&self, err: PlayerError) -> bool {
-        println!("Error: {err:?}");
+        println!("{}", tr!("console-error", error = err.to_string()));
         true
     }
 }
@@ -1,39 +1,337 @@
 //! Robot player
 
-use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
 
-use crate::euchre::{ActionData, ActionType, Card, Player, PlayerState, Rank, Suit, Team};
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::paths::create_file;
+use crate::euchre::{
+    playout, Action, ActionData, ActionType, Card, CardSet, DealPattern, Inference, Player,
+    PlayerState, Rank, RoundConfig, Suit, Team, Trace,
+};
+
+#[cfg(test)]
+mod test;
+
+/// A named bidding style, for setting up tables of players with different habits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Calls only with strong hands, and rarely goes alone.
+    Conservative,
+    /// The default thresholds.
+    Standard,
+    /// Calls on marginal hands, and goes alone more readily.
+    Aggressive,
+    /// The default thresholds, but once the top card is turned down, the player left of the dealer
+    /// leans towards calling next, and the dealer's partner towards reverse next.
+    Next,
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Preset::Conservative => "conservative",
+            Preset::Standard => "standard",
+            Preset::Aggressive => "aggressive",
+            Preset::Next => "next",
+        })
+    }
+}
+
+impl FromStr for Preset {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Preset::all()
+            .iter()
+            .copied()
+            .find(|p| p.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+impl Preset {
+    /// All presets.
+    pub fn all() -> &'static [Preset; 4] {
+        static PRESETS: [Preset; 4] = [
+            Preset::Conservative,
+            Preset::Standard,
+            Preset::Aggressive,
+            Preset::Next,
+        ];
+        &PRESETS
+    }
+
+    /// Returns the robot parameters for the preset.
+    pub fn params(self) -> RobotParams {
+        let standard = RobotParams::default();
+        match self {
+            Preset::Conservative => RobotParams {
+                call: 9,
+                loner: 12,
+                dealer_margin: 1,
+                ..standard
+            },
+            Preset::Standard => standard,
+            Preset::Aggressive => RobotParams {
+                call: 7,
+                loner: 10,
+                dealer_margin: 3,
+                ..standard
+            },
+            Preset::Next => RobotParams {
+                next_bonus: 2,
+                reverse_next_bonus: 2,
+                ..standard
+            },
+        }
+    }
+}
+
+/// Tunable parameters for the robot's bidding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RobotParams {
+    /// The minimum hand strength worth calling trump.
+    pub call: u8,
+    /// The minimum hand strength worth going alone.
+    pub loner: u8,
+    /// How far short of a call the dealer's hand may be, and still order up the top card rather
+    /// than be stuck calling a weaker suit.
+    pub dealer_margin: u8,
+    /// How much the player left of the dealer favors calling "next", the suit of the same color as
+    /// the turned down card.
+    pub next_bonus: u8,
+    /// How much the dealer's partner favors calling "reverse next", a suit of the other color from
+    /// the turned down card.
+    pub reverse_next_bonus: u8,
+    /// The number of rollouts with which the dealer evaluates each possible discard, after
+    /// picking up the top card. With none, the dealer discards by rule of thumb instead. Rollouts
+    /// are dealt at random, so they make the robot's play nondeterministic.
+    pub discard_rollouts: u16,
+}
+
+impl Default for RobotParams {
+    fn default() -> Self {
+        Self {
+            call: 8,
+            loner: 11,
+            dealer_margin: 2,
+            next_bonus: 0,
+            reverse_next_bonus: 0,
+            discard_rollouts: 0,
+        }
+    }
+}
+
+impl RobotParams {
+    /// The name of the parameter file, in the config directory, as written by training.
+    pub const FILE_NAME: &'static str = "euchre-robot.json";
 
-const MIN_Z_SCORE: u8 = 8;
-const MIN_LONER_Z_SCORE: u8 = 11;
+    /// Loads parameters from a file. A missing file yields the defaults.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes parameters to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 struct Hand {
-    cards: Vec<Card>,
+    cards: CardSet,
     trump: Suit,
-    by_suit: HashMap<Suit, Vec<Card>>,
 }
 
 #[derive(Debug, Default)]
-pub struct Robot {}
+pub struct Robot {
+    params: RobotParams,
+}
 
 impl Player for Robot {
     fn take_action(&self, state: PlayerState, action: ActionType) -> ActionData {
-        match action {
-            ActionType::BidTop => bid_top(&state),
-            ActionType::BidOther => bid_other(&state),
-            ActionType::DealerDiscard => dealer_discard(&state),
-            ActionType::Lead => lead_trick(&state),
-            ActionType::Follow => follow_trick(&state),
-        }
+        self.take_traced_action(state, action).0
+    }
+
+    /// Agrees to throw in the round, unless the hand is worth calling in any suit.
+    fn agree_to_throw_in(&self, state: PlayerState) -> bool {
+        let other = Suit::all_suits()
+            .iter()
+            .filter(|&&suit| suit != state.top.suit)
+            .map(|&suit| Hand::new(state.hand, suit).z_score(None))
+            .max()
+            .unwrap_or_default();
+        top_strength(&state).max(other) < self.params.call
     }
 }
 
+/// Calls a renege that has been exposed by an opponent's later play, or passes if there is none.
+fn call_renege(state: &PlayerState) -> ActionData {
+    state
+        .tricks
+        .exposed_revokes()
+        .find(|r| r.seat.team() != state.seat.team())
+        .map_or(ActionData::Pass, |r| ActionData::Card { card: r.card })
+}
+
 impl Robot {
+    /// Creates a robot with the specified parameters.
+    pub fn new(params: RobotParams) -> Self {
+        Self { params }
+    }
+
+    /// Returns the robot's parameters.
+    pub fn params(&self) -> RobotParams {
+        self.params
+    }
+
     pub fn into_player(self) -> Arc<dyn Player> {
         Arc::new(self)
     }
+
+    /// Takes the specified action, and returns the robot's evaluation of the alternatives, if it
+    /// weighed any: the strength of each call during the bidding, with a pass scored at the
+    /// threshold for calling, or the total points won with each discard over its rollouts. Plays
+    /// chosen by rule of thumb have no evaluation.
+    pub fn take_traced_action(
+        &self,
+        state: PlayerState,
+        action: ActionType,
+    ) -> (ActionData, Option<Trace>) {
+        match action {
+            ActionType::BidTop => (
+                bid_top(&state, self.params),
+                Some(self.trace_bid(&state, action)),
+            ),
+            ActionType::BidOther => (
+                bid_other(&state, self.params),
+                Some(self.trace_bid(&state, action)),
+            ),
+            ActionType::DealerDiscard => match self.params.discard_rollouts {
+                0 => (dealer_discard(&state), None),
+                rollouts => match self.discard_by_rollouts(&state, rollouts.into()) {
+                    Some((card, trace)) => (ActionData::Card { card }, Some(trace)),
+                    None => (dealer_discard(&state), None),
+                },
+            },
+            ActionType::Lead => (lead_trick(&state), None),
+            ActionType::Follow => (follow_trick(&state), None),
+            ActionType::CallRenege => (call_renege(&state), None),
+        }
+    }
+
+    /// Scores each bid that the robot considers.
+    fn trace_bid(&self, state: &PlayerState, action: ActionType) -> Trace {
+        let call = |suit| ActionData::Call { suit, alone: false };
+        let mut scores = vec![];
+        if action == ActionType::BidTop || state.seat != state.dealer {
+            scores.push((ActionData::Pass, i32::from(self.params.call)));
+        }
+        if action == ActionType::BidTop {
+            scores.push((call(state.top.suit), i32::from(top_strength(state))));
+        } else {
+            for &suit in Suit::all_suits() {
+                if suit != state.top.suit {
+                    let strength = Hand::new(state.hand, suit).z_score(None);
+                    let score = strength.saturating_add(convention_bonus(state, suit, self.params));
+                    scores.push((call(suit), i32::from(score)));
+                }
+            }
+        }
+        Trace { scores }
+    }
+
+    /// Chooses the dealer's discard exhaustively, by trying each card in the hand against the same
+    /// random deals of the hidden cards, and playing out the rest of the round. Returns the card
+    /// that scored best on average, along with the total for each card, or `None` if the round
+    /// can't be reconstructed.
+    fn discard_by_rollouts(&self, state: &PlayerState, rollouts: usize) -> Option<(Card, Trace)> {
+        let contract = state.contract?;
+        let inference = Inference::new(state)?;
+
+        // The dealer only discards after the top card is ordered up, so everyone before the maker
+        // passed.
+        let mut prefix = vec![];
+        let mut seat = state.dealer.next();
+        while seat != contract.maker {
+            prefix.push(Action::new(seat, ActionType::BidTop, ActionData::Pass));
+            seat = seat.next();
+        }
+        let call = ActionData::Call {
+            suit: contract.suit,
+            alone: contract.alone,
+        };
+        prefix.push(Action::new(contract.maker, ActionType::BidTop, call));
+
+        let mut rng = rand::thread_rng();
+        let mut totals = vec![0; state.hand.len()];
+        for _ in 0..rollouts {
+            let mut hands = inference.sample(&mut rng);
+            hands[state.dealer].retain(|&c| c != state.top);
+            // A seat sitting out is dealt whatever is left over; its cards are never played.
+            let dealt: CardSet = hands.values().flatten().chain([&state.top]).collect();
+            let mut rest = (!dealt).iter();
+            for hand in hands.values_mut().filter(|h| h.is_empty()) {
+                hand.extend(rest.by_ref().take(5));
+            }
+            let config =
+                RoundConfig::from_hands(state.dealer, hands, state.top, DealPattern::default())
+                    .ok()?;
+            for (&card, total) in state.hand.iter().zip(&mut totals) {
+                let discard = ActionData::Card { card };
+                prefix.push(Action::new(
+                    state.dealer,
+                    ActionType::DealerDiscard,
+                    discard,
+                ));
+                *total += playout(config.clone(), &prefix, self, state.seat.team()).ok()?;
+                prefix.pop();
+            }
+        }
+        // Ties go to the rule of thumb.
+        let best = totals.iter().copied().max()?;
+        let ActionData::Card { card: usual } = dealer_discard(state) else {
+            unreachable!("discard is a card");
+        };
+        let tied: Vec<Card> = state
+            .hand
+            .iter()
+            .zip(&totals)
+            .filter(|&(_, &total)| total == best)
+            .map(|(&card, _)| card)
+            .collect();
+        let card = if tied.contains(&usual) {
+            usual
+        } else {
+            tied[0]
+        };
+        let scores = state
+            .hand
+            .iter()
+            .zip(totals)
+            .map(|(&card, total)| (ActionData::Card { card }, total))
+            .collect();
+        Some((card, Trace { scores }))
+    }
+
+    /// Scores the strength of a hand for the specified trump suit, using the same rubric that the
+    /// robot uses for bidding. A score of 8 or more is worth a call.
+    pub fn hand_strength(hand: &[Card], trump: Suit) -> u8 {
+        Hand::new(hand, trump).z_score(None)
+    }
 }
 
 fn least_valuable(mut cards: Vec<Card>, trump: Suit) -> Card {
@@ -46,10 +344,11 @@ fn most_valuable(mut cards: Vec<Card>, trump: Suit) -> Card {
     cards.pop().expect("non-empty")
 }
 
-fn bid_top(state: &PlayerState) -> ActionData {
-    let hand = Hand::new(state.hand.clone(), state.top.suit);
-    let mut score = if state.seat.team() == state.dealer.team() {
-        let mut alt_hand = hand.clone();
+/// Scores the strength of the player's hand, if the top card is ordered up.
+fn top_strength(state: &PlayerState) -> u8 {
+    let hand = Hand::new(state.hand, state.top.suit);
+    if state.seat.team() == state.dealer.team() {
+        let mut alt_hand = hand;
         alt_hand.push(state.top);
         if state.dealer == state.seat {
             // Dealer knows what to discard (e.g., for voids).
@@ -58,8 +357,13 @@ fn bid_top(state: &PlayerState) -> ActionData {
         alt_hand.z_score(None)
     } else {
         hand.z_score(Some(state.top))
-    };
-    if score >= MIN_Z_SCORE {
+    }
+}
+
+fn bid_top(state: &PlayerState, params: RobotParams) -> ActionData {
+    let hand = Hand::new(state.hand, state.top.suit);
+    let mut score = top_strength(state);
+    if score >= params.call {
         if state.seat == state.dealer.opposite() {
             // If we're considering going alone, and the dealer is
             // opposite, ignore the top card. This could be more nuanced -
@@ -70,14 +374,14 @@ fn bid_top(state: &PlayerState) -> ActionData {
         }
         ActionData::Call {
             suit: state.top.suit,
-            alone: score >= MIN_LONER_Z_SCORE,
+            alone: score >= params.loner,
         }
-    } else if score + 2 >= MIN_Z_SCORE
+    } else if score.saturating_add(params.dealer_margin) >= params.call
         && state.seat == state.dealer
         && Suit::all_suits()
             .iter()
             .filter(|&&s| s != state.top.suit)
-            .all(|s| score > Hand::new(state.hand.clone(), *s).z_score(None))
+            .all(|s| score > Hand::new(state.hand, *s).z_score(None))
     {
         //println!("{:?}: Better than getting stuck...", self.seat);
         ActionData::Call {
@@ -89,20 +393,35 @@ fn bid_top(state: &PlayerState) -> ActionData {
     }
 }
 
-fn bid_other(state: &PlayerState) -> ActionData {
-    let mut best = (0, Suit::Club);
+/// Returns how much a convention favors calling the specified suit, once the top card has been
+/// turned down.
+fn convention_bonus(state: &PlayerState, suit: Suit, params: RobotParams) -> u8 {
+    let turned_down = state.top.suit;
+    if state.seat == state.dealer.next() && suit == turned_down.to_matching_color() {
+        params.next_bonus
+    } else if state.seat == state.dealer.opposite() && suit.color() != turned_down.color() {
+        params.reverse_next_bonus
+    } else {
+        0
+    }
+}
+
+fn bid_other(state: &PlayerState, params: RobotParams) -> ActionData {
+    // The best suit, its score including any conventional bonus, and its strength without.
+    let mut best = (0, 0, Suit::Club);
     for &suit in Suit::all_suits() {
         if suit != state.top.suit {
-            let score = Hand::new(state.hand.clone(), suit).z_score(None);
+            let strength = Hand::new(state.hand, suit).z_score(None);
+            let score = strength.saturating_add(convention_bonus(state, suit, params));
             if score > best.0 {
-                best = (score, suit);
+                best = (score, strength, suit);
             }
         }
     }
-    if best.0 >= MIN_Z_SCORE || state.seat == state.dealer {
+    if best.0 >= params.call || state.seat == state.dealer {
         ActionData::Call {
-            suit: best.1,
-            alone: best.0 >= MIN_LONER_Z_SCORE,
+            suit: best.2,
+            alone: best.1 >= params.loner,
         }
     } else {
         ActionData::Pass
@@ -111,7 +430,7 @@ fn bid_other(state: &PlayerState) -> ActionData {
 
 fn dealer_discard(state: &PlayerState) -> ActionData {
     let contract = state.contract.expect("contract must be set");
-    let mut hand = Hand::new(state.hand.clone(), contract.suit);
+    let mut hand = Hand::new(state.hand, contract.suit);
     let card = hand.dealer_discard();
     ActionData::Card { card }
 }
@@ -141,24 +460,23 @@ fn lead_trick(state: &PlayerState) -> ActionData {
     //  - Least card
 
     let contract = state.contract.expect("contract must be set");
-    let mut hand = Hand::new(state.hand.clone(), contract.suit);
+    let hand = Hand::new(state.hand, contract.suit);
     let team = state.seat.team();
     let trump = contract.suit;
     if Team::from(contract.maker) == team {
         // Right bower
         let right = Card::new(Rank::Jack, trump);
-        if let Some(card) = hand.discard(right) {
-            return ActionData::Card { card };
+        if hand.cards.contains(right) {
+            return ActionData::Card { card: right };
         }
 
-        hand.sort();
-
+        let sorted = hand.sorted();
         if hand.len() == 5 {
-            if let Some(&card) = hand.iter().find(|c| c.is_trump(trump)) {
+            if let Some(&card) = sorted.iter().find(|c| c.is_trump(trump)) {
                 // Least trump on the first round
                 return ActionData::Card { card };
             }
-        } else if let Some(&card) = hand.iter().rev().find(|c| c.is_trump(trump)) {
+        } else if let Some(&card) = sorted.iter().rev().find(|c| c.is_trump(trump)) {
             // Best trump on subsequent rounds
             return ActionData::Card { card };
         }
@@ -166,8 +484,8 @@ fn lead_trick(state: &PlayerState) -> ActionData {
 
     // Singleton ace, or ace with one other card.
     for threshold in [1, 2] {
-        if let Some(&card) = hand.iter_by_suit().find_map(|(suit, cards)| {
-            if *suit != trump && cards.len() == threshold {
+        if let Some(card) = hand.iter_by_suit().find_map(|(suit, cards)| {
+            if suit != trump && cards.len() == threshold {
                 cards.iter().find(|card| card.rank == Rank::Ace)
             } else {
                 None
@@ -177,27 +495,27 @@ fn lead_trick(state: &PlayerState) -> ActionData {
         }
     }
 
-    hand.sort();
-    let card = if hand.len() >= 4 {
+    let sorted = hand.sorted();
+    let card = if sorted.len() >= 4 {
         // Least card
-        hand.cards[0]
+        sorted[0]
     } else if Team::from(contract.maker) != team {
         // Best non-trump card as defender
-        if let Some(card) = hand.iter().rev().find(|c| !c.is_trump(trump)) {
+        if let Some(card) = sorted.iter().rev().find(|c| !c.is_trump(trump)) {
             *card
         } else {
-            *hand.cards.last().expect("non-empty")
+            *sorted.last().expect("non-empty")
         }
     } else {
         // Best card
-        *hand.cards.last().expect("non-empty")
+        *sorted.last().expect("non-empty")
     };
     ActionData::Card { card }
 }
 
 fn follow_trick(state: &PlayerState) -> ActionData {
     // Filter down to what cards I _can_ play.
-    let trick = state.tricks.last().expect("trick must be started");
+    let trick = state.tricks.in_progress().expect("trick must be started");
     let cards = trick.filter(state.hand);
     if cards.len() == 1 {
         // The easiest choice is no choice at all.
@@ -215,13 +533,11 @@ fn follow_trick(state: &PlayerState) -> ActionData {
     //  - Which cards do I want to get rid of?
     //  - When discarding, can I void a suit?
     //
-    let position = trick.cards.len();
+    let position = trick.len();
     let final_position = if contract.alone { 2 } else { 3 };
     let partner_winning = trick.best().0 == state.seat.opposite();
 
-    let (losing, winning): (Vec<_>, Vec<_>) = cards
-        .into_iter()
-        .partition(|c| c.value(trick.trump, trick.lead().1) < trick.best_value);
+    let (winning, losing): (Vec<_>, Vec<_>) = cards.into_iter().partition(|&c| trick.beats(c));
 
     let card = if winning.is_empty() {
         // Always lose with the least-valued card.
@@ -237,6 +553,7 @@ fn follow_trick(state: &PlayerState) -> ActionData {
         }
     } else if state.hand.len() >= 4
         && partner_winning
+        && !losing.is_empty()
         && trick
             .get_card(state.seat.opposite())
             .is_some_and(|c| c.rank == Rank::Ace && !c.is_trump(trump))
@@ -250,80 +567,48 @@ fn follow_trick(state: &PlayerState) -> ActionData {
     ActionData::Card { card }
 }
 
-fn discard(cards: &mut Vec<Card>, card: Card) -> Option<Card> {
-    cards
-        .iter()
-        .position(|c| *c == card)
-        .map(|idx| cards.remove(idx))
-}
-
-fn group_cards_by_suit(cards: &[Card], trump: Suit) -> HashMap<Suit, Vec<Card>> {
-    let mut group: HashMap<_, Vec<_>> = HashMap::with_capacity(4);
-    for card in cards {
-        let suit = card.effective_suit(trump);
-        group.entry(suit).or_default().push(*card);
-    }
-    group
-}
-
 impl Hand {
-    pub fn new(cards: Vec<Card>, trump: Suit) -> Self {
-        let by_suit = group_cards_by_suit(&cards, trump);
-        Self {
-            cards,
-            trump,
-            by_suit,
-        }
+    pub fn new<'a, I: IntoIterator<Item = &'a Card>>(cards: I, trump: Suit) -> Self {
+        let cards = cards.into_iter().collect();
+        Self { cards, trump }
     }
 
     pub fn len(&self) -> usize {
         self.cards.len()
     }
 
-    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
-        self.cards.iter()
+    /// Returns the cards of the specified effective suit.
+    pub fn suit(&self, suit: Suit) -> CardSet {
+        self.cards & CardSet::effective_suit(suit, self.trump)
     }
 
-    pub fn iter_by_suit(&self) -> std::collections::hash_map::Iter<'_, Suit, Vec<Card>> {
-        self.by_suit.iter()
+    /// Returns an iterator over the non-empty effective suits in the hand.
+    pub fn iter_by_suit(&self) -> impl Iterator<Item = (Suit, CardSet)> + '_ {
+        Suit::all_suits()
+            .iter()
+            .map(move |&suit| (suit, self.suit(suit)))
+            .filter(|(_, cards)| !cards.is_empty())
     }
 
     pub fn num_suits(&self) -> usize {
-        self.by_suit.len()
+        self.iter_by_suit().count()
     }
 
     pub fn push(&mut self, card: Card) {
-        let suit = card.effective_suit(self.trump);
-        self.cards.push(card);
-        self.by_suit.entry(suit).or_default().push(card);
+        self.cards.insert(card);
     }
 
-    pub fn sort(&mut self) {
+    /// Returns the cards in the hand, sorted from least to most valuable.
+    pub fn sorted(&self) -> Vec<Card> {
         let trump = self.trump;
-        self.cards.sort_unstable_by_key(|c| c.value(trump, *c));
-    }
-
-    pub fn discard(&mut self, card: Card) -> Option<Card> {
-        discard(&mut self.cards, card).map(|card| {
-            self.by_suit = group_cards_by_suit(&self.cards, self.trump);
-            card
-        })
+        let mut cards: Vec<_> = self.cards.iter().collect();
+        cards.sort_unstable_by_key(|c| c.value(trump, *c));
+        cards
     }
 
     pub fn must_discard(&mut self, card: Card) -> Card {
-        self.discard(card).expect("hand must contain card")
-    }
-
-    pub fn discard_first(&mut self) -> Option<Card> {
-        if self.cards.is_empty() {
-            None
-        } else {
-            Some(self.cards.remove(0))
-        }
-    }
-
-    pub fn must_discard_first(&mut self) -> Card {
-        self.discard_first().expect("hand is non-empty")
+        assert!(self.cards.remove(card), "hand must contain card");
+        card
     }
 
     pub fn dealer_discard(&mut self) -> Card {
@@ -331,8 +616,9 @@ impl Hand {
         let voiding: Vec<_> = self
             .iter_by_suit()
             .filter_map(|(suit, cards)| {
-                if *suit != self.trump && cards.len() == 1 && cards[0].rank != Rank::Ace {
-                    Some(cards[0])
+                let card = cards.iter().next().expect("non-empty");
+                if suit != self.trump && cards.len() == 1 && card.rank != Rank::Ace {
+                    Some(card)
                 } else {
                     None
                 }
@@ -347,12 +633,9 @@ impl Hand {
         let near_voiding: Vec<_> = self
             .iter_by_suit()
             .filter_map(|(suit, cards)| {
-                if *suit != self.trump && cards.len() == 2 {
-                    match (cards[0].rank, cards[1].rank) {
-                        (Rank::Ace, _) => Some(cards[1]),
-                        (_, Rank::Ace) => Some(cards[0]),
-                        _ => None,
-                    }
+                let ace = Card::new(Rank::Ace, suit);
+                if suit != self.trump && cards.len() == 2 && cards.contains(ace) {
+                    (cards - CardSet::from(ace)).iter().next()
                 } else {
                     None
                 }
@@ -364,8 +647,8 @@ impl Hand {
         }
 
         // Can't void a suit, just remove the weakest card.
-        self.sort();
-        self.must_discard_first()
+        let card = self.sorted()[0];
+        self.must_discard(card)
     }
 
     // A rubric based on Eric Zalas's "z-score".
@@ -374,8 +657,9 @@ impl Hand {
 
         // Intrinsic card values.
         score += self
+            .cards
             .iter()
-            .fold(0, |acc, card| acc + card_z_score(*card, self.trump));
+            .fold(0, |acc, card| acc + card_z_score(card, self.trump));
 
         // Voids.
         score += match self.num_suits() {
@@ -0,0 +1,124 @@
+use crate::euchre::{BaseRound, Round, RoundConfig, Seat, Tricks};
+
+use super::*;
+
+#[test]
+fn test_preset_names() {
+    for &preset in Preset::all() {
+        assert_eq!(preset.to_string().parse(), Ok(preset));
+    }
+    assert_eq!("Aggressive".parse(), Ok(Preset::Aggressive));
+    assert_eq!("reckless".parse::<Preset>(), Err(()));
+}
+
+#[test]
+fn test_next_convention() {
+    // North dealt, and the nine of hearts was turned down. East holds the right bower of
+    // diamonds, which is next, but not quite enough to call it on strength alone.
+    let hand: Vec<Card> = ["jd", "9d", "ac", "ts", "qc"]
+        .iter()
+        .map(|c| c.parse().unwrap())
+        .collect();
+    let tricks = Tricks::default();
+    let top: Card = "9h".parse().unwrap();
+    let bid = |preset: Preset| {
+        let state = PlayerState::new(
+            Seat::East,
+            Seat::North,
+            top,
+            None,
+            &hand,
+            hand.iter().chain([&top]).collect(),
+            &tricks,
+        );
+        Robot::new(preset.params()).take_action(state, ActionType::BidOther)
+    };
+    assert_eq!(bid(Preset::Standard), ActionData::Pass);
+    assert_eq!(
+        bid(Preset::Next),
+        ActionData::Call {
+            suit: Suit::Diamond,
+            alone: false
+        }
+    );
+}
+
+#[test]
+fn test_agree_to_throw_in() {
+    let tricks = Tricks::default();
+    let top: Card = "9h".parse().unwrap();
+    let agrees = |hand: &[&str]| {
+        let hand: Vec<Card> = hand.iter().map(|c| c.parse().unwrap()).collect();
+        let seen = hand.iter().chain([&top]).collect();
+        let state = PlayerState::new(Seat::East, Seat::North, top, None, &hand, seen, &tricks);
+        Robot::default().agree_to_throw_in(state)
+    };
+    assert!(agrees(&["9d", "tc", "qs", "9s", "td"]));
+    assert!(!agrees(&["jh", "jd", "ah", "kh", "qh"]));
+    assert!(!agrees(&["js", "jc", "as", "ks", "qs"]));
+}
+
+#[test]
+fn test_discard_by_rollouts() {
+    let robot = Robot::new(RobotParams {
+        discard_rollouts: 4,
+        ..RobotParams::default()
+    });
+    for alone in [false, true] {
+        let mut round = BaseRound::from(RoundConfig::random());
+        let dealer = round.dealer();
+        let suit = round.top_card().suit;
+        // The dealer orders themselves up, so that they always discard.
+        for seat in dealer.next_n(4) {
+            let data = if seat == dealer {
+                ActionData::Call { suit, alone }
+            } else {
+                ActionData::Pass
+            };
+            round
+                .apply_action(Action::new(seat, ActionType::BidTop, data))
+                .unwrap();
+        }
+        let expect = round.next_action().unwrap();
+        assert_eq!(expect.action, ActionType::DealerDiscard);
+        let state = round.player_state(dealer);
+        let (card, trace) = robot.discard_by_rollouts(&state, 4).unwrap();
+        assert_eq!(trace.scores.len(), state.hand.len());
+        let best = trace.scores.iter().map(|&(_, s)| s).max().unwrap();
+        assert!(trace.scores.contains(&(ActionData::Card { card }, best)));
+        let hand = state.hand.clone();
+        let data = robot.take_action(state, expect.action);
+        assert!(matches!(data, ActionData::Card { card } if hand.contains(&card)));
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+}
+
+#[test]
+fn test_traced_action() {
+    let robot = Robot::default();
+    let call = i32::from(robot.params().call);
+    let mut round = BaseRound::from(RoundConfig::random());
+    while let Some(expect) = round.next_action() {
+        let state = || round.player_state(expect.seat);
+        let (data, trace) = robot.take_traced_action(state(), expect.action);
+        assert_eq!(data, robot.take_action(state(), expect.action));
+        match expect.action {
+            ActionType::BidTop | ActionType::BidOther => {
+                let trace = trace.unwrap();
+                let best = trace.scores.iter().map(|&(_, s)| s).max().unwrap();
+                let dealer = expect.seat == round.dealer();
+                if expect.action == ActionType::BidOther && dealer {
+                    assert!(!trace.scores.iter().any(|(d, _)| *d == ActionData::Pass));
+                } else {
+                    assert!(trace.scores.contains(&(ActionData::Pass, call)));
+                }
+                // Strong hands always call, though the dealer may call on a weaker one.
+                if best > call {
+                    assert_ne!(data, ActionData::Pass);
+                }
+            }
+            _ => assert_eq!(trace, None),
+        }
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+}
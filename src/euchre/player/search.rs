@@ -0,0 +1,284 @@
+//! Determinized alpha-beta search robot.
+//!
+//! When playing a card, the robot deals the hidden cards at random, consistent with what it has
+//! inferred about the other hands, and searches the rest of the round with alpha-beta pruning, as
+//! if every hand were face up. Each deal votes for the cards that take the most tricks, and the
+//! card with the most votes is played. Bidding and discarding are left to the heuristic robot,
+//! which also breaks ties.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::{
+    ActionData, ActionType, Card, CardSet, Inference, Player, PlayerState, Robot, RobotParams,
+    Round, Seat, SeatMap, Suit, Team,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Which robot takes a seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RobotKind {
+    /// The heuristic robot, which plays by rule of thumb. It's quick, but easier to beat.
+    #[default]
+    Heuristic,
+    /// The searching robot, which bids like the heuristic robot, but plays cards by searching
+    /// sampled deals. It's slower, and harder to beat.
+    Search,
+}
+
+impl Display for RobotKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            RobotKind::Heuristic => "heuristic",
+            RobotKind::Search => "search",
+        })
+    }
+}
+
+impl FromStr for RobotKind {
+    type Err = ();
+
+    /// Parses a robot kind by name, or by difficulty: `easy` for the heuristic robot, and `hard`
+    /// for the searching robot.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "heuristic" | "easy" => Ok(RobotKind::Heuristic),
+            "search" | "hard" => Ok(RobotKind::Search),
+            _ => Err(()),
+        }
+    }
+}
+
+impl RobotKind {
+    /// Creates a robot of this kind, which bids with the specified parameters.
+    pub fn player(self, params: RobotParams) -> Arc<dyn Player> {
+        let robot = Robot::new(params);
+        match self {
+            RobotKind::Heuristic => robot.into_player(),
+            RobotKind::Search => SearchRobot::from(robot).into_player(),
+        }
+    }
+}
+
+/// A robot that plays cards by searching sampled deals.
+#[derive(Debug)]
+pub struct SearchRobot {
+    robot: Robot,
+    samples: usize,
+}
+
+impl Default for SearchRobot {
+    fn default() -> Self {
+        Self::from(Robot::default())
+    }
+}
+
+impl From<Robot> for SearchRobot {
+    /// Creates a searching robot that leaves bidding and discarding to the specified robot.
+    fn from(robot: Robot) -> Self {
+        Self { robot, samples: 20 }
+    }
+}
+
+impl Player for SearchRobot {
+    fn take_action(&self, state: PlayerState, action: ActionType) -> ActionData {
+        let best = match action {
+            ActionType::Lead | ActionType::Follow => self.best_cards(&state),
+            _ => vec![],
+        };
+        match self.robot.take_action(state, action) {
+            ActionData::Card { card } if !best.is_empty() && !best.contains(&card) => {
+                ActionData::Card { card: best[0] }
+            }
+            data => data,
+        }
+    }
+
+    fn agree_to_throw_in(&self, state: PlayerState) -> bool {
+        self.robot.agree_to_throw_in(state)
+    }
+}
+
+impl SearchRobot {
+    /// Sets the number of deals sampled per card played.
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    pub fn into_player(self) -> Arc<dyn Player> {
+        Arc::new(self)
+    }
+
+    /// Returns the cards that received the most votes, or an empty list if there's nothing to
+    /// search.
+    fn best_cards(&self, state: &PlayerState) -> Vec<Card> {
+        let Some(inference) = Inference::new(state) else {
+            return vec![];
+        };
+        let Some(contract) = state.contract else {
+            return vec![];
+        };
+        let team = state.seat.team();
+        let mut rng = rand::thread_rng();
+        let mut votes: Vec<(Card, u32)> = vec![];
+        for _ in 0..self.samples {
+            let hands = inference.sample(&mut rng);
+            let root = Position::new(state, contract.suit, &hands);
+            let values: Vec<(Card, i8)> = root
+                .moves()
+                .iter()
+                .map(|card| {
+                    let (child, gain) = root.play(card, team);
+                    (card, gain + child.search(team, -1, 6))
+                })
+                .collect();
+            let best = values.iter().map(|&(_, v)| v).max().unwrap_or_default();
+            for &(card, value) in &values {
+                match votes.iter_mut().find(|(c, _)| *c == card) {
+                    Some((_, n)) => *n += u32::from(value == best),
+                    None => votes.push((card, u32::from(value == best))),
+                }
+            }
+        }
+        let most = votes.iter().map(|&(_, n)| n).max().unwrap_or_default();
+        votes
+            .into_iter()
+            .filter(|&(_, n)| n == most)
+            .map(|(c, _)| c)
+            .collect()
+    }
+}
+
+/// Solves the round from the current position, with every hand face up. Returns each card that
+/// the next player may play, with the number of tricks that their team takes from the trick in
+/// progress onwards, with best play on both sides. Returns an empty list unless a card is to be
+/// led or followed.
+pub fn solve<R: Round>(round: &R) -> Vec<(Card, u8)> {
+    let (Some(expect), Some(contract)) = (round.next_action(), round.contract()) else {
+        return vec![];
+    };
+    if !matches!(expect.action, ActionType::Lead | ActionType::Follow) {
+        return vec![];
+    }
+    let hands = SeatMap::from_fn(|seat| round.player_state(seat).hand.clone());
+    let state = round.player_state(expect.seat);
+    let team = expect.seat.team();
+    let root = Position::new(&state, contract.suit, &hands);
+    root.moves()
+        .iter()
+        .map(|card| {
+            let (child, gain) = root.play(card, team);
+            let tricks = gain + child.search(team, -1, 6);
+            (card, u8::try_from(tricks).expect("non-negative"))
+        })
+        .collect()
+}
+
+/// A position in a round with every hand face up, from the current trick onwards.
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    trump: Suit,
+    hands: SeatMap<CardSet>,
+    /// The seat that plays next.
+    next: Seat,
+    /// The card led to the trick in progress, if any.
+    lead: Option<Card>,
+    /// The seat holding the best card in the trick in progress, and its value.
+    best: (Seat, u8),
+    /// The number of cards played into the trick in progress.
+    played: usize,
+    /// The number of cards in each trick.
+    trick_size: usize,
+    /// The partner of a player going alone.
+    sitting_out: Option<Seat>,
+}
+
+impl Position {
+    /// Creates a position from a player's view of the round, with the other hands as sampled.
+    fn new(state: &PlayerState, trump: Suit, hands: &SeatMap<Vec<Card>>) -> Self {
+        let contract = state.contract.expect("contract must be set");
+        let trick = state.tricks.in_progress();
+        Self {
+            trump,
+            hands: SeatMap::from_fn(|seat| hands[seat].iter().collect()),
+            next: state.seat,
+            lead: trick.map(|t| t.lead().1),
+            best: trick.map_or((state.seat, 0), |t| (t.best().0, t.best_value)),
+            played: trick.map_or(0, |t| t.len()),
+            trick_size: state.tricks.trick_size(),
+            sitting_out: contract.alone.then(|| contract.maker.opposite()),
+        }
+    }
+
+    /// Returns the cards that the next seat may play.
+    fn moves(&self) -> CardSet {
+        let hand = self.hands[self.next];
+        let Some(lead) = self.lead else {
+            return hand;
+        };
+        let following = hand & CardSet::effective_suit(lead.effective_suit(self.trump), self.trump);
+        if following.is_empty() {
+            hand
+        } else {
+            following
+        }
+    }
+
+    /// Plays a card for the next seat. Returns the new position, and 1 if the card completed a
+    /// trick taken by the specified team, or 0 otherwise.
+    fn play(mut self, card: Card, team: Team) -> (Self, i8) {
+        self.hands[self.next].remove(card);
+        let lead = *self.lead.get_or_insert(card);
+        let value = card.value(self.trump, lead);
+        if self.played == 0 || value > self.best.1 {
+            self.best = (self.next, value);
+        }
+        self.played += 1;
+        if self.played < self.trick_size {
+            self.next = self.next.next();
+            if Some(self.next) == self.sitting_out {
+                self.next = self.next.next();
+            }
+            return (self, 0);
+        }
+        let winner = self.best.0;
+        self.next = winner;
+        self.lead = None;
+        self.played = 0;
+        (self, i8::from(winner.team() == team))
+    }
+
+    /// Returns the number of tricks that the specified team takes from this position onwards,
+    /// with best play on both sides. Values outside `alpha..beta` are clamped.
+    fn search(&self, team: Team, mut alpha: i8, mut beta: i8) -> i8 {
+        let moves = self.moves();
+        if moves.is_empty() {
+            return 0;
+        }
+        let maximizing = self.next.team() == team;
+        let mut best = if maximizing { i8::MIN } else { i8::MAX };
+        for card in moves {
+            let (child, gain) = self.play(card, team);
+            let value = gain + child.search(team, alpha - gain, beta - gain);
+            if maximizing {
+                best = best.max(value);
+                alpha = alpha.max(value);
+            } else {
+                best = best.min(value);
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+}
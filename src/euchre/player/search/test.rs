@@ -0,0 +1,72 @@
+use crate::euchre::duplicate::play_round;
+use crate::euchre::{LoggingRound, Round, RoundConfig};
+
+use super::*;
+
+fn cards(s: &str) -> CardSet {
+    s.split_whitespace()
+        .map(|c| c.parse::<Card>().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_search() {
+    // Hearts are trump, and north leads. North-south can take both tricks only if south saves the
+    // left bower for the second trick.
+    let hands = SeatMap::from_fn(|seat| match seat {
+        Seat::North => cards("as 9c"),
+        Seat::East => cards("ks qh"),
+        Seat::South => cards("jd ts"),
+        Seat::West => cards("qs tc"),
+    });
+    let position = Position {
+        trump: Suit::Heart,
+        hands,
+        next: Seat::North,
+        lead: None,
+        best: (Seat::North, 0),
+        played: 0,
+        trick_size: 4,
+        sitting_out: None,
+    };
+    assert_eq!(position.search(Team::NorthSouth, -1, 6), 2);
+    assert_eq!(position.search(Team::EastWest, -1, 6), 0);
+
+    // Leading the club lets east discard, and keep the queen of trump for the second trick.
+    let (child, gain) = position.play("9c".parse().unwrap(), Team::NorthSouth);
+    assert_eq!(gain + child.search(Team::NorthSouth, -1, 6), 1);
+}
+
+#[test]
+fn test_play_round() {
+    let search = SearchRobot::default().with_samples(2);
+    let robot = Robot::default();
+    let mut round = LoggingRound::random();
+    while let Some(expect) = round.next_action() {
+        let state = round.player_state(expect.seat);
+        let data = if expect.seat.team() == Team::NorthSouth {
+            search.take_action(state, expect.action)
+        } else {
+            robot.take_action(state, expect.action)
+        };
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+    assert!(round.outcome().is_some());
+}
+
+#[test]
+fn test_robot_kind() {
+    for kind in [RobotKind::Heuristic, RobotKind::Search] {
+        assert_eq!(kind.to_string().parse(), Ok(kind));
+    }
+    assert_eq!("Easy".parse(), Ok(RobotKind::Heuristic));
+    assert_eq!("hard".parse(), Ok(RobotKind::Search));
+    assert_eq!("random".parse::<RobotKind>(), Err(()));
+
+    // A table of mixed robots plays a round to the end.
+    let lineup = SeatMap::from_fn(|seat| match seat.team() {
+        Team::NorthSouth => RobotKind::Search.player(RobotParams::default()),
+        Team::EastWest => RobotKind::Heuristic.player(RobotParams::default()),
+    });
+    assert!(play_round(RoundConfig::random(), &lineup).is_ok());
+}
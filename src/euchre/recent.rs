@@ -0,0 +1,62 @@
+//! Recently opened and saved files.
+
+use std::fs::{self, File};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::paths::create_file;
+
+#[cfg(test)]
+mod test;
+
+/// A list of recently opened and saved files, most recent first.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RecentFiles {
+    paths: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    /// The name of the list, in the data directory.
+    pub const FILE_NAME: &'static str = "euchre-recent.json";
+
+    /// The number of files remembered.
+    pub const CAPACITY: usize = 10;
+
+    /// Loads the list from a file. A missing file is treated as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes the list to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the files, most recent first.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Returns the `n`th most recent file, counting from 1.
+    pub fn get(&self, n: usize) -> Option<&Path> {
+        let index = n.checked_sub(1)?;
+        self.paths.get(index).map(PathBuf::as_path)
+    }
+
+    /// Moves a file to the front of the list, adding it if necessary. Paths are made absolute
+    /// where possible, so that the list can be used from any directory.
+    pub fn touch(&mut self, path: &Path) {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.paths.retain(|p| *p != path);
+        self.paths.insert(0, path);
+        self.paths.truncate(Self::CAPACITY);
+    }
+}
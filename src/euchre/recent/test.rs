@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn test_touch() {
+    let mut recent = RecentFiles::default();
+    for i in 0..12 {
+        recent.touch(Path::new(&format!("/no/such/dir/{i}.json")));
+    }
+    assert_eq!(recent.paths().len(), RecentFiles::CAPACITY);
+    assert_eq!(recent.get(1), Some(Path::new("/no/such/dir/11.json")));
+    assert_eq!(recent.get(0), None);
+
+    recent.touch(Path::new("/no/such/dir/5.json"));
+    assert_eq!(recent.get(1), Some(Path::new("/no/such/dir/5.json")));
+    assert_eq!(recent.get(2), Some(Path::new("/no/such/dir/11.json")));
+    assert_eq!(recent.paths().len(), RecentFiles::CAPACITY);
+}
@@ -1,25 +1,35 @@
 //! Round management
 
-use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use crate::engine;
+use crate::tr;
+
 use super::{
-    Action, ActionData, ActionType, Card, Deck, Event, ExpectAction, PlayerError, RoundError, Seat,
-    Suit, Team, Trick,
+    Action, ActionData, ActionType, Card, CardSet, Deck, Event, ExpectAction, PlayerError,
+    RoundError, Seat, SeatMap, Suit, Team, Trick,
 };
 
 mod base;
+mod fast;
+mod invariants;
 mod log;
 mod logging;
 mod tricks;
+mod view;
 pub use base::BaseRound;
-pub use log::{Id as LogId, Log, RawLog};
+pub use fast::FastRound;
+pub use log::{Id as LogId, Log, RawLog, Timing, Trace};
 pub use logging::LoggingRound;
-pub use tricks::Tricks;
+pub use tricks::{Revoke, Tricks};
+pub use view::RoundView;
+
+#[cfg(test)]
+mod test;
 
 /// A trait for implementing a round of euchre.
 ///
@@ -73,6 +83,9 @@ pub trait Round {
     /// Tricks played during this round.
     fn tricks(&self) -> &Tricks;
 
+    /// How failures to follow suit are handled.
+    fn renege_policy(&self) -> RenegePolicy;
+
     /// Returns a bundle of state visible to the specified player.
     fn player_state(&self, seat: Seat) -> PlayerState<'_>;
 
@@ -82,9 +95,89 @@ pub trait Round {
     /// Pops the oldest event from the queue of events.
     fn pop_event(&mut self) -> Option<Event>;
 
+    /// Returns every action that the next player may legally take, or an empty vector if the
+    /// round is over.
+    fn legal_actions(&self) -> Vec<Action> {
+        let expect = match self.next_action() {
+            Some(expect) => expect,
+            None => return vec![],
+        };
+        let state = self.player_state(expect.seat);
+        let top = self.top_card().suit;
+        let calls = |suit| {
+            [false, true]
+                .iter()
+                .map(move |&alone| ActionData::Call { suit, alone })
+        };
+        let data: Vec<ActionData> = match expect.action {
+            ActionType::BidTop => std::iter::once(ActionData::Pass)
+                .chain(calls(top))
+                .collect(),
+            ActionType::BidOther => {
+                let pass = (expect.seat != self.dealer()).then_some(ActionData::Pass);
+                let calls = Suit::all_suits()
+                    .iter()
+                    .filter(|&&suit| suit != top)
+                    .flat_map(|&suit| calls(suit));
+                pass.into_iter().chain(calls).collect()
+            }
+            ActionType::DealerDiscard | ActionType::Lead => state
+                .hand
+                .iter()
+                .map(|&card| ActionData::Card { card })
+                .collect(),
+            // When reneges are deferred, any card may be played, at the risk of being called.
+            ActionType::Follow if self.renege_policy() == RenegePolicy::Defer => state
+                .hand
+                .iter()
+                .map(|&card| ActionData::Card { card })
+                .collect(),
+            ActionType::Follow => {
+                let trick = state.tricks.in_progress().expect("trick must be started");
+                trick
+                    .filter(state.hand)
+                    .into_iter()
+                    .map(|card| ActionData::Card { card })
+                    .collect()
+            }
+            // Reneges are called out of turn, so they are never the expected action. See
+            // `legal_calls`.
+            ActionType::CallRenege => vec![],
+        };
+        data.into_iter().map(|d| expect.with_data(d)).collect()
+    }
+
+    /// Returns the reneges that the specified player may call, out of turn: those revokes by an
+    /// opponent that can be proven from the cards played. The vector is empty if the round is
+    /// over.
+    fn legal_calls(&self, seat: Seat) -> Vec<Action> {
+        if self.next_action().is_none() {
+            return vec![];
+        }
+        self.tricks()
+            .exposed_revokes()
+            .filter(|revoke| revoke.seat.team() != seat.team())
+            .map(|revoke| {
+                let data = ActionData::Card { card: revoke.card };
+                Action::new(seat, ActionType::CallRenege, data)
+            })
+            .collect()
+    }
+
+    /// Checks that the state of the round is internally consistent: that every card dealt is
+    /// accounted for exactly once, among the hands, the tricks, the top card and the dealer's
+    /// discard; that tricks are sized for the contract; and that the next action follows from the
+    /// state of play. A violation indicates a bug in the round implementation.
+    fn validate_invariants(&self) -> Result<(), RoundError> {
+        invariants::validate(self)
+    }
+
     /// The outcome of the round, if it is over.
     fn outcome(&self) -> Option<RoundOutcome> {
         let contract = self.contract()?;
+        if let Some(revoke) = self.tricks().called_renege() {
+            return Some(RoundOutcome::new(revoke.seat.team().other(), 2));
+        }
         let makers = Team::from(contract.maker);
         let defenders = makers.other();
 
@@ -109,36 +202,163 @@ pub trait Round {
     }
 }
 
+impl<R: Round> engine::Engine for R {
+    type Actor = Seat;
+    type Action = Action;
+    type View<'a>
+        = PlayerState<'a>
+    where
+        R: 'a;
+    type Event = Event;
+    type Outcome = RoundOutcome;
+    type Error = RoundError;
+
+    fn expected_actor(&self) -> Option<Seat> {
+        self.next_action().map(|expect| expect.seat)
+    }
+
+    fn view(&self, seat: Seat) -> PlayerState<'_> {
+        self.player_state(seat)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), RoundError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        Round::pop_event(self)
+    }
+
+    fn outcome(&self) -> Option<RoundOutcome> {
+        Round::outcome(self)
+    }
+}
+
+/// The pattern in which the dealer distributes cards.
+///
+/// Cards are dealt in two passes around the table, starting to the dealer's left. Each player
+/// receives a packet of two or three cards on each pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DealPattern {
+    /// Three cards to the first player, then two, three, two; then the reverse.
+    #[default]
+    ThreeTwo,
+    /// Two cards to the first player, then three, two, three; then the reverse.
+    TwoThree,
+}
+
+impl DealPattern {
+    /// Returns the sequence of packets dealt, as pairs of recipient and card count.
+    pub fn packets(self, dealer: Seat) -> Vec<(Seat, usize)> {
+        let first = match self {
+            DealPattern::ThreeTwo => 3,
+            DealPattern::TwoThree => 2,
+        };
+        let seats = dealer.next_n(4);
+        let mut packets = Vec::with_capacity(8);
+        for pass in 0..2 {
+            for (i, &seat) in seats.iter().enumerate() {
+                let count = if (i + pass) % 2 == 0 {
+                    first
+                } else {
+                    5 - first
+                };
+                packets.push((seat, count));
+            }
+        }
+        packets
+    }
+}
+
+/// How a player's failure to follow suit is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum RenegePolicy {
+    /// The card is rejected, and the player must choose another.
+    #[default]
+    Reject,
+    /// The card is played, and the revoke is recorded. Until the round is over, the opposing
+    /// team may call the renege, ending the round with a two-point penalty.
+    Defer,
+}
+
 /// Configuration & initial conditions for a round.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RoundConfig {
     /// The dealer for this round.
     dealer: Seat,
     /// Each player's hand, as dealt.
-    hands: HashMap<Seat, Vec<Card>>,
+    hands: SeatMap<Vec<Card>>,
     /// The upturned card, as dealt.
     top: Card,
+    /// The pattern in which the cards were dealt.
+    #[serde(default)]
+    pattern: DealPattern,
+    /// How failures to follow suit are handled.
+    #[serde(default)]
+    renege: RenegePolicy,
+    /// Whether the round replaces one that was thrown in. This only changes how the deal is
+    /// announced, so it isn't recorded.
+    #[serde(skip)]
+    redeal: bool,
 }
 
 impl Distribution<RoundConfig> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RoundConfig {
-        RoundConfig::new(rng.gen(), rng.gen()).expect("deck is valid")
+        RoundConfig::new(rng.gen(), rng.gen(), DealPattern::default()).expect("deck is valid")
+    }
+}
+
+/// Deals a valid round from a shuffled deck, so that fuzzing exercises play rather than
+/// validation.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RoundConfig {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut cards: Vec<Card> = Deck::default().into_iter().collect();
+        for i in (1..cards.len()).rev() {
+            let j = u.int_in_range(0..=i)?;
+            cards.swap(i, j);
+        }
+        let config = Self::new(u.arbitrary()?, Deck::with_cards(cards), u.arbitrary()?)
+            .expect("deck is valid");
+        Ok(config.with_renege_policy(u.arbitrary()?))
     }
 }
 
 impl RoundConfig {
-    /// Creates a new [`RoundConfig`], with the specified dealer & deck.
-    pub fn new(dealer: Seat, mut deck: Deck) -> Result<Self, RoundError> {
+    /// Creates a new [`RoundConfig`], dealing from the deck in the specified pattern.
+    pub fn new(dealer: Seat, mut deck: Deck, pattern: DealPattern) -> Result<Self, RoundError> {
         if deck.len() < 24 {
             return Err(RoundError::IncompleteDeck);
         }
-        let hands = dealer
-            .next_n(4)
-            .into_iter()
-            .map(|seat| (seat, deck.take(5)))
-            .collect();
+        let mut hands: SeatMap<Vec<Card>> = SeatMap::default();
+        for (seat, count) in pattern.packets(dealer) {
+            hands[seat].extend(deck.take(count));
+        }
         let top = deck.take(1)[0];
-        let mut round = Self { dealer, hands, top };
+        Self::from_hands(dealer, hands, top, pattern)
+    }
+
+    /// Creates a new [`RoundConfig`] from hands that have already been dealt.
+    pub fn from_hands(
+        dealer: Seat,
+        hands: SeatMap<Vec<Card>>,
+        top: Card,
+        pattern: DealPattern,
+    ) -> Result<Self, RoundError> {
+        let mut round = Self {
+            dealer,
+            hands,
+            top,
+            pattern,
+            renege: RenegePolicy::default(),
+            redeal: false,
+        };
         round.validate()?;
         round.canonicalize();
         Ok(round)
@@ -150,9 +370,27 @@ impl RoundConfig {
     }
 
     /// Creates a specified dealer and a shuffled deck.
-    pub fn random_with_dealer(dealer: Seat) -> Self {
+    pub fn random_with_dealer(dealer: Seat, pattern: DealPattern) -> Self {
         let deck = rand::random();
-        Self::new(dealer, deck).expect("deck is valid")
+        Self::new(dealer, deck, pattern).expect("deck is valid")
+    }
+
+    /// Sets the policy for failures to follow suit.
+    pub fn with_renege_policy(mut self, renege: RenegePolicy) -> Self {
+        self.renege = renege;
+        self
+    }
+
+    /// Returns the policy for failures to follow suit.
+    pub fn renege_policy(&self) -> RenegePolicy {
+        self.renege
+    }
+
+    /// Marks the round as replacing one that was thrown in, so that the deal is preceded by an
+    /// [`Event::Redeal`].
+    pub fn with_redeal(mut self) -> Self {
+        self.redeal = true;
+        self
     }
 
     /// Returns the dealer for this round.
@@ -160,15 +398,41 @@ impl RoundConfig {
         self.dealer
     }
 
+    /// Returns the pattern in which the cards were dealt.
+    pub fn pattern(&self) -> DealPattern {
+        self.pattern
+    }
+
+    /// Returns the specified player's hand, as dealt.
+    pub fn hand(&self, seat: Seat) -> &[Card] {
+        &self.hands[seat]
+    }
+
+    /// Returns the upturned card.
+    pub fn top(&self) -> Card {
+        self.top
+    }
+
+    /// Returns a copy of the configuration in which every hand but the seat's is emptied, for
+    /// sharing a deal from one player's point of view. The copy doesn't validate as a round.
+    pub fn redacted(&self, seat: Seat) -> Self {
+        let mut config = self.clone();
+        for &other in Seat::all_seats() {
+            if other != seat {
+                config.hands[other].clear();
+            }
+        }
+        config
+    }
+
     /// Validates and canonicalizes the configuration.
     pub fn validate(&self) -> Result<(), RoundError> {
-        let mut seen: HashSet<_> = HashSet::with_capacity(21);
-        seen.insert(self.top);
+        let mut seen = CardSet::from(self.top);
         for hand in self.hands.values() {
             if hand.len() != 5 {
                 return Err(RoundError::InvalidHandSize);
             }
-            seen.extend(hand);
+            seen = seen | hand.iter().collect();
         }
         if seen.len() == 21 {
             Ok(())
@@ -186,7 +450,7 @@ impl RoundConfig {
 }
 
 /// The contract established by whomever calls suit.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Contract {
     pub maker: Seat,
     pub suit: Suit,
@@ -194,7 +458,7 @@ pub struct Contract {
 }
 
 /// The outcome of a round.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoundOutcome {
     pub team: Team,
     pub points: u8,
@@ -202,7 +466,15 @@ pub struct RoundOutcome {
 
 impl Display for RoundOutcome {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} wins {} points", self.team, self.points)
+        f.write_str(&if self.is_thrown_in() {
+            tr!("outcome-thrown-in")
+        } else {
+            tr!(
+                "outcome-round",
+                team = self.team.to_string(),
+                points = self.points
+            )
+        })
     }
 }
 
@@ -211,6 +483,12 @@ impl RoundOutcome {
     pub fn new(team: Team, points: u8) -> Self {
         RoundOutcome { team, points }
     }
+
+    /// Returns true if the round was thrown in by agreement, without play, so that neither team
+    /// scored.
+    pub fn is_thrown_in(&self) -> bool {
+        self.points == 0
+    }
 }
 
 /// The state visible to a particular seat.
@@ -226,6 +504,9 @@ pub struct PlayerState<'a> {
     pub contract: Option<Contract>,
     /// The player's hand.
     pub hand: &'a Vec<Card>,
+    /// Every card the player has seen this round: their own hand as dealt, the top card, any
+    /// card they discarded, and all cards played into tricks.
+    pub seen: CardSet,
     /// The tricks played so far this round.
     pub tricks: &'a Tricks,
 }
@@ -238,6 +519,7 @@ impl<'a> PlayerState<'a> {
         top: Card,
         contract: Option<Contract>,
         hand: &'a Vec<Card>,
+        seen: CardSet,
         tricks: &'a Tricks,
     ) -> Self {
         Self {
@@ -246,10 +528,16 @@ impl<'a> PlayerState<'a> {
             top,
             contract,
             hand,
+            seen,
             tricks,
         }
     }
 
+    /// Returns the cards that the player has not yet seen this round.
+    pub fn unseen(&self) -> CardSet {
+        !self.seen
+    }
+
     /// Returns the player's hand, in sorted order, based on effective suit and
     /// intrinsic card value.
     pub fn sorted_hand(&self) -> Vec<Card> {
@@ -1,44 +1,49 @@
 //! Core round implementation.
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
 use super::{
-    Action, ActionData, ActionType, Card, Contract, Event, ExpectAction, PlayerError, PlayerState,
-    Round, RoundConfig, RoundError, Seat, Suit, Trick, Tricks,
+    Action, ActionData, ActionType, Card, CardSet, Contract, Event, ExpectAction, PlayerError,
+    PlayerState, RenegePolicy, Round, RoundConfig, RoundError, Seat, SeatMap, Suit, Trick, Tricks,
 };
 
 /// The core implementation for [`Round`], around which other implementations are built.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BaseRound {
     /// The dealer for this round.
     dealer: Seat,
     /// The upturned card.
     top: Card,
     /// The content of each player's hand.
-    hands: HashMap<Seat, Vec<Card>>,
+    hands: SeatMap<Vec<Card>>,
+    /// The cards that each player has seen so far.
+    seen: SeatMap<CardSet>,
     /// The established contract, once bidding is over.
     contract: Option<Contract>,
     /// Tricks played during this round.
     tricks: Tricks,
+    /// How failures to follow suit are handled.
+    renege: RenegePolicy,
     /// A queue of unacknowledged events.
     events: VecDeque<Event>,
+    /// Whether events are recorded in the queue.
+    record_events: bool,
     /// The next action required to advance the round.
     next_action: Option<ExpectAction>,
 }
 
 impl From<RoundConfig> for BaseRound {
     fn from(config: RoundConfig) -> Self {
-        let dealer = config.dealer;
-        let top = config.top;
-        BaseRound {
-            dealer,
-            top,
-            hands: config.hands,
-            contract: None,
-            tricks: Tricks::default(),
-            events: [Event::Deal(dealer, top)].into(),
-            next_action: Some(ExpectAction::new(dealer.next(), ActionType::BidTop)),
+        let packets = config.pattern.packets(config.dealer);
+        let redeal = config.redeal.then_some(Event::Redeal(config.dealer));
+        let mut round = Self::without_events(config);
+        round.record_events = true;
+        round.events.extend(redeal);
+        for (seat, count) in packets {
+            round.events.push_back(Event::Packet(seat, count));
         }
+        round.events.push_back(Event::Deal(round.dealer, round.top));
+        round
     }
 }
 
@@ -67,13 +72,18 @@ impl Round for BaseRound {
         &self.tricks
     }
 
+    fn renege_policy(&self) -> RenegePolicy {
+        self.renege
+    }
+
     fn player_state(&self, seat: Seat) -> PlayerState<'_> {
         PlayerState::new(
             seat,
             self.dealer,
             self.top,
             self.contract,
-            self.hands.get(&seat).expect("seats populated"),
+            &self.hands[seat],
+            self.seen[seat],
             &self.tricks,
         )
     }
@@ -81,6 +91,7 @@ impl Round for BaseRound {
     fn apply_action(&mut self, action: Action) -> Result<(), RoundError> {
         match (self.next_action, action) {
             (None, _) => Err(RoundError::RoundOver),
+            (_, a) if a.action == ActionType::CallRenege => self.apply(a),
             (Some(ExpectAction { seat, action }), a) if seat != a.seat || action != a.action => {
                 Err(RoundError::ExpectActioned { seat, action })
             }
@@ -99,6 +110,29 @@ fn filter_seat(contract: Contract, seat: Seat) -> Seat {
 }
 
 impl BaseRound {
+    /// Creates a new round which does not record events.
+    pub fn without_events(config: RoundConfig) -> Self {
+        let dealer = config.dealer;
+        let top = config.top;
+        let seen = SeatMap::from_fn(|seat| {
+            let mut seen: CardSet = config.hands[seat].iter().collect();
+            seen.insert(top);
+            seen
+        });
+        BaseRound {
+            dealer,
+            top,
+            hands: config.hands,
+            seen,
+            contract: None,
+            tricks: Tricks::default(),
+            renege: config.renege,
+            events: VecDeque::new(),
+            record_events: false,
+            next_action: Some(ExpectAction::new(dealer.next(), ActionType::BidTop)),
+        }
+    }
+
     /// Applies the specified action to advance the state machine.
     fn apply(&mut self, Action { seat, action, data }: Action) -> Result<(), RoundError> {
         match (action, data) {
@@ -115,6 +149,7 @@ impl BaseRound {
             }
             (ActionType::Lead, ActionData::Card { card }) => self.lead(seat, card)?,
             (ActionType::Follow, ActionData::Card { card }) => self.follow(seat, card)?,
+            (ActionType::CallRenege, ActionData::Card { card }) => self.call_renege(seat, card)?,
             _ => return Err(RoundError::InvalidActionData),
         }
         Ok(())
@@ -134,10 +169,7 @@ impl BaseRound {
         if suit == self.top.suit {
             let contract = Contract { maker, suit, alone };
             self.contract = Some(contract);
-            self.hands
-                .get_mut(&self.dealer)
-                .expect("hands populated")
-                .push(self.top);
+            self.hands[self.dealer].push(self.top);
             // If some player other than the dealer bids top alone, the top card is simply buried
             // with the rest of the dealer's hand - no need to discard.
             if alone && maker != self.dealer {
@@ -145,7 +177,7 @@ impl BaseRound {
             } else {
                 self.next_action = Some(ExpectAction::new(self.dealer, ActionType::DealerDiscard));
             }
-            self.events.push_back(Event::Call(contract));
+            self.emit(Event::Call(contract));
             Ok(())
         } else {
             Err(PlayerError::MustCallTopSuit(self.top.suit))
@@ -170,7 +202,7 @@ impl BaseRound {
             let contract = Contract { maker, suit, alone };
             self.contract = Some(contract);
             self.first_trick();
-            self.events.push_back(Event::Call(contract));
+            self.emit(Event::Call(contract));
             Ok(())
         }
     }
@@ -189,6 +221,9 @@ impl BaseRound {
         self.find_and_discard(seat, card)?;
         let trick = Trick::new(contract.suit, seat, card);
         self.tricks.push(trick);
+        for seen in self.seen.values_mut() {
+            seen.insert(card);
+        }
         self.next_action = Some(ExpectAction::new(
             filter_seat(contract, seat.next()),
             ActionType::Follow,
@@ -205,13 +240,17 @@ impl BaseRound {
         let trick = self.tricks.last_mut().expect("trick must be started");
         assert!(trick.len() < trick_size);
 
-        let hand = self.hands.get_mut(&seat).expect("hand exists");
-        if !trick.is_following_lead(hand, card) {
+        let hand = &mut self.hands[seat];
+        let revoke = !trick.is_following_lead(hand, card);
+        if revoke && self.renege == RenegePolicy::Reject {
             return Err(PlayerError::MustFollowLead(seat, trick.lead().1));
         }
 
         trick.play(seat, card);
         hand.remove(index);
+        for seen in self.seen.values_mut() {
+            seen.insert(card);
+        }
 
         if trick.len() < trick_size {
             self.next_action = Some(ExpectAction::new(
@@ -220,18 +259,46 @@ impl BaseRound {
             ));
         } else {
             let winner = trick.best().0;
-            self.events.push_back(Event::Trick(trick.clone()));
+            if self.record_events {
+                self.events.push_back(Event::Trick(*trick));
+            }
             if let Some(outcome) = self.outcome() {
-                self.events.push_back(Event::Round(outcome));
+                self.emit(Event::Round(outcome));
                 self.next_action = None;
             } else {
                 self.next_trick(winner);
             }
         }
 
+        if revoke {
+            self.tricks.record_revoke(seat, card);
+        }
+        Ok(())
+    }
+
+    /// Handles a renege called by the specified player, on a card played by an opponent. The
+    /// round ends immediately, with the penalty awarded to the caller's team.
+    fn call_renege(&mut self, seat: Seat, card: Card) -> Result<(), PlayerError> {
+        let revoke = self
+            .tricks
+            .revoke(card)
+            .filter(|r| r.seat.team() != seat.team())
+            .ok_or(PlayerError::NoRenege(card))?;
+        self.tricks.call_renege(revoke);
+        self.next_action = None;
+        self.emit(Event::Renege(revoke));
+        let outcome = self.outcome().expect("round is over");
+        self.emit(Event::Round(outcome));
         Ok(())
     }
 
+    /// Pushes an event onto the queue, if events are being recorded.
+    fn emit(&mut self, event: Event) {
+        if self.record_events {
+            self.events.push_back(event);
+        }
+    }
+
     /// Finds a card among the specified player's hand and discards it.
     fn find_and_discard(&mut self, seat: Seat, card: Card) -> Result<(), PlayerError> {
         self.find_card(seat, card)
@@ -240,9 +307,7 @@ impl BaseRound {
 
     /// Finds a card among the specified player's hand.
     fn find_card(&mut self, seat: Seat, card: Card) -> Result<usize, PlayerError> {
-        self.hands
-            .get(&seat)
-            .expect("hand exists")
+        self.hands[seat]
             .iter()
             .position(|c| *c == card)
             .ok_or(PlayerError::CardNotHeld(seat, card))
@@ -250,8 +315,7 @@ impl BaseRound {
 
     /// Discards the specified card from the player's hand.
     fn discard(&mut self, seat: Seat, index: usize) {
-        let hand = self.hands.get_mut(&seat).expect("hand exists");
-        hand.remove(index);
+        self.hands[seat].remove(index);
     }
 
     /// Sets up the state machine for the first trick, choosing the eldest hand to lead.
@@ -0,0 +1,54 @@
+//! A round for simulations, without events or logging.
+
+use delegate::delegate;
+
+use crate::euchre::{
+    Action, BaseRound, Card, Contract, Event, ExpectAction, Player, PlayerState, RenegePolicy,
+    Round, RoundConfig, RoundError, RoundOutcome, Seat, Tricks,
+};
+
+/// A [`Round`] implementation that skips the event queue, for use in simulations where only the
+/// outcome matters.
+#[derive(Debug, Clone)]
+pub struct FastRound(BaseRound);
+
+impl From<RoundConfig> for FastRound {
+    fn from(config: RoundConfig) -> Self {
+        Self(BaseRound::without_events(config))
+    }
+}
+
+impl Round for FastRound {
+    delegate! {
+        to self.0 {
+            fn dealer(&self) -> Seat;
+            fn top_card(&self) -> Card;
+            fn next_action(&self) -> Option<ExpectAction>;
+            fn contract(&self) -> Option<Contract>;
+            fn tricks(&self) -> &Tricks;
+            fn renege_policy(&self) -> RenegePolicy;
+            fn player_state(&self, seat: Seat) -> PlayerState<'_>;
+            fn apply_action(&mut self, action: Action) -> Result<(), RoundError>;
+        }
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        None
+    }
+}
+
+impl FastRound {
+    /// Creates a new random [`FastRound`].
+    pub fn random() -> Self {
+        rand::random::<RoundConfig>().into()
+    }
+
+    /// Plays the round to completion, with the specified player taking every action.
+    pub fn play_out<P: Player + ?Sized>(&mut self, player: &P) -> Result<RoundOutcome, RoundError> {
+        while let Some(expect) = self.next_action() {
+            let data = player.take_action(self.player_state(expect.seat), expect.action);
+            self.apply_action(expect.with_data(data))?;
+        }
+        Ok(self.outcome().expect("round is over"))
+    }
+}
@@ -0,0 +1,151 @@
+//! Consistency checks for the state of a round.
+
+use super::{ActionType, Card, CardSet, Round, RoundError, Seat};
+
+/// Returns an error describing the first violated invariant.
+fn violated(message: String) -> Result<(), RoundError> {
+    Err(RoundError::Invariant(message))
+}
+
+/// The cards accounted for so far.
+#[derive(Default)]
+struct Ledger {
+    cards: CardSet,
+    count: usize,
+}
+
+impl Ledger {
+    /// Accounts for a card, which must not have been accounted for already.
+    fn add(&mut self, card: Card, place: &str) -> Result<(), RoundError> {
+        self.count += 1;
+        if !self.cards.insert(card) {
+            return violated(format!("{card} is duplicated in {place}"));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the state of the round is internally consistent.
+pub(super) fn validate<R: Round + ?Sized>(round: &R) -> Result<(), RoundError> {
+    let dealer = round.dealer();
+    let top = round.top_card();
+    let contract = round.contract();
+    let tricks = round.tricks();
+    let next = round.next_action();
+    let sitting_out = contract.and_then(|c| c.alone.then(|| c.maker.opposite()));
+
+    // The dealer picks up the top card once it's ordered up, and discards unless an opponent is
+    // going alone.
+    let picked_up = contract.is_some_and(|c| c.suit == top.suit);
+    let discarding = next.is_some_and(|e| e.action == ActionType::DealerDiscard);
+    let discarded =
+        picked_up && !discarding && contract.is_some_and(|c| !c.alone || c.maker == dealer);
+
+    // Every card must be accounted for exactly once.
+    let mut ledger = Ledger::default();
+    for &seat in Seat::all_seats() {
+        let state = round.player_state(seat);
+        for &card in state.hand {
+            ledger.add(card, &format!("{seat}'s hand"))?;
+        }
+        let played = tricks.cards_played_by(seat).count();
+        let mut expect = 5;
+        if seat == dealer && picked_up && !discarded {
+            expect += 1;
+        }
+        if state.hand.len() + played != expect {
+            return violated(format!(
+                "{seat} holds {} cards and played {played}, expected {expect} in all",
+                state.hand.len()
+            ));
+        }
+    }
+    for trick in tricks {
+        for &(_, card) in trick.cards() {
+            ledger.add(card, "the tricks")?;
+        }
+    }
+    if !picked_up {
+        ledger.add(top, "the kitty")?;
+    }
+
+    // The dealer has seen their discard, but it's nowhere else.
+    let dealer_state = round.player_state(dealer);
+    let unaccounted = dealer_state.seen - ledger.cards;
+    let expect_discards = usize::from(discarded);
+    if unaccounted.len() != expect_discards {
+        return violated(format!(
+            "the dealer has seen {} unaccounted cards, expected {expect_discards}",
+            unaccounted.len()
+        ));
+    }
+    for card in unaccounted {
+        ledger.add(card, "the discard")?;
+    }
+    if ledger.count != 21 {
+        return violated(format!(
+            "{} cards are accounted for, expected 21",
+            ledger.count
+        ));
+    }
+
+    // Tricks are sized for the contract, once the dealer has discarded, and only the last may be
+    // incomplete.
+    let trick_size = if sitting_out.is_some() { 3 } else { 4 };
+    if contract.is_some() && !discarding && tricks.trick_size() != trick_size {
+        return violated(format!(
+            "tricks have {} cards, expected {trick_size}",
+            tricks.trick_size()
+        ));
+    }
+    if contract.is_none() && !tricks.is_empty() {
+        return violated("tricks were played before a contract".into());
+    }
+    if tricks.len() > 5 || tricks.completed().count() + 1 < tricks.len() {
+        return violated(format!("{} tricks are malformed", tricks.len()));
+    }
+    if let Some(seat) = sitting_out {
+        if tricks.cards_played_by(seat).next().is_some() {
+            return violated(format!("{seat} played while sitting out"));
+        }
+    }
+
+    // The next action follows from the state of the round.
+    let Some(expect) = next else {
+        if round.outcome().is_none() {
+            return violated("no action is expected, but the round isn't over".into());
+        }
+        return Ok(());
+    };
+    if round.outcome().is_some() {
+        return violated(format!("the round is over, but {expect:?} is expected"));
+    }
+    let after = |seat: Seat| {
+        let next = seat.next();
+        if Some(next) == sitting_out {
+            next.next()
+        } else {
+            next
+        }
+    };
+    let expected_seat = match (expect.action, contract) {
+        (ActionType::BidTop | ActionType::BidOther, None) => expect.seat,
+        (ActionType::DealerDiscard, Some(_)) if picked_up && tricks.is_empty() => dealer,
+        (ActionType::Lead, Some(_)) if tricks.in_progress().is_none() => match tricks.last() {
+            Some(trick) => trick.best().0,
+            None => after(dealer),
+        },
+        (ActionType::Follow, Some(_)) => match tricks.in_progress() {
+            Some(trick) => after(trick.cards().last().expect("trick is started").0),
+            None => return violated("a follow is expected, but no trick is in progress".into()),
+        },
+        _ => return violated(format!("{expect:?} is inconsistent with the round")),
+    };
+    if expect.seat != expected_seat {
+        return violated(format!(
+            "{} is expected to {}, but it's {expected_seat}'s turn",
+            expect.seat, expect.action
+        ));
+    }
+    Ok(())
+}
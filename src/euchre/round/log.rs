@@ -1,12 +1,20 @@
 //! Tree-structured log of actions for a round.
+//!
+//! Nodes are kept in an arena, indexed by ID, and each node lists its children. The arena is split
+//! into fixed-size chunks behind reference counts, so cloning a log to take a snapshot only copies
+//! the chunk pointers, and a chunk is copied only when one of the clones writes to it.
 
-use std::{collections::HashMap, fs::File, io::Read, path::Path};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt::Write, fs::File, io::Read, path::Path};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use super::RoundConfig;
-use crate::euchre::{Action, RoundError};
+use crate::euchre::{Action, ActionData, ActionType, RoundError};
 
 #[cfg(test)]
 mod test;
@@ -22,14 +30,64 @@ pub struct ActionNode {
     pub parent: Option<Id>,
     /// The action that this node represents.
     pub action: Action,
+    /// The robot's reasoning for the action, if it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Trace>,
+    /// When the action was taken, and how long the player thought about it, if it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+    /// The points the makers expect to win from the position after the action, if it was
+    /// estimated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eval: Option<f64>,
 }
 impl ActionNode {
     /// Creates a new [`ActionNode`].
     fn new(id: Id, parent: Option<Id>, action: Action) -> Self {
-        Self { id, parent, action }
+        Self {
+            id,
+            parent,
+            action,
+            trace: None,
+            timing: None,
+            eval: None,
+        }
     }
 }
 
+/// When an action was taken, and how long the player took to choose it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    /// When the action was taken, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// How long the player thought before taking the action, in milliseconds.
+    pub think_ms: u64,
+}
+impl Timing {
+    /// Records an action taken just now, after thinking for the specified time.
+    pub fn now(think_time: Duration) -> Self {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            timestamp: u64::try_from(since_epoch.as_millis()).unwrap_or(u64::MAX),
+            think_ms: u64::try_from(think_time.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Returns how long the player thought before taking the action.
+    pub fn think_time(&self) -> Duration {
+        Duration::from_millis(self.think_ms)
+    }
+}
+
+/// A robot's evaluation of the actions it considered, before choosing one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trace {
+    /// Each action considered, with its score. Higher scores are better.
+    pub scores: Vec<(ActionData, i32)>,
+}
+
 /// A serializable version of the log.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RawLog {
@@ -41,35 +99,40 @@ pub struct RawLog {
 }
 impl From<Log> for RawLog {
     fn from(log: Log) -> Self {
-        RawLog {
-            config: log.config,
-            actions: log
-                .actions
-                .into_values()
-                .sorted_unstable_by_key(|a| a.id)
-                .collect(),
-        }
+        RawLog::from(&log)
     }
 }
 impl<'a> From<&'a Log> for RawLog {
     fn from(log: &'a Log) -> Self {
         RawLog {
             config: log.config.clone(),
-            actions: log
-                .actions
-                .values()
-                .sorted_unstable_by_key(|a| a.id)
-                .cloned()
-                .collect(),
+            actions: log.action_nodes().cloned().collect(),
+        }
+    }
+}
+/// Builds a well-formed tree, in which every node's parent precedes it, filled with arbitrary
+/// actions.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RawLog {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let config = u.arbitrary()?;
+        let mut actions = vec![];
+        let len = u.arbitrary_len::<(bool, Id, Action)>()?;
+        for (id, _) in (0..Id::MAX).zip(0..len) {
+            let parent = match id.checked_sub(1) {
+                Some(last) if u.arbitrary()? => Some(u.int_in_range(0..=last)?),
+                _ => None,
+            };
+            actions.push(ActionNode::new(id, parent, u.arbitrary()?));
         }
+        Ok(Self { config, actions })
     }
 }
+
 impl RawLog {
     pub fn from_json_reader<R: Read>(r: R) -> anyhow::Result<Self> {
-        let mut log: RawLog = serde_json::from_reader(r)?;
-        log.config.validate()?;
-        log.config.canonicalize();
-        Ok(log)
+        let log: RawLog = serde_json::from_reader(r)?;
+        Ok(log.validated()?)
     }
 
     pub fn from_json_file(path: &Path) -> anyhow::Result<Self> {
@@ -77,42 +140,66 @@ impl RawLog {
         RawLog::from_json_reader(file)
     }
 
+    /// Validates and canonicalizes the initial configuration.
+    pub fn validated(mut self) -> Result<Self, RoundError> {
+        self.config.validate()?;
+        self.config.canonicalize();
+        Ok(self)
+    }
+
     pub fn into_log(self) -> Log {
         self.into()
     }
 }
 
+/// The number of nodes in each chunk of the arena.
+const CHUNK_SIZE: usize = 256;
+
+/// A node in the arena, with its children.
+#[derive(Debug, Clone)]
+struct Slot {
+    node: ActionNode,
+    /// The children of this node, in the order they were inserted.
+    children: Vec<Id>,
+}
+
 /// A tree-structured log of actions taken in a round.
+///
+/// Clones share their nodes until they are modified, so a clone is a cheap snapshot.
 #[derive(Debug, Clone)]
 pub struct Log {
     /// The initial configuration for the round.
     config: RoundConfig,
-    /// A map of actions, indexed by node ID.
-    actions: HashMap<Id, ActionNode>,
-    /// A map of children, indexed by an `Option<Id>`. The initial state, immediately after the deal, is
-    /// represented by `None`. All other actions are represented by `Some(id)`.
-    children: HashMap<Option<Id>, Vec<Id>>,
-    /// The next ID to use when adding a new action to the log.
-    next_id: Id,
+    /// The nodes, indexed by ID, in chunks of [`CHUNK_SIZE`]. Every chunk but the last is full.
+    chunks: Vec<Arc<Vec<Slot>>>,
+    /// The children of the initial state, immediately after the deal.
+    roots: Vec<Id>,
 }
 impl From<RawLog> for Log {
+    /// Builds a log from its serialized form. Nodes are renumbered in order of their original IDs,
+    /// so a log that was written by [`RawLog::from`] keeps its IDs. A node whose parent isn't
+    /// present with a lower ID can't be reached from the deal, and is dropped, along with its
+    /// descendants.
     fn from(raw: RawLog) -> Self {
-        let mut max_id = 0;
-        let mut children: HashMap<_, Vec<_>> = HashMap::new();
-        let mut actions = HashMap::new();
-        for action in raw.actions {
-            if action.id > max_id {
-                max_id = action.id;
-            }
-            children.entry(action.parent).or_default().push(action.id);
-            actions.insert(action.id, action);
-        }
-        Log {
-            config: raw.config,
-            actions,
-            children,
-            next_id: max_id + 1,
+        let mut log = Log::new(raw.config);
+        let mut ids = HashMap::new();
+        let actions = raw.actions.into_iter().rev().unique_by(|a| a.id);
+        for node in actions.sorted_unstable_by_key(|a| a.id) {
+            let parent = match node.parent {
+                None => None,
+                Some(parent) => match ids.get(&parent) {
+                    Some(&parent) => Some(parent),
+                    None => continue,
+                },
+            };
+            let id = log.push(parent, node.action);
+            let slot = log.slot_mut(id).expect("just pushed");
+            slot.node.trace = node.trace;
+            slot.node.timing = node.timing;
+            slot.node.eval = node.eval;
+            ids.insert(node.id, id);
         }
+        log
     }
 }
 
@@ -121,10 +208,50 @@ impl Log {
     pub fn new(config: RoundConfig) -> Self {
         Self {
             config,
-            actions: HashMap::default(),
-            children: HashMap::default(),
-            next_id: 0,
+            chunks: vec![],
+            roots: vec![],
+        }
+    }
+
+    /// The number of nodes in the log.
+    fn len(&self) -> usize {
+        self.chunks
+            .last()
+            .map_or(0, |last| (self.chunks.len() - 1) * CHUNK_SIZE + last.len())
+    }
+
+    /// Returns the slot for the specified node.
+    fn slot(&self, id: Id) -> Option<&Slot> {
+        let index = usize::try_from(id).ok()?;
+        self.chunks.get(index / CHUNK_SIZE)?.get(index % CHUNK_SIZE)
+    }
+
+    /// Returns the slot for the specified node, copying its chunk first if it's shared.
+    fn slot_mut(&mut self, id: Id) -> Option<&mut Slot> {
+        let index = usize::try_from(id).ok().filter(|&i| i < self.len())?;
+        Arc::make_mut(&mut self.chunks[index / CHUNK_SIZE]).get_mut(index % CHUNK_SIZE)
+    }
+
+    /// Appends a new node to the arena, as the last child of its parent, which must be present.
+    fn push(&mut self, parent: Option<Id>, action: Action) -> Id {
+        let id = Id::try_from(self.len()).expect("log is too large");
+        match parent {
+            None => self.roots.push(id),
+            Some(parent) => self
+                .slot_mut(parent)
+                .expect("parent must be in the log")
+                .children
+                .push(id),
+        }
+        if self.chunks.last().is_none_or(|c| c.len() == CHUNK_SIZE) {
+            self.chunks.push(Arc::new(Vec::with_capacity(CHUNK_SIZE)));
         }
+        let chunk = self.chunks.last_mut().expect("non-empty");
+        Arc::make_mut(chunk).push(Slot {
+            node: ActionNode::new(id, parent, action),
+            children: vec![],
+        });
+        id
     }
 
     /// Returns an immutable reference to the initial configuration.
@@ -134,25 +261,50 @@ impl Log {
 
     /// Finds a child of the specified node with a matching action.
     fn find_child(&self, parent: Option<Id>, action: Action) -> Option<Id> {
-        self.children
-            .get(&parent)
-            .and_then(|ids| ids.iter().find(|id| self.actions[id].action == action))
+        self.children(parent)
+            .iter()
+            .find(|&&id| self.node(id).is_some_and(|n| n.action == action))
             .copied()
     }
 
     /// Inserts an action into the log. If the same action is present under the same parent, this
     /// function is a no-op.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent isn't in the log.
     pub fn insert(&mut self, parent: Option<Id>, action: Action) -> Id {
-        let id = self.find_child(parent, action).unwrap_or_else(|| {
-            let id = self.next_id;
-            self.next_id += 1;
-            let node = ActionNode::new(id, parent, action);
-            let prev = self.actions.insert(node.id, node);
-            assert!(prev.is_none());
-            self.children.entry(parent).or_default().push(id);
-            id
-        });
-        id
+        self.find_child(parent, action)
+            .unwrap_or_else(|| self.push(parent, action))
+    }
+
+    /// Returns the node with the specified ID.
+    pub fn node(&self, id: Id) -> Option<&ActionNode> {
+        self.slot(id).map(|slot| &slot.node)
+    }
+
+    /// Attaches a robot's reasoning to the specified node, replacing any that was recorded
+    /// before.
+    pub fn set_trace(&mut self, id: Id, trace: Trace) -> Result<(), RoundError> {
+        let slot = self.slot_mut(id).ok_or(RoundError::InvalidLogId(id))?;
+        slot.node.trace = Some(trace);
+        Ok(())
+    }
+
+    /// Records when the action at the specified node was taken, and how long the player thought
+    /// about it, replacing any timing that was recorded before.
+    pub fn set_timing(&mut self, id: Id, timing: Timing) -> Result<(), RoundError> {
+        let slot = self.slot_mut(id).ok_or(RoundError::InvalidLogId(id))?;
+        slot.node.timing = Some(timing);
+        Ok(())
+    }
+
+    /// Records the points the makers expect to win from the position after the action at the
+    /// specified node, replacing any estimate that was recorded before.
+    pub fn set_eval(&mut self, id: Id, eval: f64) -> Result<(), RoundError> {
+        let slot = self.slot_mut(id).ok_or(RoundError::InvalidLogId(id))?;
+        slot.node.eval = Some(eval);
+        Ok(())
     }
 
     /// Returns a backtrace of actions from the specified ID, back to the very first action.
@@ -160,15 +312,82 @@ impl Log {
         let mut parent = Some(id);
         let mut trace = vec![];
         while let Some(id) = parent {
-            let action = self.actions.get(&id).ok_or(RoundError::InvalidLogId(id))?;
+            let action = self.node(id).ok_or(RoundError::InvalidLogId(id))?;
             trace.insert(0, (action.id, action.action));
             parent = action.parent;
         }
         Ok(trace)
     }
 
-    /// Returns an iterator over the nodes in the log.
+    /// Returns the children of the specified node, in the order they were inserted.
+    pub fn children(&self, parent: Option<Id>) -> &[Id] {
+        match parent {
+            None => &self.roots,
+            Some(id) => self.slot(id).map_or(&[], |slot| &slot.children),
+        }
+    }
+
+    /// Follows the first recorded continuation from the specified node, until it reaches a node
+    /// with no children.
+    pub fn first_leaf(&self, mut id: Option<Id>) -> Option<Id> {
+        while let Some(&child) = self.children(id).first() {
+            id = Some(child);
+        }
+        id
+    }
+
+    /// Returns an iterator over the nodes in the log, in order of ID.
     pub fn action_nodes(&self) -> impl Iterator<Item = &ActionNode> {
-        self.actions.values()
+        self.chunks
+            .iter()
+            .flat_map(|c| c.iter())
+            .map(|slot| &slot.node)
+    }
+
+    /// Renders the tree in Graphviz DOT format. Each node is labeled with its action, and the
+    /// main line, which follows the first recorded continuation from the deal, is drawn in bold.
+    pub fn to_dot(&self) -> String {
+        let mut main_line = vec![];
+        let mut id = None;
+        while let Some(&child) = self.children(id).first() {
+            main_line.push(child);
+            id = Some(child);
+        }
+        let mut dot = String::from("digraph log {\n    node [shape=box];\n");
+        let dealer = self.config.dealer();
+        writeln!(dot, "    deal [label=\"{dealer} dealt\", style=bold];").unwrap();
+        for node in self.action_nodes() {
+            let id = node.id;
+            let label = dot_label(node.action);
+            let parent = node.parent.map_or("deal".into(), |p| format!("n{p}"));
+            if main_line.contains(&id) {
+                writeln!(dot, "    n{id} [label=\"{label}\", style=bold];").unwrap();
+                writeln!(dot, "    {parent} -> n{id} [style=bold];").unwrap();
+            } else {
+                writeln!(dot, "    n{id} [label=\"{label}\"];").unwrap();
+                writeln!(dot, "    {parent} -> n{id};").unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Describes an action, for a node label.
+fn dot_label(action: Action) -> String {
+    let seat = action.seat;
+    match (action.action, action.data) {
+        (_, ActionData::Pass) => format!("{seat} passed"),
+        (_, ActionData::Call { suit, alone }) => {
+            format!("{seat} called {suit}{}", if alone { " alone" } else { "" })
+        }
+        (ActionType::DealerDiscard, ActionData::Card { card }) => {
+            format!("{seat} discarded {card}")
+        }
+        (ActionType::Lead, ActionData::Card { card }) => format!("{seat} led {card}"),
+        (ActionType::CallRenege, ActionData::Card { card }) => {
+            format!("{seat} called a renege on {card}")
+        }
+        (_, ActionData::Card { card }) => format!("{seat} followed {card}"),
     }
 }
@@ -1,8 +1,8 @@
+use std::iter::FromIterator;
 use std::str::FromStr;
+use std::time::Duration;
 
-use maplit::hashmap;
-
-use crate::euchre::{ActionData, ActionType, Card, Seat, Suit};
+use crate::euchre::{ActionData, ActionType, Card, DealPattern, RenegePolicy, Seat, SeatMap, Suit};
 
 use super::*;
 
@@ -17,13 +17,16 @@ fn hand(cards: [&str; 5]) -> Vec<Card> {
 fn config_fixture() -> RoundConfig {
     RoundConfig {
         dealer: Seat::North,
-        hands: hashmap! {
-            Seat::North => hand(["ad", "qs", "jh", "th", "9h"]),
-            Seat::East => hand(["jc", "kd", "ks", "kh", "qh"]),
-            Seat::South => hand(["ac", "kc", "qc", "qd", "td"]),
-            Seat::West => hand(["tc", "js", "ts", "9s", "ah"]),
-        },
+        hands: SeatMap::from_iter([
+            (Seat::North, hand(["ad", "qs", "jh", "th", "9h"])),
+            (Seat::East, hand(["jc", "kd", "ks", "kh", "qh"])),
+            (Seat::South, hand(["ac", "kc", "qc", "qd", "td"])),
+            (Seat::West, hand(["tc", "js", "ts", "9s", "ah"])),
+        ]),
         top: Card::from_str("jd").unwrap(),
+        pattern: DealPattern::ThreeTwo,
+        renege: RenegePolicy::Reject,
+        redeal: false,
     }
 }
 
@@ -34,6 +37,9 @@ fn raw_log_fixture() -> RawLog {
             id: 0,
             parent: None,
             action: Action::new(Seat::East, ActionType::BidTop, ActionData::Pass),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 1,
@@ -46,6 +52,9 @@ fn raw_log_fixture() -> RawLog {
                     alone: false,
                 },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 2,
@@ -55,6 +64,9 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::DealerDiscard,
                 ActionData::Card { card: card("qs") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 3,
@@ -64,6 +76,9 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::Lead,
                 ActionData::Card { card: card("jc") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 4,
@@ -73,16 +88,25 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::Follow,
                 ActionData::Card { card: card("ac") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 5,
             parent: Some(0),
             action: Action::new(Seat::South, ActionType::BidTop, ActionData::Pass),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 6,
             parent: Some(5),
             action: Action::new(Seat::West, ActionType::BidTop, ActionData::Pass),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 7,
@@ -95,6 +119,9 @@ fn raw_log_fixture() -> RawLog {
                     alone: false,
                 },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 8,
@@ -104,6 +131,9 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::DealerDiscard,
                 ActionData::Card { card: card("qs") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 9,
@@ -113,6 +143,9 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::Lead,
                 ActionData::Card { card: card("jc") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 10,
@@ -122,11 +155,17 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::Follow,
                 ActionData::Card { card: card("ac") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 11,
             parent: Some(6),
             action: Action::new(Seat::North, ActionType::BidTop, ActionData::Pass),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 12,
@@ -139,6 +178,9 @@ fn raw_log_fixture() -> RawLog {
                     alone: false,
                 },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 13,
@@ -148,6 +190,9 @@ fn raw_log_fixture() -> RawLog {
                 ActionType::Lead,
                 ActionData::Card { card: card("jc") },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
         ActionNode {
             id: 14,
@@ -160,6 +205,9 @@ fn raw_log_fixture() -> RawLog {
                     alone: false,
                 },
             ),
+            trace: None,
+            timing: None,
+            eval: None,
         },
     ];
     RawLog { config, actions }
@@ -297,3 +345,159 @@ fn test_log_serde() {
     let de: RawLog = serde_json::from_str(&ser).unwrap();
     assert_eq!(raw, de);
 }
+
+#[test]
+fn test_log_trace() {
+    let mut log = log_fixture();
+    assert_eq!(log.node(0).unwrap().trace, None);
+    let ser = serde_json::to_string(&RawLog::from(&log)).unwrap();
+    assert!(!ser.contains("trace"));
+
+    let trace = Trace {
+        scores: vec![
+            (ActionData::Pass, 8),
+            (
+                ActionData::Call {
+                    suit: Suit::Diamond,
+                    alone: false,
+                },
+                5,
+            ),
+        ],
+    };
+    log.set_trace(0, trace.clone()).unwrap();
+    assert_eq!(log.node(0).unwrap().trace.as_ref(), Some(&trace));
+    assert!(matches!(
+        log.set_trace(99, trace.clone()),
+        Err(RoundError::InvalidLogId(99))
+    ));
+
+    let raw = RawLog::from(&log);
+    let ser = serde_json::to_string(&raw).unwrap();
+    let de: RawLog = serde_json::from_str(&ser).unwrap();
+    assert_eq!(raw, de);
+    assert_eq!(de.into_log().node(0).unwrap().trace, Some(trace));
+}
+
+#[test]
+fn test_log_timing() {
+    let mut log = log_fixture();
+    let ser = serde_json::to_string(&RawLog::from(&log)).unwrap();
+    assert!(!ser.contains("timing"));
+
+    let timing = Timing::now(Duration::from_millis(2500));
+    assert_eq!(timing.think_time(), Duration::from_millis(2500));
+    assert!(timing.timestamp > 0);
+    log.set_timing(1, timing).unwrap();
+    assert_eq!(log.node(1).unwrap().timing, Some(timing));
+    assert_eq!(log.node(0).unwrap().timing, None);
+    assert!(matches!(
+        log.set_timing(99, timing),
+        Err(RoundError::InvalidLogId(99))
+    ));
+
+    let ser = serde_json::to_string(&RawLog::from(&log)).unwrap();
+    let de: RawLog = serde_json::from_str(&ser).unwrap();
+    assert_eq!(de.into_log().node(1).unwrap().timing, Some(timing));
+}
+
+#[test]
+fn test_log_eval() {
+    let mut log = log_fixture();
+    let ser = serde_json::to_string(&RawLog::from(&log)).unwrap();
+    assert!(!ser.contains("eval"));
+
+    log.set_eval(1, -1.5).unwrap();
+    assert_eq!(log.node(1).unwrap().eval, Some(-1.5));
+    assert_eq!(log.node(0).unwrap().eval, None);
+    assert!(matches!(
+        log.set_eval(99, 0.0),
+        Err(RoundError::InvalidLogId(99))
+    ));
+
+    let ser = serde_json::to_string(&RawLog::from(&log)).unwrap();
+    let de: RawLog = serde_json::from_str(&ser).unwrap();
+    assert_eq!(de.into_log().node(1).unwrap().eval, Some(-1.5));
+}
+
+#[test]
+fn test_log_to_dot() {
+    let mut log = Log::new(config_fixture());
+    let pass = |seat| Action::new(seat, ActionType::BidTop, ActionData::Pass);
+    let east = log.insert(None, pass(Seat::East));
+    log.insert(Some(east), pass(Seat::South));
+    log.insert(
+        Some(east),
+        Action::new(
+            Seat::South,
+            ActionType::BidTop,
+            ActionData::Call {
+                suit: Suit::Diamond,
+                alone: true,
+            },
+        ),
+    );
+    let expect = "\
+digraph log {
+    node [shape=box];
+    deal [label=\"North dealt\", style=bold];
+    n0 [label=\"East passed\", style=bold];
+    deal -> n0 [style=bold];
+    n1 [label=\"South passed\", style=bold];
+    n0 -> n1 [style=bold];
+    n2 [label=\"South called ♦ alone\"];
+    n0 -> n2;
+}
+";
+    assert_eq!(log.to_dot(), expect);
+}
+
+#[test]
+fn test_log_from_raw() {
+    // Round-tripping keeps the IDs.
+    let raw = raw_log_fixture();
+    assert_eq!(RawLog::from(raw.clone().into_log()), raw);
+
+    // Sparse IDs are renumbered in order, and nodes that can't be reached from the deal are
+    // dropped.
+    let mut raw = raw_log_fixture();
+    raw.actions.truncate(3);
+    for node in &mut raw.actions {
+        node.id = node.id * 10 + 5;
+        node.parent = node.parent.map(|p| p * 10 + 5);
+    }
+    let mut orphan = raw.actions[2].clone();
+    orphan.id = 40;
+    orphan.parent = Some(99);
+    raw.actions.insert(0, orphan);
+    let log = raw.into_log();
+    let ids: Vec<_> = log.action_nodes().map(|n| (n.id, n.parent)).collect();
+    assert_eq!(ids, [(0, None), (1, Some(0)), (2, Some(1))]);
+    assert_eq!(log.children(None), [0]);
+    assert_eq!(log.children(Some(1)), [2]);
+}
+
+#[test]
+fn test_log_snapshot() {
+    let pass = |seat| Action::new(seat, ActionType::BidTop, ActionData::Pass);
+    let mut log = Log::new(config_fixture());
+    let mut parent = None;
+    for seat in Seat::all_seats().iter().cycle().take(2 * CHUNK_SIZE + 3) {
+        parent = Some(log.insert(parent, pass(*seat)));
+    }
+    let last = parent.unwrap();
+    assert_eq!(log.backtrace(last).unwrap().len(), 2 * CHUNK_SIZE + 3);
+
+    // Changes to a snapshot aren't seen by the original, and vice versa.
+    let mut snapshot = log.clone();
+    let trace = Trace { scores: vec![] };
+    snapshot.set_trace(0, trace.clone()).unwrap();
+    let branch = snapshot.insert(Some(0), pass(Seat::North));
+    log.insert(Some(last), pass(Seat::East));
+    assert_eq!(snapshot.node(0).unwrap().trace, Some(trace));
+    assert_eq!(log.node(0).unwrap().trace, None);
+    assert_eq!(snapshot.children(Some(0)), [1, branch]);
+    assert_eq!(log.children(Some(0)), [1]);
+    assert_eq!(log.node(branch).unwrap().action, pass(Seat::East));
+    assert!(snapshot.children(Some(last)).is_empty());
+}
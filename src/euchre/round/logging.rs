@@ -1,10 +1,12 @@
 //! A round that maintains a log of actions taken.
 
+use std::time::Duration;
+
 use delegate::delegate;
 
 use crate::euchre::{
-    Action, BaseRound, Card, Contract, Event, ExpectAction, Log, LogId, PlayerState, RawLog, Round,
-    RoundConfig, RoundError, Seat, Tricks,
+    Action, BaseRound, Card, Contract, Event, ExpectAction, Log, LogId, PlayerState, RawLog,
+    RenegePolicy, Round, RoundConfig, RoundError, Seat, Timing, Trace, Tricks,
 };
 
 /// A [`Round`] implementation that maintains a [`Log`] of all actions taken.
@@ -52,6 +54,7 @@ impl Round for LoggingRound {
             fn next_action(&self) -> Option<ExpectAction>;
             fn contract(&self) -> Option<Contract>;
             fn tricks(&self) -> &Tricks;
+            fn renege_policy(&self) -> RenegePolicy;
             fn player_state(&self, seat: Seat) -> PlayerState<'_>;
             fn pop_event(&mut self) -> Option<Event>;
         }
@@ -75,11 +78,34 @@ impl LoggingRound {
         self.cursor
     }
 
+    /// Applies an action, and records the reasoning behind it in the log.
+    pub fn apply_traced_action(&mut self, action: Action, trace: Trace) -> Result<(), RoundError> {
+        self.apply_action(action)?;
+        let id = self.cursor.expect("action was logged");
+        self.log.set_trace(id, trace)
+    }
+
+    /// Records that the last action was taken just now, after the player thought for the
+    /// specified time. Does nothing if no action has been taken.
+    pub fn record_think_time(&mut self, think_time: Duration) {
+        if let Some(id) = self.cursor {
+            self.log
+                .set_timing(id, Timing::now(think_time))
+                .expect("cursor is in the log");
+        }
+    }
+
     /// Returns an immutable reference to the log.
     pub fn log(&self) -> &Log {
         &self.log
     }
 
+    /// Returns a mutable reference to the log, for adding branches. Nodes can't be removed from
+    /// the log, so the cursor stays valid.
+    pub fn log_mut(&mut self) -> &mut Log {
+        &mut self.log
+    }
+
     /// Restarts the round.
     pub fn restart(&mut self) {
         self.cursor = None;
@@ -0,0 +1,324 @@
+use rand::seq::SliceRandom;
+
+use crate::euchre::{Player, Robot};
+
+use super::*;
+
+#[test]
+fn test_fast_round_matches_base_round() {
+    let robot = Robot::default();
+    for _ in 0..100 {
+        let config = RoundConfig::random();
+        let mut base = BaseRound::from(config.clone());
+        let mut fast = FastRound::from(config);
+        while let Some(expect) = base.next_action() {
+            let data = robot.take_action(base.player_state(expect.seat), expect.action);
+            base.apply_action(expect.with_data(data)).unwrap();
+        }
+        let expect = base.outcome().unwrap();
+        let outcome = fast.play_out(&robot).unwrap();
+        assert_eq!((expect.team, expect.points), (outcome.team, outcome.points));
+        assert!(fast.pop_event().is_none());
+    }
+}
+
+#[test]
+fn test_tricks_queries() {
+    let robot = Robot::default();
+    let mut round = FastRound::random();
+    let mut leads = vec![];
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        if let (ActionType::Lead, ActionData::Card { card }) = (expect.action, data) {
+            let trump = round.contract().unwrap().suit;
+            leads.push(card.effective_suit(trump));
+        }
+        round.apply_action(expect.with_data(data)).unwrap();
+        let tricks = round.tricks();
+        let in_progress = usize::from(tricks.in_progress().is_some());
+        assert_eq!(tricks.completed().count() + in_progress, tricks.len());
+    }
+
+    let tricks = round.tricks();
+    assert!(tricks.in_progress().is_none());
+    assert_eq!(tricks.suits_led().collect::<Vec<_>>(), leads);
+    for &seat in Seat::all_seats() {
+        let played = tricks.cards_played_by(seat).count();
+        let expect = tricks.iter().filter(|t| t.get_card(seat).is_some()).count();
+        assert_eq!(played, expect);
+    }
+}
+
+#[test]
+fn test_player_state_seen() {
+    let robot = Robot::default();
+    let mut round = FastRound::random();
+    while let Some(expect) = round.next_action() {
+        let state = round.player_state(expect.seat);
+        let data = robot.take_action(state, expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+
+        let played: CardSet = round
+            .tricks()
+            .iter()
+            .flat_map(|t| t.cards().iter().map(|(_, c)| c))
+            .collect();
+        for &seat in Seat::all_seats() {
+            let state = round.player_state(seat);
+            let hand: CardSet = state.hand.iter().collect();
+            assert_eq!(state.seen & hand, hand);
+            assert_eq!(state.seen & played, played);
+            assert!(state.seen.contains(state.top));
+            assert!(state.unseen().iter().all(|c| !state.seen.contains(c)));
+        }
+    }
+}
+
+#[test]
+fn test_deal_pattern_packets() {
+    for pattern in [DealPattern::ThreeTwo, DealPattern::TwoThree] {
+        let packets = pattern.packets(Seat::West);
+        assert_eq!(packets.len(), 8);
+        assert_eq!(packets[0].0, Seat::North);
+        for &seat in Seat::all_seats() {
+            let counts: Vec<_> = packets
+                .iter()
+                .filter(|(s, _)| *s == seat)
+                .map(|(_, n)| *n)
+                .collect();
+            assert_eq!(counts.iter().sum::<usize>(), 5);
+            assert!(counts == [2, 3] || counts == [3, 2]);
+        }
+    }
+    assert_eq!(DealPattern::ThreeTwo.packets(Seat::West)[0].1, 3);
+    assert_eq!(DealPattern::TwoThree.packets(Seat::West)[0].1, 2);
+}
+
+#[test]
+fn test_redacted_config() {
+    let config = RoundConfig::random();
+    let redacted = config.redacted(Seat::East);
+    assert_eq!(redacted.hand(Seat::East), config.hand(Seat::East));
+    assert_eq!(redacted.top(), config.top());
+    assert_eq!(redacted.dealer(), config.dealer());
+    for seat in [Seat::North, Seat::South, Seat::West] {
+        assert!(redacted.hand(seat).is_empty());
+    }
+    assert!(matches!(
+        redacted.validate(),
+        Err(RoundError::InvalidHandSize)
+    ));
+}
+
+#[test]
+fn test_engine_play_out() {
+    use crate::engine::Engine;
+    use rand::seq::SliceRandom;
+
+    fn play<E: Engine>(engine: &mut E) -> Option<E::Outcome> {
+        let mut rng = rand::thread_rng();
+        engine
+            .play_out(|_, _, actions| actions.choose(&mut rng).unwrap().clone())
+            .unwrap()
+    }
+
+    for _ in 0..100 {
+        let mut round = FastRound::random();
+        assert!(play(&mut round).is_some());
+        assert!(Round::legal_actions(&round).is_empty());
+    }
+}
+
+#[test]
+fn test_legal_actions_include_robot() {
+    let robot = Robot::default();
+    let mut round = FastRound::random();
+    while let Some(expect) = round.next_action() {
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        let action = expect.with_data(data);
+        assert!(Round::legal_actions(&round).contains(&action));
+        round.apply_action(action).unwrap();
+    }
+}
+
+/// Deals a round in which West can renege on the first trick, and plays through the bidding, with
+/// East calling clubs.
+fn renege_fixture(renege: RenegePolicy) -> BaseRound {
+    let hand = |cards: [&str; 5]| cards.iter().map(|c| c.parse().unwrap()).collect();
+    let hands = SeatMap::from_fn(|seat| match seat {
+        Seat::North => hand(["ad", "qs", "jh", "th", "9h"]),
+        Seat::East => hand(["jc", "kd", "ks", "kh", "qh"]),
+        Seat::South => hand(["ac", "kc", "qc", "qd", "td"]),
+        Seat::West => hand(["tc", "js", "ts", "9s", "ah"]),
+    });
+    let config = RoundConfig::from_hands(
+        Seat::North,
+        hands,
+        "jd".parse().unwrap(),
+        DealPattern::default(),
+    )
+    .unwrap()
+    .with_renege_policy(renege);
+    let mut round = BaseRound::from(config);
+    for _ in 0..4 {
+        let expect = round.next_action().unwrap();
+        round
+            .apply_action(expect.with_data(ActionData::Pass))
+            .unwrap();
+    }
+    let expect = round.next_action().unwrap();
+    let call = ActionData::Call {
+        suit: Suit::Club,
+        alone: false,
+    };
+    round.apply_action(expect.with_data(call)).unwrap();
+    round
+}
+
+/// Plays the specified cards, in turn.
+fn play(round: &mut impl Round, cards: &[&str]) -> Result<(), RoundError> {
+    for card in cards {
+        let expect = round.next_action().unwrap();
+        let card = card.parse().unwrap();
+        round.apply_action(expect.with_data(ActionData::Card { card }))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_renege_rejected() {
+    let mut round = renege_fixture(RenegePolicy::Reject);
+    play(&mut round, &["kh", "qd"]).unwrap();
+    assert_eq!(round.legal_actions().len(), 1);
+    assert!(matches!(
+        play(&mut round, &["9s"]),
+        Err(RoundError::Player(PlayerError::MustFollowLead(
+            Seat::West,
+            _
+        )))
+    ));
+}
+
+#[test]
+fn test_renege_called() {
+    let mut round = renege_fixture(RenegePolicy::Defer);
+    play(&mut round, &["kh", "qd"]).unwrap();
+    assert_eq!(round.legal_actions().len(), 5);
+    play(&mut round, &["9s", "9h"]).unwrap();
+    assert_eq!(round.tricks().exposed_revokes().count(), 0);
+    assert!(round.legal_calls(Seat::North).is_empty());
+
+    // West later plays a heart, exposing the renege.
+    play(&mut round, &["qh", "td", "ah"]).unwrap();
+    let revoke = Revoke {
+        seat: Seat::West,
+        card: "9s".parse().unwrap(),
+        trick: 0,
+    };
+    assert_eq!(
+        round.tricks().exposed_revokes().collect::<Vec<_>>(),
+        [revoke]
+    );
+
+    // Reneges can only be called on an opponent's card.
+    let call = |seat, card: &str| {
+        let card = card.parse().unwrap();
+        Action::new(seat, ActionType::CallRenege, ActionData::Card { card })
+    };
+    for (seat, card) in [(Seat::East, "9s"), (Seat::North, "kh"), (Seat::North, "qd")] {
+        assert!(matches!(
+            round.clone().apply_action(call(seat, card)),
+            Err(RoundError::Player(PlayerError::NoRenege(_)))
+        ));
+    }
+
+    assert_eq!(round.legal_calls(Seat::North), [call(Seat::North, "9s")]);
+    assert!(round.legal_calls(Seat::East).is_empty());
+
+    while round.pop_event().is_some() {}
+    round.apply_action(call(Seat::North, "9s")).unwrap();
+    assert!(round.legal_calls(Seat::South).is_empty());
+    round.validate_invariants().unwrap();
+    assert!(round.next_action().is_none());
+    let outcome = round.outcome().unwrap();
+    assert_eq!((outcome.team, outcome.points), (Team::NorthSouth, 2));
+    assert!(matches!(round.pop_event(), Some(Event::Renege(r)) if r == revoke));
+    assert!(matches!(round.pop_event(), Some(Event::Round(_))));
+}
+
+#[test]
+fn test_invariants_hold() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..200 {
+        let mut round = BaseRound::from(RoundConfig::random());
+        round.validate_invariants().unwrap();
+        while round.next_action().is_some() {
+            let action = *Round::legal_actions(&round).choose(&mut rng).unwrap();
+            round.apply_action(action).unwrap();
+            round.validate_invariants().unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_invariants_violated() {
+    let config = RoundConfig::random();
+    let dealer = config.dealer;
+    let invalid = |config: RoundConfig| {
+        matches!(
+            BaseRound::from(config).validate_invariants(),
+            Err(RoundError::Invariant(_))
+        )
+    };
+
+    let mut duplicate = config.clone();
+    duplicate.hands[dealer][0] = duplicate.hands[dealer.next()][0];
+    assert!(invalid(duplicate));
+
+    let mut top = config.clone();
+    top.top = top.hands[dealer][0];
+    assert!(invalid(top));
+
+    let mut short = config;
+    short.hands[dealer].pop();
+    assert!(invalid(short));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_play() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand::RngCore;
+
+    let mut rng = rand::thread_rng();
+    let mut bytes = vec![0; 1024];
+    for _ in 0..500 {
+        rng.fill_bytes(&mut bytes);
+        let mut u = Unstructured::new(&bytes);
+        let mut round = BaseRound::from(RoundConfig::arbitrary(&mut u).unwrap());
+        while !u.is_empty() {
+            let legal = Round::legal_actions(&round);
+            let action = if u.arbitrary().unwrap() {
+                match legal.get(usize::from(u8::arbitrary(&mut u).unwrap()) % legal.len().max(1)) {
+                    Some(&action) => action,
+                    None => break,
+                }
+            } else {
+                Action::arbitrary(&mut u).unwrap()
+            };
+            let _ = round.apply_action(action);
+            round.validate_invariants().unwrap();
+        }
+
+        rng.fill_bytes(&mut bytes);
+        let log = RawLog::arbitrary(&mut Unstructured::new(&bytes))
+            .unwrap()
+            .into_log();
+        let mut round = LoggingRound::from(log.clone());
+        for node in log.action_nodes() {
+            if round.seek(Some(node.id)).is_ok() {
+                round.validate_invariants().unwrap();
+            }
+        }
+    }
+}
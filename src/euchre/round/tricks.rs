@@ -3,14 +3,33 @@
 use std::convert::TryFrom;
 
 use delegate::delegate;
+use serde::{Deserialize, Serialize};
 
-use super::{Team, Trick};
+use super::{Card, Seat, Suit, Team, Trick};
+
+/// A card played without following suit, by a player who could have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revoke {
+    /// The player who reneged.
+    pub seat: Seat,
+    /// The card that was played.
+    pub card: Card,
+    /// The index of the trick in which it was played.
+    pub trick: usize,
+}
 
 /// Tricks played this round.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tricks {
     tricks: Vec<Trick>,
     trick_size: usize,
+    /// Revokes that haven't been called. These are only known to the offender, so they're never
+    /// sent to other players.
+    #[serde(skip)]
+    revokes: Vec<Revoke>,
+    /// The renege that was called, if any.
+    #[serde(default)]
+    called: Option<Revoke>,
 }
 
 impl Default for Tricks {
@@ -18,14 +37,26 @@ impl Default for Tricks {
         Self {
             tricks: vec![],
             trick_size: 4,
+            revokes: vec![],
+            called: None,
         }
     }
 }
 
+impl<'a> IntoIterator for &'a Tricks {
+    type Item = &'a Trick;
+    type IntoIter = std::slice::Iter<'a, Trick>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tricks.iter()
+    }
+}
+
 impl Tricks {
     delegate! {
         to self.tricks {
             pub fn len(&self) -> usize;
+            pub fn is_empty(&self) -> bool;
             pub fn last(&self) -> Option<&Trick>;
             pub fn last_mut(&mut self) -> Option<&mut Trick>;
         }
@@ -48,12 +79,86 @@ impl Tricks {
         self.trick_size = trick_size;
     }
 
+    /// Returns an iterator over all tricks, including the trick in progress.
+    pub fn iter(&self) -> std::slice::Iter<'_, Trick> {
+        self.tricks.iter()
+    }
+
+    /// Returns true if all cards have been played into the trick.
+    pub fn is_complete(&self, trick: &Trick) -> bool {
+        trick.len() == self.trick_size
+    }
+
+    /// Returns an iterator over the completed tricks.
+    pub fn completed(&self) -> impl Iterator<Item = &Trick> {
+        self.tricks.iter().filter(move |t| self.is_complete(t))
+    }
+
+    /// Returns the trick in progress, if any.
+    pub fn in_progress(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|t| !self.is_complete(t))
+    }
+
+    /// Returns an iterator over the cards played by the specified seat, in order.
+    pub fn cards_played_by(&self, seat: Seat) -> impl Iterator<Item = Card> + '_ {
+        self.tricks.iter().filter_map(move |t| t.get_card(seat))
+    }
+
+    /// Returns an iterator over the effective suit led in each trick, in order.
+    pub fn suits_led(&self) -> impl Iterator<Item = Suit> + '_ {
+        self.tricks.iter().map(Trick::lead_suit)
+    }
+
+    /// Records a revoke in the trick in progress.
+    pub fn record_revoke(&mut self, seat: Seat, card: Card) {
+        let trick = self.len().checked_sub(1).expect("trick must be started");
+        self.revokes.push(Revoke { seat, card, trick });
+    }
+
+    /// Returns the revoke for the specified card, if it was a renege.
+    pub fn revoke(&self, card: Card) -> Option<Revoke> {
+        self.revokes.iter().find(|r| r.card == card).copied()
+    }
+
+    /// Records that a renege was called.
+    pub fn call_renege(&mut self, revoke: Revoke) {
+        self.called = Some(revoke);
+    }
+
+    /// Returns the renege that was called, if any.
+    pub fn called_renege(&self) -> Option<Revoke> {
+        self.called
+    }
+
+    /// Returns an iterator over the revokes that can be proven from the cards played: a player
+    /// who failed to follow a suit, and later played a card of that suit.
+    pub fn exposed_revokes(&self) -> impl Iterator<Item = Revoke> + '_ {
+        self.tricks.iter().enumerate().flat_map(move |(i, trick)| {
+            let following = trick.following();
+            trick
+                .cards()
+                .iter()
+                .skip(1)
+                .filter(move |(_, card)| !following.contains(*card))
+                .filter(move |&&(seat, _)| {
+                    self.tricks[i + 1..]
+                        .iter()
+                        .filter_map(|t| t.get_card(seat))
+                        .any(|c| following.contains(c))
+                })
+                .map(move |&(seat, card)| Revoke {
+                    seat,
+                    card,
+                    trick: i,
+                })
+        })
+    }
+
     /// Counts the number of completed tricks won by the specified team.
     pub fn win_count(&self, team: Team) -> u8 {
         let count = self
-            .tricks
-            .iter()
-            .filter(|t| t.len() == self.trick_size && Team::from(t.best().0) == team)
+            .completed()
+            .filter(|t| Team::from(t.best().0) == team)
             .count();
         u8::try_from(count).expect("less than 256")
     }
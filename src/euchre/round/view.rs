@@ -0,0 +1,185 @@
+//! A seat-visible snapshot of a round.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Action, ActionData, ActionType, Card, CardSet, Contract, Event, ExpectAction, PlayerError,
+    PlayerState, RenegePolicy, Round, RoundError, Seat, Tricks,
+};
+
+/// Placeholder for hands that are not visible from the view's seat.
+static HIDDEN: Vec<Card> = Vec::new();
+
+/// A snapshot of a round, as visible to a particular seat.
+///
+/// The view omits the hands of other players, so it's safe to share with a remote player. A
+/// public view, as shared with spectators, omits all hands. It implements [`Round`], so that it
+/// can be rendered with the same machinery as a local round.
+/// Applying an action to a view does not advance its state: the action is validated and queued
+/// for delivery to the authoritative round, and the view awaits a fresh snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundView {
+    /// The seat from which the round is viewed, or `None` for a public view.
+    seat: Option<Seat>,
+    /// The dealer for this round.
+    dealer: Seat,
+    /// The upturned card.
+    top: Card,
+    /// The established contract, once bidding is over.
+    contract: Option<Contract>,
+    /// The viewer's hand.
+    hand: Vec<Card>,
+    /// The cards that the viewer has seen so far.
+    seen: CardSet,
+    /// Tricks played during this round.
+    tricks: Tricks,
+    /// How failures to follow suit are handled.
+    #[serde(default)]
+    renege: RenegePolicy,
+    /// The next action required to advance the round.
+    next_action: Option<ExpectAction>,
+    /// Actions applied to the view, awaiting delivery.
+    #[serde(skip)]
+    pending: Vec<Action>,
+}
+
+impl RoundView {
+    /// Creates a new [`RoundView`] of the round from the specified seat.
+    pub fn new<R: Round + ?Sized>(round: &R, seat: Seat) -> Self {
+        Self::from_state(&round.player_state(seat), round.next_action())
+            .with_renege_policy(round.renege_policy())
+    }
+
+    /// Creates a new public [`RoundView`] of the round, which reveals no player's hand.
+    pub fn public<R: Round + ?Sized>(round: &R) -> Self {
+        Self {
+            seat: None,
+            dealer: round.dealer(),
+            top: round.top_card(),
+            contract: round.contract(),
+            hand: vec![],
+            seen: CardSet::new(),
+            tricks: round.tricks().clone(),
+            renege: round.renege_policy(),
+            next_action: round.next_action(),
+            pending: vec![],
+        }
+    }
+
+    /// Creates a new [`RoundView`] from a player's state, and the next expected action. The view
+    /// rejects reneges until a policy is set with
+    /// [`with_renege_policy`](`RoundView::with_renege_policy`).
+    pub fn from_state(state: &PlayerState, next_action: Option<ExpectAction>) -> Self {
+        Self {
+            seat: Some(state.seat),
+            dealer: state.dealer,
+            top: state.top,
+            contract: state.contract,
+            hand: state.hand.clone(),
+            seen: state.seen,
+            tricks: state.tricks.clone(),
+            renege: RenegePolicy::default(),
+            next_action,
+            pending: vec![],
+        }
+    }
+
+    /// Sets the policy for handling failures to follow suit.
+    pub fn with_renege_policy(mut self, renege: RenegePolicy) -> Self {
+        self.renege = renege;
+        self
+    }
+
+    /// Returns the seat from which the round is viewed, or `None` for a public view.
+    pub fn seat(&self) -> Option<Seat> {
+        self.seat
+    }
+
+    /// Explains why an action of the expected type is not legal.
+    fn illegal(&self, action: Action) -> RoundError {
+        match (action.action, action.data) {
+            (_, ActionData::Card { card }) if !self.hand.contains(&card) => {
+                PlayerError::CardNotHeld(action.seat, card).into()
+            }
+            (ActionType::Follow, ActionData::Card { .. }) => match self.tricks.in_progress() {
+                Some(trick) => PlayerError::MustFollowLead(action.seat, trick.lead().1).into(),
+                None => RoundError::InvalidActionData,
+            },
+            (ActionType::BidTop, ActionData::Call { .. }) => {
+                PlayerError::MustCallTopSuit(self.top.suit).into()
+            }
+            (ActionType::BidOther, ActionData::Call { .. }) => {
+                PlayerError::CannotCallTopSuit(self.top.suit).into()
+            }
+            (ActionType::BidOther, ActionData::Pass) => PlayerError::DealerMustBidOther.into(),
+            _ => RoundError::InvalidActionData,
+        }
+    }
+
+    /// Takes the actions that have been applied to the view since the last call.
+    pub fn take_pending(&mut self) -> Vec<Action> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+impl Round for RoundView {
+    fn dealer(&self) -> Seat {
+        self.dealer
+    }
+
+    fn top_card(&self) -> Card {
+        self.top
+    }
+
+    fn next_action(&self) -> Option<ExpectAction> {
+        self.next_action
+    }
+
+    fn contract(&self) -> Option<Contract> {
+        self.contract
+    }
+
+    fn tricks(&self) -> &Tricks {
+        &self.tricks
+    }
+
+    fn renege_policy(&self) -> RenegePolicy {
+        self.renege
+    }
+
+    fn player_state(&self, seat: Seat) -> PlayerState<'_> {
+        let (hand, seen) = if Some(seat) == self.seat {
+            (&self.hand, self.seen)
+        } else {
+            (&HIDDEN, CardSet::new())
+        };
+        PlayerState::new(
+            seat,
+            self.dealer,
+            self.top,
+            self.contract,
+            hand,
+            seen,
+            &self.tricks,
+        )
+    }
+
+    fn apply_action(&mut self, action: Action) -> Result<(), RoundError> {
+        match self.next_action {
+            None => Err(RoundError::RoundOver),
+            Some(ExpectAction { seat, action: a }) if seat != action.seat || a != action.action => {
+                Err(RoundError::ExpectActioned { seat, action: a })
+            }
+            Some(_) if !self.legal_actions().contains(&action) => Err(self.illegal(action)),
+            Some(_) => {
+                self.pending.push(action);
+                self.next_action = None;
+                Ok(())
+            }
+        }
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        None
+    }
+}
@@ -0,0 +1,188 @@
+//! Score sheets.
+//!
+//! A score sheet lists the rounds of a game, one per row: who dealt, who made trump and in what,
+//! how the contract fared, and the running totals. It can be written as aligned text, as a
+//! Markdown table, or as CSV.
+
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::{Game, Round, RoundRecord, Team};
+
+#[cfg(test)]
+mod test;
+
+/// The column headings.
+const HEADINGS: [&str; 8] = [
+    "#", "Dealer", "Maker", "Contract", "Result", "Points", "N/S", "E/W",
+];
+
+/// The widths of the columns, in the text format.
+const WIDTHS: [usize; 8] = [3, 6, 6, 8, 14, 7, 3, 3];
+
+/// How a score sheet is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SheetFormat {
+    /// Columns aligned with spaces, for the terminal.
+    #[default]
+    Text,
+    /// A Markdown table.
+    Markdown,
+    /// Comma-separated values, for spreadsheets.
+    Csv,
+}
+
+impl SheetFormat {
+    /// Chooses the format for a file by its extension: Markdown for `.md`, CSV for `.csv`, and
+    /// text otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("md") => SheetFormat::Markdown,
+            Some("csv") => SheetFormat::Csv,
+            _ => SheetFormat::Text,
+        }
+    }
+}
+
+impl FromStr for SheetFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(SheetFormat::Text),
+            "markdown" | "md" => Ok(SheetFormat::Markdown),
+            "csv" => Ok(SheetFormat::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The rounds of a game, with the running score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreSheet {
+    rows: Vec<RoundRecord>,
+    target_score: u8,
+    winner: Option<Team>,
+}
+
+impl ScoreSheet {
+    /// Creates a score sheet for the rounds completed so far in the current game.
+    pub fn new<R: Round>(game: &Game<R>) -> Self {
+        Self {
+            rows: game.history().to_vec(),
+            target_score: game.target_score(),
+            winner: game.winner(),
+        }
+    }
+
+    /// Returns the rounds, in order.
+    pub fn rows(&self) -> &[RoundRecord] {
+        &self.rows
+    }
+
+    /// Returns the column headings.
+    pub fn headings() -> [&'static str; 8] {
+        HEADINGS
+    }
+
+    /// Returns the cells of each row, in the order of the headings.
+    pub fn cells(&self) -> impl Iterator<Item = [String; 8]> + '_ {
+        self.rows.iter().enumerate().map(|(i, row)| cells(i, row))
+    }
+
+    /// Describes how the game ended, if it's over.
+    pub fn footer(&self) -> Option<String> {
+        let winner = self.winner?;
+        let last = self.rows.last()?;
+        Some(format!(
+            "{winner} wins {}-{}, playing to {}.",
+            last.score[winner],
+            last.score[winner.other()],
+            self.target_score
+        ))
+    }
+
+    /// Writes the score sheet in the specified format.
+    pub fn format(&self, format: SheetFormat) -> String {
+        match format {
+            SheetFormat::Text => self.to_string(),
+            SheetFormat::Markdown => self.to_markdown(),
+            SheetFormat::Csv => self.to_csv(),
+        }
+    }
+
+    /// Writes the score sheet as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let row = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+        let headings = HEADINGS.map(String::from);
+        let mut md = row(&headings);
+        md.push_str(&row(&HEADINGS.map(|_| "---".to_string())));
+        for cells in self.cells() {
+            md.push_str(&row(&cells));
+        }
+        if let Some(footer) = self.footer() {
+            md.push('\n');
+            md.push_str(&footer);
+            md.push('\n');
+        }
+        md
+    }
+
+    /// Writes the score sheet as CSV, without the footer.
+    pub fn to_csv(&self) -> String {
+        let mut csv = HEADINGS.join(",");
+        csv.push('\n');
+        for cells in self.cells() {
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl Display for ScoreSheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = |cells: &[String]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(WIDTHS)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            padded.join(" ").trim_end().to_string()
+        };
+        writeln!(f, "{}", line(&HEADINGS.map(String::from)))?;
+        for cells in self.cells() {
+            writeln!(f, "{}", line(&cells))?;
+        }
+        if let Some(footer) = self.footer() {
+            writeln!(f, "{footer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the cells describing a round.
+fn cells(index: usize, row: &RoundRecord) -> [String; 8] {
+    let contract = &row.contract;
+    let result = match (row.renege, row.tricks) {
+        (Some(seat), _) => format!("renege by {}", seat.to_abbr()),
+        (None, 5) => "march".to_string(),
+        (None, 3..=4) => format!("made {}", row.tricks),
+        (None, _) => format!("euchred ({})", row.tricks),
+    };
+    [
+        (index + 1).to_string(),
+        row.dealer.to_string(),
+        contract.maker.to_string(),
+        if contract.alone {
+            format!("{} alone", contract.suit)
+        } else {
+            contract.suit.to_string()
+        },
+        result,
+        format!("{} +{}", row.outcome.team.to_abbr(), row.outcome.points),
+        row.score[Team::NorthSouth].to_string(),
+        row.score[Team::EastWest].to_string(),
+    ]
+}
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use super::*;
+use crate::euchre::{
+    Contract, LoggingRound, Player, RawGame, Robot, RoundOutcome, Seat, Suit, TeamMap,
+};
+
+/// Plays a game to the end with robots, scoring its final round.
+fn finished_game() -> Game<LoggingRound> {
+    let robot = Robot::default();
+    let mut game = Game::from(LoggingRound::random()).with_target_score(5);
+    while game.winner().is_none() {
+        while let Some(expect) = game.round().next_action() {
+            let state = game.round().player_state(expect.seat);
+            let data = robot.take_action(state, expect.action);
+            game.round_mut()
+                .apply_action(expect.with_data(data))
+                .unwrap();
+        }
+        game.score_round();
+        if game.winner().is_none() {
+            game.next_round();
+        }
+    }
+    game
+}
+
+#[test]
+fn test_score_sheet() {
+    let game = finished_game();
+    let sheet = ScoreSheet::new(&game);
+    assert_eq!(sheet.rows().len(), usize::from(game.rounds()));
+    let last = sheet.rows().last().unwrap();
+    for team in [Team::NorthSouth, Team::EastWest] {
+        assert_eq!(last.score[team], game.score(team));
+        let points: u8 = sheet
+            .rows()
+            .iter()
+            .filter(|row| row.outcome.team == team)
+            .map(|row| row.outcome.points)
+            .sum();
+        assert_eq!(points, game.score(team));
+    }
+
+    let rows = sheet.rows().len();
+    assert!(sheet.footer().is_some());
+    assert_eq!(sheet.to_string().lines().count(), rows + 2);
+    assert_eq!(sheet.to_markdown().lines().count(), rows + 4);
+    assert_eq!(sheet.to_csv().lines().count(), rows + 1);
+
+    // The history survives saving and loading.
+    let raw = RawGame::from(&game);
+    let restored = raw.into_game().unwrap();
+    assert_eq!(ScoreSheet::new(&restored), sheet);
+}
+
+#[test]
+fn test_score_sheet_cells() {
+    let game = Game::from(LoggingRound::random());
+    let sheet = ScoreSheet::new(&game);
+    assert!(sheet.rows().is_empty());
+    assert_eq!(sheet.footer(), None);
+    assert_eq!(
+        sheet.to_csv(),
+        "#,Dealer,Maker,Contract,Result,Points,N/S,E/W\n"
+    );
+
+    let mut score = TeamMap::default();
+    score[Team::NorthSouth] = 4;
+    let mut row = RoundRecord {
+        dealer: Seat::West,
+        contract: Contract {
+            maker: Seat::North,
+            suit: Suit::Heart,
+            alone: true,
+        },
+        tricks: 5,
+        renege: None,
+        outcome: RoundOutcome::new(Team::NorthSouth, 4),
+        score,
+    };
+    let march = cells(0, &row);
+    assert_eq!(march[0], "1");
+    assert_eq!(march[1], Seat::West.to_string());
+    assert_eq!(march[3], format!("{} alone", Suit::Heart));
+    assert_eq!(march[4], "march");
+    assert_eq!(march[5], "N/S +4");
+    assert_eq!(&march[6..], ["4", "0"]);
+
+    row.tricks = 2;
+    assert_eq!(cells(1, &row)[4], "euchred (2)");
+    row.renege = Some(Seat::East);
+    assert_eq!(cells(1, &row)[4], "renege by E");
+}
+
+#[test]
+fn test_sheet_format() {
+    assert_eq!(
+        SheetFormat::from_path(Path::new("a/b.md")),
+        SheetFormat::Markdown
+    );
+    assert_eq!(SheetFormat::from_path(Path::new("b.csv")), SheetFormat::Csv);
+    assert_eq!(SheetFormat::from_path(Path::new("b")), SheetFormat::Text);
+    assert_eq!("Markdown".parse(), Ok(SheetFormat::Markdown));
+    assert_eq!("xml".parse::<SheetFormat>(), Err(()));
+}
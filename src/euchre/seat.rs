@@ -1,12 +1,21 @@
 //! Table position.
 
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 use std::{convert::TryFrom, fmt::Display};
 
 use rand::distributions::{Distribution, Standard};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::i18n::{tr, tr_in, Lang};
+use crate::tr;
 
 /// Table position, represented as cardinal direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Seat {
     North,
     East,
@@ -15,12 +24,7 @@ pub enum Seat {
 }
 impl Display for Seat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
-            Seat::North => "North",
-            Seat::East => "East",
-            Seat::South => "South",
-            Seat::West => "West",
-        })
+        f.write_str(&tr(self.message_id(), None))
     }
 }
 impl Distribution<Seat> for Standard {
@@ -49,6 +53,27 @@ impl TryFrom<char> for Seat {
     }
 }
 
+impl FromStr for Seat {
+    type Err = ();
+
+    /// Parses a seat from its name or abbreviation, ignoring case. The English name is accepted
+    /// in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Seat::try_from(c),
+            _ => Seat::all_seats()
+                .iter()
+                .copied()
+                .find(|seat| {
+                    let english = tr_in(Lang::English, seat.message_id(), None);
+                    english.eq_ignore_ascii_case(s) || seat.to_string().eq_ignore_ascii_case(s)
+                })
+                .ok_or(()),
+        }
+    }
+}
+
 impl Seat {
     /// All possible table positions, in clockwise order.
     pub fn all_seats() -> &'static [Seat; 4] {
@@ -71,6 +96,16 @@ impl Seat {
         }
     }
 
+    /// The ID of the localized message naming the table position.
+    fn message_id(self) -> &'static str {
+        match self {
+            Seat::North => "seat-north",
+            Seat::East => "seat-east",
+            Seat::South => "seat-south",
+            Seat::West => "seat-west",
+        }
+    }
+
     /// The opposite table position.
     pub fn opposite(self) -> Seat {
         match self {
@@ -103,7 +138,7 @@ impl Seat {
 }
 
 /// A team consists of the two seats opposite one another.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Team {
     NorthSouth,
     EastWest,
@@ -118,9 +153,9 @@ impl From<Seat> for Team {
 }
 impl Display for Team {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
-            Team::NorthSouth => "North/South",
-            Team::EastWest => "East/West",
+        f.write_str(&match self {
+            Team::NorthSouth => tr!("team-north-south"),
+            Team::EastWest => tr!("team-east-west"),
         })
     }
 }
@@ -141,3 +176,129 @@ impl Team {
         }
     }
 }
+
+/// A map from [`Seat`] to a value, backed by a fixed-size array.
+///
+/// Serializes as a map keyed by seat name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SeatMap<T>([T; 4]);
+
+impl<T> SeatMap<T> {
+    /// Creates a new map, populating each seat with the provided function.
+    pub fn from_fn<F: FnMut(Seat) -> T>(mut f: F) -> Self {
+        Self(Seat::all_seats().map(&mut f))
+    }
+
+    /// Returns an iterator over seats and their values, in clockwise order from north.
+    pub fn iter(&self) -> impl Iterator<Item = (Seat, &T)> {
+        Seat::all_seats().iter().copied().zip(self.0.iter())
+    }
+
+    /// Returns an iterator over the values, in clockwise order from north.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Returns a mutable iterator over the values, in clockwise order from north.
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T> Index<Seat> for SeatMap<T> {
+    type Output = T;
+
+    fn index(&self, seat: Seat) -> &T {
+        &self.0[seat as usize]
+    }
+}
+
+impl<T> IndexMut<Seat> for SeatMap<T> {
+    fn index_mut(&mut self, seat: Seat) -> &mut T {
+        &mut self.0[seat as usize]
+    }
+}
+
+impl<T: Default> FromIterator<(Seat, T)> for SeatMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Seat, T)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (seat, value) in iter {
+            map[seat] = value;
+        }
+        map
+    }
+}
+
+impl<T: Serialize> Serialize for SeatMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(4))?;
+        for (seat, value) in self.iter() {
+            map.serialize_entry(&seat, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SeatMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut map = HashMap::<Seat, T>::deserialize(deserializer)?;
+        if let Some(seat) = Seat::all_seats().iter().find(|s| !map.contains_key(s)) {
+            let msg = format!("missing seat {seat}");
+            return Err(serde::de::Error::custom(msg));
+        }
+        Ok(Self::from_fn(|seat| {
+            map.remove(&seat).expect("all seats present")
+        }))
+    }
+}
+
+/// A map from [`Team`] to a value, backed by a fixed-size array.
+///
+/// Serializes as a map keyed by team name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TeamMap<T>([T; 2]);
+
+impl<T> TeamMap<T> {
+    /// Returns an iterator over teams and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (Team, &T)> {
+        [Team::NorthSouth, Team::EastWest]
+            .iter()
+            .copied()
+            .zip(self.0.iter())
+    }
+}
+
+impl<T> Index<Team> for TeamMap<T> {
+    type Output = T;
+
+    fn index(&self, team: Team) -> &T {
+        &self.0[team as usize]
+    }
+}
+
+impl<T> IndexMut<Team> for TeamMap<T> {
+    fn index_mut(&mut self, team: Team) -> &mut T {
+        &mut self.0[team as usize]
+    }
+}
+
+impl<T: Serialize> Serialize for TeamMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        for (team, value) in self.iter() {
+            map.serialize_entry(&team, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default> Deserialize<'de> for TeamMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = HashMap::<Team, T>::deserialize(deserializer)?;
+        let mut team_map = Self::default();
+        for (team, value) in map {
+            team_map[team] = value;
+        }
+        Ok(team_map)
+    }
+}
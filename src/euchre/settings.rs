@@ -0,0 +1,209 @@
+//! User settings for the terminal UI.
+//!
+//! Settings are edited from the start menu, and kept in a settings file in the config directory,
+//! so that they carry over from one session to the next. The rules apply to games started after
+//! they're changed; everything else applies straight away.
+
+use std::fmt::Display;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+use super::paths::create_file;
+use super::{RobotKind, Rules};
+
+#[cfg(test)]
+mod test;
+
+/// How face-down cards are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardBack {
+    /// A medium shade.
+    #[default]
+    Shade,
+    /// A light shade.
+    Light,
+    /// A solid block.
+    Solid,
+    /// Plain ASCII, for terminals without block elements.
+    Ascii,
+}
+
+impl CardBack {
+    /// All card backs.
+    pub const ALL: [CardBack; 4] = [
+        CardBack::Shade,
+        CardBack::Light,
+        CardBack::Solid,
+        CardBack::Ascii,
+    ];
+
+    /// The character drawn for a face-down card.
+    pub fn glyph(self) -> char {
+        match self {
+            CardBack::Shade => '▒',
+            CardBack::Light => '░',
+            CardBack::Solid => '█',
+            CardBack::Ascii => '#',
+        }
+    }
+
+    /// The next card back, cycling back to the first.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&b| b == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl Display for CardBack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CardBack::Shade => "shade",
+            CardBack::Light => "light",
+            CardBack::Solid => "solid",
+            CardBack::Ascii => "ascii",
+        })
+    }
+}
+
+impl FromStr for CardBack {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|b| b.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+/// The colors used to draw the terminal UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Red suits in red, and other highlights in color.
+    #[default]
+    Color,
+    /// No colors, for terminals or players that do without.
+    Monochrome,
+}
+
+impl Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Theme::Color => "color",
+            Theme::Monochrome => "monochrome",
+        })
+    }
+}
+
+/// The game lengths that may be chosen, in points.
+pub const TARGET_SCORES: [u8; 4] = [5, 7, 10, 15];
+
+/// The longest delay before robots' events are acknowledged automatically, in milliseconds.
+pub const MAX_AUTOPLAY_DELAY: u16 = 5000;
+
+/// Preferences for the terminal UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Whether robots play the seats that the user isn't playing.
+    pub robot_autoplay: bool,
+    /// Whether to annotate the cards in the hand with their odds of winning the trick.
+    pub trick_odds: bool,
+    /// Whether to show the estimated odds of winning the game on the scoreboard.
+    pub win_odds: bool,
+    /// Whether to ask the user to estimate the strength of their hand before they bid.
+    pub strength_trainer: bool,
+    /// How face-down cards are drawn.
+    pub card_back: CardBack,
+    /// The rules for new games.
+    pub rules: Rules,
+    /// Which robot plays the seats that the user isn't playing.
+    pub robot: RobotKind,
+    /// The colors used to draw the UI.
+    pub theme: Theme,
+    /// How long to show events, such as completed tricks, before moving on without a keypress
+    /// while robots are playing, in milliseconds. With none, events wait for a key.
+    pub autoplay_delay: u16,
+    /// Whether to show consecutive events, such as the deal and a robot's call, on one screen,
+    /// rather than one at a time.
+    pub batch_events: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            robot_autoplay: true,
+            trick_odds: false,
+            win_odds: true,
+            strength_trainer: false,
+            card_back: CardBack::default(),
+            rules: Rules::default(),
+            robot: RobotKind::default(),
+            theme: Theme::default(),
+            autoplay_delay: 0,
+            batch_events: false,
+        }
+    }
+}
+
+impl Settings {
+    /// The name of the settings file, in the config directory.
+    pub const FILE_NAME: &'static str = "euchre-settings.json";
+
+    /// Loads settings from a file. A missing file is treated as the defaults, but settings that
+    /// are out of range are an error.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let settings: Self = match File::open(path) {
+            Ok(file) => serde_json::from_reader(file)?,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err.into()),
+        };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Checks that the settings are in range.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let rules = &self.rules;
+        if !TARGET_SCORES.contains(&rules.target_score) {
+            bail!(
+                "invalid game length: {} (expected 5, 7, 10 or 15)",
+                rules.target_score
+            );
+        }
+        if rules.best_of % 2 != 1 {
+            bail!(
+                "invalid rubber length: {} (expected an odd number)",
+                rules.best_of
+            );
+        }
+        if self.autoplay_delay > MAX_AUTOPLAY_DELAY {
+            bail!(
+                "invalid autoplay delay: {} ms (expected at most {MAX_AUTOPLAY_DELAY})",
+                self.autoplay_delay
+            );
+        }
+        Ok(())
+    }
+
+    /// How long to show events before moving on, if they move on without a keypress.
+    pub fn autoplay_delay(&self) -> Option<Duration> {
+        (self.autoplay_delay > 0).then(|| Duration::from_millis(u64::from(self.autoplay_delay)))
+    }
+
+    /// Writes settings to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
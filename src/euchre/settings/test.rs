@@ -0,0 +1,59 @@
+use std::fs;
+
+use super::*;
+
+#[test]
+fn test_card_back() {
+    for back in CardBack::ALL {
+        assert_eq!(back.to_string().parse(), Ok(back));
+    }
+    assert_eq!("Solid".parse(), Ok(CardBack::Solid));
+    assert_eq!("plaid".parse::<CardBack>(), Err(()));
+    assert_eq!(CardBack::Shade.next(), CardBack::Light);
+    assert_eq!(CardBack::Ascii.next(), CardBack::Shade);
+}
+
+#[test]
+fn test_load_save() {
+    let dir = std::env::temp_dir().join(format!("deckard-settings-{}", std::process::id()));
+    let path = dir.join(Settings::FILE_NAME);
+    assert_eq!(Settings::load(&path).unwrap(), Settings::default());
+
+    let settings = Settings {
+        strength_trainer: true,
+        card_back: CardBack::Ascii,
+        robot: RobotKind::Search,
+        theme: Theme::Monochrome,
+        ..Settings::default()
+    };
+    settings.save(&path).unwrap();
+    assert_eq!(Settings::load(&path).unwrap(), settings);
+
+    fs::write(&path, r#"{"win_odds": false}"#).unwrap();
+    let partial = Settings::load(&path).unwrap();
+    assert!(!partial.win_odds);
+    assert!(partial.robot_autoplay);
+
+    fs::write(&path, r#"{"autoplay_delay": 60000}"#).unwrap();
+    assert!(Settings::load(&path).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_validate() {
+    let mut settings = Settings::default();
+    assert!(settings.validate().is_ok());
+    assert_eq!(settings.autoplay_delay(), None);
+
+    settings.rules.target_score = 12;
+    assert!(settings.validate().is_err());
+    settings.rules.target_score = 5;
+    settings.rules.best_of = 4;
+    assert!(settings.validate().is_err());
+    settings.rules.best_of = 3;
+    settings.autoplay_delay = 1000;
+    assert!(settings.validate().is_ok());
+    assert_eq!(settings.autoplay_delay(), Some(Duration::from_secs(1)));
+    settings.autoplay_delay = MAX_AUTOPLAY_DELAY + 1;
+    assert!(settings.validate().is_err());
+}
@@ -0,0 +1,228 @@
+//! Aggregate player statistics.
+//!
+//! Statistics are tracked per seat, and accumulated across sessions in a local stats file. They
+//! can also be gathered from an archive of round logs.
+
+use std::fmt::{Display, Write};
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::paths::create_file;
+use super::{FastRound, Log, Round, RoundError, Seat, SeatMap, TeamMap};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Statistics for a single seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SeatStats {
+    /// The number of rounds played.
+    pub deals: u32,
+    /// The number of contracts declared by this seat.
+    pub calls: u32,
+    /// The number of this seat's contracts that were euchred.
+    pub euchred: u32,
+    /// The number of this seat's contracts that were played alone.
+    pub loners: u32,
+    /// The number of lone hands in which this seat took all five tricks.
+    pub loner_marches: u32,
+    /// The number of points scored by this seat's team.
+    pub points: u32,
+}
+
+impl SeatStats {
+    /// The fraction of rounds in which this seat declared the contract.
+    pub fn call_rate(&self) -> Option<f64> {
+        ratio(self.calls, self.deals)
+    }
+
+    /// The fraction of this seat's contracts that were euchred.
+    pub fn euchre_rate(&self) -> Option<f64> {
+        ratio(self.euchred, self.calls)
+    }
+
+    /// The fraction of this seat's lone hands that took all five tricks.
+    pub fn loner_success(&self) -> Option<f64> {
+        ratio(self.loner_marches, self.loners)
+    }
+
+    /// The average number of points scored by this seat's team per round.
+    pub fn points_per_deal(&self) -> Option<f64> {
+        ratio(self.points, self.deals)
+    }
+}
+
+/// Returns the ratio of two counts, or `None` if the denominator is zero.
+fn ratio(num: u32, den: u32) -> Option<f64> {
+    (den > 0).then(|| f64::from(num) / f64::from(den))
+}
+
+/// Statistics for every seat.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Stats {
+    seats: SeatMap<SeatStats>,
+}
+
+impl Stats {
+    /// The name of the stats file, in the data directory.
+    pub const FILE_NAME: &'static str = "euchre-stats.json";
+
+    /// Loads statistics from a file. A missing file is treated as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(file)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes statistics to a file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = create_file(path)?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Returns the statistics for the specified seat.
+    pub fn seat(&self, seat: Seat) -> &SeatStats {
+        &self.seats[seat]
+    }
+
+    /// Accumulates statistics from a finished round. Rounds that are not over are ignored.
+    pub fn record_round<R: Round + ?Sized>(&mut self, round: &R) {
+        let (Some(contract), Some(outcome)) = (round.contract(), round.outcome()) else {
+            return;
+        };
+        let makers = contract.maker.team();
+        for (seat, stats) in Seat::all_seats().iter().zip(self.seats.values_mut()) {
+            stats.deals += 1;
+            if seat.team() == outcome.team {
+                stats.points += u32::from(outcome.points);
+            }
+        }
+        let maker = &mut self.seats[contract.maker];
+        maker.calls += 1;
+        if outcome.team != makers {
+            maker.euchred += 1;
+        }
+        if contract.alone {
+            maker.loners += 1;
+            if round.tricks().win_count(makers) == 5 {
+                maker.loner_marches += 1;
+            }
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pct = |r: Option<f64>| r.map_or("-".into(), |r| format!("{:.0}%", r * 100.0));
+        writeln!(
+            f,
+            "{:<6} {:>6} {:>6} {:>7} {:>7} {:>7}",
+            tr!("report-seat"),
+            tr!("stats-deals"),
+            tr!("stats-call"),
+            tr!("report-euchre"),
+            tr!("stats-alone"),
+            tr!("stats-points")
+        )?;
+        for (seat, stats) in self.seats.iter() {
+            writeln!(
+                f,
+                "{:<6} {:>6} {:>6} {:>7} {:>7} {:>7}",
+                seat.to_string(),
+                stats.deals,
+                pct(stats.call_rate()),
+                pct(stats.euchre_rate()),
+                pct(stats.loner_success()),
+                stats
+                    .points_per_deal()
+                    .map_or("-".into(), |p| format!("{p:.2}")),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Statistics aggregated over a corpus of round logs, following the main line of each.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CorpusStats {
+    /// The number of logs.
+    pub logs: u32,
+    /// The number of logs whose main line doesn't finish the round. These are left out of the
+    /// other statistics.
+    pub unfinished: u32,
+    /// The number of rounds won by each team.
+    pub wins: TeamMap<u32>,
+    /// The statistics for each seat.
+    pub stats: Stats,
+}
+
+impl CorpusStats {
+    /// Replays the main line of each log, which follows the first recorded continuation from the
+    /// deal, and accumulates the statistics of the finished rounds.
+    pub fn analyze(logs: &[Log]) -> Result<Self, RoundError> {
+        let mut corpus = Self::default();
+        for log in logs {
+            corpus.logs += 1;
+            let mut round = FastRound::from(log.config().clone());
+            if let Some(leaf) = log.first_leaf(None) {
+                for (_, action) in log.backtrace(leaf)? {
+                    round.apply_action(action)?;
+                }
+            }
+            match round.outcome() {
+                Some(outcome) => {
+                    corpus.wins[outcome.team] += 1;
+                    corpus.stats.record_round(&round);
+                }
+                None => corpus.unfinished += 1,
+            }
+        }
+        Ok(corpus)
+    }
+
+    /// Formats the statistics for each seat as CSV.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("seat,deals,calls,euchred,loners,loner_marches,points\n");
+        for (seat, stats) in self.stats.seats.iter() {
+            writeln!(
+                csv,
+                "{seat},{},{},{},{},{},{}",
+                stats.deals,
+                stats.calls,
+                stats.euchred,
+                stats.loners,
+                stats.loner_marches,
+                stats.points
+            )
+            .expect("infallible");
+        }
+        csv
+    }
+}
+
+impl Display for CorpusStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = tr!(
+            "report-logs",
+            logs = self.logs,
+            unfinished = self.unfinished
+        );
+        writeln!(f, "{text}")?;
+        let wins = self
+            .wins
+            .iter()
+            .map(|(team, n)| format!("{team} {n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(f, "{}", tr!("report-rounds-won", wins = wins))?;
+        writeln!(f)?;
+        write!(f, "{}", self.stats)
+    }
+}
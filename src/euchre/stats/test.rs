@@ -0,0 +1,84 @@
+use crate::euchre::{FastRound, LoggingRound, Player, Robot, Team};
+
+use super::*;
+
+#[test]
+fn test_record_rounds() {
+    let robot = Robot::default();
+    let mut stats = Stats::default();
+    let mut points = [0u32; 2];
+    for _ in 0..50 {
+        let mut round = FastRound::random();
+        stats.record_round(&round);
+        let outcome = round.play_out(&robot).unwrap();
+        stats.record_round(&round);
+        points[outcome.team as usize] += u32::from(outcome.points);
+    }
+
+    let calls: u32 = Seat::all_seats().iter().map(|&s| stats.seat(s).calls).sum();
+    assert_eq!(calls, 50);
+    for &seat in Seat::all_seats() {
+        let s = stats.seat(seat);
+        assert_eq!(s.deals, 50);
+        assert!(s.euchred <= s.calls);
+        assert!(s.loner_marches <= s.loners && s.loners <= s.calls);
+    }
+    assert_eq!(
+        stats.seat(Seat::North).points,
+        points[Team::NorthSouth as usize]
+    );
+    assert_eq!(
+        stats.seat(Seat::East).points,
+        points[Team::EastWest as usize]
+    );
+
+    let ser = serde_json::to_string(&stats).unwrap();
+    assert_eq!(serde_json::from_str::<Stats>(&ser).unwrap(), stats);
+}
+
+#[test]
+fn test_rates() {
+    let stats = SeatStats {
+        deals: 8,
+        calls: 4,
+        euchred: 1,
+        loners: 0,
+        loner_marches: 0,
+        points: 6,
+    };
+    assert_eq!(stats.call_rate(), Some(0.5));
+    assert_eq!(stats.euchre_rate(), Some(0.25));
+    assert_eq!(stats.loner_success(), None);
+    assert_eq!(stats.points_per_deal(), Some(0.75));
+}
+
+#[test]
+fn test_corpus_stats() {
+    let robot = Robot::default();
+    let mut logs = vec![];
+    let mut wins = [0u32; 2];
+    for _ in 0..10 {
+        let mut round = LoggingRound::random();
+        while let Some(expect) = round.next_action() {
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        wins[round.outcome().unwrap().team as usize] += 1;
+        logs.push(round.log().clone());
+    }
+    logs.push(LoggingRound::random().log().clone());
+
+    let corpus = CorpusStats::analyze(&logs).unwrap();
+    assert_eq!(corpus.logs, 11);
+    assert_eq!(corpus.unfinished, 1);
+    assert_eq!(
+        corpus.wins[Team::NorthSouth],
+        wins[Team::NorthSouth as usize]
+    );
+    assert_eq!(corpus.wins[Team::EastWest], wins[Team::EastWest as usize]);
+    assert_eq!(corpus.stats.seat(Seat::South).deals, 10);
+
+    let csv = corpus.to_csv();
+    assert_eq!(csv.lines().count(), 5);
+    assert!(csv.lines().nth(1).unwrap().starts_with("North,10,"));
+}
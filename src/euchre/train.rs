@@ -0,0 +1,157 @@
+//! Self-play training.
+//!
+//! Training hill-climbs through robot parameters. Each step nudges one of the best parameters found
+//! so far, and plays the candidate against them in duplicate, on freshly dealt boards: the
+//! candidate sits North-South and the incumbent East-West, and since every board is played both
+//! ways, neither side benefits from the cards. The candidate takes over if it wins more boards
+//! than it loses. Deals and perturbations are drawn from a seeded generator, so a run can be
+//! reproduced exactly.
+
+use std::fmt::Display;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::{Duplicate, Lineup, Robot, RobotParams, RoundError, SeatMap, Team};
+
+#[cfg(test)]
+mod test;
+
+/// The outcome of a training step.
+#[derive(Debug, Clone, Copy)]
+pub struct TrainingStep {
+    /// The step number, starting from 1.
+    pub iteration: usize,
+    /// The parameters that were tried.
+    pub candidate: RobotParams,
+    /// The number of boards on which the candidate outscored the incumbent.
+    pub won: usize,
+    /// The number of boards on which the incumbent outscored the candidate.
+    pub lost: usize,
+    /// Whether the candidate replaced the incumbent.
+    pub accepted: bool,
+}
+
+impl TrainingStep {
+    /// Returns the fraction of decided boards won by the candidate.
+    pub fn win_rate(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        match self.won + self.lost {
+            0 => 0.5,
+            decided => self.won as f64 / decided as f64,
+        }
+    }
+}
+
+impl Display for TrainingStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let p = self.candidate;
+        write!(
+            f,
+            "{:>4}  call {:>2}  loner {:>2}  margin {:>2}  won {:>3}  lost {:>3}  ({:>3.0}%){}",
+            self.iteration,
+            p.call,
+            p.loner,
+            p.dealer_margin,
+            self.won,
+            self.lost,
+            self.win_rate() * 100.0,
+            if self.accepted { "  *" } else { "" }
+        )
+    }
+}
+
+/// Trains robot parameters through seeded self-play.
+#[derive(Debug, Clone)]
+pub struct Trainer {
+    seed: u64,
+    iterations: usize,
+    boards: usize,
+}
+
+impl Default for Trainer {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            iterations: 50,
+            boards: 200,
+        }
+    }
+}
+
+impl Trainer {
+    /// Sets the seed for the deals and perturbations.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the number of candidates to try.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the number of boards on which each candidate is evaluated.
+    pub fn with_boards(mut self, boards: usize) -> Self {
+        self.boards = boards.max(1);
+        self
+    }
+
+    /// Hill-climbs from the specified parameters, reporting each step as it's taken. Returns the
+    /// best parameters found.
+    pub fn train<F: FnMut(&TrainingStep)>(
+        &self,
+        start: RobotParams,
+        mut report: F,
+    ) -> Result<RobotParams, RoundError> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut best = start;
+        for iteration in 1..=self.iterations {
+            let candidate = perturb(best, &mut rng);
+            let dup = Duplicate::new((0..self.boards).map(|_| rng.gen()).collect());
+            let incumbent = Robot::new(best).into_player();
+            let challenger = Robot::new(candidate).into_player();
+            let lineup: Lineup = SeatMap::from_fn(|seat| match seat.team() {
+                Team::NorthSouth => challenger.clone(),
+                Team::EastWest => incumbent.clone(),
+            });
+            let result = dup.play(&lineup)?;
+            let nets = result.boards().iter().map(|b| b.net(Team::NorthSouth));
+            let won = nets.clone().filter(|&n| n > 0).count();
+            let lost = nets.filter(|&n| n < 0).count();
+            let accepted = won > lost;
+            if accepted {
+                best = candidate;
+            }
+            report(&TrainingStep {
+                iteration,
+                candidate,
+                won,
+                lost,
+                accepted,
+            });
+        }
+        Ok(best)
+    }
+}
+
+/// Nudges one parameter up or down by one, keeping the loner threshold above the call threshold.
+fn perturb<R: Rng + ?Sized>(params: RobotParams, rng: &mut R) -> RobotParams {
+    let up = rng.gen_bool(0.5);
+    let nudge = |value: u8| {
+        if up {
+            value.saturating_add(1)
+        } else {
+            value.saturating_sub(1)
+        }
+    };
+    let mut params = params;
+    match rng.gen_range(0..3) {
+        0 => params.call = nudge(params.call).max(1),
+        1 => params.loner = nudge(params.loner),
+        _ => params.dealer_margin = nudge(params.dealer_margin),
+    }
+    params.loner = params.loner.max(params.call + 1);
+    params
+}
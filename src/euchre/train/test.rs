@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn test_perturb() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut params = RobotParams::default();
+    for _ in 0..100 {
+        let next = perturb(params, &mut rng);
+        assert!(next.call >= 1);
+        assert!(next.loner > next.call);
+        params = next;
+    }
+}
+
+#[test]
+fn test_train() {
+    let trainer = Trainer::default()
+        .with_seed(3)
+        .with_iterations(3)
+        .with_boards(4);
+    let mut steps = vec![];
+    let best = trainer
+        .train(RobotParams::default(), |step| steps.push(*step))
+        .unwrap();
+    assert_eq!(steps.len(), 3);
+    let last_accepted = steps.iter().rev().find(|s| s.accepted);
+    assert_eq!(
+        best,
+        last_accepted.map_or(RobotParams::default(), |s| s.candidate)
+    );
+
+    // Training is reproducible.
+    let again = trainer.train(RobotParams::default(), |_| ()).unwrap();
+    assert_eq!(best, again);
+}
@@ -1,26 +1,85 @@
 //! Trick
 
+use std::convert::TryFrom;
 use std::fmt::Display;
 
-use crate::euchre::{Card, Seat, Suit};
+use serde::{Deserialize, Serialize};
+
+use crate::euchre::{Card, CardSet, Seat, Suit};
+
+/// The most cards that can be played into a trick.
+const MAX_CARDS: usize = 4;
 
 /// A trick played during a round.
-#[derive(Debug, Clone)]
+///
+/// The effective suit of the lead, and the cards that follow it, are worked out once when the
+/// trick is led, and the best card is tracked as cards are played, so that checking a play or
+/// finding the legal cards in a hand doesn't revalue the cards already played. The cards are
+/// stored inline, so that a trick can be copied into an event without allocating.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "RawTrick", try_from = "RawTrick")]
 pub struct Trick {
     /// The trump suit for this trick.
     pub trump: Suit,
-    /// The cards that have been played into this trick.
-    pub cards: Vec<(Seat, Card)>,
+    /// The cards that have been played into this trick, of which the first `len` are valid.
+    cards: [(Seat, Card); MAX_CARDS],
+    /// The number of cards played into this trick.
+    len: u8,
     /// The index of the best card played.
     pub best: usize,
     /// The value of the best card played.
     pub best_value: u8,
+    /// The effective suit of the lead card.
+    lead_suit: Suit,
+    /// The cards that follow the lead.
+    following: CardSet,
+}
+
+/// A trick, as serialized.
+#[derive(Serialize, Deserialize)]
+struct RawTrick {
+    trump: Suit,
+    cards: Vec<(Seat, Card)>,
+    #[serde(default)]
+    best: usize,
+    #[serde(default)]
+    best_value: u8,
+}
+
+impl From<Trick> for RawTrick {
+    fn from(trick: Trick) -> Self {
+        Self {
+            trump: trick.trump,
+            cards: trick.cards().to_vec(),
+            best: trick.best,
+            best_value: trick.best_value,
+        }
+    }
+}
+
+impl TryFrom<RawTrick> for Trick {
+    type Error = &'static str;
+
+    /// Replays the cards into a new trick. The best card is worked out again, rather than
+    /// trusted.
+    fn try_from(raw: RawTrick) -> Result<Self, Self::Error> {
+        if raw.cards.len() > MAX_CARDS {
+            return Err("too many cards in trick");
+        }
+        let mut cards = raw.cards.into_iter();
+        let (leader, lead) = cards.next().ok_or("trick must be led")?;
+        let mut trick = Trick::new(raw.trump, leader, lead);
+        for (seat, card) in cards {
+            trick.play(seat, card);
+        }
+        Ok(trick)
+    }
 }
 
 impl Display for Trick {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[")?;
-        for (i, (seat, card)) in self.cards.iter().enumerate() {
+        for (i, (seat, card)) in self.cards().iter().enumerate() {
             if i != 0 {
                 write!(f, ", ")?;
             }
@@ -33,17 +92,31 @@ impl Display for Trick {
 impl Trick {
     /// Creates a new trick.
     pub fn new(trump: Suit, leader: Seat, card: Card) -> Self {
+        let lead_suit = card.effective_suit(trump);
         Self {
             trump,
-            cards: vec![(leader, card)],
+            cards: [(leader, card); MAX_CARDS],
+            len: 1,
             best: 0,
             best_value: card.value(trump, card),
+            lead_suit,
+            following: CardSet::effective_suit(lead_suit, trump),
         }
     }
 
     /// The number of cards played into this trick.
     pub fn len(&self) -> usize {
-        self.cards.len()
+        usize::from(self.len)
+    }
+
+    /// Returns true if no cards have been played into this trick.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The cards that have been played into this trick, in order.
+    pub fn cards(&self) -> &[(Seat, Card)] {
+        &self.cards[..self.len()]
     }
 
     /// The lead card.
@@ -51,47 +124,84 @@ impl Trick {
         self.cards[0]
     }
 
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump
+    }
+
+    /// The effective suit of the lead card.
+    pub fn lead_suit(&self) -> Suit {
+        self.lead_suit
+    }
+
+    /// The set of cards that follow the lead.
+    pub fn following(&self) -> CardSet {
+        self.following
+    }
+
     /// The best card.
     pub fn best(&self) -> (Seat, Card) {
         self.cards[self.best]
     }
 
+    /// Returns the value of the card, if it were played into this trick.
+    pub fn value(&self, card: Card) -> u8 {
+        card.value(self.trump, self.lead().1)
+    }
+
+    /// Returns true if the card would take the lead, if it were played into this trick.
+    pub fn beats(&self, card: Card) -> bool {
+        self.value(card) > self.best_value
+    }
+
     /// Return the specified player's card in this trick.
     pub fn get_card(&self, seat: Seat) -> Option<Card> {
-        self.cards
+        self.cards()
             .iter()
             .find_map(|(s, c)| if *s == seat { Some(*c) } else { None })
     }
 
     /// Validate that the player is following the lead suit where possible.
     pub fn is_following_lead(&self, hand: &[Card], card: Card) -> bool {
-        let lead_card = self.lead().1;
-        card.is_following(self.trump, lead_card)
-            || !hand.iter().any(|c| c.is_following(self.trump, lead_card))
+        self.following.contains(card) || !hand.iter().any(|&c| self.following.contains(c))
     }
 
-    /// Filters the hand down to the set of playable cards.
-    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
-        let following: Vec<_> = hand
-            .iter()
-            .filter(|c| c.is_following(self.trump, self.lead().1))
-            .copied()
-            .collect();
+    /// Returns the subset of the hand that may be played: the cards that follow the lead, or the
+    /// whole hand if none do.
+    pub fn legal(&self, hand: CardSet) -> CardSet {
+        let following = hand & self.following;
         if following.is_empty() {
-            hand.to_vec()
+            hand
         } else {
             following
         }
     }
 
+    /// Filters the hand down to the set of playable cards.
+    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
+        if hand.iter().any(|&c| self.following.contains(c)) {
+            hand.iter()
+                .copied()
+                .filter(|&c| self.following.contains(c))
+                .collect()
+        } else {
+            hand.to_vec()
+        }
+    }
+
     /// Plays a card into the trick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the trick already has four cards.
     pub fn play(&mut self, seat: Seat, card: Card) {
-        let card_value = card.value(self.trump, self.lead().1);
+        let card_value = self.value(card);
         if card_value > self.best_value {
             self.best_value = card_value;
-            self.best = self.cards.len();
+            self.best = self.len();
         }
-        self.cards.push((seat, card));
+        self.cards[self.len()] = (seat, card);
+        self.len += 1;
     }
 }
 
@@ -154,4 +264,49 @@ mod test {
             assert_eq!(case.expect, case.trick.best().0);
         }
     }
+
+    #[test]
+    fn test_trick_legal() {
+        let trick = trick('H', &["NJD", "EAS"]);
+        assert_eq!(trick.lead_suit(), Suit::Heart);
+        assert!(trick.following().contains("9♡".parse().unwrap()));
+        assert!(!trick.following().contains("A♢".parse().unwrap()));
+        assert!(!trick.beats("A♡".parse().unwrap()));
+        assert!(trick.beats("J♡".parse().unwrap()));
+
+        let hand: Vec<Card> = ["A♢", "9♡", "K♣"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(trick.filter(&hand), &hand[1..2]);
+        assert!(trick.is_following_lead(&hand, hand[1]));
+        assert!(!trick.is_following_lead(&hand, hand[0]));
+        let set: CardSet = hand.iter().copied().collect();
+        assert_eq!(trick.legal(set), CardSet::from(hand[1]));
+
+        let void = &[hand[0], hand[2]];
+        assert_eq!(trick.filter(void), void);
+        assert!(trick.is_following_lead(void, hand[0]));
+        let set: CardSet = void.iter().copied().collect();
+        assert_eq!(trick.legal(set), set);
+    }
+
+    #[test]
+    fn test_trick_serde() {
+        let trick = trick('S', &["N9C", "EJC", "SJS", "WAC"]);
+        let json = serde_json::to_string(&trick).unwrap();
+        assert!(!json.contains("following"), "{}", json);
+        assert!(json.contains(r#""best":2"#), "{}", json);
+        let copy: Trick = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy.best().0, Seat::South);
+        assert_eq!(copy.best_value, trick.best_value);
+        assert_eq!(copy.lead_suit(), Suit::Club);
+        assert_eq!(copy.following(), trick.following());
+
+        let result = serde_json::from_str::<Trick>(r#"{"trump":"Spade","cards":[]}"#);
+        assert!(result.is_err());
+        let json = json.replace("]]", r#"],["North","K♣"]]"#);
+        let result = serde_json::from_str::<Trick>(&json);
+        assert!(result.unwrap_err().to_string().contains("too many"));
+    }
 }
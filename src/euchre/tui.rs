@@ -1,36 +1,78 @@
 //! Rich terminal UI.
 
 use std::convert::TryFrom;
-use std::fs::File;
-use std::io::{self, stdout, Stdout};
-use std::path::Path;
+use std::fs;
+use std::io::{self, stdout, Stdout, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+use itertools::Itertools;
 use ratatui::crossterm::event::KeyCode;
 use ratatui::crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use ratatui::crossterm::{event, ExecutableCommand};
+use ratatui::layout::Flex;
 use ratatui::prelude::*;
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Block, Paragraph, Tabs};
 
 mod action;
+mod archive;
 mod arena;
+mod chat;
+mod command;
 mod hand;
 mod history;
 mod info;
+mod picker;
+mod remote;
+mod replay;
+mod results;
+mod review;
+mod save;
 mod scoreboard;
+mod settings;
+mod sheet;
+mod start;
+mod stats;
+mod summary;
+mod trainer;
+mod tutorial;
 use self::action::{ActionChoice, ActionChoiceState};
+use self::archive::{ArchiveBrowser, ArchiveBrowserState};
 use self::arena::Arena;
-use self::hand::{Hand, HandState};
+use self::command::Command;
+use self::hand::{Entry, Hand, HandState};
+pub use self::history::format_log;
 use self::history::{History, HistoryState};
-use self::info::Info;
+use self::info::{narrate, Info};
+use self::picker::{FilePicker, FilePickerState};
+pub use self::remote::RemoteTui;
+use self::replay::Replay;
+use self::results::RoundResults;
+use self::review::{Review, ReviewState};
+use self::save::{FormInput, SaveForm};
 use self::scoreboard::Scoreboard;
+use self::settings::{SettingsMenu, SettingsMenuState};
+use self::sheet::ScoreSheetView;
+use self::start::{StartChoice, StartMenu, StartMenuState};
+use self::stats::StatsScreen;
+use self::summary::GameSummary;
+use self::trainer::StrengthTrainer;
+use self::tutorial::Tutorial;
 
 use super::action::ActionData;
+use super::paths::create_file;
 use super::{
-    Action, ActionType, Event, ExpectAction, Game, LogId, LoggingRound, Player, RawLog, Robot,
-    Round, Seat,
+    action_values, add_robot_lines, expected_points, trick_odds, win_probability, Achievements,
+    Action, ActionType, Archive, CardBack, Cut, DealPattern, Event, ExpectAction, Game, LogId,
+    LoggingRound, Metadata, Paths, Player, Preset, RawGame, RawLog, RecentFiles, RenegePolicy,
+    Robot, RobotKind, RobotParams, Round, RoundConfig, Rules, ScoreSheet, SearchRobot, Seat,
+    SeatMap, Settings, SheetFormat, Stats, Suit, Theme,
 };
+use crate::engine::tui::line_with_span;
+use crate::logging;
+use crate::tr;
 
 type Term = Terminal<CrosstermBackend<Stdout>>;
 
@@ -50,6 +92,29 @@ pub fn tui_restore() -> io::Result<()> {
     Ok(())
 }
 
+/// Which of the optional panes are shown.
+#[derive(Debug, Clone, Copy)]
+struct Panes {
+    /// The history pane, beside the table.
+    history: bool,
+    /// The message area, below the table.
+    messages: bool,
+    /// The debug panel, along the bottom of the screen, which tails the log.
+    debug: bool,
+}
+impl Default for Panes {
+    fn default() -> Self {
+        Self {
+            history: true,
+            messages: true,
+            debug: false,
+        }
+    }
+}
+
+/// The height of the debug panel, including its border.
+const DEBUG_HEIGHT: u16 = 10;
+
 /// Helper struct to keep track of UI areas in the layout.
 struct Areas {
     arena: Rect,
@@ -59,27 +124,58 @@ struct Areas {
     action: Rect,
     message: Rect,
     history: Rect,
+    summary: Rect,
+    results: Rect,
+    stats: Rect,
+    sheet: Rect,
+    form: Rect,
+    picker: Rect,
+    archive: Rect,
+    start: Rect,
+    settings: Rect,
+    tutorial: Rect,
+    trainer: Rect,
+    debug: Rect,
 }
 impl Areas {
-    /// Performs layout math to figure out the render areas.
-    fn new(frame: &Frame, mode: &Mode) -> Self {
-        let [game, history] = Layout::new(
-            Direction::Horizontal,
-            [Constraint::Length(40), Constraint::Min(20)],
-        )
-        .areas(frame.area());
+    /// Performs layout math to figure out the render areas. Hidden panes are given empty areas,
+    /// and the table takes the space they would have used.
+    fn new(area: Rect, mode: &Mode, panes: Panes) -> Self {
+        let [area, debug] = if panes.debug {
+            Layout::vertical([Constraint::Min(0), Constraint::Length(DEBUG_HEIGHT)]).areas(area)
+        } else {
+            [area, Rect::default()]
+        };
+        let [game, history] = if panes.history {
+            Layout::new(
+                Direction::Horizontal,
+                [Constraint::Length(40), Constraint::Min(20)],
+            )
+            .areas(area)
+        } else {
+            [area, Rect::default()]
+        };
         let action_size = if let Mode::ActionChoice(choice, _) = mode {
             u16::try_from(choice.len()).expect("less than 2^16")
         } else {
             0
         };
+        let message_size = if panes.messages {
+            Constraint::Min(2)
+        } else {
+            Constraint::Length(0)
+        };
+        let hand_size = match mode {
+            Mode::Hand(hand, _) if hand.has_hint() => 2,
+            _ => 1,
+        };
         let [arena_score_info, hand, action, message] = Layout::new(
             Direction::Vertical,
             [
                 Constraint::Length(9),           // arena & score & info
-                Constraint::Length(1),           // hand
+                Constraint::Length(hand_size),   // hand, with an optional hint
                 Constraint::Length(action_size), // optional action
-                Constraint::Min(2),              // optional messages
+                message_size,                    // optional messages
             ],
         )
         .areas(game);
@@ -99,6 +195,24 @@ impl Areas {
             ],
         )
         .areas(score_info);
+        let summary = centered(game, GameSummary::WIDTH, GameSummary::HEIGHT);
+        let results = centered(game, RoundResults::WIDTH, RoundResults::HEIGHT);
+        let stats = centered(game, StatsScreen::WIDTH, StatsScreen::HEIGHT);
+        let sheet = centered(area, ScoreSheetView::WIDTH, ScoreSheetView::HEIGHT);
+        let form = centered(game, SaveForm::WIDTH, SaveForm::HEIGHT);
+        let picker = centered(area, FilePicker::WIDTH, FilePicker::HEIGHT);
+        let archive = centered(area, ArchiveBrowser::WIDTH, ArchiveBrowser::HEIGHT);
+        let start = centered(area, StartMenu::WIDTH, StartMenu::HEIGHT);
+        let settings = centered(area, SettingsMenu::WIDTH, SettingsMenu::HEIGHT);
+        let [_, tutorial] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(Tutorial::HEIGHT)])
+                .areas(game);
+        let trainer = Rect {
+            y: action.bottom(),
+            height: StrengthTrainer::HEIGHT,
+            ..action
+        }
+        .intersection(game);
         Self {
             arena,
             score,
@@ -107,10 +221,193 @@ impl Areas {
             action,
             message,
             history,
+            summary,
+            results,
+            stats,
+            sheet,
+            form,
+            picker,
+            archive,
+            start,
+            settings,
+            tutorial,
+            trainer,
+            debug,
+        }
+    }
+}
+
+/// Returns a rect of the specified size, centered within the area.
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Renders the table within the specified area: the arena, scoreboard, and info panes, along with
+/// the hand for the specified seat, and any mode-specific widgets. The arena is oriented with the
+/// viewer's seat at the bottom. If the game is played against the clock, the clock of the player
+/// to act runs from the start of their turn. Returns the layout, so that the caller can render
+/// additional widgets.
+#[allow(clippy::too_many_arguments)]
+fn render_table<R: Round>(
+    frame: &mut Frame,
+    area: Rect,
+    panes: Panes,
+    mode: &mut Mode,
+    game: &Game<R>,
+    seat: Option<Seat>,
+    viewer: Seat,
+    card_back: CardBack,
+    turn: Option<Instant>,
+    win_odds: Option<f64>,
+    messages: Vec<Line<'static>>,
+) -> Areas {
+    let areas = Areas::new(area, mode, panes);
+    let round = game.round();
+    let clocks = game.clock().map(|clock| {
+        let acting = round.next_action().map(|expect| expect.seat);
+        SeatMap::from_fn(|seat| match turn {
+            Some(started) if acting == Some(seat) => clock.remaining_after(seat, started.elapsed()),
+            _ => clock.remaining(seat),
+        })
+    });
+    let arena = Arena::new(mode, round)
+        .with_clocks(clocks)
+        .with_viewer(viewer)
+        .with_card_back(card_back);
+    frame.render_widget(arena, areas.arena);
+    frame.render_widget(Scoreboard::new(game).with_win_odds(win_odds), areas.score);
+    frame.render_widget(Info::new(mode, game), areas.info);
+    match mode {
+        Mode::Hand(hand, state) => {
+            frame.render_stateful_widget(hand.clone(), areas.hand, state);
+        }
+        _ => {
+            if let Some(seat) = seat {
+                let hand = round.player_state(seat).sorted_hand();
+                frame.render_widget(Hand::new(seat, hand), areas.hand);
+            }
+        }
+    }
+    if let Mode::ActionChoice(choice, state) = mode {
+        frame.render_stateful_widget(choice.clone(), areas.action, state);
+    }
+    if let Mode::History(history, state) = mode {
+        frame.render_stateful_widget(history.clone(), areas.history, state);
+    }
+    if let Mode::Review(review, state) = mode {
+        frame.render_stateful_widget(review.clone(), areas.history, state);
+    }
+    frame.render_widget(Paragraph::new(messages), areas.message);
+    if panes.debug {
+        let height = usize::from(areas.debug.height.saturating_sub(2));
+        let lines: Vec<Line> = logging::recent()
+            .tail(height)
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        let block = Block::bordered().title(tr!("ui-log"));
+        frame.render_widget(Paragraph::new(lines).block(block), areas.debug);
+    }
+    if let Mode::Event(Event::Round(outcome)) = mode {
+        if let Some(results) = RoundResults::new(game, *outcome) {
+            frame.render_widget(results, areas.results);
+        }
+    }
+    if let Mode::Event(Event::Game(outcome)) = mode {
+        frame.render_widget(GameSummary::new(*outcome), areas.summary);
+    }
+    areas
+}
+
+/// Formats error, notice, and debug messages for display.
+fn messages(error: Option<&str>, notices: &[String], debug: Option<&str>) -> Vec<Line<'static>> {
+    let mut lines = vec![];
+    if let Some(error) = error {
+        lines.push(Line::from(error.to_string()).red().bold());
+    }
+    for notice in notices {
+        lines.push(Line::from(notice.clone()).yellow().bold());
+    }
+    if let Some(debug) = debug {
+        lines.push(Line::from(debug.to_string()).blue().bold());
+    }
+    lines
+}
+
+/// Returns the mode in which to prompt the user for the expected action. Unless the rules let
+/// players renege, cards that don't follow suit are greyed out.
+fn user_action_mode<R: Round>(game: &Game<R>, expect: ExpectAction) -> Mode {
+    let round = game.round();
+    match expect.action {
+        ActionType::BidTop => Mode::action_choice(ActionChoice::bid_top(round.top_card().suit)),
+        ActionType::BidOther => Mode::action_choice(ActionChoice::bid_other(round.top_card().suit)),
+        ActionType::DealerDiscard
+        | ActionType::Lead
+        | ActionType::Follow
+        | ActionType::CallRenege => {
+            let cards = round.player_state(expect.seat).sorted_hand();
+            let mut hand = Hand::new(expect.seat, cards);
+            if let (ActionType::Follow, RenegePolicy::Reject, Some(trick)) = (
+                expect.action,
+                game.rules().renege,
+                round.tricks().in_progress(),
+            ) {
+                hand = hand.with_trick(trick);
+            }
+            Mode::hand(hand)
         }
     }
 }
 
+/// Returns true if the event is purely informational: a deal, a call, or a finished trick, which
+/// can be moved past without the user's attention.
+fn is_informational(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Deal(..) | Event::Redeal(_) | Event::Call(_) | Event::Trick(_)
+    )
+}
+
+/// The number of random deals used to value each action for a robot suggestion.
+const SUGGEST_ROLLOUTS: usize = 50;
+
+/// Returns a description of what the robot would do next, if anything, along with how many more
+/// points it expects to win than with the best alternative.
+fn robot_suggestion<R: Round>(robot: &Robot, round: &R) -> Option<String> {
+    let expect = round.next_action()?;
+    let state = round.player_state(expect.seat);
+    let data = robot.take_action(state, expect.action);
+    let suggest = data.to_string();
+    let values = action_values(round, robot, SUGGEST_ROLLOUTS, &mut rand::thread_rng());
+    let value = values.iter().find(|&&(d, _)| d == data).map(|&(_, v)| v);
+    let alternative = values
+        .iter()
+        .filter(|&&(d, _)| d != data)
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    let confidence = match (value, alternative) {
+        (Some(_), None) => format!(" ({})", tr!("ui-forced")),
+        (Some(value), Some(&(other, v))) => format!(
+            " ({})",
+            tr!(
+                "ui-versus",
+                points = format!("{:+.1}", value - v),
+                action = other.to_string()
+            )
+        ),
+        (None, _) => String::new(),
+    };
+    Some(tr!(
+        "ui-robot-suggests",
+        action = format!("{suggest}{confidence}")
+    ))
+}
+
 /// Modal interface state.
 #[derive(Debug)]
 enum Mode {
@@ -122,6 +419,10 @@ enum Mode {
     ActionChoice(ActionChoice, ActionChoiceState),
     /// Show the interactive history explorer.
     History(History, HistoryState),
+    /// Walk through a finished round, move by move.
+    Review(Review, ReviewState),
+    /// Wait for another player to act.
+    Wait,
 }
 
 impl Mode {
@@ -129,7 +430,8 @@ impl Mode {
         Self::Event(event)
     }
     fn hand(hand: Hand) -> Self {
-        Self::Hand(hand, HandState::default().with_selected(Some(0)))
+        let selected = hand.first_playable();
+        Self::Hand(hand, HandState::default().with_selected(selected))
     }
     fn action_choice(choice: ActionChoice) -> Self {
         Self::ActionChoice(choice, ActionChoiceState::default().with_selected(Some(0)))
@@ -137,54 +439,271 @@ impl Mode {
     fn history(history: History, selected: Option<usize>) -> Self {
         Self::History(history, HistoryState::default().with_selected(selected))
     }
+    fn review(review: Review) -> Self {
+        Self::Review(review, ReviewState::default().with_selected(Some(0)))
+    }
 }
 
-/// The human player's seat at the table.
+/// How long each packet of the deal is displayed.
+const PACKET_DELAY: Duration = Duration::from_millis(150);
+
+/// How long each card of a replayed trick is displayed.
+const REPLAY_DELAY: Duration = Duration::from_millis(600);
+
+/// The human player's seat at the table, unless changed with `:seat`.
 const HUMAN_SEAT: Seat = Seat::South;
 
+/// The file written by the `s` key.
+const SAVE_PATH: &str = "euchre.json";
+
+/// The file written by `:sheet`, unless another is given.
+const SHEET_PATH: &str = "euchre-scoresheet.md";
+
+/// How often the clock is redrawn, while it runs.
+const CLOCK_TICK: Duration = Duration::from_millis(200);
+
+/// The number of random deals used to estimate the odds of winning a trick.
+const ODDS_SAMPLES: usize = 200;
+
+/// The number of simulated finishes used to estimate the odds of winning the game.
+const WIN_SAMPLES: usize = 100;
+
+/// The number of random deals used to estimate the points the makers expect to win, in review.
+const EVAL_ROLLOUTS: usize = 50;
+
+/// A game held in a background tab.
+struct Tab {
+    /// The title shown in the tab bar.
+    title: String,
+    /// The game being played.
+    game: Game<LoggingRound>,
+    /// The mode in which the tab was left.
+    mode: Mode,
+    /// When the user's turn began, if the tab's clock is running.
+    turn_started: Option<Instant>,
+    /// Descriptive details about the game, for when it's saved.
+    metadata: Metadata,
+    /// The tutorial, if the game is the tutorial deal.
+    tutorial: Option<Tutorial>,
+    /// The hand-strength trainer, if the user is bidding.
+    trainer: Option<StrengthTrainer>,
+}
+
 /// Terminal UI state.
 pub struct Tui {
     /// The current mode.
     mode: Mode,
     /// The game being played.
     game: Game<LoggingRound>,
-    /// The robot implementation.
-    robot: Robot,
+    /// The title of the active tab.
+    title: String,
+    /// Descriptive details about the active tab's game, for when it's saved.
+    metadata: Metadata,
+    /// The background tabs, in order, skipping the active one.
+    tabs: Vec<Tab>,
+    /// The position of the active tab, among all tabs.
+    active: usize,
+    /// The human player's seat.
+    seat: Seat,
+    /// The robot playing each seat, when it isn't played by the user.
+    robots: SeatMap<Robot>,
+    /// Which kind of robot plays each seat. The searching robot bids with the seat's robot.
+    kinds: SeatMap<RobotKind>,
     /// Whether to auto-play as robots.
     robot_autoplay: bool,
+    /// Whether to record the robots' reasoning in the round log.
+    trace: bool,
+    /// Whether to check the round's invariants after every step.
+    paranoid: bool,
+    /// Whether to add the robot's preferred lines to the log, once a round is over.
+    robot_lines: bool,
+    /// Whether to annotate the cards in the hand with their odds of winning the trick.
+    show_odds: bool,
+    /// Whether to show the estimated odds of winning the game on the scoreboard.
+    show_win_odds: bool,
+    /// Whether to ask the user to estimate the strength of their hand before they bid.
+    strength_trainer: bool,
+    /// The estimated odds of winning the game, as of the last completed trick.
+    win_odds: Option<f64>,
+    /// When the user's turn began, for charging their time when the game is played against the
+    /// clock.
+    turn_started: Option<Instant>,
     /// An error message to display to the user.
     error: Option<String>,
     /// A debug message to display to the user.
     debug: Option<String>,
+    /// Notices to display to the user, such as newly earned achievements.
+    notices: Vec<String>,
+    /// Informational events that were batched into the screen of the current event, oldest
+    /// first.
+    batched: Vec<Event>,
+    /// Where settings and data are stored.
+    paths: Paths,
+    /// Player statistics, accumulated across sessions.
+    stats: Stats,
+    /// Whether the statistics screen is shown.
+    show_stats: bool,
+    /// Whether the score sheet is shown.
+    show_sheet: bool,
+    /// Achievements earned by the human player, accumulated across sessions.
+    achievements: Achievements,
+    /// Which of the optional panes are shown.
+    panes: Panes,
+    /// How face-down cards are drawn.
+    card_back: CardBack,
+    /// The settings, as last loaded or saved. The toggles that can also be changed in play are
+    /// kept in their own fields.
+    settings: Settings,
+    /// The rules for games started from the start menu or in a new tab.
+    new_game_rules: Rules,
+    /// A trick being replayed in the arena.
+    replay: Option<Replay>,
+    /// The save form, if it is open.
+    form: Option<SaveForm>,
+    /// The file picker, if it is open.
+    picker: Option<(FilePicker, FilePickerState)>,
+    /// The archive browser, if it is open.
+    archive: Option<(ArchiveBrowser, ArchiveBrowserState)>,
+    /// The start menu, if it is open.
+    start: Option<(StartMenu, StartMenuState)>,
+    /// The settings menu, if it is open.
+    settings_menu: Option<(SettingsMenu, SettingsMenuState)>,
+    /// The tutorial, if the active tab's game is the tutorial deal.
+    tutorial: Option<Tutorial>,
+    /// The hand-strength trainer, if the user is bidding in the active tab.
+    trainer: Option<StrengthTrainer>,
+    /// The command being entered on the command line, if it is open.
+    command: Option<String>,
     /// Set to true ot exit the main loop.
     exit: bool,
 }
 
 impl Default for Tui {
     fn default() -> Self {
-        Game::default().into()
+        Self::new_game(Rules::default())
     }
 }
 impl From<Game<LoggingRound>> for Tui {
     fn from(mut game: Game<LoggingRound>) -> Self {
         let event = game.round_mut().pop_event().expect("deal");
+        Self::new(game, Mode::Event(event))
+    }
+}
+
+impl Tui {
+    /// Creates a new [`Tui`] in the specified mode.
+    fn new(game: Game<LoggingRound>, mode: Mode) -> Self {
+        let paths = Paths::new();
+        let (settings, error) = match Settings::load(&paths.settings()) {
+            Ok(settings) => (settings, None),
+            Err(e) => {
+                let error = tr!(
+                    "ui-load-failed",
+                    path = paths.settings().display().to_string(),
+                    error = e.to_string()
+                );
+                (Settings::default(), Some(error))
+            }
+        };
         Self {
-            mode: Mode::Event(event),
+            mode,
             game,
-            robot: Robot::default(),
-            robot_autoplay: true,
-            error: None,
+            title: tr!("ui-game-tab"),
+            metadata: Metadata::default(),
+            tabs: vec![],
+            active: 0,
+            seat: HUMAN_SEAT,
+            robots: {
+                let params = RobotParams::load(&paths.robot_params()).unwrap_or_default();
+                SeatMap::from_fn(|_| Robot::new(params))
+            },
+            kinds: SeatMap::from_fn(|_| settings.robot),
+            robot_autoplay: settings.robot_autoplay,
+            trace: false,
+            paranoid: false,
+            robot_lines: false,
+            show_odds: settings.trick_odds,
+            show_win_odds: settings.win_odds,
+            strength_trainer: settings.strength_trainer,
+            win_odds: None,
+            turn_started: None,
+            error,
             debug: None,
+            notices: vec![],
+            batched: vec![],
+            stats: Stats::load(&paths.stats()).unwrap_or_default(),
+            show_stats: false,
+            show_sheet: false,
+            achievements: Achievements::load(&paths.achievements()).unwrap_or_default(),
+            paths,
+            panes: Panes::default(),
+            card_back: settings.card_back,
+            settings,
+            new_game_rules: settings.rules,
+            replay: None,
+            form: None,
+            picker: None,
+            archive: None,
+            start: None,
+            settings_menu: None,
+            tutorial: None,
+            trainer: None,
+            command: None,
             exit: false,
         }
     }
-}
 
-impl Tui {
-    /// Loads a saved round from a file.
-    pub fn from_round_file(log_path: &Path) -> anyhow::Result<Self> {
-        let log = RawLog::from_json_file(log_path)?.into_log();
-        let round = LoggingRound::from(log);
+    /// Starts a new game, with the specified rules.
+    pub fn new_game(rules: Rules) -> Self {
+        let cut: Cut = rand::random();
+        let config = RoundConfig::random_with_dealer(cut.dealer, DealPattern::default())
+            .with_renege_policy(rules.renege);
+        let game = Game::from(LoggingRound::from(config)).with_rules(rules);
+        let mut tui = Self::new(game, Mode::event(Event::Cut(cut.into())));
+        tui.new_game_rules = rules;
+        tui
+    }
+
+    /// Starts the tutorial: a scripted deal, played as a single-round game, with prompts that
+    /// explain the rules as it's played.
+    pub fn tutorial() -> Self {
+        let game = Game::from(LoggingRound::from(Tutorial::config())).with_target_score(1);
+        let mut tui = Self::from(game);
+        tui.title = tr!("tutorial-title");
+        tui.tutorial = Some(Tutorial::default());
+        tui
+    }
+
+    /// Loads a saved game or round from a file.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let mut tui = match RawGame::from_json_file(path) {
+            Ok(game) => Self::from_raw_game(game)?,
+            Err(_) => Self::from_raw_log(RawLog::from_json_file(path)?)?,
+        };
+        if let Some(name) = path.file_name() {
+            tui.title = name.to_string_lossy().into_owned();
+        }
+        tui.remember_file(path);
+        Ok(tui)
+    }
+
+    /// Resumes a saved game.
+    fn from_raw_game(game: RawGame) -> anyhow::Result<Self> {
+        let metadata = game.metadata().cloned().unwrap_or_default();
+        let mut game = game.into_game()?;
+        // Drop events that were replayed while restoring the round.
+        while game.round_mut().pop_event().is_some() {}
+        let round = game.round();
+        let deal = Event::Deal(round.dealer(), round.top_card());
+        let mut tui = Self::new(game, Mode::event(deal));
+        tui.metadata = metadata;
+        tui.game_step();
+        Ok(tui)
+    }
+
+    /// Loads a saved round, to be played as a single-round game.
+    fn from_raw_log(log: RawLog) -> anyhow::Result<Self> {
+        let round = LoggingRound::from(log.into_log());
         let game = Game::from(round).with_target_score(1);
         Ok(game.into())
     }
@@ -193,60 +712,123 @@ impl Tui {
     pub fn run(mut self, mut terminal: Term) -> anyhow::Result<()> {
         while !self.exit {
             terminal.draw(|frame| self.render_frame(frame))?;
+            let error = self.error.clone();
             self.handle_events()?;
+            if let Some(error) = self.error.as_ref().filter(|&e| Some(e) != error.as_ref()) {
+                tracing::warn!("{error}");
+            }
         }
+        self.autosave();
         Ok(())
     }
 
     // Top-level frame renderer.
     fn render_frame(&mut self, frame: &mut Frame) {
-        let areas = Areas::new(frame, &self.mode);
-        let round = self.game.round();
-        frame.render_widget(Arena::new(&self.mode, round), areas.arena);
-        frame.render_widget(Scoreboard::new(&self.game), areas.score);
-        frame.render_widget(Info::new(&self.mode, &self.game), areas.info);
-        match &mut self.mode {
-            Mode::Hand(hand, state) => {
-                frame.render_stateful_widget(hand.clone(), areas.hand, state);
-            }
-            Mode::ActionChoice(_, _) => {
-                let seat = round.next_action().map_or(HUMAN_SEAT, |e| e.seat);
-                self.render_hand_for_seat(seat, frame, areas.hand);
-            }
-            Mode::History(_, _) => self.render_current_hand(frame, areas.hand),
-            Mode::Event(_) => (),
+        let next_seat = self.game.round().next_action().map(|expect| expect.seat);
+        let seat = match self.mode {
+            Mode::ActionChoice(_, _) => Some(next_seat.unwrap_or(self.seat)),
+            Mode::History(_, _) | Mode::Review(_, _) => next_seat,
+            _ => None,
+        };
+        let mut messages = messages(self.error.as_deref(), &self.notices, self.debug.as_deref());
+        let batched = self.batched.iter().map(|event| narrate(event).dim());
+        messages.splice(0..0, batched);
+        if let Some(command) = &self.command {
+            messages.insert(0, Line::from(format!(":{command}")));
         }
-        if let Mode::ActionChoice(choice, state) = &mut self.mode {
-            frame.render_stateful_widget(choice.clone(), areas.action, state);
+        let mut area = frame.area();
+        if !self.tabs.is_empty() {
+            let [bar, rest] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+            frame.render_widget(self.tab_bar(), bar);
+            area = rest;
         }
-        if let Mode::History(history, state) = &mut self.mode {
-            frame.render_stateful_widget(history.clone(), areas.history, state);
+        let areas = render_table(
+            frame,
+            area,
+            self.panes,
+            &mut self.mode,
+            &self.game,
+            seat,
+            self.seat,
+            self.card_back,
+            self.turn_started,
+            self.win_odds,
+            messages,
+        );
+        if let Some(replay) = &self.replay {
+            let arena = Arena::with_cards(replay.cards()).with_viewer(self.seat);
+            frame.render_widget(arena, areas.arena);
         }
-        let mut lines = vec![];
-        if let Some(error) = self.error.clone() {
-            lines.push(Line::from(error).red().bold());
+        if let Some(form) = &self.form {
+            frame.render_widget(form, areas.form);
         }
-        if let Some(debug) = self.debug.clone() {
-            lines.push(Line::from(debug).blue().bold());
+        if let Some((picker, state)) = &mut self.picker {
+            frame.render_stateful_widget(&*picker, areas.picker, state);
         }
-        frame.render_widget(Paragraph::new(lines), areas.message);
-    }
-
-    /// Renders the current player's hand.
-    fn render_current_hand(&self, frame: &mut Frame, area: Rect) {
-        if let Some(seat) = self.game.round().next_action().map(|expect| expect.seat) {
-            self.render_hand_for_seat(seat, frame, area);
+        if self.show_stats {
+            frame.render_widget(StatsScreen::new(&self.stats), areas.stats);
+        }
+        if self.show_sheet {
+            let sheet = ScoreSheet::new(&self.game);
+            frame.render_widget(ScoreSheetView::new(&sheet), areas.sheet);
+        }
+        if let Some((browser, state)) = &mut self.archive {
+            frame.render_stateful_widget(&*browser, areas.archive, state);
+        }
+        if let Some((menu, state)) = &mut self.start {
+            frame.render_stateful_widget(&*menu, areas.start, state);
+        }
+        if let Some((menu, state)) = &mut self.settings_menu {
+            frame.render_stateful_widget(&*menu, areas.settings, state);
+        }
+        if let Some(tutorial) = &self.tutorial {
+            frame.render_widget(tutorial, areas.tutorial);
+        }
+        if let (Some(trainer), Mode::ActionChoice(_, _)) = (&self.trainer, &self.mode) {
+            frame.render_widget(trainer, areas.trainer);
+        }
+        if self.settings.theme == Theme::Monochrome {
+            for cell in &mut frame.buffer_mut().content {
+                cell.set_fg(Color::Reset).set_bg(Color::Reset);
+            }
         }
-    }
-
-    /// Renders the hand for the specified player.
-    fn render_hand_for_seat(&self, seat: Seat, frame: &mut Frame, area: Rect) {
-        let hand = self.game.round().player_state(seat).sorted_hand();
-        frame.render_widget(Hand::new(seat, hand), area);
     }
 
     /// Top-level event handler.
     fn handle_events(&mut self) -> io::Result<()> {
+        // The deal is animated, advancing automatically unless a key is pressed.
+        if matches!(self.mode, Mode::Event(Event::Packet(_, _))) && !event::poll(PACKET_DELAY)? {
+            self.game_step();
+            return Ok(());
+        }
+
+        // A replayed trick advances automatically, until a key is pressed.
+        if let Some(replay) = &mut self.replay {
+            if !event::poll(REPLAY_DELAY)? {
+                if !replay.advance() {
+                    self.replay = None;
+                }
+                return Ok(());
+            }
+            self.replay = None;
+        }
+
+        // While robots are playing, events move on by themselves after the autoplay delay, unless
+        // a key is pressed.
+        if let Some(delay) = self.autoplay_delay() {
+            if !event::poll(delay)? {
+                self.game_step();
+                return Ok(());
+            }
+        }
+
+        // The clock runs, until a key is pressed.
+        if self.turn_started.is_some() && self.game.clock().is_some() && !event::poll(CLOCK_TICK)? {
+            self.out_of_time();
+            return Ok(());
+        }
+
         let event::Event::Key(key) = event::read()? else {
             return Ok(());
         };
@@ -254,21 +836,219 @@ impl Tui {
         // Output messages only persist for one refresh cycle.
         self.error = None;
         self.debug = None;
+        self.notices.clear();
+
+        // The statistics screen and score sheet are dismissed by any key.
+        if self.show_stats || self.show_sheet {
+            self.show_stats = false;
+            self.show_sheet = false;
+            return Ok(());
+        }
+
+        // The settings menu takes all input while it is open, and saves the settings on closing.
+        if let Some((menu, state)) = &mut self.settings_menu {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    let settings = menu.settings();
+                    self.settings_menu = None;
+                    self.save_settings(settings);
+                }
+                KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                KeyCode::Enter | KeyCode::Char(' ') => menu.change(state),
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        // As does the start menu.
+        if let Some((menu, state)) = &mut self.start {
+            let choice = match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.select_previous();
+                    None
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.select_next();
+                    None
+                }
+                KeyCode::Enter | KeyCode::Char(' ') => menu.selected(state),
+                KeyCode::Esc => Some(StartChoice::NewGame),
+                KeyCode::Char(c) => menu.shortcut(c),
+                _ => None,
+            };
+            if let Some(choice) = choice {
+                self.choose_start(choice);
+            }
+            return Ok(());
+        }
+
+        // The command line takes all input while it is open.
+        if let Some(command) = &mut self.command {
+            match key.code {
+                KeyCode::Esc => self.command = None,
+                KeyCode::Backspace if command.is_empty() => self.command = None,
+                KeyCode::Backspace => {
+                    command.pop();
+                }
+                KeyCode::Enter => {
+                    let line = std::mem::take(command);
+                    self.command = None;
+                    match line.parse() {
+                        Ok(command) => self.run_command(command),
+                        Err(e) => self.error = Some(e),
+                    }
+                }
+                KeyCode::Char(c) => command.push(c),
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        // The save form takes all input while it is open.
+        if let Some(form) = &mut self.form {
+            match form.handle_key(key.code) {
+                FormInput::Editing => (),
+                FormInput::Cancel => self.form = None,
+                FormInput::Submit => {
+                    let (path, metadata) = form.values();
+                    self.form = None;
+                    self.metadata = metadata;
+                    self.save_game(&path);
+                }
+            }
+            return Ok(());
+        }
+
+        // So does the file picker.
+        if let Some((picker, state)) = &mut self.picker {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.picker = None,
+                KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    let path = picker.selected(state).map(Path::to_path_buf);
+                    self.picker = None;
+                    if let Some(path) = path {
+                        self.load_game(&path);
+                    }
+                }
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        // And the archive browser.
+        if let Some((browser, state)) = &mut self.archive {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.archive = None,
+                KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    let path = browser.selected(state).map(Path::to_path_buf);
+                    self.archive = None;
+                    if let Some(path) = path {
+                        self.review_archived(&path);
+                    }
+                }
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        // The strength trainer takes the user's estimate before they bid.
+        if let (Some(trainer), Mode::ActionChoice(_, _)) = (&mut self.trainer, &self.mode) {
+            if !trainer.is_answered() {
+                match key.code {
+                    KeyCode::Esc => self.trainer = None,
+                    KeyCode::Char(c) if trainer.answer(c) => (),
+                    _ => self.error = Some(tr!("ui-trainer-pending")),
+                }
+                return Ok(());
+            }
+        }
+
+        // Card notation takes precedence over other bindings in the hand.
+        if let Mode::Hand(hand, state) = &mut self.mode {
+            match hand.type_key(key.code) {
+                Entry::Ignored => (),
+                Entry::Rank(_) => return Ok(()),
+                Entry::Card(index) => {
+                    state.select(Some(index));
+                    let expect = self.game.round().next_action();
+                    if let Some(action) = hand.action(state, expect) {
+                        self.apply_action(action);
+                    }
+                    return Ok(());
+                }
+                Entry::Unplayable(card) => {
+                    let suit = hand.lead_suit().map(|s| s.to_string()).unwrap_or_default();
+                    self.error = Some(tr!("ui-cannot-play", card = card.to_string(), suit = suit));
+                    return Ok(());
+                }
+                Entry::Missing(card) => {
+                    self.error = Some(tr!("ui-card-not-in-hand", card = card.to_string()));
+                    return Ok(());
+                }
+            }
+        }
 
         #[allow(clippy::match_same_arms)]
         match (&mut self.mode, key.code) {
             // Quit, or exit history
             (Mode::History(_, _), KeyCode::Char('!' | 'q')) => self.game_step(),
+            (Mode::Review(review, _), KeyCode::Char('v' | 'q')) => {
+                let end = review.end();
+                self.seek_round_history(end);
+                self.game_step();
+            }
             (_, KeyCode::Char('q')) => self.exit = true,
 
-            // End of game
+            // Switch tabs
+            (_, KeyCode::Tab) => self.switch_tab((self.active + 1) % (self.tabs.len() + 1)),
+            (_, KeyCode::BackTab) => {
+                self.switch_tab((self.active + self.tabs.len()) % (self.tabs.len() + 1));
+            }
+            (_, KeyCode::Char(c @ '1'..='9')) => {
+                self.switch_tab(c.to_digit(10).expect("digit") as usize - 1);
+            }
+
+            // End of game, and on to the next, unless the rubber is over
+            (Mode::Event(Event::Game(outcome)), _) if outcome.rubber.winner().is_none() => {
+                self.game.next_game();
+                self.game_step();
+            }
             (Mode::Event(Event::Game(_)), _) => (),
 
             // Enter history mode
             (_, KeyCode::Char('!')) => self.enter_history_mode(),
 
-            // Save the game log
-            (_, KeyCode::Char('s')) => self.save_round(),
+            // Review the round, once it's over
+            (_, KeyCode::Char('v')) if self.game.round().outcome().is_some() => {
+                self.enter_review_mode();
+            }
+
+            // Save the game
+            (_, KeyCode::Char('s')) => {
+                self.form = Some(SaveForm::new(Path::new(SAVE_PATH), &self.metadata));
+            }
+
+            // Show or hide panes
+            (_, KeyCode::Char('H')) => self.panes.history = !self.panes.history,
+            (_, KeyCode::Char('M')) => self.panes.messages = !self.panes.messages,
+            (_, KeyCode::Char('D')) => self.panes.debug = !self.panes.debug,
+
+            // Open the command line
+            (_, KeyCode::Char(':')) => self.command = Some(String::new()),
+
+            // Show statistics
+            (_, KeyCode::Char('%')) => self.show_stats = true,
+
+            // Show the score sheet.
+            (_, KeyCode::Char('#')) => self.show_sheet = true,
+
+            // Browse finished games
+            (_, KeyCode::Char('A')) => self.open_archive(),
 
             // What would the robot do?
             (Mode::Hand(_, _) | Mode::ActionChoice(_, _), KeyCode::Char('?')) => self.ask_robot(),
@@ -276,6 +1056,18 @@ impl Tui {
             // Toggle robot autoplay
             (_, KeyCode::Char('@')) => self.toggle_robot_autoplay(),
 
+            // Toggle the odds of winning the trick
+            (_, KeyCode::Char('o')) => self.toggle_odds(),
+
+            // Toggle the odds of winning the game
+            (_, KeyCode::Char('w')) => self.toggle_win_odds(),
+
+            // Toggle the hand-strength trainer
+            (_, KeyCode::Char('z')) => self.toggle_strength_trainer(),
+
+            // Call a renege
+            (_, KeyCode::Char('r')) => self.call_renege(),
+
             // Event acknowledgement
             (Mode::Event(Event::Round(_)), _) => self.next_round(),
             (Mode::Event(_), _) => self.game_step(),
@@ -287,8 +1079,8 @@ impl Tui {
                     self.apply_action(action);
                 }
             }
-            (Mode::Hand(_, s), KeyCode::Left | KeyCode::Char('h')) => s.select_previous(),
-            (Mode::Hand(_, s), KeyCode::Right | KeyCode::Char('l')) => s.select_next(),
+            (Mode::Hand(hand, s), KeyCode::Left | KeyCode::Char('h')) => hand.select_previous(s),
+            (Mode::Hand(hand, s), KeyCode::Right | KeyCode::Char('l')) => hand.select_next(s),
 
             // Action choices
             (Mode::ActionChoice(choice, state), KeyCode::Enter | KeyCode::Char(' ')) => {
@@ -307,6 +1099,11 @@ impl Tui {
                     self.game_step();
                 }
             }
+            (Mode::History(history, _), KeyCode::Char('g')) => history.toggle_graph(),
+            (Mode::History(history, state), KeyCode::Char('p')) => {
+                let id = history.selected_action(state);
+                self.replay_trick(id);
+            }
             (Mode::History(history, state), KeyCode::Up | KeyCode::Char('k')) => {
                 state.select_previous();
                 if let Some(id) = history.selected(state) {
@@ -320,6 +1117,27 @@ impl Tui {
                 }
             }
 
+            // Round review
+            (Mode::Review(review, state), KeyCode::Enter | KeyCode::Char(' ')) => {
+                if let Some(id) = review.selected(state) {
+                    self.branch_from_review(id);
+                }
+            }
+            (Mode::Review(review, state), KeyCode::Up | KeyCode::Char('k')) => {
+                state.select_previous();
+                if let Some(id) = review.selected(state) {
+                    self.seek_round_history(id);
+                    self.evaluate_review_position();
+                }
+            }
+            (Mode::Review(review, state), KeyCode::Down | KeyCode::Char('j')) => {
+                state.select_next();
+                if let Some(id) = review.selected(state) {
+                    self.seek_round_history(id);
+                    self.evaluate_review_position();
+                }
+            }
+
             _ => (),
         }
 
@@ -328,9 +1146,16 @@ impl Tui {
 
     /// Starts the next round of the game, and checks to see if the game is over.
     fn next_round(&mut self) {
+        // The tutorial is practice, and doesn't count.
+        if self.tutorial.is_none() {
+            self.record_stats();
+            self.record_achievements();
+            self.archive_game();
+        }
         self.game.next_round();
-        if let Some(team) = self.game.winner() {
-            self.mode = Mode::event(Event::Game(team));
+        self.autosave();
+        if let Some(outcome) = self.game.outcome() {
+            self.mode = Mode::event(Event::Game(outcome));
         } else {
             self.game_step();
         }
@@ -340,9 +1165,31 @@ impl Tui {
     /// blocked waiting on a non-robot player's action. Internally takes care
     /// of advancing to the next round, if the game is not over.
     fn game_step(&mut self) {
+        self.batched.clear();
+        let batch = self.settings.batch_events && self.tutorial.is_none();
+        // With batching, an informational event is held back until the next event or action
+        // that needs the user, and then shown on one screen with the others.
+        let mut held: Option<Event> = None;
         loop {
+            if self.paranoid {
+                if let Err(err) = self.game.round().validate_invariants() {
+                    self.error = Some(err.to_string());
+                }
+            }
+
             // Drain events.
             if let Some(event) = self.game.round_mut().pop_event() {
+                if matches!(event, Event::Deal(_, _) | Event::Trick(_)) {
+                    self.estimate_win_odds();
+                }
+                if matches!(event, Event::Round(_)) && self.robot_lines {
+                    self.add_robot_lines();
+                }
+                self.batched.extend(held.take());
+                if batch && is_informational(&event) {
+                    held = Some(event);
+                    continue;
+                }
                 self.mode = Mode::event(event);
                 break;
             }
@@ -350,67 +1197,245 @@ impl Tui {
             // We may have missed the end-of-round event, because we dropped events in
             // `seek_round_history`. Generate a synthetic event.
             if let Some(outcome) = self.game.round().outcome() {
+                self.batched.extend(held.take());
                 self.mode = Mode::event(Event::Round(outcome));
                 break;
             }
 
             // Handle round actions.
             if let Some(expect) = self.game.round().next_action() {
-                if expect.seat == HUMAN_SEAT || !self.robot_autoplay {
-                    self.await_user_action(expect);
+                if expect.seat == self.seat || !self.robot_autoplay {
+                    match held.take() {
+                        Some(event) => self.mode = Mode::event(event),
+                        None => self.await_user_action(expect),
+                    }
                     break;
                 }
                 self.play_as_robot(expect);
             }
         }
+        if let Some(tutorial) = &mut self.tutorial {
+            tutorial.update(self.game.round(), self.seat);
+        }
     }
 
     /// Applies the specified action to the game and updates the mode.
     fn apply_action(&mut self, action: Action) {
+        if self.out_of_time() {
+            return;
+        }
         if let Err(err) = self.game.round_mut().apply_action(action) {
             self.error = Some(err.to_string());
         } else {
+            self.trainer = None;
+            if let Some(started) = self.turn_started.take() {
+                let elapsed = started.elapsed();
+                self.game.charge_time(action.seat, elapsed);
+                self.game.round_mut().record_think_time(elapsed);
+            }
             self.game_step();
         }
     }
 
-    /// Updates the UI mode to await user input for an action.
+    /// Forfeits the game if the player to act has run out of time, and returns true. Otherwise,
+    /// returns false.
+    fn out_of_time(&mut self) -> bool {
+        let (Some(started), Some(clock), Some(expect)) = (
+            self.turn_started,
+            self.game.clock(),
+            self.game.round().next_action(),
+        ) else {
+            return false;
+        };
+        let elapsed = started.elapsed();
+        if !clock.remaining_after(expect.seat, elapsed).is_zero() {
+            return false;
+        }
+        self.turn_started = None;
+        self.game.charge_time(expect.seat, elapsed);
+        self.autosave();
+        if let Some(outcome) = self.game.outcome() {
+            self.mode = Mode::event(Event::Game(outcome));
+        }
+        true
+    }
+
+    /// Updates the UI mode to await user input for an action, and starts the clock.
     fn await_user_action(&mut self, expect: ExpectAction) {
-        self.mode = match expect.action {
-            ActionType::BidTop => {
-                let top_suit = self.game.round().top_card().suit;
-                Mode::action_choice(ActionChoice::bid_top(top_suit))
-            }
-            ActionType::BidOther => {
-                let top_suit = self.game.round().top_card().suit;
-                Mode::action_choice(ActionChoice::bid_other(top_suit))
-            }
-            ActionType::DealerDiscard | ActionType::Lead | ActionType::Follow => {
-                let cards = self.game.round().player_state(expect.seat).sorted_hand();
-                Mode::hand(Hand::new(expect.seat, cards))
+        self.turn_started.get_or_insert_with(Instant::now);
+        self.mode = user_action_mode(&self.game, expect);
+        if self.show_odds {
+            self.annotate_odds();
+        }
+        self.start_strength_trainer(expect);
+    }
+
+    /// Toggles the hand-strength trainer.
+    fn toggle_strength_trainer(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
+        self.strength_trainer = !self.strength_trainer;
+        self.trainer = None;
+        if let Some(expect) = self.game.round().next_action() {
+            if matches!(self.mode, Mode::ActionChoice(_, _)) {
+                self.start_strength_trainer(expect);
             }
+        }
+        self.debug = Some(if self.strength_trainer {
+            tr!("ui-trainer-enabled")
+        } else {
+            tr!("ui-trainer-disabled")
+        });
+    }
+
+    /// Asks the user to estimate the strength of their hand, if the trainer is enabled and they're
+    /// about to bid.
+    fn start_strength_trainer(&mut self, expect: ExpectAction) {
+        if !self.strength_trainer || self.trainer.is_some() || self.game.assists_locked() {
+            return;
+        }
+        let round = self.game.round();
+        let top = round.top_card().suit;
+        let suits: Vec<Suit> = match expect.action {
+            ActionType::BidTop => vec![top],
+            ActionType::BidOther => Suit::all_suits()
+                .iter()
+                .copied()
+                .filter(|&s| s != top)
+                .collect(),
+            _ => return,
         };
+        let state = round.player_state(expect.seat);
+        let params = self.robots[expect.seat].params();
+        self.trainer = StrengthTrainer::new(state.hand, &suits, &params);
+    }
+
+    /// Toggles the annotation of each card in the hand with its odds of winning the trick.
+    fn toggle_odds(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
+        self.show_odds = !self.show_odds;
+        if self.show_odds {
+            self.annotate_odds();
+        } else if let Mode::Hand(hand, _) = &mut self.mode {
+            hand.set_odds(vec![]);
+        }
+        self.debug = Some(if self.show_odds {
+            tr!("ui-odds-shown")
+        } else {
+            tr!("ui-odds-hidden")
+        });
+    }
+
+    /// Estimates the odds of winning the trick with each card in the hand, if a card is to be led
+    /// or followed.
+    fn annotate_odds(&mut self) {
+        let round = self.game.round();
+        if let (Mode::Hand(hand, _), Some(expect)) = (&mut self.mode, round.next_action()) {
+            let state = round.player_state(expect.seat);
+            let mut rng = rand::thread_rng();
+            if let Some(odds) = trick_odds(
+                &state,
+                expect.action,
+                &self.robots[expect.seat],
+                ODDS_SAMPLES,
+                &mut rng,
+            ) {
+                hand.set_odds(odds);
+            }
+        }
+    }
+
+    /// Toggles the estimated odds of winning the game on the scoreboard.
+    fn toggle_win_odds(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
+        self.show_win_odds = !self.show_win_odds;
+        self.estimate_win_odds();
+        self.debug = Some(if self.show_win_odds {
+            tr!("ui-win-odds-shown")
+        } else {
+            tr!("ui-win-odds-hidden")
+        });
+    }
+
+    /// Estimates the odds of the user's team winning the game, if they're shown.
+    fn estimate_win_odds(&mut self) {
+        self.win_odds = None;
+        if self.show_win_odds && !self.game.assists_locked() {
+            let state = self.game.round().player_state(self.seat);
+            let mut rng = rand::thread_rng();
+            self.win_odds = win_probability(
+                &self.game,
+                &state,
+                &self.robots[self.seat],
+                WIN_SAMPLES,
+                &mut rng,
+            );
+        }
     }
 
     /// Asks what the robot would do, displaying the result as a debug message.
     fn ask_robot(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
         let round = self.game.round();
-        if let Some(expect) = round.next_action() {
-            let state = round.player_state(expect.seat);
-            let data = self.robot.take_action(state, expect.action);
-            let suggest = match data {
-                ActionData::Pass => "Pass".into(),
-                ActionData::Call { suit, alone: false } => format!("Call {suit}"),
-                ActionData::Call { suit, alone: true } => format!("Call {suit} alone"),
-                ActionData::Card { card } => card.to_string(),
-            };
-            self.debug = Some(format!("Robot suggests: {suggest}"));
+        let seat = round.next_action().map_or(self.seat, |expect| expect.seat);
+        self.debug = robot_suggestion(&self.robots[seat], round);
+    }
+
+    /// Sets the bidding style of the robot at the specified seat.
+    pub fn set_preset(&mut self, seat: Seat, preset: Preset) {
+        self.robots[seat] = Robot::new(preset.params());
+    }
+
+    /// Sets which kind of robot plays the specified seat.
+    pub fn set_robot_kind(&mut self, seat: Seat, kind: RobotKind) {
+        self.kinds[seat] = kind;
+    }
+
+    /// Enables or disables recording the robots' reasoning in the round log, for review.
+    pub fn set_trace(&mut self, enabled: bool) {
+        self.trace = enabled;
+    }
+
+    /// Enables or disables checking the round's invariants after every step of play. Violations
+    /// are reported as errors.
+    pub fn set_paranoid(&mut self, enabled: bool) {
+        self.paranoid = enabled;
+    }
+
+    /// Enables or disables adding the line the robot prefers at each of the user's decisions to
+    /// the log, once a round is over.
+    pub fn set_robot_lines(&mut self, enabled: bool) {
+        self.robot_lines = enabled;
+    }
+
+    /// Adds the line the robot prefers at each of the user's decisions on the main line of the
+    /// round, as branches to explore in the history browser.
+    fn add_robot_lines(&mut self) {
+        let robot = &self.robots[self.seat];
+        let log = self.game.round_mut().log_mut();
+        let mut rng = rand::thread_rng();
+        match add_robot_lines(log, self.seat, robot, SUGGEST_ROLLOUTS, &mut rng) {
+            Ok(0) => (),
+            Ok(count) => self.debug = Some(tr!("ui-robot-lines", count = count)),
+            Err(e) => self.error = Some(e.to_string()),
         }
     }
 
     /// Toggle robot autoplay.
     fn toggle_robot_autoplay(&mut self) {
-        self.robot_autoplay = !self.robot_autoplay;
+        self.set_robot_autoplay(!self.robot_autoplay);
+    }
+
+    /// Enables or disables robot autoplay.
+    fn set_robot_autoplay(&mut self, enabled: bool) {
+        self.robot_autoplay = enabled;
 
         // If we're currently waiting for the user to take action on behalf of a robot player,
         // advance the state machine automatically.
@@ -418,32 +1443,167 @@ impl Tui {
             self.game_step();
         }
 
-        self.debug = Some(format!(
-            "Robot autoplay {}",
-            if self.robot_autoplay {
-                "enabled"
-            } else {
-                "disabled"
-            }
-        ));
+        self.debug = Some(if self.robot_autoplay {
+            tr!("ui-autoplay-enabled")
+        } else {
+            tr!("ui-autoplay-disabled")
+        });
     }
 
     /// Uses the robot to resolve the next action.
     fn play_as_robot(&mut self, expect: ExpectAction) {
         let round = self.game.round_mut();
+
+        // Call any renege that an opponent has exposed, before taking the expected action.
         let state = round.player_state(expect.seat);
-        let data = self.robot.take_action(state, expect.action);
+        if let data @ ActionData::Card { .. } =
+            self.robots[expect.seat].take_action(state, ActionType::CallRenege)
+        {
+            let action = Action::new(expect.seat, ActionType::CallRenege, data);
+            tracing::info!("{} robot called a renege: {data}", expect.seat);
+            round.apply_action(action).expect("robots don't err");
+            return;
+        }
+
+        let state = round.player_state(expect.seat);
+        let robot = &self.robots[expect.seat];
+        let started = Instant::now();
+        let (data, trace) = match self.kinds[expect.seat] {
+            RobotKind::Search => {
+                let searcher = SearchRobot::from(Robot::new(robot.params()));
+                (searcher.take_action(state, expect.action), None)
+            }
+            RobotKind::Heuristic if self.trace => robot.take_traced_action(state, expect.action),
+            RobotKind::Heuristic => (robot.take_action(state, expect.action), None),
+        };
+        tracing::info!("{} robot: {} {data}", expect.seat, expect.action);
+        if let Some(trace) = &trace {
+            let scores = trace.scores.iter().map(|(d, score)| format!("{d} {score}"));
+            tracing::debug!("{} robot considered {}", expect.seat, scores.format(", "));
+        }
         let action = expect.with_data(data);
-        round.apply_action(action).expect("robots don't err");
+        match trace {
+            Some(trace) => round.apply_traced_action(action, trace),
+            None => round.apply_action(action),
+        }
+        .expect("robots don't err");
+        round.record_think_time(started.elapsed());
+    }
+
+    /// Calls a renege on behalf of the human player, if an opponent has exposed one.
+    fn call_renege(&mut self) {
+        let round = self.game.round();
+        if round.next_action().is_none() {
+            return;
+        }
+        let revoke = round
+            .tricks()
+            .exposed_revokes()
+            .find(|r| r.seat.team() != self.seat.team());
+        match revoke {
+            Some(revoke) => {
+                let data = ActionData::Card { card: revoke.card };
+                self.apply_action(Action::new(self.seat, ActionType::CallRenege, data));
+            }
+            None => self.error = Some(tr!("ui-no-renege-exposed")),
+        }
+    }
+
+    /// Returns true, and explains why, if the game is competitive and the history, seeking, and
+    /// robot hints are locked until it's over.
+    fn assists_locked(&mut self) -> bool {
+        let locked = self.game.assists_locked();
+        if locked {
+            self.error = Some(tr!("ui-competitive-locked"));
+        }
+        locked
     }
 
     /// Enters history browser mode.
     fn enter_history_mode(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
         let round = self.game.round();
         let cursor = round.cursor();
         let history = History::new(cursor, round.log());
         let index = history.cursor_position();
         self.mode = Mode::history(history, index);
+        self.panes.history = true;
+    }
+
+    /// Enters review mode, starting from the deal.
+    pub fn enter_review_mode(&mut self) {
+        if self.assists_locked() {
+            return;
+        }
+        let round = self.game.round();
+        match Review::new(round.cursor(), round.log(), &self.robots[self.seat]) {
+            Ok(review) => {
+                self.seek_round_history(None);
+                self.mode = Mode::review(review);
+                self.panes.history = true;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    /// Estimates the points the makers expect to win from the position under review, caching the
+    /// estimate in the round log so that it's only computed once.
+    fn evaluate_review_position(&mut self) {
+        if let Mode::Review(review, _) = &mut self.mode {
+            review.set_expected(None);
+        } else {
+            return;
+        }
+        let round = self.game.round();
+        let (Some(id), Some(contract)) = (round.cursor(), round.contract()) else {
+            return;
+        };
+        let team = contract.maker.team();
+        let value = match round.log().node(id).and_then(|node| node.eval) {
+            Some(value) => value,
+            None => {
+                let mut rng = rand::thread_rng();
+                let robot = &self.robots[self.seat];
+                let Some(value) = expected_points(round, team, robot, EVAL_ROLLOUTS, &mut rng)
+                else {
+                    return;
+                };
+                if let Err(e) = self.game.round_mut().log_mut().set_eval(id, value) {
+                    self.error = Some(e.to_string());
+                }
+                value
+            }
+        };
+        if let Mode::Review(review, _) = &mut self.mode {
+            review.set_expected(Some((team, value)));
+        }
+    }
+
+    /// Replays the trick in which the specified card was played.
+    fn replay_trick(&mut self, id: Option<LogId>) {
+        let replay = id.map(|id| Replay::new(self.game.round().log(), id));
+        match replay {
+            Some(Ok(Some(replay))) => self.replay = Some(replay),
+            Some(Err(e)) => self.error = Some(e.to_string()),
+            None | Some(Ok(None)) => self.error = Some(tr!("ui-select-trick-card")),
+        }
+    }
+
+    /// Leaves review mode to play out an alternative line, from the specified position. If a
+    /// robot is next to act, autoplay is disabled so that the user can choose its move.
+    fn branch_from_review(&mut self, id: Option<LogId>) {
+        self.seek_round_history(id);
+        let robot_next = self
+            .game
+            .round()
+            .next_action()
+            .is_some_and(|expect| expect.seat != self.seat);
+        if robot_next && self.robot_autoplay {
+            self.toggle_robot_autoplay();
+        }
+        self.game_step();
     }
 
     /// Seeks to a particular point in round history.
@@ -456,21 +1616,541 @@ impl Tui {
         }
     }
 
-    /// Saves the round to a file.
-    fn save_round(&mut self) {
-        // TODO: Make this less of a hack... add an input for filename, etc.
-        if let Err(e) = self.try_save_round() {
-            self.error = Some(format!("Failed to write euchre.json: {e}"));
+    /// Records the outcome of the current round in the player statistics.
+    fn record_stats(&mut self) {
+        self.stats.record_round(self.game.round());
+        let path = self.paths.stats();
+        if let Err(e) = self.stats.save(&path) {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
+        }
+    }
+
+    /// Checks the current round for achievements, and announces any that were newly earned.
+    fn record_achievements(&mut self) {
+        for achievement in self.achievements.record_round(&self.game, self.seat) {
+            self.notices.push(tr!(
+                "ui-achievement",
+                achievement = achievement.to_string(),
+                description = achievement.description()
+            ));
+        }
+        let path = self.paths.achievements();
+        if let Err(e) = self.achievements.save(&path) {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
+        }
+    }
+
+    /// Saves the game to a file.
+    fn save_game(&mut self, path: &Path) {
+        if let Err(e) = self.try_save_game(path) {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
+        } else {
+            self.debug = Some(tr!("ui-wrote", path = path.display().to_string()));
+            self.remember_file(path);
+        }
+    }
+
+    /// Writes the score sheet of the current game, in the format given by the file's extension.
+    fn write_sheet(&mut self, path: &Path) {
+        let sheet = ScoreSheet::new(&self.game).format(SheetFormat::from_path(path));
+        if let Err(e) = create_file(path).and_then(|mut file| file.write_all(sheet.as_bytes())) {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
         } else {
-            self.debug = Some("Wrote to euchre.json".into());
+            self.debug = Some(tr!("ui-wrote", path = path.display().to_string()));
+        }
+    }
+
+    /// Adds a file to the recently used list.
+    fn remember_file(&mut self, path: &Path) {
+        let recent_path = self.paths.recent_files();
+        let result = RecentFiles::load(&recent_path).and_then(|mut recent| {
+            recent.touch(path);
+            recent.save(&recent_path)
+        });
+        if let Err(e) = result {
+            self.error = Some(tr!("ui-recent-failed", error = e.to_string()));
+        }
+    }
+
+    /// Saves the game in progress, so that it can be resumed on the next startup. Once the game
+    /// (or rubber) is over, the autosave is removed. A game that hasn't started yet leaves any
+    /// earlier autosave alone, as does the tutorial.
+    fn autosave(&mut self) {
+        if self.tutorial.is_some() {
+            return;
+        }
+        let path = self.paths.autosave();
+        let result = if self
+            .game
+            .outcome()
+            .is_some_and(|o| o.rubber.winner().is_some())
+        {
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+                _ => Ok(()),
+            }
+        } else if self.game.rounds() > 0 || self.game.round().cursor().is_some() {
+            self.try_save_game(&path)
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
         }
     }
 
-    /// Tries to save the round to a file, or returns an error.
-    fn try_save_round(&self) -> Result<(), anyhow::Error> {
-        let file = File::create("euchre.json")?;
-        let log = RawLog::from(self.game.round());
-        serde_json::to_writer(file, &log)?;
+    /// Archives the game if the current round, once scored, ends it.
+    fn archive_game(&mut self) {
+        let Some(outcome) = self.game.round().outcome() else {
+            return;
+        };
+        if self.game.score(outcome.team) + outcome.points < self.game.target_score() {
+            return;
+        }
+        let mut metadata = Metadata {
+            rules: Some(self.game.rules()),
+            ..self.metadata.clone()
+        };
+        if metadata.date.is_empty() {
+            metadata.date = Metadata::today();
+        }
+        let game = RawGame::from(&self.game).with_metadata(metadata);
+        if let Err(e) = Archive::new(&self.paths.archive_dir()).store(&game) {
+            self.error = Some(tr!("ui-archive-failed", error = e.to_string()));
+        }
+    }
+
+    /// Opens the archive browser, listing finished games.
+    fn open_archive(&mut self) {
+        match Archive::new(&self.paths.archive_dir()).list() {
+            Ok(games) if games.is_empty() => self.error = Some(tr!("ui-no-finished-games")),
+            Ok(games) => {
+                let state = ArchiveBrowserState::default().with_selected(Some(0));
+                self.archive = Some((ArchiveBrowser::new(games), state));
+            }
+            Err(e) => self.error = Some(tr!("ui-list-finished-failed", error = e.to_string())),
+        }
+    }
+
+    /// Opens an archived game in a new tab, and reviews its final round.
+    fn review_archived(&mut self, path: &Path) {
+        let tui = match RawGame::from_json_file(path).and_then(Self::from_raw_game) {
+            Ok(tui) => tui,
+            Err(e) => {
+                self.error = Some(tr!(
+                    "ui-load-failed",
+                    path = path.display().to_string(),
+                    error = e.to_string()
+                ));
+                return;
+            }
+        };
+        let title = tui.metadata.date.clone();
+        self.open_tab(tui);
+        if !title.is_empty() {
+            self.title = title;
+        }
+        self.enter_review_mode();
+    }
+
+    /// Opens the start menu, which offers to resume the autosaved game, if there is one in
+    /// progress.
+    pub fn open_start_menu(&mut self) {
+        let menu = StartMenu::read(&self.paths.autosave());
+        self.start = Some((menu, StartMenuState::default().with_selected(Some(0))));
+    }
+
+    /// Acts on a choice from the start menu. The menu stays open beneath the settings menu, and
+    /// reopens if there are no saved games to load.
+    fn choose_start(&mut self, choice: StartChoice) {
+        match choice {
+            StartChoice::NewGame => {
+                self.start = None;
+                // The game dealt at startup may predate a change to the rules in the settings.
+                if self.game.rules() != self.new_game_rules {
+                    let tui = Self::new_game(self.new_game_rules);
+                    self.game = tui.game;
+                    self.mode = tui.mode;
+                }
+            }
+            StartChoice::Resume => {
+                self.start = None;
+                self.resume_autosave();
+            }
+            StartChoice::Load => {
+                self.open_picker();
+                if self.picker.is_some() {
+                    self.start = None;
+                }
+            }
+            StartChoice::Settings => {
+                let menu = SettingsMenu::new(self.current_settings());
+                let state = SettingsMenuState::default().with_selected(Some(0));
+                self.settings_menu = Some((menu, state));
+            }
+            StartChoice::Quit => self.exit = true,
+        }
+    }
+
+    /// Returns the settings in effect.
+    fn current_settings(&self) -> Settings {
+        Settings {
+            robot_autoplay: self.robot_autoplay,
+            trick_odds: self.show_odds,
+            win_odds: self.show_win_odds,
+            strength_trainer: self.strength_trainer,
+            card_back: self.card_back,
+            ..self.settings
+        }
+    }
+
+    /// Puts the settings into effect. The rules apply to the next new game, and a change of robot
+    /// applies to every seat, replacing any chosen on the command line.
+    fn apply_settings(&mut self, settings: Settings) {
+        self.robot_autoplay = settings.robot_autoplay;
+        self.show_odds = settings.trick_odds;
+        self.show_win_odds = settings.win_odds;
+        self.strength_trainer = settings.strength_trainer;
+        self.card_back = settings.card_back;
+        if settings.robot != self.settings.robot {
+            self.kinds = SeatMap::from_fn(|_| settings.robot);
+        }
+        if settings.rules != self.settings.rules {
+            self.new_game_rules = Rules {
+                time_control: self.new_game_rules.time_control,
+                ..settings.rules
+            };
+        }
+        self.settings = settings;
+        self.estimate_win_odds();
+    }
+
+    /// Returns how long to show the current event before moving on, if it moves on by itself:
+    /// while robots are playing, with an autoplay delay set, and nothing else on screen.
+    fn autoplay_delay(&self) -> Option<Duration> {
+        let overlay = self.start.is_some()
+            || self.settings_menu.is_some()
+            || self.form.is_some()
+            || self.picker.is_some()
+            || self.archive.is_some()
+            || self.command.is_some()
+            || self.tutorial.is_some()
+            || self.show_stats
+            || self.show_sheet;
+        let informational = matches!(&self.mode, Mode::Event(event) if is_informational(event));
+        if !self.robot_autoplay || overlay || !informational {
+            return None;
+        }
+        self.settings.autoplay_delay()
+    }
+
+    /// Puts the settings into effect, and writes them to the settings file.
+    fn save_settings(&mut self, settings: Settings) {
+        if let Err(e) = settings.validate() {
+            self.error = Some(tr!("settings-invalid", error = e.to_string()));
+            return;
+        }
+        self.apply_settings(settings);
+        let path = self.paths.settings();
+        if let Err(e) = settings.save(&path) {
+            self.error = Some(tr!(
+                "ui-write-failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ));
+        }
+    }
+
+    /// Replaces the current game with the autosaved one.
+    fn resume_autosave(&mut self) {
+        let path = self.paths.autosave();
+        let result = RawGame::from_json_file(&path).and_then(Self::from_raw_game);
+        match result {
+            Ok(tui) => {
+                self.game = tui.game;
+                self.mode = tui.mode;
+                self.turn_started = tui.turn_started;
+                self.metadata = tui.metadata;
+                self.tutorial = tui.tutorial;
+                self.trainer = tui.trainer;
+                self.error = tui.error;
+                self.debug = Some(tr!("ui-resumed"));
+            }
+            Err(e) => {
+                self.error = Some(tr!(
+                    "ui-load-failed",
+                    path = path.display().to_string(),
+                    error = e.to_string()
+                ))
+            }
+        }
+    }
+
+    /// Tries to save the game to a file, or returns an error.
+    fn try_save_game(&self, path: &Path) -> Result<(), anyhow::Error> {
+        let file = create_file(path)?;
+        let metadata = Metadata {
+            date: Metadata::today(),
+            rules: Some(self.game.rules()),
+            ..self.metadata.clone()
+        };
+        let game = RawGame::from(&self.game).with_metadata(metadata);
+        serde_json::to_writer(file, &game)?;
         Ok(())
     }
+
+    /// Runs a command from the command line.
+    fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Save(path) => {
+                let path = path.unwrap_or_else(|| PathBuf::from(SAVE_PATH));
+                self.save_game(&path);
+            }
+            Command::Sheet(path) => {
+                let path = path.unwrap_or_else(|| PathBuf::from(SHEET_PATH));
+                self.write_sheet(&path);
+            }
+            Command::Load(Some(path)) => self.load_game(&path),
+            Command::Load(None) => self.open_picker(),
+            Command::TabNew(path) => self.new_tab(path.as_deref()),
+            Command::TabClose => self.close_tab(),
+            Command::Archive => self.open_archive(),
+            Command::Seek(moves) => self.seek_move(moves),
+            Command::Seat(seat) => {
+                if self.assists_locked() {
+                    return;
+                }
+                self.seat = seat;
+                self.debug = Some(tr!("ui-playing-as", seat = seat.to_string()));
+                if matches!(self.mode, Mode::ActionChoice(_, _) | Mode::Hand(_, _)) {
+                    self.game_step();
+                }
+            }
+            Command::Robot(enabled) => self.set_robot_autoplay(enabled),
+            Command::Preset(seat, preset) => {
+                self.set_preset(seat, preset);
+                self.debug = Some(tr!(
+                    "ui-bids",
+                    seat = seat.to_string(),
+                    preset = preset.to_string()
+                ));
+            }
+            Command::ThrowIn => self.throw_in(),
+            Command::Back(back) => {
+                self.card_back = back;
+                self.debug = Some(tr!("ui-card-back", back = back.to_string()));
+            }
+            Command::Quit => self.exit = true,
+        }
+    }
+
+    /// Proposes to throw in the round without playing it. If every robot agrees, the deal passes
+    /// to the next dealer, and neither team scores.
+    fn throw_in(&mut self) {
+        let round = self.game.round();
+        if round.contract().is_some() {
+            self.error = Some(tr!("ui-throw-in-too-late"));
+            return;
+        }
+        let declined = Seat::all_seats()
+            .iter()
+            .copied()
+            .filter(|&seat| seat != self.seat)
+            .find(|&seat| !self.robots[seat].agree_to_throw_in(round.player_state(seat)));
+        if let Some(seat) = declined {
+            self.error = Some(tr!("ui-throw-in-declined", seat = seat.to_string()));
+            return;
+        }
+        // The new round announces the redeal.
+        if self.game.throw_in().is_some() {
+            self.turn_started = None;
+            self.autosave();
+            self.game_step();
+        }
+    }
+
+    /// Seeks to the position after the specified number of moves along the current line of play,
+    /// which continues past the cursor along the first recorded branch.
+    fn seek_move(&mut self, moves: usize) {
+        if self.assists_locked() {
+            return;
+        }
+        let round = self.game.round();
+        let log = round.log();
+        let line = match log.first_leaf(round.cursor()) {
+            Some(leaf) => match log.backtrace(leaf) {
+                Ok(line) => line,
+                Err(e) => {
+                    self.error = Some(e.to_string());
+                    return;
+                }
+            },
+            None => vec![],
+        };
+        let id = match moves.checked_sub(1) {
+            None => None,
+            Some(index) => match line.get(index) {
+                Some(&(id, _)) => Some(id),
+                None => {
+                    self.error = Some(tr!("ui-moves-played", count = line.len()));
+                    return;
+                }
+            },
+        };
+        self.seek_round_history(id);
+        self.game_step();
+    }
+
+    /// Replaces the current game with one loaded from a file.
+    fn load_game(&mut self, path: &Path) {
+        match Self::from_file(path) {
+            Ok(tui) => {
+                self.game = tui.game;
+                self.mode = tui.mode;
+                self.turn_started = tui.turn_started;
+                self.title = tui.title;
+                self.metadata = tui.metadata;
+                self.error = tui.error;
+                self.debug = Some(tr!("ui-loaded", path = path.display().to_string()));
+                self.estimate_win_odds();
+            }
+            Err(e) => {
+                self.error = Some(tr!(
+                    "ui-load-failed",
+                    path = path.display().to_string(),
+                    error = e.to_string()
+                ))
+            }
+        }
+    }
+
+    /// Opens the file picker, listing recently used files, followed by the saved games and rounds
+    /// in the working directory.
+    fn open_picker(&mut self) {
+        let recent = RecentFiles::load(&self.paths.recent_files()).unwrap_or_default();
+        match FilePicker::new(Path::new("."), recent.paths()) {
+            Ok(picker) if picker.is_empty() => self.error = Some(tr!("ui-no-saved-games")),
+            Ok(picker) => {
+                let state = FilePickerState::default().with_selected(Some(0));
+                self.picker = Some((picker, state));
+            }
+            Err(e) => self.error = Some(tr!("ui-list-saved-failed", error = e.to_string())),
+        }
+    }
+
+    /// Returns the tab bar, with the active tab highlighted.
+    fn tab_bar(&self) -> Tabs<'static> {
+        let (before, after) = self.tabs.split_at(self.active);
+        let titles = before
+            .iter()
+            .map(|tab| tab.title.as_str())
+            .chain(std::iter::once(self.title.as_str()))
+            .chain(after.iter().map(|tab| tab.title.as_str()))
+            .enumerate()
+            .map(|(i, title)| format!("{} {title}", i + 1));
+        Tabs::new(titles)
+            .select(self.active)
+            .highlight_style(Style::new().reversed())
+    }
+
+    /// Swaps the active tab with a background tab.
+    fn swap_tab(&mut self, tab: &mut Tab) {
+        std::mem::swap(&mut self.title, &mut tab.title);
+        std::mem::swap(&mut self.game, &mut tab.game);
+        std::mem::swap(&mut self.mode, &mut tab.mode);
+        std::mem::swap(&mut self.turn_started, &mut tab.turn_started);
+        std::mem::swap(&mut self.metadata, &mut tab.metadata);
+        std::mem::swap(&mut self.tutorial, &mut tab.tutorial);
+        std::mem::swap(&mut self.trainer, &mut tab.trainer);
+        self.batched.clear();
+        self.estimate_win_odds();
+    }
+
+    /// Switches to the tab at the specified position.
+    fn switch_tab(&mut self, index: usize) {
+        if index == self.active || index > self.tabs.len() {
+            return;
+        }
+        let mut tab = self.tabs.remove(if index < self.active {
+            index
+        } else {
+            index - 1
+        });
+        self.swap_tab(&mut tab);
+        let pos = if index < self.active {
+            self.active - 1
+        } else {
+            self.active
+        };
+        self.tabs.insert(pos, tab);
+        self.active = index;
+    }
+
+    /// Opens a new game in a new tab, or a saved game or round, and switches to it.
+    fn new_tab(&mut self, path: Option<&Path>) {
+        let tui = match path {
+            Some(path) => match Self::from_file(path) {
+                Ok(tui) => tui,
+                Err(e) => {
+                    self.error = Some(tr!(
+                        "ui-load-failed",
+                        path = path.display().to_string(),
+                        error = e.to_string()
+                    ));
+                    return;
+                }
+            },
+            None => Self::new_game(self.new_game_rules),
+        };
+        self.open_tab(tui);
+    }
+
+    /// Opens a game in a new tab, and switches to it.
+    fn open_tab(&mut self, tui: Tui) {
+        self.error = tui.error;
+        let mut tab = Tab {
+            title: tui.title,
+            game: tui.game,
+            mode: tui.mode,
+            turn_started: tui.turn_started,
+            metadata: tui.metadata,
+            tutorial: tui.tutorial,
+            trainer: tui.trainer,
+        };
+        self.swap_tab(&mut tab);
+        self.tabs.insert(self.active, tab);
+        self.active = self.tabs.len();
+    }
+
+    /// Closes the active tab, and switches to the next one, or the previous if it was the last.
+    fn close_tab(&mut self) {
+        if self.tabs.is_empty() {
+            self.error = Some(tr!("ui-last-tab"));
+            return;
+        }
+        let pos = self.active.min(self.tabs.len() - 1);
+        let mut tab = self.tabs.remove(pos);
+        self.swap_tab(&mut tab);
+        self.active = pos;
+    }
 }
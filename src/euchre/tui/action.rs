@@ -3,7 +3,10 @@
 use ratatui::widgets::{ListItem, ListState};
 use ratatui::{prelude::*, widgets::List};
 
+use super::line_with_span;
 use crate::euchre::{Action, ActionData, ExpectAction, Suit};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
 
 pub type ActionChoiceState = ListState;
 
@@ -67,17 +70,19 @@ impl ActionChoice {
 
 impl From<ActionData> for ListItem<'static> {
     fn from(action: ActionData) -> Self {
-        let spans: Vec<Span> = match action {
-            ActionData::Pass => vec!["Pass".into()],
-            ActionData::Call { suit, alone } => vec![
-                "Call ".into(),
+        let line = match action {
+            ActionData::Pass => Line::from(tr!("action-pass")),
+            ActionData::Call { suit, alone: false } => {
+                line_with_span(&tr!("action-call", suit = PLACEHOLDER), suit.to_span())
+            }
+            ActionData::Call { suit, alone: true } => line_with_span(
+                &tr!("action-call-alone", suit = PLACEHOLDER),
                 suit.to_span(),
-                if alone { " alone" } else { "" }.into(),
-            ],
+            ),
             // Cards are selected with the [`Hand`] widget.
             ActionData::Card { .. } => unreachable!(),
         };
-        ListItem::new(Line::from(spans))
+        ListItem::new(line)
     }
 }
 
@@ -0,0 +1,80 @@
+//! Archive browser widget
+
+use std::path::Path;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, List, ListState, StatefulWidget};
+
+use crate::euchre::{ArchivedGame, Team};
+use crate::tr;
+
+pub type ArchiveBrowserState = ListState;
+
+/// A list of finished games to choose from.
+#[derive(Debug, Clone)]
+pub struct ArchiveBrowser {
+    games: Vec<ArchivedGame>,
+}
+
+impl ArchiveBrowser {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 56;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 12;
+
+    pub fn new(games: Vec<ArchivedGame>) -> Self {
+        Self { games }
+    }
+
+    /// Returns true if there are no games to choose from.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Returns the archive file of the selected game.
+    pub fn selected(&self, state: &ArchiveBrowserState) -> Option<&Path> {
+        state
+            .selected()
+            .and_then(|idx| self.games.get(idx))
+            .map(|game| game.path.as_path())
+    }
+}
+
+/// Describes an archived game on one line.
+fn game_line(game: &ArchivedGame) -> Line<'static> {
+    let outcome = &game.outcome;
+    let date = if game.date.is_empty() {
+        "-"
+    } else {
+        game.date.as_str()
+    };
+    let score = format!(
+        "N/S {:>2} - E/W {:>2}",
+        outcome.score[Team::NorthSouth],
+        outcome.score[Team::EastWest]
+    );
+    let mut details = vec![
+        tr!("archive-rounds", rounds = outcome.rounds),
+        tr!("metadata-target", points = game.rules.target_score),
+    ];
+    if game.rules.best_of > 1 {
+        details.push(tr!("metadata-best-of", games = game.rules.best_of));
+    }
+    Line::from(vec![
+        format!("{date:<10}  ").into(),
+        score.bold(),
+        format!("  {}", details.join(", ")).dim(),
+    ])
+}
+
+impl StatefulWidget for &ArchiveBrowser {
+    type State = ArchiveBrowserState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+        let list = List::new(self.games.iter().map(game_line))
+            .block(Block::bordered().title(format!(" {} ", tr!("archive-title"))))
+            .highlight_style(Style::new().reversed());
+        StatefulWidget::render(list, area, buf, state);
+    }
+}
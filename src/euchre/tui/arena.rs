@@ -1,33 +1,177 @@
 //! Widget for the play arena
 
+use std::time::Duration;
+
 use ratatui::widgets::{Block, Widget};
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use crate::euchre::{ActionType, Card, Event, Round, Seat, Trick};
+use crate::euchre::{
+    ActionType, Card, CardBack, Event, ExpectAction, Round, Seat, SeatMap, Suit, Trick,
+};
+use crate::tr;
 
 use super::Mode;
 
+#[cfg(test)]
+mod test;
+
+/// The number of cards dealt to each player.
+const HAND_SIZE: usize = 5;
+
+/// Returns the number of cards each player holds, as anyone at the table can tell. The partner of
+/// a player going alone lays their hand down, and so holds none.
+fn hand_sizes(round: &impl Round) -> SeatMap<usize> {
+    let contract = round.contract();
+    let next = round.next_action();
+    SeatMap::from_fn(|seat| {
+        if contract.is_some_and(|c| c.alone && c.maker.opposite() == seat) {
+            return 0;
+        }
+        let discarding = next
+            == Some(ExpectAction {
+                seat,
+                action: ActionType::DealerDiscard,
+            });
+        let held = HAND_SIZE + usize::from(discarding);
+        held.saturating_sub(round.tricks().cards_played_by(seat).count())
+    })
+}
+
+/// The seats around the table, as seen from a player's seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Orientation {
+    /// The player's own seat, at the bottom.
+    bottom: Seat,
+    /// The next seat clockwise, on the player's left.
+    left: Seat,
+    /// The player's partner, across the table.
+    top: Seat,
+    /// The player's right-hand opponent.
+    right: Seat,
+}
+
+impl Orientation {
+    /// Orients the table so that the specified seat is at the bottom.
+    fn from_viewer(viewer: Seat) -> Self {
+        Self {
+            bottom: viewer,
+            left: viewer.next(),
+            top: viewer.opposite(),
+            right: viewer.opposite().next(),
+        }
+    }
+}
+
 pub struct Arena {
     top: Option<Card>,
-    trick: Option<Trick>,
+    cards: SeatMap<Option<Card>>,
+    packet: Option<Seat>,
+    clocks: Option<SeatMap<Duration>>,
+    orientation: Orientation,
+    /// The number of cards in each player's hand, or none if hands aren't shown.
+    hands: SeatMap<usize>,
+    back: CardBack,
+    /// The trump suit, if a trick is shown. Trump cards are shown in bold.
+    trump: Option<Suit>,
+    /// The seat whose card is winning the trick, if any. Its card is underlined.
+    winning: Option<Seat>,
 }
 
 impl Arena {
     pub fn new(mode: &Mode, round: &impl Round) -> Self {
         let action = round.next_action().map(|expect| expect.action);
         let top = match (mode, action) {
-            (Mode::Event(Event::Game(_)), _) => None,
+            (Mode::Event(Event::Game(_) | Event::Cut(_)), _) => None,
             (Mode::Event(Event::Deal(_, _)), _) | (_, Some(ActionType::BidTop)) => {
                 Some(round.top_card())
             }
             _ => None,
         };
         let trick = match (mode, action) {
-            (Mode::Event(Event::Trick(trick)), _) => Some(trick.clone()),
-            (_, Some(ActionType::Follow)) => round.tricks().last().cloned(),
+            (Mode::Event(Event::Trick(trick)), _) => Some(trick),
+            (_, Some(ActionType::Follow)) => round.tricks().in_progress(),
             _ => None,
         };
-        Self { top, trick }
+        let cards = match (mode, trick) {
+            (Mode::Event(Event::Cut(cut)), _) => SeatMap::from_fn(|seat| cut.card(seat)),
+            (_, Some(trick)) => SeatMap::from_fn(|seat| trick.get_card(seat)),
+            _ => SeatMap::default(),
+        };
+        let packet = match mode {
+            Mode::Event(Event::Packet(seat, _)) => Some(*seat),
+            _ => None,
+        };
+        let hands = match mode {
+            Mode::Event(
+                Event::Cut(_) | Event::Deal(_, _) | Event::Packet(_, _) | Event::Game(_),
+            ) => SeatMap::default(),
+            _ => hand_sizes(round),
+        };
+        Self {
+            top,
+            cards,
+            packet,
+            clocks: None,
+            orientation: Orientation::from_viewer(Seat::South),
+            hands,
+            back: CardBack::default(),
+            trump: None,
+            winning: None,
+        }
+        .with_trick(trick)
+    }
+
+    /// Shows only the specified cards, such as those of a trick being replayed.
+    pub fn with_cards(cards: SeatMap<Option<Card>>) -> Self {
+        Self {
+            top: None,
+            cards,
+            packet: None,
+            clocks: None,
+            orientation: Orientation::from_viewer(Seat::South),
+            hands: SeatMap::default(),
+            back: CardBack::default(),
+            trump: None,
+            winning: None,
+        }
+    }
+
+    /// Draws face-down cards with the specified back.
+    pub fn with_card_back(mut self, back: CardBack) -> Self {
+        self.back = back;
+        self
+    }
+
+    /// Marks the trump cards in the trick, and the card that is winning it.
+    fn with_trick(mut self, trick: Option<&Trick>) -> Self {
+        self.trump = trick.map(Trick::trump);
+        self.winning = trick.map(|trick| trick.best().0);
+        self
+    }
+
+    /// Orients the table so that the specified seat is at the bottom, with their partner at the
+    /// top. By default, south is at the bottom.
+    pub fn with_viewer(mut self, viewer: Seat) -> Self {
+        self.orientation = Orientation::from_viewer(viewer);
+        self
+    }
+
+    /// Shows the time remaining to each player, beside their seat.
+    pub fn with_clocks(mut self, clocks: Option<SeatMap<Duration>>) -> Self {
+        self.clocks = clocks;
+        self
+    }
+
+    /// Labels a seat with its clock, if any.
+    fn seat_label(&self, seat: Seat) -> String {
+        match &self.clocks {
+            Some(clocks) => tr!(
+                "ui-seat-clock",
+                seat = seat.to_abbr().to_string(),
+                time = format_clock(clocks[seat])
+            ),
+            None => seat.to_abbr().to_string(),
+        }
     }
 
     fn top_card_span(&self) -> Span<'_> {
@@ -35,30 +179,88 @@ impl Arena {
     }
 
     fn trick_card_span(&self, seat: Seat) -> Span<'_> {
-        self.trick
-            .as_ref()
-            .and_then(|t| t.get_card(seat))
-            .map_or(Span::raw("  "), Card::to_span)
+        if self.packet == Some(seat) {
+            Span::raw(self.back.glyph().to_string().repeat(2))
+        } else {
+            self.cards[seat].map_or(Span::raw("  "), |card| {
+                card.to_span().add_modifier(self.card_modifier(seat, card))
+            })
+        }
+    }
+
+    /// Returns how to emphasize a card in the trick: bold for trump, underlined for the winning
+    /// card.
+    fn card_modifier(&self, seat: Seat, card: Card) -> Modifier {
+        let mut modifier = Modifier::empty();
+        if self.trump.is_some_and(|trump| card.is_trump(trump)) {
+            modifier |= Modifier::BOLD;
+        }
+        if self.winning == Some(seat) {
+            modifier |= Modifier::UNDERLINED;
+        }
+        modifier
+    }
+
+    /// Labels a hidden hand with a card back and the number of cards in it, or leaves it blank if
+    /// it's empty.
+    fn hidden_hand(&self, seat: Seat) -> String {
+        match self.hands[seat] {
+            0 => String::new(),
+            n => format!("{}{n}", self.back.glyph()),
+        }
     }
 
     fn to_lines(&self) -> Vec<Line<'_>> {
+        let Orientation {
+            bottom,
+            left,
+            top,
+            right,
+        } = self.orientation;
+        let hidden = format!(
+            "{:<6}{:^2}{:>6}",
+            self.hidden_hand(left),
+            self.hidden_hand(top),
+            self.hidden_hand(right)
+        );
         vec![
-            Span::raw("N").into_centered_line(),
-            Line::default(),
-            self.trick_card_span(Seat::North).into_centered_line(),
+            Span::raw(self.seat_label(top)).into_centered_line(),
+            Span::raw(hidden).dim().into_centered_line(),
+            self.trick_card_span(top).into_centered_line(),
             Line::from(vec![
-                Span::raw("W  "),
-                self.trick_card_span(Seat::West),
+                Span::raw(format!("{}  ", left.to_abbr())),
+                self.trick_card_span(left),
                 self.top_card_span(),
-                self.trick_card_span(Seat::East),
-                Span::raw("  E"),
+                self.trick_card_span(right),
+                Span::raw(format!("  {}", right.to_abbr())),
             ])
             .centered(),
-            self.trick_card_span(Seat::South).into_centered_line(),
+            self.bottom_line(),
             Line::default(),
-            Span::raw("S").into_centered_line(),
+            Span::raw(self.seat_label(bottom)).into_centered_line(),
         ]
     }
+
+    /// Lays out the bottom seat's trick card, flanked by the clocks for the seats on the left and
+    /// right, if any.
+    fn bottom_line(&self) -> Line<'_> {
+        let Orientation {
+            bottom,
+            left,
+            right,
+            ..
+        } = self.orientation;
+        let card = self.trick_card_span(bottom);
+        match &self.clocks {
+            Some(clocks) => Line::from(vec![
+                Span::raw(format!("{:<6}", format_clock(clocks[left]))),
+                card,
+                Span::raw(format!("{:>6}", format_clock(clocks[right]))),
+            ])
+            .centered(),
+            None => card.into_centered_line(),
+        }
+    }
 }
 
 impl Widget for Arena {
@@ -72,3 +274,9 @@ impl Widget for Arena {
             .render(area, buf);
     }
 }
+
+/// Formats the time on a clock as minutes and seconds, rounding down (e.g., `4:07`).
+fn format_clock(time: Duration) -> String {
+    let secs = time.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::euchre::tui::tutorial::Tutorial;
+use crate::euchre::{ActionData, FastRound};
+
+#[test]
+fn test_orientation() {
+    let south = Orientation::from_viewer(Seat::South);
+    assert_eq!(south.bottom, Seat::South);
+    assert_eq!(south.left, Seat::West);
+    assert_eq!(south.top, Seat::North);
+    assert_eq!(south.right, Seat::East);
+
+    let east = Orientation::from_viewer(Seat::East);
+    assert_eq!(east.bottom, Seat::East);
+    assert_eq!(east.left, Seat::South);
+    assert_eq!(east.top, Seat::West);
+    assert_eq!(east.right, Seat::North);
+}
+
+#[test]
+fn test_arena_lines() {
+    let text = |arena: &Arena| -> Vec<String> {
+        arena
+            .to_lines()
+            .iter()
+            .map(|line| line.to_string().trim().to_string())
+            .collect()
+    };
+    let cards = SeatMap::default();
+    let south = text(&Arena::with_cards(cards));
+    assert_eq!(south[0], "N");
+    assert_eq!(south[3], "W          E");
+    assert_eq!(south[6], "S");
+
+    let north = text(&Arena::with_cards(cards).with_viewer(Seat::North));
+    assert_eq!(north[0], "S");
+    assert_eq!(north[3], "E          W");
+    assert_eq!(north[6], "N");
+}
+
+#[test]
+fn test_card_modifier() {
+    let card = |s: &str| -> Card { s.parse().unwrap() };
+    let mut trick = Trick::new(Suit::Heart, Seat::North, card("ac"));
+    trick.play(Seat::East, card("jd"));
+    trick.play(Seat::South, card("9h"));
+    let cards = SeatMap::from_fn(|seat| trick.get_card(seat));
+    let arena = Arena::with_cards(cards).with_trick(Some(&trick));
+    assert_eq!(
+        arena.card_modifier(Seat::North, card("ac")),
+        Modifier::empty()
+    );
+    assert_eq!(
+        arena.card_modifier(Seat::East, card("jd")),
+        Modifier::BOLD | Modifier::UNDERLINED
+    );
+    assert_eq!(arena.card_modifier(Seat::South, card("9h")), Modifier::BOLD);
+
+    let arena = Arena::with_cards(cards);
+    assert_eq!(
+        arena.card_modifier(Seat::East, card("jd")),
+        Modifier::empty()
+    );
+}
+
+#[test]
+fn test_hand_sizes() {
+    let mut round = FastRound::from(Tutorial::config());
+    assert_eq!(hand_sizes(&round), SeatMap::from_fn(|_| HAND_SIZE));
+    // South, left of the dealer, orders up the top card.
+    let expect = round.next_action().unwrap();
+    round
+        .apply_action(expect.with_data(ActionData::Call {
+            suit: Suit::Heart,
+            alone: false,
+        }))
+        .unwrap();
+    let sizes = hand_sizes(&round);
+    assert_eq!(sizes[Seat::East], HAND_SIZE + 1);
+    assert_eq!(sizes[Seat::South], HAND_SIZE);
+}
+
+#[test]
+fn test_hidden_hands() {
+    let mut arena = Arena::with_cards(SeatMap::default()).with_card_back(CardBack::Ascii);
+    arena.hands = SeatMap::from_fn(|seat| if seat == Seat::North { 0 } else { 4 });
+    assert_eq!(arena.to_lines()[1].to_string(), "#4          #4");
+}
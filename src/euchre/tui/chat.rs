@@ -0,0 +1,60 @@
+//! Chat widget
+
+use std::collections::VecDeque;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Paragraph, Widget, Wrap};
+
+use crate::euchre::Seat;
+use crate::tr;
+
+pub struct Chat<'a> {
+    log: &'a VecDeque<(Seat, String)>,
+    draft: Option<&'a str>,
+}
+
+impl<'a> Chat<'a> {
+    pub fn new(log: &'a VecDeque<(Seat, String)>, draft: Option<&'a str>) -> Self {
+        Self { log, draft }
+    }
+}
+
+impl Widget for Chat<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let block = Block::bordered().title(format!(" {} ", tr!("chat-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [log, input] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(u16::from(self.draft.is_some())),
+        ])
+        .areas(inner);
+
+        // Show the most recent messages that fit, ignoring wrapping.
+        let skip = self.log.len().saturating_sub(usize::from(log.height));
+        let lines: Vec<Line> = self
+            .log
+            .iter()
+            .skip(skip)
+            .map(|(seat, text)| {
+                Line::from(vec![format!("{seat}: ").bold(), Span::raw(text.clone())])
+            })
+            .collect();
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .render(log, buf);
+
+        if let Some(draft) = self.draft {
+            Line::from(vec![
+                "> ".bold(),
+                Span::raw(draft.to_string()),
+                "_".slow_blink(),
+            ])
+            .render(input, buf);
+        }
+    }
+}
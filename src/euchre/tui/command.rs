@@ -0,0 +1,117 @@
+//! Command line
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::euchre::{CardBack, Preset, Seat};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// A command entered on the `:` command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Saves the game, to the specified file or the default.
+    Save(Option<PathBuf>),
+    /// Writes the score sheet, to the specified file or the default.
+    Sheet(Option<PathBuf>),
+    /// Loads a saved game or round, or chooses one from those in the working directory.
+    Load(Option<PathBuf>),
+    /// Opens a new game in a new tab, or a saved game or round.
+    TabNew(Option<PathBuf>),
+    /// Closes the active tab.
+    TabClose,
+    /// Browses the finished games in the archive.
+    Archive,
+    /// Seeks to the position after the specified number of moves along the current line of
+    /// play, where zero is the deal.
+    Seek(usize),
+    /// Takes the specified seat at the table.
+    Seat(Seat),
+    /// Enables or disables robot autoplay.
+    Robot(bool),
+    /// Sets the bidding style of the robot at the specified seat.
+    Preset(Seat, Preset),
+    /// Proposes to throw in the round without playing it.
+    ThrowIn,
+    /// Sets how face-down cards are drawn.
+    Back(CardBack),
+    /// Quits the game.
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let Some(name) = words.next() else {
+            return Err(tr!("command-empty"));
+        };
+        let args: Vec<&str> = words.collect();
+        let max_args = if name == "preset" { 2 } else { 1 };
+        if args.len() > max_args {
+            return Err(tr!("command-too-many-args", name = name));
+        }
+        let arg = args.first().copied();
+        let required = || arg.ok_or_else(|| tr!("command-requires-arg", name = name));
+        match name {
+            "w" | "save" => Ok(Command::Save(arg.map(PathBuf::from))),
+            "sheet" => Ok(Command::Sheet(arg.map(PathBuf::from))),
+            "e" | "load" => Ok(Command::Load(arg.map(PathBuf::from))),
+            "tabnew" => Ok(Command::TabNew(arg.map(PathBuf::from))),
+            "tabc" | "tabclose" => Ok(Command::TabClose),
+            "archive" => Ok(Command::Archive),
+            "seek" => {
+                let moves = required()?;
+                moves
+                    .parse()
+                    .map(Command::Seek)
+                    .map_err(|_| tr!("command-invalid-moves", moves = moves))
+            }
+            "seat" => {
+                let seat = required()?;
+                seat.parse()
+                    .map(Command::Seat)
+                    .map_err(|()| tr!("command-invalid-seat", seat = seat))
+            }
+            "robot" => match required()? {
+                "on" => Ok(Command::Robot(true)),
+                "off" => Ok(Command::Robot(false)),
+                arg => Err(tr!("command-invalid-robot", setting = arg)),
+            },
+            "preset" => {
+                let (Some(seat), Some(preset)) = (arg, args.get(1)) else {
+                    return Err(tr!("command-preset-requires-args"));
+                };
+                let seat = seat
+                    .parse()
+                    .map_err(|()| tr!("command-invalid-seat", seat = seat))?;
+                let preset = preset.parse().map_err(|()| {
+                    let names: Vec<_> = Preset::all().iter().map(Preset::to_string).collect();
+                    tr!(
+                        "command-invalid-preset",
+                        preset = *preset,
+                        expected = names.join(", ")
+                    )
+                })?;
+                Ok(Command::Preset(seat, preset))
+            }
+            "throw" => Ok(Command::ThrowIn),
+            "back" => {
+                let back = required()?;
+                back.parse().map(Command::Back).map_err(|()| {
+                    let names: Vec<_> = CardBack::ALL.iter().map(CardBack::to_string).collect();
+                    tr!(
+                        "command-invalid-back",
+                        back = back,
+                        expected = names.join(", ")
+                    )
+                })
+            }
+            "q" | "quit" => Ok(Command::Quit),
+            _ => Err(tr!("command-unknown", name = name)),
+        }
+    }
+}
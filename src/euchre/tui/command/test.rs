@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn test_parse() {
+    for (s, expect) in [
+        ("save", Command::Save(None)),
+        ("w foo.json", Command::Save(Some("foo.json".into()))),
+        ("load bar.json", Command::Load(Some("bar.json".into()))),
+        ("load", Command::Load(None)),
+        ("sheet", Command::Sheet(None)),
+        ("sheet game.csv", Command::Sheet(Some("game.csv".into()))),
+        ("tabnew", Command::TabNew(None)),
+        ("tabnew bar.json", Command::TabNew(Some("bar.json".into()))),
+        ("tabclose", Command::TabClose),
+        ("archive", Command::Archive),
+        ("seek 12", Command::Seek(12)),
+        ("seek 0", Command::Seek(0)),
+        (" seat  west ", Command::Seat(Seat::West)),
+        ("robot off", Command::Robot(false)),
+        (
+            "preset east Aggressive",
+            Command::Preset(Seat::East, Preset::Aggressive),
+        ),
+        ("throw", Command::ThrowIn),
+        ("back solid", Command::Back(CardBack::Solid)),
+        ("q", Command::Quit),
+    ] {
+        assert_eq!(s.parse::<Command>(), Ok(expect), "{s}");
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    for s in [
+        "",
+        "frobnicate",
+        "seek",
+        "seek twelve",
+        "seat middle",
+        "robot hard",
+        "save a b",
+        "preset east",
+        "preset east reckless",
+        "preset east next extra",
+        "back",
+        "back plaid",
+    ] {
+        assert!(s.parse::<Command>().is_err(), "{}", s);
+    }
+}
@@ -1,16 +1,47 @@
 //! Hand widget
 
+use std::convert::TryFrom;
+
+use ratatui::crossterm::event::KeyCode;
 use ratatui::prelude::*;
 use ratatui::widgets::ListState;
 
-use crate::euchre::{Action, ActionData, Card, ExpectAction, Seat};
+use crate::euchre::{Action, ActionData, Card, ExpectAction, Rank, Seat, Suit, Trick};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+use super::line_with_span;
+
+#[cfg(test)]
+mod test;
 
 pub type HandState = ListState;
 
+/// The result of typing a key as card notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry {
+    /// The key is not card notation.
+    Ignored,
+    /// A rank was typed, and the suit is still to come.
+    Rank(Rank),
+    /// A card in the hand was typed, at the specified index.
+    Card(usize),
+    /// A card in the hand was typed, but it can't be played, because it doesn't follow suit.
+    Unplayable(Card),
+    /// A card that isn't in the hand was typed.
+    Missing(Card),
+}
+
 #[derive(Debug, Clone)]
 pub struct Hand {
     seat: Seat,
     cards: Vec<Card>,
+    /// The rank typed so far, as the first half of card notation.
+    entry: Option<Rank>,
+    /// The estimated odds of winning the trick with each playable card.
+    odds: Vec<(Card, f64)>,
+    /// The suit led, and the cards that follow it, if the hand must follow suit.
+    follow: Option<(Suit, Vec<Card>)>,
 }
 
 impl Hand {
@@ -19,13 +50,106 @@ impl Hand {
         I: IntoIterator<Item = Card>,
     {
         let cards: Vec<_> = cards.into_iter().collect();
-        Self { seat, cards }
+        Self {
+            seat,
+            cards,
+            entry: None,
+            odds: vec![],
+            follow: None,
+        }
+    }
+
+    /// Restricts play to the cards that follow the lead of the trick, if the hand has any.
+    pub fn with_trick(mut self, trick: &Trick) -> Self {
+        let playable = trick.filter(&self.cards);
+        if playable.len() < self.cards.len() {
+            self.follow = Some((trick.lead_suit(), playable));
+        }
+        self
+    }
+
+    /// Returns true if some cards can't be played, in which case the hand is drawn with a hint
+    /// line beneath it.
+    pub fn has_hint(&self) -> bool {
+        self.follow.is_some()
+    }
+
+    /// Returns true if the card may be played.
+    pub fn is_playable(&self, card: Card) -> bool {
+        self.follow
+            .as_ref()
+            .is_none_or(|(_, playable)| playable.contains(&card))
+    }
+
+    /// Returns the suit that must be followed, if some cards can't be played.
+    pub fn lead_suit(&self) -> Option<Suit> {
+        self.follow.as_ref().map(|&(suit, _)| suit)
+    }
+
+    /// Returns the position of the first card that may be played.
+    pub fn first_playable(&self) -> Option<usize> {
+        self.cards.iter().position(|&c| self.is_playable(c))
+    }
+
+    /// Selects the next card that may be played, if there is one.
+    pub fn select_next(&self, state: &mut HandState) {
+        let start = state.selected().map_or(0, |idx| idx + 1);
+        let next = (start..self.cards.len()).find(|&idx| self.is_playable(self.cards[idx]));
+        if next.is_some() {
+            state.select(next);
+        }
+    }
+
+    /// Selects the previous card that may be played, if there is one.
+    pub fn select_previous(&self, state: &mut HandState) {
+        let end = state.selected().unwrap_or(0).min(self.cards.len());
+        let previous = (0..end)
+            .rev()
+            .find(|&idx| self.is_playable(self.cards[idx]));
+        if previous.is_some() {
+            state.select(previous);
+        }
+    }
+
+    /// Annotates playable cards with their odds of winning the trick.
+    pub fn set_odds(&mut self, odds: Vec<(Card, f64)>) {
+        self.odds = odds;
+    }
+
+    /// Handles a key as card notation, such as `jd` or `ah`. A rank starts an entry, and a suit
+    /// completes it; any other key abandons the entry, and is otherwise ignored.
+    pub fn type_key(&mut self, key: KeyCode) -> Entry {
+        let KeyCode::Char(c) = key else {
+            self.entry = None;
+            return Entry::Ignored;
+        };
+        match self.entry.take() {
+            Some(rank) => match Suit::try_from(c) {
+                Ok(suit) => {
+                    let card = Card::new(rank, suit);
+                    match self.cards.iter().position(|&c| c == card) {
+                        Some(_) if !self.is_playable(card) => Entry::Unplayable(card),
+                        Some(index) => Entry::Card(index),
+                        None => Entry::Missing(card),
+                    }
+                }
+                Err(()) => Entry::Ignored,
+            },
+            None => match Rank::try_from(c) {
+                Ok(rank) => {
+                    self.entry = Some(rank);
+                    Entry::Rank(rank)
+                }
+                Err(()) => Entry::Ignored,
+            },
+        }
     }
 
     pub fn selected(&self, state: &HandState) -> Option<Card> {
         state
             .selected()
             .and_then(|idx| self.cards.get(idx).copied())
+            .filter(|&card| self.is_playable(card))
     }
 
     pub fn action(&self, state: &HandState, expect: Option<ExpectAction>) -> Option<Action> {
@@ -34,18 +158,44 @@ impl Hand {
             .map(|(expect, card)| expect.with_data(ActionData::Card { card }))
     }
 
-    fn line(self, selected: Option<Card>) -> Line<'static> {
-        let mut spans = vec![format!("{}'s hand: ", self.seat).into()];
-        for card in self.cards {
+    /// Explains why some cards can't be played.
+    fn hint(&self) -> Option<Line<'static>> {
+        let (suit, _) = self.follow.as_ref()?;
+        let text = tr!("ui-must-follow", suit = PLACEHOLDER);
+        Some(line_with_span(&text, suit.to_span()).dim())
+    }
+
+    fn line(&self, selected: Option<Card>) -> Line<'static> {
+        let mut spans = vec![format!("{} ", tr!("ui-hand", seat = self.seat.to_string())).into()];
+        for &card in &self.cards {
             let mut card_span = card.to_span();
+            if !self.is_playable(card) {
+                card_span = card_span.dark_gray().dim();
+            }
             if selected.is_some_and(|c| c == card) {
                 card_span = card_span.reversed();
             }
             spans.push(card_span);
+            if let Some((_, p)) = self.odds.iter().find(|(c, _)| *c == card) {
+                spans.push(format!(" {:.0}%", p * 100.0).dim());
+            }
             spans.push(" ".into());
         }
+        if let Some(rank) = self.entry {
+            spans.push(format!("{rank}_").dim());
+        }
         Line::from(spans)
     }
+
+    /// Renders the hand on the first line of the area, and the hint, if any, on the second.
+    fn render_lines(&self, area: Rect, buf: &mut Buffer, selected: Option<Card>) {
+        let [line, hint] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(area);
+        self.line(selected).render(line, buf);
+        if let Some(text) = self.hint() {
+            text.render(hint, buf);
+        }
+    }
 }
 
 impl Widget for Hand {
@@ -69,6 +219,6 @@ impl StatefulWidget for Hand {
             state.select(Some(0));
         }
         let selected = self.selected(state);
-        self.line(selected).render(area, buf);
+        self.render_lines(area, buf, selected);
     }
 }
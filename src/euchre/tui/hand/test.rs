@@ -0,0 +1,88 @@
+use super::*;
+
+fn hand() -> Hand {
+    let cards = ["9s", "jd", "ah", "tc"].iter().map(|c| c.parse().unwrap());
+    Hand::new(Seat::South, cards)
+}
+
+fn type_str(hand: &mut Hand, s: &str) -> Vec<Entry> {
+    s.chars().map(|c| hand.type_key(KeyCode::Char(c))).collect()
+}
+
+#[test]
+fn test_type_card() {
+    let mut hand = hand();
+    assert_eq!(
+        type_str(&mut hand, "jd"),
+        vec![Entry::Rank(Rank::Jack), Entry::Card(1)]
+    );
+    assert_eq!(type_str(&mut hand, "AH")[1], Entry::Card(2));
+    assert_eq!(type_str(&mut hand, "t♣")[1], Entry::Card(3));
+    assert_eq!(
+        type_str(&mut hand, "ks")[1],
+        Entry::Missing("ks".parse().unwrap())
+    );
+}
+
+#[test]
+fn test_abandon_entry() {
+    let mut hand = hand();
+    assert_eq!(type_str(&mut hand, "l"), vec![Entry::Ignored]);
+    assert_eq!(
+        type_str(&mut hand, "qq"),
+        vec![Entry::Rank(Rank::Queen), Entry::Ignored]
+    );
+    assert_eq!(type_str(&mut hand, "9")[0], Entry::Rank(Rank::Nine));
+    assert_eq!(hand.type_key(KeyCode::Left), Entry::Ignored);
+    assert_eq!(type_str(&mut hand, "s"), vec![Entry::Ignored]);
+}
+
+#[test]
+fn test_odds() {
+    let mut hand = hand();
+    let card = |s: &str| s.parse().unwrap();
+    hand.set_odds(vec![(card("jd"), 0.75), (card("ah"), 0.125)]);
+    let line = hand.line(None).to_string();
+    assert_eq!(line, "South's hand: 9♤ J♦ 75% A♡ 12% T♣ ");
+}
+
+#[test]
+fn test_follow_suit() {
+    let card = |s: &str| s.parse().unwrap();
+    // Diamonds are led with hearts as trump, so the jack of diamonds is trump, and the only
+    // diamond is the ace.
+    let cards = ["9s", "jd", "ad", "tc"].iter().map(|c| card(c));
+    let trick = Trick::new(Suit::Heart, Seat::West, card("kd"));
+    let hand = Hand::new(Seat::South, cards).with_trick(&trick);
+    assert!(hand.has_hint());
+    assert_eq!(hand.lead_suit(), Some(Suit::Diamond));
+    assert!(hand.is_playable(card("ad")));
+    assert!(!hand.is_playable(card("jd")));
+    assert_eq!(hand.first_playable(), Some(2));
+
+    let mut state = HandState::default().with_selected(Some(2));
+    hand.select_next(&mut state);
+    assert_eq!(state.selected(), Some(2));
+    hand.select_previous(&mut state);
+    assert_eq!(state.selected(), Some(2));
+    state.select(Some(0));
+    assert_eq!(hand.selected(&state), None);
+
+    let mut hand = hand;
+    assert_eq!(type_str(&mut hand, "9s")[1], Entry::Unplayable(card("9s")));
+    assert_eq!(type_str(&mut hand, "ad")[1], Entry::Card(2));
+}
+
+#[test]
+fn test_void_in_lead() {
+    let cards = ["9s", "tc", "qc"].iter().map(|c| c.parse().unwrap());
+    let trick = Trick::new(Suit::Spade, Seat::West, "kh".parse().unwrap());
+    let hand = Hand::new(Seat::South, cards).with_trick(&trick);
+    assert!(!hand.has_hint());
+
+    let mut state = HandState::default().with_selected(Some(0));
+    hand.select_next(&mut state);
+    assert_eq!(state.selected(), Some(1));
+    hand.select_previous(&mut state);
+    assert_eq!(state.selected(), Some(0));
+}
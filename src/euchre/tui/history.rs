@@ -11,8 +11,16 @@ use ratatui::widgets::{Block, ListState, Padding, Widget};
 use tree::PreorderNode;
 
 use crate::euchre::{Action, ActionData, ActionType, Log, LogId, Seat};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
 
+use super::line_with_span;
+
+mod graph;
+#[cfg(test)]
+mod test;
 mod tree;
+use self::graph::Graph;
 use self::tree::{Id as TreeId, Tree};
 
 const VERT: char = '│';
@@ -21,10 +29,13 @@ const ARC_UP_RIGHT: char = '╰';
 
 pub type HistoryState = ListState;
 
-/// A widget for displaying a tree-structured history of actions.
+/// A widget for displaying a tree-structured history of actions, either as an indented list or
+/// as a graph.
 #[derive(Debug, Clone)]
 pub struct History {
     items: Vec<Prefixed<HistoryItem>>,
+    graph: Graph,
+    show_graph: bool,
 }
 
 /// A history item.
@@ -37,6 +48,8 @@ enum HistoryItem {
         id: LogId,
         parent: Option<LogId>,
         action: Action,
+        /// The number of tricks that have been led, as of this action.
+        trick: u8,
     },
     /// The cursor position at the time the history widget was opened.
     Cursor { parent: Option<LogId> },
@@ -50,10 +63,28 @@ impl HistoryItem {
             Self::Action { parent, .. } | Self::Cursor { parent, .. } => *parent,
         }
     }
+
+    /// A badge for the phase of the round in which the action was taken, including the trick
+    /// number during play.
+    fn badge(&self) -> Option<String> {
+        let Self::Action { action, trick, .. } = self else {
+            return None;
+        };
+        Some(match action.action {
+            ActionType::BidTop => tr!("history-bid-top"),
+            ActionType::BidOther => tr!("history-bid-other"),
+            ActionType::DealerDiscard => tr!("history-discard"),
+            ActionType::Lead | ActionType::Follow | ActionType::CallRenege => {
+                tr!("history-trick", trick = *trick)
+            }
+        })
+    }
 }
 
-/// Helper function to build a tree out of a log.
-fn build_tree(cursor: Option<LogId>, log: &Log) -> Tree<HistoryItem> {
+/// Helper function to build a tree out of a log. The `Cursor` node is inserted under the
+/// specified parent, if any.
+#[allow(clippy::option_option)]
+fn build_tree(cursor: Option<Option<LogId>>, log: &Log) -> Tree<HistoryItem> {
     let mut builder = Tree::builder();
     let mut id_map: HashMap<Option<LogId>, TreeId> = HashMap::new();
     let mut parents: Vec<(TreeId, Option<LogId>)> = vec![];
@@ -63,20 +94,32 @@ fn build_tree(cursor: Option<LogId>, log: &Log) -> Tree<HistoryItem> {
     let root = builder.insert(HistoryItem::Deal { dealer });
     id_map.insert(None, root);
 
-    // Insert all actions, sorted by sequence number.
+    // Insert all actions, sorted by sequence number. Parents always precede their children, so
+    // each action's trick number can be counted up from its parent's.
+    let mut tricks: HashMap<LogId, u8> = HashMap::new();
     for node in log.action_nodes().sorted_unstable_by_key(|n| n.id) {
+        let led = u8::from(node.action.action == ActionType::Lead);
+        let trick = node
+            .parent
+            .and_then(|p| tricks.get(&p))
+            .copied()
+            .unwrap_or(0)
+            + led;
+        tricks.insert(node.id, trick);
         let id = builder.insert(HistoryItem::Action {
             id: node.id,
             parent: node.parent,
             action: node.action,
+            trick,
         });
         parents.push((id, node.parent));
         id_map.insert(Some(node.id), id);
     }
 
-    // Always insert the `Cursor` node.
-    let id = builder.insert(HistoryItem::Cursor { parent: cursor });
-    parents.push((id, cursor));
+    if let Some(cursor) = cursor {
+        let id = builder.insert(HistoryItem::Cursor { parent: cursor });
+        parents.push((id, cursor));
+    }
 
     // Now that all nodes have been assigned IDs, we can set the parents.
     for (id, parent) in parents {
@@ -130,14 +173,17 @@ impl PrefixHelper {
 impl History {
     /// Creates a new history widget.
     pub fn new(cursor: Option<LogId>, log: &Log) -> Self {
-        let tree = build_tree(cursor, log);
-        let mut items = vec![];
-        let mut helper = PrefixHelper::default();
-        for node in tree.preorder() {
-            let prefix = helper.prefix(&node);
-            items.push(Prefixed::new(prefix, node.data.clone()));
+        let tree = build_tree(Some(cursor), log);
+        Self {
+            items: prefixed_items(&tree),
+            graph: Graph::new(&tree),
+            show_graph: false,
         }
-        Self { items }
+    }
+
+    /// Switches between the list and the graph.
+    pub fn toggle_graph(&mut self) {
+        self.show_graph = !self.show_graph;
     }
 
     /// Returns the index of the cursor item.
@@ -157,6 +203,18 @@ impl History {
             .map(|item| item.inner().parent())
     }
 
+    /// Returns the log entry for the selected action, if an action is selected.
+    pub fn selected_action(&self, state: &HistoryState) -> Option<LogId> {
+        match state
+            .selected()
+            .and_then(|idx| self.items.get(idx))?
+            .inner()
+        {
+            HistoryItem::Action { id, .. } => Some(*id),
+            _ => None,
+        }
+    }
+
     /// Determines the indexes of the first and last item to be displayed, given the height
     /// of the rendering area.
     fn get_item_bounds(&self, state: &HistoryState, height: usize) -> (usize, usize) {
@@ -180,29 +238,65 @@ impl History {
     }
 }
 
-/// Helper function for translating an [`Action`] into a collection of [`Span`]s.
-fn action_spans(action: Action) -> Vec<Span<'static>> {
-    let mut spans = vec![Span::from(action.seat.to_string())];
-    match (action.action, action.data) {
-        (_, ActionData::Pass) => spans.push(" passed".into()),
-        (_, ActionData::Call { suit, alone }) => {
-            spans.extend([" called ".into(), suit.to_span()]);
-            if alone {
-                spans.push(" alone".into());
+/// Assigns prefixes to the nodes of the tree, in preorder.
+fn prefixed_items(tree: &Tree<HistoryItem>) -> Vec<Prefixed<HistoryItem>> {
+    let mut helper = PrefixHelper::default();
+    tree.preorder()
+        .map(|node| Prefixed::new(helper.prefix(&node), node.data.clone()))
+        .collect()
+}
+
+/// Formats the log as text, laid out like the history list, without a cursor. Red suits are
+/// highlighted with ANSI escape codes if `color` is set.
+pub fn format_log(log: &Log, color: bool) -> String {
+    let tree = build_tree(None, log);
+    let mut text = String::new();
+    for item in prefixed_items(&tree) {
+        for span in item.into_line(false).spans {
+            match span.style.fg {
+                Some(Color::Red) if color => {
+                    text.push_str(&ansi_term::Colour::Red.paint(span.content).to_string());
+                }
+                _ => text.push_str(&span.content),
             }
         }
-        (ActionType::DealerDiscard, ActionData::Card { card }) => {
-            spans.extend([" discarded ".into(), card.to_span()]);
-        }
-        (ActionType::Lead, ActionData::Card { card }) => {
-            spans.extend([" led ".into(), card.to_span()]);
-        }
-        (ActionType::Follow, ActionData::Card { card }) => {
-            spans.extend([" followed ".into(), card.to_span()]);
+        text.push('\n');
+    }
+    text
+}
+
+/// Helper function for translating an [`Action`] into a collection of [`Span`]s.
+pub fn action_spans(action: Action) -> Vec<Span<'static>> {
+    let seat = action.seat.to_string();
+    let (text, span) = match (action.action, action.data) {
+        (_, ActionData::Pass) => return vec![tr!("history-passed", seat = seat).into()],
+        (_, ActionData::Call { suit, alone }) => {
+            let text = if alone {
+                tr!("history-called-alone", seat = seat, suit = PLACEHOLDER)
+            } else {
+                tr!("history-called", seat = seat, suit = PLACEHOLDER)
+            };
+            (text, suit.to_span())
         }
+        (ActionType::DealerDiscard, ActionData::Card { card }) => (
+            tr!("history-discarded", seat = seat, card = PLACEHOLDER),
+            card.to_span(),
+        ),
+        (ActionType::Lead, ActionData::Card { card }) => (
+            tr!("history-led", seat = seat, card = PLACEHOLDER),
+            card.to_span(),
+        ),
+        (ActionType::Follow, ActionData::Card { card }) => (
+            tr!("history-followed", seat = seat, card = PLACEHOLDER),
+            card.to_span(),
+        ),
+        (ActionType::CallRenege, ActionData::Card { card }) => (
+            tr!("history-called-renege", seat = seat, card = PLACEHOLDER),
+            card.to_span(),
+        ),
         _ => unreachable!(),
-    }
-    spans
+    };
+    line_with_span(&text, span).spans
 }
 
 trait IntoSpans {
@@ -213,9 +307,9 @@ trait IntoSpans {
 impl IntoSpans for HistoryItem {
     fn into_spans(self) -> Vec<Span<'static>> {
         match self {
-            Self::Deal { dealer } => vec![format!("{dealer} dealt").into()],
+            Self::Deal { dealer } => vec![tr!("history-dealt", seat = dealer.to_string()).into()],
             Self::Action { action, .. } => action_spans(action),
-            Self::Cursor { .. } => vec!["(you are here)".into()],
+            Self::Cursor { .. } => vec![tr!("history-cursor").into()],
         }
     }
 }
@@ -271,6 +365,10 @@ impl StatefulWidget for History {
         if let Some(index) = state.selected() {
             state.select(Some(index.max(1).min(self.items.len() - 1)));
         }
+        if self.show_graph {
+            self.graph.render(list_area, buf, state.selected());
+            return;
+        }
 
         let list_height = list_area.height as usize;
         let (first_index, last_index) = self.get_item_bounds(state, list_height);
@@ -285,7 +383,11 @@ impl StatefulWidget for History {
             .take(last_index - first_index + 1)
         {
             let selected = state.selected().is_some_and(|s| s == i);
-            let line = item.into_line(selected);
+            let badge = item.inner().badge();
+            let mut line = item.into_line(selected);
+            if let Some(badge) = badge {
+                line.push_span(format!("  {badge}").dim());
+            }
             line.render(item_area, buf);
             item_area = item_area.offset(Offset { x: 0, y: 1 });
         }
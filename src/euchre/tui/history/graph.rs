@@ -0,0 +1,221 @@
+//! A graphical rendering of the history tree.
+//!
+//! The tree is laid out left to right: each column is a depth in the tree, and each node is drawn
+//! as a box. A node's first child continues on its row, and every other child starts a new row
+//! below, joined to its parent by a branch.
+
+use std::convert::TryFrom;
+
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+
+use super::tree::Tree;
+use super::HistoryItem;
+use crate::euchre::{ActionData, ActionType};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The width of a node's label.
+const LABEL_WIDTH: usize = 4;
+
+/// The width of a column: a boxed label, and the edge to the next column.
+const COLUMN_WIDTH: u16 = LABEL_WIDTH as u16 + 4;
+
+const HORIZ: &str = "─";
+const VERT: &str = "│";
+const DOWN_HORIZ: &str = "┬";
+const VERT_RIGHT: &str = "├";
+const ARC_UP_RIGHT: &str = "╰";
+
+/// A history item, positioned in the graph.
+#[derive(Debug, Clone)]
+struct Node {
+    /// The column of the node.
+    depth: usize,
+    /// The row of the node.
+    row: usize,
+    /// The row of the node's parent, if it has one.
+    parent_row: Option<usize>,
+    /// The item.
+    item: HistoryItem,
+}
+
+impl Node {
+    /// Returns the position of the node's box, relative to the top-left of the graph.
+    fn position(&self) -> (u16, u16) {
+        let x = u16::try_from(self.depth).unwrap_or(u16::MAX);
+        let y = u16::try_from(self.row).unwrap_or(u16::MAX);
+        (x.saturating_mul(COLUMN_WIDTH), y)
+    }
+
+    /// Returns a short label for the node, padded to [`LABEL_WIDTH`].
+    fn label(&self) -> Line<'static> {
+        let spans: Vec<Span<'static>> = match &self.item {
+            HistoryItem::Deal { .. } => vec![tr!("history-graph-deal").into()],
+            HistoryItem::Cursor { .. } => vec![tr!("history-graph-here").into()],
+            HistoryItem::Action { action, .. } => {
+                let seat = action.seat.to_abbr();
+                match (action.action, action.data) {
+                    (_, ActionData::Pass) => vec![format!("{seat} --").into()],
+                    (_, ActionData::Call { suit, alone }) => vec![
+                        format!("{seat} ").into(),
+                        suit.to_span(),
+                        if alone { "*" } else { " " }.into(),
+                    ],
+                    (ActionType::DealerDiscard, ActionData::Card { card }) => {
+                        vec![format!("{seat}↓").into(), card.to_span()]
+                    }
+                    (ActionType::CallRenege, ActionData::Card { card }) => {
+                        vec![format!("{seat}!").into(), card.to_span()]
+                    }
+                    (_, ActionData::Card { card }) => {
+                        vec![format!("{seat} ").into(), card.to_span()]
+                    }
+                }
+            }
+        };
+        Line::from(spans)
+    }
+}
+
+/// A graph of the history tree, with nodes in the same order as the history list.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    nodes: Vec<Node>,
+}
+
+impl Graph {
+    /// Lays out the tree.
+    pub fn new(tree: &Tree<HistoryItem>) -> Self {
+        // The row of the most recently visited node at each depth.
+        let mut rows: Vec<usize> = vec![];
+        let mut last_row = 0;
+        let mut nodes = vec![];
+        for node in tree.preorder() {
+            // A first child immediately follows its parent in the traversal, and continues its
+            // row. Every other node starts a new row.
+            let row = match rows.len() {
+                0 => 0,
+                len if node.depth == len => rows[len - 1],
+                _ => {
+                    last_row += 1;
+                    last_row
+                }
+            };
+            rows.truncate(node.depth);
+            let parent_row = rows.last().copied();
+            rows.push(row);
+            nodes.push(Node {
+                depth: node.depth,
+                row,
+                parent_row,
+                item: node.data.clone(),
+            });
+        }
+        Self { nodes }
+    }
+
+    /// Renders the graph, scrolled so that the selected node is visible.
+    pub fn render(&self, area: Rect, buf: &mut Buffer, selected: Option<usize>) {
+        let (offset_x, offset_y) =
+            selected
+                .and_then(|index| self.nodes.get(index))
+                .map_or((0, 0), |node| {
+                    let (x, y) = node.position();
+                    (
+                        (x + COLUMN_WIDTH).saturating_sub(area.width),
+                        (y + 1).saturating_sub(area.height),
+                    )
+                });
+        let mut canvas = Canvas {
+            area,
+            buf,
+            offset_x,
+            offset_y,
+        };
+        for (index, node) in self.nodes.iter().enumerate() {
+            let (x, y) = node.position();
+            if let Some(parent_row) = node.parent_row {
+                canvas.edge(x, y, u16::try_from(parent_row).unwrap_or(u16::MAX));
+            }
+            let mut label = node.label();
+            if selected == Some(index) {
+                label = label.reversed();
+            }
+            canvas.put(x, y, "[");
+            canvas.line(x + 1, y, &label);
+            canvas.put(x + 1 + LABEL_WIDTH as u16, y, "]");
+        }
+    }
+}
+
+/// Helper for drawing into a scrolled region of the buffer.
+struct Canvas<'a> {
+    area: Rect,
+    buf: &'a mut Buffer,
+    offset_x: u16,
+    offset_y: u16,
+}
+
+impl Canvas<'_> {
+    /// Translates graph coordinates to a buffer position, if it's visible.
+    fn translate(&self, x: u16, y: u16) -> Option<Position> {
+        let x = x.checked_sub(self.offset_x)?;
+        let y = y.checked_sub(self.offset_y)?;
+        (x < self.area.width && y < self.area.height)
+            .then(|| Position::new(self.area.x + x, self.area.y + y))
+    }
+
+    /// Returns the symbol at the specified position, if it's visible.
+    fn get(&self, x: u16, y: u16) -> Option<&str> {
+        let pos = self.translate(x, y)?;
+        self.buf.cell(pos).map(|cell| cell.symbol())
+    }
+
+    /// Draws a symbol at the specified position.
+    fn put(&mut self, x: u16, y: u16, symbol: &str) {
+        if let Some(pos) = self.translate(x, y) {
+            if let Some(cell) = self.buf.cell_mut(pos) {
+                cell.set_symbol(symbol);
+            }
+        }
+    }
+
+    /// Draws a line of text starting at the specified position.
+    fn line(&mut self, x: u16, y: u16, line: &Line<'_>) {
+        let mut x = x;
+        for span in &line.spans {
+            for grapheme in span.styled_graphemes(line.style) {
+                if let Some(pos) = self.translate(x, y) {
+                    if let Some(cell) = self.buf.cell_mut(pos) {
+                        cell.set_symbol(grapheme.symbol).set_style(grapheme.style);
+                    }
+                }
+                x += 1;
+            }
+        }
+    }
+
+    /// Draws the edge into a node at the specified position, from its parent's row.
+    fn edge(&mut self, x: u16, y: u16, parent_row: u16) {
+        let branch = x.saturating_sub(2);
+        if y == parent_row {
+            self.put(branch, y, HORIZ);
+        } else {
+            self.put(branch, parent_row, DOWN_HORIZ);
+            for row in parent_row + 1..y {
+                // An earlier sibling's branch ends here; it now continues past it.
+                let symbol = if self.get(branch, row) == Some(ARC_UP_RIGHT) {
+                    VERT_RIGHT
+                } else {
+                    VERT
+                };
+                self.put(branch, row, symbol);
+            }
+            self.put(branch, y, ARC_UP_RIGHT);
+        }
+        self.put(branch + 1, y, HORIZ);
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+use crate::euchre::{Action, Seat};
+
+#[test]
+fn test_render() {
+    // deal -- N -- E -- S
+    //           |    `- here
+    //           `- E
+    let pass = |id, parent, seat| HistoryItem::Action {
+        id,
+        parent,
+        action: Action::new(seat, ActionType::BidTop, ActionData::Pass),
+        trick: 0,
+    };
+    let mut builder = Tree::builder();
+    let deal = builder.insert(HistoryItem::Deal { dealer: Seat::West });
+    let north = builder.insert(pass(0, None, Seat::North));
+    let east = builder.insert(pass(1, Some(0), Seat::East));
+    let south = builder.insert(pass(2, Some(1), Seat::South));
+    let alt = builder.insert(pass(3, Some(0), Seat::East));
+    let cursor = builder.insert(HistoryItem::Cursor { parent: Some(1) });
+    for (node, parent) in [
+        (north, deal),
+        (east, north),
+        (south, east),
+        (alt, north),
+        (cursor, east),
+    ] {
+        builder.set_parent(node, parent);
+    }
+    let graph = Graph::new(&builder.build());
+
+    let area = Rect::new(0, 0, 30, 4);
+    let mut buf = Buffer::empty(area);
+    graph.render(area, &mut buf, None);
+    let expect = Buffer::with_lines([
+        "[deal]──[N --]┬─[E --]┬─[S --]",
+        "              │       ╰─[here]",
+        "              ╰─[E --]        ",
+        "                              ",
+    ]);
+    assert_eq!(buf, expect);
+}
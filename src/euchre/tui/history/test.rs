@@ -0,0 +1,73 @@
+use super::*;
+use crate::euchre::{Card, Rank, RoundConfig, Suit};
+
+#[test]
+fn test_format_log() {
+    let config = RoundConfig::random();
+    let dealer = config.dealer();
+    let first = dealer.next();
+    let second = first.next();
+    let mut log = Log::new(config);
+    let pass = |seat| Action::new(seat, ActionType::BidTop, ActionData::Pass);
+    let call = |seat| {
+        let data = ActionData::Call {
+            suit: Suit::Heart,
+            alone: false,
+        };
+        Action::new(seat, ActionType::BidTop, data)
+    };
+    let a = log.insert(None, pass(first));
+    log.insert(Some(a), pass(second));
+    log.insert(None, call(first));
+
+    let expect = format!(
+        "{dealer} dealt\n\
+         ├ {first} passed\n\
+         │ ╰ {second} passed\n\
+         ╰ {first} called ♡\n"
+    );
+    assert_eq!(format_log(&log, false), expect);
+
+    let colored = format_log(&log, true);
+    assert!(colored.contains(&ansi_term::Colour::Red.paint("♡").to_string()));
+}
+
+#[test]
+fn test_history_badges() {
+    let config = RoundConfig::random();
+    let first = config.dealer().next();
+    let mut log = Log::new(config);
+    let pass = |action| Action::new(first, action, ActionData::Pass);
+    let card = |action, rank| {
+        let data = ActionData::Card {
+            card: Card::new(rank, Suit::Spade),
+        };
+        Action::new(first, action, data)
+    };
+    let a = log.insert(None, pass(ActionType::BidTop));
+    let b = log.insert(Some(a), pass(ActionType::BidOther));
+    let c = log.insert(Some(b), card(ActionType::DealerDiscard, Rank::Nine));
+    let d = log.insert(Some(c), card(ActionType::Lead, Rank::Ace));
+    let e = log.insert(Some(d), card(ActionType::Follow, Rank::King));
+    log.insert(Some(e), card(ActionType::Lead, Rank::Queen));
+    log.insert(Some(c), card(ActionType::Lead, Rank::Jack));
+
+    let history = History::new(None, &log);
+    let badges: Vec<_> = history
+        .items
+        .iter()
+        .map(|item| item.inner().badge())
+        .collect();
+    let expect = [
+        None,
+        Some("bid 1"),
+        Some("bid 2"),
+        Some("discard"),
+        Some("trick 1"),
+        Some("trick 1"),
+        Some("trick 2"),
+        Some("trick 1"),
+        None,
+    ];
+    assert_eq!(badges, expect.map(|b| b.map(String::from)));
+}
@@ -104,14 +104,16 @@ impl<T> Node<T> {
 pub struct Preorder<'a, T> {
     /// The log we're traversing.
     tree: &'a Tree<T>,
-    /// A queue of nodes to traverse.
-    queue: VecDeque<Id>,
+    /// A queue of nodes to traverse, with their depths.
+    queue: VecDeque<(Id, usize)>,
 }
 
 /// A node in the pre-order traversal of the tree.
 pub struct PreorderNode<'a, T> {
     /// The node.
     pub data: &'a T,
+    /// The number of ancestors of the node.
+    pub depth: usize,
     /// Set to `true` if the node has other siblings.
     pub sibling: bool,
     /// Set to `true` if the node has other siblings, and this is the last one in the traversal.
@@ -138,7 +140,12 @@ impl<T> Tree<T> {
 
     /// Creates a new iterator for a preorder traversal of the tree.
     pub fn preorder(&self) -> Preorder<'_, T> {
-        let queue = self.roots.iter().copied().sorted_unstable().collect();
+        let queue = self
+            .roots
+            .iter()
+            .sorted_unstable()
+            .map(|&id| (id, 0))
+            .collect();
         Preorder { tree: self, queue }
     }
 }
@@ -147,7 +154,7 @@ impl<'a, T> Iterator for Preorder<'a, T> {
     type Item = PreorderNode<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let id = self.queue.pop_front()?;
+        let (id, depth) = self.queue.pop_front()?;
         let node = self.tree.get(id).expect("consistency");
         let siblings = node
             .parent
@@ -163,11 +170,12 @@ impl<'a, T> Iterator for Preorder<'a, T> {
         if let Some(children) = children {
             // Visit children in ascending order.
             for id in children.iter().sorted_unstable().rev() {
-                self.queue.push_front(*id);
+                self.queue.push_front((*id, depth + 1));
             }
         }
         Some(PreorderNode {
             data: &node.data,
+            depth,
             sibling,
             last_sibling,
             leaf: children.is_none(),
@@ -13,18 +13,18 @@ fn test_preorder() {
     let tree = builder.build();
     let nodes: Vec<_> = tree
         .preorder()
-        .map(|n| (*n.data, n.sibling, n.last_sibling, n.leaf))
+        .map(|n| (*n.data, n.depth, n.sibling, n.last_sibling, n.leaf))
         .collect();
     assert_eq!(
         nodes,
         vec![
-            ('d', false, false, false),
-            ('f', false, false, false),
-            ('b', true, false, false),
-            ('c', false, false, true),
-            ('g', true, true, false),
-            ('a', true, false, true),
-            ('e', true, true, true),
+            ('d', 0, false, false, false),
+            ('f', 1, false, false, false),
+            ('b', 2, true, false, false),
+            ('c', 3, false, false, true),
+            ('g', 2, true, true, false),
+            ('a', 3, true, false, true),
+            ('e', 3, true, true, true),
         ]
     );
 }
@@ -7,11 +7,14 @@ use ratatui::{
     widgets::{Block, Paragraph, Widget},
 };
 
-use crate::euchre::{Contract, Event, ExpectAction, Game, Round, RoundOutcome, Seat};
+use crate::euchre::{Contract, Event, ExpectAction, Game, Round, Seat};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
 
-use super::Mode;
+use super::{line_with_span, Mode};
 
 enum First {
+    Cut,
     Dealer(Seat),
     Contract(Contract),
     Empty,
@@ -19,12 +22,17 @@ enum First {
 impl First {
     fn into_line(self) -> Line<'static> {
         match self {
-            Self::Dealer(dealer) => format!("{dealer} dealt.").into(),
-            Self::Contract(contract) => Line::from_iter([
-                format!("{} called ", contract.maker).into(),
-                contract.suit.to_span(),
-                if contract.alone { " alone." } else { "." }.into(),
-            ]),
+            Self::Cut => tr!("narration-low-card-deals").into(),
+            Self::Dealer(dealer) => tr!("narration-dealt", seat = dealer.to_string()).into(),
+            Self::Contract(contract) => {
+                let seat = contract.maker.to_string();
+                let text = if contract.alone {
+                    tr!("narration-called-alone", seat = seat, suit = PLACEHOLDER)
+                } else {
+                    tr!("narration-called", seat = seat, suit = PLACEHOLDER)
+                };
+                line_with_span(&text, contract.suit.to_span())
+            }
             Self::Empty => Line::default(),
         }
     }
@@ -38,19 +46,47 @@ enum Second {
 impl Second {
     fn into_line(self) -> Line<'static> {
         match self {
+            Self::Event(Event::Cut(cut)) => {
+                tr!("narration-deals-first", seat = cut.dealer.to_string()).into()
+            }
+            Self::Event(Event::Packet(seat, count)) => {
+                tr!("narration-packet", count = count, seat = seat.to_string()).into()
+            }
+            Self::Event(Event::Redeal(dealer)) => {
+                tr!("narration-redeal", seat = dealer.to_string()).into()
+            }
+            Self::Event(Event::Renege(revoke)) => tr!(
+                "narration-renege",
+                seat = revoke.seat.to_string(),
+                card = revoke.card.to_string()
+            )
+            .into(),
             Self::Event(Event::Trick(trick)) => {
-                format!("{} takes the trick.", trick.best().0).into()
+                tr!("narration-trick", seat = trick.best().0.to_string()).into()
             }
-            Self::Event(Event::Round(RoundOutcome { team, points })) => {
-                format!("{} win {points} points.", team.to_abbr()).into()
+            Self::Event(Event::Game(outcome)) => {
+                tr!("narration-game", team = outcome.winner.to_abbr()).into()
             }
-            Self::Event(Event::Game(team)) => format!("{} wins the game.", team.to_abbr()).into(),
-            Self::Expect(ExpectAction { seat, action }) => format!("{seat} to {action}.").into(),
+            Self::Expect(ExpectAction { seat, action }) => tr!(
+                "narration-expect",
+                seat = seat.to_string(),
+                action = action.to_string()
+            )
+            .into(),
             _ => Line::default(),
         }
     }
 }
 
+/// Describes an event in a line, as it would be narrated while it was shown.
+pub fn narrate(event: &Event) -> Line<'static> {
+    match event {
+        Event::Deal(dealer, _) => First::Dealer(*dealer).into_line(),
+        Event::Call(contract) => First::Contract(*contract).into_line(),
+        _ => Second::Event(event.clone()).into_line(),
+    }
+}
+
 pub struct Info(First, Second);
 
 impl Info {
@@ -59,6 +95,7 @@ impl Info {
 
         let first = match (mode, round.contract()) {
             (Mode::Event(Event::Game(_)), _) => First::Empty,
+            (Mode::Event(Event::Cut(_)), _) => First::Cut,
             (_, Some(contract)) => First::Contract(contract),
             (_, None) => First::Dealer(round.dealer()),
         };
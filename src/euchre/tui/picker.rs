@@ -0,0 +1,122 @@
+//! File picker widget
+
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, List, ListState, StatefulWidget};
+
+use crate::euchre::{Metadata, RawGame, RawLog};
+use crate::tr;
+
+pub type FilePickerState = ListState;
+
+/// A saved game or round.
+#[derive(Debug, Clone)]
+struct Entry {
+    path: PathBuf,
+    metadata: Option<Metadata>,
+    /// Whether the file is from the recently used list.
+    recent: bool,
+}
+
+impl Entry {
+    /// Reads a file, returning `None` if it isn't a saved game or round.
+    fn read(path: PathBuf, recent: bool) -> Option<Self> {
+        match RawGame::from_json_file(&path) {
+            Ok(game) => Some(Self {
+                metadata: game.metadata().cloned(),
+                path,
+                recent,
+            }),
+            Err(_) => RawLog::from_json_file(&path).ok().map(|_| Self {
+                path,
+                metadata: None,
+                recent,
+            }),
+        }
+    }
+}
+
+/// A list of saved games and rounds to choose from.
+#[derive(Debug, Clone)]
+pub struct FilePicker {
+    entries: Vec<Entry>,
+}
+
+impl FilePicker {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 72;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 12;
+
+    /// Lists recently used files, followed by the saved games and rounds in a directory. Other
+    /// JSON files are skipped, as are recent files that no longer exist.
+    pub fn new(dir: &Path, recent: &[PathBuf]) -> io::Result<Self> {
+        let mut paths = vec![];
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension() == Some(OsStr::new("json")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        let is_recent =
+            |path: &Path| fs::canonicalize(path).is_ok_and(|path| recent.contains(&path));
+        let entries = recent
+            .iter()
+            .filter_map(|path| Entry::read(path.clone(), true))
+            .chain(
+                paths
+                    .into_iter()
+                    .filter(|path| !is_recent(path))
+                    .filter_map(|path| Entry::read(path, false)),
+            )
+            .collect();
+        Ok(Self { entries })
+    }
+
+    /// Returns true if there are no files to choose from.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the selected file.
+    pub fn selected(&self, state: &FilePickerState) -> Option<&Path> {
+        state
+            .selected()
+            .and_then(|idx| self.entries.get(idx))
+            .map(|entry| entry.path.as_path())
+    }
+}
+
+impl StatefulWidget for &FilePicker {
+    type State = FilePickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+        let items = self.entries.iter().map(|entry| {
+            // Recent files may be anywhere, so show where.
+            let name = if entry.recent {
+                entry.path.display().to_string()
+            } else {
+                entry
+                    .path
+                    .file_name()
+                    .map_or_else(String::new, |n| n.to_string_lossy().into_owned())
+            };
+            let summary = entry.metadata.as_ref().map(Metadata::summary);
+            let mut line = Line::from(name.bold());
+            if let Some(summary) = summary.filter(|s| !s.is_empty()) {
+                line.push_span(format!("  {summary}").dim());
+            }
+            line
+        });
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" {} ", tr!("picker-title"))))
+            .highlight_style(Style::new().reversed());
+        StatefulWidget::render(list, area, buf, state);
+    }
+}
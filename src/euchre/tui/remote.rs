@@ -0,0 +1,393 @@
+//! Terminal UI for a game hosted remotely.
+
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, KeyCode};
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::euchre::net::{
+    Client, ClientMessage, NetError, SeatStatus, ServerMessage, TableSummary,
+};
+use crate::euchre::{Action, CardBack, Event, Game, Robot, Round, RoundView, Seat};
+use crate::tr;
+
+use super::chat::Chat;
+use super::{
+    messages, render_table, robot_suggestion, user_action_mode, Mode, Panes, Term, PACKET_DELAY,
+};
+
+/// How often to check for messages from the host, while waiting for user input.
+const POLL_DELAY: Duration = Duration::from_millis(50);
+
+/// How long spectators are shown each event, before it is acknowledged automatically.
+const SPECTATOR_DELAY: Duration = Duration::from_millis(1500);
+
+/// How long to wait between attempts to reconnect to the host.
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// The number of chat messages to retain.
+const CHAT_HISTORY: usize = 100;
+
+/// Terminal UI state for a remote game.
+pub struct RemoteTui {
+    /// The current mode.
+    mode: Mode,
+    /// The most recent view of the game, once the host has sent one.
+    game: Option<Game<RoundView>>,
+    /// The state of the table, while waiting for the game to begin.
+    table: Option<TableSummary>,
+    /// The connection to the host.
+    client: Client,
+    /// Messages received from the host, but not yet processed.
+    inbox: VecDeque<ServerMessage>,
+    /// The robot implementation, for suggestions.
+    robot: Robot,
+    /// Chat messages received from the host.
+    chat: VecDeque<(Seat, String)>,
+    /// The chat message being composed, if any.
+    draft: Option<String>,
+    /// When the most recent view of the game arrived, from which the clock of the player to act
+    /// runs, if the game is played against the clock.
+    updated: Option<Instant>,
+    /// When the connection to the host was lost, or the last attempt to reconnect.
+    disconnected: Option<Instant>,
+    /// An error message to display to the user.
+    error: Option<String>,
+    /// A debug message to display to the user.
+    debug: Option<String>,
+    /// Set to true to exit the main loop.
+    exit: bool,
+}
+
+impl From<Client> for RemoteTui {
+    fn from(client: Client) -> Self {
+        Self {
+            mode: Mode::Wait,
+            game: None,
+            table: None,
+            client,
+            inbox: VecDeque::new(),
+            robot: Robot::default(),
+            chat: VecDeque::new(),
+            draft: None,
+            updated: None,
+            disconnected: None,
+            error: None,
+            debug: None,
+            exit: false,
+        }
+    }
+}
+
+impl RemoteTui {
+    /// Connects to the host at the specified URL, and claims a seat at the named table.
+    pub fn connect(url: &str, table: Option<&str>, seat: Option<Seat>) -> Result<Self, NetError> {
+        Client::join(url, table, seat).map(Self::from)
+    }
+
+    /// Connects to the host at the specified URL as a spectator of the named table.
+    pub fn spectate(url: &str, table: Option<&str>) -> Result<Self, NetError> {
+        Client::spectate(url, table).map(Self::from)
+    }
+
+    /// Runs the terminal UI until the user exits.
+    pub fn run(mut self, mut terminal: Term) -> anyhow::Result<()> {
+        while !self.exit {
+            terminal.draw(|frame| self.render_frame(frame))?;
+            self.handle_events()?;
+            self.reconnect();
+            self.receive();
+            self.step();
+        }
+        Ok(())
+    }
+
+    // Top-level frame renderer.
+    fn render_frame(&mut self, frame: &mut Frame) {
+        let mut error = self.error.clone();
+        if self.disconnected.is_some() {
+            error = Some(tr!("remote-disconnected"));
+        }
+        let messages = messages(error.as_deref(), &[], self.debug.as_deref());
+        if let Some(game) = &self.game {
+            let seat = match self.mode {
+                Mode::Hand(_, _) => None,
+                _ => self.client.seat(),
+            };
+            let areas = render_table(
+                frame,
+                frame.area(),
+                Panes::default(),
+                &mut self.mode,
+                game,
+                seat,
+                self.client.seat().unwrap_or(Seat::South),
+                CardBack::default(),
+                self.updated,
+                None,
+                messages,
+            );
+            frame.render_widget(Chat::new(&self.chat, self.draft.as_deref()), areas.history);
+        } else {
+            let [lobby, chat] = Layout::horizontal([Constraint::Fill(1), Constraint::Length(40)])
+                .areas(frame.area());
+            let mut lines = self.lobby_lines();
+            lines.extend(messages);
+            frame.render_widget(Paragraph::new(lines), lobby);
+            frame.render_widget(Chat::new(&self.chat, self.draft.as_deref()), chat);
+        }
+    }
+
+    /// Describes the table, while waiting for the game to begin.
+    fn lobby_lines(&self) -> Vec<Line<'static>> {
+        let Some(table) = &self.table else {
+            return vec![Line::from(tr!("remote-waiting-for-players"))];
+        };
+        let mut lines = vec![
+            Line::from(tr!("remote-table", name = table.name.as_str()).bold()),
+            Line::default(),
+        ];
+        for (seat, status) in table.seats.iter() {
+            let status = match status {
+                SeatStatus::Open => tr!("remote-seat-open"),
+                SeatStatus::Robot => tr!("remote-seat-robot"),
+                SeatStatus::Claimed {
+                    connected: false, ..
+                } => tr!("remote-seat-disconnected"),
+                SeatStatus::Claimed { ready: true, .. } => tr!("remote-seat-ready"),
+                SeatStatus::Claimed { ready: false, .. } => tr!("remote-seat-not-ready"),
+            };
+            let status = if Some(seat) == self.client.seat() {
+                tr!("remote-seat-yours", status = status)
+            } else {
+                status
+            };
+            lines.push(Line::from(format!("  {:<6} {status}", seat.to_string())));
+        }
+        lines.push(Line::default());
+        if self.client.seat().is_some() {
+            lines.push(Line::from(tr!("remote-toggle-ready")));
+        } else {
+            lines.push(Line::from(tr!("remote-waiting-for-game")));
+        }
+        lines
+    }
+
+    /// Returns true if the user's seat is marked ready.
+    fn is_ready(&self) -> bool {
+        match (&self.table, self.client.seat()) {
+            (Some(table), Some(seat)) => {
+                matches!(table.seats[seat], SeatStatus::Claimed { ready: true, .. })
+            }
+            _ => false,
+        }
+    }
+
+    /// Top-level event handler.
+    fn handle_events(&mut self) -> io::Result<()> {
+        // The deal is animated, advancing automatically unless a key is pressed. Spectators
+        // aren't expected to acknowledge events, so those advance automatically too.
+        let delay = match &self.mode {
+            Mode::Event(Event::Packet(_, _)) => Some(PACKET_DELAY),
+            Mode::Event(Event::Game(_)) => None,
+            Mode::Event(_) if self.client.seat().is_none() => Some(SPECTATOR_DELAY),
+            _ => None,
+        };
+        if !event::poll(delay.unwrap_or(POLL_DELAY))? {
+            if delay.is_some() {
+                self.acknowledge();
+            }
+            return Ok(());
+        }
+
+        let event::Event::Key(key) = event::read()? else {
+            return Ok(());
+        };
+
+        // Output messages only persist for one refresh cycle.
+        self.error = None;
+        self.debug = None;
+
+        // While composing a chat message, keys are captured by the input line.
+        if let Some(draft) = &mut self.draft {
+            match key.code {
+                KeyCode::Enter => self.send_chat(),
+                KeyCode::Esc => self.draft = None,
+                KeyCode::Backspace => {
+                    draft.pop();
+                }
+                KeyCode::Char(c) => draft.push(c),
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        #[allow(clippy::match_same_arms)]
+        match (&mut self.mode, key.code) {
+            // Quit
+            (_, KeyCode::Char('q')) => self.exit = true,
+
+            // Chat
+            (_, KeyCode::Char('c')) if self.client.seat().is_some() => {
+                self.draft = Some(String::new());
+            }
+
+            // Ready
+            (Mode::Wait, KeyCode::Char('r')) if self.game.is_none() => {
+                let ready = !self.is_ready();
+                if self.client.set_ready(ready).is_err() {
+                    self.disconnected.get_or_insert_with(Instant::now);
+                }
+            }
+
+            // End of game
+            (Mode::Event(Event::Game(_)), _) => (),
+
+            // What would the robot do?
+            (Mode::Hand(_, _) | Mode::ActionChoice(_, _), KeyCode::Char('?')) => {
+                if let Some(game) = &self.game {
+                    self.debug = robot_suggestion(&self.robot, game.round());
+                }
+            }
+
+            // Event acknowledgement
+            (Mode::Event(_), _) => self.acknowledge(),
+
+            // Hand management
+            (Mode::Hand(hand, state), KeyCode::Enter | KeyCode::Char(' ')) => {
+                let expect = self.game.as_ref().and_then(|g| g.round().next_action());
+                if let Some(action) = hand.action(state, expect) {
+                    self.apply_action(action);
+                }
+            }
+            (Mode::Hand(_, s), KeyCode::Left | KeyCode::Char('h')) => s.select_previous(),
+            (Mode::Hand(_, s), KeyCode::Right | KeyCode::Char('l')) => s.select_next(),
+
+            // Action choices
+            (Mode::ActionChoice(choice, state), KeyCode::Enter | KeyCode::Char(' ')) => {
+                let expect = self.game.as_ref().and_then(|g| g.round().next_action());
+                if let Some(action) = choice.action(state, expect) {
+                    self.apply_action(action);
+                }
+            }
+            (Mode::ActionChoice(_, s), KeyCode::Up | KeyCode::Char('k')) => s.select_previous(),
+            (Mode::ActionChoice(_, s), KeyCode::Down | KeyCode::Char('j')) => s.select_next(),
+
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to rejoin the host periodically, if the connection has been lost.
+    fn reconnect(&mut self) {
+        match self.disconnected {
+            Some(at) if at.elapsed() >= RECONNECT_DELAY => match self.client.reconnect() {
+                Ok(()) => self.disconnected = None,
+                Err(_) => self.disconnected = Some(Instant::now()),
+            },
+            _ => (),
+        }
+    }
+
+    /// Receives pending messages from the host.
+    fn receive(&mut self) {
+        if self.disconnected.is_some() {
+            return;
+        }
+        loop {
+            match self.client.try_recv() {
+                Ok(Some(ServerMessage::Chat { seat, text })) => {
+                    if self.chat.len() == CHAT_HISTORY {
+                        self.chat.pop_front();
+                    }
+                    self.chat.push_back((seat, text));
+                }
+                Ok(Some(msg)) => self.inbox.push_back(msg),
+                Ok(None) => break,
+                Err(_) => {
+                    self.disconnected = Some(Instant::now());
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Processes messages from the host, until an event needs to be displayed to the user.
+    fn step(&mut self) {
+        // Events are displayed until the user acknowledges them.
+        if matches!(self.mode, Mode::Event(_)) {
+            return;
+        }
+        let mut changed = matches!(self.mode, Mode::Wait);
+        while let Some(msg) = self.inbox.pop_front() {
+            match msg {
+                ServerMessage::State { game } => {
+                    self.game = Some(game.into());
+                    self.updated = Some(Instant::now());
+                    changed = true;
+                }
+                ServerMessage::Event { event } => {
+                    self.mode = Mode::event(event);
+                    return;
+                }
+                ServerMessage::Table { table } => self.table = Some(table),
+                ServerMessage::Error { message } => self.error = Some(message),
+                ServerMessage::Welcome { .. }
+                | ServerMessage::Tables { .. }
+                | ServerMessage::Spectating
+                | ServerMessage::Chat { .. } => (),
+            }
+        }
+        if changed {
+            self.await_turn();
+        }
+    }
+
+    /// Prompts the user for an action, if it's their turn. Otherwise, waits.
+    fn await_turn(&mut self) {
+        let seat = self.client.seat();
+        let game = self.game.as_ref();
+        self.mode = match game.and_then(|g| g.round().next_action().map(|e| (g, e))) {
+            Some((game, expect)) if Some(expect.seat) == seat => user_action_mode(game, expect),
+            _ => Mode::Wait,
+        };
+    }
+
+    /// Acknowledges the event being displayed.
+    fn acknowledge(&mut self) {
+        self.mode = Mode::Wait;
+        self.step();
+    }
+
+    /// Sends the chat message being composed.
+    fn send_chat(&mut self) {
+        let Some(text) = self.draft.take().filter(|t| !t.trim().is_empty()) else {
+            return;
+        };
+        if self.client.send(ClientMessage::Chat { text }).is_err() {
+            self.disconnected.get_or_insert_with(Instant::now);
+        }
+    }
+
+    /// Submits the specified action to the host.
+    fn apply_action(&mut self, action: Action) {
+        let Some(game) = &mut self.game else {
+            return;
+        };
+        let round = game.round_mut();
+        if let Err(err) = round.apply_action(action) {
+            self.error = Some(err.to_string());
+            return;
+        }
+        for action in round.take_pending() {
+            if self.client.send(ClientMessage::Action { action }).is_err() {
+                self.disconnected.get_or_insert_with(Instant::now);
+            }
+        }
+        self.mode = Mode::Wait;
+    }
+}
@@ -0,0 +1,59 @@
+//! Trick replay
+
+use crate::euchre::{ActionType, Card, FastRound, Log, LogId, Round, RoundError, SeatMap, Trick};
+
+#[cfg(test)]
+mod test;
+
+/// A trick being replayed in the arena, card by card.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    trick: Trick,
+    shown: usize,
+}
+
+impl Replay {
+    /// Finds the trick in which the specified card was played. If the trick is unfinished at that
+    /// point, it is completed along the first recorded continuation. Returns `None` if the action
+    /// isn't a card played into a trick.
+    pub fn new(log: &Log, id: LogId) -> Result<Option<Self>, RoundError> {
+        let leaf = log.first_leaf(Some(id)).expect("starts from a node");
+        let mut round = FastRound::from(log.config().clone());
+        let mut index = None;
+        for (node, action) in log.backtrace(leaf)? {
+            round.apply_action(action)?;
+            if node == id {
+                if !matches!(action.action, ActionType::Lead | ActionType::Follow) {
+                    return Ok(None);
+                }
+                index = Some(round.tricks().len() - 1);
+            }
+            let tricks = round.tricks();
+            if index.is_some_and(|i| tricks.iter().nth(i).is_some_and(|t| tricks.is_complete(t))) {
+                break;
+            }
+        }
+        Ok(index
+            .and_then(|i| round.tricks().iter().nth(i).cloned())
+            .map(|trick| Self { trick, shown: 0 }))
+    }
+
+    /// Shows the next card. Returns false once the whole trick has been shown.
+    pub fn advance(&mut self) -> bool {
+        if self.shown < self.trick.len() {
+            self.shown += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the cards shown so far.
+    pub fn cards(&self) -> SeatMap<Option<Card>> {
+        let mut cards = SeatMap::default();
+        for &(seat, card) in self.trick.cards().iter().take(self.shown) {
+            cards[seat] = Some(card);
+        }
+        cards
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::euchre::{ActionData, LoggingRound, Player, Robot};
+
+#[test]
+fn test_replay() {
+    let robot = Robot::default();
+    let round = loop {
+        let mut round = LoggingRound::random();
+        while let Some(expect) = round.next_action() {
+            let data = robot.take_action(round.player_state(expect.seat), expect.action);
+            round.apply_action(expect.with_data(data)).unwrap();
+        }
+        if round.contract().is_some() {
+            break round;
+        }
+    };
+    let log = round.log();
+
+    let (id, action) = log
+        .backtrace(round.cursor().unwrap())
+        .unwrap()
+        .into_iter()
+        .find(|(_, a)| a.action == ActionType::Lead)
+        .unwrap();
+    let ActionData::Card { card } = action.data else {
+        panic!("lead without a card");
+    };
+    let mut replay = Replay::new(log, id).unwrap().expect("a trick");
+    assert_eq!(replay.cards(), SeatMap::default());
+    assert!(replay.advance());
+    assert_eq!(replay.cards()[action.seat], Some(card));
+    let size = replay.trick.len();
+    assert!(size >= 3);
+    for _ in 1..size {
+        assert!(replay.advance());
+    }
+    assert!(!replay.advance());
+
+    let bid = log
+        .action_nodes()
+        .find(|n| n.action.action == ActionType::BidTop)
+        .unwrap();
+    assert!(Replay::new(log, bid.id).unwrap().is_none());
+}
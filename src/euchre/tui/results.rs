@@ -0,0 +1,128 @@
+//! End-of-round results widget
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Row, Table, Widget};
+
+use crate::euchre::{Contract, Game, Round, RoundOutcome, Team};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+use super::line_with_span;
+
+#[cfg(test)]
+mod test;
+
+/// Summarizes a finished round, until the user acknowledges it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundResults {
+    contract: Contract,
+    outcome: RoundOutcome,
+    /// The tricks taken by each team, indexed by [`Team`].
+    tricks: [u8; 2],
+    /// Each team's score, once the round is scored, indexed by [`Team`].
+    score: [u8; 2],
+}
+
+impl RoundResults {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 30;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 10;
+
+    /// Summarizes the game's current round, which finished with the specified outcome, before it
+    /// is scored. Returns `None` if trump was never called.
+    pub fn new<R: Round>(game: &Game<R>, outcome: RoundOutcome) -> Option<Self> {
+        let contract = game.round().contract()?;
+        let tricks = game.round().tricks();
+        let teams = [Team::NorthSouth, Team::EastWest];
+        Some(Self {
+            contract,
+            outcome,
+            tricks: teams.map(|team| tricks.win_count(team)),
+            score: teams.map(|team| {
+                let points = if team == outcome.team {
+                    outcome.points
+                } else {
+                    0
+                };
+                game.score(team) + points
+            }),
+        })
+    }
+
+    /// Returns true if the makers were euchred.
+    pub fn is_euchre(&self) -> bool {
+        self.outcome.team != self.contract.maker.team()
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let seat = self.contract.maker.to_string();
+        let called = if self.contract.alone {
+            tr!("narration-called-alone", seat = seat, suit = PLACEHOLDER)
+        } else {
+            tr!("narration-called", seat = seat, suit = PLACEHOLDER)
+        };
+        let result = if self.is_euchre() {
+            tr!(
+                "results-euchred",
+                makers = self.contract.maker.team().to_abbr(),
+                team = self.outcome.team.to_abbr(),
+                points = self.outcome.points
+            )
+        } else {
+            tr!(
+                "narration-round",
+                team = self.outcome.team.to_abbr(),
+                points = self.outcome.points
+            )
+        };
+        vec![
+            line_with_span(&called, self.contract.suit.to_span()),
+            Line::from(result).bold(),
+        ]
+    }
+
+    fn table(&self) -> Table<'static> {
+        let row = |label: String, values: [u8; 2]| {
+            Row::new([label, values[0].to_string(), values[1].to_string()])
+        };
+        let mut points = [0; 2];
+        points[self.outcome.team as usize] = self.outcome.points;
+        Table::new(
+            [
+                row(tr!("ui-tricks"), self.tricks),
+                row(tr!("results-points"), points),
+                row(tr!("ui-score"), self.score),
+            ],
+            [
+                Constraint::Length(8),
+                Constraint::Length(5),
+                Constraint::Length(5),
+            ],
+        )
+        .header(Row::new(["", "N/S", "E/W"]))
+    }
+}
+
+impl Widget for RoundResults {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("results-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [text, table, hint] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+        Paragraph::new(self.lines()).render(text, buf);
+        Widget::render(self.table(), table, buf);
+        Paragraph::new(tr!("results-continue"))
+            .dim()
+            .render(hint, buf);
+    }
+}
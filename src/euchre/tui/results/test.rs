@@ -0,0 +1,30 @@
+use super::*;
+use crate::euchre::tui::tutorial::Tutorial;
+use crate::euchre::{FastRound, Player, Robot};
+
+#[test]
+fn test_round_results() {
+    let mut game = Game::from(FastRound::from(Tutorial::config()));
+    assert!(RoundResults::new(&game, RoundOutcome::new(Team::NorthSouth, 1)).is_none());
+
+    let robot = Robot::default();
+    while let Some(expect) = game.round().next_action() {
+        let state = game.round().player_state(expect.seat);
+        let data = robot.take_action(state, expect.action);
+        game.round_mut()
+            .apply_action(expect.with_data(data))
+            .unwrap();
+    }
+    let outcome = game.round().outcome().unwrap();
+    let results = RoundResults::new(&game, outcome).unwrap();
+    assert_eq!(results.tricks.iter().sum::<u8>(), 5);
+    assert_eq!(results.score.iter().sum::<u8>(), outcome.points);
+    assert_eq!(results.score[outcome.team as usize], outcome.points);
+    let makers = results.contract.maker.team();
+    assert_eq!(results.is_euchre(), results.tricks[makers as usize] < 3);
+
+    game.score_round();
+    for team in [Team::NorthSouth, Team::EastWest] {
+        assert_eq!(game.score(team), results.score[team as usize]);
+    }
+}
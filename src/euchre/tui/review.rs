@@ -0,0 +1,273 @@
+//! Widget for reviewing a finished round, move by move
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+use std::time::Duration;
+
+use ratatui::layout::Offset;
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, ListState, Padding, Widget};
+
+use crate::euchre::{
+    playout, Action, ActionData, ActionType, FastRound, Log, LogId, Player, Robot, Round,
+    RoundError, Team, Trace,
+};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+use super::history::action_spans;
+use super::line_with_span;
+
+pub type ReviewState = ListState;
+
+/// The width of the evaluation bar, in cells.
+const BAR_WIDTH: usize = 16;
+
+/// A move in the reviewed line, along with the robot's preferred alternative.
+#[derive(Debug, Clone)]
+struct ReviewStep {
+    /// The log entry for the move.
+    id: LogId,
+    /// The log entry preceding the move.
+    parent: Option<LogId>,
+    /// The move that was played.
+    action: Action,
+    /// The move that the robot would have played instead.
+    robot: ActionData,
+    /// Points won by N/S, if the robot plays out the round after the move.
+    value: i32,
+    /// Points won by N/S, if the robot plays out the round from before the move.
+    robot_value: i32,
+    /// The reasoning of the robot that played the move, if it was recorded.
+    trace: Option<Trace>,
+    /// How long the player thought about the move, if it was recorded.
+    think_time: Option<Duration>,
+}
+
+/// A widget for walking through a finished round, with the robot's evaluation of each move.
+#[derive(Debug, Clone)]
+pub struct Review {
+    steps: Vec<ReviewStep>,
+    /// Points won by N/S at the end of the round.
+    final_value: i32,
+    /// The makers, and the points they expect to win from the selected position, if estimated.
+    expected: Option<(Team, f64)>,
+}
+
+impl Review {
+    /// Creates a review of the line of play that passes through the cursor. If the round isn't
+    /// finished at the cursor, the line is extended along the first recorded continuation.
+    pub fn new(cursor: Option<LogId>, log: &Log, robot: &Robot) -> Result<Self, RoundError> {
+        let line = match log.first_leaf(cursor) {
+            Some(id) => log.backtrace(id)?,
+            None => vec![],
+        };
+
+        let config = log.config();
+        let mut round = FastRound::from(config.clone());
+        let mut actions = vec![];
+        let mut steps = vec![];
+        let mut parent = None;
+        for (id, action) in line {
+            let state = round.player_state(action.seat);
+            // Reneges are called out of turn, so there's no alternative to suggest.
+            let robot_data = match action.action {
+                ActionType::CallRenege => action.data,
+                _ => robot.take_action(state, action.action),
+            };
+            actions.push(Action::new(action.seat, action.action, robot_data));
+            let robot_value = playout(config.clone(), &actions, robot, Team::NorthSouth)?;
+            actions.pop();
+            actions.push(action);
+            let value = playout(config.clone(), &actions, robot, Team::NorthSouth)?;
+            round.apply_action(action)?;
+            let node = log.node(id);
+            steps.push(ReviewStep {
+                id,
+                parent,
+                action,
+                robot: robot_data,
+                value,
+                robot_value,
+                trace: node.and_then(|node| node.trace.clone()),
+                think_time: node.and_then(|node| node.timing).map(|t| t.think_time()),
+            });
+            parent = Some(id);
+        }
+        let final_value = match round.outcome() {
+            Some(outcome) if outcome.team == Team::NorthSouth => i32::from(outcome.points),
+            Some(outcome) => -i32::from(outcome.points),
+            None => 0,
+        };
+        Ok(Self {
+            steps,
+            final_value,
+            expected: None,
+        })
+    }
+
+    /// The number of items in the review: one for each move, and one for the end of the round.
+    pub fn len(&self) -> usize {
+        self.steps.len() + 1
+    }
+
+    /// Returns the log entry for the position before the selected move.
+    #[allow(clippy::option_option)]
+    pub fn selected(&self, state: &ReviewState) -> Option<Option<LogId>> {
+        let index = state.selected()?;
+        Some(
+            self.steps
+                .get(index)
+                .map_or_else(|| self.end(), |step| step.parent),
+        )
+    }
+
+    /// Returns the log entry for the end of the round.
+    pub fn end(&self) -> Option<LogId> {
+        self.steps.last().map(|step| step.id)
+    }
+
+    /// Sets the points the makers expect to win from the selected position.
+    pub fn set_expected(&mut self, expected: Option<(Team, f64)>) {
+        self.expected = expected;
+    }
+
+    /// Returns the robot's evaluation of the selected position, in points won by N/S.
+    fn evaluation(&self, state: &ReviewState) -> i32 {
+        state
+            .selected()
+            .and_then(|index| self.steps.get(index))
+            .map_or(self.final_value, |step| step.robot_value)
+    }
+
+    /// Renders the recorded reasoning behind the selected move, if any.
+    fn trace_line(&self, state: &ReviewState) -> Option<Line<'static>> {
+        let step = self.steps.get(state.selected()?)?;
+        let trace = step.trace.as_ref()?;
+        let mut spans = vec![format!("{} ", tr!("review-thinking")).dim()];
+        for (i, &(data, score)) in trace.scores.iter().enumerate() {
+            if i > 0 {
+                spans.push(", ".dim());
+            }
+            spans.extend(data_spans(data));
+            spans.push(format!(" {score}").dim());
+        }
+        Some(Line::from_iter(spans))
+    }
+
+    /// Renders the evaluation bar.
+    fn eval_line(value: i32) -> Line<'static> {
+        // Four points is the most that can be won in a round.
+        let half = BAR_WIDTH / 2;
+        let offset = usize::try_from(value.clamp(-4, 4) + 4).expect("non-negative") * half / 4;
+        Line::from_iter([
+            Span::raw("N/S "),
+            Span::raw("█".repeat(offset)),
+            Span::raw("░".repeat(BAR_WIDTH - offset)),
+            Span::raw(format!(" E/W  {value:+}")),
+        ])
+    }
+
+    /// Renders the bar for the points the makers expect to win, if they've been estimated.
+    fn expected_line(&self) -> Option<Line<'static>> {
+        let (team, value) = self.expected?;
+        let half = BAR_WIDTH as f64 / 2.0;
+        let offset = ((value.clamp(-4.0, 4.0) + 4.0) * half / 4.0).round() as usize;
+        Some(Line::from_iter([
+            Span::raw(format!("{} ", team.to_abbr())),
+            Span::raw("█".repeat(offset)),
+            Span::raw("░".repeat(BAR_WIDTH - offset)),
+            format!(" {}  {value:+.2}", tr!("review-makers")).dim(),
+        ]))
+    }
+
+    /// Renders a move, along with how long the player thought about it, and the robot's
+    /// alternative if it differs.
+    fn step_line(step: &ReviewStep, selected: bool) -> Line<'static> {
+        let mut spans = action_spans(step.action);
+        spans.push(format!(" ({:+})", step.value).into());
+        if let Some(think_time) = step.think_time {
+            spans.push(format!(" {:.1}s", think_time.as_secs_f64()).dim());
+        }
+        if step.robot != step.action.data {
+            spans.push(format!("  {} ", tr!("review-robot")).dim());
+            spans.extend(data_spans(step.robot).into_iter().map(Span::dim));
+            spans.push(format!(" ({:+})", step.robot_value).dim());
+        }
+        if selected {
+            spans = spans.into_iter().map(Span::reversed).collect();
+        }
+        Line::from_iter(spans)
+    }
+}
+
+/// Describes the data for an action, without the player.
+fn data_spans(data: ActionData) -> Vec<Span<'static>> {
+    match data {
+        ActionData::Pass => vec![tr!("review-pass").into()],
+        ActionData::Call { suit, alone: false } => vec![suit.to_span()],
+        ActionData::Call { suit, alone: true } => {
+            line_with_span(
+                &tr!("review-call-alone", suit = PLACEHOLDER),
+                suit.to_span(),
+            )
+            .spans
+        }
+        ActionData::Card { card } => vec![card.to_span()],
+    }
+}
+
+impl StatefulWidget for Review {
+    type State = ReviewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let block = Block::new().padding(Padding::left(1));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.height < 4 {
+            return;
+        }
+        let [bar, list] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(inner);
+        let [eval, expected, trace] = Layout::vertical([Constraint::Length(1); 3]).areas(bar);
+        Self::eval_line(self.evaluation(state)).render(eval, buf);
+        if let Some(line) = self.expected_line() {
+            line.render(expected, buf);
+        }
+        if let Some(line) = self.trace_line(state) {
+            line.render(trace, buf);
+        }
+
+        let selected = state.selected().unwrap_or(0).min(self.len() - 1);
+        state.select(Some(selected));
+        let height = list.height as usize;
+        let offset = state
+            .offset()
+            .min(selected)
+            .max((selected + 1).saturating_sub(height));
+        *state.offset_mut() = offset;
+
+        let mut lines: Vec<Line> = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| Self::step_line(step, i == selected))
+            .collect();
+        let end = Line::from(tr!(
+            "review-end-of-round",
+            value = format!("{:+}", self.final_value)
+        ));
+        lines.push(if selected == self.steps.len() {
+            end.reversed()
+        } else {
+            end
+        });
+
+        let mut item_area = Rect::new(list.x, list.y, list.width, 1);
+        for line in lines.into_iter().skip(offset).take(height) {
+            line.render(item_area, buf);
+            item_area = item_area.offset(Offset { x: 0, y: 1 });
+        }
+    }
+}
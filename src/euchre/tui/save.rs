@@ -0,0 +1,126 @@
+//! Save form widget
+
+use std::path::{Path, PathBuf};
+
+use ratatui::crossterm::event::KeyCode;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::euchre::{Metadata, Seat};
+use crate::{i18n, tr};
+
+/// The labels of the form's fields.
+const LABELS: [&str; 6] = [
+    "save-file",
+    "seat-north",
+    "seat-east",
+    "seat-south",
+    "seat-west",
+    "save-notes",
+];
+
+/// The outcome of a key press in the form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormInput {
+    /// The form is still being edited.
+    Editing,
+    /// The form was dismissed without saving.
+    Cancel,
+    /// The form was submitted.
+    Submit,
+}
+
+/// A form for naming the save file, and describing the game.
+#[derive(Debug, Clone)]
+pub struct SaveForm {
+    values: [String; 6],
+    focus: usize,
+}
+
+impl SaveForm {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 40;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 10;
+
+    /// Creates a form, filled in with the specified path and metadata.
+    pub fn new(path: &Path, metadata: &Metadata) -> Self {
+        let player = |seat: Seat| metadata.players[seat].clone();
+        Self {
+            values: [
+                path.display().to_string(),
+                player(Seat::North),
+                player(Seat::East),
+                player(Seat::South),
+                player(Seat::West),
+                metadata.notes.clone(),
+            ],
+            focus: 0,
+        }
+    }
+
+    /// Handles a key press.
+    pub fn handle_key(&mut self, key: KeyCode) -> FormInput {
+        match key {
+            KeyCode::Esc => return FormInput::Cancel,
+            KeyCode::Enter => return FormInput::Submit,
+            KeyCode::Tab | KeyCode::Down => self.focus = (self.focus + 1) % LABELS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focus = (self.focus + LABELS.len() - 1) % LABELS.len();
+            }
+            KeyCode::Backspace => {
+                self.values[self.focus].pop();
+            }
+            KeyCode::Char(c) => self.values[self.focus].push(c),
+            _ => (),
+        }
+        FormInput::Editing
+    }
+
+    /// Returns the path and metadata entered in the form. The metadata's date and rules are left
+    /// for the caller to fill in.
+    pub fn values(&self) -> (PathBuf, Metadata) {
+        let [path, north, east, south, west, notes] = self.values.clone();
+        let mut metadata = Metadata {
+            notes: notes.trim().to_string(),
+            ..Metadata::default()
+        };
+        for (seat, name) in [
+            (Seat::North, north),
+            (Seat::East, east),
+            (Seat::South, south),
+            (Seat::West, west),
+        ] {
+            metadata.players[seat] = name.trim().to_string();
+        }
+        (PathBuf::from(path.trim()), metadata)
+    }
+}
+
+impl Widget for &SaveForm {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("save-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let mut lines: Vec<Line<'_>> = LABELS
+            .iter()
+            .zip(&self.values)
+            .enumerate()
+            .map(|(i, (label, value))| {
+                let label = Span::raw(format!("{:>5}: ", i18n::tr(label, None)));
+                if i == self.focus {
+                    Line::from(vec![label.bold(), value.as_str().into(), "_".into()])
+                } else {
+                    Line::from(vec![label, value.as_str().into()])
+                }
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(Line::from(tr!("save-keys")).dim());
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
@@ -1,12 +1,16 @@
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Row, Table, Widget};
 
-use crate::euchre::{Game, Round, Team};
+use crate::euchre::{Game, Round, Rubber, Team};
+use crate::tr;
 
 pub struct Scoreboard {
+    rubber: Rubber,
     ns_score: u8,
     ew_score: u8,
     ns_tricks: u8,
     ew_tricks: u8,
+    win_odds: Option<f64>,
 }
 
 impl Scoreboard {
@@ -17,12 +21,20 @@ impl Scoreboard {
         let ns_tricks = tricks.win_count(Team::NorthSouth);
         let ew_tricks = tricks.win_count(Team::EastWest);
         Self {
+            rubber: game.rubber(),
             ns_score,
             ew_score,
             ns_tricks,
             ew_tricks,
+            win_odds: None,
         }
     }
+
+    /// Shows the estimated probability that the user's team wins the game.
+    pub fn with_win_odds(mut self, win_odds: Option<f64>) -> Self {
+        self.win_odds = win_odds;
+        self
+    }
 }
 
 impl Widget for Scoreboard {
@@ -30,21 +42,38 @@ impl Widget for Scoreboard {
     where
         Self: Sized,
     {
+        let mut block = Block::bordered();
+        if self.rubber.best_of() > 1 {
+            block = block.title(format!(
+                " {} ",
+                tr!(
+                    "summary-games",
+                    ns = self.rubber.games(Team::NorthSouth),
+                    ew = self.rubber.games(Team::EastWest)
+                )
+            ));
+        }
+        if let Some(odds) = self.win_odds {
+            let percent = format!("{:.0}", odds * 100.0);
+            block = block.title_bottom(
+                Line::from(format!(" {} ", tr!("ui-win-odds", percent = percent))).right_aligned(),
+            );
+        }
         Table::default()
             .header(Row::new(["", "N/S", "E/W"]))
             .rows([
                 Row::new([
-                    String::from("Score"),
+                    tr!("ui-score"),
                     self.ns_score.to_string(),
                     self.ew_score.to_string(),
                 ]),
                 Row::new([
-                    String::from("Trick"),
+                    tr!("ui-trick"),
                     self.ns_tricks.to_string(),
                     self.ew_tricks.to_string(),
                 ]),
             ])
-            .block(Block::bordered())
+            .block(block)
             .render(area, buf);
     }
 }
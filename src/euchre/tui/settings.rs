@@ -0,0 +1,228 @@
+//! Settings menu, opened from the start menu
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph, StatefulWidget, Widget};
+
+use crate::euchre::settings::TARGET_SCORES;
+use crate::euchre::{DealerRotation, RenegePolicy, RobotKind, Settings, Theme};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+pub type SettingsMenuState = ListState;
+
+/// The rubber lengths offered, in games.
+const BEST_OF: [u8; 5] = [1, 3, 5, 7, 9];
+
+/// The autoplay delays offered, in milliseconds.
+const AUTOPLAY_DELAYS: [u16; 5] = [0, 500, 1000, 2000, 3000];
+
+/// Returns the value after the current one, cycling back to the first. A value that isn't
+/// offered moves on to the first.
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T) -> T {
+    match values.iter().position(|&v| v == current) {
+        Some(index) => values[(index + 1) % values.len()],
+        None => values[0],
+    }
+}
+
+/// A setting that can be changed from the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Setting {
+    RobotAutoplay,
+    TrickOdds,
+    WinOdds,
+    StrengthTrainer,
+    CardBack,
+    Theme,
+    AutoplayDelay,
+    BatchEvents,
+    Robot,
+    TargetScore,
+    BestOf,
+    Renege,
+    Competitive,
+    Rotation,
+}
+
+impl Setting {
+    fn all() -> &'static [Setting; 14] {
+        static SETTINGS: [Setting; 14] = [
+            Setting::RobotAutoplay,
+            Setting::TrickOdds,
+            Setting::WinOdds,
+            Setting::StrengthTrainer,
+            Setting::CardBack,
+            Setting::Theme,
+            Setting::AutoplayDelay,
+            Setting::BatchEvents,
+            Setting::Robot,
+            Setting::TargetScore,
+            Setting::BestOf,
+            Setting::Renege,
+            Setting::Competitive,
+            Setting::Rotation,
+        ];
+        &SETTINGS
+    }
+
+    fn label(self) -> String {
+        match self {
+            Setting::RobotAutoplay => tr!("settings-autoplay"),
+            Setting::TrickOdds => tr!("settings-trick-odds"),
+            Setting::WinOdds => tr!("settings-win-odds"),
+            Setting::StrengthTrainer => tr!("settings-trainer"),
+            Setting::CardBack => tr!("settings-card-back"),
+            Setting::Theme => tr!("settings-theme"),
+            Setting::AutoplayDelay => tr!("settings-autoplay-delay"),
+            Setting::BatchEvents => tr!("settings-batch-events"),
+            Setting::Robot => tr!("settings-robot"),
+            Setting::TargetScore => tr!("settings-points"),
+            Setting::BestOf => tr!("settings-best-of"),
+            Setting::Renege => tr!("settings-renege"),
+            Setting::Competitive => tr!("settings-competitive"),
+            Setting::Rotation => tr!("settings-rotation"),
+        }
+    }
+}
+
+/// A menu for changing the settings, one at a time.
+#[derive(Debug, Clone)]
+pub struct SettingsMenu {
+    settings: Settings,
+}
+
+impl SettingsMenu {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 48;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 19;
+
+    /// Creates a menu for changing the specified settings.
+    pub fn new(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Returns the settings, as changed.
+    pub fn settings(&self) -> Settings {
+        self.settings
+    }
+
+    /// Changes the selected setting: toggles it on or off, or moves on to its next value. Only
+    /// valid values are offered.
+    pub fn change(&mut self, state: &SettingsMenuState) {
+        let Some(&setting) = state.selected().and_then(|idx| Setting::all().get(idx)) else {
+            return;
+        };
+        let settings = &mut self.settings;
+        match setting {
+            Setting::RobotAutoplay => settings.robot_autoplay = !settings.robot_autoplay,
+            Setting::TrickOdds => settings.trick_odds = !settings.trick_odds,
+            Setting::WinOdds => settings.win_odds = !settings.win_odds,
+            Setting::StrengthTrainer => settings.strength_trainer = !settings.strength_trainer,
+            Setting::CardBack => settings.card_back = settings.card_back.next(),
+            Setting::Theme => {
+                settings.theme = cycle(&[Theme::Color, Theme::Monochrome], settings.theme)
+            }
+            Setting::AutoplayDelay => {
+                settings.autoplay_delay = cycle(&AUTOPLAY_DELAYS, settings.autoplay_delay)
+            }
+            Setting::BatchEvents => settings.batch_events = !settings.batch_events,
+            Setting::Robot => {
+                settings.robot = cycle(&[RobotKind::Heuristic, RobotKind::Search], settings.robot)
+            }
+            Setting::TargetScore => {
+                let rules = &mut settings.rules;
+                rules.target_score = cycle(&TARGET_SCORES, rules.target_score)
+            }
+            Setting::BestOf => settings.rules.best_of = cycle(&BEST_OF, settings.rules.best_of),
+            Setting::Renege => {
+                let policies = [RenegePolicy::Reject, RenegePolicy::Defer];
+                settings.rules.renege = cycle(&policies, settings.rules.renege)
+            }
+            Setting::Competitive => settings.rules.competitive = !settings.rules.competitive,
+            Setting::Rotation => {
+                let rules = &mut settings.rules;
+                rules.rotation = cycle(&DealerRotation::ALL, rules.rotation)
+            }
+        }
+    }
+
+    /// Describes the current value of a setting.
+    fn value(&self, setting: Setting) -> String {
+        let on_off = |on: bool| {
+            if on {
+                tr!("settings-on")
+            } else {
+                tr!("settings-off")
+            }
+        };
+        let settings = &self.settings;
+        match setting {
+            Setting::RobotAutoplay => on_off(settings.robot_autoplay),
+            Setting::TrickOdds => on_off(settings.trick_odds),
+            Setting::WinOdds => on_off(settings.win_odds),
+            Setting::StrengthTrainer => on_off(settings.strength_trainer),
+            Setting::CardBack => format!(
+                "{} {}",
+                settings.card_back.glyph().to_string().repeat(2),
+                settings.card_back
+            ),
+            Setting::Theme => match settings.theme {
+                Theme::Color => tr!("settings-color"),
+                Theme::Monochrome => tr!("settings-monochrome"),
+            },
+            Setting::AutoplayDelay => match settings.autoplay_delay {
+                0 => tr!("settings-off"),
+                ms => tr!("settings-delay", ms = ms),
+            },
+            Setting::BatchEvents => on_off(settings.batch_events),
+            Setting::Robot => match settings.robot {
+                RobotKind::Heuristic => tr!("settings-robot-easy"),
+                RobotKind::Search => tr!("settings-robot-hard"),
+            },
+            Setting::TargetScore => settings.rules.target_score.to_string(),
+            Setting::BestOf => tr!("settings-games", count = settings.rules.best_of),
+            Setting::Renege => match settings.rules.renege {
+                RenegePolicy::Reject => tr!("settings-renege-reject"),
+                RenegePolicy::Defer => tr!("settings-renege-defer"),
+            },
+            Setting::Competitive => on_off(settings.rules.competitive),
+            Setting::Rotation => match settings.rules.rotation {
+                DealerRotation::Clockwise => tr!("settings-rotation-clockwise"),
+                DealerRotation::Random => tr!("settings-rotation-random"),
+                DealerRotation::Fixed => tr!("settings-rotation-fixed"),
+            },
+        }
+    }
+}
+
+impl StatefulWidget for &SettingsMenu {
+    type State = SettingsMenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("settings-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [items, _, hint] = Layout::vertical([
+            Constraint::Length(14),
+            Constraint::Length(1),
+            Constraint::Length(2),
+        ])
+        .areas(inner);
+        let list = List::new(
+            Setting::all()
+                .iter()
+                .map(|&s| format!("{:<28}{}", s.label(), self.value(s))),
+        )
+        .highlight_style(Style::new().reversed());
+        StatefulWidget::render(list, items, buf, state);
+        Paragraph::new(vec![
+            Line::from(tr!("settings-rules-hint")).dim(),
+            Line::from(tr!("settings-hint")).dim(),
+        ])
+        .render(hint, buf);
+    }
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::euchre::{CardBack, Rules};
+
+#[test]
+fn test_change() {
+    let mut menu = SettingsMenu::new(Settings::default());
+    let mut state = SettingsMenuState::default();
+    menu.change(&state);
+    assert_eq!(menu.settings(), Settings::default());
+
+    state.select(Some(0));
+    menu.change(&state);
+    assert!(!menu.settings().robot_autoplay);
+    state.select(Some(3));
+    menu.change(&state);
+    assert!(menu.settings().strength_trainer);
+    state.select(Some(4));
+    menu.change(&state);
+    assert_eq!(menu.settings().card_back, CardBack::Light);
+    menu.change(&state);
+    assert_eq!(menu.settings().card_back, CardBack::Solid);
+}
+
+#[test]
+fn test_change_rules() {
+    let mut menu = SettingsMenu::new(Settings::default());
+    let mut state = SettingsMenuState::default();
+    state.select(Some(6));
+    menu.change(&state);
+    assert_eq!(menu.settings().autoplay_delay, 500);
+    state.select(Some(7));
+    menu.change(&state);
+    assert!(menu.settings().batch_events);
+    state.select(Some(8));
+    menu.change(&state);
+    assert_eq!(menu.settings().robot, RobotKind::Search);
+    state.select(Some(9));
+    menu.change(&state);
+    assert_eq!(menu.settings().rules.target_score, 15);
+    menu.change(&state);
+    assert_eq!(menu.settings().rules.target_score, 5);
+    state.select(Some(11));
+    menu.change(&state);
+    assert_eq!(menu.settings().rules.renege, RenegePolicy::Defer);
+    state.select(Some(13));
+    menu.change(&state);
+    assert_eq!(menu.settings().rules.rotation, DealerRotation::Random);
+
+    // Out-of-range values move on to the first valid one.
+    let mut menu = SettingsMenu::new(Settings {
+        rules: Rules {
+            best_of: 4,
+            ..Rules::default()
+        },
+        ..Settings::default()
+    });
+    state.select(Some(10));
+    menu.change(&state);
+    assert_eq!(menu.settings().rules.best_of, 1);
+    assert!(menu.settings().validate().is_ok());
+}
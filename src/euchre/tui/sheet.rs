@@ -0,0 +1,63 @@
+//! Score sheet widget
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Row, Table, Widget};
+
+use crate::euchre::ScoreSheet;
+use crate::tr;
+
+pub struct ScoreSheetView<'a>(&'a ScoreSheet);
+
+impl<'a> ScoreSheetView<'a> {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 64;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 20;
+
+    pub fn new(sheet: &'a ScoreSheet) -> Self {
+        Self(sheet)
+    }
+
+    /// Lays out the most recent rounds that fit in the specified number of rows.
+    fn table(&self, rows: usize) -> Table<'static> {
+        let cells: Vec<_> = self.0.cells().collect();
+        let skip = cells.len().saturating_sub(rows);
+        Table::new(
+            cells.into_iter().skip(skip).map(Row::new),
+            [
+                Constraint::Length(3),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(8),
+                Constraint::Length(14),
+                Constraint::Length(7),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ],
+        )
+        .header(Row::new(ScoreSheet::headings()).bold())
+    }
+}
+
+impl Widget for ScoreSheetView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("sheet-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [table, footer] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+        let rows = usize::from(table.height.saturating_sub(1));
+        if self.0.rows().is_empty() {
+            Paragraph::new(tr!("sheet-empty")).render(table, buf);
+        } else {
+            Widget::render(self.table(rows), table, buf);
+        }
+        if let Some(text) = self.0.footer() {
+            Paragraph::new(text).render(footer, buf);
+        }
+    }
+}
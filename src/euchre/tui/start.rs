@@ -0,0 +1,148 @@
+//! Start menu, offering to start or resume a game, or to edit the settings
+
+use std::path::Path;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph, StatefulWidget, Widget};
+
+use crate::euchre::{RawGame, Team};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+pub type StartMenuState = ListState;
+
+/// What to do on startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartChoice {
+    /// Start a new game, leaving the autosave alone until the new game has progressed.
+    NewGame,
+    /// Resume the autosaved game.
+    Resume,
+    /// Load a saved game or round.
+    Load,
+    /// Edit the settings.
+    Settings,
+    /// Quit.
+    Quit,
+}
+
+impl StartChoice {
+    fn all() -> &'static [StartChoice; 5] {
+        static CHOICES: [StartChoice; 5] = [
+            StartChoice::NewGame,
+            StartChoice::Resume,
+            StartChoice::Load,
+            StartChoice::Settings,
+            StartChoice::Quit,
+        ];
+        &CHOICES
+    }
+
+    fn label(self) -> String {
+        match self {
+            StartChoice::NewGame => tr!("start-new-game"),
+            StartChoice::Resume => tr!("start-resume"),
+            StartChoice::Load => tr!("start-load"),
+            StartChoice::Settings => tr!("start-settings"),
+            StartChoice::Quit => tr!("start-quit"),
+        }
+    }
+}
+
+/// A menu shown on startup, offering to start a new game, to resume the game in progress, if
+/// there is one, to load a saved game, or to edit the settings.
+#[derive(Debug, Clone)]
+pub struct StartMenu {
+    /// A description of the autosaved game, if it can be resumed.
+    summary: Option<String>,
+}
+
+impl StartMenu {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 48;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 9;
+
+    /// Creates the menu, offering to resume the autosaved game if there's one in progress.
+    pub fn read(path: &Path) -> Self {
+        Self {
+            summary: Self::summarize(path),
+        }
+    }
+
+    /// Describes an autosaved game, returning `None` if there isn't one that can be resumed.
+    fn summarize(path: &Path) -> Option<String> {
+        let raw = RawGame::from_json_file(path).ok()?;
+        let date = raw.metadata().map(|m| m.date.clone()).unwrap_or_default();
+        let game = raw.into_game().ok()?;
+        if game.outcome().is_some_and(|o| o.rubber.winner().is_some()) {
+            return None;
+        }
+        let mut summary = tr!(
+            "start-summary",
+            ns = game.score(Team::NorthSouth),
+            ew = game.score(Team::EastWest),
+            rounds = game.rounds()
+        );
+        if !date.is_empty() {
+            summary.push_str(&format!(" ({date})"));
+        }
+        Some(summary)
+    }
+
+    /// Returns the choices on offer, in order. A game can only be resumed if one is in progress.
+    pub fn choices(&self) -> Vec<StartChoice> {
+        StartChoice::all()
+            .iter()
+            .copied()
+            .filter(|&c| c != StartChoice::Resume || self.summary.is_some())
+            .collect()
+    }
+
+    /// Returns the selected choice.
+    pub fn selected(&self, state: &StartMenuState) -> Option<StartChoice> {
+        state
+            .selected()
+            .and_then(|idx| self.choices().get(idx).copied())
+    }
+
+    /// Returns the choice for a shortcut key, if it's on offer.
+    pub fn shortcut(&self, key: char) -> Option<StartChoice> {
+        let choice = match key {
+            'n' => StartChoice::NewGame,
+            'r' => StartChoice::Resume,
+            'l' => StartChoice::Load,
+            's' => StartChoice::Settings,
+            'q' => StartChoice::Quit,
+            _ => return None,
+        };
+        self.choices().contains(&choice).then_some(choice)
+    }
+}
+
+impl StatefulWidget for &StartMenu {
+    type State = StartMenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("start-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [summary, _, choices] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .areas(inner);
+        let text = match &self.summary {
+            Some(summary) => tr!("start-in-progress", summary = summary.as_str()),
+            None => tr!("start-no-game"),
+        };
+        Paragraph::new(text.dim()).render(summary, buf);
+        let list = List::new(self.choices().into_iter().map(StartChoice::label))
+            .highlight_style(Style::new().reversed());
+        StatefulWidget::render(list, choices, buf, state);
+    }
+}
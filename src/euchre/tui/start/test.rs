@@ -0,0 +1,34 @@
+use std::fs::{self, File};
+
+use super::*;
+use crate::euchre::{Game, LoggingRound};
+
+#[test]
+fn test_read() {
+    let dir = std::env::temp_dir().join(format!("deckard-start-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("euchre.json");
+    let menu = StartMenu::read(&path);
+    assert!(menu.summary.is_none());
+    assert_eq!(
+        menu.choices(),
+        [
+            StartChoice::NewGame,
+            StartChoice::Load,
+            StartChoice::Settings,
+            StartChoice::Quit
+        ]
+    );
+    assert_eq!(menu.shortcut('r'), None);
+
+    let game = Game::from(LoggingRound::random());
+    let file = File::create(&path).unwrap();
+    serde_json::to_writer(file, &RawGame::from(&game)).unwrap();
+    let menu = StartMenu::read(&path);
+    assert_eq!(menu.summary.as_deref(), Some("N/S 0, E/W 0 after 0 rounds"));
+    assert_eq!(menu.choices().len(), 5);
+    assert_eq!(menu.shortcut('r'), Some(StartChoice::Resume));
+    let state = StartMenuState::default().with_selected(Some(1));
+    assert_eq!(menu.selected(&state), Some(StartChoice::Resume));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,69 @@
+//! Player statistics widget
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Row, Table, Widget};
+
+use crate::euchre::{Seat, Stats};
+use crate::tr;
+
+pub struct StatsScreen<'a>(&'a Stats);
+
+impl<'a> StatsScreen<'a> {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 40;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 7;
+
+    pub fn new(stats: &'a Stats) -> Self {
+        Self(stats)
+    }
+
+    fn table(&self) -> Table<'static> {
+        let pct = |r: Option<f64>| r.map_or("-".into(), |r| format!("{:.0}%", r * 100.0));
+        let rows = Seat::all_seats().iter().map(|&seat| {
+            let stats = self.0.seat(seat);
+            Row::new([
+                seat.to_abbr().to_string(),
+                stats.deals.to_string(),
+                pct(stats.call_rate()),
+                pct(stats.euchre_rate()),
+                pct(stats.loner_success()),
+                stats
+                    .points_per_deal()
+                    .map_or("-".into(), |p| format!("{p:.2}")),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(1),
+                Constraint::Length(5),
+                Constraint::Length(5),
+                Constraint::Length(5),
+                Constraint::Length(6),
+                Constraint::Length(6),
+            ],
+        )
+        .header(Row::new([
+            String::new(),
+            tr!("stats-deals"),
+            tr!("stats-call"),
+            tr!("stats-euchre"),
+            tr!("stats-alone"),
+            tr!("stats-points"),
+        ]))
+    }
+}
+
+impl Widget for StatsScreen<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("stats-title")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        Widget::render(self.table(), inner, buf);
+    }
+}
@@ -0,0 +1,92 @@
+//! End-of-game summary widget
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Row, Table, Widget};
+
+use crate::euchre::{GameOutcome, Team};
+use crate::tr;
+
+pub struct GameSummary(GameOutcome);
+
+impl GameSummary {
+    /// The width of the rendered widget, including borders.
+    pub const WIDTH: u16 = 30;
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 8;
+
+    pub fn new(outcome: GameOutcome) -> Self {
+        Self(outcome)
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let outcome = &self.0;
+        let result = match outcome.forfeit {
+            Some(seat) => tr!(
+                "summary-on-time",
+                team = outcome.winner.to_abbr(),
+                seat = seat.to_abbr().to_string()
+            ),
+            None => tr!(
+                "ui-wins-by",
+                team = outcome.winner.to_abbr(),
+                margin = outcome.margin()
+            ),
+        };
+        let mut lines = vec![
+            result.into(),
+            tr!("ui-rounds-played", rounds = outcome.rounds).into(),
+        ];
+        let rubber = outcome.rubber;
+        if rubber.best_of() > 1 {
+            let games = tr!(
+                "summary-games",
+                ns = rubber.games(Team::NorthSouth),
+                ew = rubber.games(Team::EastWest)
+            );
+            lines.push(match rubber.winner() {
+                Some(team) => tr!("summary-takes-it", games = games, team = team.to_abbr()).into(),
+                None => tr!("summary-next-game", games = games).into(),
+            });
+        }
+        lines
+    }
+
+    fn table(&self) -> Table<'static> {
+        let outcome = &self.0;
+        let row = |label: &str, f: &dyn Fn(Team) -> u8| {
+            Row::new([
+                label.to_string(),
+                f(Team::NorthSouth).to_string(),
+                f(Team::EastWest).to_string(),
+            ])
+        };
+        Table::new(
+            [
+                row(&tr!("ui-score"), &|t| outcome.score[t]),
+                row(&tr!("ui-loners"), &|t| outcome.loners[t]),
+            ],
+            [
+                Constraint::Length(8),
+                Constraint::Length(5),
+                Constraint::Length(5),
+            ],
+        )
+        .header(Row::new(["", "N/S", "E/W"]))
+    }
+}
+
+impl Widget for GameSummary {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("ui-game-over")));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [text, table] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(3)]).areas(inner);
+        Paragraph::new(self.lines()).render(text, buf);
+        Widget::render(self.table(), table, buf);
+    }
+}
@@ -0,0 +1,159 @@
+//! Hand-strength trainer, shown during bidding
+
+use std::iter::FromIterator;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use super::line_with_span;
+use crate::euchre::{Card, Robot, RobotParams, Suit};
+use crate::i18n::PLACEHOLDER;
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// How far short of a call a hand may be, and still count as borderline.
+const BORDERLINE: u8 = 3;
+
+/// A range of hand strengths, by what the robot would do with them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrengthBucket {
+    /// Well short of a call.
+    Weak,
+    /// Within [`BORDERLINE`] of a call.
+    Borderline,
+    /// Worth a call.
+    Call,
+    /// Worth going alone.
+    Alone,
+}
+
+impl StrengthBucket {
+    /// All buckets, from weakest to strongest, in the order they're offered.
+    pub const ALL: [StrengthBucket; 4] = [
+        StrengthBucket::Weak,
+        StrengthBucket::Borderline,
+        StrengthBucket::Call,
+        StrengthBucket::Alone,
+    ];
+
+    /// Buckets a hand strength by the robot's thresholds for calling and going alone.
+    pub fn of(strength: u8, params: &RobotParams) -> Self {
+        if strength >= params.loner {
+            StrengthBucket::Alone
+        } else if strength >= params.call {
+            StrengthBucket::Call
+        } else if strength + BORDERLINE >= params.call {
+            StrengthBucket::Borderline
+        } else {
+            StrengthBucket::Weak
+        }
+    }
+
+    /// The localized name of the bucket.
+    fn label(self) -> String {
+        match self {
+            StrengthBucket::Weak => tr!("trainer-weak"),
+            StrengthBucket::Borderline => tr!("trainer-borderline"),
+            StrengthBucket::Call => tr!("trainer-call"),
+            StrengthBucket::Alone => tr!("trainer-alone"),
+        }
+    }
+}
+
+/// Asks the user to estimate the strength of their hand before they bid, and then reveals the
+/// strength by the robot's rubric.
+#[derive(Debug, Clone)]
+pub struct StrengthTrainer {
+    /// The suit that the hand is scored for.
+    suit: Suit,
+    /// The hand's strength, by the robot's rubric.
+    strength: u8,
+    /// The bucket that the strength falls in.
+    actual: StrengthBucket,
+    /// The user's estimate, once made.
+    guess: Option<StrengthBucket>,
+}
+
+impl StrengthTrainer {
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 4;
+
+    /// Creates a trainer for a hand, scored for the strongest of the suits that may be called.
+    pub fn new(hand: &[Card], suits: &[Suit], params: &RobotParams) -> Option<Self> {
+        let (suit, strength) = suits
+            .iter()
+            .map(|&suit| (suit, Robot::hand_strength(hand, suit)))
+            .max_by_key(|&(_, strength)| strength)?;
+        Some(Self {
+            suit,
+            strength,
+            actual: StrengthBucket::of(strength, params),
+            guess: None,
+        })
+    }
+
+    /// Returns true once the user has made their estimate.
+    pub fn is_answered(&self) -> bool {
+        self.guess.is_some()
+    }
+
+    /// Records the user's estimate, by its number in the list of buckets. Returns false if there's
+    /// no such bucket.
+    pub fn answer(&mut self, number: char) -> bool {
+        let bucket = number
+            .to_digit(10)
+            .and_then(|n| StrengthBucket::ALL.get((n as usize).checked_sub(1)?));
+        self.guess = bucket.copied().or(self.guess);
+        bucket.is_some()
+    }
+
+    /// Returns whether the estimate was right, once it's been made.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.guess.map(|guess| guess == self.actual)
+    }
+
+    /// Lays out the question, or the answer once the estimate has been made.
+    fn lines(&self) -> Vec<Line<'static>> {
+        let Some(guess) = self.guess else {
+            let choices = StrengthBucket::ALL
+                .iter()
+                .enumerate()
+                .map(|(i, bucket)| format!("{} {}", i + 1, bucket.label()));
+            return vec![
+                line_with_span(
+                    &tr!("trainer-question", suit = PLACEHOLDER),
+                    self.suit.to_span(),
+                ),
+                Line::from(choices.collect::<Vec<_>>().join(" · ")).dim(),
+            ];
+        };
+        let reveal = tr!(
+            "trainer-reveal",
+            suit = PLACEHOLDER,
+            strength = self.strength,
+            bucket = self.actual.label()
+        );
+        let verdict = if guess == self.actual {
+            Span::from(tr!("trainer-correct")).green()
+        } else {
+            Span::from(tr!("trainer-incorrect", guess = guess.label())).red()
+        };
+        vec![
+            line_with_span(&reveal, self.suit.to_span()),
+            Line::from_iter([verdict]),
+        ]
+    }
+}
+
+impl Widget for &StrengthTrainer {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("trainer-title")));
+        Paragraph::new(self.lines()).block(block).render(area, buf);
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn test_strength_bucket() {
+    let params = RobotParams::default();
+    assert_eq!(StrengthBucket::of(0, &params), StrengthBucket::Weak);
+    assert_eq!(StrengthBucket::of(4, &params), StrengthBucket::Weak);
+    assert_eq!(StrengthBucket::of(5, &params), StrengthBucket::Borderline);
+    assert_eq!(StrengthBucket::of(7, &params), StrengthBucket::Borderline);
+    assert_eq!(StrengthBucket::of(8, &params), StrengthBucket::Call);
+    assert_eq!(StrengthBucket::of(10, &params), StrengthBucket::Call);
+    assert_eq!(StrengthBucket::of(11, &params), StrengthBucket::Alone);
+}
+
+#[test]
+fn test_strength_trainer() {
+    let hand: Vec<Card> = ["jh", "jd", "ah", "kh", "qh"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let params = RobotParams::default();
+    let suits = [Suit::Club, Suit::Heart, Suit::Spade];
+    let mut trainer = StrengthTrainer::new(&hand, &suits, &params).unwrap();
+    assert_eq!(trainer.suit, Suit::Heart);
+    assert_eq!(trainer.strength, Robot::hand_strength(&hand, Suit::Heart));
+    assert_eq!(trainer.actual, StrengthBucket::Alone);
+    assert!(StrengthTrainer::new(&hand, &[], &params).is_none());
+
+    assert!(!trainer.is_answered());
+    assert_eq!(trainer.is_correct(), None);
+    assert!(!trainer.answer('0'));
+    assert!(!trainer.answer('5'));
+    assert!(!trainer.answer('x'));
+    assert!(!trainer.is_answered());
+    assert!(trainer.answer('3'));
+    assert_eq!(trainer.is_correct(), Some(false));
+    assert!(trainer.answer('4'));
+    assert_eq!(trainer.is_correct(), Some(true));
+}
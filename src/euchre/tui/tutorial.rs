@@ -0,0 +1,120 @@
+//! Tutorial widget, explaining the rules as a scripted deal is played
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Clear, Paragraph, Widget, Wrap};
+
+use crate::euchre::{ActionType, Round, RoundConfig, Seat};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The deal played in the tutorial. East deals, so the user bids first, with a hand that is strong
+/// in the suit of the upturned card.
+const DEAL: &str = include_str!("tutorial.json");
+
+/// A point in the round that the tutorial explains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lesson {
+    /// Before the user's first decision.
+    Welcome,
+    /// The user may order up the top card.
+    OrderUp,
+    /// The user may name another suit as trump.
+    NameTrump,
+    /// The user picked up the top card, and must discard.
+    Discard,
+    /// The user leads a trick.
+    Lead,
+    /// The user follows a trick.
+    Follow,
+    /// The round is over.
+    Scoring,
+}
+
+/// Prompts that walk a new player through the tutorial deal, one decision at a time.
+#[derive(Debug, Clone)]
+pub struct Tutorial {
+    lesson: Lesson,
+    text: String,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Self {
+            lesson: Lesson::Welcome,
+            text: tr!("tutorial-welcome"),
+        }
+    }
+}
+
+impl Tutorial {
+    /// The height of the rendered widget, including borders.
+    pub const HEIGHT: u16 = 9;
+
+    /// Returns the deal played in the tutorial.
+    pub fn config() -> RoundConfig {
+        serde_json::from_str(DEAL).expect("valid tutorial deal")
+    }
+
+    /// Moves on to the lesson for the user's next decision. While the robots are acting, the
+    /// prompt for the user's last decision remains.
+    pub fn update<R: Round>(&mut self, round: &R, seat: Seat) {
+        if round.outcome().is_some() {
+            self.lesson = Lesson::Scoring;
+            self.text = tr!("tutorial-scoring");
+            return;
+        }
+        let Some(expect) = round.next_action().filter(|expect| expect.seat == seat) else {
+            return;
+        };
+        let top = round.top_card();
+        let trump = round.contract().map(|contract| contract.suit.to_string());
+        let (lesson, text) = match expect.action {
+            ActionType::BidTop => (
+                Lesson::OrderUp,
+                tr!(
+                    "tutorial-order-up",
+                    card = top.to_string(),
+                    suit = top.suit.to_string()
+                ),
+            ),
+            ActionType::BidOther => (
+                Lesson::NameTrump,
+                tr!("tutorial-name-trump", suit = top.suit.to_string()),
+            ),
+            ActionType::DealerDiscard => (Lesson::Discard, tr!("tutorial-discard")),
+            ActionType::Lead => (
+                Lesson::Lead,
+                tr!("tutorial-lead", suit = trump.unwrap_or_default()),
+            ),
+            ActionType::Follow | ActionType::CallRenege => {
+                let state = round.player_state(seat);
+                let led = state
+                    .tricks
+                    .last()
+                    .map(|trick| trick.lead_suit().to_string());
+                (
+                    Lesson::Follow,
+                    tr!("tutorial-follow", suit = led.unwrap_or_default()),
+                )
+            }
+        };
+        self.lesson = lesson;
+        self.text = text;
+    }
+}
+
+impl Widget for &Tutorial {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Clear.render(area, buf);
+        let block = Block::bordered().title(format!(" {} ", tr!("tutorial-title")));
+        Paragraph::new(self.text.as_str())
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+use crate::euchre::{LoggingRound, Player, Robot};
+
+#[test]
+fn test_tutorial() {
+    let config = Tutorial::config();
+    assert!(config.validate().is_ok());
+    let mut round = LoggingRound::from(config);
+    let expect = round.next_action().unwrap();
+    assert_eq!(
+        (expect.seat, expect.action),
+        (Seat::South, ActionType::BidTop)
+    );
+
+    // Play the deal out with robots, noting each lesson as it's first shown.
+    let robot = Robot::default();
+    let mut tutorial = Tutorial::default();
+    let mut lessons = vec![tutorial.lesson];
+    while let Some(expect) = round.next_action() {
+        tutorial.update(&round, Seat::South);
+        assert!(!tutorial.text.is_empty());
+        if !lessons.contains(&tutorial.lesson) {
+            lessons.push(tutorial.lesson);
+        }
+        let data = robot.take_action(round.player_state(expect.seat), expect.action);
+        round.apply_action(expect.with_data(data)).unwrap();
+    }
+    tutorial.update(&round, Seat::South);
+    assert_eq!(tutorial.lesson, Lesson::Scoring);
+
+    // With both bowers, South orders up, and then leads the first trick.
+    assert_eq!(
+        lessons[..3],
+        [Lesson::Welcome, Lesson::OrderUp, Lesson::Lead]
+    );
+}
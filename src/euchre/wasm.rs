@@ -0,0 +1,118 @@
+//! JavaScript bindings, for running the engine in a browser.
+//!
+//! Build with `--no-default-features --features wasm --target wasm32-unknown-unknown`, and
+//! generate the JavaScript glue with `wasm-bindgen`. Values cross the boundary as plain objects,
+//! in the same shape as their JSON serialization.
+
+use wasm_bindgen::prelude::*;
+
+use super::{Action, Cut, Event, Game, GameView, LoggingRound, Player, Robot, Round, Seat};
+
+/// Converts a value to a JavaScript object.
+fn to_js<T: serde::Serialize>(value: &T) -> Result<JsValue, JsError> {
+    Ok(serde_wasm_bindgen::to_value(value)?)
+}
+
+/// A game of euchre.
+///
+/// The game doesn't play any seats on its own: the caller decides which seats are played by
+/// robots, and asks for [`EuchreGame::robot_suggestion`] on their turns.
+#[wasm_bindgen]
+pub struct EuchreGame {
+    game: Game<LoggingRound>,
+    robot: Robot,
+    /// Events that haven't yet been collected by the caller.
+    events: Vec<Event>,
+}
+
+#[wasm_bindgen]
+impl EuchreGame {
+    /// Starts a new game, with a random cut for the first deal.
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        let cut: Cut = rand::random();
+        let mut game = Self {
+            game: Game::from_cut(&cut),
+            robot: Robot::default(),
+            events: vec![Event::Cut(cut.into())],
+        };
+        game.collect_events();
+        game
+    }
+
+    /// Returns the state of the game, as visible from the specified seat (e.g., `"south"` or
+    /// `"S"`), or as visible to a spectator if no seat is specified.
+    pub fn state(&self, seat: Option<String>) -> Result<JsValue, JsError> {
+        let view: GameView = match seat {
+            Some(seat) => {
+                let seat: Seat = seat
+                    .parse()
+                    .map_err(|()| JsError::new(&format!("invalid seat: {seat}")))?;
+                self.game.view(seat)
+            }
+            None => self.game.public_view(),
+        };
+        to_js(&view)
+    }
+
+    /// Returns the legal actions for the player who is expected to act next.
+    pub fn legal_actions(&self) -> Result<JsValue, JsError> {
+        to_js(&self.game.round().legal_actions())
+    }
+
+    /// Returns the reneges that the specified seat may call out of turn.
+    pub fn legal_calls(&self, seat: String) -> Result<JsValue, JsError> {
+        let seat: Seat = seat
+            .parse()
+            .map_err(|()| JsError::new(&format!("invalid seat: {seat}")))?;
+        to_js(&self.game.round().legal_calls(seat))
+    }
+
+    /// Applies an action. When the round ends, the next round is dealt automatically.
+    pub fn apply(&mut self, action: JsValue) -> Result<(), JsError> {
+        if self.game.outcome().is_some() {
+            return Err(JsError::new("game is over"));
+        }
+        let action: Action = serde_wasm_bindgen::from_value(action)?;
+        self.game.round_mut().apply_action(action)?;
+        self.collect_events();
+        Ok(())
+    }
+
+    /// Returns the action that the robot would take next, or `null` if the game is over.
+    pub fn robot_suggestion(&self) -> Result<JsValue, JsError> {
+        let round = self.game.round();
+        let action = round.next_action().map(|expect| {
+            let state = round.player_state(expect.seat);
+            expect.with_data(self.robot.take_action(state, expect.action))
+        });
+        to_js(&action)
+    }
+
+    /// Returns the events that have occurred since the last call.
+    pub fn events(&mut self) -> Result<JsValue, JsError> {
+        to_js(&std::mem::take(&mut self.events))
+    }
+
+    /// Returns the outcome of the game, or `null` if the game is not over.
+    pub fn outcome(&self) -> Result<JsValue, JsError> {
+        to_js(&self.game.outcome())
+    }
+}
+
+impl EuchreGame {
+    /// Collects events from the round, and advances to the next round when it's over.
+    fn collect_events(&mut self) {
+        while let Some(event) = self.game.round_mut().pop_event() {
+            self.events.push(event);
+        }
+        if self.game.round().outcome().is_some() {
+            self.game.next_round();
+            match self.game.outcome() {
+                Some(outcome) => self.events.push(Event::Game(outcome)),
+                None => self.collect_events(),
+            }
+        }
+    }
+}
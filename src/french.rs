@@ -6,10 +6,13 @@ use std::str::FromStr;
 use std::{convert::TryFrom, fmt::Display};
 
 use ansi_term::ANSIString;
+#[cfg(feature = "tui")]
 use ratatui::text::Span;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Serialize};
 
+use crate::deck::Deck;
+
 /// Suit color.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
@@ -19,6 +22,7 @@ pub enum Color {
 
 /// French suits.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Suit {
     Club,
     Diamond,
@@ -51,6 +55,7 @@ impl Suit {
     }
 
     /// Returns a [`ratatui::text::Span`] for the suit.
+    #[cfg(feature = "tui")]
     pub fn to_span(self) -> Span<'static> {
         use ratatui::style::Color::Red;
         match self.color() {
@@ -128,6 +133,36 @@ pub enum Rank {
     King,
 }
 
+impl Rank {
+    /// Returns an array of all ranks, in ascending order with aces low.
+    pub fn all_ranks() -> &'static [Rank] {
+        static RANKS: [Rank; 13] = [
+            Rank::Ace,
+            Rank::Two,
+            Rank::Three,
+            Rank::Four,
+            Rank::Five,
+            Rank::Six,
+            Rank::Seven,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Ten,
+            Rank::Jack,
+            Rank::Queen,
+            Rank::King,
+        ];
+        &RANKS
+    }
+
+    /// Returns the rank's position in ace-high order, from 0 for a two up to 12 for an ace.
+    pub fn ace_high(self) -> u8 {
+        match self {
+            Rank::Ace => 12,
+            _ => self as u8 - 1,
+        }
+    }
+}
+
 impl Display for Rank {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let sym = match self {
@@ -186,14 +221,122 @@ impl FromStr for Rank {
     }
 }
 
-pub struct Card {
-    pub rank: Rank,
-    pub suit: Suit,
+/// A card from a French deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Card {
+    /// A ranked card of a particular suit.
+    Suited {
+        /// Card rank.
+        rank: Rank,
+        /// Card suit.
+        suit: Suit,
+    },
+    /// The little (black) joker.
+    Joker,
+    /// The big (red) joker.
+    BigJoker,
+}
+
+impl Card {
+    /// Creates a new suited [`Card`].
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Card::Suited { rank, suit }
+    }
+
+    /// Returns the rank of this card, or `None` for a joker.
+    pub fn rank(self) -> Option<Rank> {
+        match self {
+            Card::Suited { rank, .. } => Some(rank),
+            Card::Joker | Card::BigJoker => None,
+        }
+    }
+
+    /// Returns the suit of this card, or `None` for a joker.
+    pub fn suit(self) -> Option<Suit> {
+        match self {
+            Card::Suited { suit, .. } => Some(suit),
+            Card::Joker | Card::BigJoker => None,
+        }
+    }
+
+    /// Returns true if this card is a joker.
+    pub fn is_joker(self) -> bool {
+        matches!(self, Card::Joker | Card::BigJoker)
+    }
+
+    /// Returns the color of this card.
+    pub fn color(self) -> Color {
+        match self {
+            Card::Suited { suit, .. } => suit.color(),
+            Card::Joker => Color::Black,
+            Card::BigJoker => Color::Red,
+        }
+    }
+
+    /// Returns a [`ratatui::text::Span`] for the card.
+    #[cfg(feature = "tui")]
+    pub fn to_span(self) -> Span<'static> {
+        use ratatui::style::Color::Red;
+        match self.color() {
+            Color::Black => Span::raw(self.to_string()),
+            Color::Red => Span::raw(self.to_string()).style(Red),
+        }
+    }
+}
+
+/// The first codepoint of the Unicode playing-card block, which depicts a card back.
+const UNICODE_BASE: u32 = 0x1F0A0;
+
+/// Suits in the order in which they appear in the Unicode playing-card block.
+const UNICODE_SUITS: [Suit; 4] = [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club];
+
+impl Card {
+    /// Returns the character from the Unicode playing-card block that depicts this card.
+    pub fn to_unicode_char(self) -> char {
+        let offset = match self {
+            Card::Suited { rank, suit } => {
+                let row = UNICODE_SUITS.iter().position(|&s| s == suit).unwrap() as u32;
+                // The block includes a knight between the jack and the queen, which has no
+                // counterpart in the French deck.
+                let col = match rank {
+                    Rank::Queen | Rank::King => rank as u32 + 2,
+                    _ => rank as u32 + 1,
+                };
+                row * 16 + col
+            }
+            Card::BigJoker => 0x1F,
+            Card::Joker => 0x2F,
+        };
+        char::from_u32(UNICODE_BASE + offset).expect("valid codepoint")
+    }
+
+    /// Parses a character from the Unicode playing-card block. Returns `None` for characters
+    /// outside the block, as well as for the card back and the knights.
+    pub fn from_unicode_char(c: char) -> Option<Self> {
+        let offset = (c as u32).checked_sub(UNICODE_BASE)?;
+        let (row, col) = (offset / 16, offset % 16);
+        match (row, col) {
+            (1, 0xF) => return Some(Card::BigJoker),
+            (2, 0xF) | (3, 0xF) => return Some(Card::Joker),
+            _ => (),
+        }
+        let suit = *UNICODE_SUITS.get(row as usize)?;
+        let rank = match col {
+            0x1..=0xB => Rank::all_ranks()[col as usize - 1],
+            0xD | 0xE => Rank::all_ranks()[col as usize - 2],
+            _ => return None,
+        };
+        Some(Card::new(rank, suit))
+    }
 }
 
 impl Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.rank, self.suit)
+        match self {
+            Card::Suited { rank, suit } => write!(f, "{rank}{suit}"),
+            Card::Joker => f.write_str("jk"),
+            Card::BigJoker => f.write_str("JK"),
+        }
     }
 }
 
@@ -201,11 +344,16 @@ impl FromStr for Card {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jk" => return Ok(Card::Joker),
+            "JK" => return Ok(Card::BigJoker),
+            _ => (),
+        }
         let mut chars = s.chars();
         let rank: Rank = chars.next().ok_or(())?.try_into()?;
         let suit: Suit = chars.next().ok_or(())?.try_into()?;
         if chars.next().is_none() {
-            Ok(Card { rank, suit })
+            Ok(Card::new(rank, suit))
         } else {
             Err(())
         }
@@ -240,3 +388,164 @@ impl<'de> Deserialize<'de> for Card {
         deserializer.deserialize_str(CardVisitor)
     }
 }
+
+impl Deck<Card> {
+    /// Creates a standard 52-card deck, without jokers, in suit and rank order.
+    pub fn standard() -> Self {
+        DeckBuilder::new().build()
+    }
+
+    /// Creates a standard 52-card deck, plus the little and big jokers.
+    pub fn standard_with_jokers() -> Self {
+        DeckBuilder::new().with_jokers().build()
+    }
+}
+
+/// A builder for decks made up of an arbitrary subset of the French deck.
+///
+/// By default, the builder produces a single copy of each of the 52 suited cards. The set of
+/// ranks and suits may be restricted, the deck may be duplicated, and jokers may be added.
+#[derive(Debug, Clone)]
+pub struct DeckBuilder {
+    ranks: Vec<Rank>,
+    suits: Vec<Suit>,
+    copies: usize,
+    jokers: bool,
+}
+
+impl Default for DeckBuilder {
+    fn default() -> Self {
+        Self {
+            ranks: Rank::all_ranks().to_vec(),
+            suits: Suit::all_suits().to_vec(),
+            copies: 1,
+            jokers: false,
+        }
+    }
+}
+
+impl DeckBuilder {
+    /// Creates a new [`DeckBuilder`] for a standard 52-card deck.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the deck to the specified ranks.
+    pub fn ranks<I: IntoIterator<Item = Rank>>(mut self, ranks: I) -> Self {
+        self.ranks = ranks.into_iter().collect();
+        self
+    }
+
+    /// Restricts the deck to the specified suits.
+    pub fn suits<I: IntoIterator<Item = Suit>>(mut self, suits: I) -> Self {
+        self.suits = suits.into_iter().collect();
+        self
+    }
+
+    /// Retains only the ranks for which the predicate returns true.
+    pub fn filter_ranks<F: Fn(Rank) -> bool>(mut self, f: F) -> Self {
+        self.ranks.retain(|&r| f(r));
+        self
+    }
+
+    /// Retains only the suits for which the predicate returns true.
+    pub fn filter_suits<F: Fn(Suit) -> bool>(mut self, f: F) -> Self {
+        self.suits.retain(|&s| f(s));
+        self
+    }
+
+    /// Sets the number of copies of each card in the deck.
+    pub fn copies(mut self, copies: usize) -> Self {
+        self.copies = copies;
+        self
+    }
+
+    /// Adds a little and a big joker to each copy of the deck.
+    pub fn with_jokers(mut self) -> Self {
+        self.jokers = true;
+        self
+    }
+
+    /// Builds the deck, in suit and rank order, with jokers following each copy.
+    pub fn build(&self) -> Deck<Card> {
+        let jokers: &[Card] = if self.jokers {
+            &[Card::Joker, Card::BigJoker]
+        } else {
+            &[]
+        };
+        let copy = self
+            .suits
+            .iter()
+            .flat_map(|&suit| self.ranks.iter().map(move |&rank| Card::new(rank, suit)))
+            .chain(jokers.iter().copied());
+        (0..self.copies).flat_map(|_| copy.clone()).collect()
+    }
+}
+
+/// Parses whitespace-separated cards, for tests.
+#[cfg(test)]
+pub(crate) fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_card_round_trip() {
+        for card in Deck::standard_with_jokers().take(54) {
+            let s = card.to_string();
+            assert_eq!(Card::from_str(&s), Ok(card), "{s}");
+        }
+        assert_eq!(
+            Card::from_str("jd"),
+            Ok(Card::new(Rank::Jack, Suit::Diamond))
+        );
+        assert_eq!(Card::from_str("Jk"), Err(()));
+    }
+
+    #[test]
+    fn test_unicode_char() {
+        for card in Deck::standard_with_jokers() {
+            let c = card.to_unicode_char();
+            assert_eq!(Card::from_unicode_char(c), Some(card), "{c}");
+        }
+        assert_eq!(
+            Card::new(Rank::Ace, Suit::Spade).to_unicode_char(),
+            '\u{1F0A1}'
+        );
+        assert_eq!(
+            Card::new(Rank::Queen, Suit::Club).to_unicode_char(),
+            '\u{1F0DD}'
+        );
+        assert_eq!(Card::from_unicode_char('\u{1F0AC}'), None);
+        assert_eq!(Card::from_unicode_char('\u{1F0A0}'), None);
+        assert_eq!(Card::from_unicode_char('A'), None);
+    }
+
+    #[test]
+    fn test_standard_deck() {
+        let deck = Deck::standard();
+        assert_eq!(deck.len(), 52);
+        assert!(!deck.cards().iter().any(|c| c.is_joker()));
+        assert_eq!(Deck::standard_with_jokers().len(), 54);
+    }
+
+    #[test]
+    fn test_deck_builder() {
+        let deck = DeckBuilder::new()
+            .filter_ranks(|r| r >= Rank::Nine)
+            .suits([Suit::Heart, Suit::Spade].iter().copied())
+            .copies(2)
+            .with_jokers()
+            .build();
+        assert_eq!(deck.len(), 2 * (2 * 5 + 2));
+        assert_eq!(deck.cards().iter().filter(|c| c.is_joker()).count(), 4);
+        assert!(deck
+            .cards()
+            .iter()
+            .filter_map(|c| c.rank())
+            .all(|r| r >= Rank::Nine));
+    }
+}
@@ -0,0 +1,167 @@
+//! Localization.
+//!
+//! User-facing text is looked up by message ID in [Fluent](https://projectfluent.org) resources,
+//! one per language, which are compiled into the binary. The language is chosen once at startup
+//! with [`set_lang`], and messages missing from its resource fall back to English. Messages are
+//! usually formatted with the [`tr!`](crate::tr) macro.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+pub use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentResource;
+use unic_langid::LanguageIdentifier;
+
+#[cfg(test)]
+mod test;
+
+/// A language that the user-facing text is available in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    /// English, in which every message is written.
+    #[default]
+    English,
+    /// French.
+    French,
+}
+
+impl Lang {
+    /// All of the languages.
+    pub const ALL: [Lang; 2] = [Lang::English, Lang::French];
+
+    /// The environment variable that chooses the language, when it isn't given on the command
+    /// line.
+    pub const VAR: &'static str = "DECKARD_LANG";
+
+    /// The language's code.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::English => "en",
+            Lang::French => "fr",
+        }
+    }
+
+    /// The language's Fluent resource.
+    fn source(self) -> &'static str {
+        match self {
+            Lang::English => include_str!("i18n/en.ftl"),
+            Lang::French => include_str!("i18n/fr.ftl"),
+        }
+    }
+
+    /// Chooses the language set by `DECKARD_LANG`, or failing that, by the system locale in
+    /// `LC_ALL`, `LC_MESSAGES`, or `LANG`. Unsupported languages fall back to English.
+    pub fn from_env() -> Self {
+        [Self::VAR, "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for Lang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = ();
+
+    /// Parses a language tag (e.g., `fr` or `fr-CA`), or a POSIX locale (e.g., `fr_CA.UTF-8`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+        let id: LanguageIdentifier = tag.parse().map_err(|_| ())?;
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|lang| id.language.as_str() == lang.code())
+            .ok_or(())
+    }
+}
+
+/// An argument that a UI replaces with styled text, such as a colored suit, after formatting a
+/// message. It's the object replacement character, which never appears in the messages.
+pub const PLACEHOLDER: &str = "\u{fffc}";
+
+/// The language in use.
+static LANG: AtomicU8 = AtomicU8::new(Lang::English as u8);
+
+/// Sets the language in use.
+pub fn set_lang(lang: Lang) {
+    LANG.store(lang as u8, Ordering::Relaxed);
+}
+
+/// Returns the language in use.
+pub fn lang() -> Lang {
+    match LANG.load(Ordering::Relaxed) {
+        1 => Lang::French,
+        _ => Lang::English,
+    }
+}
+
+/// Returns the bundle of messages for the language, which is parsed on first use.
+fn bundle(lang: Lang) -> &'static FluentBundle<FluentResource> {
+    static BUNDLES: [OnceLock<FluentBundle<FluentResource>>; 2] =
+        [OnceLock::new(), OnceLock::new()];
+    BUNDLES[lang as usize].get_or_init(|| {
+        let id: LanguageIdentifier = lang.code().parse().expect("valid language");
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // Isolation marks around arguments show up as garbage in some terminals.
+        bundle.set_use_isolating(false);
+        let resource = FluentResource::try_new(lang.source().to_string())
+            .unwrap_or_else(|(_, errors)| panic!("invalid {} resource: {:?}", lang, errors));
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|errors| panic!("invalid {} resource: {:?}", lang, errors));
+        bundle
+    })
+}
+
+/// Formats a message in the specified language, falling back to English if it's missing. An
+/// unknown message ID is returned as is, so that a missing message is visible, but not fatal.
+pub fn tr_in(lang: Lang, id: &str, args: Option<&FluentArgs>) -> String {
+    let found = [lang, Lang::English].iter().find_map(|&lang| {
+        let bundle = bundle(lang);
+        let pattern = bundle.get_message(id)?.value()?;
+        Some((bundle, pattern))
+    });
+    let Some((bundle, pattern)) = found else {
+        tracing::warn!("missing message: {id}");
+        return id.to_string();
+    };
+    let mut errors = vec![];
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        tracing::warn!("failed to format {id}: {errors:?}");
+    }
+    text.into_owned()
+}
+
+/// Formats a message in the language in use.
+pub fn tr(id: &str, args: Option<&FluentArgs>) -> String {
+    tr_in(lang(), id, args)
+}
+
+/// Formats a localized message, with optional named arguments, which may be strings or numbers:
+/// for example, `tr!("narration-trick", seat = seat.to_string())`.
+#[macro_export]
+macro_rules! tr {
+    ($id:literal) => {
+        $crate::i18n::tr($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr($id, Some(&args))
+    }};
+}
@@ -0,0 +1,597 @@
+## Seats and teams
+
+seat-north = North
+seat-east = East
+seat-south = South
+seat-west = West
+team-north-south = North/South
+team-east-west = East/West
+
+## Actions
+
+action-type-bid-top = bid top
+action-type-bid-other = bid other
+action-type-discard = discard
+action-type-lead = lead
+action-type-follow = follow
+action-type-call-renege = call renege
+action-pass = Pass
+action-call = Call { $suit }
+action-call-alone = Call { $suit } alone
+
+## Errors
+
+error-dealer-must-bid = the dealer must bid
+error-must-call-top-suit = must call { $suit }
+error-cannot-call-top-suit = cannot call { $suit }
+error-card-not-held = { $seat } does not hold { $card }
+error-must-follow-lead = { $seat } must follow { $card }
+error-no-renege = { $card } was not a renege
+error-incomplete-deck = deck is missing cards
+error-duplicate-card = deck contains duplicate card
+error-invalid-hand-size = a player has the incorrect number of cards
+error-invalid-action-data = action contains invalid data
+error-expected-action = expected { $seat } to { $action }
+error-round-over = round is over
+error-invariant = invariant violated: { $reason }
+error-invalid-log-id = invalid log id { $id }
+
+## Narration
+
+narration-low-card-deals = Low card deals.
+narration-dealt = { $seat } dealt.
+narration-called = { $seat } called { $suit }.
+narration-called-alone = { $seat } called { $suit } alone.
+narration-deals-first = { $seat } deals first.
+narration-packet = { $count ->
+    [one] 1 card
+   *[other] { $count } cards
+} to { $seat }.
+narration-turned-up = { $card } is turned up.
+narration-redeal = Thrown in. { $seat } deals.
+narration-renege = { $seat } reneged with { $card }.
+narration-trick = { $seat } takes the trick.
+narration-round = { $team } win { $points } points.
+narration-game = { $team } wins the game.
+narration-expect = { $seat } to { $action }.
+
+## Outcomes
+
+outcome-round = { $team } wins { $points } points
+outcome-thrown-in = Thrown in
+outcome-game = { $team } wins { $score } after { $rounds ->
+    [one] 1 round
+   *[other] { $rounds } rounds
+}
+outcome-game-on-time = { $team } wins { $score } after { $rounds ->
+    [one] 1 round
+   *[other] { $rounds } rounds
+}, when { $seat } ran out of time
+
+## User interface
+
+ui-robot-suggests = Robot suggests: { $action }
+ui-wrote = Wrote to { $path }
+ui-write-failed = Failed to write { $path }: { $error }
+ui-new-game = New game
+ui-save = Save
+ui-history = History
+ui-robot-autoplay = Robot autoplay
+ui-hint = Hint
+ui-score = Score
+ui-tricks = Tricks
+ui-loners = Loners
+ui-click-to-discard = Click a card to discard.
+ui-click-to-play = Click a card to play.
+ui-continue = Continue
+ui-game-over = Game over
+ui-wins-by = { $team } wins by { $margin }.
+ui-rounds-played = { $rounds ->
+    [one] 1 round
+   *[other] { $rounds } rounds
+} played.
+ui-load-failed = Failed to load { $path }: { $error }
+ui-loaded = Loaded { $path }
+ui-no-renege-exposed = No renege has been exposed
+ui-select-trick-card = Select a card played to a trick
+ui-recent-failed = Failed to update recent files: { $error }
+ui-archive-failed = Failed to archive the game: { $error }
+ui-no-finished-games = No finished games yet
+ui-list-finished-failed = Failed to list finished games: { $error }
+ui-resumed = Resumed the game in progress
+ui-playing-as = Playing as { $seat }
+ui-bids = { $seat } bids { $preset }
+ui-moves-played = { $count ->
+    [one] Only 1 move has been played
+   *[other] Only { $count } moves have been played
+}
+ui-no-saved-games = No saved games found
+ui-list-saved-failed = Failed to list saved games: { $error }
+ui-last-tab = Can't close the last tab
+ui-competitive-locked = Locked until the game is over, in competitive mode
+ui-log = Log
+ui-achievement = Achievement unlocked: { $achievement } ({ $description })
+ui-odds-shown = Trick odds shown
+ui-odds-hidden = Trick odds hidden
+ui-win-odds = Win { $percent }%
+ui-win-odds-shown = Win odds shown
+ui-win-odds-hidden = Win odds hidden
+ui-robot-lines = Added the robot's line at { $count } of your decisions to the history
+ui-throw-in-too-late = Too late to throw in: trump has been called
+ui-throw-in-declined = { $seat } wants to play this hand
+ui-trainer-enabled = Strength trainer enabled
+ui-trainer-disabled = Strength trainer disabled
+ui-trainer-pending = Estimate your hand's strength first (1-4), or press Esc to skip
+ui-card-back = Card backs: { $back }
+ui-must-follow = You must follow { $suit }, which was led.
+ui-cannot-play = { $card } can't be played: you must follow { $suit }.
+ui-seat-clock = { $seat } { $time }
+ui-hand = { $seat }'s hand:
+ui-card-not-in-hand = { $card } is not in your hand
+ui-game-tab = Game
+ui-autoplay-enabled = Robot autoplay enabled
+ui-autoplay-disabled = Robot autoplay disabled
+ui-forced = forced
+ui-versus = { $points } pts vs { $action }
+ui-trick = Trick
+
+## Saved games
+
+metadata-target = to { $points }
+metadata-best-of = best of { $games }
+metadata-clock = clock { $control }
+
+## Console
+
+console-invalid-input = Invalid input, try again
+console-hand = Hand: { $cards }
+console-trick = Trick: { $trick }
+console-trick-won = Trick: { $trick } → { $seat }
+console-bid-top = Bid top?
+console-bid-other = Bid other?
+console-suit = Suit?
+console-alone = Alone?
+console-discard = Discard?
+console-lead = Lead?
+console-follow = Follow?
+console-call-renege = Call a renege?
+console-card = Card?
+console-cuts = { $seat } cuts { $card }
+console-dealer = Dealer: { $seat }
+console-top-card = Top card: { $card }
+console-error = Error: { $error }
+
+## Achievements
+
+achievement-loner-march = Going it alone
+achievement-loner-march-description = Take all five tricks on a lone hand
+achievement-loner-euchre = Stopped cold
+achievement-loner-euchre-description = Euchre an opponent's lone hand
+achievement-comeback = Back from the brink
+achievement-comeback-description = Win a game after trailing 0-8
+achievement-shutout = Skunked
+achievement-shutout-description = Win a game without the opponents scoring
+
+## Commands
+
+command-empty = Empty command
+command-too-many-args = Too many arguments to :{ $name }
+command-requires-arg = :{ $name } requires an argument
+command-invalid-moves = Invalid move number: { $moves }
+command-invalid-seat = Invalid seat: { $seat }
+command-invalid-robot = Invalid robot setting: { $setting } (expected on or off)
+command-preset-requires-args = :preset requires a seat and a preset
+command-invalid-preset = Invalid preset: { $preset } (expected { $expected })
+command-invalid-back = Invalid card back: { $back } (expected { $expected })
+command-unknown = Unknown command: { $name }
+
+## Screens
+
+start-title = Euchre
+start-in-progress = Game in progress: { $summary }
+start-no-game = No game in progress
+start-new-game = New game (n)
+start-resume = Resume game (r)
+start-load = Load a saved game (l)
+start-settings = Settings (s)
+start-quit = Quit (q)
+settings-title = Settings
+settings-autoplay = Robot autoplay
+settings-trick-odds = Trick odds
+settings-win-odds = Win odds
+settings-trainer = Strength trainer
+settings-card-back = Card backs
+settings-theme = Theme
+settings-autoplay-delay = Autoplay delay
+settings-batch-events = Batch events
+settings-robot = Robots
+settings-points = Points to win
+settings-best-of = Rubber
+settings-renege = Reneges
+settings-competitive = Competitive
+settings-rotation = Deal passes
+settings-on = on
+settings-off = off
+settings-color = color
+settings-monochrome = monochrome
+settings-delay = { $ms } ms
+settings-robot-easy = easy
+settings-robot-hard = hard
+settings-games = best of { $count }
+settings-renege-reject = rejected
+settings-renege-defer = allowed
+settings-rotation-clockwise = clockwise
+settings-rotation-random = at random
+settings-rotation-fixed = never
+settings-rules-hint = Rules apply from the next new game.
+settings-hint = ↵ to change, Esc to save and go back
+settings-invalid = Settings not saved: { $error }
+save-title = Save game
+save-file = File
+save-notes = Notes
+save-keys = ↵ save · ⇥ next field · esc cancel
+summary-games = Games { $ns }-{ $ew }
+summary-takes-it = { $games }; { $team } takes it.
+summary-next-game = { $games }; any key for next.
+summary-on-time = { $team } wins on time ({ $seat }).
+review-end-of-round = End of round ({ $value })
+remote-disconnected = Disconnected from host. Reconnecting...
+remote-waiting-for-players = Waiting for players...
+remote-table = Table: { $name }
+remote-seat-open = open (robot)
+remote-seat-robot = robot
+remote-seat-disconnected = disconnected
+remote-seat-ready = ready
+remote-seat-not-ready = not ready
+remote-seat-yours = { $status } (you)
+remote-toggle-ready = Press r to toggle ready.
+remote-waiting-for-game = Waiting for the game to begin...
+history-bid-top = bid 1
+history-bid-other = bid 2
+history-discard = discard
+history-trick = trick { $trick }
+history-dealt = { $seat } dealt
+history-passed = { $seat } passed
+history-called = { $seat } called { $suit }
+history-called-alone = { $seat } called { $suit } alone
+history-discarded = { $seat } discarded { $card }
+history-led = { $seat } led { $card }
+history-followed = { $seat } followed { $card }
+history-called-renege = { $seat } called a renege on { $card }
+history-cursor = (you are here)
+history-graph-deal = deal
+history-graph-here = here
+review-thinking = thinking:
+review-pass = pass
+review-call-alone = { $suit } alone
+review-makers = makers
+review-robot = robot:
+stats-title = Statistics
+stats-deals = Deals
+stats-call = Call%
+stats-euchre = Euch%
+stats-alone = Alone%
+stats-points = Pts/dl
+archive-title = Finished games
+archive-rounds = { $rounds ->
+    [one] 1 round
+   *[other] { $rounds } rounds
+}
+picker-title = Load game
+chat-title = Chat
+start-summary = N/S { $ns }, E/W { $ew } after { $rounds ->
+    [one] 1 round
+   *[other] { $rounds } rounds
+}
+
+## Score sheet
+
+sheet-title = Score sheet
+sheet-empty = No rounds have been completed.
+
+## Round results
+
+results-title = Round over
+results-euchred = { $makers } euchred! { $team } win { $points } points.
+results-points = Points
+results-continue = Press any key to continue.
+
+## Tutorial
+
+tutorial-title = Tutorial
+tutorial-welcome = Welcome to euchre! You sit South, and North is your partner. One team makes trump, and must then take at least three of the five tricks. Press any key to see your hand.
+tutorial-order-up = The upturned card is { $card }. You may order it up, making { $suit } trump, and the dealer takes it into their hand. The jack of trump is the highest card, then the other jack of the same color. Holding both, order it up!
+tutorial-name-trump = Everyone passed, so { $suit } is turned down. You may now name any other suit as trump, or pass.
+tutorial-discard = You ordered up the top card as dealer, so it's in your hand. Discard a card, keeping your trumps and aces.
+tutorial-lead = Your lead. { $suit } is trump: its jack is the highest card, then the other jack of the same color, then its ace, king, queen, ten, and nine. Leading trump draws out the opponents' trumps.
+tutorial-follow = { $suit } was led. You must follow suit if you can; if not, any card will do, and a trump wins the trick. The other jack of trump's color counts as trump, not as its printed suit.
+tutorial-scoring = The round is over. Making trump scores 1 point for three or four tricks, 2 for all five, and 4 for all five alone. Take fewer than three, and you're euchred: the other team scores 2. Games are played to 10 points.
+
+## Strength trainer
+
+trainer-title = Hand strength
+trainer-question = How strong is your hand in { $suit }?
+trainer-weak = weak
+trainer-borderline = borderline
+trainer-call = worth a call
+trainer-alone = worth going alone
+trainer-reveal = Strength in { $suit }: { $strength }, { $bucket }
+trainer-correct = Right!
+trainer-incorrect = You guessed { $guess }.
+
+## Drills
+
+drill-header = Drill { $n } of { $count } ({ $kind })
+drill-position = You are { $seat } at position { $position }. { $dealer } dealt, and turned up { $card }.
+drill-illegal = { $action } isn't allowed here, try again.
+drill-correct = Correct!
+drill-best = The best choice was { $action }, which wins { $loss } more points on average.
+drill-tally = { $correct }/{ $attempts } correct
+drill-tally-accuracy = { $correct }/{ $attempts } correct ({ $accuracy }%), { $loss } points lost per decision
+drill-session = This session: { $tally }
+drill-all-time = All time: { $tally }
+drill-recent = Recent sessions: { $sessions }
+
+## Reports
+
+report-seat = Seat
+report-euchre = Euchre%
+report-logs = { $logs } logs, { $unfinished } unfinished
+report-rounds-won = Rounds won: { $wins }
+report-in-progress = In progress, after { $rounds } rounds.
+report-deal = Deal
+report-dealt = { $dealer } dealt, and turned up { $card }.
+report-called = { $seat } called { $suit }.
+report-called-alone = { $seat } called { $suit } alone.
+report-tricks = Tricks
+report-bidding-accuracy = Bidding accuracy
+report-position = Position
+report-position-name = { $position ->
+        [0] 1st
+        [1] 2nd
+        [2] 3rd
+       *[3] Dealer
+    }
+report-strength = Strength
+report-passes = Passes
+report-pass-ok = Pass ok
+report-calls = Calls
+report-call-ok = Call ok
+report-lead = Lead
+report-trick-number = Trick { $n }
+report-leads-by-role = Leads by role
+report-leads-by-trick = Leads by trick
+report-type = Type
+report-action = Action
+report-value = Value
+report-best-other = Best other
+report-think = Think
+report-blunders = { $blunders } blunder(s), giving up at least { $points } points each
+report-think-time = { $seconds }s per decision
+report-board = Board
+report-board-dealt = Dealt
+report-board-rotated = Rotated
+report-net = N/S net
+report-total = Total
+report-bidding-analysis = Bidding analysis
+report-bids-in-logs = { $bids } bids in { $logs } logs.
+report-lead-analysis = Lead analysis
+report-leads-in-logs = { $leads } leads in { $logs } logs.
+report-puzzles = Found { $total } puzzles in { $logs } logs: { $tally }
+report-euchre-round = Euchre round
+report-wrote = Wrote { $path }
+report-searched = North and South searched; East and West played by heuristics.
+report-your-table = Your table:
+report-robot-table = Robot table:
+report-practice = You finished { $diff } points against the robots on the same cards.
+report-robots = Robots: { $robots }.
+lead-right-bower = Right bower
+lead-left-bower = Left bower
+lead-high-trump = High trump
+lead-low-trump = Low trump
+lead-side-ace = Side ace
+lead-side-king = Side king
+lead-low-side = Low side
+lead-maker = Maker
+lead-partner = Partner
+lead-defender = Defender
+paths-config = Config
+paths-data = Data
+paths-autosaves = Autosaves
+paths-archive = Archive
+paths-robot = Robot
+paths-settings = Settings
+paths-stats = Stats
+paths-achievements = Achievements
+paths-drills = Drills
+paths-recent-files = Recent files
+
+## Other games
+
+engine-player = Player { $n }
+engine-player-abbr = P{ $n }
+engine-cant-do = Can't do "{ $input }" now; enter ? for options
+engine-input-closed = input closed
+engine-hint-wait = q: quit
+engine-hint-play = ↑↓: select, enter: play, q: quit
+engine-hint-pause = { $message } (q: quit)
+engine-log = Log
+engine-next-round = Press any key to deal the next round
+engine-next-game = Press any key to deal the next game
+
+game-you-are = You are { $player }
+game-you-are-help = You are { $player }; enter ? to list your options
+game-hand = Hand: { $cards }
+game-trick = Trick: { $trick }
+game-trick-won = Trick: { $trick } -> { $winner }
+game-table = Table: { $cards }
+game-trump = Trump: { $trump }
+game-led = Led: { $card }
+game-auction = Auction: { $calls }
+game-call = { $player }: { $call }
+game-view = { $player } (dealer { $dealer })
+game-score = Score: { $score }
+game-wins = { $player } wins
+game-team-wins = { $team } win
+game-upcard = Upcard: { $card }
+game-stock-pile = Stock: { $stock }, pile: { $pile }
+game-cards-held = Cards held: { $sizes }
+game-action-draw = Draw
+game-action-take = Take { $cards }
+game-action-meld = Meld { $cards }
+game-drew = { $player } drew
+game-took = { $player } took { $cards }
+game-melded = { $player } melded { $meld }
+game-discarded = { $player } discarded { $card }
+game-played = { $player } played { $card }
+game-went-out = { $player } went out
+game-stock-ran-out = The stock ran out
+game-passed = { $player } passed
+game-player-you = { $player } (you)
+
+bridge-no-trump = NT
+bridge-contract = { $bid } by { $declarer }
+bridge-contract-line = Contract: { $contract }
+bridge-passed-out = Passed out
+bridge-made = { $contract } made
+bridge-made-over = { $contract } made, plus { $over }
+bridge-down = { $contract } down { $under }
+bridge-dummy = Dummy ({ $seat }): { $hand }
+bridge-view = { $seat } (dealer { $dealer }, vulnerable: { $vulnerable })
+bridge-vulnerable-none = none
+bridge-vulnerable-both = both
+bridge-playing-from = Playing from { $seat }
+bridge-games = Games
+bridge-partial = Partial
+bridge-total = Total
+bridge-rubber-won = { $team } win the rubber
+
+canasta-natural = natural canasta
+canasta-mixed = mixed canasta
+canasta-action-take = Take
+canasta-action-withdraw = Withdraw
+canasta-team-score = { $total } (melds { $melds }, canastas { $canastas }, red threes { $red_threes }, going out { $going_out }, in hand { $hands })
+canasta-red-three = { $player } laid aside { $card }
+canasta-took-pile = { $player } took the pile of { $count ->
+        [one] { $count } card
+       *[other] { $count } cards
+    }, with { $card } on top
+canasta-withdrew = { $player } withdrew their melds
+canasta-no-melds = no melds; minimum { $minimum }
+canasta-on-top = with { $card } on top
+canasta-frozen = frozen
+
+rummy-action-lay-off = Lay off { $card } on { $meld }
+rummy-player-score = { $total } (melds { $melds }, in hand { $hand })
+rummy-laid-off = { $player } laid off { $card }, making { $meld }
+rummy-must-play = You must play { $card }
+
+skat-clubs = Clubs
+skat-spades = Spades
+skat-hearts = Hearts
+skat-diamonds = Diamonds
+skat-grand = Grand
+skat-null = Null
+skat-hand = hand
+skat-ouvert = ouvert
+skat-schwarz = schwarz
+skat-schneider = schneider
+skat-hold = Hold
+skat-won = won
+skat-lost = lost
+skat-overbid = overbid
+skat-action-pick-up = Pick up
+skat-action-bury = Bury { $card }
+skat-passed-in = Passed in
+skat-result = { $declarer } played { $contract } at { $bid } with { $matadors } matadors, taking { $points } points
+skat-result-null = { $declarer } played { $contract } at { $bid }, taking { $tricks } tricks
+skat-won-auction = { $player } won the auction at { $bid }
+skat-picked-up = { $player } picked up the skat
+skat-declared = { $player } declared { $contract }
+skat-shows = { $player } shows { $cards }
+skat-declarer = Declarer: { $declarer } at { $bid }
+skat-declarer-plays = Declarer: { $declarer } plays { $contract } at { $bid }
+skat-skat = Skat: { $cards }
+skat-open = Open ({ $declarer }): { $cards }
+
+durak-title = Durak
+durak-action-attack = Attack { $cards }
+durak-action-beat = Beat { $attack } with { $defense }
+durak-action-take = Take
+durak-is-durak = { $player } is the durak
+durak-nobody = Nobody is the durak
+durak-attacked = { $player } attacked with { $cards }
+durak-beat = { $player } beat { $attack } with { $defense }
+durak-will-take = { $player } will take
+durak-beat-off = { $player } beat off the attack
+durak-drew = { $player } drew { $count }
+durak-out = { $player } is out
+durak-view = { $player }: { $attacker } attacking { $defender }
+durak-status = Trump: { $card }, stock: { $stock }, discards: { $discards }
+durak-tui-status = Trump: { $card }  Stock: { $stock }  Discards: { $discards }
+durak-player-status = { $player }: { $cards } cards, lost { $losses }
+durak-role-out = out
+durak-role-attacking = attacking
+durak-role-defending = defending
+durak-table-title = Table
+durak-table-taking = Table: { $player } will take
+durak-hint-over = n: new game, q: quit
+
+scopa-action-take = { $card } takes { $capture }
+scopa-player-points = { $player }: { $points } points ({ $tally })
+scopa-dealt = New hands dealt, { $left } cards left
+scopa-captured = { $player } played { $card }, taking { $capture }
+scopa-scopa = Scopa for { $player }!
+scopa-took-rest = { $player } took the rest: { $cards }
+scopa-captures = Captured: { $captured }; scopas: { $scopas }
+scopa-deck = Deck: { $stock }
+scopa-tally = cards { $cards }, coins { $coins }, primiera { $primiera }, scopas { $scopas }
+scopa-settebello = settebello
+
+briscola-wins = { $player } wins, { $points }
+briscola-drawn = Drawn, { $points }
+briscola-is = The briscola is { $card }
+briscola-trick = { $player } won the trick, { $points } points
+briscola-status = Briscola: { $card }; stock: { $stock }
+briscola-points = Points: { $points }
+briscola-games-won = Games won: { $first }-{ $second }
+
+ninety-nine-action-bid = Bid { $card }
+ninety-nine-result = { $player } bid { $bid }, took { $tricks }: { $points } points
+ninety-nine-has-bid = { $player } has bid
+ninety-nine-bid = Bid: { $cards }
+ninety-nine-tricks = Tricks: { $taken }
+
+nap-nap = Nap
+nap-bid = Bid { $tricks }
+nap-thrown-in = Thrown in
+nap-made = { $player } made { $bid }, taking { $tricks } tricks: { $score }
+nap-failed = { $player } failed { $bid }, taking { $tricks } tricks: { $score }
+nap-won-bidding = { $player } won the bidding: { $bid }
+nap-bidding = Bidding: { $calls }
+nap-contract = { $player }: { $bid }, taken { $taken }
+
+bezique-seven = Seven
+bezique-marriage = Marriage { $suit }
+bezique-bezique = Bezique
+bezique-four = Four { $rank ->
+        [ace] aces
+        [king] kings
+        [queen] queens
+       *[jack] jacks
+    }
+bezique-sequence = Sequence
+bezique-double-bezique = Double bezique
+bezique-action-exchange = Exchange
+bezique-player-points = { $player }: { $points } (melds { $melds }, brisques { $brisques }{ $last ->
+        [yes] , last trick 10
+       *[no] {""}
+    })
+bezique-turned-up-seven = Turned up { $card }: 10 to the dealer
+bezique-turned-up = Turned up { $card }
+bezique-declared = { $player } declared { $meld } for { $points }
+bezique-exchanged = { $player } exchanged the seven of trumps for 10
+bezique-endgame = The stock is exhausted
+bezique-trump-stock = Trump: { $card }; stock: { $stock }
+bezique-melds = Melds: { $melds }
+bezique-table = Table ({ $player }): { $cards }
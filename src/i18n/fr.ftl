@@ -0,0 +1,612 @@
+## Places et équipes
+
+seat-north = Nord
+seat-east = Est
+seat-south = Sud
+seat-west = Ouest
+team-north-south = Nord/Sud
+team-east-west = Est/Ouest
+
+## Actions
+
+action-type-bid-top = prendre la retourne
+action-type-bid-other = choisir l'atout
+action-type-discard = écarter
+action-type-lead = entamer
+action-type-follow = fournir
+action-type-call-renege = dénoncer une renonce
+action-pass = Passer
+action-call = Prendre à { $suit }
+action-call-alone = Prendre à { $suit } seul
+
+## Erreurs
+
+error-dealer-must-bid = le donneur doit choisir l'atout
+error-must-call-top-suit = il faut prendre à { $suit }
+error-cannot-call-top-suit = impossible de prendre à { $suit }
+error-card-not-held = { $seat } n'a pas { $card }
+error-must-follow-lead = { $seat } doit fournir à { $card }
+error-no-renege = { $card } n'était pas une renonce
+error-incomplete-deck = il manque des cartes au paquet
+error-duplicate-card = le paquet contient une carte en double
+error-invalid-hand-size = un joueur n'a pas le bon nombre de cartes
+error-invalid-action-data = l'action contient des données invalides
+error-expected-action = { $seat } devait { $action }
+error-round-over = la donne est terminée
+error-invariant = invariant violé : { $reason }
+error-invalid-log-id = identifiant de journal invalide : { $id }
+
+## Narration
+
+narration-low-card-deals = La carte la plus basse donne.
+narration-dealt = { $seat } a donné.
+narration-called = { $seat } a pris à { $suit }.
+narration-called-alone = { $seat } a pris à { $suit } seul.
+narration-deals-first = { $seat } donne en premier.
+narration-packet = { $count ->
+    [one] 1 carte
+   *[other] { $count } cartes
+} pour { $seat }.
+narration-turned-up = { $card } est retournée.
+narration-redeal = Donne annulée. { $seat } distribue.
+narration-renege = { $seat } a renoncé avec { $card }.
+narration-trick = { $seat } remporte le pli.
+narration-round = { $team } marquent { $points } points.
+narration-game = { $team } remportent la partie.
+narration-expect = À { $seat } de { $action }.
+
+## Outcomes
+
+outcome-round = { $team } marquent { $points } points
+outcome-thrown-in = Donne annulée
+outcome-game = { $team } gagnent { $score } après { $rounds ->
+    [one] 1 donne
+   *[other] { $rounds } donnes
+}
+outcome-game-on-time = { $team } gagnent { $score } après { $rounds ->
+    [one] 1 donne
+   *[other] { $rounds } donnes
+}, quand { $seat } a dépassé son temps
+
+## Interface
+
+ui-robot-suggests = Le robot suggère : { $action }
+ui-wrote = Enregistré dans { $path }
+ui-write-failed = Impossible d'écrire { $path } : { $error }
+ui-new-game = Nouvelle partie
+ui-save = Enregistrer
+ui-history = Historique
+ui-robot-autoplay = Jeu automatique
+ui-hint = Conseil
+ui-score = Score
+ui-tricks = Plis
+ui-loners = Parties seul
+ui-click-to-discard = Cliquez sur une carte à écarter.
+ui-click-to-play = Cliquez sur une carte à jouer.
+ui-continue = Continuer
+ui-game-over = Partie terminée
+ui-wins-by = { $team } gagnent par { $margin }.
+ui-rounds-played = { $rounds ->
+    [one] 1 donne jouée.
+   *[other] { $rounds } donnes jouées.
+}
+ui-load-failed = Échec du chargement de { $path } : { $error }
+ui-loaded = { $path } chargé
+ui-no-renege-exposed = Aucune renonce n'a été révélée
+ui-select-trick-card = Choisissez une carte jouée dans un pli
+ui-recent-failed = Échec de la mise à jour des fichiers récents : { $error }
+ui-archive-failed = Échec de l'archivage de la partie : { $error }
+ui-no-finished-games = Aucune partie terminée pour l'instant
+ui-list-finished-failed = Échec de la liste des parties terminées : { $error }
+ui-resumed = Partie en cours reprise
+ui-playing-as = Vous jouez { $seat }
+ui-bids = { $seat } enchérit en mode { $preset }
+ui-moves-played = { $count ->
+    [one] Seulement 1 coup a été joué
+   *[other] Seulement { $count } coups ont été joués
+}
+ui-no-saved-games = Aucune partie sauvegardée trouvée
+ui-list-saved-failed = Échec de la liste des parties sauvegardées : { $error }
+ui-last-tab = Impossible de fermer le dernier onglet
+ui-competitive-locked = Verrouillé jusqu'à la fin de la partie, en mode compétitif
+ui-log = Journal
+ui-achievement = Succès débloqué : { $achievement } ({ $description })
+ui-odds-shown = Chances au pli affichées
+ui-odds-hidden = Chances au pli masquées
+ui-win-odds = Victoire { $percent } %
+ui-win-odds-shown = Chances de victoire affichées
+ui-win-odds-hidden = Chances de victoire masquées
+ui-robot-lines = Ligne du robot ajoutée à l'historique pour { $count } de vos décisions
+ui-throw-in-too-late = Trop tard pour annuler la donne : l'atout a été choisi
+ui-throw-in-declined = { $seat } veut jouer cette donne
+ui-trainer-enabled = Entraînement à l'évaluation activé
+ui-trainer-disabled = Entraînement à l'évaluation désactivé
+ui-trainer-pending = Évaluez d'abord la force de votre main (1-4), ou appuyez sur Échap pour passer
+ui-card-back = Dos des cartes : { $back }
+ui-must-follow = Vous devez fournir à { $suit }, la couleur demandée.
+ui-cannot-play = { $card } ne peut pas être jouée : vous devez fournir à { $suit }.
+ui-seat-clock = { $seat } { $time }
+ui-hand = Main de { $seat } :
+ui-card-not-in-hand = { $card } n'est pas dans votre main
+ui-game-tab = Partie
+ui-autoplay-enabled = Jeu automatique activé
+ui-autoplay-disabled = Jeu automatique désactivé
+ui-forced = forcé
+ui-versus = { $points } pts contre { $action }
+ui-trick = Pli
+
+## Parties enregistrées
+
+metadata-target = en { $points }
+metadata-best-of = au meilleur des { $games }
+metadata-clock = pendule { $control }
+
+## Console
+
+console-invalid-input = Saisie invalide, réessayez
+console-hand = Main : { $cards }
+console-trick = Pli : { $trick }
+console-trick-won = Pli : { $trick } → { $seat }
+console-bid-top = Prendre la retourne ?
+console-bid-other = Choisir une autre couleur ?
+console-suit = Couleur ?
+console-alone = Seul ?
+console-discard = Écart ?
+console-lead = Entame ?
+console-follow = Carte à jouer ?
+console-call-renege = Dénoncer une renonce ?
+console-card = Carte ?
+console-cuts = { $seat } coupe { $card }
+console-dealer = Donneur : { $seat }
+console-top-card = Retourne : { $card }
+console-error = Erreur : { $error }
+
+## Achievements
+
+achievement-loner-march = Cavalier seul
+achievement-loner-march-description = Faire les cinq plis en jouant seul
+achievement-loner-euchre = Coupé net
+achievement-loner-euchre-description = Euchrer un adversaire qui joue seul
+achievement-comeback = Retour du bord du gouffre
+achievement-comeback-description = Gagner une partie après avoir été mené 0-8
+achievement-shutout = Capot
+achievement-shutout-description = Gagner une partie sans que les adversaires marquent
+
+## Commands
+
+command-empty = Commande vide
+command-too-many-args = Trop d'arguments pour :{ $name }
+command-requires-arg = :{ $name } exige un argument
+command-invalid-moves = Nombre de coups invalide : { $moves }
+command-invalid-seat = Place invalide : { $seat }
+command-invalid-robot = Réglage du robot invalide : { $setting } (on ou off attendu)
+command-preset-requires-args = :preset exige une place et un style
+command-invalid-preset = Style invalide : { $preset } ({ $expected } attendu)
+command-invalid-back = Dos de carte invalide : { $back } ({ $expected } attendu)
+command-unknown = Commande inconnue : { $name }
+
+## Screens
+
+start-title = Euchre
+start-in-progress = Partie en cours : { $summary }
+start-no-game = Aucune partie en cours
+start-new-game = Nouvelle partie (n)
+start-resume = Reprendre la partie (r)
+start-load = Charger une partie (l)
+start-settings = Réglages (s)
+start-quit = Quitter (q)
+settings-title = Réglages
+settings-autoplay = Jeu automatique
+settings-trick-odds = Chances au pli
+settings-win-odds = Chances de victoire
+settings-trainer = Évaluation de la main
+settings-card-back = Dos des cartes
+settings-theme = Thème
+settings-autoplay-delay = Délai automatique
+settings-batch-events = Regrouper les événements
+settings-robot = Robots
+settings-points = Points pour gagner
+settings-best-of = Manche
+settings-renege = Renonces
+settings-competitive = Compétition
+settings-rotation = Tour de donne
+settings-on = oui
+settings-off = non
+settings-color = couleur
+settings-monochrome = monochrome
+settings-delay = { $ms } ms
+settings-robot-easy = facile
+settings-robot-hard = difficile
+settings-games = au meilleur des { $count }
+settings-renege-reject = refusées
+settings-renege-defer = permises
+settings-rotation-clockwise = à gauche
+settings-rotation-random = au hasard
+settings-rotation-fixed = fixe
+settings-rules-hint = Les règles valent dès la prochaine partie.
+settings-hint = ↵ pour changer, Échap pour enregistrer et revenir
+settings-invalid = Réglages non enregistrés : { $error }
+save-title = Sauvegarder la partie
+save-file = Fichier
+save-notes = Notes
+save-keys = ↵ sauvegarder · ⇥ champ suivant · échap annuler
+summary-games = Parties { $ns }-{ $ew }
+summary-takes-it = { $games } ; { $team } l'emporte.
+summary-next-game = { $games } ; une touche pour continuer.
+summary-on-time = { $team } gagnent au temps ({ $seat }).
+review-end-of-round = Fin de la donne ({ $value })
+remote-disconnected = Déconnecté de l'hôte. Reconnexion...
+remote-waiting-for-players = En attente des joueurs...
+remote-table = Table : { $name }
+remote-seat-open = libre (robot)
+remote-seat-robot = robot
+remote-seat-disconnected = déconnecté
+remote-seat-ready = prêt
+remote-seat-not-ready = pas prêt
+remote-seat-yours = { $status } (vous)
+remote-toggle-ready = Appuyez sur r pour vous déclarer prêt.
+remote-waiting-for-game = En attente du début de la partie...
+history-bid-top = enchère 1
+history-bid-other = enchère 2
+history-discard = écart
+history-trick = pli { $trick }
+history-dealt = { $seat } a donné
+history-passed = { $seat } a passé
+history-called = { $seat } a pris à { $suit }
+history-called-alone = { $seat } a pris à { $suit } seul
+history-discarded = { $seat } a écarté { $card }
+history-led = { $seat } a entamé { $card }
+history-followed = { $seat } a fourni { $card }
+history-called-renege = { $seat } a dénoncé une renonce sur { $card }
+history-cursor = (vous êtes ici)
+history-graph-deal = donne
+history-graph-here = ici
+review-thinking = réflexion :
+review-pass = passe
+review-call-alone = { $suit } seul
+review-makers = preneurs
+review-robot = robot :
+stats-title = Statistiques
+stats-deals = Donnes
+stats-call = Prise%
+stats-euchre = Euch%
+stats-alone = Seul%
+stats-points = Pts/dn
+archive-title = Parties terminées
+archive-rounds = { $rounds ->
+    [one] 1 donne
+   *[other] { $rounds } donnes
+}
+picker-title = Charger une partie
+chat-title = Discussion
+start-summary = N/S { $ns }, E/W { $ew } après { $rounds ->
+    [one] 1 donne
+   *[other] { $rounds } donnes
+}
+
+## Score sheet
+
+sheet-title = Feuille de marque
+sheet-empty = Aucune donne n'a encore été jouée.
+
+## Résultats de la donne
+
+results-title = Fin de la donne
+results-euchred = { $makers } euchrés ! { $team } marquent { $points } points.
+results-points = Points
+results-continue = Appuyez sur une touche pour continuer.
+
+## Tutoriel
+
+tutorial-title = Tutoriel
+tutorial-welcome = Bienvenue à l'euchre ! Vous êtes Sud, et Nord est votre partenaire. Une équipe choisit l'atout, et doit ensuite remporter au moins trois des cinq levées. Appuyez sur une touche pour voir votre main.
+tutorial-order-up = La carte retournée est { $card }. Vous pouvez la faire prendre, ce qui fait de { $suit } l'atout, et le donneur la met dans sa main. Le valet d'atout est la plus forte carte, puis l'autre valet de la même couleur. Avec les deux en main, faites-la prendre !
+tutorial-name-trump = Tout le monde a passé, donc { $suit } est retournée. Vous pouvez maintenant nommer une autre couleur comme atout, ou passer.
+tutorial-discard = En tant que donneur, vous avez pris la carte retournée. Écartez une carte, en gardant vos atouts et vos as.
+tutorial-lead = À vous d'entamer. { $suit } est l'atout : son valet est la plus forte carte, puis l'autre valet de la même couleur, puis l'as, le roi, la dame, le dix et le neuf d'atout. Entamer atout fait tomber les atouts adverses.
+tutorial-follow = { $suit } a été entamé. Vous devez fournir si vous le pouvez ; sinon, jouez n'importe quelle carte, et un atout remporte la levée. L'autre valet de la couleur de l'atout compte comme un atout, et non dans sa couleur imprimée.
+tutorial-scoring = La donne est terminée. Choisir l'atout rapporte 1 point pour trois ou quatre levées, 2 pour les cinq, et 4 pour les cinq en solitaire. Avec moins de trois levées, vous êtes euchrés : l'autre équipe marque 2 points. Les parties se jouent en 10 points.
+
+## Évaluation de la main
+
+trainer-title = Force de la main
+trainer-question = Quelle est la force de votre main à { $suit } ?
+trainer-weak = faible
+trainer-borderline = limite
+trainer-call = de quoi prendre
+trainer-alone = de quoi jouer seul
+trainer-reveal = Force à { $suit } : { $strength }, { $bucket }
+trainer-correct = Bien vu !
+trainer-incorrect = Vous aviez répondu { $guess }.
+
+## Exercices
+
+drill-header = Exercice { $n } sur { $count } ({ $kind })
+drill-position = Vous êtes { $seat }, en position { $position }. { $dealer } a distribué et retourné { $card }.
+drill-illegal = { $action } n'est pas permis ici, réessayez.
+drill-correct = Correct !
+drill-best = Le meilleur choix était { $action }, qui rapporte { $loss } points de plus en moyenne.
+drill-tally = { $correct }/{ $attempts } justes
+drill-tally-accuracy = { $correct }/{ $attempts } justes ({ $accuracy } %), { $loss } points perdus par décision
+drill-session = Cette séance : { $tally }
+drill-all-time = Au total : { $tally }
+drill-recent = Séances récentes : { $sessions }
+
+## Rapports
+
+report-seat = Place
+report-euchre = Euchre%
+report-logs = { $logs } journaux, { $unfinished } inachevés
+report-rounds-won = Donnes gagnées : { $wins }
+report-in-progress = En cours, après { $rounds ->
+        [one] { $rounds } donne
+       *[other] { $rounds } donnes
+    }.
+report-deal = Donne
+report-dealt = { $dealer } a distribué et retourné { $card }.
+report-called = { $seat } a pris à { $suit }.
+report-called-alone = { $seat } a pris à { $suit }, seul.
+report-tricks = Plis
+report-bidding-accuracy = Précision des enchères
+report-position = Position
+report-position-name = { $position ->
+        [0] 1er
+        [1] 2e
+        [2] 3e
+       *[3] Donneur
+    }
+report-strength = Force
+report-passes = Passes
+report-pass-ok = Passe ok
+report-calls = Prises
+report-call-ok = Prise ok
+report-lead = Entame
+report-trick-number = Pli { $n }
+report-leads-by-role = Entames par rôle
+report-leads-by-trick = Entames par pli
+report-type = Type
+report-action = Action
+report-value = Valeur
+report-best-other = Meilleure autre
+report-think = Réflexion
+report-blunders = { $blunders ->
+        [one] { $blunders } gaffe
+       *[other] { $blunders } gaffes
+    }, coûtant au moins { $points } points chacune
+report-think-time = { $seconds } s par décision
+report-board = Donne
+report-board-dealt = Distribuée
+report-board-rotated = Tournée
+report-net = Net N/S
+report-total = Total
+report-bidding-analysis = Analyse des enchères
+report-bids-in-logs = { $bids } enchères dans { $logs } journaux.
+report-lead-analysis = Analyse des entames
+report-leads-in-logs = { $leads } entames dans { $logs } journaux.
+report-puzzles = { $total } problèmes trouvés dans { $logs } journaux : { $tally }
+report-euchre-round = Donne d'euchre
+report-wrote = Écrit : { $path }
+report-searched = Nord et Sud ont cherché ; Est et Ouest ont joué par heuristiques.
+report-your-table = Votre table :
+report-robot-table = Table des robots :
+report-practice = Vous avez fini à { $diff } points face aux robots, avec les mêmes cartes.
+report-robots = Robots : { $robots }.
+lead-right-bower = Valet d'atout
+lead-left-bower = Valet de couleur
+lead-high-trump = Gros atout
+lead-low-trump = Petit atout
+lead-side-ace = As hors atout
+lead-side-king = Roi hors atout
+lead-low-side = Petite hors atout
+lead-maker = Preneur
+lead-partner = Partenaire
+lead-defender = Défenseur
+paths-config = Configuration
+paths-data = Données
+paths-autosaves = Sauvegardes auto
+paths-archive = Archives
+paths-robot = Robot
+paths-settings = Réglages
+paths-stats = Statistiques
+paths-achievements = Succès
+paths-drills = Exercices
+paths-recent-files = Fichiers récents
+
+## Autres jeux
+
+engine-player = Joueur { $n }
+engine-player-abbr = J{ $n }
+engine-cant-do = Impossible de jouer « { $input } » maintenant ; tapez ? pour voir les options
+engine-input-closed = entrée fermée
+engine-hint-wait = q : quitter
+engine-hint-play = ↑↓ : choisir, entrée : jouer, q : quitter
+engine-hint-pause = { $message } (q : quitter)
+engine-log = Journal
+engine-next-round = Appuyez sur une touche pour distribuer la donne suivante
+engine-next-game = Appuyez sur une touche pour distribuer la partie suivante
+
+game-you-are = Vous êtes { $player }
+game-you-are-help = Vous êtes { $player } ; tapez ? pour voir vos options
+game-hand = Main : { $cards }
+game-trick = Pli : { $trick }
+game-trick-won = Pli : { $trick } -> { $winner }
+game-table = Table : { $cards }
+game-trump = Atout : { $trump }
+game-led = Entame : { $card }
+game-auction = Enchères : { $calls }
+game-call = { $player } : { $call }
+game-view = { $player } (donneur { $dealer })
+game-score = Score : { $score }
+game-wins = { $player } gagne
+game-team-wins = { $team } gagnent
+game-upcard = Carte retournée : { $card }
+game-stock-pile = Talon : { $stock }, défausse : { $pile }
+game-cards-held = Cartes en main : { $sizes }
+game-action-draw = Piocher
+game-action-take = Prendre { $cards }
+game-action-meld = Poser { $cards }
+game-drew = { $player } a pioché
+game-took = { $player } a pris { $cards }
+game-melded = { $player } a posé { $meld }
+game-discarded = { $player } a défaussé { $card }
+game-played = { $player } a joué { $card }
+game-went-out = { $player } est sorti
+game-stock-ran-out = Le talon est épuisé
+game-passed = { $player } a passé
+game-player-you = { $player } (vous)
+
+bridge-no-trump = SA
+bridge-contract = { $bid } par { $declarer }
+bridge-contract-line = Contrat : { $contract }
+bridge-passed-out = Donne passée
+bridge-made = { $contract } réussi
+bridge-made-over = { $contract } réussi, plus { $over }
+bridge-down = { $contract } chuté de { $under }
+bridge-dummy = Mort ({ $seat }) : { $hand }
+bridge-view = { $seat } (donneur { $dealer }, vulnérable : { $vulnerable })
+bridge-vulnerable-none = personne
+bridge-vulnerable-both = tous
+bridge-playing-from = On joue de la main de { $seat }
+bridge-games = Manches
+bridge-partial = Partielle
+bridge-total = Total
+bridge-rubber-won = { $team } gagnent le robre
+
+canasta-natural = canasta naturelle
+canasta-mixed = canasta mixte
+canasta-action-take = Prendre
+canasta-action-withdraw = Reprendre
+canasta-team-score = { $total } (combinaisons { $melds }, canastas { $canastas }, trois rouges { $red_threes }, sortie { $going_out }, en main { $hands })
+canasta-red-three = { $player } a posé { $card } de côté
+canasta-took-pile = { $player } a pris la défausse { $count ->
+        [one] de { $count } carte
+       *[other] de { $count } cartes
+    }, avec { $card } dessus
+canasta-withdrew = { $player } a repris ses combinaisons
+canasta-no-melds = aucune combinaison ; minimum { $minimum }
+canasta-on-top = avec { $card } dessus
+canasta-frozen = gelée
+
+rummy-action-lay-off = Ajouter { $card } à { $meld }
+rummy-player-score = { $total } (combinaisons { $melds }, en main { $hand })
+rummy-laid-off = { $player } a ajouté { $card }, pour faire { $meld }
+rummy-must-play = Vous devez jouer { $card }
+
+skat-clubs = Trèfle
+skat-spades = Pique
+skat-hearts = Cœur
+skat-diamonds = Carreau
+skat-grand = Grand
+skat-null = Nul
+skat-hand = main
+skat-ouvert = ouvert
+skat-schwarz = schwarz
+skat-schneider = schneider
+skat-hold = Je tiens
+skat-won = gagné
+skat-lost = perdu
+skat-overbid = enchère trop haute
+skat-action-pick-up = Prendre le skat
+skat-action-bury = Écarter { $card }
+skat-passed-in = Donne passée
+skat-result = { $declarer } a joué { $contract } à { $bid } avec { $matadors } matadors, en faisant { $points } points
+skat-result-null = { $declarer } a joué { $contract } à { $bid }, en faisant { $tricks } plis
+skat-won-auction = { $player } a remporté les enchères à { $bid }
+skat-picked-up = { $player } a pris le skat
+skat-declared = { $player } a annoncé { $contract }
+skat-shows = { $player } montre { $cards }
+skat-declarer = Déclarant : { $declarer } à { $bid }
+skat-declarer-plays = Déclarant : { $declarer } joue { $contract } à { $bid }
+skat-skat = Skat : { $cards }
+skat-open = Jeu ouvert ({ $declarer }) : { $cards }
+
+durak-title = Durak
+durak-action-attack = Attaquer avec { $cards }
+durak-action-beat = Battre { $attack } avec { $defense }
+durak-action-take = Ramasser
+durak-is-durak = { $player } est le durak
+durak-nobody = Personne n'est le durak
+durak-attacked = { $player } a attaqué avec { $cards }
+durak-beat = { $player } a battu { $attack } avec { $defense }
+durak-will-take = { $player } va ramasser
+durak-beat-off = { $player } a repoussé l'attaque
+durak-drew = { $player } a pioché { $count }
+durak-out = { $player } est sorti
+durak-view = { $player } : { $attacker } attaque { $defender }
+durak-status = Atout : { $card }, talon : { $stock }, défausse : { $discards }
+durak-tui-status = Atout : { $card }  Talon : { $stock }  Défausse : { $discards }
+durak-player-status = { $player } : { $cards } cartes, { $losses } défaites
+durak-role-out = sorti
+durak-role-attacking = attaque
+durak-role-defending = défend
+durak-table-title = Table
+durak-table-taking = Table : { $player } va ramasser
+durak-hint-over = n : nouvelle partie, q : quitter
+
+scopa-action-take = { $card } prend { $capture }
+scopa-player-points = { $player } : { $points ->
+        [one] { $points } point
+       *[other] { $points } points
+    } ({ $tally })
+scopa-dealt = Nouvelles mains distribuées, { $left } cartes restantes
+scopa-captured = { $player } a joué { $card }, prenant { $capture }
+scopa-scopa = Scopa pour { $player } !
+scopa-took-rest = { $player } a pris le reste : { $cards }
+scopa-captures = Prises : { $captured } ; scopas : { $scopas }
+scopa-deck = Talon : { $stock }
+scopa-tally = cartes { $cards }, deniers { $coins }, primiera { $primiera }, scopas { $scopas }
+scopa-settebello = settebello
+
+briscola-wins = { $player } gagne, { $points }
+briscola-drawn = Partie nulle, { $points }
+briscola-is = La briscola est { $card }
+briscola-trick = { $player } a remporté le pli, { $points ->
+        [one] { $points } point
+       *[other] { $points } points
+    }
+briscola-status = Briscola : { $card } ; talon : { $stock }
+briscola-points = Points : { $points }
+briscola-games-won = Parties gagnées : { $first }-{ $second }
+
+ninety-nine-action-bid = Annoncer avec { $card }
+ninety-nine-result = { $player } a annoncé { $bid }, a fait { $tricks } : { $points ->
+        [one] { $points } point
+       *[other] { $points } points
+    }
+ninety-nine-has-bid = { $player } a annoncé
+ninety-nine-bid = Annonce : { $cards }
+ninety-nine-tricks = Plis : { $taken }
+
+nap-nap = Nap
+nap-bid = Annonce de { $tricks }
+nap-thrown-in = Donne annulée
+nap-made = { $player } a réussi { $bid }, en faisant { $tricks } plis : { $score }
+nap-failed = { $player } a chuté { $bid }, en faisant { $tricks } plis : { $score }
+nap-won-bidding = { $player } a remporté les enchères : { $bid }
+nap-bidding = Enchères : { $calls }
+nap-contract = { $player } : { $bid }, { $taken } plis faits
+
+bezique-seven = Sept
+bezique-marriage = Mariage { $suit }
+bezique-bezique = Bésigue
+bezique-four = Quatre { $rank ->
+        [ace] as
+        [king] rois
+        [queen] dames
+       *[jack] valets
+    }
+bezique-sequence = Séquence
+bezique-double-bezique = Double bésigue
+bezique-action-exchange = Échanger
+bezique-player-points = { $player } : { $points } (annonces { $melds }, brisques { $brisques }{ $last ->
+        [yes] , dernier pli 10
+       *[no] {""}
+    })
+bezique-turned-up-seven = Retourné { $card } : 10 pour le donneur
+bezique-turned-up = Retourné { $card }
+bezique-declared = { $player } a annoncé { $meld } pour { $points }
+bezique-exchanged = { $player } a échangé le sept d'atout contre 10
+bezique-endgame = Le talon est épuisé
+bezique-trump-stock = Atout : { $card } ; talon : { $stock }
+bezique-melds = Annonces : { $melds }
+bezique-table = Table ({ $player }) : { $cards }
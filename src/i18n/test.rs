@@ -0,0 +1,76 @@
+use super::*;
+
+/// Returns the IDs of the messages in the language's resource.
+fn message_ids(lang: Lang) -> Vec<&'static str> {
+    lang.source()
+        .lines()
+        .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+        .filter_map(|line| line.split_once('='))
+        .map(|(id, _)| id.trim())
+        .collect()
+}
+
+#[test]
+fn test_lang_from_str() {
+    assert_eq!("en".parse(), Ok(Lang::English));
+    assert_eq!("en-US".parse(), Ok(Lang::English));
+    assert_eq!("fr".parse(), Ok(Lang::French));
+    assert_eq!("fr_CA.UTF-8".parse(), Ok(Lang::French));
+    assert_eq!("fr_FR@euro".parse(), Ok(Lang::French));
+    assert_eq!("de".parse::<Lang>(), Err(()));
+    assert_eq!("".parse::<Lang>(), Err(()));
+    for lang in Lang::ALL {
+        assert_eq!(lang.to_string().parse(), Ok(lang));
+    }
+}
+
+#[test]
+fn test_tr_in() {
+    assert_eq!(tr_in(Lang::English, "action-pass", None), "Pass");
+    assert_eq!(tr_in(Lang::French, "action-pass", None), "Passer");
+
+    let mut args = FluentArgs::new();
+    args.set("seat", "North");
+    assert_eq!(
+        tr_in(Lang::English, "narration-trick", Some(&args)),
+        "North takes the trick."
+    );
+}
+
+#[test]
+fn test_tr_in_plural() {
+    let packet = |lang, count: u8| {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        args.set("seat", "North");
+        tr_in(lang, "narration-packet", Some(&args))
+    };
+    assert_eq!(packet(Lang::English, 1), "1 card to North.");
+    assert_eq!(packet(Lang::English, 3), "3 cards to North.");
+    assert_eq!(packet(Lang::French, 1), "1 carte pour North.");
+    assert_eq!(packet(Lang::French, 2), "2 cartes pour North.");
+}
+
+#[test]
+fn test_tr_in_missing() {
+    assert_eq!(
+        tr_in(Lang::French, "no-such-message", None),
+        "no-such-message"
+    );
+}
+
+#[test]
+fn test_resources_complete() {
+    let english = message_ids(Lang::English);
+    assert!(!english.is_empty());
+    for lang in Lang::ALL {
+        let ids = message_ids(lang);
+        for id in &english {
+            assert!(ids.contains(id), "{} is missing {}", lang, id);
+            assert!(bundle(lang).has_message(id), "{} can't parse {}", lang, id);
+        }
+        for id in &ids {
+            assert!(english.contains(id), "{} has extra message {}", lang, id);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! The 40-card Italian deck, for games such as scopa and briscola.
+//!
+//! The deck is the French deck with the eights, nines and tens removed, leaving aces through
+//! sevens and the three face cards in each suit. The face cards stand in for the fante, cavallo
+//! and re of the Italian suits, and take the values 8, 9 and 10 that follow the sevens. Diamonds
+//! stand in for coins.
+
+use itertools::Itertools;
+
+use crate::deck::Deck;
+#[cfg(test)]
+pub(crate) use crate::french::cards;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+
+#[cfg(test)]
+mod test;
+
+/// The suit that stands in for coins, which counts for more in some games.
+pub const COINS: Suit = Suit::Diamond;
+
+/// The 40-card Italian deck.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new()
+        .filter_ranks(|r| r <= Rank::Seven || r >= Rank::Jack)
+        .build()
+}
+
+/// The value of the card, counting up from 1 for an ace to 7 for a seven, and then 8, 9 and 10
+/// for the jack, queen and king.
+pub fn value(card: Card) -> u8 {
+    match card.rank() {
+        Some(Rank::Jack) => 8,
+        Some(Rank::Queen) => 9,
+        Some(Rank::King) => 10,
+        Some(rank) => rank as u8 + 1,
+        None => 0,
+    }
+}
+
+/// Formats cards by suit, and in order of value within each suit.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| (c.suit(), value(c)))
+        .map(|c| c.to_string())
+        .join(" ")
+}
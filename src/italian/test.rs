@@ -0,0 +1,18 @@
+use std::collections::HashSet;
+
+use super::*;
+
+#[test]
+fn test_deck() {
+    let cards: HashSet<Card> = deck().into_iter().collect();
+    assert_eq!(cards.len(), 40);
+    for &suit in Suit::all_suits() {
+        let values: Vec<u8> = cards
+            .iter()
+            .filter(|c| c.suit() == Some(suit))
+            .map(|&c| value(c))
+            .sorted()
+            .collect();
+        assert_eq!(values, (1..=10).collect::<Vec<u8>>());
+    }
+}
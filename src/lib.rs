@@ -0,0 +1,23 @@
+//! Deckard is a card game engine, with a terminal UI, robot players, and a tree-structured log
+//! format for exploring "what if" scenarios.
+
+#![allow(clippy::module_name_repetitions, clippy::struct_field_names)]
+
+pub mod bezique;
+pub mod bridge;
+pub mod briscola;
+pub mod canasta;
+pub mod deck;
+pub mod durak;
+pub mod engine;
+pub mod euchre;
+pub mod french;
+pub mod i18n;
+pub mod italian;
+#[cfg(feature = "tui")]
+pub mod logging;
+pub mod nap;
+pub mod ninety_nine;
+pub mod rummy;
+pub mod scopa;
+pub mod skat;
@@ -0,0 +1,128 @@
+//! Diagnostic logging.
+//!
+//! Robot decisions, network activity, and errors are reported through `tracing`. [`init`]
+//! installs a subscriber that writes them to a log file or to stderr, and keeps the most recent
+//! lines in memory, so that the terminal UI can show them in a debug panel without writing over
+//! the screen.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::{self, MakeWriter};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+#[cfg(test)]
+mod test;
+
+/// The number of recent lines kept in memory.
+pub const RECENT_LINES: usize = 200;
+
+/// The most recent log lines, shared between the subscriber and the UI.
+#[derive(Debug, Clone, Default)]
+pub struct RecentLines(Arc<Mutex<VecDeque<String>>>);
+
+impl RecentLines {
+    /// Returns up to `n` of the most recent lines, oldest first.
+    pub fn tail(&self, n: usize) -> Vec<String> {
+        let lines = self.0.lock().expect("not poisoned");
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(n))
+            .cloned()
+            .collect()
+    }
+
+    /// Appends a line, dropping the oldest once there are more than [`RECENT_LINES`].
+    fn push(&self, line: String) {
+        let mut lines = self.0.lock().expect("not poisoned");
+        if lines.len() == RECENT_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+impl<'a> MakeWriter<'a> for RecentLines {
+    type Writer = RecentWriter;
+
+    fn make_writer(&'a self) -> RecentWriter {
+        RecentWriter {
+            lines: self.clone(),
+            buf: vec![],
+        }
+    }
+}
+
+/// Collects a formatted event, and appends its lines to the recent lines when dropped.
+#[derive(Debug)]
+pub struct RecentWriter {
+    lines: RecentLines,
+    buf: Vec<u8>,
+}
+
+impl Write for RecentWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for RecentWriter {
+    fn drop(&mut self) {
+        for line in String::from_utf8_lossy(&self.buf).lines() {
+            self.lines.push(line.to_string());
+        }
+    }
+}
+
+/// Returns the recent log lines, which stay empty unless [`init`] has been called.
+pub fn recent() -> &'static RecentLines {
+    static RECENT: OnceLock<RecentLines> = OnceLock::new();
+    RECENT.get_or_init(RecentLines::default)
+}
+
+/// Returns the most detailed level to log, given the number of times the verbosity was raised
+/// (`-v`) less the number of times it was lowered (`-q`). Warnings and errors are logged by
+/// default.
+pub fn level(verbosity: i16) -> LevelFilter {
+    match verbosity {
+        i16::MIN..=-2 => LevelFilter::OFF,
+        -1 => LevelFilter::ERROR,
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Installs the global subscriber, which logs events up to the specified level to the recent
+/// lines, and to the log file if one is specified. Otherwise, events are written to stderr, unless
+/// `stderr` is false because the terminal UI has the screen.
+pub fn init(level: LevelFilter, log_file: Option<&Path>, stderr: bool) -> anyhow::Result<()> {
+    let file = log_file
+        .map(File::create)
+        .transpose()?
+        .map(|file| fmt::layer().with_ansi(false).with_writer(Mutex::new(file)));
+    let stderr = (stderr && file.is_none()).then(|| fmt::layer().with_writer(io::stderr));
+    let recent = fmt::layer()
+        .with_ansi(false)
+        .without_time()
+        .with_target(false)
+        .with_writer(recent().clone());
+    tracing_subscriber::registry()
+        .with(level)
+        .with(recent)
+        .with(file)
+        .with(stderr)
+        .try_init()?;
+    Ok(())
+}
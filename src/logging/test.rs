@@ -0,0 +1,38 @@
+use tracing_subscriber::layer::SubscriberExt;
+
+use super::*;
+
+#[test]
+fn test_level() {
+    assert_eq!(level(0), LevelFilter::WARN);
+    assert_eq!(level(2), LevelFilter::DEBUG);
+    assert_eq!(level(9), LevelFilter::TRACE);
+    assert_eq!(level(-1), LevelFilter::ERROR);
+    assert_eq!(level(-5), LevelFilter::OFF);
+}
+
+#[test]
+fn test_recent_lines() {
+    let lines = RecentLines::default();
+    let subscriber = tracing_subscriber::registry().with(LevelFilter::INFO).with(
+        fmt::layer()
+            .with_ansi(false)
+            .without_time()
+            .with_target(false)
+            .with_writer(lines.clone()),
+    );
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!("first");
+        tracing::debug!("hidden");
+        tracing::warn!("second\nthird");
+    });
+    assert_eq!(lines.tail(10), [" INFO first", " WARN second", "third"]);
+    assert_eq!(lines.tail(1), ["third"]);
+
+    for i in 0..RECENT_LINES {
+        lines.push(i.to_string());
+    }
+    let tail = lines.tail(RECENT_LINES + 1);
+    assert_eq!(tail.len(), RECENT_LINES);
+    assert_eq!(tail[0], "0");
+}
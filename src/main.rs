@@ -1,17 +1,197 @@
-#![allow(clippy::module_name_repetitions, clippy::struct_field_names)]
+use std::io::IsTerminal;
 
 use clap::Parser;
 
+use deckard::euchre::net::ChatPolicy;
+use deckard::euchre::{Paths, RecentFiles, RenegePolicy, RobotKind, Rules, Settings};
+use deckard::i18n::{self, Lang};
+use deckard::{
+    bezique, bridge, briscola, canasta, durak, euchre, logging, nap, ninety_nine, rummy, scopa,
+    skat,
+};
+
 mod args;
-mod deck;
-mod euchre;
-mod french;
-use self::args::{Args, Game, Ui};
+use self::args::{Args, Command, Game, Ui};
 
 fn main() {
     let args = Args::parse();
-    match (args.game.unwrap_or_default(), args.ui.unwrap_or_default()) {
+    i18n::set_lang(args.lang.unwrap_or_else(Lang::from_env));
+    if let Err(err) = run(&args) {
+        eprintln!("Error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+/// Runs the subcommand or game selected on the command line.
+fn run(args: &Args) -> anyhow::Result<()> {
+    let verbosity = i16::from(args.verbose) - i16::from(args.quiet);
+    let tui = args.command.is_none()
+        && args.serve.is_none()
+        && args.http.is_none()
+        && matches!(args.ui.clone().unwrap_or_default(), Ui::Tui);
+    logging::init(logging::level(verbosity), args.log_file.as_deref(), !tui)?;
+    match &args.command {
+        Some(Command::Stats { paths, csv, .. }) if !paths.is_empty() => {
+            return euchre::corpus_stats_main(paths, *csv);
+        }
+        Some(Command::Stats { file, .. }) => return euchre::stats_main(file.as_deref()),
+        Some(Command::Bidding {
+            paths,
+            rollouts,
+            csv,
+            html,
+        }) => return euchre::bidding_main(paths, *rollouts, *csv, html.as_deref()),
+        Some(Command::Leads { paths, html }) => return euchre::leads_main(paths, html.as_deref()),
+        Some(Command::Duplicate {
+            boards,
+            deals,
+            practice,
+            search,
+        }) => {
+            return euchre::duplicate_main(
+                *boards,
+                deals.as_deref(),
+                *practice,
+                *search,
+                &args.robot_kinds(RobotKind::default()),
+            );
+        }
+        Some(Command::Train {
+            iterations,
+            boards,
+            seed,
+            output,
+        }) => {
+            return euchre::train_main(*iterations, *boards, *seed, output.as_deref());
+        }
+        Some(Command::Bench {
+            rounds,
+            searches,
+            seed,
+        }) => return euchre::bench_main(*rounds, *searches, *seed),
+        Some(Command::LogStats { paths }) => return euchre::log_stats_main(paths),
+        Some(Command::Analyze { path, depth }) => return euchre::analyze_main(path, *depth),
+        Some(Command::Puzzles {
+            paths,
+            output,
+            difficulty,
+        }) => return euchre::puzzles_main(paths, output, *difficulty),
+        Some(Command::Drill {
+            kind,
+            position,
+            count,
+            depth,
+        }) => return euchre::drill_main(*kind, *position, *count, *depth),
+        Some(Command::ScoreSheet {
+            path,
+            format,
+            output,
+        }) => return euchre::score_sheet_main(path, *format, output.as_deref()),
+        Some(Command::Diary { path, seat, output }) => {
+            return euchre::diary_main(path, *seat, output.as_deref());
+        }
+        Some(Command::Export { path, output }) => {
+            return euchre::export_main(path, output.as_deref());
+        }
+        Some(Command::Deal {
+            game,
+            seed,
+            dealer,
+            seat,
+        }) => {
+            return match game.clone().unwrap_or_default() {
+                Game::Euchre => euchre::deal_main(*seed, *dealer, *seat),
+                _ => Err(anyhow::anyhow!("only euchre deals are supported")),
+            };
+        }
+        Some(Command::Paths) => return euchre::paths_main(),
+        Some(Command::Show {
+            path,
+            no_color,
+            dot,
+        }) => {
+            let color = !no_color
+                && std::env::var_os("NO_COLOR").is_none()
+                && std::io::stdout().is_terminal();
+            return euchre::show_main(path, color, *dot);
+        }
+        None => (),
+    }
+    let load = match args.load_recent {
+        Some(n) => {
+            let recent = RecentFiles::load(&Paths::new().recent_files())?;
+            let Some(path) = recent.get(n) else {
+                anyhow::bail!("no recent file #{n}");
+            };
+            Some(path.to_path_buf())
+        }
+        None => args.load.clone(),
+    };
+    match (
+        args.game.clone().unwrap_or_default(),
+        args.ui.clone().unwrap_or_default(),
+    ) {
+        (Game::Euchre, _) if args.serve.is_some() => {
+            let chat = if args.quiet_play {
+                ChatPolicy::QuietDuringPlay
+            } else {
+                ChatPolicy::Open
+            };
+            euchre::serve_main(args.serve.as_deref().unwrap(), chat, args.time_control)
+        }
+        (Game::Euchre, _) if args.http.is_some() => {
+            euchre::http_main(args.http.as_deref().unwrap())
+        }
+        (Game::Euchre, _) if args.connect.is_some() => euchre::remote_tui_main(
+            args.connect.as_deref().unwrap(),
+            args.table.as_deref(),
+            args.seat,
+            args.spectate,
+        ),
         (Game::Euchre, Ui::Cli) => euchre::cli_main(),
-        (Game::Euchre, Ui::Tui) => euchre::tui_main(args.load.as_deref()),
+        (Game::Bridge, _) => bridge::cli_main(),
+        (Game::Canasta, _) => canasta::cli_main(),
+        (Game::Rummy, _) => rummy::cli_main(),
+        (Game::Skat, _) => skat::cli_main(),
+        (Game::Durak, Ui::Tui) => durak::tui_main(),
+        (Game::Durak, _) => durak::cli_main(),
+        (Game::Scopa, Ui::Tui) => scopa::tui_main(),
+        (Game::Scopa, _) => scopa::cli_main(),
+        (Game::Briscola, Ui::Tui) => briscola::tui_main(),
+        (Game::Briscola, _) => briscola::cli_main(),
+        (Game::NinetyNine, _) => ninety_nine::cli_main(),
+        (Game::Nap, _) => nap::cli_main(),
+        (Game::Bezique, _) => bezique::cli_main(),
+        #[cfg(feature = "gui")]
+        (Game::Euchre, Ui::Gui) => euchre::gui_main(load.as_deref()),
+        (Game::Euchre, Ui::Tui) => {
+            // The command line overrides the settings, which the terminal UI reports if invalid.
+            let settings = Settings::load(&Paths::new().settings()).unwrap_or_default();
+            let renege = if args.renege {
+                RenegePolicy::Defer
+            } else {
+                settings.rules.renege
+            };
+            let rules = Rules {
+                target_score: args.points.unwrap_or(settings.rules.target_score),
+                best_of: args.best_of.unwrap_or(settings.rules.best_of),
+                renege,
+                competitive: args.competitive || settings.rules.competitive,
+                time_control: args.time_control,
+                rotation: args.rotation.unwrap_or(settings.rules.rotation),
+            };
+            let robot_kinds = args.robot_kinds(settings.robot);
+            euchre::tui_main(
+                load.as_deref(),
+                args.review,
+                rules,
+                &args.preset,
+                &robot_kinds,
+                args.trace,
+                args.robot_lines,
+                args.paranoid,
+                args.tutorial,
+            )
+        }
     }
 }
@@ -0,0 +1,94 @@
+//! Nap, or Napoleon.
+//!
+//! Nap is played by four players with a 52-card deck, and each is dealt five cards. Starting with
+//! the player to the dealer's left, each has one chance to bid the number of tricks they'll take,
+//! from two up to all five, which is called nap, or to pass. Each bid must be higher than the
+//! last. If everyone passes, the deal is thrown in.
+//!
+//! The bidder leads to the first trick, and the suit of the card they lead is trump. Players must
+//! follow suit if they can; the highest trump or else the highest card of the suit led wins the
+//! trick, and its winner leads to the next. If the bidder takes as many tricks as they bid, each
+//! of the others pays them that many points, or ten for nap; if not, they pay each of the others
+//! that many, or five for nap.
+
+mod deal;
+mod error;
+mod robot;
+
+use itertools::Itertools;
+
+pub use self::deal::{
+    Action, Call, Deal, DealConfig, DealResult, DealView, Event, Phase, RawDeal, Trick,
+};
+pub use self::error::NapError;
+pub use self::robot::Robot;
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The number of players.
+pub const PLAYERS: u8 = 4;
+
+/// The number of cards dealt to each player, and so the number of tricks.
+pub const HAND_SIZE: usize = 5;
+
+/// The lowest bid.
+pub const MIN_BID: u8 = 2;
+
+/// The number of deals in a game, so that each player deals three times.
+pub const DEALS: usize = 12;
+
+/// The 52-card deck.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new().build()
+}
+
+/// The rank of a card, from 0 for a two up to 12 for an ace.
+pub fn rank_order(card: Card) -> u8 {
+    card.rank().map_or(0, Rank::ace_high)
+}
+
+/// Formats cards by suit, from highest to lowest.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| deal::sort_key(c))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Plays a game of twelve deals with a simple command-line interface, with the user as the first
+/// of four players and robots as the others. The record of every deal is written to stderr when
+/// the game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = [0; PLAYERS as usize];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let mut deals = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    for _ in 0..DEALS {
+        let config = DealConfig::random(dealer, &mut rng);
+        let mut deal = Deal::from(config);
+        let result = console
+            .play(&mut deal, me, |deal, player, legal| {
+                robot.choose(&deal.view(player), &legal)
+            })?
+            .expect("deal is over");
+        for player in Player::all(PLAYERS) {
+            scores[player.index()] += result.score(player);
+        }
+        deals.push(RawDeal::from(&deal));
+        let totals = Player::all(PLAYERS)
+            .map(|p| format!("{p} {}", scores[p.index()]))
+            .join(", ");
+        console.println(tr!("game-score", score = totals))?;
+        dealer = dealer.next(PLAYERS);
+    }
+    serde_json::to_writer(std::io::stderr(), &deals)?;
+    Ok(())
+}
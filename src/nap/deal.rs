@@ -0,0 +1,687 @@
+//! A single deal: the bidding, and the play of the tricks.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    deck, format_cards, rank_order, Card, NapError, Player, Suit, HAND_SIZE, MIN_BID, PLAYERS,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Sorts cards for display: by suit, and then from highest to lowest.
+pub(crate) fn sort_key(card: Card) -> (Option<Suit>, Reverse<u8>) {
+    (card.suit(), Reverse(rank_order(card)))
+}
+
+/// The cards dealt to each player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealConfig {
+    dealer: Player,
+    hands: Vec<Vec<Card>>,
+}
+
+impl DealConfig {
+    /// Deals a shuffled deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        let hands = (0..PLAYERS).map(|_| deck.take(HAND_SIZE)).collect();
+        Self { dealer, hands }
+    }
+
+    /// Creates a deal from the specified hands, indexed by player.
+    pub fn from_hands(dealer: Player, hands: Vec<Vec<Card>>) -> Result<Self, NapError> {
+        let config = Self { dealer, hands };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that each player is dealt five cards, and no card is dealt twice.
+    pub fn validate(&self) -> Result<(), NapError> {
+        if self.dealer.0 >= PLAYERS
+            || self.hands.len() != usize::from(PLAYERS)
+            || self.hands.iter().any(|h| h.len() != HAND_SIZE)
+        {
+            return Err(NapError::InvalidHandSize);
+        }
+        let mut seen = vec![];
+        for &card in self.hands.iter().flatten() {
+            if seen.contains(&card) || card.suit().is_none() {
+                return Err(NapError::InvalidCard(card));
+            }
+            seen.push(card);
+        }
+        Ok(())
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The cards dealt to the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+}
+
+/// A call during the bidding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Call {
+    /// Bids to take the number of tricks.
+    Bid(u8),
+    /// Declines to bid.
+    Pass,
+}
+
+impl Display for Call {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Call::Bid(tricks) if usize::from(*tricks) == HAND_SIZE => f.write_str(&tr!("nap-nap")),
+            Call::Bid(tricks) => f.write_str(&tr!("nap-bid", tricks = *tricks)),
+            Call::Pass => f.write_str(&tr!("action-pass")),
+        }
+    }
+}
+
+/// The stage of the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// The players are bidding.
+    Bid,
+    /// The cards are being played.
+    Play,
+    /// The deal is over.
+    Over,
+}
+
+/// An action taken during the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// A call during the bidding.
+    Call(Call),
+    /// A card played to a trick.
+    Play(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Call(call) => write!(f, "{call}"),
+            Action::Play(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A trick.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trick {
+    /// The trump suit.
+    pub trump: Suit,
+    /// The cards played so far, in order.
+    pub cards: Vec<(Player, Card)>,
+}
+
+impl Trick {
+    /// Creates an empty trick.
+    pub fn new(trump: Suit) -> Self {
+        Self {
+            trump,
+            cards: vec![],
+        }
+    }
+
+    /// The suit that must be followed, or `None` for an empty trick.
+    pub fn lead_suit(&self) -> Option<Suit> {
+        self.cards.first().and_then(|&(_, card)| card.suit())
+    }
+
+    /// Returns true once every player has played.
+    pub fn is_complete(&self) -> bool {
+        self.cards.len() == usize::from(PLAYERS)
+    }
+
+    /// The card winning the trick so far: the highest trump, or else the highest card of the
+    /// suit led.
+    pub fn winner(&self) -> Option<(Player, Card)> {
+        let lead = self.lead_suit()?;
+        self.cards.iter().copied().max_by_key(|&(_, card)| {
+            let class = match card.suit() {
+                Some(suit) if suit == self.trump => 2,
+                Some(suit) if suit == lead => 1,
+                _ => 0,
+            };
+            (class, rank_order(card))
+        })
+    }
+
+    /// Filters the hand down to the cards that may be played to this trick.
+    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
+        let Some(lead) = self.lead_suit() else {
+            return hand.to_vec();
+        };
+        let following: Vec<Card> = hand
+            .iter()
+            .copied()
+            .filter(|&c| c.suit() == Some(lead))
+            .collect();
+        if following.is_empty() {
+            hand.to_vec()
+        } else {
+            following
+        }
+    }
+}
+
+impl Display for Trick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, (player, card)) in self.cards.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}:{card}", tr!("engine-player-abbr", n = player.0 + 1))?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The result of a deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealResult {
+    /// The bidder and their bid, or `None` if the deal was thrown in.
+    pub contract: Option<(Player, u8)>,
+    /// The number of tricks taken by the bidder.
+    pub tricks: u8,
+}
+
+impl DealResult {
+    /// Returns true if the bidder took as many tricks as they bid.
+    pub fn made(&self) -> bool {
+        self.contract.is_some_and(|(_, bid)| self.tricks >= bid)
+    }
+
+    /// The points the bidder wins from, or pays to, each of the other players.
+    pub fn stake(&self) -> i32 {
+        match self.contract {
+            None => 0,
+            Some((_, bid)) if usize::from(bid) == HAND_SIZE => {
+                if self.made() {
+                    10
+                } else {
+                    5
+                }
+            }
+            Some((_, bid)) => i32::from(bid),
+        }
+    }
+
+    /// The points scored by the player.
+    pub fn score(&self, player: Player) -> i32 {
+        let Some((bidder, _)) = self.contract else {
+            return 0;
+        };
+        let stake = if self.made() {
+            self.stake()
+        } else {
+            -self.stake()
+        };
+        if player == bidder {
+            stake * (i32::from(PLAYERS) - 1)
+        } else {
+            -stake
+        }
+    }
+}
+
+impl Display for DealResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((bidder, bid)) = self.contract else {
+            return f.write_str(&tr!("nap-thrown-in"));
+        };
+        let (player, bid) = (bidder.to_string(), Call::Bid(bid).to_string());
+        let (tricks, score) = (self.tricks, self.score(bidder));
+        let text = if self.made() {
+            tr!(
+                "nap-made",
+                player = player,
+                bid = bid,
+                tricks = tricks,
+                score = score
+            )
+        } else {
+            tr!(
+                "nap-failed",
+                player = player,
+                bid = bid,
+                tricks = tricks,
+                score = score
+            )
+        };
+        f.write_str(&text)
+    }
+}
+
+/// An event that occurs during the deal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    /// A player made a call.
+    Call(Player, Call),
+    /// The bidding is over, and the player won it with the bid.
+    Bidder(Player, u8),
+    /// The bidder led the first card, fixing the trump suit.
+    Trump(Suit),
+    /// The trick is over.
+    Trick(Trick),
+    /// The deal is over.
+    Over(DealResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Call(player, call) => {
+                let (player, call) = (player.to_string(), call.to_string());
+                f.write_str(&tr!("game-call", player = player, call = call))
+            }
+            Event::Bidder(player, bid) => {
+                let (player, bid) = (player.to_string(), Call::Bid(*bid).to_string());
+                f.write_str(&tr!("nap-won-bidding", player = player, bid = bid))
+            }
+            Event::Trump(suit) => f.write_str(&tr!("game-trump", trump = suit.to_string())),
+            Event::Trick(trick) => {
+                let winner = trick.winner().expect("trick is complete").0;
+                let (trick, winner) = (trick.to_string(), winner.to_string());
+                f.write_str(&tr!("game-trick-won", trick = trick, winner = winner))
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of a deal, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct DealView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The player's remaining cards.
+    pub hand: &'a [Card],
+    /// The calls made so far, starting with the player to the dealer's left.
+    pub calls: &'a [Call],
+    /// The bidder and their bid, once the bidding is over.
+    pub contract: Option<(Player, u8)>,
+    /// The tricks played so far. The last may be incomplete.
+    pub tricks: &'a [Trick],
+    /// The stage of the deal.
+    pub phase: Phase,
+    /// The player expected to act next.
+    pub to_play: Option<Player>,
+}
+
+impl DealView<'_> {
+    /// The trump suit, once the first card is led.
+    pub fn trump(&self) -> Option<Suit> {
+        self.tricks.first().map(|t| t.trump)
+    }
+
+    /// The highest bid so far.
+    pub fn high_bid(&self) -> Option<u8> {
+        high_bid(self.calls)
+    }
+
+    /// The trick in progress, if any cards have been played to it.
+    pub fn current_trick(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|t| !t.is_complete())
+    }
+
+    /// The number of tricks the player has taken so far.
+    pub fn taken(&self, player: Player) -> u8 {
+        taken(self.tricks, player)
+    }
+}
+
+impl Display for DealView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        match self.contract {
+            None if !self.calls.is_empty() => {
+                let first = self.dealer.next(PLAYERS);
+                let calls = self
+                    .calls
+                    .iter()
+                    .zip(std::iter::successors(Some(first), |p| {
+                        Some(p.next(PLAYERS))
+                    }))
+                    .map(|(call, player)| {
+                        format!("{}:{call}", tr!("engine-player-abbr", n = player.0 + 1))
+                    })
+                    .join(" ");
+                writeln!(f, "{}", tr!("nap-bidding", calls = calls))?;
+            }
+            None => (),
+            Some((bidder, bid)) => {
+                let taken = self.taken(bidder);
+                let (bidder, bid) = (bidder.to_string(), Call::Bid(bid).to_string());
+                let text = tr!("nap-contract", player = bidder, bid = bid, taken = taken);
+                writeln!(f, "{text}")?;
+            }
+        }
+        if let Some(trump) = self.trump() {
+            writeln!(f, "{}", tr!("game-trump", trump = trump.to_string()))?;
+        }
+        if let Some(trick) = self.current_trick() {
+            writeln!(f, "{}", tr!("game-trick", trick = trick.to_string()))?;
+        }
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The highest bid among the calls.
+fn high_bid(calls: &[Call]) -> Option<u8> {
+    calls
+        .iter()
+        .filter_map(|c| match c {
+            Call::Bid(tricks) => Some(*tricks),
+            Call::Pass => None,
+        })
+        .max()
+}
+
+/// The number of completed tricks won by the player.
+fn taken(tricks: &[Trick], player: Player) -> u8 {
+    let taken = tricks
+        .iter()
+        .filter(|t| t.is_complete() && t.winner().map(|(p, _)| p) == Some(player))
+        .count();
+    u8::try_from(taken).expect("at most 5 tricks")
+}
+
+/// The state of a deal.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    config: DealConfig,
+    hands: Vec<Vec<Card>>,
+    calls: Vec<Call>,
+    tricks: Vec<Trick>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<DealConfig> for Deal {
+    fn from(config: DealConfig) -> Self {
+        Self {
+            hands: config.hands.clone(),
+            calls: vec![],
+            tricks: vec![],
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        }
+    }
+}
+
+impl Deal {
+    /// The deal as it was dealt.
+    pub fn config(&self) -> &DealConfig {
+        &self.config
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The tricks played so far. The last may be incomplete.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The bidder and their bid, once the bidding is over.
+    pub fn contract(&self) -> Option<(Player, u8)> {
+        if self.calls.len() < usize::from(PLAYERS) {
+            return None;
+        }
+        let first = self.config.dealer.next(PLAYERS);
+        let bid = high_bid(&self.calls)?;
+        let position = self.calls.iter().position(|&c| c == Call::Bid(bid))?;
+        let bidder = (0..position).fold(first, |p, _| p.next(PLAYERS));
+        Some((bidder, bid))
+    }
+
+    /// The stage of the deal.
+    pub fn phase(&self) -> Phase {
+        if self.calls.len() < usize::from(PLAYERS) {
+            Phase::Bid
+        } else if self.contract().is_none()
+            || self.tricks.iter().filter(|t| t.is_complete()).count() == HAND_SIZE
+        {
+            Phase::Over
+        } else {
+            Phase::Play
+        }
+    }
+
+    /// The player whose card is to be played next, or `None` if the play hasn't started or is
+    /// over.
+    pub fn to_play(&self) -> Option<Player> {
+        if self.phase() != Phase::Play {
+            return None;
+        }
+        match self.tricks.last() {
+            None => self.contract().map(|(bidder, _)| bidder),
+            Some(trick) if !trick.is_complete() => Some(
+                trick
+                    .cards
+                    .last()
+                    .expect("trick is started")
+                    .0
+                    .next(PLAYERS),
+            ),
+            Some(trick) => Some(trick.winner().expect("trick is complete").0),
+        }
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        match self.phase() {
+            Phase::Bid => {
+                let first = self.config.dealer.next(PLAYERS);
+                Some((0..self.calls.len()).fold(first, |p, _| p.next(PLAYERS)))
+            }
+            Phase::Play => self.to_play(),
+            Phase::Over => None,
+        }
+    }
+
+    /// Returns the state of the deal, as visible to the player.
+    pub fn view(&self, player: Player) -> DealView<'_> {
+        DealView {
+            player,
+            dealer: self.config.dealer,
+            hand: &self.hands[player.index()],
+            calls: &self.calls,
+            contract: self.contract(),
+            tricks: &self.tricks,
+            phase: self.phase(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// The calls that may be made next: a pass, or any bid higher than the last.
+    fn legal_calls(&self) -> Vec<Call> {
+        let min = high_bid(&self.calls).map_or(MIN_BID, |b| b + 1);
+        let max = u8::try_from(HAND_SIZE).expect("small hand");
+        std::iter::once(Call::Pass)
+            .chain((min..=max).map(Call::Bid))
+            .collect()
+    }
+
+    /// Returns the actions the next player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.phase() {
+            Phase::Bid => self.legal_calls().into_iter().map(Action::Call).collect(),
+            Phase::Play => {
+                let player = self.to_play().expect("play is in progress");
+                let hand = &self.hands[player.index()];
+                let mut cards = match self.tricks.last().filter(|t| !t.is_complete()) {
+                    Some(trick) => trick.filter(hand),
+                    None => hand.clone(),
+                };
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.into_iter().map(Action::Play).collect()
+            }
+            Phase::Over => vec![],
+        }
+    }
+
+    /// Applies the next player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), NapError> {
+        match (self.phase(), action) {
+            (Phase::Over, _) => return Err(NapError::DealOver),
+            (Phase::Bid, Action::Call(call)) => self.call(call)?,
+            (Phase::Play, Action::Play(card)) => self.play(card)?,
+            _ => return Err(NapError::OutOfTurn(action)),
+        }
+        self.actions.push(action);
+        if let Some(result) = self.result() {
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    fn call(&mut self, call: Call) -> Result<(), NapError> {
+        if !self.legal_calls().contains(&call) {
+            return Err(NapError::IllegalCall(call));
+        }
+        let player = self.next_actor().expect("bidding is in progress");
+        self.calls.push(call);
+        self.events.push_back(Event::Call(player, call));
+        if let Some((bidder, bid)) = self.contract() {
+            self.events.push_back(Event::Bidder(bidder, bid));
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, card: Card) -> Result<(), NapError> {
+        let player = self.to_play().expect("play is in progress");
+        let hand = &self.hands[player.index()];
+        if !hand.contains(&card) {
+            return Err(NapError::CardNotHeld(player, card));
+        }
+        match self.tricks.last().filter(|t| !t.is_complete()) {
+            Some(trick) if !trick.filter(hand).contains(&card) => {
+                return Err(NapError::MustFollowSuit(player));
+            }
+            Some(_) => (),
+            None => {
+                let trump = match self.tricks.first() {
+                    Some(trick) => trick.trump,
+                    None => {
+                        let trump = card.suit().expect("not a joker");
+                        self.events.push_back(Event::Trump(trump));
+                        trump
+                    }
+                };
+                self.tricks.push(Trick::new(trump));
+            }
+        }
+        let trick = self.tricks.last_mut().expect("trick is started");
+        trick.cards.push((player, card));
+        self.hands[player.index()].retain(|&c| c != card);
+        let trick = self.tricks.last().expect("trick is started");
+        if trick.is_complete() {
+            self.events.push_back(Event::Trick(trick.clone()));
+        }
+        Ok(())
+    }
+
+    /// The result of the deal, once it's over.
+    pub fn result(&self) -> Option<DealResult> {
+        if self.phase() != Phase::Over {
+            return None;
+        }
+        let contract = self.contract();
+        let tricks = contract.map_or(0, |(bidder, _)| taken(&self.tricks, bidder));
+        Some(DealResult { contract, tricks })
+    }
+}
+
+impl crate::engine::Engine for Deal {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = DealView<'a>;
+    type Event = Event;
+    type Outcome = DealResult;
+    type Error = NapError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> DealView<'_> {
+        Deal::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Deal::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), NapError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<DealResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a deal: the cards dealt, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawDeal {
+    config: DealConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Deal> for RawDeal {
+    fn from(deal: &Deal) -> Self {
+        Self {
+            config: deal.config.clone(),
+            actions: deal.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawDeal> for Deal {
+    type Error = NapError;
+
+    fn try_from(raw: RawDeal) -> Result<Self, NapError> {
+        raw.config.validate()?;
+        let mut deal = Deal::from(raw.config);
+        for action in raw.actions {
+            deal.apply_action(action)?;
+        }
+        deal.events.clear();
+        Ok(deal)
+    }
+}
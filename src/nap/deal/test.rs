@@ -0,0 +1,158 @@
+use std::convert::TryFrom;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::nap::Robot;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// A deal in which the player to the dealer's left, Player 1, holds the top spades.
+fn config() -> DealConfig {
+    let hands = vec![
+        cards("A♤ K♤ Q♤ J♤ A♡"),
+        cards("2♤ 3♡ 4♡ K♣ Q♣"),
+        cards("T♤ 9♤ 2♡ 2♦ 3♦"),
+        cards("K♡ Q♡ 5♣ 6♣ 7♣"),
+    ];
+    DealConfig::from_hands(Player(3), hands).unwrap()
+}
+
+fn calls(deal: &mut Deal, calls: &[Call]) {
+    for &call in calls {
+        deal.apply_action(Action::Call(call)).unwrap();
+    }
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut hands: Vec<Vec<Card>> = (0..4).map(|p| config().hand(Player(p)).to_vec()).collect();
+    let mut short = hands.clone();
+    short[3].pop();
+    let result = DealConfig::from_hands(Player(0), short);
+    assert!(matches!(result, Err(NapError::InvalidHandSize)));
+    hands[0][0] = card("7♣");
+    let result = DealConfig::from_hands(Player(0), hands);
+    assert!(matches!(result, Err(NapError::InvalidCard(_))));
+}
+
+#[test]
+fn test_bidding() {
+    let mut deal = Deal::from(config());
+    assert_eq!(deal.phase(), Phase::Bid);
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+    assert_eq!(
+        deal.legal_actions(),
+        [
+            Call::Pass,
+            Call::Bid(2),
+            Call::Bid(3),
+            Call::Bid(4),
+            Call::Bid(5)
+        ]
+        .iter()
+        .copied()
+        .map(Action::Call)
+        .collect::<Vec<_>>()
+    );
+    calls(&mut deal, &[Call::Bid(3)]);
+    assert!(matches!(
+        deal.apply_action(Action::Call(Call::Bid(3))),
+        Err(NapError::IllegalCall(Call::Bid(3)))
+    ));
+    calls(&mut deal, &[Call::Pass, Call::Bid(4), Call::Pass]);
+    assert_eq!(deal.contract(), Some((Player(2), 4)));
+    assert_eq!(deal.next_actor(), Some(Player(2)));
+    let events: Vec<String> = std::iter::from_fn(|| deal.pop_event())
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(
+        events,
+        [
+            "Player 1: Bid 3",
+            "Player 2: Pass",
+            "Player 3: Bid 4",
+            "Player 4: Pass",
+            "Player 3 won the bidding: Bid 4"
+        ]
+    );
+}
+
+#[test]
+fn test_thrown_in() {
+    let mut deal = Deal::from(config());
+    calls(&mut deal, &[Call::Pass; 4]);
+    assert_eq!(deal.phase(), Phase::Over);
+    let result = deal.result().unwrap();
+    assert_eq!(result.contract, None);
+    assert!(Player::all(PLAYERS).all(|p| result.score(p) == 0));
+}
+
+#[test]
+fn test_nap() {
+    let mut deal = Deal::from(config());
+    calls(
+        &mut deal,
+        &[Call::Bid(5), Call::Pass, Call::Pass, Call::Pass],
+    );
+    while deal.pop_event().is_some() {}
+    // The first card led fixes the trump suit.
+    deal.apply_action(Action::Play(card("A♤"))).unwrap();
+    assert_eq!(
+        deal.pop_event().map(|e| e.to_string()),
+        Some("Trump: ♤".into())
+    );
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("3♡"))),
+        Err(NapError::MustFollowSuit(Player(1)))
+    ));
+    for c in ["2♤", "T♤", "5♣"].iter() {
+        deal.apply_action(Action::Play(card(c))).unwrap();
+    }
+    assert_eq!(deal.view(Player(0)).taken(Player(0)), 1);
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    assert_eq!(result.contract, Some((Player(0), 5)));
+    assert_eq!(result.tricks, 5);
+    assert!(result.made());
+    let scores: Vec<i32> = Player::all(PLAYERS).map(|p| result.score(p)).collect();
+    assert_eq!(scores, [30, -10, -10, -10]);
+    assert_eq!(result.to_string(), "Player 1 made Nap, taking 5 tricks: 30");
+}
+
+#[test]
+fn test_failed() {
+    let result = DealResult {
+        contract: Some((Player(2), 3)),
+        tricks: 2,
+    };
+    let scores: Vec<i32> = Player::all(PLAYERS).map(|p| result.score(p)).collect();
+    assert_eq!(scores, [3, 3, -9, 3]);
+    let result = DealResult {
+        contract: Some((Player(1), 5)),
+        tricks: 4,
+    };
+    let scores: Vec<i32> = Player::all(PLAYERS).map(|p| result.score(p)).collect();
+    assert_eq!(scores, [5, -15, 5, 5]);
+}
+
+#[test]
+fn test_raw_deal() {
+    let mut rng = StdRng::seed_from_u64(17);
+    let mut deal = Deal::from(DealConfig::random(Player(1), &mut rng));
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    let raw = serde_json::to_string(&RawDeal::from(&deal)).unwrap();
+    let replayed = Deal::try_from(serde_json::from_str::<RawDeal>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), deal.actions());
+}
@@ -0,0 +1,29 @@
+//! Errors
+
+use super::{Action, Call, Card, Player};
+
+/// An error that can occur during a deal.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NapError {
+    /// A player isn't dealt five cards.
+    #[error("each player must be dealt 5 cards")]
+    InvalidHandSize,
+    /// The same card was dealt twice.
+    #[error("{0} is dealt more than once")]
+    InvalidCard(Card),
+    /// The bid is out of range, or isn't higher than the last.
+    #[error("{0} is not a legal call")]
+    IllegalCall(Call),
+    /// The action isn't expected at this point in the deal.
+    #[error("unexpected action: {0}")]
+    OutOfTurn(Action),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The player must follow the suit that was led.
+    #[error("{0} must follow suit")]
+    MustFollowSuit(Player),
+    /// The deal is over, no more actions are expected.
+    #[error("deal is over")]
+    DealOver,
+}
@@ -0,0 +1,136 @@
+//! A robot player.
+//!
+//! The robot bids the number of tricks it expects to take with its longest, strongest suit as
+//! trump, and leads that suit first if it wins the bidding. In play, the bidder tries to take each
+//! trick cheaply; the other players only try to take the tricks the bidder would otherwise win.
+
+use std::convert::TryFrom;
+
+use super::{rank_order, Action, Call, Card, DealView, Phase, Suit, Trick, HAND_SIZE, MIN_BID};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &DealView, legal: &[Action]) -> Action {
+        let choice = match view.phase {
+            Phase::Bid => Some(Action::Call(choose_call(view))),
+            Phase::Play => {
+                let cards: Vec<Card> = legal
+                    .iter()
+                    .filter_map(|a| match a {
+                        Action::Play(card) => Some(*card),
+                        Action::Call(_) => None,
+                    })
+                    .collect();
+                choose_card(view, &cards).map(Action::Play)
+            }
+            Phase::Over => None,
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0])
+    }
+}
+
+/// Estimates the number of tricks the hand will take with the suit as trump, leading first. An
+/// unbroken run of trumps from the ace is sure to take a trick per card.
+fn estimate(hand: &[Card], trump: Suit) -> f64 {
+    let trumps: Vec<u8> = hand
+        .iter()
+        .filter(|c| c.suit() == Some(trump))
+        .map(|&c| rank_order(c))
+        .collect();
+    let solid = (0..=12u8).rev().take_while(|r| trumps.contains(r)).count();
+    let sure = 13 - u8::try_from(solid).expect("at most 13 ranks");
+    hand.iter()
+        .map(|&card| {
+            let rank = rank_order(card);
+            if card.suit() == Some(trump) {
+                match rank {
+                    _ if rank >= sure => 1.0,
+                    11 => 0.9,
+                    10 => 0.75,
+                    9 => 0.6,
+                    _ if trumps.len() >= 3 => 0.5,
+                    _ => 0.25,
+                }
+            } else {
+                match rank {
+                    12 => 0.75,
+                    11 => 0.3,
+                    _ => 0.0,
+                }
+            }
+        })
+        .sum()
+}
+
+/// The suit the robot would choose as trump, and the number of tricks it expects to take.
+fn best_trump(hand: &[Card]) -> Option<(Suit, f64)> {
+    hand.iter()
+        .filter_map(|c| c.suit())
+        .map(|suit| (suit, estimate(hand, suit)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+/// Bids the expected number of tricks, if that's higher than the last bid.
+fn choose_call(view: &DealView) -> Call {
+    let Some((_, expected)) = best_trump(view.hand) else {
+        return Call::Pass;
+    };
+    let min = view.high_bid().map_or(MIN_BID, |b| b + 1);
+    let max = u8::try_from(HAND_SIZE).expect("small hand");
+    (min..=max)
+        .rev()
+        .find(|&b| f64::from(b) <= expected + 0.25)
+        .map_or(Call::Pass, Call::Bid)
+}
+
+/// Chooses a card to play.
+fn choose_card(view: &DealView, legal: &[Card]) -> Option<Card> {
+    let (bidder, _) = view.contract?;
+    let bidding = bidder == view.player;
+    let strength = |&c: &Card| {
+        (
+            view.trump().is_some_and(|t| c.suit() == Some(t)),
+            rank_order(c),
+        )
+    };
+    let Some(trick) = view.current_trick() else {
+        if bidding {
+            // Lead trumps, starting with the suit to be made trump.
+            let trump = view
+                .trump()
+                .or_else(|| best_trump(view.hand).map(|(s, _)| s));
+            let trumps = legal.iter().copied().filter(|c| c.suit() == trump);
+            return trumps
+                .max_by_key(strength)
+                .or_else(|| legal.iter().copied().max_by_key(strength));
+        }
+        return legal.iter().copied().max_by_key(strength);
+    };
+    let (winners, losers): (Vec<Card>, Vec<Card>) =
+        legal.iter().copied().partition(|&c| wins(trick, view, c));
+    let bidder_winning = trick.winner().map(|(p, _)| p) == Some(bidder);
+    let want = bidding || bidder_winning || !trick.cards.iter().any(|&(p, _)| p == bidder);
+    if want && !winners.is_empty() {
+        winners.into_iter().min_by_key(strength)
+    } else if !losers.is_empty() {
+        losers.into_iter().min_by_key(strength)
+    } else {
+        winners.into_iter().min_by_key(strength)
+    }
+}
+
+/// Returns true if the card would take the lead in the trick.
+fn wins(trick: &Trick, view: &DealView, card: Card) -> bool {
+    let mut trick = trick.clone();
+    trick.cards.push((view.player, card));
+    trick.winner().map(|(p, _)| p) == Some(view.player)
+}
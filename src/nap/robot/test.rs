@@ -0,0 +1,55 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::nap::{Deal, DealConfig, Player};
+
+#[test]
+fn test_bidding() {
+    let hands = vec![
+        cards("A♤ K♤ Q♤ J♤ A♡"),
+        cards("2♤ 3♡ 4♡ K♣ Q♣"),
+        cards("T♤ 9♤ 2♡ 2♦ 3♦"),
+        cards("K♡ Q♡ 5♣ 6♣ 7♣"),
+    ];
+    let config = DealConfig::from_hands(Player(3), hands).unwrap();
+    let mut deal = Deal::from(config);
+    while deal.phase() == Phase::Bid {
+        let player = deal.next_actor().unwrap();
+        let action = Robot.choose(&deal.view(player), &deal.legal_actions());
+        deal.apply_action(action).unwrap();
+    }
+    // The top four spades and an ace are worth nap, and nobody else can outbid it.
+    assert_eq!(deal.contract(), Some((Player(0), 5)));
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    assert!(result.made());
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(21);
+    let (mut contracts, mut made) = (0, 0);
+    for i in 0..100 {
+        let config = DealConfig::random(Player(i % 4), &mut rng);
+        let mut deal = Deal::from(config);
+        let result = deal
+            .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+            .unwrap()
+            .expect("deal is over");
+        let total: i32 = Player::all(4).map(|p| result.score(p)).sum();
+        assert_eq!(total, 0);
+        if result.contract.is_some() {
+            contracts += 1;
+        }
+        if result.made() {
+            made += 1;
+        }
+    }
+    assert!(contracts > 50, "{}", contracts);
+    assert!(made * 2 > contracts, "{} of {}", made, contracts);
+}
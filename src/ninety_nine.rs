@@ -0,0 +1,121 @@
+//! Ninety-Nine.
+//!
+//! David Parlett's game for three players, with a 36-card deck from the sixes up to the aces. Each
+//! is dealt twelve cards, and bids by laying three of them aside face down: each club counts 3,
+//! each heart 2, each spade 1 and each diamond nothing, so that the three cards encode a bid of
+//! anywhere from none to all nine of the tricks that remain to be played. The player to the
+//! dealer's left leads to the first trick; players must follow suit if they can, and the highest
+//! trump or else the highest card of the suit led wins the trick.
+//!
+//! Each trick taken scores a point. A player who takes exactly the number of tricks they bid
+//! scores a bonus of 30 if they're the only one to do so, 20 if two players do, or 10 if all
+//! three do. Clubs are trumps in the first deal; thereafter the trump suit is the one worth the
+//! number of players who made their bids in the previous deal. The game is over once a player
+//! reaches 100 points.
+
+mod deal;
+mod error;
+mod robot;
+
+use itertools::Itertools;
+
+pub use self::deal::{
+    Action, Deal, DealConfig, DealResult, DealView, Event, Phase, RawDeal, Trick,
+};
+pub use self::error::NinetyNineError;
+pub use self::robot::Robot;
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The number of players.
+pub const PLAYERS: u8 = 3;
+
+/// The number of cards each player lays aside to bid.
+pub const BID_CARDS: usize = 3;
+
+/// The number of tricks played in each deal.
+pub const TRICKS: usize = 9;
+
+/// The score that ends the game.
+pub const GAME_SCORE: u32 = 100;
+
+/// The 36-card deck with which ninety-nine is played.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new()
+        .filter_ranks(|r| r == Rank::Ace || r >= Rank::Six)
+        .build()
+}
+
+/// The number of tricks a bid card counts for: 3 for a club, 2 for a heart, 1 for a spade, and
+/// nothing for a diamond.
+pub fn bid_value(suit: Suit) -> u8 {
+    match suit {
+        Suit::Club => 3,
+        Suit::Heart => 2,
+        Suit::Spade => 1,
+        Suit::Diamond => 0,
+    }
+}
+
+/// The bid encoded by the cards laid aside.
+pub fn bid(cards: &[Card]) -> u8 {
+    cards.iter().filter_map(|c| c.suit()).map(bid_value).sum()
+}
+
+/// The trump suit following a deal in which the specified number of players made their bids.
+pub fn next_trump(made: usize) -> Suit {
+    match made {
+        0 => Suit::Diamond,
+        1 => Suit::Spade,
+        2 => Suit::Heart,
+        _ => Suit::Club,
+    }
+}
+
+/// Formats cards by suit, from highest to lowest.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| deal::sort_key(c))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Plays a game to 100 points with a simple command-line interface, with the user as the first
+/// of three players and robots as the others. The record of every deal is written to stderr when
+/// the game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = [0; PLAYERS as usize];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let mut trump = Suit::Club;
+    let mut deals = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    while scores.iter().all(|&s| s < GAME_SCORE) {
+        let config = DealConfig::random(dealer, trump, &mut rng);
+        let mut deal = Deal::from(config);
+        let result = console
+            .play(&mut deal, me, |deal, player, legal| {
+                robot.choose(&deal.view(player), &legal)
+            })?
+            .expect("deal is over");
+        for player in Player::all(PLAYERS) {
+            scores[player.index()] += result.score(player);
+        }
+        deals.push(RawDeal::from(&deal));
+        let totals = Player::all(PLAYERS)
+            .map(|p| format!("{p} {}", scores[p.index()]))
+            .join(", ");
+        console.println(tr!("game-score", score = totals))?;
+        trump = next_trump(result.made());
+        dealer = dealer.next(PLAYERS);
+    }
+    serde_json::to_writer(std::io::stderr(), &deals)?;
+    Ok(())
+}
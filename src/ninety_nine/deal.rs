@@ -0,0 +1,627 @@
+//! A single deal: the bids, and the play of the tricks.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    bid, deck, format_cards, Card, NinetyNineError, Player, Suit, BID_CARDS, PLAYERS, TRICKS,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of cards dealt to each player.
+const HAND_SIZE: usize = BID_CARDS + TRICKS;
+
+/// Sorts cards for display: by suit, from the clubs down to the diamonds, and then from highest to
+/// lowest.
+pub(crate) fn sort_key(card: Card) -> (Reverse<u8>, Reverse<u8>) {
+    let suit = card.suit().map_or(0, super::bid_value);
+    let rank = card.rank().map_or(0, |r| r.ace_high());
+    (Reverse(suit), Reverse(rank))
+}
+
+/// The cards dealt to each player, and the trump suit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealConfig {
+    dealer: Player,
+    trump: Suit,
+    hands: Vec<Vec<Card>>,
+}
+
+impl DealConfig {
+    /// Deals a shuffled deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, trump: Suit, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        let hands = (0..PLAYERS).map(|_| deck.take(HAND_SIZE)).collect();
+        Self {
+            dealer,
+            trump,
+            hands,
+        }
+    }
+
+    /// Creates a deal from the specified hands, indexed by player, which must hold the 36 cards
+    /// of the deck between them.
+    pub fn from_hands(
+        dealer: Player,
+        trump: Suit,
+        hands: Vec<Vec<Card>>,
+    ) -> Result<Self, NinetyNineError> {
+        let config = Self {
+            dealer,
+            trump,
+            hands,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that every card is dealt exactly once.
+    pub fn validate(&self) -> Result<(), NinetyNineError> {
+        if self.dealer.0 >= PLAYERS
+            || self.hands.len() != usize::from(PLAYERS)
+            || self.hands.iter().any(|h| h.len() != HAND_SIZE)
+        {
+            return Err(NinetyNineError::InvalidHandSize);
+        }
+        let deck = deck();
+        let mut seen = vec![];
+        for &card in self.hands.iter().flatten() {
+            if seen.contains(&card) || !deck.cards().contains(&card) {
+                return Err(NinetyNineError::InvalidCard(card));
+            }
+            seen.push(card);
+        }
+        Ok(())
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump
+    }
+
+    /// The cards dealt to the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+}
+
+/// The stage of the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// The players are laying cards aside to bid.
+    Bid,
+    /// The cards are being played.
+    Play,
+    /// The deal is over.
+    Over,
+}
+
+/// An action taken during the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// A card laid aside face down as part of the player's bid.
+    Bid(Card),
+    /// A card played to a trick.
+    Play(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Bid(card) => {
+                f.write_str(&tr!("ninety-nine-action-bid", card = card.to_string()))
+            }
+            Action::Play(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A trick.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trick {
+    /// The trump suit.
+    pub trump: Suit,
+    /// The cards played so far, in order.
+    pub cards: Vec<(Player, Card)>,
+}
+
+impl Trick {
+    /// Creates an empty trick.
+    pub fn new(trump: Suit) -> Self {
+        Self {
+            trump,
+            cards: vec![],
+        }
+    }
+
+    /// The suit that must be followed, or `None` for an empty trick.
+    pub fn lead_suit(&self) -> Option<Suit> {
+        self.cards.first().and_then(|&(_, card)| card.suit())
+    }
+
+    /// Returns true once all three players have played.
+    pub fn is_complete(&self) -> bool {
+        self.cards.len() == usize::from(PLAYERS)
+    }
+
+    /// The card winning the trick so far: the highest trump, or else the highest card of the
+    /// suit led.
+    pub fn winner(&self) -> Option<(Player, Card)> {
+        let lead = self.lead_suit()?;
+        self.cards.iter().copied().max_by_key(|&(_, card)| {
+            let class = match card.suit() {
+                Some(suit) if suit == self.trump => 2,
+                Some(suit) if suit == lead => 1,
+                _ => 0,
+            };
+            (class, card.rank().map_or(0, |r| r.ace_high()))
+        })
+    }
+
+    /// Filters the hand down to the cards that may be played to this trick.
+    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
+        let Some(lead) = self.lead_suit() else {
+            return hand.to_vec();
+        };
+        let following: Vec<Card> = hand
+            .iter()
+            .copied()
+            .filter(|&c| c.suit() == Some(lead))
+            .collect();
+        if following.is_empty() {
+            hand.to_vec()
+        } else {
+            following
+        }
+    }
+}
+
+impl Display for Trick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, (player, card)) in self.cards.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}:{card}", tr!("engine-player-abbr", n = player.0 + 1))?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The result of a deal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealResult {
+    /// Each player's bid.
+    pub bids: Vec<u8>,
+    /// The number of tricks each player took.
+    pub tricks: Vec<u8>,
+}
+
+impl DealResult {
+    /// Returns true if the player took exactly the number of tricks they bid.
+    pub fn made_bid(&self, player: Player) -> bool {
+        self.bids[player.index()] == self.tricks[player.index()]
+    }
+
+    /// The number of players who made their bids.
+    pub fn made(&self) -> usize {
+        Player::all(PLAYERS).filter(|&p| self.made_bid(p)).count()
+    }
+
+    /// The points scored by the player: one for each trick, and a bonus for making their bid
+    /// that's greater the fewer players made theirs.
+    pub fn score(&self, player: Player) -> u32 {
+        let bonus = if self.made_bid(player) {
+            match self.made() {
+                1 => 30,
+                2 => 20,
+                _ => 10,
+            }
+        } else {
+            0
+        };
+        u32::from(self.tricks[player.index()]) + bonus
+    }
+}
+
+impl Display for DealResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = Player::all(PLAYERS).map(|p| {
+            let i = p.index();
+            tr!(
+                "ninety-nine-result",
+                player = p.to_string(),
+                bid = self.bids[i],
+                tricks = self.tricks[i],
+                points = self.score(p)
+            )
+        });
+        write!(f, "{}", lines.format("\n"))
+    }
+}
+
+/// An event that occurs during the deal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    /// A player laid aside their bid cards.
+    Bid(Player),
+    /// The trick is over.
+    Trick(Trick),
+    /// The deal is over.
+    Over(DealResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Bid(player) => {
+                f.write_str(&tr!("ninety-nine-has-bid", player = player.to_string()))
+            }
+            Event::Trick(trick) => {
+                let winner = trick.winner().expect("trick is complete").0;
+                let (trick, winner) = (trick.to_string(), winner.to_string());
+                f.write_str(&tr!("game-trick-won", trick = trick, winner = winner))
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of a deal, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct DealView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The trump suit.
+    pub trump: Suit,
+    /// The player's remaining cards.
+    pub hand: &'a [Card],
+    /// The cards the player has laid aside to bid.
+    pub bid_cards: &'a [Card],
+    /// The tricks played so far. The last may be incomplete.
+    pub tricks: &'a [Trick],
+    /// The stage of the deal.
+    pub phase: Phase,
+    /// The player expected to act next.
+    pub to_play: Option<Player>,
+}
+
+impl DealView<'_> {
+    /// The player's bid, once they've laid aside all of their bid cards.
+    pub fn bid(&self) -> Option<u8> {
+        (self.bid_cards.len() == BID_CARDS).then(|| bid(self.bid_cards))
+    }
+
+    /// The trick in progress, if any cards have been played to it.
+    pub fn current_trick(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|t| !t.is_complete())
+    }
+
+    /// The number of tricks the player has taken so far.
+    pub fn taken(&self, player: Player) -> u8 {
+        let taken = self
+            .tricks
+            .iter()
+            .filter(|t| t.is_complete() && t.winner().map(|(p, _)| p) == Some(player))
+            .count();
+        u8::try_from(taken).expect("at most 9 tricks")
+    }
+}
+
+impl Display for DealView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        writeln!(f, "{}", tr!("game-trump", trump = self.trump.to_string()))?;
+        if !self.bid_cards.is_empty() {
+            let text = tr!("ninety-nine-bid", cards = format_cards(self.bid_cards));
+            f.write_str(&text)?;
+            match self.bid() {
+                Some(bid) => writeln!(f, " ({bid})")?,
+                None => writeln!(f)?,
+            }
+        }
+        if self.phase != Phase::Bid {
+            let taken = Player::all(PLAYERS)
+                .map(|p| {
+                    format!(
+                        "{} {}",
+                        tr!("engine-player-abbr", n = p.0 + 1),
+                        self.taken(p)
+                    )
+                })
+                .join(", ");
+            writeln!(f, "{}", tr!("ninety-nine-tricks", taken = taken))?;
+        }
+        if let Some(trick) = self.current_trick() {
+            writeln!(f, "{}", tr!("game-trick", trick = trick.to_string()))?;
+        }
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a deal.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    config: DealConfig,
+    hands: Vec<Vec<Card>>,
+    bids: Vec<Vec<Card>>,
+    tricks: Vec<Trick>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<DealConfig> for Deal {
+    fn from(config: DealConfig) -> Self {
+        Self {
+            hands: config.hands.clone(),
+            bids: vec![vec![]; usize::from(PLAYERS)],
+            tricks: vec![],
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        }
+    }
+}
+
+impl Deal {
+    /// The deal as it was dealt.
+    pub fn config(&self) -> &DealConfig {
+        &self.config
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The cards the player has laid aside to bid.
+    pub fn bid_cards(&self, player: Player) -> &[Card] {
+        &self.bids[player.index()]
+    }
+
+    /// The tricks played so far. The last may be incomplete.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The player yet to finish bidding, starting from the dealer's left.
+    fn bidder(&self) -> Option<Player> {
+        let mut player = self.config.dealer.next(PLAYERS);
+        for _ in 0..PLAYERS {
+            if self.bids[player.index()].len() < BID_CARDS {
+                return Some(player);
+            }
+            player = player.next(PLAYERS);
+        }
+        None
+    }
+
+    /// The stage of the deal.
+    pub fn phase(&self) -> Phase {
+        if self.bidder().is_some() {
+            Phase::Bid
+        } else if self.tricks.iter().filter(|t| t.is_complete()).count() == TRICKS {
+            Phase::Over
+        } else {
+            Phase::Play
+        }
+    }
+
+    /// The player whose card is to be played next, or `None` if the play hasn't started or is
+    /// over.
+    pub fn to_play(&self) -> Option<Player> {
+        if self.phase() != Phase::Play {
+            return None;
+        }
+        match self.tricks.last() {
+            None => Some(self.config.dealer.next(PLAYERS)),
+            Some(trick) if !trick.is_complete() => Some(
+                trick
+                    .cards
+                    .last()
+                    .expect("trick is started")
+                    .0
+                    .next(PLAYERS),
+            ),
+            Some(trick) => Some(trick.winner().expect("trick is complete").0),
+        }
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        match self.phase() {
+            Phase::Bid => self.bidder(),
+            Phase::Play => self.to_play(),
+            Phase::Over => None,
+        }
+    }
+
+    /// Returns the state of the deal, as visible to the player.
+    pub fn view(&self, player: Player) -> DealView<'_> {
+        DealView {
+            player,
+            dealer: self.config.dealer,
+            trump: self.config.trump,
+            hand: &self.hands[player.index()],
+            bid_cards: &self.bids[player.index()],
+            tricks: &self.tricks,
+            phase: self.phase(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// Returns the actions the next player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.phase() {
+            Phase::Bid => {
+                let bidder = self.bidder().expect("bidding is in progress");
+                let mut cards = self.hands[bidder.index()].clone();
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.into_iter().map(Action::Bid).collect()
+            }
+            Phase::Play => {
+                let player = self.to_play().expect("play is in progress");
+                let hand = &self.hands[player.index()];
+                let mut cards = match self.tricks.last().filter(|t| !t.is_complete()) {
+                    Some(trick) => trick.filter(hand),
+                    None => hand.clone(),
+                };
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.into_iter().map(Action::Play).collect()
+            }
+            Phase::Over => vec![],
+        }
+    }
+
+    /// Applies the next player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), NinetyNineError> {
+        match (self.phase(), action) {
+            (Phase::Over, _) => return Err(NinetyNineError::DealOver),
+            (Phase::Bid, Action::Bid(card)) => self.bid(card)?,
+            (Phase::Play, Action::Play(card)) => self.play(card)?,
+            _ => return Err(NinetyNineError::OutOfTurn(action)),
+        }
+        self.actions.push(action);
+        if let Some(result) = self.result() {
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    fn bid(&mut self, card: Card) -> Result<(), NinetyNineError> {
+        let bidder = self.bidder().expect("bidding is in progress");
+        let hand = &mut self.hands[bidder.index()];
+        if !hand.contains(&card) {
+            return Err(NinetyNineError::CardNotHeld(bidder, card));
+        }
+        hand.retain(|&c| c != card);
+        let bids = &mut self.bids[bidder.index()];
+        bids.push(card);
+        if bids.len() == BID_CARDS {
+            self.events.push_back(Event::Bid(bidder));
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, card: Card) -> Result<(), NinetyNineError> {
+        let player = self.to_play().expect("play is in progress");
+        let hand = &self.hands[player.index()];
+        if !hand.contains(&card) {
+            return Err(NinetyNineError::CardNotHeld(player, card));
+        }
+        match self.tricks.last().filter(|t| !t.is_complete()) {
+            Some(trick) if !trick.filter(hand).contains(&card) => {
+                return Err(NinetyNineError::MustFollowSuit(player));
+            }
+            Some(_) => (),
+            None => self.tricks.push(Trick::new(self.config.trump)),
+        }
+        let trick = self.tricks.last_mut().expect("trick is started");
+        trick.cards.push((player, card));
+        self.hands[player.index()].retain(|&c| c != card);
+        let trick = self.tricks.last().expect("trick is started");
+        if trick.is_complete() {
+            self.events.push_back(Event::Trick(trick.clone()));
+        }
+        Ok(())
+    }
+
+    /// The result of the deal, once it's over.
+    pub fn result(&self) -> Option<DealResult> {
+        if self.phase() != Phase::Over {
+            return None;
+        }
+        let view = self.view(self.config.dealer);
+        Some(DealResult {
+            bids: self.bids.iter().map(|b| bid(b)).collect(),
+            tricks: Player::all(PLAYERS).map(|p| view.taken(p)).collect(),
+        })
+    }
+}
+
+impl crate::engine::Engine for Deal {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = DealView<'a>;
+    type Event = Event;
+    type Outcome = DealResult;
+    type Error = NinetyNineError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> DealView<'_> {
+        Deal::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Deal::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), NinetyNineError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<DealResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a deal: the cards dealt, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawDeal {
+    config: DealConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Deal> for RawDeal {
+    fn from(deal: &Deal) -> Self {
+        Self {
+            config: deal.config.clone(),
+            actions: deal.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawDeal> for Deal {
+    type Error = NinetyNineError;
+
+    fn try_from(raw: RawDeal) -> Result<Self, NinetyNineError> {
+        raw.config.validate()?;
+        let mut deal = Deal::from(raw.config);
+        for action in raw.actions {
+            deal.apply_action(action)?;
+        }
+        deal.events.clear();
+        Ok(deal)
+    }
+}
@@ -0,0 +1,119 @@
+use std::convert::TryFrom;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::ninety_nine::Robot;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// A deal with clubs as trumps, in which Player 1 holds every club and leads.
+fn config() -> DealConfig {
+    let hands = vec![
+        cards("A♣ K♣ Q♣ J♣ T♣ 9♣ 8♣ 7♣ 6♣ A♡ K♡ Q♡"),
+        cards("J♡ T♡ 9♡ 8♡ 7♡ 6♡ A♤ K♤ Q♤ J♤ T♤ 9♤"),
+        cards("8♤ 7♤ 6♤ A♦ K♦ Q♦ J♦ T♦ 9♦ 8♦ 7♦ 6♦"),
+    ];
+    DealConfig::from_hands(Player(2), Suit::Club, hands).unwrap()
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut hands: Vec<Vec<Card>> = (0..3).map(|p| config().hand(Player(p)).to_vec()).collect();
+    let mut short = hands.clone();
+    short[0].pop();
+    let result = DealConfig::from_hands(Player(0), Suit::Club, short);
+    assert!(matches!(result, Err(NinetyNineError::InvalidHandSize)));
+    hands[0][0] = card("6♦");
+    let result = DealConfig::from_hands(Player(0), Suit::Club, hands.clone());
+    assert!(matches!(result, Err(NinetyNineError::InvalidCard(_))));
+    hands[0][0] = card("2♣");
+    let result = DealConfig::from_hands(Player(0), Suit::Club, hands);
+    assert!(matches!(result, Err(NinetyNineError::InvalidCard(_))));
+}
+
+#[test]
+fn test_bids() {
+    let mut deal = Deal::from(config());
+    assert_eq!(deal.phase(), Phase::Bid);
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("A♣"))),
+        Err(NinetyNineError::OutOfTurn(_))
+    ));
+    assert!(matches!(
+        deal.apply_action(Action::Bid(card("A♦"))),
+        Err(NinetyNineError::CardNotHeld(Player(0), _))
+    ));
+    let bids = ["J♣ T♣ 9♣", "J♤ T♤ 9♤", "8♦ 7♦ 6♦"];
+    for (player, bid) in Player::all(PLAYERS).zip(bids.iter()) {
+        for card in cards(bid) {
+            assert_eq!(deal.next_actor(), Some(player));
+            deal.apply_action(Action::Bid(card)).unwrap();
+        }
+        assert_eq!(
+            deal.pop_event().map(|e| e.to_string()),
+            Some(format!("{player} has bid"))
+        );
+    }
+    assert_eq!(deal.view(Player(0)).bid(), Some(9));
+    assert_eq!(deal.view(Player(1)).bid(), Some(3));
+    assert_eq!(deal.view(Player(2)).bid(), Some(0));
+    assert_eq!(deal.hand(Player(0)).len(), TRICKS);
+    assert_eq!(deal.phase(), Phase::Play);
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+}
+
+#[test]
+fn test_play() {
+    let mut deal = Deal::from(config());
+    for card in cards("J♣ T♣ 9♣ J♤ T♤ 9♤ 8♦ 7♦ 6♦") {
+        deal.apply_action(Action::Bid(card)).unwrap();
+    }
+    while deal.pop_event().is_some() {}
+    deal.apply_action(Action::Play(card("A♡"))).unwrap();
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("A♤"))),
+        Err(NinetyNineError::MustFollowSuit(Player(1)))
+    ));
+    assert_eq!(
+        deal.legal_actions(),
+        cards("J♡ T♡ 9♡ 8♡ 7♡ 6♡")
+            .into_iter()
+            .map(Action::Play)
+            .collect::<Vec<_>>()
+    );
+    // Player 1 takes every trick, with the high hearts and then the clubs.
+    let result = deal
+        .play_out(|_, _, legal| legal[0])
+        .unwrap()
+        .expect("deal is over");
+    assert_eq!(result.bids, [9, 3, 0]);
+    assert_eq!(result.tricks, [9, 0, 0]);
+    assert_eq!(result.made(), 2);
+    let scores: Vec<u32> = Player::all(PLAYERS).map(|p| result.score(p)).collect();
+    assert_eq!(scores, [29, 0, 20]);
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("A♣"))),
+        Err(NinetyNineError::DealOver)
+    ));
+}
+
+#[test]
+fn test_raw_deal() {
+    let mut rng = StdRng::seed_from_u64(99);
+    let mut deal = Deal::from(DealConfig::random(Player(1), Suit::Heart, &mut rng));
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    let raw = serde_json::to_string(&RawDeal::from(&deal)).unwrap();
+    let replayed = Deal::try_from(serde_json::from_str::<RawDeal>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), deal.actions());
+}
@@ -0,0 +1,26 @@
+//! Errors
+
+use super::{Action, Card, Player};
+
+/// An error that can occur during a deal.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NinetyNineError {
+    /// A player isn't dealt twelve cards.
+    #[error("each player must be dealt 12 cards")]
+    InvalidHandSize,
+    /// The same card was dealt twice, or a card isn't from the 36-card deck.
+    #[error("{0} is not dealt exactly once")]
+    InvalidCard(Card),
+    /// The action isn't expected at this point in the deal.
+    #[error("unexpected action: {0}")]
+    OutOfTurn(Action),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The player must follow the suit that was led.
+    #[error("{0} must follow suit")]
+    MustFollowSuit(Player),
+    /// The deal is over, no more actions are expected.
+    #[error("deal is over")]
+    DealOver,
+}
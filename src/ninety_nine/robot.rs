@@ -0,0 +1,129 @@
+//! A robot player.
+//!
+//! To bid, the robot estimates the chance each card has of taking a trick, and lays aside the
+//! three cards whose suits come closest to encoding the tricks it expects the other nine to take.
+//! In play, it tries to take tricks until it has made its bid, and then tries to lose the rest.
+
+use itertools::Itertools;
+
+use super::{bid, Action, Card, DealView, Phase, Rank, Suit, Trick, BID_CARDS};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &DealView, legal: &[Action]) -> Action {
+        let choice = match view.phase {
+            Phase::Bid => Some(Action::Bid(choose_bid(view))),
+            Phase::Play => {
+                let cards: Vec<Card> = legal
+                    .iter()
+                    .filter_map(|a| match a {
+                        Action::Play(card) => Some(*card),
+                        Action::Bid(_) => None,
+                    })
+                    .collect();
+                choose_card(view, &cards).map(Action::Play)
+            }
+            Phase::Over => None,
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0])
+    }
+}
+
+/// The rank of a card, from 0 for a two up to 12 for an ace.
+fn rank(card: Card) -> u8 {
+    card.rank().map_or(0, Rank::ace_high)
+}
+
+/// Estimates the chance of the card taking a trick.
+fn win_chance(trump: Suit, card: Card) -> f64 {
+    let trump_card = card.suit() == Some(trump);
+    match (trump_card, card.rank()) {
+        (true, Some(Rank::Ace)) => 1.0,
+        (true, Some(Rank::King)) => 0.85,
+        (true, Some(Rank::Queen)) => 0.7,
+        (true, Some(Rank::Jack)) => 0.55,
+        (true, Some(Rank::Ten)) => 0.4,
+        (true, _) => 0.25,
+        (false, Some(Rank::Ace)) => 0.8,
+        (false, Some(Rank::King)) => 0.4,
+        (false, Some(Rank::Queen)) => 0.15,
+        _ => 0.0,
+    }
+}
+
+/// Chooses the next card to lay aside, as part of the bid that best matches the hand.
+fn choose_bid(view: &DealView) -> Card {
+    let needed = BID_CARDS - view.bid_cards.len();
+    let plan = view
+        .hand
+        .iter()
+        .copied()
+        .combinations(needed)
+        .map(|combo| {
+            let cards: Vec<Card> = view
+                .bid_cards
+                .iter()
+                .copied()
+                .chain(combo.clone())
+                .collect();
+            let expected: f64 = view
+                .hand
+                .iter()
+                .filter(|c| !combo.contains(c))
+                .map(|&c| win_chance(view.trump, c))
+                .sum();
+            let miss = (expected - f64::from(bid(&cards))).abs();
+            // Between equally good bids, prefer to lay aside the weaker cards.
+            let kept: f64 = combo.iter().map(|&c| win_chance(view.trump, c)).sum();
+            (combo, miss + kept / 100.0)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(combo, _)| combo);
+    plan.and_then(|combo| combo.first().copied())
+        .unwrap_or(view.hand[0])
+}
+
+/// Chooses a card to play: the cheapest card that wins the trick if the robot still needs tricks,
+/// or the most dangerous card that loses it if not.
+fn choose_card(view: &DealView, legal: &[Card]) -> Option<Card> {
+    let bid = view.bid()?;
+    let want = view.taken(view.player) < bid;
+    let Some(trick) = view.current_trick() else {
+        let compare = |a: &Card, b: &Card| {
+            let chance = |&c: &Card| win_chance(view.trump, c);
+            chance(a)
+                .total_cmp(&chance(b))
+                .then(rank(*a).cmp(&rank(*b)))
+        };
+        return if want {
+            legal.iter().copied().max_by(compare)
+        } else {
+            legal.iter().copied().min_by(compare)
+        };
+    };
+    let strength = |&c: &Card| (c.suit() == Some(view.trump), rank(c));
+    let (winners, losers): (Vec<Card>, Vec<Card>) =
+        legal.iter().copied().partition(|&c| wins(trick, view, c));
+    match (want, winners.is_empty(), losers.is_empty()) {
+        (true, false, _) => winners.into_iter().min_by_key(strength),
+        (true, true, _) => losers.into_iter().min_by_key(strength),
+        (false, _, false) => losers.into_iter().max_by_key(strength),
+        (false, _, true) => winners.into_iter().max_by_key(strength),
+    }
+}
+
+/// Returns true if the card would take the lead in the trick.
+fn wins(trick: &Trick, view: &DealView, card: Card) -> bool {
+    let mut trick = trick.clone();
+    trick.cards.push((view.player, card));
+    trick.winner().map(|(p, _)| p) == Some(view.player)
+}
@@ -0,0 +1,82 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::ninety_nine::{Deal, DealConfig, Player, TRICKS};
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+fn view_of<'a>(hand: &'a [Card], bid_cards: &'a [Card], tricks: &'a [Trick]) -> DealView<'a> {
+    DealView {
+        player: Player(0),
+        dealer: Player(1),
+        trump: Suit::Club,
+        hand,
+        bid_cards,
+        tricks,
+        phase: Phase::Play,
+        to_play: Some(Player(0)),
+    }
+}
+
+#[test]
+fn test_bid() {
+    let hands = vec![
+        cards("A♣ K♣ Q♣ J♣ T♣ 9♣ 8♣ 7♣ 6♣ A♡ K♡ Q♡"),
+        cards("J♡ T♡ 9♡ 8♡ 7♡ 6♡ A♤ K♤ Q♤ J♤ T♤ 9♤"),
+        cards("8♤ 7♤ 6♤ A♦ K♦ Q♦ J♦ T♦ 9♦ 8♦ 7♦ 6♦"),
+    ];
+    let config = DealConfig::from_hands(Player(2), Suit::Club, hands).unwrap();
+    let mut deal = Deal::from(config);
+    while deal.phase() == Phase::Bid {
+        let player = deal.next_actor().unwrap();
+        let action = Robot.choose(&deal.view(player), &deal.legal_actions());
+        deal.apply_action(action).unwrap();
+    }
+    // Every trump is a likely trick, while the diamonds are mostly hopeless.
+    let high = deal.view(Player(0)).bid().unwrap();
+    let low = deal.view(Player(2)).bid().unwrap();
+    assert!(high >= 4, "{}", high);
+    assert!(low <= 2, "{}", low);
+}
+
+#[test]
+fn test_play() {
+    let hand = cards("A♡ 7♡ 6♤");
+    let trick = Trick {
+        trump: Suit::Club,
+        cards: vec![(Player(2), card("K♡"))],
+    };
+    let tricks = [trick];
+    let legal = [Action::Play(card("A♡")), Action::Play(card("7♡"))];
+    // Having bid nothing, duck under the king.
+    let bid_cards = cards("6♦ 7♦ 8♦");
+    let view = view_of(&hand, &bid_cards, &tricks);
+    assert_eq!(Robot.choose(&view, &legal), legal[1]);
+    // Having bid a trick, take it with the ace.
+    let bid_cards = cards("6♦ 7♦ 8♤");
+    let view = view_of(&hand, &bid_cards, &tricks);
+    assert_eq!(Robot.choose(&view, &legal), legal[0]);
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut made = 0;
+    for i in 0..30 {
+        let config = DealConfig::random(Player(i % 3), Suit::Spade, &mut rng);
+        let mut deal = Deal::from(config);
+        let result = deal
+            .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+            .unwrap()
+            .expect("deal is over");
+        let tricks: usize = result.tricks.iter().map(|&t| usize::from(t)).sum();
+        assert_eq!(tricks, TRICKS);
+        made += result.made();
+    }
+    assert!(made > 10, "{}", made);
+}
@@ -0,0 +1,111 @@
+//! Rummy 500.
+//!
+//! Two to six players are each dealt seven cards, or thirteen with two players, and the next card
+//! is turned up to start the discard pile, which is spread so that every card in it can be seen.
+//! On their turn, a player draws from the stock, or takes any card from the discard pile along
+//! with every card above it, and must then play that card at once. They may meld sets of three or
+//! four cards of a rank and runs of three or more cards of a suit, and lay off cards on any meld
+//! on the table, before discarding. The round ends when a player plays or discards their last
+//! card.
+//!
+//! Each player scores the cards they've melded and laid off, less the cards left in their hand.
+//! The first player to 500 points wins.
+
+mod error;
+mod meld;
+mod robot;
+mod round;
+
+use std::convert::TryFrom;
+
+use itertools::Itertools;
+
+pub use self::error::RummyError;
+pub use self::meld::{find_melds, is_meld, Meld, MeldKind};
+pub use self::robot::Robot;
+pub use self::round::{
+    Action, Event, Phase, PlayerScore, RawRound, Round, RoundConfig, RoundResult, RoundView,
+};
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, Rank, Suit};
+use crate::tr;
+
+/// The score a player must reach to win the game.
+pub const GAME_SCORE: i32 = 500;
+
+/// The standard deck with which rummy is played.
+pub fn deck() -> Deck<Card> {
+    Deck::standard()
+}
+
+/// The points a card scores: 15 for an ace, 10 for a ten or face card, and the number of pips
+/// otherwise. An ace melded below the two counts only 5.
+pub fn card_value(card: Card) -> i32 {
+    match card.rank() {
+        Some(Rank::Ace) => 15,
+        Some(rank) if rank >= Rank::Ten => 10,
+        Some(rank) => i32::from(rank as u8) + 1,
+        None => 0,
+    }
+}
+
+/// Formats cards by suit, and in rank order within each suit.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|c| (c.suit(), c.rank()))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Returns the winner, once a player has reached the game score. If more than one has, the
+/// highest score wins, and there's no winner until a tie at the top is broken.
+pub fn winner(scores: &[i32]) -> Option<Player> {
+    let best = scores.iter().copied().max()?;
+    let leaders: Vec<usize> = scores.iter().positions(|&s| s == best).collect();
+    match leaders[..] {
+        [i] if best >= GAME_SCORE => Some(Player(u8::try_from(i).ok()?)),
+        _ => None,
+    }
+}
+
+/// Plays a game with a simple command-line interface, with the user as the first of three
+/// players and robots as the others. The record of every round is written to stderr when the
+/// game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    const PLAYERS: u8 = 3;
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = vec![0; usize::from(PLAYERS)];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let mut rounds = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    loop {
+        let config = RoundConfig::random(dealer, PLAYERS, &mut rng)?;
+        let mut round = Round::from(config);
+        let result = console
+            .play(&mut round, me, |round, player, legal| {
+                robot.choose(&round.view(player), &legal)
+            })?
+            .expect("round is over");
+        for (score, result) in scores.iter_mut().zip(&result.scores) {
+            *score += result.total();
+        }
+        rounds.push(RawRound::from(&round));
+        let totals = Player::all(PLAYERS)
+            .map(|p| format!("{p} {}", scores[p.index()]))
+            .join(", ");
+        console.println(tr!("game-score", score = totals))?;
+        if let Some(player) = winner(&scores) {
+            console.println(tr!("game-wins", player = player.to_string()))?;
+            break;
+        }
+        dealer = dealer.next(PLAYERS);
+    }
+    serde_json::to_writer(std::io::stderr(), &rounds)?;
+    Ok(())
+}
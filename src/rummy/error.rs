@@ -0,0 +1,44 @@
+//! Errors
+
+use super::{Card, Player};
+
+/// An error that can occur during a round.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RummyError {
+    /// Rummy is played by two to six players.
+    #[error("rummy is played by two to six players, not {0}")]
+    InvalidPlayers(u8),
+    /// The deck isn't a standard deck.
+    #[error("the deck must hold the 52 cards of a standard deck")]
+    InvalidDeck,
+    /// The player must draw or take from the pile before playing.
+    #[error("must draw or take from the pile first")]
+    MustDraw,
+    /// The player has already drawn this turn.
+    #[error("already drew this turn")]
+    AlreadyDrew,
+    /// The stock is exhausted.
+    #[error("the stock is empty")]
+    StockEmpty,
+    /// The card isn't in the discard pile.
+    #[error("{0} is not in the discard pile")]
+    NotInPile(Card),
+    /// The card can't be taken from the pile, because it couldn't be played.
+    #[error("cannot take {0}, as it could not be played")]
+    CannotTake(Card),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The cards don't make a legal meld.
+    #[error("not a set or a run")]
+    InvalidMeld,
+    /// The card doesn't fit the meld, or there's no such meld.
+    #[error("cannot lay off {0} there")]
+    CannotLayOff(Card),
+    /// The player must play the card they took from the pile before doing anything else.
+    #[error("must play {0} first")]
+    MustPlay(Card),
+    /// The round is over, no more actions are expected.
+    #[error("round is over")]
+    RoundOver,
+}
@@ -0,0 +1,212 @@
+//! Sets and runs.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{card_value, Card, Player, Rank, Suit};
+
+#[cfg(test)]
+mod test;
+
+/// The position of a card in a run, with the ace either below the two or above the king.
+fn position(card: Card, ace_high: bool) -> u8 {
+    match card.rank().expect("rummy is played without jokers") {
+        Rank::Ace if ace_high => 13,
+        rank => rank as u8,
+    }
+}
+
+/// Returns the suit of a card. Rummy is played without jokers.
+fn suit_of(card: Card) -> Suit {
+    card.suit().expect("rummy is played without jokers")
+}
+
+/// If the cards form a run of three or more in one suit, returns whether the ace is high.
+fn run_ace_high(cards: &[Card]) -> Option<bool> {
+    let suit = suit_of(*cards.first()?);
+    if cards.len() < 3 || cards.iter().any(|&c| suit_of(c) != suit) {
+        return None;
+    }
+    [false, true].iter().copied().find(|&ace_high| {
+        let mut positions: Vec<u8> = cards.iter().map(|&c| position(c, ace_high)).collect();
+        positions.sort_unstable();
+        positions.windows(2).all(|w| w[0] + 1 == w[1])
+    })
+}
+
+/// Returns true if the cards form a set of three or four of a rank.
+fn is_set(cards: &[Card]) -> bool {
+    let rank = cards.first().and_then(|c| c.rank());
+    (3..=4).contains(&cards.len()) && cards.iter().all(|c| c.rank() == rank)
+}
+
+/// Returns true if the cards form a legal meld.
+pub fn is_meld(cards: &[Card]) -> bool {
+    is_set(cards) || run_ace_high(cards).is_some()
+}
+
+/// The kind of a meld.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeldKind {
+    /// Three or four cards of the rank.
+    Set(Rank),
+    /// Three or more consecutive cards of the suit.
+    Run(Suit),
+}
+
+/// A meld on the table, with the player who contributed each card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Meld {
+    kind: MeldKind,
+    cards: Vec<(Player, Card)>,
+}
+
+impl Meld {
+    /// Creates a meld from the player's cards, or returns `None` if they don't form one.
+    pub fn new(player: Player, cards: &[Card]) -> Option<Self> {
+        let kind = if is_set(cards) {
+            MeldKind::Set(cards[0].rank()?)
+        } else {
+            run_ace_high(cards)?;
+            MeldKind::Run(suit_of(cards[0]))
+        };
+        let mut meld = Self {
+            kind,
+            cards: cards.iter().map(|&c| (player, c)).collect(),
+        };
+        meld.sort();
+        Some(meld)
+    }
+
+    /// The kind of meld.
+    pub fn kind(&self) -> MeldKind {
+        self.kind
+    }
+
+    /// The cards in the meld, in order.
+    pub fn cards(&self) -> impl Iterator<Item = Card> + '_ {
+        self.cards.iter().map(|&(_, c)| c)
+    }
+
+    /// The number of cards in the meld.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the meld has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Whether an ace in this run is high. Aces in sets are always high.
+    fn ace_high(&self) -> bool {
+        let cards: Vec<Card> = self.cards().collect();
+        run_ace_high(&cards).unwrap_or(true)
+    }
+
+    fn sort(&mut self) {
+        let ace_high = self.ace_high();
+        self.cards
+            .sort_by_key(|&(_, c)| (position(c, ace_high), suit_of(c)));
+    }
+
+    /// Returns true if the card may be laid off on the meld.
+    pub fn fits(&self, card: Card) -> bool {
+        match self.kind {
+            MeldKind::Set(rank) => card.rank() == Some(rank) && self.len() < 4,
+            MeldKind::Run(_) => {
+                let mut cards: Vec<Card> = self.cards().collect();
+                cards.push(card);
+                run_ace_high(&cards).is_some()
+            }
+        }
+    }
+
+    /// Lays off a card on the meld, which must fit.
+    pub fn lay_off(&mut self, player: Player, card: Card) {
+        debug_assert!(self.fits(card));
+        self.cards.push((player, card));
+        self.sort();
+    }
+
+    /// The points scored by the player for the cards they contributed. An ace counts only 5 at
+    /// the bottom of a run.
+    pub fn points(&self, player: Player) -> i32 {
+        let low_ace = matches!(self.kind, MeldKind::Run(_)) && !self.ace_high();
+        self.cards
+            .iter()
+            .filter(|&&(p, _)| p == player)
+            .map(|&(_, c)| match c.rank() {
+                Some(Rank::Ace) if low_ace => 5,
+                _ => card_value(c),
+            })
+            .sum()
+    }
+}
+
+impl Display for Meld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, card) in self.cards().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{card}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Every meld that can be made from the cards: sets of three and four, and runs of three or
+/// more.
+pub fn find_melds(cards: &[Card]) -> Vec<Vec<Card>> {
+    let mut melds = vec![];
+    for &rank in Rank::all_ranks() {
+        let of_rank: Vec<Card> = cards
+            .iter()
+            .copied()
+            .filter(|c| c.rank() == Some(rank))
+            .collect();
+        if of_rank.len() == 4 {
+            for skip in 0..4 {
+                let mut set = of_rank.clone();
+                set.remove(skip);
+                melds.push(set);
+            }
+        }
+        if of_rank.len() >= 3 {
+            melds.push(of_rank);
+        }
+    }
+    for &suit in Suit::all_suits() {
+        for ace_high in [false, true].iter().copied() {
+            let mut of_suit: Vec<Card> = cards
+                .iter()
+                .copied()
+                .filter(|&c| suit_of(c) == suit)
+                .collect();
+            of_suit.sort_by_key(|&c| position(c, ace_high));
+            // Each maximal sequence of consecutive cards, and every stretch of three or more
+            // within it.
+            let mut start = 0;
+            for end in 1..=of_suit.len() {
+                let breaks = end == of_suit.len()
+                    || position(of_suit[end - 1], ace_high) + 1 != position(of_suit[end], ace_high);
+                if !breaks {
+                    continue;
+                }
+                for i in start..end {
+                    for j in i + 3..=end {
+                        let run = of_suit[i..j].to_vec();
+                        // Runs without an ace are found on both passes.
+                        if !melds.contains(&run) {
+                            melds.push(run);
+                        }
+                    }
+                }
+                start = end;
+            }
+        }
+    }
+    melds
+}
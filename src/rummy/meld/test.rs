@@ -0,0 +1,57 @@
+use super::*;
+use crate::french::cards;
+
+#[test]
+fn test_is_meld() {
+    assert!(is_meld(&cards("7♣ 7♦ 7♡")));
+    assert!(is_meld(&cards("7♣ 7♦ 7♡ 7♤")));
+    assert!(is_meld(&cards("5♣ 3♣ 4♣")));
+    assert!(is_meld(&cards("A♣ 2♣ 3♣")));
+    assert!(is_meld(&cards("Q♣ K♣ A♣")));
+    assert!(!is_meld(&cards("K♣ A♣ 2♣")));
+    assert!(!is_meld(&cards("7♣ 7♦")));
+    assert!(!is_meld(&cards("5♣ 6♣ 7♦")));
+    assert!(!is_meld(&cards("5♣ 6♣ 8♣")));
+}
+
+#[test]
+fn test_lay_off() {
+    let (p1, p2) = (Player(0), Player(1));
+    let mut run = Meld::new(p1, &cards("3♡ 2♡ 4♡")).unwrap();
+    assert_eq!(run.kind(), MeldKind::Run(Suit::Heart));
+    assert!(run.fits(cards("A♡")[0]));
+    assert!(run.fits(cards("5♡")[0]));
+    assert!(!run.fits(cards("6♡")[0]));
+    assert!(!run.fits(cards("5♣")[0]));
+    run.lay_off(p2, cards("A♡")[0]);
+    assert_eq!(run.to_string(), "A♡ 2♡ 3♡ 4♡");
+    assert_eq!((run.points(p1), run.points(p2)), (9, 5));
+
+    let mut run = Meld::new(p1, &cards("J♤ Q♤ K♤")).unwrap();
+    run.lay_off(p2, cards("A♤")[0]);
+    assert_eq!(run.points(p2), 15);
+    assert!(!run.fits(cards("2♤")[0]));
+
+    let mut set = Meld::new(p1, &cards("A♣ A♦ A♡")).unwrap();
+    assert_eq!(set.kind(), MeldKind::Set(Rank::Ace));
+    assert_eq!(set.points(p1), 45);
+    set.lay_off(p2, cards("A♤")[0]);
+    assert!(!set.fits(cards("A♤")[0]));
+}
+
+#[test]
+fn test_find_melds() {
+    let melds = find_melds(&cards("A♣ 2♣ 3♣ 4♣ K♣ Q♣ 9♦ 9♡ 9♤"));
+    let expected = [
+        "A♣ 2♣ 3♣",
+        "A♣ 2♣ 3♣ 4♣",
+        "2♣ 3♣ 4♣",
+        "Q♣ K♣ A♣",
+        "9♦ 9♡ 9♤",
+    ];
+    assert_eq!(melds.len(), expected.len());
+    for meld in expected.iter().map(|m| cards(m)) {
+        assert!(melds.iter().any(|m| m == &meld));
+    }
+    assert!(melds.iter().all(|m| is_meld(m)));
+}
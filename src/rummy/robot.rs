@@ -0,0 +1,103 @@
+//! A robot player.
+//!
+//! The robot takes from the discard pile when it can do so without picking up more than a few
+//! cards, and otherwise draws. It melds as much as it can, as soon as it can, and lays off every
+//! card that fits a meld on the table. It discards its highest card that isn't close to making a
+//! meld.
+
+use std::cmp::Reverse;
+
+use super::{card_value, Action, Card, Phase, RoundView};
+
+#[cfg(test)]
+mod test;
+
+/// The most cards the robot will take from the pile at once.
+const MAX_TAKE: usize = 3;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &RoundView, legal: &[Action]) -> Action {
+        let choice = match view.phase {
+            Phase::Draw => take(view, legal),
+            Phase::Play => meld(legal)
+                .or_else(|| lay_off(legal))
+                .or_else(|| discard(view, legal)),
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0].clone())
+    }
+}
+
+/// Takes the shallowest card that may be taken from the pile, if it's near enough to the top,
+/// and draws otherwise.
+fn take(view: &RoundView, legal: &[Action]) -> Option<Action> {
+    let depth = |card: Card| view.pile.len() - view.pile.iter().position(|&c| c == card).unwrap();
+    let shallowest = legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Take(card) => Some(*card),
+            _ => None,
+        })
+        .min_by_key(|&card| depth(card));
+    match shallowest {
+        Some(card) if depth(card) <= MAX_TAKE || !legal.contains(&Action::Draw) => {
+            Some(Action::Take(card))
+        }
+        _ => Some(Action::Draw),
+    }
+}
+
+/// Makes the meld with the most cards, and the highest count.
+fn meld(legal: &[Action]) -> Option<Action> {
+    legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Meld(cards) => Some(cards),
+            _ => None,
+        })
+        .max_by_key(|cards| {
+            let count: i32 = cards.iter().copied().map(card_value).sum();
+            (cards.len(), count)
+        })
+        .map(|cards| Action::Meld(cards.clone()))
+}
+
+/// Lays off the first card that fits a meld.
+fn lay_off(legal: &[Action]) -> Option<Action> {
+    legal
+        .iter()
+        .find(|a| matches!(a, Action::LayOff(..)))
+        .cloned()
+}
+
+/// Returns true if the card is a step away from a meld: paired with another card of its rank,
+/// or close to another card of its suit.
+fn is_near_meld(card: Card, hand: &[Card]) -> bool {
+    let rank = |c: Card| c.rank().map(|r| r.ace_high());
+    hand.iter().any(|&other| {
+        other != card
+            && (other.rank() == card.rank()
+                || (other.suit() == card.suit()
+                    && rank(other)
+                        .zip(rank(card))
+                        .is_some_and(|(a, b)| a.abs_diff(b) <= 2)))
+    })
+}
+
+/// Discards the highest card that isn't near a meld.
+fn discard(view: &RoundView, legal: &[Action]) -> Option<Action> {
+    legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Discard(card) => Some(*card),
+            _ => None,
+        })
+        .min_by_key(|&card| (is_near_meld(card, view.hand), Reverse(card_value(card))))
+        .map(Action::Discard)
+}
@@ -0,0 +1,41 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::rummy::{winner, Player, Round, RoundConfig};
+
+#[test]
+fn test_is_near_meld() {
+    let hand = cards("5♣ 7♣ K♦ K♡ 2♤ A♤");
+    assert!(is_near_meld(cards("5♣")[0], &hand));
+    assert!(is_near_meld(cards("K♦")[0], &hand));
+    assert!(!is_near_meld(cards("2♤")[0], &hand));
+    assert!(!is_near_meld(cards("A♤")[0], &hand));
+}
+
+#[test]
+fn test_robots() {
+    for seed in 0..100 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let players = 2 + (seed % 5) as u8;
+        let mut scores = vec![0; usize::from(players)];
+        let mut dealer = Player(0);
+        let mut rounds = 0;
+        while winner(&scores).is_none() && rounds < 100 {
+            let config = RoundConfig::random(dealer, players, &mut rng).unwrap();
+            let mut round = Round::from(config);
+            let result = round
+                .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+                .unwrap()
+                .expect("round is over");
+            for (score, result) in scores.iter_mut().zip(&result.scores) {
+                *score += result.total();
+            }
+            dealer = dealer.next(players);
+            rounds += 1;
+        }
+        assert!(winner(&scores).is_some());
+    }
+}
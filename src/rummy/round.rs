@@ -0,0 +1,702 @@
+//! A single round: from the deal until a player goes out, or the stock runs out.
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{card_value, deck, find_melds, format_cards, is_meld, Card, Meld, Player, RummyError};
+use crate::deck::{Deck, Pile, Stock, Waste};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of cards dealt to each player: thirteen with two players, and seven otherwise.
+fn hand_size(players: u8) -> usize {
+    if players == 2 {
+        13
+    } else {
+        7
+    }
+}
+
+/// The order of the deck, and the players at the table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundConfig {
+    dealer: Player,
+    players: u8,
+    deck: Vec<Card>,
+}
+
+impl RoundConfig {
+    /// Shuffles the deck.
+    pub fn random<R: Rng + ?Sized>(
+        dealer: Player,
+        players: u8,
+        rng: &mut R,
+    ) -> Result<Self, RummyError> {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        Self::new(dealer, players, deck.into_iter().collect())
+    }
+
+    /// Creates a round from the specified deck, which must hold the cards of a standard deck.
+    /// The last card is the top of the deck.
+    pub fn new(dealer: Player, players: u8, deck: Vec<Card>) -> Result<Self, RummyError> {
+        let config = Self {
+            dealer,
+            players,
+            deck,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks the number of players, and that the deck holds exactly the cards of a standard
+    /// deck.
+    pub fn validate(&self) -> Result<(), RummyError> {
+        if !(2..=6).contains(&self.players) || self.dealer.0 >= self.players {
+            return Err(RummyError::InvalidPlayers(self.players));
+        }
+        let expected: HashSet<Card> = deck().into_iter().collect();
+        let actual: HashSet<Card> = self.deck.iter().copied().collect();
+        if self.deck.len() != expected.len() || actual != expected {
+            return Err(RummyError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer. The player to their left plays first.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The number of players.
+    pub fn players(&self) -> u8 {
+        self.players
+    }
+}
+
+/// The stage of a player's turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// The player must draw from the stock, or take from the discard pile.
+    Draw,
+    /// The player may meld and lay off, and must then discard, unless they go out.
+    Play,
+}
+
+/// An action taken during a player's turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Draws the top card of the stock.
+    Draw,
+    /// Takes the card from the discard pile, along with every card above it. The card must be
+    /// played at once.
+    Take(Card),
+    /// Melds the cards.
+    Meld(Vec<Card>),
+    /// Lays off the card on the meld at the specified index.
+    LayOff(Card, usize),
+    /// Discards a card, ending the turn.
+    Discard(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Draw => f.write_str(&tr!("game-action-draw")),
+            Action::Take(card) => f.write_str(&tr!("game-action-take", cards = card.to_string())),
+            Action::Meld(cards) => {
+                f.write_str(&tr!("game-action-meld", cards = format_cards(cards)))
+            }
+            Action::LayOff(card, i) => {
+                let card = card.to_string();
+                f.write_str(&tr!("rummy-action-lay-off", card = card, meld = i + 1))
+            }
+            Action::Discard(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A player's score for a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PlayerScore {
+    /// The count of the cards the player melded and laid off.
+    pub melds: i32,
+    /// The count of the cards left in the player's hand, which is negative.
+    pub hand: i32,
+}
+
+impl PlayerScore {
+    /// The player's total for the round.
+    pub fn total(&self) -> i32 {
+        self.melds + self.hand
+    }
+}
+
+impl Display for PlayerScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = tr!(
+            "rummy-player-score",
+            total = self.total(),
+            melds = self.melds,
+            hand = self.hand
+        );
+        f.write_str(&text)
+    }
+}
+
+/// The result of a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// The player who went out, or `None` if the stock ran out.
+    pub went_out: Option<Player>,
+    /// Each player's score for the round.
+    pub scores: Vec<PlayerScore>,
+}
+
+impl Display for RoundResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.went_out {
+            Some(player) => f.write_str(&tr!("game-went-out", player = player.to_string()))?,
+            None => f.write_str(&tr!("game-stock-ran-out"))?,
+        }
+        for (i, score) in self.scores.iter().enumerate() {
+            write!(f, "\n{}: {score}", tr!("engine-player", n = i + 1))?;
+        }
+        Ok(())
+    }
+}
+
+/// An event that occurs during a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// The card turned up to start the discard pile.
+    Upcard(Card),
+    /// A player drew from the stock.
+    Draw(Player),
+    /// A player took cards from the discard pile, the first of which they must play.
+    Take(Player, Vec<Card>),
+    /// A player melded.
+    Meld(Player, Meld),
+    /// A player laid off a card, resulting in the meld.
+    LayOff(Player, Card, Meld),
+    /// A player discarded.
+    Discard(Player, Card),
+    /// A player went out.
+    Out(Player),
+    /// The round is over.
+    Over(RoundResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Upcard(card) => f.write_str(&tr!("game-upcard", card = card.to_string())),
+            Event::Draw(player) => f.write_str(&tr!("game-drew", player = player.to_string())),
+            Event::Take(player, cards) => {
+                let (player, cards) = (player.to_string(), cards.iter().join(" "));
+                f.write_str(&tr!("game-took", player = player, cards = cards))
+            }
+            Event::Meld(player, meld) => {
+                let (player, meld) = (player.to_string(), meld.to_string());
+                f.write_str(&tr!("game-melded", player = player, meld = meld))
+            }
+            Event::LayOff(player, card, meld) => {
+                let (player, card, meld) = (player.to_string(), card.to_string(), meld.to_string());
+                let text = tr!("rummy-laid-off", player = player, card = card, meld = meld);
+                f.write_str(&text)
+            }
+            Event::Discard(player, card) => {
+                let (player, card) = (player.to_string(), card.to_string());
+                f.write_str(&tr!("game-discarded", player = player, card = card))
+            }
+            Event::Out(player) => f.write_str(&tr!("game-went-out", player = player.to_string())),
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the round, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct RoundView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The player's cards.
+    pub hand: &'a [Card],
+    /// The number of cards held by each player.
+    pub hand_sizes: Vec<usize>,
+    /// The melds on the table.
+    pub melds: &'a [Meld],
+    /// The number of cards in the stock.
+    pub stock: usize,
+    /// The discard pile, from bottom to top.
+    pub pile: Vec<Card>,
+    /// The player whose turn it is, or `None` if the round is over.
+    pub to_play: Option<Player>,
+    /// The stage of their turn.
+    pub phase: Phase,
+    /// The card taken from the pile, which must be played before anything else.
+    pub must_play: Option<Card>,
+}
+
+impl Display for RoundView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        for (i, meld) in self.melds.iter().enumerate() {
+            writeln!(f, "{}: {meld}", i + 1)?;
+        }
+        let (stock, pile) = (self.stock, self.pile.iter().join(" "));
+        writeln!(f, "{}", tr!("game-stock-pile", stock = stock, pile = pile))?;
+        let sizes = self
+            .hand_sizes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != self.player.index())
+            .map(|(i, n)| format!("{} {n}", tr!("engine-player-abbr", n = i + 1)))
+            .join(", ");
+        writeln!(f, "{}", tr!("game-cards-held", sizes = sizes))?;
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))?;
+        if let Some(card) = self.must_play {
+            write!(f, "\n{}", tr!("rummy-must-play", card = card.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// The state of a round.
+#[derive(Debug, Clone)]
+pub struct Round {
+    config: RoundConfig,
+    hands: Vec<Vec<Card>>,
+    stock: Stock<Card>,
+    pile: Waste<Card>,
+    melds: Vec<Meld>,
+    turn: Player,
+    phase: Phase,
+    must_play: Option<Card>,
+    went_out: Option<Player>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<RoundConfig> for Round {
+    fn from(config: RoundConfig) -> Self {
+        let stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let turn = config.dealer.next(config.players);
+        let mut round = Self {
+            hands: vec![vec![]; usize::from(config.players)],
+            config,
+            stock,
+            pile: Waste::new(),
+            melds: vec![],
+            turn,
+            phase: Phase::Draw,
+            must_play: None,
+            went_out: None,
+            actions: vec![],
+            events: VecDeque::new(),
+        };
+        round.deal();
+        round
+    }
+}
+
+impl Round {
+    /// Deals the hands, starting to the dealer's left, and turns up the first card of the pile.
+    fn deal(&mut self) {
+        let players = self.config.players;
+        let size = hand_size(players);
+        let mut player = self.turn;
+        for _ in 0..players {
+            let hand = (0..size).filter_map(|_| self.stock.draw()).collect();
+            self.hands[player.index()] = hand;
+            player = player.next(players);
+        }
+        if let Some(card) = self.stock.draw() {
+            self.pile.push(card);
+            self.events.push_back(Event::Upcard(card));
+        }
+    }
+
+    /// The round as it was dealt.
+    pub fn config(&self) -> &RoundConfig {
+        &self.config
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The melds on the table.
+    pub fn melds(&self) -> &[Meld] {
+        &self.melds
+    }
+
+    /// The stage of the current player's turn.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns true once a player has gone out, or the current player can neither draw nor take
+    /// from the pile.
+    fn is_over(&self) -> bool {
+        self.went_out.is_some()
+            || (self.phase == Phase::Draw
+                && self.stock.is_empty()
+                && self.take_options().is_empty())
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        (!self.is_over()).then_some(self.turn)
+    }
+
+    /// Returns the state of the round, as visible to the player.
+    pub fn view(&self, player: Player) -> RoundView<'_> {
+        RoundView {
+            player,
+            dealer: self.config.dealer,
+            hand: &self.hands[player.index()],
+            hand_sizes: self.hands.iter().map(Vec::len).collect(),
+            melds: &self.melds,
+            stock: self.stock.len(),
+            pile: self.pile.iter().copied().collect(),
+            to_play: self.next_actor(),
+            phase: self.phase,
+            must_play: self.must_play,
+        }
+    }
+
+    /// The current player's hand.
+    fn current_hand(&self) -> &[Card] {
+        &self.hands[self.turn.index()]
+    }
+
+    /// The cards that would be taken from the pile along with the card, starting with the card
+    /// itself.
+    fn taken_with(&self, card: Card) -> Option<Vec<Card>> {
+        let i = self.pile.iter().position(|&c| c == card)?;
+        Some(self.pile.iter().skip(i).copied().collect())
+    }
+
+    /// The cards in the pile that may be taken.
+    fn take_options(&self) -> Vec<Card> {
+        self.pile
+            .iter()
+            .copied()
+            .filter(|&card| self.check_take(card).is_ok())
+            .collect()
+    }
+
+    fn check_draw(&self) -> Result<(), RummyError> {
+        if self.phase != Phase::Draw {
+            return Err(RummyError::AlreadyDrew);
+        }
+        if self.stock.is_empty() {
+            return Err(RummyError::StockEmpty);
+        }
+        Ok(())
+    }
+
+    /// Checks that the player may take the card from the pile. They must be able to play it at
+    /// once, either by laying it off, or by melding it with cards from their hand and the others
+    /// they'd take with it.
+    fn check_take(&self, card: Card) -> Result<(), RummyError> {
+        if self.phase != Phase::Draw {
+            return Err(RummyError::AlreadyDrew);
+        }
+        let Some(taken) = self.taken_with(card) else {
+            return Err(RummyError::NotInPile(card));
+        };
+        if self.melds.iter().any(|m| m.fits(card)) {
+            return Ok(());
+        }
+        let mut cards = self.current_hand().to_vec();
+        cards.extend(taken);
+        if find_melds(&cards).iter().any(|m| m.contains(&card)) {
+            Ok(())
+        } else {
+            Err(RummyError::CannotTake(card))
+        }
+    }
+
+    /// Checks that the player holds every card once.
+    fn check_held(&self, cards: &[Card]) -> Result<(), RummyError> {
+        let hand = self.current_hand();
+        let mut seen = HashSet::new();
+        for &card in cards {
+            if !hand.contains(&card) || !seen.insert(card) {
+                return Err(RummyError::CardNotHeld(self.turn, card));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_meld(&self, cards: &[Card]) -> Result<(), RummyError> {
+        if self.phase != Phase::Play {
+            return Err(RummyError::MustDraw);
+        }
+        self.check_held(cards)?;
+        if !is_meld(cards) {
+            return Err(RummyError::InvalidMeld);
+        }
+        match self.must_play {
+            Some(card) if !cards.contains(&card) => Err(RummyError::MustPlay(card)),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_lay_off(&self, card: Card, i: usize) -> Result<(), RummyError> {
+        if self.phase != Phase::Play {
+            return Err(RummyError::MustDraw);
+        }
+        self.check_held(&[card])?;
+        if !self.melds.get(i).is_some_and(|m| m.fits(card)) {
+            return Err(RummyError::CannotLayOff(card));
+        }
+        match self.must_play {
+            Some(must) if must != card => Err(RummyError::MustPlay(must)),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_discard(&self, card: Card) -> Result<(), RummyError> {
+        if self.phase != Phase::Play {
+            return Err(RummyError::MustDraw);
+        }
+        if let Some(must) = self.must_play {
+            return Err(RummyError::MustPlay(must));
+        }
+        self.check_held(&[card])
+    }
+
+    /// Returns the actions the current player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.is_over() {
+            return vec![];
+        }
+        let mut actions = vec![];
+        match self.phase {
+            Phase::Draw => {
+                if self.check_draw().is_ok() {
+                    actions.push(Action::Draw);
+                }
+                actions.extend(self.take_options().into_iter().map(Action::Take));
+            }
+            Phase::Play => {
+                let mut hand = self.current_hand().to_vec();
+                hand.sort_by_key(|c| (c.suit(), c.rank()));
+                actions.extend(
+                    find_melds(&hand)
+                        .into_iter()
+                        .filter(|m| self.check_meld(m).is_ok())
+                        .map(Action::Meld),
+                );
+                for &card in &hand {
+                    actions.extend(
+                        (0..self.melds.len())
+                            .filter(|&i| self.check_lay_off(card, i).is_ok())
+                            .map(|i| Action::LayOff(card, i)),
+                    );
+                }
+                actions.extend(
+                    hand.into_iter()
+                        .filter(|&c| self.check_discard(c).is_ok())
+                        .map(Action::Discard),
+                );
+            }
+        }
+        actions
+    }
+
+    /// Applies the current player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), RummyError> {
+        if self.is_over() {
+            return Err(RummyError::RoundOver);
+        }
+        match &action {
+            Action::Draw => self.draw()?,
+            Action::Take(card) => self.take(*card)?,
+            Action::Meld(cards) => self.meld(cards)?,
+            Action::LayOff(card, i) => self.lay_off(*card, *i)?,
+            Action::Discard(card) => self.discard(*card)?,
+        }
+        self.actions.push(action);
+        if let Some(result) = self.result() {
+            self.events.push_back(Event::Over(result));
+        }
+        Ok(())
+    }
+
+    /// Removes the cards from the current player's hand.
+    fn remove_cards(&mut self, cards: &[Card]) {
+        self.hands[self.turn.index()].retain(|c| !cards.contains(c));
+    }
+
+    /// Ends the round if the player has no cards left.
+    fn check_out(&mut self) {
+        let player = self.turn;
+        if self.hands[player.index()].is_empty() {
+            self.went_out = Some(player);
+            self.events.push_back(Event::Out(player));
+        }
+    }
+
+    fn draw(&mut self) -> Result<(), RummyError> {
+        self.check_draw()?;
+        let card = self.stock.draw().expect("the stock is not empty");
+        self.hands[self.turn.index()].push(card);
+        self.events.push_back(Event::Draw(self.turn));
+        self.phase = Phase::Play;
+        Ok(())
+    }
+
+    fn take(&mut self, card: Card) -> Result<(), RummyError> {
+        self.check_take(card)?;
+        let taken = self.taken_with(card).expect("card is in the pile");
+        let mut pile = Pile::new();
+        self.pile
+            .move_to(&mut pile, taken.len())
+            .expect("the pile is face up");
+        self.hands[self.turn.index()].extend_from_slice(&taken);
+        self.events.push_back(Event::Take(self.turn, taken));
+        self.must_play = Some(card);
+        self.phase = Phase::Play;
+        Ok(())
+    }
+
+    fn meld(&mut self, cards: &[Card]) -> Result<(), RummyError> {
+        self.check_meld(cards)?;
+        let meld = Meld::new(self.turn, cards).expect("cards form a meld");
+        self.remove_cards(cards);
+        self.events.push_back(Event::Meld(self.turn, meld.clone()));
+        self.melds.push(meld);
+        self.must_play = None;
+        self.check_out();
+        Ok(())
+    }
+
+    fn lay_off(&mut self, card: Card, i: usize) -> Result<(), RummyError> {
+        self.check_lay_off(card, i)?;
+        self.remove_cards(&[card]);
+        self.melds[i].lay_off(self.turn, card);
+        let event = Event::LayOff(self.turn, card, self.melds[i].clone());
+        self.events.push_back(event);
+        self.must_play = None;
+        self.check_out();
+        Ok(())
+    }
+
+    fn discard(&mut self, card: Card) -> Result<(), RummyError> {
+        self.check_discard(card)?;
+        let player = self.turn;
+        self.remove_cards(&[card]);
+        self.pile.push(card);
+        self.events.push_back(Event::Discard(player, card));
+        self.check_out();
+        if self.went_out.is_none() {
+            self.turn = player.next(self.config.players);
+            self.phase = Phase::Draw;
+        }
+        Ok(())
+    }
+
+    /// The result of the round, once it's over.
+    pub fn result(&self) -> Option<RoundResult> {
+        if !self.is_over() {
+            return None;
+        }
+        let scores = Player::all(self.config.players)
+            .map(|player| PlayerScore {
+                melds: self.melds.iter().map(|m| m.points(player)).sum(),
+                hand: -self.hands[player.index()]
+                    .iter()
+                    .copied()
+                    .map(card_value)
+                    .sum::<i32>(),
+            })
+            .collect();
+        Some(RoundResult {
+            went_out: self.went_out,
+            scores,
+        })
+    }
+}
+
+impl crate::engine::Engine for Round {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = RoundView<'a>;
+    type Event = Event;
+    type Outcome = RoundResult;
+    type Error = RummyError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> RoundView<'_> {
+        Round::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), RummyError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<RoundResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a round: the order of the deck, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRound {
+    config: RoundConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Round> for RawRound {
+    fn from(round: &Round) -> Self {
+        Self {
+            config: round.config.clone(),
+            actions: round.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawRound> for Round {
+    type Error = RummyError;
+
+    fn try_from(raw: RawRound) -> Result<Self, RummyError> {
+        raw.config.validate()?;
+        let mut round = Round::from(raw.config);
+        for action in raw.actions {
+            round.apply_action(action)?;
+        }
+        round.events.clear();
+        Ok(round)
+    }
+}
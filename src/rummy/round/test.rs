@@ -0,0 +1,204 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::rummy::Robot;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// Stacks the deck so that the players from the dealer's left are dealt the hands, followed by
+/// the upcard, and then the stock in order. Hands are topped up with cards that make no melds
+/// with any of the cards specified.
+fn stacked(players: u8, hands: &[&str], upcard: &str, stock: &str) -> RoundConfig {
+    let mut hands: Vec<Vec<Card>> = hands.iter().map(|h| cards(h)).collect();
+    hands.resize(usize::from(players), vec![]);
+    let upcard = card(upcard);
+    let stock = cards(stock);
+    let mut known: Vec<Card> = hands.iter().flatten().copied().collect();
+    known.push(upcard);
+    known.extend(&stock);
+    let mut rest: Vec<Card> = deck().into_iter().filter(|c| !known.contains(c)).collect();
+    for hand in &mut hands {
+        while hand.len() < hand_size(players) {
+            let melds = find_melds(&known).len();
+            let i = rest
+                .iter()
+                .position(|&c| {
+                    let mut cards = known.clone();
+                    cards.push(c);
+                    find_melds(&cards).len() == melds
+                })
+                .expect("a card that makes no melds");
+            let c = rest.remove(i);
+            known.push(c);
+            hand.push(c);
+        }
+    }
+    let mut deck = rest;
+    deck.extend(stock.into_iter().rev());
+    deck.push(upcard);
+    for hand in hands.into_iter().rev() {
+        deck.extend(hand);
+    }
+    RoundConfig::new(Player(players - 1), players, deck).unwrap()
+}
+
+#[test]
+fn test_invalid_config() {
+    let deck: Vec<Card> = crate::rummy::deck().into_iter().collect();
+    for players in [1, 7].iter().copied() {
+        let result = RoundConfig::new(Player(0), players, deck.clone());
+        assert!(matches!(result, Err(RummyError::InvalidPlayers(_))));
+    }
+    let result = RoundConfig::new(Player(3), 3, deck.clone());
+    assert!(matches!(result, Err(RummyError::InvalidPlayers(3))));
+    let mut short = deck.clone();
+    short.pop();
+    let result = RoundConfig::new(Player(0), 3, short.clone());
+    assert!(matches!(result, Err(RummyError::InvalidDeck)));
+    short.push(short[0]);
+    let result = RoundConfig::new(Player(0), 3, short);
+    assert!(matches!(result, Err(RummyError::InvalidDeck)));
+}
+
+#[test]
+fn test_deal() {
+    let mut round = Round::from(stacked(3, &["5♣"], "K♦", ""));
+    assert_eq!(round.hand(Player(0)).len(), 7);
+    assert!(round.hand(Player(0)).contains(&card("5♣")));
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    assert_eq!(round.pop_event(), Some(Event::Upcard(card("K♦"))));
+
+    let deck: Vec<Card> = crate::rummy::deck().into_iter().collect();
+    let round = Round::from(RoundConfig::new(Player(0), 2, deck.clone()).unwrap());
+    assert_eq!(round.view(Player(0)).hand_sizes, [13, 13]);
+    assert_eq!(round.next_actor(), Some(Player(1)));
+
+    let round = Round::from(RoundConfig::new(Player(5), 6, deck).unwrap());
+    let view = round.view(Player(2));
+    assert_eq!(view.hand_sizes, vec![7; 6]);
+    assert_eq!(view.stock, 52 - 43);
+    assert_eq!(view.pile.len(), 1);
+}
+
+#[test]
+fn test_take() {
+    let config = stacked(3, &["7♣ 8♣ 2♤ 2♦ K♦", "9♣"], "2♡", "3♤ 4♦");
+    let mut round = Round::from(config);
+    assert!(matches!(
+        round.apply_action(Action::Discard(card("K♦"))),
+        Err(RummyError::MustDraw)
+    ));
+    round.apply_action(Action::Draw).unwrap();
+    round.apply_action(Action::Discard(card("K♦"))).unwrap();
+    round.apply_action(Action::Draw).unwrap();
+    round.apply_action(Action::Discard(card("9♣"))).unwrap();
+    assert_eq!(round.view(Player(2)).pile, cards("2♡ K♦ 9♣"));
+    // Player 3 has nothing to meld with.
+    assert_eq!(round.legal_actions(), [Action::Draw]);
+    round.apply_action(Action::Draw).unwrap();
+    let discard = round.hand(Player(2))[0];
+    round.apply_action(Action::Discard(discard)).unwrap();
+
+    let legal = round.legal_actions();
+    assert!(legal.contains(&Action::Take(card("2♡"))));
+    assert!(legal.contains(&Action::Take(card("9♣"))));
+    assert!(!legal.contains(&Action::Take(card("K♦"))));
+    assert!(matches!(
+        round.apply_action(Action::Take(card("K♦"))),
+        Err(RummyError::CannotTake(_))
+    ));
+    assert!(matches!(
+        round.apply_action(Action::Take(card("5♤"))),
+        Err(RummyError::NotInPile(_))
+    ));
+
+    // Taking the deuce picks up every card above it, and the deuce must be played at once.
+    round.apply_action(Action::Take(card("2♡"))).unwrap();
+    assert!(round.view(Player(0)).pile.is_empty());
+    assert!(round.hand(Player(0)).contains(&card("9♣")));
+    assert!(round.hand(Player(0)).contains(&discard));
+    assert!(matches!(
+        round.apply_action(Action::Discard(card("K♦"))),
+        Err(RummyError::MustPlay(_))
+    ));
+    let legal = round.legal_actions();
+    assert_eq!(legal, [Action::Meld(cards("2♦ 2♤ 2♡"))]);
+    assert!(matches!(
+        round.apply_action(Action::Meld(cards("7♣ 8♣ 9♣"))),
+        Err(RummyError::MustPlay(_))
+    ));
+    round.apply_action(Action::Meld(cards("2♦ 2♤ 2♡"))).unwrap();
+    round.apply_action(Action::Meld(cards("7♣ 8♣ 9♣"))).unwrap();
+    assert_eq!(round.melds().len(), 2);
+    let legal = round.legal_actions();
+    assert!(legal.contains(&Action::Discard(card("K♦"))));
+}
+
+#[test]
+fn test_going_out() {
+    let hand = "5♡ 6♡ 7♡ 8♡ 9♤ 9♦ 9♣";
+    let mut round = Round::from(stacked(3, &[hand], "2♣", "4♡"));
+    round.apply_action(Action::Draw).unwrap();
+    round.apply_action(Action::Meld(cards("4♡ 5♡ 6♡"))).unwrap();
+    round.apply_action(Action::Meld(cards("9♤ 9♦ 9♣"))).unwrap();
+    let legal = round.legal_actions();
+    assert_eq!(
+        legal,
+        [
+            Action::LayOff(card("7♡"), 0),
+            Action::Discard(card("7♡")),
+            Action::Discard(card("8♡")),
+        ]
+    );
+    assert!(matches!(
+        round.apply_action(Action::LayOff(card("8♡"), 0)),
+        Err(RummyError::CannotLayOff(_))
+    ));
+    round.apply_action(Action::LayOff(card("7♡"), 0)).unwrap();
+    round.apply_action(Action::Discard(card("8♡"))).unwrap();
+    assert_eq!(round.next_actor(), None);
+    assert!(matches!(
+        round.apply_action(Action::Draw),
+        Err(RummyError::RoundOver)
+    ));
+
+    let count = |p: Player| -> i32 { round.hand(p).iter().copied().map(card_value).sum() };
+    let result = round.result().unwrap();
+    assert_eq!(result.went_out, Some(Player(0)));
+    assert_eq!(
+        result.scores,
+        [
+            PlayerScore {
+                melds: 4 + 5 + 6 + 7 + 27,
+                hand: 0,
+            },
+            PlayerScore {
+                melds: 0,
+                hand: -count(Player(1)),
+            },
+            PlayerScore {
+                melds: 0,
+                hand: -count(Player(2)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_raw_round() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut round = Round::from(RoundConfig::random(Player(1), 4, &mut rng).unwrap());
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("round is over");
+    let raw = serde_json::to_string(&RawRound::from(&round)).unwrap();
+    let replayed = Round::try_from(serde_json::from_str::<RawRound>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), round.actions());
+}
@@ -0,0 +1,121 @@
+//! Scopa.
+//!
+//! Two players are each dealt three cards from the 40-card Italian deck, and four more are laid
+//! face up on the table. The dealer's opponent plays first. On their turn, a player plays a card
+//! from their hand, and captures a card on the table of the same value, or failing that, a set of
+//! cards whose values add up to it. Capturing is compulsory, and a card that captures nothing
+//! stays on the table. Sweeping the table clean is a scopa, worth a point, unless it's the last
+//! play of the round. Once both hands are empty, three more cards are dealt to each player, until
+//! the deck runs out. The cards left on the table at the end go to the last player to capture.
+//!
+//! Players then score a point each for the most cards, the most coins, the seven of coins (the
+//! settebello), and the best primiera, as well as a point for every scopa. The first player to
+//! 11 points wins.
+
+mod error;
+mod robot;
+mod round;
+mod scoring;
+
+use std::convert::TryFrom;
+
+use itertools::Itertools;
+
+pub use self::error::ScopaError;
+pub use self::robot::Robot;
+pub use self::round::{Action, Event, RawRound, Round, RoundConfig, RoundResult, RoundView};
+pub use self::scoring::{primiera, primiera_value, Tally};
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::italian::{deck, format_cards, value, Card, Rank, Suit, COINS};
+use crate::tr;
+
+/// The score a player must reach to win the game.
+pub const GAME_SCORE: u32 = 11;
+
+/// The number of players.
+pub const PLAYERS: u8 = 2;
+
+/// The seven of coins, which scores a point for the player who captures it.
+pub fn settebello() -> Card {
+    Card::new(Rank::Seven, COINS)
+}
+
+/// Returns the winner, once a player has reached the game score. If both have, the higher score
+/// wins, and there's no winner until a tie is broken.
+pub fn winner(scores: &[u32]) -> Option<Player> {
+    let best = scores.iter().copied().max()?;
+    let leaders: Vec<usize> = scores.iter().positions(|&s| s == best).collect();
+    match leaders[..] {
+        [i] if best >= GAME_SCORE => Some(Player(u8::try_from(i).ok()?)),
+        _ => None,
+    }
+}
+
+/// Plays a game with a simple command-line interface, with the user as the first player and a
+/// robot as the second. The record of every round is written to stderr when the game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = vec![0; usize::from(PLAYERS)];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let mut rounds = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    loop {
+        let mut round = Round::from(RoundConfig::random(dealer, &mut rng));
+        let result = console
+            .play(&mut round, me, |round, player, legal| {
+                robot.choose(&round.view(player), &legal)
+            })?
+            .expect("round is over");
+        for (score, points) in scores.iter_mut().zip(&result.points) {
+            *score += points;
+        }
+        rounds.push(RawRound::from(&round));
+        console.println(tr!("game-score", score = scores.iter().join("-")))?;
+        if let Some(player) = winner(&scores) {
+            console.println(tr!("game-wins", player = player.to_string()))?;
+            break;
+        }
+        dealer = dealer.next(PLAYERS);
+    }
+    serde_json::to_writer(std::io::stderr(), &rounds)?;
+    Ok(())
+}
+
+/// Plays a game in a rich terminal UI, with the user as the first player and a robot as the
+/// second.
+#[cfg(feature = "tui")]
+pub fn tui_main() -> anyhow::Result<()> {
+    let mut tui = crate::engine::tui::Tui::new("Scopa")?;
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = vec![0; usize::from(PLAYERS)];
+    let mut dealer = Player(rand::random::<u8>() % PLAYERS);
+    let me = Player(0);
+    tui.println(tr!("game-you-are", player = me.to_string()));
+    loop {
+        let mut round = Round::from(RoundConfig::random(dealer, &mut rng));
+        let Some(result) = tui.play(&mut round, me, |round, player, legal| {
+            robot.choose(&round.view(player), &legal)
+        })?
+        else {
+            return Ok(());
+        };
+        for (score, points) in scores.iter_mut().zip(&result.points) {
+            *score += points;
+        }
+        tui.println(tr!("game-score", score = scores.iter().join("-")));
+        if let Some(player) = winner(&scores) {
+            tui.println(tr!("game-wins", player = player.to_string()));
+            tui.pause(&tr!("ui-game-over"))?;
+            return Ok(());
+        }
+        if !tui.pause(&tr!("engine-next-round"))? {
+            return Ok(());
+        }
+        dealer = dealer.next(PLAYERS);
+    }
+}
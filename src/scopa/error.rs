@@ -0,0 +1,29 @@
+//! Errors
+
+use super::{Card, Player};
+
+/// An error that can occur during a round.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ScopaError {
+    /// The deck isn't the 40-card Italian deck.
+    #[error("the deck must hold the 40 cards of the Italian deck")]
+    InvalidDeck,
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The card isn't on the table, or was named twice.
+    #[error("{0} is not on the table")]
+    NotOnTable(Card),
+    /// The card could capture, so it may not be left on the table.
+    #[error("{0} must capture")]
+    MustCapture(Card),
+    /// A card of the same value is on the table, and must be captured instead.
+    #[error("{0} must capture a card of the same value")]
+    MustMatch(Card),
+    /// The captured cards don't add up to the value of the card played.
+    #[error("{0} cannot capture those cards")]
+    InvalidCapture(Card),
+    /// The round is over, no more actions are expected.
+    #[error("round is over")]
+    RoundOver,
+}
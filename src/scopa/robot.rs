@@ -0,0 +1,60 @@
+//! A robot player.
+//!
+//! The robot weighs each play by the cards it captures: a scopa above all, then the settebello,
+//! sevens and sixes for the primiera, coins, and the number of cards. When it can't capture, it
+//! leaves the card it values least, avoiding leaving the table open to a sweep.
+
+use super::{primiera_value, settebello, value, Action, Card, RoundView, COINS};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &RoundView, legal: &[Action]) -> Action {
+        legal
+            .iter()
+            .max_by_key(|a| score(view, a))
+            .cloned()
+            .unwrap_or_else(|| legal[0].clone())
+    }
+}
+
+/// The worth of a card to whoever captures it.
+fn worth(card: Card) -> i32 {
+    let mut worth = 1;
+    if card == settebello() {
+        worth += 8;
+    }
+    if card.suit() == Some(COINS) {
+        worth += 1;
+    }
+    if primiera_value(card) >= 18 {
+        worth += 2;
+    }
+    worth
+}
+
+/// Scores a play: the worth of the cards captured, or for a card left on the table, less its
+/// worth, and less again if the table could then be swept by a single card.
+fn score(view: &RoundView, action: &Action) -> i32 {
+    if action.capture.is_empty() {
+        let total: u32 = view.table.iter().map(|&c| u32::from(value(c))).sum::<u32>()
+            + u32::from(value(action.card));
+        let exposed = if total <= 10 { 5 } else { 0 };
+        return -worth(action.card) - exposed;
+    }
+    let scopa = action.capture.len() == view.table.len() && !view.is_last_play();
+    let captured: i32 = action
+        .capture
+        .iter()
+        .copied()
+        .chain([action.card])
+        .map(worth)
+        .sum();
+    captured + if scopa { 20 } else { 0 }
+}
@@ -0,0 +1,61 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::italian::cards;
+use crate::scopa::{Player, Round, RoundConfig};
+
+fn view_of<'a>(hand: &'a [Card], table: &'a [Card], captured: &'a [Vec<Card>]) -> RoundView<'a> {
+    RoundView {
+        player: Player(0),
+        dealer: Player(1),
+        hand,
+        opponent_hand: 3,
+        table,
+        captured,
+        scopas: &[0, 0],
+        stock: 20,
+        to_play: Some(Player(0)),
+    }
+}
+
+#[test]
+fn test_choose() {
+    let captured = vec![vec![], vec![]];
+    let hand = cards("7♦ 5♣ K♤");
+    let table = cards("2♣ 5♡ 7♣");
+    let view = view_of(&hand, &table, &captured);
+    // The seven of clubs counts for more than the two and the five together.
+    let legal = [
+        Action::new(hand[0], cards("7♣")),
+        Action::new(hand[0], cards("2♣ 5♡")),
+        Action::new(hand[1], cards("5♡")),
+        Action::new(hand[2], vec![]),
+    ];
+    assert_eq!(Robot.choose(&view, &legal), legal[0]);
+    // A scopa beats everything.
+    let table = cards("3♣ 4♡");
+    let view = view_of(&hand, &table, &captured);
+    let legal = [
+        Action::new(hand[0], cards("3♣ 4♡")),
+        Action::new(hand[1], vec![]),
+        Action::new(hand[2], vec![]),
+    ];
+    assert_eq!(Robot.choose(&view, &legal), legal[0]);
+}
+
+#[test]
+fn test_robots() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut scopas = 0;
+    for i in 0..50 {
+        let mut round = Round::from(RoundConfig::random(Player(i % 2), &mut rng));
+        let result = round
+            .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+            .unwrap()
+            .expect("round is over");
+        scopas += result.tallies.iter().map(|t| t.scopas).sum::<u32>();
+    }
+    assert!(scopas > 0);
+}
@@ -0,0 +1,501 @@
+//! A single round: from the first deal until the deck runs out, and every card is played.
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{deck, format_cards, value, Card, Player, ScopaError, Tally, PLAYERS};
+use crate::deck::{Deck, Stock};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of cards dealt to each player at a time.
+const HAND_SIZE: usize = 3;
+
+/// The number of cards laid face up on the table at the start of the round.
+const TABLE_SIZE: usize = 4;
+
+/// The order of the deck, and the dealer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundConfig {
+    dealer: Player,
+    deck: Vec<Card>,
+}
+
+impl RoundConfig {
+    /// Shuffles the deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        Self {
+            dealer,
+            deck: deck.into_iter().collect(),
+        }
+    }
+
+    /// Creates a round from the specified deck, which must hold the cards of the Italian deck.
+    /// The last card is the top of the deck.
+    pub fn new(dealer: Player, deck: Vec<Card>) -> Result<Self, ScopaError> {
+        let config = Self { dealer, deck };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that the deck holds exactly the cards of the Italian deck.
+    pub fn validate(&self) -> Result<(), ScopaError> {
+        let expected: HashSet<Card> = deck().into_iter().collect();
+        let actual: HashSet<Card> = self.deck.iter().copied().collect();
+        if self.dealer.0 >= PLAYERS || self.deck.len() != expected.len() || actual != expected {
+            return Err(ScopaError::InvalidDeck);
+        }
+        Ok(())
+    }
+
+    /// The dealer. Their opponent plays first.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+}
+
+/// Plays a card, capturing cards from the table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Action {
+    /// The card played.
+    pub card: Card,
+    /// The cards it captures, if any.
+    pub capture: Vec<Card>,
+}
+
+impl Action {
+    pub fn new(card: Card, capture: Vec<Card>) -> Self {
+        Self { card, capture }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.capture.is_empty() {
+            write!(f, "{}", self.card)
+        } else {
+            let (card, capture) = (self.card.to_string(), format_cards(&self.capture));
+            f.write_str(&tr!("scopa-action-take", card = card, capture = capture))
+        }
+    }
+}
+
+/// The result of a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// Each player's captures.
+    pub tallies: Vec<Tally>,
+    /// The points each player scores.
+    pub points: Vec<u32>,
+}
+
+impl Display for RoundResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = Player::all(PLAYERS).map(|p| {
+            let i = p.index();
+            let (points, tally) = (self.points[i], self.tallies[i].to_string());
+            tr!(
+                "scopa-player-points",
+                player = p.to_string(),
+                points = points,
+                tally = tally
+            )
+        });
+        write!(f, "{}", lines.format("\n"))
+    }
+}
+
+/// An event that occurs during a round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    /// Cards were laid face up on the table.
+    Table(Vec<Card>),
+    /// Players were dealt new hands, with the specified number of cards left in the deck.
+    Deal(usize),
+    /// A player played a card, capturing cards from the table.
+    Play(Player, Action),
+    /// A player swept the table.
+    Scopa(Player),
+    /// The last player to capture took the cards left on the table.
+    Rest(Player, Vec<Card>),
+    /// The round is over.
+    Over(RoundResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Table(cards) => f.write_str(&tr!("game-table", cards = cards.iter().join(" "))),
+            Event::Deal(left) => f.write_str(&tr!("scopa-dealt", left = *left)),
+            Event::Play(player, action) if action.capture.is_empty() => {
+                let (player, card) = (player.to_string(), action.card.to_string());
+                f.write_str(&tr!("game-played", player = player, card = card))
+            }
+            Event::Play(player, action) => {
+                let (player, card) = (player.to_string(), action.card.to_string());
+                let capture = action.capture.iter().join(" ");
+                let text = tr!(
+                    "scopa-captured",
+                    player = player,
+                    card = card,
+                    capture = capture
+                );
+                f.write_str(&text)
+            }
+            Event::Scopa(player) => f.write_str(&tr!("scopa-scopa", player = player.to_string())),
+            Event::Rest(player, cards) => {
+                let (player, cards) = (player.to_string(), cards.iter().join(" "));
+                f.write_str(&tr!("scopa-took-rest", player = player, cards = cards))
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of the round, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct RoundView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The player's cards.
+    pub hand: &'a [Card],
+    /// The number of cards held by their opponent.
+    pub opponent_hand: usize,
+    /// The cards on the table.
+    pub table: &'a [Card],
+    /// The cards each player has captured.
+    pub captured: &'a [Vec<Card>],
+    /// The number of scopas each player has made.
+    pub scopas: &'a [u32],
+    /// The number of cards left in the deck.
+    pub stock: usize,
+    /// The player whose turn it is, or `None` if the round is over.
+    pub to_play: Option<Player>,
+}
+
+impl RoundView<'_> {
+    /// Returns true if the player's turn is the last of the round, after which no scopa counts.
+    pub fn is_last_play(&self) -> bool {
+        self.stock == 0 && self.hand.len() + self.opponent_hand == 1
+    }
+}
+
+impl Display for RoundView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        writeln!(f, "{}", tr!("game-table", cards = format_cards(self.table)))?;
+        let captured = Player::all(PLAYERS)
+            .map(|p| {
+                let i = p.index();
+                let abbr = tr!("engine-player-abbr", n = i + 1);
+                format!("{abbr} {}", self.captured[i].len())
+            })
+            .join(", ");
+        let scopas = self.scopas.iter().join("-");
+        let text = tr!("scopa-captures", captured = captured, scopas = scopas);
+        writeln!(f, "{text}")?;
+        writeln!(f, "{}", tr!("scopa-deck", stock = self.stock))?;
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a round.
+#[derive(Debug, Clone)]
+pub struct Round {
+    config: RoundConfig,
+    hands: Vec<Vec<Card>>,
+    stock: Stock<Card>,
+    table: Vec<Card>,
+    captured: Vec<Vec<Card>>,
+    scopas: Vec<u32>,
+    turn: Player,
+    last_capture: Option<Player>,
+    over: bool,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<RoundConfig> for Round {
+    fn from(config: RoundConfig) -> Self {
+        let stock = Stock::from(Deck::with_cards(config.deck.clone()));
+        let players = usize::from(PLAYERS);
+        let mut round = Self {
+            hands: vec![vec![]; players],
+            stock,
+            table: vec![],
+            captured: vec![vec![]; players],
+            scopas: vec![0; players],
+            turn: config.dealer.next(PLAYERS),
+            last_capture: None,
+            over: false,
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        };
+        round.deal();
+        round.table = (0..TABLE_SIZE).filter_map(|_| round.stock.draw()).collect();
+        round.events.push_back(Event::Table(round.table.clone()));
+        round
+    }
+}
+
+impl Round {
+    /// Deals a hand to each player, starting with the dealer's opponent.
+    fn deal(&mut self) {
+        let mut player = self.config.dealer.next(PLAYERS);
+        for _ in 0..PLAYERS {
+            let hand = (0..HAND_SIZE).filter_map(|_| self.stock.draw()).collect();
+            self.hands[player.index()] = hand;
+            player = player.next(PLAYERS);
+        }
+    }
+
+    /// The round as it was dealt.
+    pub fn config(&self) -> &RoundConfig {
+        &self.config
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The cards on the table.
+    pub fn table(&self) -> &[Card] {
+        &self.table
+    }
+
+    /// The cards the player has captured.
+    pub fn captured(&self, player: Player) -> &[Card] {
+        &self.captured[player.index()]
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        (!self.over).then_some(self.turn)
+    }
+
+    /// Returns the state of the round, as visible to the player.
+    pub fn view(&self, player: Player) -> RoundView<'_> {
+        RoundView {
+            player,
+            dealer: self.config.dealer,
+            hand: &self.hands[player.index()],
+            opponent_hand: self.hands[player.next(PLAYERS).index()].len(),
+            table: &self.table,
+            captured: &self.captured,
+            scopas: &self.scopas,
+            stock: self.stock.len(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// The sets of cards on the table that the card may capture: each card of the same value, or
+    /// failing any, every set of cards whose values add up to it.
+    fn captures(&self, card: Card) -> Vec<Vec<Card>> {
+        let target = value(card);
+        let matches: Vec<Vec<Card>> = self
+            .table
+            .iter()
+            .filter(|&&c| value(c) == target)
+            .map(|&c| vec![c])
+            .collect();
+        if !matches.is_empty() {
+            return matches;
+        }
+        self.table
+            .iter()
+            .copied()
+            .powerset()
+            .filter(|cards| {
+                cards.len() > 1 && cards.iter().map(|&c| value(c)).sum::<u8>() == target
+            })
+            .collect()
+    }
+
+    fn check_play(&self, action: &Action) -> Result<(), ScopaError> {
+        let card = action.card;
+        if !self.hand(self.turn).contains(&card) {
+            return Err(ScopaError::CardNotHeld(self.turn, card));
+        }
+        let mut seen = HashSet::new();
+        for &c in &action.capture {
+            if !self.table.contains(&c) || !seen.insert(c) {
+                return Err(ScopaError::NotOnTable(c));
+            }
+        }
+        let captures = self.captures(card);
+        match action.capture.len() {
+            0 if !captures.is_empty() => Err(ScopaError::MustCapture(card)),
+            0 => Ok(()),
+            _ if captures
+                .iter()
+                .any(|c| seen == c.iter().copied().collect::<HashSet<_>>()) =>
+            {
+                Ok(())
+            }
+            n if n > 1 && captures.iter().any(|c| c.len() == 1) => Err(ScopaError::MustMatch(card)),
+            _ => Err(ScopaError::InvalidCapture(card)),
+        }
+    }
+
+    /// Returns the actions the current player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.over {
+            return vec![];
+        }
+        let mut hand = self.hand(self.turn).to_vec();
+        hand.sort_by_key(|&c| (value(c), c.suit()));
+        let mut actions = vec![];
+        for card in hand {
+            let captures = self.captures(card);
+            if captures.is_empty() {
+                actions.push(Action::new(card, vec![]));
+            }
+            actions.extend(captures.into_iter().map(|c| Action::new(card, c)));
+        }
+        actions
+    }
+
+    /// Applies the current player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), ScopaError> {
+        if self.over {
+            return Err(ScopaError::RoundOver);
+        }
+        self.check_play(&action)?;
+        let player = self.turn;
+        self.hands[player.index()].retain(|&c| c != action.card);
+        self.events.push_back(Event::Play(player, action.clone()));
+        if action.capture.is_empty() {
+            self.table.push(action.card);
+        } else {
+            self.table.retain(|c| !action.capture.contains(c));
+            let captured = &mut self.captured[player.index()];
+            captured.push(action.card);
+            captured.extend_from_slice(&action.capture);
+            self.last_capture = Some(player);
+        }
+        let last = self.stock.is_empty() && self.hands.iter().all(Vec::is_empty);
+        if !action.capture.is_empty() && self.table.is_empty() && !last {
+            self.scopas[player.index()] += 1;
+            self.events.push_back(Event::Scopa(player));
+        }
+        self.actions.push(action);
+        self.turn = player.next(PLAYERS);
+        if self.hands.iter().all(Vec::is_empty) {
+            if last {
+                self.finish();
+            } else {
+                self.deal();
+                self.events.push_back(Event::Deal(self.stock.len()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends the round, giving the cards left on the table to the last player to capture.
+    fn finish(&mut self) {
+        if let Some(player) = self.last_capture {
+            if !self.table.is_empty() {
+                let rest: Vec<Card> = self.table.drain(..).collect();
+                self.captured[player.index()].extend_from_slice(&rest);
+                self.events.push_back(Event::Rest(player, rest));
+            }
+        }
+        self.over = true;
+        let result = self.result().expect("round is over");
+        self.events.push_back(Event::Over(result));
+    }
+
+    /// The result of the round, once it's over.
+    pub fn result(&self) -> Option<RoundResult> {
+        if !self.over {
+            return None;
+        }
+        let tallies: Vec<Tally> = Player::all(PLAYERS)
+            .map(|p| Tally::new(&self.captured[p.index()], self.scopas[p.index()]))
+            .collect();
+        let points = Tally::points(&tallies);
+        Some(RoundResult { tallies, points })
+    }
+}
+
+impl crate::engine::Engine for Round {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = RoundView<'a>;
+    type Event = Event;
+    type Outcome = RoundResult;
+    type Error = ScopaError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> RoundView<'_> {
+        Round::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Round::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), ScopaError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<RoundResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a round: the order of the deck, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRound {
+    config: RoundConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Round> for RawRound {
+    fn from(round: &Round) -> Self {
+        Self {
+            config: round.config.clone(),
+            actions: round.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawRound> for Round {
+    type Error = ScopaError;
+
+    fn try_from(raw: RawRound) -> Result<Self, ScopaError> {
+        raw.config.validate()?;
+        let mut round = Round::from(raw.config);
+        for action in raw.actions {
+            round.apply_action(action)?;
+        }
+        round.events.clear();
+        Ok(round)
+    }
+}
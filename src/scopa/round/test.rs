@@ -0,0 +1,131 @@
+use std::convert::TryFrom;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::italian::cards;
+use crate::scopa::Robot;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// Stacks the deck so that the dealer's opponent and then the dealer are dealt the hands,
+/// followed by the table, and then the stock in order. The second player deals.
+fn stacked(hands: [&str; 2], table: &str, stock: &str) -> RoundConfig {
+    let hands = [cards(hands[0]), cards(hands[1])];
+    let table = cards(table);
+    let stock = cards(stock);
+    let stacked: Vec<Card> = hands
+        .iter()
+        .flatten()
+        .chain(&table)
+        .chain(&stock)
+        .copied()
+        .collect();
+    let mut deck: Vec<Card> = super::deck()
+        .into_iter()
+        .filter(|c| !stacked.contains(c))
+        .collect();
+    deck.extend(stacked.into_iter().rev());
+    RoundConfig::new(Player(1), deck).unwrap()
+}
+
+#[test]
+fn test_invalid_deck() {
+    let mut deck: Vec<Card> = super::deck().into_iter().collect();
+    deck.pop();
+    let result = RoundConfig::new(Player(0), deck);
+    assert!(matches!(result, Err(ScopaError::InvalidDeck)));
+}
+
+#[test]
+fn test_captures() {
+    let config = stacked(["5♣ 6♦ 7♤", "J♣ Q♣ K♣"], "A♣ 2♦ 3♤ 5♡", "");
+    let mut round = Round::from(config);
+    assert_eq!(round.next_actor(), Some(Player(0)));
+    assert_eq!(round.table(), &cards("A♣ 2♦ 3♤ 5♡")[..]);
+    // A card of the same value must be taken, in preference to a sum.
+    assert_eq!(
+        round.legal_actions(),
+        [
+            Action::new(card("5♣"), cards("5♡")),
+            Action::new(card("6♦"), cards("A♣ 5♡")),
+            Action::new(card("6♦"), cards("A♣ 2♦ 3♤")),
+            Action::new(card("7♤"), cards("2♦ 5♡")),
+        ]
+    );
+    let errors = [
+        (Action::new(card("5♣"), vec![]), "must capture"),
+        (Action::new(card("5♣"), cards("2♦ 3♤")), "same value"),
+        (Action::new(card("6♦"), cards("A♣ 2♦")), "cannot capture"),
+        (Action::new(card("6♦"), cards("6♣")), "not on the table"),
+        (Action::new(card("K♣"), vec![]), "does not hold"),
+    ];
+    for (action, message) in errors.iter().cloned() {
+        let err = round.apply_action(action).unwrap_err();
+        assert!(err.to_string().contains(message), "{}", err);
+    }
+    round
+        .apply_action(Action::new(card("6♦"), cards("A♣ 2♦ 3♤")))
+        .unwrap();
+    assert_eq!(round.table(), &cards("5♡")[..]);
+    assert_eq!(round.captured(Player(0)), &cards("6♦ A♣ 2♦ 3♤")[..]);
+    // Nothing on the table adds up to a face card, so it stays there.
+    assert_eq!(
+        round.legal_actions(),
+        [
+            Action::new(card("J♣"), vec![]),
+            Action::new(card("Q♣"), vec![]),
+            Action::new(card("K♣"), vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_scopa() {
+    let config = stacked(["K♣ 2♣ 3♣", "4♣ 5♣ 6♣"], "A♦ 2♦ 3♦ 4♦", "");
+    let mut round = Round::from(config);
+    while round.pop_event().is_some() {}
+    let sweep = Action::new(card("K♣"), cards("A♦ 2♦ 3♦ 4♦"));
+    assert!(round.legal_actions().contains(&sweep));
+    round.apply_action(sweep.clone()).unwrap();
+    assert_eq!(round.pop_event(), Some(Event::Play(Player(0), sweep)));
+    assert_eq!(round.pop_event(), Some(Event::Scopa(Player(0))));
+    assert_eq!(round.view(Player(1)).scopas, [1, 0]);
+    assert!(round.table().is_empty());
+}
+
+#[test]
+fn test_last_play() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut round = Round::from(RoundConfig::random(Player(0), &mut rng));
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("round is over");
+    // The cards left on the table go to the last player to capture.
+    let cards: usize = result.tallies.iter().map(|t| t.cards).sum();
+    assert_eq!(cards, 40);
+    assert!(round.table().is_empty());
+    assert!(matches!(
+        round.apply_action(Action::new(card("A♣"), vec![])),
+        Err(ScopaError::RoundOver)
+    ));
+}
+
+#[test]
+fn test_raw_round() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut round = Round::from(RoundConfig::random(Player(1), &mut rng));
+    let result = round
+        .play_out(|round, player, legal| Robot.choose(&round.view(player), &legal))
+        .unwrap()
+        .expect("round is over");
+    let raw = serde_json::to_string(&RawRound::from(&round)).unwrap();
+    let replayed = Round::try_from(serde_json::from_str::<RawRound>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), round.actions());
+}
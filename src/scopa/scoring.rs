@@ -0,0 +1,108 @@
+//! Scoring
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{settebello, value, Card, Suit, COINS};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The value of a card towards the primiera: 21 for a seven, 18 for a six, 16 for an ace, 15,
+/// 14, 13 and 12 for the five down to the two, and 10 for a face card.
+pub fn primiera_value(card: Card) -> u32 {
+    match value(card) {
+        7 => 21,
+        6 => 18,
+        1 => 16,
+        v @ 2..=5 => u32::from(v) + 10,
+        _ => 10,
+    }
+}
+
+/// The primiera of a set of cards: the sum of the best card in each suit, or nothing in a suit
+/// without any cards.
+pub fn primiera(cards: &[Card]) -> u32 {
+    Suit::all_suits()
+        .iter()
+        .map(|&suit| {
+            cards
+                .iter()
+                .filter(|c| c.suit() == Some(suit))
+                .map(|&c| primiera_value(c))
+                .max()
+                .unwrap_or(0)
+        })
+        .sum()
+}
+
+/// A player's captures for a round, counted towards the scoring categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Tally {
+    /// The number of cards captured.
+    pub cards: usize,
+    /// The number of coins captured.
+    pub coins: usize,
+    /// True if the seven of coins was captured.
+    pub settebello: bool,
+    /// The primiera of the cards captured.
+    pub primiera: u32,
+    /// The number of scopas made.
+    pub scopas: u32,
+}
+
+impl Tally {
+    /// Counts the captured cards.
+    pub fn new(captured: &[Card], scopas: u32) -> Self {
+        Self {
+            cards: captured.len(),
+            coins: captured.iter().filter(|c| c.suit() == Some(COINS)).count(),
+            settebello: captured.contains(&settebello()),
+            primiera: primiera(captured),
+            scopas,
+        }
+    }
+
+    /// The points each player scores: one each for the most cards, the most coins, the
+    /// settebello and the best primiera, with none for a tie, and one for each scopa.
+    pub fn points(tallies: &[Tally]) -> Vec<u32> {
+        fn best<K: Ord + Copy>(tallies: &[Tally], key: fn(&Tally) -> K) -> Option<usize> {
+            let max = tallies.iter().map(key).max()?;
+            let mut leaders = tallies.iter().enumerate().filter(|(_, t)| key(t) == max);
+            match (leaders.next(), leaders.next()) {
+                (Some((i, _)), None) => Some(i),
+                _ => None,
+            }
+        }
+        let mut points: Vec<u32> = tallies.iter().map(|t| t.scopas).collect();
+        let categories = [
+            best(tallies, |t| t.cards),
+            best(tallies, |t| t.coins),
+            best(tallies, |t| t.settebello),
+            best(tallies, |t| t.primiera),
+        ];
+        for i in categories.iter().flatten() {
+            points[*i] += 1;
+        }
+        points
+    }
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = tr!(
+            "scopa-tally",
+            cards = self.cards,
+            coins = self.coins,
+            primiera = self.primiera,
+            scopas = self.scopas
+        );
+        f.write_str(&text)?;
+        if self.settebello {
+            write!(f, ", {}", tr!("scopa-settebello"))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+use crate::italian::cards;
+
+#[test]
+fn test_primiera() {
+    assert_eq!(primiera(&cards("7♦ 7♡ 6♣ A♤")), 21 + 21 + 18 + 16);
+    // Only the best card in each suit counts, and a missing suit counts nothing.
+    assert_eq!(primiera(&cards("7♦ 6♦ K♡ 2♡")), 21 + 12);
+    assert_eq!(primiera(&[]), 0);
+}
+
+#[test]
+fn test_points() {
+    let first = Tally::new(&cards("7♦ 2♦ 3♦ K♣ Q♣"), 2);
+    let second = Tally::new(&cards("7♡ 7♣ 7♤ 4♦ 5♦"), 0);
+    assert!(first.settebello);
+    assert_eq!((first.coins, second.coins), (3, 2));
+    // Cards are tied, so nobody scores them; the second player has the better primiera.
+    assert_eq!(Tally::points(&[first, second]), [4, 1]);
+}
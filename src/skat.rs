@@ -0,0 +1,97 @@
+//! Skat.
+//!
+//! Skat is played by three players with a 32-card deck, from the sevens up to the aces. Each is
+//! dealt ten cards, and two are dealt face down to the skat. The players bid for the right to
+//! declare the game, naming values the game they intend to play will be worth. The declarer may
+//! pick up the skat and put two cards back, and then plays alone against the other two. In suit
+//! and grand games, the declarer must take more than half of the 120 card points, counting the
+//! skat; in a null game, they must lose every trick.
+
+mod auction;
+mod deal;
+mod error;
+mod game;
+mod robot;
+mod scoring;
+
+use itertools::Itertools;
+
+pub use self::auction::{Auction, Call};
+pub use self::deal::{
+    Action, Deal, DealConfig, DealResult, DealView, Event, Phase, RawDeal, Trick,
+};
+pub use self::error::SkatError;
+pub use self::game::{Contract, GameType};
+pub use self::robot::Robot;
+pub use self::scoring::{bid_values, Score};
+use crate::deck::Deck;
+use crate::engine::console::Console;
+pub use crate::engine::Player;
+pub use crate::french::{Card, DeckBuilder, Rank, Suit};
+use crate::tr;
+
+/// The number of deals in a game, so that each player deals three times.
+pub const DEALS: usize = 9;
+
+/// The 32-card deck with which skat is played.
+pub fn deck() -> Deck<Card> {
+    DeckBuilder::new()
+        .filter_ranks(|r| r == Rank::Ace || r >= Rank::Seven)
+        .build()
+}
+
+/// The card points of a card: 11 for an ace, 10 for a ten, 4 for a king, 3 for a queen, 2 for a
+/// jack, and nothing otherwise.
+pub fn card_points(card: Card) -> u8 {
+    match card.rank() {
+        Some(Rank::Ace) => 11,
+        Some(Rank::Ten) => 10,
+        Some(Rank::King) => 4,
+        Some(Rank::Queen) => 3,
+        Some(Rank::Jack) => 2,
+        _ => 0,
+    }
+}
+
+/// Formats cards with the jacks first, and then by suit, from highest to lowest.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .sorted_by_key(|&&c| game::sort_key(c))
+        .map(|c| c.to_string())
+        .join(" ")
+}
+
+/// Plays a game of nine deals with a simple command-line interface, with the user as the first
+/// of three players and robots as the others. The record of every deal is written to stderr
+/// when the game is over.
+pub fn cli_main() -> anyhow::Result<()> {
+    let mut console = Console::stdio();
+    let robot = Robot;
+    let mut rng = rand::thread_rng();
+    let mut scores = [0; 3];
+    let mut dealer = Player(rand::random::<u8>() % 3);
+    let mut deals = vec![];
+    let me = Player(0);
+    console.println(tr!("game-you-are-help", player = me.to_string()))?;
+    for _ in 0..DEALS {
+        let config = DealConfig::random(dealer, &mut rng);
+        let mut deal = Deal::from(config);
+        let result = console
+            .play(&mut deal, me, |deal, player, legal| {
+                robot.choose(&deal.view(player), &legal)
+            })?
+            .expect("deal is over");
+        for player in Player::all(3) {
+            scores[player.index()] += result.score(player);
+        }
+        deals.push(RawDeal::from(&deal));
+        let totals = Player::all(3)
+            .map(|p| format!("{p} {}", scores[p.index()]))
+            .join(", ");
+        console.println(tr!("game-score", score = totals))?;
+        dealer = dealer.next(3);
+    }
+    serde_json::to_writer(std::io::stderr(), &deals)?;
+    Ok(())
+}
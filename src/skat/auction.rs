@@ -0,0 +1,225 @@
+//! The auction.
+//!
+//! Middlehand bids first, to forehand, who holds each bid or passes. Whoever survives is then bid
+//! to by rearhand in the same way. If nobody has bid by then, the survivor may bid 18 or pass,
+//! and the deal is passed in. The last player left in becomes the declarer, at the highest value
+//! bid or held.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{bid_values, Player, SkatError};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// A call during the auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Call {
+    /// Bids the value.
+    Bid(u16),
+    /// Holds the value bid, as the player being bid to.
+    Hold,
+    /// Drops out of the auction.
+    Pass,
+}
+
+impl Display for Call {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Call::Bid(value) => write!(f, "{value}"),
+            Call::Hold => f.write_str(&tr!("skat-hold")),
+            Call::Pass => f.write_str(&tr!("action-pass")),
+        }
+    }
+}
+
+/// The state of the auction, replayed from the calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct State {
+    /// The player bidding.
+    bidder: Player,
+    /// The player being bid to, or `None` if the last player left is bidding alone.
+    listener: Option<Player>,
+    /// Whether the listener must answer a bid.
+    answering: bool,
+    /// The highest value bid so far.
+    value: Option<u16>,
+    /// Whether the auction is over.
+    complete: bool,
+}
+
+/// The sequence of calls made during the auction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Auction {
+    dealer: Player,
+    calls: Vec<Call>,
+}
+
+impl Auction {
+    /// Creates an empty auction.
+    pub fn new(dealer: Player) -> Self {
+        Self {
+            dealer,
+            calls: vec![],
+        }
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The player to the dealer's left, who leads to the first trick.
+    pub fn forehand(&self) -> Player {
+        self.dealer.next(3)
+    }
+
+    /// The calls made so far.
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Returns an iterator over the calls made so far, with the players that made them.
+    pub fn iter(&self) -> impl Iterator<Item = (Player, Call)> + '_ {
+        (0..self.calls.len()).map(move |i| {
+            let before = Auction {
+                dealer: self.dealer,
+                calls: self.calls[..i].to_vec(),
+            };
+            let player = before.next_player().expect("call was expected");
+            (player, self.calls[i])
+        })
+    }
+
+    /// Replays the calls.
+    fn state(&self) -> State {
+        let forehand = self.forehand();
+        let middlehand = forehand.next(3);
+        let rearhand = middlehand.next(3);
+        let mut state = State {
+            bidder: middlehand,
+            listener: Some(forehand),
+            answering: false,
+            value: None,
+            complete: false,
+        };
+        for &call in &self.calls {
+            let survivor = match (call, state.answering, state.listener) {
+                (Call::Bid(value), false, listener) => {
+                    state.value = Some(value);
+                    state.answering = listener.is_some();
+                    state.complete = listener.is_none();
+                    continue;
+                }
+                (Call::Hold, true, _) => {
+                    state.answering = false;
+                    continue;
+                }
+                (Call::Pass, true, _) => state.bidder,
+                (Call::Pass, false, Some(listener)) => listener,
+                (Call::Pass, false, None) => {
+                    // The last player left passed without a bid.
+                    state.complete = true;
+                    continue;
+                }
+                _ => unreachable!("calls are checked when made"),
+            };
+            state.answering = false;
+            if state.bidder == middlehand {
+                state.bidder = rearhand;
+                state.listener = Some(survivor);
+            } else if state.value.is_some() {
+                state.bidder = survivor;
+                state.complete = true;
+            } else {
+                state.bidder = survivor;
+                state.listener = None;
+            }
+        }
+        state
+    }
+
+    /// Returns true once the auction is over.
+    pub fn is_complete(&self) -> bool {
+        self.state().complete
+    }
+
+    /// Returns true if the auction ended without a bid.
+    pub fn is_passed_in(&self) -> bool {
+        let state = self.state();
+        state.complete && state.value.is_none()
+    }
+
+    /// The player expected to call next, or `None` if the auction is over.
+    pub fn next_player(&self) -> Option<Player> {
+        let state = self.state();
+        if state.complete {
+            None
+        } else if state.answering {
+            state.listener
+        } else {
+            Some(state.bidder)
+        }
+    }
+
+    /// The highest value bid so far.
+    pub fn value(&self) -> Option<u16> {
+        self.state().value
+    }
+
+    /// The declarer and the value of their bid, once the auction is over. Returns `None` if the
+    /// auction is still in progress, or if it was passed in.
+    pub fn declarer(&self) -> Option<(Player, u16)> {
+        let state = self.state();
+        if !state.complete {
+            return None;
+        }
+        Some((state.bidder, state.value?))
+    }
+
+    /// Returns true if the next player may make the specified call.
+    pub fn is_legal(&self, call: Call) -> bool {
+        Self::is_legal_in(self.state(), call, &bid_values())
+    }
+
+    fn is_legal_in(state: State, call: Call, values: &[u16]) -> bool {
+        if state.complete {
+            return false;
+        }
+        match call {
+            Call::Pass => true,
+            Call::Hold => state.answering,
+            Call::Bid(value) => {
+                !state.answering && state.value.is_none_or(|v| value > v) && values.contains(&value)
+            }
+        }
+    }
+
+    /// Returns every call the next player may make, starting with a pass or hold, and then bids
+    /// from lowest to highest.
+    pub fn legal_calls(&self) -> Vec<Call> {
+        let state = self.state();
+        let values = bid_values();
+        [Call::Pass, Call::Hold]
+            .iter()
+            .copied()
+            .chain(values.iter().copied().map(Call::Bid))
+            .filter(|&call| Self::is_legal_in(state, call, &values))
+            .collect()
+    }
+
+    /// Makes a call on behalf of the next player.
+    pub fn call(&mut self, call: Call) -> Result<(), SkatError> {
+        if self.is_complete() {
+            return Err(SkatError::AuctionOver);
+        }
+        if !self.is_legal(call) {
+            return Err(SkatError::IllegalCall(call));
+        }
+        self.calls.push(call);
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+fn auction(dealer: Player, calls: &[Call]) -> Auction {
+    let mut auction = Auction::new(dealer);
+    for &call in calls {
+        auction.call(call).unwrap();
+    }
+    auction
+}
+
+#[test]
+fn test_passed_in() {
+    let mut auction = auction(Player(2), &[Call::Pass, Call::Pass]);
+    assert!(!auction.is_complete());
+    // Forehand may still bid.
+    assert_eq!(auction.next_player(), Some(Player(0)));
+    assert!(!auction.is_legal(Call::Hold));
+    auction.call(Call::Pass).unwrap();
+    assert!(auction.is_passed_in());
+    assert_eq!(auction.next_player(), None);
+    assert_eq!(auction.declarer(), None);
+    assert!(matches!(
+        auction.call(Call::Pass),
+        Err(SkatError::AuctionOver)
+    ));
+
+    let auction = self::auction(Player(2), &[Call::Pass, Call::Pass, Call::Bid(18)]);
+    assert_eq!(auction.declarer(), Some((Player(0), 18)));
+}
+
+#[test]
+fn test_bidding() {
+    // Middlehand bids to forehand, who holds 18 and passes 20.
+    let mut auction = auction(Player(0), &[]);
+    assert_eq!(auction.forehand(), Player(1));
+    assert_eq!(auction.next_player(), Some(Player(2)));
+    assert!(!auction.is_legal(Call::Bid(19)));
+    assert!(!auction.is_legal(Call::Hold));
+    auction.call(Call::Bid(18)).unwrap();
+    assert_eq!(auction.next_player(), Some(Player(1)));
+    assert_eq!(auction.legal_calls(), [Call::Pass, Call::Hold]);
+    auction.call(Call::Hold).unwrap();
+    assert!(!auction.is_legal(Call::Bid(18)));
+    auction.call(Call::Bid(20)).unwrap();
+    auction.call(Call::Pass).unwrap();
+
+    // Rearhand then bids to middlehand, who passes.
+    assert_eq!(auction.next_player(), Some(Player(0)));
+    assert_eq!(auction.legal_calls()[..2], [Call::Pass, Call::Bid(22)]);
+    auction.call(Call::Bid(22)).unwrap();
+    assert_eq!(auction.next_player(), Some(Player(2)));
+    auction.call(Call::Hold).unwrap();
+    auction.call(Call::Pass).unwrap();
+    assert_eq!(auction.declarer(), Some((Player(2), 22)));
+    let calls: Vec<(Player, Call)> = auction.iter().collect();
+    assert_eq!(
+        calls[..3],
+        [
+            (Player(2), Call::Bid(18)),
+            (Player(1), Call::Hold),
+            (Player(2), Call::Bid(20)),
+        ]
+    );
+    assert_eq!(calls[6], (Player(0), Call::Pass));
+
+    // Whoever survives wins at the highest value bid.
+    let auction = self::auction(Player(0), &[Call::Pass, Call::Bid(18), Call::Pass]);
+    assert_eq!(auction.declarer(), Some((Player(0), 18)));
+    let auction = self::auction(Player(0), &[Call::Bid(18), Call::Pass, Call::Pass]);
+    assert_eq!(auction.declarer(), Some((Player(2), 18)));
+}
@@ -0,0 +1,802 @@
+//! A single deal: the auction, the skat, and the play of the game.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::game::sort_key;
+use super::{
+    card_points, deck, format_cards, Auction, Call, Card, Contract, GameType, Player, Score,
+    SkatError, Suit,
+};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// The number of players.
+const PLAYERS: u8 = 3;
+
+/// The cards dealt to each player and to the skat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealConfig {
+    dealer: Player,
+    hands: Vec<Vec<Card>>,
+    skat: Vec<Card>,
+}
+
+impl DealConfig {
+    /// Deals a shuffled deck.
+    pub fn random<R: Rng + ?Sized>(dealer: Player, rng: &mut R) -> Self {
+        let mut deck = deck();
+        deck.shuffle(rng);
+        let hands = (0..PLAYERS).map(|_| deck.take(10)).collect();
+        Self {
+            dealer,
+            hands,
+            skat: deck.take(2),
+        }
+    }
+
+    /// Creates a deal from the specified hands, indexed by player, and skat, which must hold the
+    /// 32 cards of the deck between them.
+    pub fn from_hands(
+        dealer: Player,
+        hands: Vec<Vec<Card>>,
+        skat: Vec<Card>,
+    ) -> Result<Self, SkatError> {
+        let config = Self {
+            dealer,
+            hands,
+            skat,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that every card is dealt exactly once.
+    pub fn validate(&self) -> Result<(), SkatError> {
+        if self.dealer.0 >= PLAYERS
+            || self.hands.len() != usize::from(PLAYERS)
+            || self.hands.iter().any(|h| h.len() != 10)
+            || self.skat.len() != 2
+        {
+            return Err(SkatError::InvalidHandSize);
+        }
+        let deck = deck();
+        let mut seen = vec![];
+        for &card in self.hands.iter().flatten().chain(&self.skat) {
+            if seen.contains(&card) || !deck.cards().contains(&card) {
+                return Err(SkatError::InvalidCard(card));
+            }
+            seen.push(card);
+        }
+        Ok(())
+    }
+
+    /// The dealer.
+    pub fn dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// The cards dealt to the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The cards dealt to the skat.
+    pub fn skat(&self) -> &[Card] {
+        &self.skat
+    }
+}
+
+/// The stage of the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    /// The players are bidding.
+    Auction,
+    /// The declarer may pick up the skat, or declare a hand game.
+    Skat,
+    /// The declarer must put two cards back in the skat.
+    Bury,
+    /// The declarer must declare a game.
+    Declare,
+    /// The cards are being played.
+    Play,
+    /// The deal is over.
+    Over,
+}
+
+/// An action taken during the deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// A call during the auction.
+    Call(Call),
+    /// The declarer picks up the skat.
+    PickUp,
+    /// The declarer puts a card back in the skat.
+    Bury(Card),
+    /// The declarer declares the game.
+    Declare(Contract),
+    /// A card played to a trick.
+    Play(Card),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Call(call) => write!(f, "{call}"),
+            Action::PickUp => f.write_str(&tr!("skat-action-pick-up")),
+            Action::Bury(card) => f.write_str(&tr!("skat-action-bury", card = card.to_string())),
+            Action::Declare(contract) => write!(f, "{contract}"),
+            Action::Play(card) => write!(f, "{card}"),
+        }
+    }
+}
+
+/// A trick.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trick {
+    /// The type of game.
+    pub game: GameType,
+    /// The cards played so far, in order.
+    pub cards: Vec<(Player, Card)>,
+}
+
+impl Trick {
+    /// Creates an empty trick.
+    pub fn new(game: GameType) -> Self {
+        Self {
+            game,
+            cards: vec![],
+        }
+    }
+
+    /// The suit that must be followed, or `None` if a trump was led. Returns `None` for an empty
+    /// trick.
+    pub fn lead_suit(&self) -> Option<Option<Suit>> {
+        let &(_, card) = self.cards.first()?;
+        Some(self.game.follow_suit(card))
+    }
+
+    /// Returns true once all three players have played.
+    pub fn is_complete(&self) -> bool {
+        self.cards.len() == usize::from(PLAYERS)
+    }
+
+    /// The card winning the trick so far: the highest trump, or else the highest card of the
+    /// suit led.
+    pub fn winner(&self) -> Option<(Player, Card)> {
+        let lead = self.lead_suit()?;
+        self.cards.iter().copied().max_by_key(|&(_, card)| {
+            let class = if self.game.is_trump(card) {
+                2
+            } else if self.game.follow_suit(card) == lead {
+                1
+            } else {
+                0
+            };
+            (class, self.game.strength(card))
+        })
+    }
+
+    /// The card points in the trick.
+    pub fn points(&self) -> u8 {
+        self.cards.iter().map(|&(_, c)| card_points(c)).sum()
+    }
+
+    /// Filters the hand down to the cards that may be played to this trick.
+    pub fn filter(&self, hand: &[Card]) -> Vec<Card> {
+        let Some(lead) = self.lead_suit() else {
+            return hand.to_vec();
+        };
+        let following: Vec<Card> = hand
+            .iter()
+            .copied()
+            .filter(|&c| self.game.follow_suit(c) == lead)
+            .collect();
+        if following.is_empty() {
+            hand.to_vec()
+        } else {
+            following
+        }
+    }
+}
+
+impl Display for Trick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, (player, card)) in self.cards.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}:{card}", tr!("engine-player-abbr", n = player.0 + 1))?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The result of a deal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealResult {
+    /// The declarer and their game, or `None` if the deal was passed in.
+    pub contract: Option<(Player, Contract)>,
+    /// The declarer's bid.
+    pub bid: u16,
+    /// The declarer's matadors.
+    pub matadors: u8,
+    /// The card points taken by the declarer, counting the skat.
+    pub points: u8,
+    /// The number of tricks taken by the declarer.
+    pub tricks: u8,
+    /// The score.
+    pub score: Score,
+}
+
+impl DealResult {
+    /// The points scored by the player.
+    pub fn score(&self, player: Player) -> i32 {
+        match self.contract {
+            Some((declarer, _)) if declarer == player => self.score.points(),
+            _ => 0,
+        }
+    }
+}
+
+impl Display for DealResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((declarer, contract)) = self.contract else {
+            return f.write_str(&tr!("skat-passed-in"));
+        };
+        let (declarer, contract_name) = (declarer.to_string(), contract.to_string());
+        let text = if contract.game == GameType::Null {
+            tr!(
+                "skat-result-null",
+                declarer = declarer,
+                contract = contract_name,
+                bid = self.bid,
+                tricks = self.tricks
+            )
+        } else {
+            tr!(
+                "skat-result",
+                declarer = declarer,
+                contract = contract_name,
+                bid = self.bid,
+                matadors = self.matadors,
+                points = self.points
+            )
+        };
+        write!(f, "{text}: {}", self.score)
+    }
+}
+
+/// An event that occurs during the deal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    /// A player made a call.
+    Call(Player, Call),
+    /// The auction is over, and the declarer won it with the bid.
+    Declarer(Player, u16),
+    /// The declarer picked up the skat.
+    PickUp(Player),
+    /// The declarer declared the game.
+    Declare(Player, Contract),
+    /// The declarer's cards were exposed, for an open game.
+    Ouvert(Player, Vec<Card>),
+    /// The trick is over.
+    Trick(Trick),
+    /// The deal is over.
+    Over(DealResult),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Call(player, call) => write!(f, "{player}: {call}"),
+            Event::Declarer(player, bid) => {
+                let player = player.to_string();
+                f.write_str(&tr!("skat-won-auction", player = player, bid = *bid))
+            }
+            Event::PickUp(player) => {
+                f.write_str(&tr!("skat-picked-up", player = player.to_string()))
+            }
+            Event::Declare(player, contract) => {
+                let (player, contract) = (player.to_string(), contract.to_string());
+                f.write_str(&tr!("skat-declared", player = player, contract = contract))
+            }
+            Event::Ouvert(player, hand) => {
+                let (player, cards) = (player.to_string(), format_cards(hand));
+                f.write_str(&tr!("skat-shows", player = player, cards = cards))
+            }
+            Event::Trick(trick) => {
+                let winner = trick.winner().expect("trick is complete").0;
+                let (trick, winner) = (trick.to_string(), winner.to_string());
+                f.write_str(&tr!("game-trick-won", trick = trick, winner = winner))
+            }
+            Event::Over(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+/// The state of a deal, as visible to a particular player.
+#[derive(Debug, Clone)]
+pub struct DealView<'a> {
+    /// The player.
+    pub player: Player,
+    /// The dealer.
+    pub dealer: Player,
+    /// The player's remaining cards.
+    pub hand: &'a [Card],
+    /// The auction.
+    pub auction: &'a Auction,
+    /// The declarer and their bid, once the auction is over.
+    pub declarer: Option<(Player, u16)>,
+    /// The game, once it's been declared.
+    pub contract: Option<Contract>,
+    /// The skat, once the player has picked it up as declarer.
+    pub skat: Option<&'a [Card]>,
+    /// The declarer's remaining cards, in an open game.
+    pub ouvert: Option<&'a [Card]>,
+    /// The tricks played so far. The last may be incomplete.
+    pub tricks: &'a [Trick],
+    /// The stage of the deal.
+    pub phase: Phase,
+    /// The player expected to act next.
+    pub to_play: Option<Player>,
+}
+
+impl DealView<'_> {
+    /// The trick in progress, if any cards have been played to it.
+    pub fn current_trick(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|t| !t.is_complete())
+    }
+
+    /// The cards played to the tricks so far.
+    pub fn played(&self) -> impl Iterator<Item = (Player, Card)> + '_ {
+        self.tricks.iter().flat_map(|t| t.cards.iter().copied())
+    }
+}
+
+impl Display for DealView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (player, dealer) = (self.player.to_string(), self.dealer.to_string());
+        writeln!(f, "{}", tr!("game-view", player = player, dealer = dealer))?;
+        match (self.declarer, self.contract) {
+            (None, _) if self.auction.calls().is_empty() => (),
+            (None, _) => {
+                let calls: Vec<String> = self
+                    .auction
+                    .iter()
+                    .map(|(player, call)| {
+                        format!("{}:{call}", tr!("engine-player-abbr", n = player.0 + 1))
+                    })
+                    .collect();
+                writeln!(f, "{}", tr!("game-auction", calls = calls.join(" ")))?;
+            }
+            (Some((declarer, bid)), None) => {
+                let declarer = declarer.to_string();
+                writeln!(
+                    f,
+                    "{}",
+                    tr!("skat-declarer", declarer = declarer, bid = bid)
+                )?;
+            }
+            (Some((declarer, bid)), Some(contract)) => {
+                let (declarer, contract) = (declarer.to_string(), contract.to_string());
+                let text = tr!(
+                    "skat-declarer-plays",
+                    declarer = declarer,
+                    contract = contract,
+                    bid = bid
+                );
+                writeln!(f, "{text}")?;
+            }
+        }
+        if let Some(skat) = self.skat {
+            writeln!(f, "{}", tr!("skat-skat", cards = format_cards(skat)))?;
+        }
+        if let (Some(ouvert), Some((declarer, _))) = (self.ouvert, self.declarer) {
+            if declarer != self.player {
+                let (declarer, cards) = (declarer.to_string(), format_cards(ouvert));
+                writeln!(
+                    f,
+                    "{}",
+                    tr!("skat-open", declarer = declarer, cards = cards)
+                )?;
+            }
+        }
+        if let Some(trick) = self.current_trick() {
+            writeln!(f, "{}", tr!("game-trick", trick = trick.to_string()))?;
+        }
+        f.write_str(&tr!("game-hand", cards = format_cards(self.hand)))
+    }
+}
+
+/// The state of a deal.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    config: DealConfig,
+    hands: Vec<Vec<Card>>,
+    skat: Vec<Card>,
+    auction: Auction,
+    picked_up: bool,
+    contract: Option<Contract>,
+    tricks: Vec<Trick>,
+    actions: Vec<Action>,
+    events: VecDeque<Event>,
+}
+
+impl From<DealConfig> for Deal {
+    fn from(config: DealConfig) -> Self {
+        Self {
+            hands: config.hands.clone(),
+            skat: config.skat.clone(),
+            auction: Auction::new(config.dealer),
+            picked_up: false,
+            contract: None,
+            tricks: vec![],
+            actions: vec![],
+            events: VecDeque::new(),
+            config,
+        }
+    }
+}
+
+impl Deal {
+    /// The deal as it was dealt.
+    pub fn config(&self) -> &DealConfig {
+        &self.config
+    }
+
+    /// The auction.
+    pub fn auction(&self) -> &Auction {
+        &self.auction
+    }
+
+    /// The game, once it's been declared.
+    pub fn contract(&self) -> Option<Contract> {
+        self.contract
+    }
+
+    /// The cards held by the player.
+    pub fn hand(&self, player: Player) -> &[Card] {
+        &self.hands[player.index()]
+    }
+
+    /// The tricks played so far. The last may be incomplete.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The actions taken so far.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The declarer, once the auction is over.
+    fn declarer(&self) -> Option<Player> {
+        self.auction.declarer().map(|(player, _)| player)
+    }
+
+    /// The completed tricks won by the declarer.
+    fn declarer_tricks(&self) -> impl Iterator<Item = &Trick> + '_ {
+        let declarer = self.declarer();
+        self.tricks
+            .iter()
+            .filter(|t| t.is_complete())
+            .filter(move |t| t.winner().map(|(p, _)| p) == declarer)
+    }
+
+    /// Returns true once every trick has been played, or the declarer has taken a trick in a
+    /// null game.
+    fn is_play_over(&self) -> bool {
+        let Some(contract) = self.contract else {
+            return false;
+        };
+        let complete = self.tricks.iter().filter(|t| t.is_complete()).count();
+        complete == 10 || (contract.game == GameType::Null && self.declarer_tricks().count() > 0)
+    }
+
+    /// The stage of the deal.
+    pub fn phase(&self) -> Phase {
+        let Some(declarer) = self.declarer() else {
+            return if self.auction.is_complete() {
+                Phase::Over
+            } else {
+                Phase::Auction
+            };
+        };
+        if self.contract.is_some() {
+            return if self.is_play_over() {
+                Phase::Over
+            } else {
+                Phase::Play
+            };
+        }
+        if !self.picked_up {
+            Phase::Skat
+        } else if self.hands[declarer.index()].len() > 10 {
+            Phase::Bury
+        } else {
+            Phase::Declare
+        }
+    }
+
+    /// The player whose card is to be played next, or `None` if the play hasn't started or is
+    /// over.
+    pub fn to_play(&self) -> Option<Player> {
+        if self.phase() != Phase::Play {
+            return None;
+        }
+        match self.tricks.last() {
+            None => Some(self.auction.forehand()),
+            Some(trick) if !trick.is_complete() => Some(
+                trick
+                    .cards
+                    .last()
+                    .expect("trick is started")
+                    .0
+                    .next(PLAYERS),
+            ),
+            Some(trick) => Some(trick.winner().expect("trick is complete").0),
+        }
+    }
+
+    /// The player expected to act next.
+    pub fn next_actor(&self) -> Option<Player> {
+        match self.phase() {
+            Phase::Auction => self.auction.next_player(),
+            Phase::Skat | Phase::Bury | Phase::Declare => self.declarer(),
+            Phase::Play => self.to_play(),
+            Phase::Over => None,
+        }
+    }
+
+    /// Returns the state of the deal, as visible to the player.
+    pub fn view(&self, player: Player) -> DealView<'_> {
+        let declarer = self.declarer();
+        let skat = (self.picked_up && declarer == Some(player)).then_some(self.skat.as_slice());
+        let ouvert = self
+            .contract
+            .filter(|c| c.ouvert)
+            .and(declarer)
+            .map(|d| self.hands[d.index()].as_slice());
+        DealView {
+            player,
+            dealer: self.config.dealer,
+            hand: &self.hands[player.index()],
+            auction: &self.auction,
+            declarer: self.auction.declarer(),
+            contract: self.contract,
+            skat,
+            ouvert,
+            tricks: &self.tricks,
+            phase: self.phase(),
+            to_play: self.next_actor(),
+        }
+    }
+
+    /// Returns the actions the next player may take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.phase() {
+            Phase::Auction => self
+                .auction
+                .legal_calls()
+                .into_iter()
+                .map(Action::Call)
+                .collect(),
+            Phase::Skat => std::iter::once(Action::PickUp)
+                .chain(Contract::all(true).into_iter().map(Action::Declare))
+                .collect(),
+            Phase::Bury => {
+                let declarer = self.declarer().expect("auction is won");
+                let mut cards = self.hands[declarer.index()].clone();
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.into_iter().map(Action::Bury).collect()
+            }
+            Phase::Declare => Contract::all(false)
+                .into_iter()
+                .map(Action::Declare)
+                .collect(),
+            Phase::Play => {
+                let player = self.to_play().expect("play is in progress");
+                let hand = &self.hands[player.index()];
+                let mut cards = match self.tricks.last().filter(|t| !t.is_complete()) {
+                    Some(trick) => trick.filter(hand),
+                    None => hand.clone(),
+                };
+                cards.sort_by_key(|&c| sort_key(c));
+                cards.into_iter().map(Action::Play).collect()
+            }
+            Phase::Over => vec![],
+        }
+    }
+
+    /// Applies the next player's action.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), SkatError> {
+        let phase = self.phase();
+        match (phase, action) {
+            (_, Action::Call(call)) => self.call(call)?,
+            (Phase::Over, _) => return Err(SkatError::DealOver),
+            (Phase::Skat, Action::PickUp) => self.pick_up(),
+            (Phase::Bury, Action::Bury(card)) => self.bury(card)?,
+            (Phase::Skat | Phase::Declare, Action::Declare(contract)) => self.declare(contract)?,
+            (Phase::Play, Action::Play(card)) => self.play(card)?,
+            _ => return Err(SkatError::OutOfTurn(action)),
+        }
+        self.actions.push(action);
+        if phase != Phase::Over {
+            if let Some(result) = self.result() {
+                self.events.push_back(Event::Over(result));
+            }
+        }
+        Ok(())
+    }
+
+    fn call(&mut self, call: Call) -> Result<(), SkatError> {
+        let player = self.auction.next_player().ok_or(SkatError::AuctionOver)?;
+        self.auction.call(call)?;
+        self.events.push_back(Event::Call(player, call));
+        if let Some((declarer, bid)) = self.auction.declarer() {
+            self.events.push_back(Event::Declarer(declarer, bid));
+        }
+        Ok(())
+    }
+
+    fn pick_up(&mut self) {
+        let declarer = self.declarer().expect("auction is won");
+        let skat = std::mem::take(&mut self.skat);
+        self.hands[declarer.index()].extend(skat);
+        self.picked_up = true;
+        self.events.push_back(Event::PickUp(declarer));
+    }
+
+    fn bury(&mut self, card: Card) -> Result<(), SkatError> {
+        let declarer = self.declarer().expect("auction is won");
+        let hand = &mut self.hands[declarer.index()];
+        if !hand.contains(&card) {
+            return Err(SkatError::CardNotHeld(declarer, card));
+        }
+        hand.retain(|&c| c != card);
+        self.skat.push(card);
+        Ok(())
+    }
+
+    fn declare(&mut self, contract: Contract) -> Result<(), SkatError> {
+        if !contract.is_valid() || contract.hand == self.picked_up {
+            return Err(SkatError::IllegalContract(contract));
+        }
+        let declarer = self.declarer().expect("auction is won");
+        self.contract = Some(contract);
+        self.events.push_back(Event::Declare(declarer, contract));
+        if contract.ouvert {
+            let hand = self.hands[declarer.index()].clone();
+            self.events.push_back(Event::Ouvert(declarer, hand));
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, card: Card) -> Result<(), SkatError> {
+        let contract = self.contract.expect("game is declared");
+        let player = self.to_play().expect("play is in progress");
+        let hand = &self.hands[player.index()];
+        if !hand.contains(&card) {
+            return Err(SkatError::CardNotHeld(player, card));
+        }
+        match self.tricks.last().filter(|t| !t.is_complete()) {
+            Some(trick) if !trick.filter(hand).contains(&card) => {
+                return Err(SkatError::MustFollowSuit(player));
+            }
+            Some(_) => (),
+            None => self.tricks.push(Trick::new(contract.game)),
+        }
+        let trick = self.tricks.last_mut().expect("trick is started");
+        trick.cards.push((player, card));
+        self.hands[player.index()].retain(|&c| c != card);
+        let trick = self.tricks.last().expect("trick is started");
+        if trick.is_complete() {
+            self.events.push_back(Event::Trick(trick.clone()));
+        }
+        Ok(())
+    }
+
+    /// The result of the deal, once it's over.
+    pub fn result(&self) -> Option<DealResult> {
+        if self.phase() != Phase::Over {
+            return None;
+        }
+        let Some((declarer, bid)) = self.auction.declarer() else {
+            return Some(DealResult {
+                contract: None,
+                bid: 0,
+                matadors: 0,
+                points: 0,
+                tricks: 0,
+                score: Score::default(),
+            });
+        };
+        let contract = self.contract.expect("game is declared");
+        let mut dealt = self.config.hands[declarer.index()].clone();
+        dealt.extend_from_slice(&self.config.skat);
+        let matadors = contract.game.matadors(&dealt);
+        let tricks = self.declarer_tricks().count();
+        let points = self.declarer_tricks().map(Trick::points).sum::<u8>()
+            + self.skat.iter().copied().map(card_points).sum::<u8>();
+        let tricks = u8::try_from(tricks).expect("at most 10 tricks");
+        Some(DealResult {
+            contract: Some((declarer, contract)),
+            bid,
+            matadors,
+            points,
+            tricks,
+            score: Score::new(&contract, bid, matadors, points, tricks),
+        })
+    }
+}
+
+impl crate::engine::Engine for Deal {
+    type Actor = Player;
+    type Action = Action;
+    type View<'a> = DealView<'a>;
+    type Event = Event;
+    type Outcome = DealResult;
+    type Error = SkatError;
+
+    fn expected_actor(&self) -> Option<Player> {
+        self.next_actor()
+    }
+
+    fn view(&self, player: Player) -> DealView<'_> {
+        Deal::view(self, player)
+    }
+
+    fn legal_actions(&self) -> Vec<Action> {
+        Deal::legal_actions(self)
+    }
+
+    fn apply(&mut self, action: Action) -> Result<(), SkatError> {
+        self.apply_action(action)
+    }
+
+    fn pop_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn outcome(&self) -> Option<DealResult> {
+        self.result()
+    }
+}
+
+/// A serializable record of a deal: the cards dealt, and the actions taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawDeal {
+    config: DealConfig,
+    actions: Vec<Action>,
+}
+
+impl From<&Deal> for RawDeal {
+    fn from(deal: &Deal) -> Self {
+        Self {
+            config: deal.config.clone(),
+            actions: deal.actions.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawDeal> for Deal {
+    type Error = SkatError;
+
+    fn try_from(raw: RawDeal) -> Result<Self, SkatError> {
+        raw.config.validate()?;
+        let mut deal = Deal::from(raw.config);
+        for action in raw.actions {
+            deal.apply_action(action)?;
+        }
+        deal.events.clear();
+        Ok(deal)
+    }
+}
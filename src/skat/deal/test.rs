@@ -0,0 +1,177 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::skat::Robot;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+/// A deal in which forehand, Player 1, holds all four jacks.
+fn config() -> DealConfig {
+    let hands = vec![
+        cards("J♣ J♤ J♡ J♦ A♣ T♣ K♣ A♤ T♤ A♡"),
+        cards("Q♣ 9♣ 8♣ 7♣ K♤ Q♤ 9♤ 8♤ 7♤ K♡"),
+        cards("T♡ Q♡ 9♡ 8♡ 7♡ A♦ T♦ K♦ Q♦ 9♦"),
+    ];
+    DealConfig::from_hands(Player(2), hands, cards("8♦ 7♦")).unwrap()
+}
+
+/// Makes the calls, which leave forehand as declarer at 18.
+fn won_by_forehand() -> Deal {
+    let mut deal = Deal::from(config());
+    for call in [Call::Pass, Call::Pass, Call::Bid(18)].iter().copied() {
+        deal.apply_action(Action::Call(call)).unwrap();
+    }
+    deal
+}
+
+fn hand_game(game: GameType) -> Contract {
+    Contract {
+        hand: true,
+        ..Contract::new(game)
+    }
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut hands: Vec<Vec<Card>> = (0..3).map(|p| config().hand(Player(p)).to_vec()).collect();
+    let result = DealConfig::from_hands(Player(0), hands.clone(), cards("8♦"));
+    assert!(matches!(result, Err(SkatError::InvalidHandSize)));
+    hands[0][0] = card("6♦");
+    let result = DealConfig::from_hands(Player(0), hands.clone(), cards("8♦ 7♦"));
+    assert!(matches!(result, Err(SkatError::InvalidCard(_))));
+    hands[0][0] = card("J♤");
+    let result = DealConfig::from_hands(Player(0), hands, cards("8♦ 7♦"));
+    assert!(matches!(result, Err(SkatError::InvalidCard(_))));
+}
+
+#[test]
+fn test_passed_in() {
+    let mut deal = Deal::from(config());
+    for _ in 0..3 {
+        deal.apply_action(Action::Call(Call::Pass)).unwrap();
+    }
+    assert_eq!(deal.phase(), Phase::Over);
+    assert_eq!(deal.next_actor(), None);
+    assert!(deal.legal_actions().is_empty());
+    let result = deal.result().unwrap();
+    assert_eq!(result.contract, None);
+    assert_eq!(result.score(Player(0)), 0);
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("J♣"))),
+        Err(SkatError::DealOver)
+    ));
+}
+
+#[test]
+fn test_hand_game() {
+    let mut deal = won_by_forehand();
+    assert_eq!(deal.phase(), Phase::Skat);
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+    let legal = deal.legal_actions();
+    assert_eq!(legal[0], Action::PickUp);
+    assert!(legal.contains(&Action::Declare(hand_game(GameType::Grand))));
+    assert!(!legal.contains(&Action::Declare(Contract::new(GameType::Grand))));
+    assert!(matches!(
+        deal.apply_action(Action::Bury(card("J♣"))),
+        Err(SkatError::OutOfTurn(_))
+    ));
+    assert!(matches!(
+        deal.apply_action(Action::Declare(Contract::new(GameType::Grand))),
+        Err(SkatError::IllegalContract(_))
+    ));
+    deal.apply_action(Action::Declare(hand_game(GameType::Grand)))
+        .unwrap();
+
+    // Forehand leads, and the others must follow suit.
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+    deal.apply_action(Action::Play(card("A♤"))).unwrap();
+    assert!(matches!(
+        deal.apply_action(Action::Play(card("K♡"))),
+        Err(SkatError::MustFollowSuit(_))
+    ));
+    assert_eq!(deal.legal_actions().len(), 5);
+    deal.apply_action(Action::Play(card("7♤"))).unwrap();
+    assert_eq!(deal.legal_actions().len(), 10);
+    deal.apply_action(Action::Play(card("7♡"))).unwrap();
+    assert_eq!(deal.tricks()[0].winner(), Some((Player(0), card("A♤"))));
+    assert_eq!(deal.next_actor(), Some(Player(0)));
+
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    assert_eq!(
+        result.contract,
+        Some((Player(0), hand_game(GameType::Grand)))
+    );
+    assert_eq!(result.matadors, 4);
+    assert!(result.score.won);
+    assert!(result.score.value >= 24 * 6);
+    assert_eq!(result.score(Player(0)), i32::from(result.score.value));
+    assert_eq!(result.score(Player(1)), 0);
+}
+
+#[test]
+fn test_skat() {
+    let mut deal = won_by_forehand();
+    deal.apply_action(Action::PickUp).unwrap();
+    assert_eq!(deal.phase(), Phase::Bury);
+    assert_eq!(deal.hand(Player(0)).len(), 12);
+    assert_eq!(deal.view(Player(0)).skat, Some(&[][..]));
+    deal.apply_action(Action::Bury(card("T♤"))).unwrap();
+    deal.apply_action(Action::Bury(card("8♦"))).unwrap();
+    assert_eq!(deal.phase(), Phase::Declare);
+    assert_eq!(deal.view(Player(0)).skat, Some(&cards("T♤ 8♦")[..]));
+    assert_eq!(deal.view(Player(1)).skat, None);
+    assert_eq!(deal.legal_actions().len(), 7);
+    assert!(matches!(
+        deal.apply_action(Action::Declare(hand_game(GameType::Grand))),
+        Err(SkatError::IllegalContract(_))
+    ));
+    deal.apply_action(Action::Declare(Contract::new(GameType::Grand)))
+        .unwrap();
+    assert_eq!(deal.phase(), Phase::Play);
+}
+
+#[test]
+fn test_null() {
+    let mut deal = won_by_forehand();
+    let contract = Contract {
+        ouvert: true,
+        ..hand_game(GameType::Null)
+    };
+    deal.apply_action(Action::Declare(contract)).unwrap();
+    assert_eq!(deal.view(Player(1)).ouvert, Some(config().hand(Player(0))));
+    for c in ["A♤", "7♤", "7♡"].iter() {
+        deal.apply_action(Action::Play(card(c))).unwrap();
+    }
+    // The declarer took a trick, so the game is lost.
+    assert_eq!(deal.phase(), Phase::Over);
+    let result = deal.result().unwrap();
+    assert_eq!(result.tricks, 1);
+    assert_eq!(result.score(Player(0)), -2 * 59);
+    let events: Vec<Event> = std::iter::from_fn(|| deal.pop_event()).collect();
+    assert!(matches!(events.last(), Some(Event::Over(_))));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::Ouvert(Player(0), _))));
+}
+
+#[test]
+fn test_raw_deal() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut deal = Deal::from(DealConfig::random(Player(1), &mut rng));
+    let result = deal
+        .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+        .unwrap()
+        .expect("deal is over");
+    let raw = serde_json::to_string(&RawDeal::from(&deal)).unwrap();
+    let replayed = Deal::try_from(serde_json::from_str::<RawDeal>(&raw).unwrap()).unwrap();
+    assert_eq!(replayed.result(), Some(result));
+    assert_eq!(replayed.actions(), deal.actions());
+}
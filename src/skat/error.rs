@@ -0,0 +1,36 @@
+//! Errors
+
+use super::{Action, Call, Card, Contract, Player};
+
+/// An error that can occur during a deal.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SkatError {
+    /// A player doesn't hold ten cards, or the skat doesn't hold two.
+    #[error("each player must be dealt 10 cards, with 2 in the skat")]
+    InvalidHandSize,
+    /// The same card was dealt twice, or a card isn't from the skat deck.
+    #[error("{0} is not dealt exactly once")]
+    InvalidCard(Card),
+    /// The call isn't legal at this point in the auction.
+    #[error("{0} is not a legal call")]
+    IllegalCall(Call),
+    /// A call was made after the auction was over.
+    #[error("the auction is over")]
+    AuctionOver,
+    /// The action isn't expected at this point in the deal.
+    #[error("unexpected action: {0}")]
+    OutOfTurn(Action),
+    /// The contract can't be declared, because its announcements aren't consistent, or it's a
+    /// hand game after the skat was picked up.
+    #[error("cannot declare {0}")]
+    IllegalContract(Contract),
+    /// The player doesn't hold the card.
+    #[error("{0} does not hold {1}")]
+    CardNotHeld(Player, Card),
+    /// The player must follow the suit that was led, or trumps if a trump was led.
+    #[error("{0} must follow suit")]
+    MustFollowSuit(Player),
+    /// The deal is over, no more actions are expected.
+    #[error("deal is over")]
+    DealOver,
+}
@@ -0,0 +1,259 @@
+//! Games and contracts.
+
+use std::cmp::Reverse;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Card, Rank, Suit};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Returns the suit of a card. Skat is played without jokers.
+pub(crate) fn suit_of(card: Card) -> Suit {
+    card.suit().expect("skat is played without jokers")
+}
+
+/// Returns the rank of a card. Skat is played without jokers.
+pub(crate) fn rank_of(card: Card) -> Rank {
+    card.rank().expect("skat is played without jokers")
+}
+
+/// The jacks, from highest to lowest.
+const JACKS: [Suit; 4] = [Suit::Club, Suit::Spade, Suit::Heart, Suit::Diamond];
+
+/// The ranks of a suit other than the jack, from highest to lowest, outside of null games.
+const RANKS: [Rank; 7] = [
+    Rank::Ace,
+    Rank::Ten,
+    Rank::King,
+    Rank::Queen,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+];
+
+/// Sorts cards for display: the jacks first, and then each suit from clubs down to diamonds, from
+/// highest to lowest.
+pub(crate) fn sort_key(card: Card) -> (bool, usize, Reverse<u8>) {
+    let suit = JACKS
+        .iter()
+        .position(|&s| s == suit_of(card))
+        .expect("jacks of every suit");
+    let strength = GameType::Grand.strength(card);
+    (rank_of(card) != Rank::Jack, suit, Reverse(strength))
+}
+
+/// The type of game the declarer plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameType {
+    /// The jacks and the suit are trumps.
+    Suit(Suit),
+    /// Only the jacks are trumps.
+    Grand,
+    /// There are no trumps, and the declarer must lose every trick.
+    Null,
+}
+
+impl GameType {
+    /// Every type of game, from the lowest base value to the highest.
+    pub fn all() -> [GameType; 6] {
+        [
+            GameType::Suit(Suit::Diamond),
+            GameType::Suit(Suit::Heart),
+            GameType::Suit(Suit::Spade),
+            GameType::Suit(Suit::Club),
+            GameType::Null,
+            GameType::Grand,
+        ]
+    }
+
+    /// Returns true if the card is a trump.
+    pub fn is_trump(self, card: Card) -> bool {
+        match self {
+            GameType::Null => false,
+            GameType::Grand => rank_of(card) == Rank::Jack,
+            GameType::Suit(trump) => rank_of(card) == Rank::Jack || suit_of(card) == trump,
+        }
+    }
+
+    /// The suit the card belongs to for following suit, or `None` for trumps.
+    pub fn follow_suit(self, card: Card) -> Option<Suit> {
+        (!self.is_trump(card)).then_some(suit_of(card))
+    }
+
+    /// The card's strength among the cards of its suit, or among the trumps.
+    pub fn strength(self, card: Card) -> u8 {
+        let rank = rank_of(card);
+        if self == GameType::Null {
+            return match rank {
+                Rank::Ace => 7,
+                _ => rank as u8 - Rank::Seven as u8,
+            };
+        }
+        if rank == Rank::Jack {
+            let i = JACKS
+                .iter()
+                .position(|&s| s == suit_of(card))
+                .expect("jacks of every suit");
+            return 20 - i as u8;
+        }
+        let i = RANKS
+            .iter()
+            .position(|&r| r == rank)
+            .expect("a rank from seven to ace");
+        10 - i as u8
+    }
+
+    /// The trumps, from highest to lowest.
+    pub fn trumps(self) -> Vec<Card> {
+        let jacks = JACKS.iter().map(|&s| Card::new(Rank::Jack, s));
+        match self {
+            GameType::Null => vec![],
+            GameType::Grand => jacks.collect(),
+            GameType::Suit(suit) => jacks
+                .chain(RANKS.iter().map(|&r| Card::new(r, suit)))
+                .collect(),
+        }
+    }
+
+    /// The number of matadors: the trumps held in an unbroken sequence from the club jack down
+    /// ("with"), or missing above the highest trump held ("without").
+    pub fn matadors(self, cards: &[Card]) -> u8 {
+        let trumps = self.trumps();
+        let Some(&top) = trumps.first() else {
+            return 0;
+        };
+        let with = cards.contains(&top);
+        let n = trumps
+            .iter()
+            .take_while(|c| cards.contains(c) == with)
+            .count();
+        n as u8
+    }
+}
+
+impl Display for GameType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GameType::Suit(Suit::Club) => tr!("skat-clubs"),
+            GameType::Suit(Suit::Spade) => tr!("skat-spades"),
+            GameType::Suit(Suit::Heart) => tr!("skat-hearts"),
+            GameType::Suit(Suit::Diamond) => tr!("skat-diamonds"),
+            GameType::Grand => tr!("skat-grand"),
+            GameType::Null => tr!("skat-null"),
+        };
+        f.write_str(&name)
+    }
+}
+
+/// The game announced by the declarer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Contract {
+    /// The type of game.
+    pub game: GameType,
+    /// Whether the declarer plays without picking up the skat.
+    pub hand: bool,
+    /// Whether the declarer announced schneider, promising 90 card points.
+    pub schneider: bool,
+    /// Whether the declarer announced schwarz, promising every trick.
+    pub schwarz: bool,
+    /// Whether the declarer plays with their cards exposed.
+    pub ouvert: bool,
+}
+
+impl Contract {
+    /// A game played after picking up the skat, without announcements.
+    pub fn new(game: GameType) -> Self {
+        Self {
+            game,
+            hand: false,
+            schneider: false,
+            schwarz: false,
+            ouvert: false,
+        }
+    }
+
+    /// Returns true if the announcements are consistent with each other and the type of game.
+    /// Schneider and schwarz may only be announced in hand games, and schwarz implies schneider.
+    /// A suit or grand game may be played open only when schwarz is announced.
+    pub fn is_valid(&self) -> bool {
+        if self.game == GameType::Null {
+            return !self.schneider && !self.schwarz;
+        }
+        (self.hand || !self.schneider)
+            && (self.schneider || !self.schwarz)
+            && (self.schwarz || !self.ouvert)
+    }
+
+    /// Every valid contract, played from hand or after picking up the skat.
+    pub fn all(hand: bool) -> Vec<Contract> {
+        let mut contracts = vec![];
+        for game in GameType::all().iter().copied() {
+            let plain = Contract {
+                hand,
+                ..Contract::new(game)
+            };
+            contracts.push(plain);
+            if game == GameType::Null {
+                contracts.push(Contract {
+                    ouvert: true,
+                    ..plain
+                });
+            } else if hand {
+                let schneider = Contract {
+                    schneider: true,
+                    ..plain
+                };
+                let schwarz = Contract {
+                    schwarz: true,
+                    ..schneider
+                };
+                let ouvert = Contract {
+                    ouvert: true,
+                    ..schwarz
+                };
+                contracts.extend([schneider, schwarz, ouvert].iter().copied());
+            }
+        }
+        contracts
+    }
+
+    /// The base value of the game: 9 to 12 for the suits, from diamonds up to clubs, and 24 for
+    /// grand. A null game has a fixed value, which is also its base value: 23, 35 played from
+    /// hand, 46 open, and 59 open from hand.
+    pub fn base_value(&self) -> u16 {
+        match self.game {
+            GameType::Suit(Suit::Diamond) => 9,
+            GameType::Suit(Suit::Heart) => 10,
+            GameType::Suit(Suit::Spade) => 11,
+            GameType::Suit(Suit::Club) => 12,
+            GameType::Grand => 24,
+            GameType::Null => match (self.hand, self.ouvert) {
+                (false, false) => 23,
+                (true, false) => 35,
+                (false, true) => 46,
+                (true, true) => 59,
+            },
+        }
+    }
+}
+
+impl Display for Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.game)?;
+        if self.hand {
+            write!(f, " {}", tr!("skat-hand"))?;
+        }
+        if self.ouvert {
+            write!(f, " {}", tr!("skat-ouvert"))?;
+        } else if self.schwarz {
+            write!(f, " {}", tr!("skat-schwarz"))?;
+        } else if self.schneider {
+            write!(f, " {}", tr!("skat-schneider"))?;
+        }
+        Ok(())
+    }
+}
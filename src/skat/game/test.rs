@@ -0,0 +1,73 @@
+use super::*;
+use crate::french::cards;
+
+fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_trumps() {
+    let clubs = GameType::Suit(Suit::Club);
+    assert!(clubs.is_trump(card("J♡")));
+    assert!(clubs.is_trump(card("7♣")));
+    assert!(!clubs.is_trump(card("A♤")));
+    assert_eq!(clubs.follow_suit(card("J♡")), None);
+    assert_eq!(clubs.follow_suit(card("A♡")), Some(Suit::Heart));
+    assert!(GameType::Grand.is_trump(card("J♦")));
+    assert!(!GameType::Grand.is_trump(card("A♣")));
+    assert!(!GameType::Null.is_trump(card("J♣")));
+    assert_eq!(GameType::Null.follow_suit(card("J♣")), Some(Suit::Club));
+
+    let order = |game: GameType, s: &str| {
+        let mut sorted = cards(s);
+        sorted.sort_by_key(|&c| Reverse(game.strength(c)));
+        sorted
+    };
+    assert_eq!(
+        order(clubs, "7♣ T♣ J♦ A♣ J♣ Q♣ J♤"),
+        cards("J♣ J♤ J♦ A♣ T♣ Q♣ 7♣")
+    );
+    assert_eq!(
+        order(GameType::Null, "T♡ J♡ A♡ 7♡ K♡"),
+        cards("A♡ K♡ J♡ T♡ 7♡")
+    );
+}
+
+#[test]
+fn test_matadors() {
+    let clubs = GameType::Suit(Suit::Club);
+    assert_eq!(clubs.matadors(&cards("J♣ J♤ J♡ 7♦")), 3);
+    assert_eq!(clubs.matadors(&cards("J♣ J♤ J♡ J♦ A♣ T♣ 9♣")), 6);
+    assert_eq!(clubs.matadors(&cards("J♡ A♣")), 2);
+    assert_eq!(clubs.matadors(&cards("7♣")), 10);
+    assert_eq!(GameType::Grand.matadors(&cards("J♣ J♤ J♡ J♦")), 4);
+    assert_eq!(GameType::Grand.matadors(&cards("A♣")), 4);
+    assert_eq!(GameType::Null.matadors(&cards("J♣")), 0);
+}
+
+#[test]
+fn test_contracts() {
+    assert_eq!(Contract::all(false).len(), 7);
+    assert_eq!(Contract::all(true).len(), 7 + 5 * 3);
+    assert!(Contract::all(true).iter().all(Contract::is_valid));
+    let spades = Contract {
+        hand: true,
+        schneider: true,
+        ..Contract::new(GameType::Suit(Suit::Spade))
+    };
+    assert_eq!(spades.to_string(), "Spades hand schneider");
+    assert_eq!(spades.base_value(), 11);
+    assert!(!Contract {
+        hand: false,
+        ..spades
+    }
+    .is_valid());
+    let null = Contract {
+        hand: true,
+        ouvert: true,
+        ..Contract::new(GameType::Null)
+    };
+    assert_eq!(null.to_string(), "Null hand ouvert");
+    assert_eq!(null.base_value(), 59);
+    assert_eq!(Contract::new(GameType::Grand).base_value(), 24);
+}
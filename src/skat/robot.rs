@@ -0,0 +1,232 @@
+//! A robot player.
+//!
+//! The robot bids on a suit game when it holds six trumps, counting the jacks, or five with two
+//! jacks and a side ace, and on a grand with three jacks and two aces. It bids up to the value of
+//! the game with the matadors it holds. As declarer, it always picks up the skat, buries its most
+//! valuable cards from short suits, and declares the best game that reaches its bid.
+//!
+//! In play, it leads trumps as declarer and aces as a defender. It wins tricks as cheaply as it
+//! can, and throws points onto tricks its side is winning. In a null game, the declarer plays its
+//! highest card that doesn't win the trick.
+
+use std::cmp::Reverse;
+
+use super::game::rank_of;
+use super::{card_points, Action, Call, Card, Contract, DealView, GameType, Phase, Rank, Trick};
+
+#[cfg(test)]
+mod test;
+
+/// A robot player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot;
+
+impl Robot {
+    /// Chooses one of the legal actions for the player whose turn it is.
+    pub fn choose(&self, view: &DealView, legal: &[Action]) -> Action {
+        let choice = match view.phase {
+            Phase::Auction => bid(view, legal),
+            Phase::Skat => Some(Action::PickUp),
+            Phase::Bury => bury(view),
+            Phase::Declare => declare(view),
+            Phase::Play => play(view, legal),
+            Phase::Over => None,
+        };
+        choice
+            .filter(|a| legal.contains(a))
+            .unwrap_or_else(|| legal[0])
+    }
+}
+
+/// Returns true if the cards are strong enough to play the game.
+fn is_playable(game: GameType, cards: &[Card]) -> bool {
+    let trumps = cards.iter().filter(|&&c| game.is_trump(c)).count();
+    let jacks = cards.iter().filter(|&&c| rank_of(c) == Rank::Jack).count();
+    let aces = cards
+        .iter()
+        .filter(|&&c| rank_of(c) == Rank::Ace && !game.is_trump(c))
+        .count();
+    match game {
+        GameType::Suit(_) => trumps >= 6 || (trumps == 5 && jacks >= 2 && aces >= 1),
+        GameType::Grand => (jacks >= 3 && aces >= 2) || (jacks == 4 && aces >= 1),
+        GameType::Null => cards.iter().all(|&c| game.strength(c) <= 3),
+    }
+}
+
+/// The value of the game, played after picking up the skat, without schneider.
+fn value(game: GameType, cards: &[Card]) -> u16 {
+    let contract = Contract::new(game);
+    match game {
+        GameType::Null => contract.base_value(),
+        _ => contract.base_value() * (u16::from(game.matadors(cards)) + 1),
+    }
+}
+
+/// The most valuable game the cards are strong enough to play, with its value.
+fn best_game(cards: &[Card]) -> Option<(GameType, u16)> {
+    GameType::all()
+        .iter()
+        .copied()
+        .filter(|&game| is_playable(game, cards))
+        .map(|game| (game, value(game, cards)))
+        .max_by_key(|&(_, value)| value)
+}
+
+/// Bids or holds up to the value of the best game in hand.
+fn bid(view: &DealView, legal: &[Action]) -> Option<Action> {
+    let max = best_game(view.hand).map_or(0, |(_, value)| value);
+    if legal.contains(&Action::Call(Call::Hold)) {
+        let value = view.auction.value().unwrap_or(0);
+        let call = if value <= max { Call::Hold } else { Call::Pass };
+        return Some(Action::Call(call));
+    }
+    let next = legal.iter().find_map(|a| match a {
+        Action::Call(Call::Bid(value)) => Some(*value),
+        _ => None,
+    });
+    match next {
+        Some(value) if value <= max => Some(Action::Call(Call::Bid(value))),
+        _ => Some(Action::Call(Call::Pass)),
+    }
+}
+
+/// The game the declarer plans to play with the cards, which is the most trumps, if it can't
+/// play any game.
+fn plan(cards: &[Card]) -> GameType {
+    best_game(cards).map_or_else(
+        || {
+            GameType::all()
+                .iter()
+                .copied()
+                .filter(|&g| g != GameType::Null)
+                .max_by_key(|&g| cards.iter().filter(|&&c| g.is_trump(c)).count())
+                .expect("there are suit games")
+        },
+        |(game, _)| game,
+    )
+}
+
+/// Buries the most valuable card from a short side suit, keeping aces, or the highest card for
+/// a null game.
+fn bury(view: &DealView) -> Option<Action> {
+    let game = plan(view.hand);
+    let suit_len = |card: Card| {
+        view.hand
+            .iter()
+            .filter(|&&c| game.follow_suit(c) == game.follow_suit(card))
+            .count()
+    };
+    view.hand
+        .iter()
+        .copied()
+        .max_by_key(|&c| {
+            if game == GameType::Null {
+                return (true, true, game.strength(c), Reverse(0));
+            }
+            (
+                !game.is_trump(c),
+                rank_of(c) != Rank::Ace,
+                card_points(c),
+                Reverse(suit_len(c)),
+            )
+        })
+        .map(Action::Bury)
+}
+
+/// Declares the best game that reaches the bid, counting the skat towards the matadors.
+fn declare(view: &DealView) -> Option<Action> {
+    let (_, bid) = view.declarer?;
+    let mut dealt = view.hand.to_vec();
+    dealt.extend_from_slice(view.skat?);
+    let game = GameType::all()
+        .iter()
+        .copied()
+        .max_by_key(|&game| {
+            let value = value(game, &dealt);
+            let trumps = view.hand.iter().filter(|&&c| game.is_trump(c)).count();
+            (value >= bid, is_playable(game, view.hand), trumps, value)
+        })
+        .expect("there are games");
+    Some(Action::Declare(Contract::new(game)))
+}
+
+/// Returns true if the player would win the trick with the card.
+fn wins(trick: &Trick, view: &DealView, card: Card) -> bool {
+    let mut trick = trick.clone();
+    trick.cards.push((view.player, card));
+    trick.winner().is_some_and(|(p, _)| p == view.player)
+}
+
+/// Plays a card.
+fn play(view: &DealView, legal: &[Action]) -> Option<Action> {
+    let cards: Vec<Card> = legal
+        .iter()
+        .filter_map(|a| match a {
+            Action::Play(card) => Some(*card),
+            _ => None,
+        })
+        .collect();
+    let game = view.contract?.game;
+    let (declarer, _) = view.declarer?;
+    let declaring = view.player == declarer;
+    let lowest = |cards: &[Card]| {
+        cards
+            .iter()
+            .copied()
+            .min_by_key(|&c| (card_points(c), game.is_trump(c), game.strength(c)))
+    };
+    let card = match (view.current_trick(), game) {
+        (None, GameType::Null) => cards.iter().copied().min_by_key(|&c| game.strength(c)),
+        (Some(trick), GameType::Null) if declaring => cards
+            .iter()
+            .copied()
+            .filter(|&c| !wins(trick, view, c))
+            .max_by_key(|&c| game.strength(c))
+            .or_else(|| lowest(&cards)),
+        (Some(_), GameType::Null) => lowest(&cards),
+        (None, _) => {
+            let trumps: Vec<Card> = cards
+                .iter()
+                .copied()
+                .filter(|&c| game.is_trump(c))
+                .collect();
+            let aces = cards
+                .iter()
+                .copied()
+                .find(|&c| rank_of(c) == Rank::Ace && !game.is_trump(c));
+            if declaring && trumps.len() >= 2 {
+                trumps.iter().copied().max_by_key(|&c| game.strength(c))
+            } else {
+                aces.or_else(|| lowest(&cards))
+            }
+        }
+        (Some(trick), _) => {
+            let (winner, _) = trick.winner()?;
+            let friendly = (winner == declarer) == declaring;
+            let last = trick.cards.len() == 2;
+            let winning: Vec<Card> = cards
+                .iter()
+                .copied()
+                .filter(|&c| wins(trick, view, c))
+                .collect();
+            if friendly && last {
+                cards
+                    .iter()
+                    .copied()
+                    .max_by_key(|&c| (!game.is_trump(c), card_points(c)))
+            } else if friendly || winning.is_empty() {
+                lowest(&cards)
+            } else if last {
+                winning
+                    .iter()
+                    .copied()
+                    .min_by_key(|&c| (game.is_trump(c), game.strength(c)))
+            } else if trick.points() >= 10 {
+                winning.iter().copied().max_by_key(|&c| game.strength(c))
+            } else {
+                lowest(&cards)
+            }
+        }
+    };
+    card.map(Action::Play)
+}
@@ -0,0 +1,72 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::*;
+use crate::engine::Engine;
+use crate::french::cards;
+use crate::skat::{Deal, DealConfig, Player, Suit};
+
+#[test]
+fn test_best_game() {
+    let hand = cards("J♣ J♡ A♣ T♣ 9♣ 8♣ A♤ K♡ 8♦ 7♦");
+    let clubs = GameType::Suit(Suit::Club);
+    assert!(is_playable(clubs, &hand));
+    assert!(!is_playable(GameType::Grand, &hand));
+    // With one, game two, times twelve.
+    assert_eq!(best_game(&hand), Some((clubs, 24)));
+
+    let hand = cards("J♣ J♤ J♦ A♣ T♣ A♤ K♤ A♡ 8♦ 7♦");
+    assert_eq!(best_game(&hand), Some((GameType::Grand, 72)));
+
+    let hand = cards("7♣ 8♣ T♣ 7♤ 9♤ 8♡ 9♡ T♦ 7♦ 8♦");
+    assert_eq!(best_game(&hand), Some((GameType::Null, 23)));
+
+    let hand = cards("J♦ A♣ 9♣ 8♣ A♤ K♡ Q♡ 8♦ 7♦ T♦");
+    assert_eq!(best_game(&hand), None);
+}
+
+#[test]
+fn test_bidding() {
+    let hands = vec![
+        cards("7♣ K♦ 7♤ 8♤ 9♤ 7♡ 8♡ 9♡ 7♦ 8♦"),
+        cards("J♣ J♤ J♦ A♣ A♤ K♤ A♡ Q♡ 9♦ T♦"),
+        cards("J♡ T♣ K♣ Q♣ 9♣ 8♣ T♤ Q♤ T♡ K♡"),
+    ];
+    let config = DealConfig::from_hands(Player(2), hands, cards("A♦ Q♦")).unwrap();
+    let mut deal = Deal::from(config);
+    while deal.auction().declarer().is_none() {
+        let player = deal.next_actor().unwrap();
+        let legal = deal.legal_actions();
+        let action = Robot.choose(&deal.view(player), &legal);
+        deal.apply_action(action).unwrap();
+    }
+    // Player 1 has nothing to play. Player 3 bids up to 36 for clubs without two, which Player 2
+    // holds, with a grand with two worth 72.
+    let calls: Vec<Call> = deal.auction().calls().to_vec();
+    assert_eq!(calls[..2], [Call::Bid(18), Call::Pass]);
+    assert_eq!(deal.auction().declarer(), Some((Player(1), 36)));
+    assert_eq!(calls.last(), Some(&Call::Pass));
+}
+
+#[test]
+fn test_robots() {
+    let mut declared = 0;
+    let mut won = 0;
+    for seed in 0..200 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let dealer = Player((seed % 3) as u8);
+        let mut deal = Deal::from(DealConfig::random(dealer, &mut rng));
+        let result = deal
+            .play_out(|deal, player, legal| Robot.choose(&deal.view(player), &legal))
+            .unwrap()
+            .expect("deal is over");
+        if result.contract.is_some() {
+            declared += 1;
+            if result.score.won {
+                won += 1;
+            }
+        }
+    }
+    assert!(declared > 50, "declared {} of 200", declared);
+    assert!(won * 2 > declared, "won {} of {}", won, declared);
+}
@@ -0,0 +1,108 @@
+//! Scoring.
+//!
+//! A suit or grand game is worth its base value times a multiplier, which counts the matadors,
+//! plus one for the game, and one more for each of: playing from hand, schneider, schneider
+//! announced, schwarz, schwarz announced, and playing open. A null game has a fixed value. The
+//! declarer scores the value of a game they win, and loses twice the value of a game they lose.
+//!
+//! A declarer whose game turns out to be worth less than their bid has overbid, and loses the
+//! game, valued at the lowest multiple of its base value that reaches the bid.
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Contract, GameType};
+use crate::tr;
+
+#[cfg(test)]
+mod test;
+
+/// Every value a game may be worth, which are the values that may be bid, from lowest to highest.
+pub fn bid_values() -> Vec<u16> {
+    let mut values = BTreeSet::new();
+    for contract in Contract::all(false).into_iter().chain(Contract::all(true)) {
+        let base = contract.base_value();
+        let max = match contract.game {
+            GameType::Null => 1,
+            GameType::Grand => 11,
+            GameType::Suit(_) => 18,
+        };
+        values.extend((1..=max).map(|level| base * level));
+    }
+    values.into_iter().filter(|&v| v >= 18).collect()
+}
+
+/// The score of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Score {
+    /// Whether the declarer won.
+    pub won: bool,
+    /// Whether the declarer lost by overbidding.
+    pub overbid: bool,
+    /// The value of the game.
+    pub value: u16,
+}
+
+impl Score {
+    /// Scores a game, given the declarer's bid and matadors, and the card points and tricks they
+    /// took, counting the skat.
+    pub fn new(contract: &Contract, bid: u16, matadors: u8, points: u8, tricks: u8) -> Self {
+        let base = contract.base_value();
+        let (mut won, mut value) = if contract.game == GameType::Null {
+            (tricks == 0, base)
+        } else {
+            let schneider = points >= 90 || points <= 30;
+            let schwarz = tricks == 10 || tricks == 0;
+            let level = 1
+                + u16::from(matadors)
+                + [
+                    contract.hand,
+                    schneider,
+                    contract.schneider,
+                    schwarz,
+                    contract.schwarz,
+                    contract.ouvert,
+                ]
+                .iter()
+                .map(|&b| u16::from(b))
+                .sum::<u16>();
+            let won = points >= 61
+                && (!contract.schneider || points >= 90)
+                && (!contract.schwarz || tricks == 10);
+            (won, base * level)
+        };
+        let overbid = value < bid;
+        if overbid {
+            won = false;
+            value = bid.div_ceil(base) * base;
+        }
+        Self {
+            won,
+            overbid,
+            value,
+        }
+    }
+
+    /// The points scored by the declarer.
+    pub fn points(&self) -> i32 {
+        let value = i32::from(self.value);
+        if self.won {
+            value
+        } else {
+            -2 * value
+        }
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcome = match (self.won, self.overbid) {
+            (true, _) => tr!("skat-won"),
+            (false, false) => tr!("skat-lost"),
+            (false, true) => tr!("skat-overbid"),
+        };
+        write!(f, "{outcome}, {:+}", self.points())
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+use crate::skat::Suit;
+
+fn game(game: GameType) -> Contract {
+    Contract::new(game)
+}
+
+#[test]
+fn test_bid_values() {
+    let values = bid_values();
+    assert_eq!(
+        values[..16],
+        [18, 20, 22, 23, 24, 27, 30, 33, 35, 36, 40, 44, 45, 46, 48, 50]
+    );
+    assert_eq!(values.last(), Some(&264));
+}
+
+#[test]
+fn test_score() {
+    let clubs = game(GameType::Suit(Suit::Club));
+    // With two, game three, times twelve.
+    let score = Score::new(&clubs, 18, 2, 61, 5);
+    assert_eq!((score.won, score.value, score.points()), (true, 36, 36));
+    // Losing scores twice the value, and schneider counts either way.
+    let score = Score::new(&clubs, 18, 2, 30, 2);
+    assert_eq!((score.won, score.value, score.points()), (false, 48, -96));
+    // Schwarz counts too.
+    let score = Score::new(&clubs, 18, 1, 120, 10);
+    assert_eq!(score.value, 12 * 4);
+
+    // Hand, with schneider announced but not made.
+    let hand = Contract {
+        hand: true,
+        schneider: true,
+        ..game(GameType::Grand)
+    };
+    let score = Score::new(&hand, 18, 1, 85, 7);
+    assert_eq!((score.won, score.value), (false, 24 * 4));
+
+    // An overbid game is valued at the lowest multiple of its base value that reaches the bid.
+    let diamonds = game(GameType::Suit(Suit::Diamond));
+    let score = Score::new(&diamonds, 24, 1, 70, 6);
+    assert_eq!(score.value, 27);
+    assert!(score.overbid && !score.won);
+    assert_eq!(score.to_string(), "overbid, -54");
+
+    let null = game(GameType::Null);
+    assert_eq!(Score::new(&null, 23, 0, 0, 0).points(), 23);
+    assert_eq!(Score::new(&null, 23, 0, 10, 1).points(), -46);
+}